@@ -2,8 +2,6 @@
 
 use anyhow::Result;
 use async_trait::async_trait;
-use once_cell::sync::Lazy;
-use regex::Regex;
 use std::path::Path;
 use std::sync::{Mutex, OnceLock};
 use std::time::Duration;
@@ -12,21 +10,15 @@ use tokio::sync::mpsc;
 use super::super::client::{BridgeClient, BridgeProcess};
 use super::super::types::*;
 use super::util::{bridge_cwd, extract_output_from_result, format_tool_call, parse_claude_permission_mode, parse_json_schema, resolve_prompt_paths};
+use crate::agent::retry::{ErrorClass, add_jitter_ms, classify_error, parse_retry_after_ms};
 use crate::agent::runner::{AgentResult, AgentRunner};
 use crate::{AgentConfig, Job, LogEvent};
 
 /// Maximum number of retries when connection drops
 const MAX_CONNECTION_RETRIES: u32 = 3;
-/// Maximum number of retries when rate limited (HTTP 429)
-const MAX_RATE_LIMIT_RETRIES: u32 = 20;
 /// Delay between connection-drop retries in milliseconds
 const CONNECTION_RETRY_DELAY_MS: u64 = 2000;
 
-static RETRY_AFTER_RE: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(?i)retry[- ]after\s*[:=]?\s*(\d+)\s*(s|sec|secs|second|seconds|m|min|mins|minute|minutes)?")
-        .expect("valid retry-after regex")
-});
-
 /// Global bridge process for lazy initialization.
 /// Stored in OnceLock so it persists for the lifetime of the application.
 static BRIDGE_PROCESS: OnceLock<Mutex<Option<BridgeProcess>>> = OnceLock::new();
@@ -63,58 +55,6 @@ pub(super) fn ensure_bridge_running(client: &BridgeClient) {
     }
 }
 
-fn is_rate_limited(code: Option<&str>, message: &str) -> bool {
-    if code.is_some_and(|c| c.eq_ignore_ascii_case("429") || c.eq_ignore_ascii_case("rate_limit")) {
-        return true;
-    }
-    if message.contains("429") {
-        return true;
-    }
-    let msg = message.to_ascii_lowercase();
-    msg.contains("rate limit") || msg.contains("too many requests")
-}
-
-fn parse_retry_after_ms(message: &str) -> Option<u64> {
-    let caps = RETRY_AFTER_RE.captures(message)?;
-    let value: u64 = caps.get(1)?.as_str().parse().ok()?;
-    let unit = caps.get(2).map(|m| m.as_str().to_ascii_lowercase());
-    match unit.as_deref() {
-        None | Some("s") | Some("sec") | Some("secs") | Some("second") | Some("seconds") => Some(value.saturating_mul(1000)),
-        Some("m") | Some("min") | Some("mins") | Some("minute") | Some("minutes") => Some(value.saturating_mul(60_000)),
-        Some(_) => None,
-    }
-}
-
-/// Calculate retry delay for rate limit errors with exponential backoff.
-/// Pattern (seconds): 2, 4, 8, 16, 30, 60, 60, ...
-fn rate_limit_delay_ms(attempt: u32, retry_after_ms: Option<u64>) -> u64 {
-    if let Some(ms) = retry_after_ms {
-        return ms.clamp(1_000, 60_000);
-    }
-    match attempt {
-        1 => 2_000,
-        2 => 4_000,
-        3 => 8_000,
-        4 => 16_000,
-        5 => 30_000,
-        _ => 60_000,
-    }
-}
-
-fn add_jitter_ms(delay_ms: u64) -> u64 {
-    let max_jitter = (delay_ms / 10).min(1_000);
-    if max_jitter == 0 {
-        return delay_ms;
-    }
-    let mut buf = [0u8; 8];
-    if getrandom::getrandom(&mut buf).is_ok() {
-        let r = u64::from_le_bytes(buf);
-        delay_ms.saturating_add(r % (max_jitter + 1))
-    } else {
-        delay_ms
-    }
-}
-
 /// Claude adapter using the SDK Bridge
 ///
 /// Provides full Claude Agent SDK features:
@@ -135,15 +75,22 @@ impl ClaudeBridgeAdapter {
         Self { client: BridgeClient::with_url(url) }
     }
 
-    fn build_prompt(&self, job: &Job, _config: &AgentConfig) -> String {
+    pub(crate) fn build_prompt(&self, job: &Job, _config: &AgentConfig) -> String {
         let paths = resolve_prompt_paths(job);
 
         // Use Claude's native skill invocation with /skill-name
         // The skill must be installed in .claude/skills/ for Claude to find it
         let mut prompt = format!("/{}", job.skill);
 
-        // Add file context
-        prompt.push_str(&format!(" on file {}:{}", paths.file_path, job.source_line));
+        // Add file (or directory) context
+        if job.is_dir_target() {
+            prompt.push_str(&format!(
+                " on directory {} (read and modify any files within it as needed)",
+                paths.file_path
+            ));
+        } else {
+            prompt.push_str(&format!(" on file {}:{}", paths.file_path, job.source_line));
+        }
 
         // Add IDE context if available
         if !paths.ide_context.is_empty() {
@@ -162,7 +109,7 @@ impl ClaudeBridgeAdapter {
         prompt
     }
 
-    fn build_system_prompt(&self, job: &Job, config: &AgentConfig) -> Option<String> {
+    pub(crate) fn build_system_prompt(&self, job: &Job, config: &AgentConfig) -> Option<String> {
         let template = config.get_skill_template(&job.skill);
         let mut system_prompt = template.system_prompt.unwrap_or_default();
 
@@ -253,7 +200,7 @@ impl ClaudeBridgeAdapter {
             agents: clone_map_if_non_empty(&config.agents),
             allowed_tools: clone_if_non_empty(&config.allowed_tools),
             disallowed_tools: clone_if_non_empty(&config.disallowed_tools),
-            env: clone_map_if_non_empty(&config.env),
+            env: clone_map_if_non_empty(&config.env_for_job(&job.env)),
             mcp_servers: clone_map_if_non_empty(&config.mcp_servers),
             system_prompt: self.build_system_prompt(job, config),
             system_prompt_mode: Some(match config.system_prompt_mode {
@@ -288,9 +235,16 @@ impl Default for ClaudeBridgeAdapter {
     fn default() -> Self { Self::new() }
 }
 
-#[async_trait]
-impl AgentRunner for ClaudeBridgeAdapter {
-    async fn run(&self, job: &Job, worktree: &Path, config: &AgentConfig, event_tx: mpsc::Sender<LogEvent>) -> Result<AgentResult> {
+impl ClaudeBridgeAdapter {
+    /// Run a query, or reattach to `resume_session_id` with a continuation prompt.
+    async fn run_query(
+        &self,
+        job: &Job,
+        worktree: &Path,
+        config: &AgentConfig,
+        event_tx: mpsc::Sender<LogEvent>,
+        resume_session_id: Option<&str>,
+    ) -> Result<AgentResult> {
         let job_id = job.id;
 
         // Ensure bridge server is running (lazy-start if needed)
@@ -299,24 +253,32 @@ impl AgentRunner for ClaudeBridgeAdapter {
         let prompt = self.build_prompt(job, config);
         let cwd = bridge_cwd(worktree);
 
-        let _ = event_tx.send(LogEvent::system(format!("Starting Claude SDK job #{}", job_id)).for_job(job_id)).await;
-        let _ = event_tx.send(LogEvent::system(format!(">>> {}", prompt)).for_job(job_id)).await;
+        if let Some(session_id) = resume_session_id {
+            let _ = event_tx.send(LogEvent::system(format!("Resuming Claude SDK session {} for job #{}", session_id, job_id)).for_job(job_id)).await;
+        } else {
+            let _ = event_tx.send(LogEvent::system(format!("Starting Claude SDK job #{}", job_id)).for_job(job_id)).await;
+            let _ = event_tx.send(LogEvent::system(format!(">>> {}", prompt)).for_job(job_id)).await;
+        }
 
         // Clone prompt for sent_prompt before moving into request
-        let sent_prompt = prompt.clone();
+        let sent_prompt = if resume_session_id.is_some() { "continue".to_string() } else { prompt.clone() };
         let initial_request = self.build_request(job, config, prompt, cwd.clone());
         let mut result = AgentResult {
             success: false, error: None, changed_files: Vec::new(), cost_usd: None,
             input_tokens: None, output_tokens: None, cache_read_tokens: None, cache_write_tokens: None,
-            duration_ms: None, sent_prompt: Some(sent_prompt), output_text: None, structured_output: None, session_id: None,
+            duration_ms: None, tool_calls: None, sent_prompt: Some(sent_prompt), output_text: None, structured_output: None, session_id: None,
         };
 
         let mut output_text = String::new();
-        let mut captured_session_id: Option<String> = job.bridge_session_id.clone();
+        let mut captured_session_id: Option<String> =
+            resume_session_id.map(str::to_string).or_else(|| job.bridge_session_id.clone());
         let mut structured_result: Option<serde_json::Value> = None;
         let mut connection_retries = 0u32;
         let mut rate_limit_retries = 0u32;
-        let mut use_continue_request = false;
+        // Last transient error, reported if the connection retries run out
+        let mut last_transient_error: Option<String> = None;
+        // A resumed session starts with the same "continue" request used after dropped connections
+        let mut use_continue_request = resume_session_id.is_some();
 
         loop {
             let mut received_session_complete = false;
@@ -346,9 +308,14 @@ impl AgentRunner for ClaudeBridgeAdapter {
                 let event = match event_result {
                     Ok(e) => e,
                     Err(e) => {
-                        if is_rate_limited(None, &e) {
+                        let class = classify_error(None, &e);
+                        if class == ErrorClass::RateLimited {
                             rate_limited_retry_after_ms = parse_retry_after_ms(&e);
                             rate_limited_message = Some(e);
+                        } else if class == ErrorClass::Transient {
+                            // Log as system message, don't set result.error so outer loop retries
+                            let _ = event_tx.send(LogEvent::system(format!("Connection issue: {}", e)).for_job(job_id)).await;
+                            last_transient_error = Some(e);
                         } else {
                             let message = format!("Bridge event stream error: {}", e);
                             result.error = Some(message.clone());
@@ -374,6 +341,7 @@ impl AgentRunner for ClaudeBridgeAdapter {
                         let _ = event_tx.send(LogEvent::thought(content).for_job(job_id)).await;
                     }
                     BridgeEvent::ToolUse { tool_name, mut tool_input, tool_use_id, .. } => {
+                        *result.tool_calls.get_or_insert(0) += 1;
                         let formatted = format_tool_call(&tool_name, &tool_input);
                         if let Some(obj) = tool_input.as_object_mut() {
                             obj.insert("tool_use_id".into(), serde_json::json!(tool_use_id));
@@ -386,9 +354,14 @@ impl AgentRunner for ClaudeBridgeAdapter {
                         let _ = event_tx.send(LogEvent::tool_output("tool", if success { output } else { format!("Error: {}", output) }).for_job(job_id)).await;
                     }
                     BridgeEvent::Error { message, code, .. } => {
-                        if is_rate_limited(code.as_deref(), &message) {
+                        let class = classify_error(code.as_deref(), &message);
+                        if class == ErrorClass::RateLimited {
                             rate_limited_retry_after_ms = parse_retry_after_ms(&message);
                             rate_limited_message = Some(message);
+                        } else if class == ErrorClass::Transient {
+                            // Log as system message, don't set result.error so outer loop retries
+                            let _ = event_tx.send(LogEvent::system(format!("Connection issue: {}", message)).for_job(job_id)).await;
+                            last_transient_error = Some(message);
                         } else {
                             let _ = event_tx.send(LogEvent::error(&message).for_job(job_id)).await;
                             result.error = Some(message);
@@ -429,24 +402,25 @@ impl AgentRunner for ClaudeBridgeAdapter {
 
             if let Some(message) = rate_limited_message.take() {
                 rate_limit_retries += 1;
-                if rate_limit_retries > MAX_RATE_LIMIT_RETRIES {
+                let max_retries = config.retry.max_retries;
+                if rate_limit_retries > max_retries {
                     let final_message = format!(
-                        "Rate limited (HTTP 429) too many times ({}/{}): {}",
-                        rate_limit_retries, MAX_RATE_LIMIT_RETRIES, message
+                        "Rate limited (HTTP 429), giving up after {} retries: {}",
+                        max_retries, message
                     );
                     result.error = Some(final_message.clone());
                     let _ = event_tx.send(LogEvent::error(&final_message).for_job(job_id)).await;
                     break;
                 }
 
-                let base_delay_ms = rate_limit_delay_ms(rate_limit_retries, rate_limited_retry_after_ms);
+                let base_delay_ms = config.retry.delay_ms(rate_limit_retries, rate_limited_retry_after_ms);
                 let delay_ms = add_jitter_ms(base_delay_ms);
                 let delay_s = (delay_ms + 999) / 1000;
                 let _ = event_tx
                     .send(
                         LogEvent::system(format!(
                             "Rate limited, retrying in {}s ({}/{})...",
-                            delay_s, rate_limit_retries, MAX_RATE_LIMIT_RETRIES
+                            delay_s, rate_limit_retries, max_retries
                         ))
                         .for_job(job_id),
                     )
@@ -462,8 +436,10 @@ impl AgentRunner for ClaudeBridgeAdapter {
 
             // Check if we can retry
             if connection_retries >= MAX_CONNECTION_RETRIES {
-                result.error = Some("Claude session ended unexpectedly (no completion event received)".to_string());
-                let _ = event_tx.send(LogEvent::error("Claude session ended unexpectedly").for_job(job_id)).await;
+                let reason = last_transient_error.as_deref().unwrap_or("no completion event received");
+                let message = format!("Claude session ended unexpectedly ({})", reason);
+                let _ = event_tx.send(LogEvent::error(&message).for_job(job_id)).await;
+                result.error = Some(message);
                 break;
             }
 
@@ -490,6 +466,17 @@ impl AgentRunner for ClaudeBridgeAdapter {
 
         Ok(result)
     }
+}
+
+#[async_trait]
+impl AgentRunner for ClaudeBridgeAdapter {
+    async fn run(&self, job: &Job, worktree: &Path, config: &AgentConfig, event_tx: mpsc::Sender<LogEvent>) -> Result<AgentResult> {
+        self.run_query(job, worktree, config, event_tx, None).await
+    }
+
+    async fn resume(&self, job: &Job, session_id: &str, worktree: &Path, config: &AgentConfig, event_tx: mpsc::Sender<LogEvent>) -> Result<AgentResult> {
+        self.run_query(job, worktree, config, event_tx, Some(session_id)).await
+    }
 
     fn id(&self) -> &str { "claude" }
     fn is_available(&self) -> bool { self.client.health_check().is_ok() }
@@ -497,33 +484,22 @@ impl AgentRunner for ClaudeBridgeAdapter {
 
 #[cfg(test)]
 mod tests {
-    use super::{is_rate_limited, parse_retry_after_ms, rate_limit_delay_ms};
+    use super::ClaudeBridgeAdapter;
+    use crate::config::Config;
+    use crate::{AgentConfig, Job, ScopeDefinition};
+    use std::path::PathBuf;
 
     #[test]
-    fn detects_rate_limit_by_code() {
-        assert!(is_rate_limited(Some("429"), "anything"));
-        assert!(is_rate_limited(Some("rate_limit"), "anything"));
-        assert!(is_rate_limited(Some("RATE_LIMIT"), "anything"));
-    }
+    fn claude_build_prompt_ends_with_the_global_prompt_suffix() {
+        let mut cfg = Config::with_defaults();
+        cfg.settings.global_prompt_suffix = Some("Never touch vendor/.".to_string());
+        let job = Job::new(1, "refactor".to_string(), ScopeDefinition::file(PathBuf::from("src/main.rs")), "src/main.rs:42".to_string(),
+            Some("fix the bug".to_string()), "claude".to_string(), PathBuf::from("src/main.rs"), 42, None);
 
-    #[test]
-    fn detects_rate_limit_by_message() {
-        assert!(is_rate_limited(None, "HTTP 429 Too Many Requests"));
-        assert!(is_rate_limited(None, "Rate limit exceeded"));
-        assert!(is_rate_limited(None, "Too many requests"));
-    }
+        let adapter = ClaudeBridgeAdapter::new();
+        let prompt = adapter.build_prompt(&cfg.job_with_prompt_suffix(&job), &AgentConfig::claude_default());
 
-    #[test]
-    fn parses_retry_after_ms() {
-        assert_eq!(parse_retry_after_ms("Retry-After: 5"), Some(5_000));
-        assert_eq!(parse_retry_after_ms("retry after 2s"), Some(2_000));
-        assert_eq!(parse_retry_after_ms("retry-after=3 mins"), Some(180_000));
-        assert_eq!(parse_retry_after_ms("no hint here"), None);
-    }
-
-    #[test]
-    fn rate_limit_delay_clamps_retry_after() {
-        assert_eq!(rate_limit_delay_ms(1, Some(500)), 1_000);
-        assert_eq!(rate_limit_delay_ms(1, Some(90_000)), 60_000);
+        assert!(prompt.starts_with("/refactor on file src/main.rs:42"), "got: {}", prompt);
+        assert!(prompt.ends_with("fix the bug\n\nNever touch vendor/."), "got: {}", prompt);
     }
 }
@@ -11,44 +11,14 @@ use super::super::client::BridgeClient;
 use super::super::types::*;
 use super::claude::ensure_bridge_running;
 use super::util::{ResolvedPaths, bridge_cwd, extract_output_from_result, format_tool_call, parse_json_schema, resolve_prompt_paths};
+use crate::agent::retry::{ErrorClass, add_jitter_ms, classify_error, parse_retry_after_ms};
 use crate::agent::runner::{AgentResult, AgentRunner};
 use crate::{AgentConfig, Job, LogEvent};
 
 /// Maximum number of retries when connection drops
 const MAX_RETRIES: u32 = 15;
-/// Maximum number of retries when rate limited (HTTP 429)
-const MAX_RATE_LIMIT_RETRIES: u32 = 20;
 
-fn is_rate_limited(code: Option<&str>, message: &str) -> bool {
-    if code.is_some_and(|c| c.eq_ignore_ascii_case("429") || c.eq_ignore_ascii_case("rate_limit")) {
-        return true;
-    }
-    if message.contains("429") {
-        return true;
-    }
-    let msg = message.to_ascii_lowercase();
-    msg.contains("rate limit") || msg.contains("too many requests")
-}
-
-/// Check if an error message indicates a retriable connection issue
-fn is_retriable_connection_error(message: &str) -> bool {
-    let msg = message.to_ascii_lowercase();
-    msg.contains("reconnect")
-        || msg.contains("connection")
-        || msg.contains("network")
-        || msg.contains("timeout")
-        || msg.contains("reset")
-        || msg.contains("econnreset")
-        || msg.contains("epipe")
-        || msg.contains("socket")
-        || msg.contains("closed")
-        || msg.contains("failed to start")
-        || msg.contains("bridge")
-        || msg.contains("refused")
-        || msg.contains("disconnected")
-}
-
-/// Calculate retry delay with exponential backoff (capped at 30s)
+/// Calculate connection-retry delay with exponential backoff (capped at 30s)
 /// Pattern: 1s, 2s, 4s, 8s, 10s, 20s, 30s, 30s, ...
 fn retry_delay_ms(attempt: u32) -> u64 {
     match attempt {
@@ -182,7 +152,7 @@ impl CodexBridgeAdapter {
         (None, Vec::new())
     }
 
-    pub(super) fn build_prompt(&self, job: &Job, config: &AgentConfig, worktree: &Path) -> String {
+    pub(crate) fn build_prompt(&self, job: &Job, config: &AgentConfig, worktree: &Path) -> String {
         let paths = resolve_prompt_paths(job);
         let template = config.get_skill_template(&job.skill);
         let scope = Self::format_scope(job);
@@ -232,10 +202,18 @@ impl CodexBridgeAdapter {
         }
 
         // Add the task
-        prompt.push_str(&format!(
-            "## Task\n\nExecute the '{}' skill on file `{}` at line {}.\n",
-            job.skill, paths.file_path, job.source_line
-        ));
+        if job.is_dir_target() {
+            prompt.push_str(&format!(
+                "## Task\n\nExecute the '{}' skill on the directory `{}`. \
+                You may read and modify any files within it.\n",
+                job.skill, paths.file_path
+            ));
+        } else {
+            prompt.push_str(&format!(
+                "## Task\n\nExecute the '{}' skill on file `{}` at line {}.\n",
+                job.skill, paths.file_path, job.source_line
+            ));
+        }
 
         // Add IDE context if available
         if !skill_template_covered_ide_context && !paths.ide_context.is_empty() {
@@ -307,7 +285,7 @@ impl AgentRunner for CodexBridgeAdapter {
             cwd: cwd.clone(),
             thread_id: job.bridge_session_id.clone(),
             sandbox: config.sandbox.clone().or_else(|| Some("workspace-write".to_string())),
-            env: if config.env.is_empty() { None } else { Some(config.env.clone()) },
+            env: Some(config.env_for_job(&job.env)).filter(|env| !env.is_empty()),
             output_schema: parse_json_schema(config.structured_output_schema.as_deref()),
             model: config.model.clone(), effort: None, approval_policy: None, skip_git_repo_check: None,
         };
@@ -315,7 +293,7 @@ impl AgentRunner for CodexBridgeAdapter {
         let mut result = AgentResult {
             success: false, error: None, changed_files: Vec::new(), cost_usd: None,
             input_tokens: None, output_tokens: None, cache_read_tokens: None, cache_write_tokens: None,
-            duration_ms: None, sent_prompt: Some(prompt), output_text: None, structured_output: None, session_id: None,
+            duration_ms: None, tool_calls: None, sent_prompt: Some(prompt), output_text: None, structured_output: None, session_id: None,
         };
 
         let mut output_text = String::new();
@@ -323,11 +301,14 @@ impl AgentRunner for CodexBridgeAdapter {
         let mut structured_result: Option<serde_json::Value> = None;
         let mut connection_retries = 0u32;
         let mut rate_limit_retries = 0u32;
+        // Last transient error, reported if the connection retries run out
+        let mut last_transient_error: Option<String> = None;
         let mut use_continue_request = false;
 
         loop {
             let mut received_session_complete = false;
             let mut rate_limited_message: Option<String> = None;
+            let mut rate_limited_retry_after_ms: Option<u64> = None;
             let (tx, mut rx) = tokio::sync::mpsc::channel::<Result<BridgeEvent, String>>(100);
             let client = self.client.clone();
 
@@ -350,11 +331,14 @@ impl AgentRunner for CodexBridgeAdapter {
                 let event = match event_result {
                     Ok(e) => e,
                     Err(e) => {
-                        if is_rate_limited(None, &e) {
+                        let class = classify_error(None, &e);
+                        if class == ErrorClass::RateLimited {
+                            rate_limited_retry_after_ms = parse_retry_after_ms(&e);
                             rate_limited_message = Some(e);
-                        } else if is_retriable_connection_error(&e) {
+                        } else if class == ErrorClass::Transient {
                             // Log as system message, don't set result.error so outer loop retries
                             let _ = event_tx.send(LogEvent::system(format!("Connection issue: {}", e)).for_job(job_id)).await;
+                            last_transient_error = Some(e);
                         } else {
                             let message = format!("Bridge event stream error: {}", e);
                             result.error = Some(message.clone());
@@ -386,6 +370,7 @@ impl AgentRunner for CodexBridgeAdapter {
                         let _ = event_tx.send(LogEvent::thought(content).for_job(job_id)).await;
                     }
                     BridgeEvent::ToolUse { tool_name, tool_input, tool_use_id, .. } => {
+                        *result.tool_calls.get_or_insert(0) += 1;
                         let mut args = tool_input.clone();
                         if let Some(obj) = args.as_object_mut() {
                             obj.insert("tool_use_id".to_string(), serde_json::json!(tool_use_id));
@@ -398,11 +383,14 @@ impl AgentRunner for CodexBridgeAdapter {
                         let _ = event_tx.send(LogEvent::tool_output("tool", output).for_job(job_id)).await;
                     }
                     BridgeEvent::Error { message, code, .. } => {
-                        if is_rate_limited(code.as_deref(), &message) {
+                        let class = classify_error(code.as_deref(), &message);
+                        if class == ErrorClass::RateLimited {
+                            rate_limited_retry_after_ms = parse_retry_after_ms(&message);
                             rate_limited_message = Some(message);
-                        } else if is_retriable_connection_error(&message) {
+                        } else if class == ErrorClass::Transient {
                             // Log as system message, don't set result.error so outer loop retries
                             let _ = event_tx.send(LogEvent::system(format!("Connection issue: {}", message)).for_job(job_id)).await;
+                            last_transient_error = Some(message);
                         } else {
                             result.error = Some(message.clone());
                             let _ = event_tx.send(LogEvent::error(message).for_job(job_id)).await;
@@ -433,24 +421,25 @@ impl AgentRunner for CodexBridgeAdapter {
 
             if let Some(message) = rate_limited_message.take() {
                 rate_limit_retries += 1;
-                if rate_limit_retries > MAX_RATE_LIMIT_RETRIES {
+                let max_retries = config.retry.max_retries;
+                if rate_limit_retries > max_retries {
                     let final_message = format!(
-                        "Rate limited (HTTP 429) too many times ({}/{}): {}",
-                        rate_limit_retries, MAX_RATE_LIMIT_RETRIES, message
+                        "Rate limited (HTTP 429), giving up after {} retries: {}",
+                        max_retries, message
                     );
                     result.error = Some(final_message.clone());
                     let _ = event_tx.send(LogEvent::error(&final_message).for_job(job_id)).await;
                     break;
                 }
 
-                let delay = retry_delay_ms(rate_limit_retries);
+                let delay = add_jitter_ms(config.retry.delay_ms(rate_limit_retries, rate_limited_retry_after_ms));
                 let _ = event_tx
                     .send(
                         LogEvent::system(format!(
                             "Rate limited, retrying in {}s ({}/{})...",
-                            delay / 1000,
+                            delay.div_ceil(1000),
                             rate_limit_retries,
-                            MAX_RATE_LIMIT_RETRIES
+                            max_retries
                         ))
                         .for_job(job_id),
                     )
@@ -465,8 +454,10 @@ impl AgentRunner for CodexBridgeAdapter {
             }
 
             if connection_retries >= MAX_RETRIES {
-                result.error = Some("Codex session ended unexpectedly (no completion event received)".to_string());
-                let _ = event_tx.send(LogEvent::error("Codex session ended unexpectedly").for_job(job_id)).await;
+                let reason = last_transient_error.as_deref().unwrap_or("no completion event received");
+                let message = format!("Codex session ended unexpectedly ({})", reason);
+                let _ = event_tx.send(LogEvent::error(&message).for_job(job_id)).await;
+                result.error = Some(message);
                 break;
             }
 
@@ -503,7 +494,8 @@ impl AgentRunner for CodexBridgeAdapter {
 
 #[cfg(test)]
 mod tests {
-    use super::{is_rate_limited, is_retriable_connection_error, CodexBridgeAdapter};
+    use super::CodexBridgeAdapter;
+    use crate::config::Config;
     use crate::{AgentConfig, Job, ScopeDefinition};
     use std::path::{Path, PathBuf};
     use tempfile::TempDir;
@@ -565,23 +557,14 @@ Description: {description}
     }
 
     #[test]
-    fn detects_rate_limit_code() {
-        assert!(is_rate_limited(Some("429"), "anything"));
-        assert!(is_rate_limited(Some("rate_limit"), "anything"));
-        assert!(is_rate_limited(Some("RATE_LIMIT"), "anything"));
-    }
+    fn codex_build_prompt_includes_the_global_prompt_suffix() {
+        let mut cfg = Config::with_defaults();
+        cfg.settings.global_prompt_suffix = Some("Never touch vendor/.".to_string());
+        let job = cfg.job_with_prompt_suffix(&create_test_job("refactor", Some("fix the bug"), "src/main.rs", 42));
 
-    #[test]
-    fn detects_retriable_connection_errors() {
-        // "Reconnecting... 1/5" is the actual message from Codex SDK
-        assert!(is_retriable_connection_error("Reconnecting... 1/5"));
-        assert!(is_retriable_connection_error("Connection lost"));
-        assert!(is_retriable_connection_error("Network error"));
-        assert!(is_retriable_connection_error("timeout"));
-        assert!(is_retriable_connection_error("ECONNRESET"));
-        assert!(is_retriable_connection_error("socket closed"));
-        // Should NOT match unrelated errors
-        assert!(!is_retriable_connection_error("Invalid API key"));
-        assert!(!is_retriable_connection_error("File not found"));
+        let adapter = CodexBridgeAdapter::new();
+        let prompt = adapter.build_prompt(&job, &AgentConfig::codex_default(), Path::new("."));
+
+        assert!(prompt.contains("## User Request\n\nfix the bug\n\nNever touch vendor/."), "got: {}", prompt);
     }
 }
@@ -0,0 +1,369 @@
+//! Diagram export for chain definitions (Graphviz DOT / Mermaid).
+//!
+//! Renders chain steps as nodes with their trigger conditions, plus edges for
+//! sequential flow, conditional skips, `loop_to` jumps and `on_state` branches.
+
+use crate::config::{ModeChain, StepAction};
+
+/// Output format for [`render_chain_diagram`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainDiagramFormat {
+    Dot,
+    Mermaid,
+}
+
+impl ChainDiagramFormat {
+    /// Parse a format name ("dot"/"graphviz" or "mermaid"/"mmd")
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "dot" | "graphviz" => Some(Self::Dot),
+            "mermaid" | "mmd" => Some(Self::Mermaid),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EdgeKind {
+    /// Normal step-to-step flow
+    Next,
+    /// Bypass taken when the following step's conditions are not met
+    Skip,
+    /// `loop_to` jump back to an earlier step
+    Loop,
+}
+
+#[derive(Debug, Clone)]
+struct Edge {
+    from: String,
+    to: String,
+    label: Option<String>,
+    kind: EdgeKind,
+}
+
+fn step_node_id(index: usize) -> String {
+    format!("step{}", index)
+}
+
+fn node_id_or_end(chain: &ModeChain, index: usize) -> String {
+    if index < chain.steps.len() {
+        step_node_id(index)
+    } else {
+        "end".to_string()
+    }
+}
+
+/// Human-readable condition summary for a step (e.g. "on: issues_found; unless: clean")
+fn condition_label(chain: &ModeChain, index: usize) -> Option<String> {
+    let step = chain.steps.get(index)?;
+    let mut parts = Vec::new();
+    if let Some(trigger) = step.trigger_on.as_ref().filter(|t| !t.is_empty()) {
+        parts.push(format!("on: {}", trigger.join(" | ")));
+    }
+    if let Some(skip) = step.skip_on.as_ref().filter(|s| !s.is_empty()) {
+        parts.push(format!("unless: {}", skip.join(" | ")));
+    }
+    if parts.is_empty() { None } else { Some(parts.join("; ")) }
+}
+
+fn step_label(chain: &ModeChain, index: usize) -> String {
+    let step = &chain.steps[index];
+    let mut label = format!("{}. {}", index + 1, step.skill);
+    if let Some(agent) = step.agent.as_deref().filter(|a| !a.trim().is_empty()) {
+        label.push_str(&format!("\n(agent: {})", agent));
+    }
+    label
+}
+
+fn collect_edges(chain: &ModeChain) -> Vec<Edge> {
+    let mut edges = Vec::new();
+    if chain.steps.is_empty() {
+        edges.push(Edge {
+            from: "start".to_string(),
+            to: "end".to_string(),
+            label: None,
+            kind: EdgeKind::Next,
+        });
+        return edges;
+    }
+
+    // Entry into each step (from start or the previous step), labelled with its conditions.
+    for index in 0..chain.steps.len() {
+        let from = if index == 0 {
+            "start".to_string()
+        } else {
+            step_node_id(index - 1)
+        };
+        let condition = condition_label(chain, index);
+
+        edges.push(Edge {
+            from: from.clone(),
+            to: step_node_id(index),
+            label: condition.clone(),
+            kind: EdgeKind::Next,
+        });
+
+        // Conditional steps can be skipped, continuing with the following step.
+        if condition.is_some() {
+            edges.push(Edge {
+                from,
+                to: node_id_or_end(chain, index + 1),
+                label: Some("skip".to_string()),
+                kind: EdgeKind::Skip,
+            });
+        }
+    }
+
+    edges.push(Edge {
+        from: step_node_id(chain.steps.len() - 1),
+        to: "end".to_string(),
+        label: None,
+        kind: EdgeKind::Next,
+    });
+
+    // loop_to edges: when the step would run (and loops remain), jump back instead.
+    for (index, step) in chain.steps.iter().enumerate() {
+        let Some(target) = step.loop_to.as_deref() else {
+            continue;
+        };
+        let label = format!("loop (max {})", chain.max_loops);
+        match chain.steps.iter().position(|s| s.skill == target) {
+            Some(target_idx) => edges.push(Edge {
+                from: step_node_id(index),
+                to: step_node_id(target_idx),
+                label: Some(label),
+                kind: EdgeKind::Loop,
+            }),
+            None => edges.push(Edge {
+                from: step_node_id(index),
+                to: "missing".to_string(),
+                label: Some(format!("loop_to '{}' not found", target)),
+                kind: EdgeKind::Loop,
+            }),
+        }
+    }
+
+    // on_state branches, taken after the step ran
+    for (index, step) in chain.steps.iter().enumerate() {
+        let mut actions: Vec<_> = step.on_state.iter().collect();
+        actions.sort_by(|a, b| a.0.cmp(b.0));
+        for (state, action) in actions {
+            let (to, label, kind) = match action {
+                StepAction::Goto(target) => {
+                    let target_idx = chain.steps.iter().position(|s| &s.skill == target);
+                    match target_idx {
+                        Some(idx) => (step_node_id(idx), state.clone(), EdgeKind::Loop),
+                        None => (
+                            "missing".to_string(),
+                            format!("{}: goto '{}' not found", state, target),
+                            EdgeKind::Loop,
+                        ),
+                    }
+                }
+                StepAction::Stop => (
+                    "end".to_string(),
+                    format!("{}: stop", state),
+                    EdgeKind::Skip,
+                ),
+                StepAction::Skip => (
+                    node_id_or_end(chain, index + 2),
+                    format!("{}: skip next", state),
+                    EdgeKind::Skip,
+                ),
+            };
+            edges.push(Edge {
+                from: step_node_id(index),
+                to,
+                label: Some(label),
+                kind,
+            });
+        }
+    }
+
+    edges
+}
+
+fn has_missing_target(edges: &[Edge]) -> bool {
+    edges.iter().any(|e| e.to == "missing")
+}
+
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn escape_mermaid(s: &str) -> String {
+    s.replace('"', "#quot;").replace('\n', "<br/>")
+}
+
+/// Render a chain definition as a Graphviz DOT or Mermaid flowchart.
+pub fn render_chain_diagram(name: &str, chain: &ModeChain, format: ChainDiagramFormat) -> String {
+    let edges = collect_edges(chain);
+    match format {
+        ChainDiagramFormat::Dot => render_dot(name, chain, &edges),
+        ChainDiagramFormat::Mermaid => render_mermaid(name, chain, &edges),
+    }
+}
+
+fn render_dot(name: &str, chain: &ModeChain, edges: &[Edge]) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("digraph \"{}\" {{\n", escape_dot(name)));
+    out.push_str("  rankdir=TB;\n");
+    if let Some(desc) = chain.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        out.push_str(&format!("  label=\"{}\";\n  labelloc=t;\n", escape_dot(desc)));
+    }
+    out.push_str("  start [label=\"start\", shape=circle];\n");
+    for index in 0..chain.steps.len() {
+        out.push_str(&format!(
+            "  {} [label=\"{}\", shape=box];\n",
+            step_node_id(index),
+            escape_dot(&step_label(chain, index))
+        ));
+    }
+    out.push_str("  end [label=\"end\", shape=doublecircle];\n");
+    if has_missing_target(edges) {
+        out.push_str("  missing [label=\"missing step\", shape=octagon, color=red];\n");
+    }
+
+    for edge in edges {
+        let mut attrs = Vec::new();
+        if let Some(label) = &edge.label {
+            attrs.push(format!("label=\"{}\"", escape_dot(label)));
+        }
+        match edge.kind {
+            EdgeKind::Next => {}
+            EdgeKind::Skip => attrs.push("style=dashed".to_string()),
+            EdgeKind::Loop => {
+                attrs.push("style=dotted".to_string());
+                attrs.push("constraint=false".to_string());
+            }
+        }
+        if attrs.is_empty() {
+            out.push_str(&format!("  {} -> {};\n", edge.from, edge.to));
+        } else {
+            out.push_str(&format!("  {} -> {} [{}];\n", edge.from, edge.to, attrs.join(", ")));
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+fn render_mermaid(name: &str, chain: &ModeChain, edges: &[Edge]) -> String {
+    let mut out = String::new();
+    out.push_str("flowchart TD\n");
+    out.push_str(&format!("  %% chain: {}\n", name));
+    if let Some(desc) = chain.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        out.push_str(&format!("  %% {}\n", desc.replace('\n', " ")));
+    }
+    out.push_str("  start((start))\n");
+    for index in 0..chain.steps.len() {
+        out.push_str(&format!(
+            "  {}[\"{}\"]\n",
+            step_node_id(index),
+            escape_mermaid(&step_label(chain, index))
+        ));
+    }
+    out.push_str("  end_node(((end)))\n");
+    if has_missing_target(edges) {
+        out.push_str("  missing{{\"missing step\"}}\n");
+    }
+
+    // `end` is a reserved word in Mermaid flowcharts.
+    let node = |id: &str| -> String {
+        if id == "end" { "end_node".to_string() } else { id.to_string() }
+    };
+
+    for edge in edges {
+        let arrow = match edge.kind {
+            EdgeKind::Next => "-->",
+            EdgeKind::Skip | EdgeKind::Loop => "-.->",
+        };
+        match &edge.label {
+            Some(label) => out.push_str(&format!(
+                "  {} {}|\"{}\"| {}\n",
+                node(&edge.from),
+                arrow,
+                escape_mermaid(label),
+                node(&edge.to)
+            )),
+            None => out.push_str(&format!("  {} {} {}\n", node(&edge.from), arrow, node(&edge.to))),
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ChainStep;
+
+    fn step(skill: &str) -> ChainStep {
+        ChainStep {
+            skill: skill.to_string(),
+            trigger_on: None,
+            skip_on: None,
+            agent: None,
+            inject_context: None,
+            loop_to: None,
+            on_state: Default::default(),
+        }
+    }
+
+    fn chain(steps: Vec<ChainStep>) -> ModeChain {
+        ModeChain {
+            version: 0,
+            description: Some("Review then fix".to_string()),
+            states: Vec::new(),
+            steps,
+            stop_on_failure: true,
+            pass_full_response: true,
+            max_loops: 2,
+            use_worktree: None,
+        }
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!(ChainDiagramFormat::parse("DOT"), Some(ChainDiagramFormat::Dot));
+        assert_eq!(ChainDiagramFormat::parse("mermaid"), Some(ChainDiagramFormat::Mermaid));
+        assert_eq!(ChainDiagramFormat::parse("svg"), None);
+    }
+
+    #[test]
+    fn dot_contains_conditions_skips_and_loops() {
+        let mut fix = step("fix");
+        fix.trigger_on = Some(vec!["issues_found".to_string()]);
+        let mut again = step("review");
+        again.trigger_on = Some(vec!["issues_found".to_string()]);
+        again.loop_to = Some("fix".to_string());
+        let chain = chain(vec![step("review"), fix, again]);
+
+        let dot = render_chain_diagram("review+fix", &chain, ChainDiagramFormat::Dot);
+        assert!(dot.starts_with("digraph \"review+fix\" {"));
+        assert!(dot.contains("step0 -> step1 [label=\"on: issues_found\"];"));
+        assert!(dot.contains("step0 -> step2 [label=\"skip\", style=dashed];"));
+        assert!(dot.contains("step2 -> step1 [label=\"loop (max 2)\", style=dotted, constraint=false];"));
+        assert!(dot.contains("step2 -> end;"));
+    }
+
+    #[test]
+    fn mermaid_avoids_reserved_end_keyword() {
+        let chain = chain(vec![step("review"), step("fix")]);
+        let mermaid = render_chain_diagram("review+fix", &chain, ChainDiagramFormat::Mermaid);
+        assert!(mermaid.starts_with("flowchart TD\n"));
+        assert!(mermaid.contains("  start --> step0\n"));
+        assert!(mermaid.contains("  step1 --> end_node\n"));
+        assert!(!mermaid.contains("--> end\n"));
+    }
+
+    #[test]
+    fn marks_missing_loop_targets() {
+        let mut review = step("review");
+        review.loop_to = Some("nope".to_string());
+        let chain = chain(vec![review]);
+        let dot = render_chain_diagram("c", &chain, ChainDiagramFormat::Dot);
+        assert!(dot.contains("missing [label=\"missing step\""));
+        assert!(dot.contains("loop_to 'nope' not found"));
+    }
+}
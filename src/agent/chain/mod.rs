@@ -5,6 +5,7 @@
 //! Chains enable complex agent pipelines like "review → fix → test" with conditional
 //! branching based on previous step outcomes.
 
+mod diagram;
 mod prompt;
 mod state;
 mod types;
@@ -13,12 +14,14 @@ use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::mpsc;
 
-use crate::config::{Config, ModeChain};
+use crate::config::{Config, ModeChain, StepAction};
 use crate::bugbounty::BugBountyManager;
 use crate::{AgentConfig, Job, LogEvent};
 
 use super::AgentRegistry;
 
+pub use diagram::{render_chain_diagram, ChainDiagramFormat};
+pub use prompt::{build_chained_prompt, create_step_job};
 pub use types::{AgentResultSummary, ChainProgressEvent, ChainResult, ChainStepResult};
 
 /// Executes mode chains by orchestrating sequential agent runs.
@@ -177,7 +180,9 @@ impl<'a> ChainRunner<'a> {
     /// and executing the appropriate agent. Context (summaries) from each step
     /// is accumulated and passed to subsequent steps.
     ///
-    /// Supports `loop_to` for restarting from a previous step (limited by `max_loops`).
+    /// Supports `loop_to` for restarting from a previous step (limited by `max_loops`)
+    /// and `on_state` branching after a step completes (limited to
+    /// `steps.len() * (max_loops + 1)` executed steps).
     pub async fn run_chain(
         &self,
         chain_name: &str,
@@ -194,6 +199,8 @@ impl<'a> ChainRunner<'a> {
         let mut chain_success = true;
         let mut last_skill: Option<String> = None;
         let mut loop_count: u32 = 0;
+        let mut executed_steps: usize = 0;
+        let max_executed_steps = chain.steps.len() * (chain.max_loops as usize + 1);
 
         let _ = event_tx
             .send(LogEvent::system(format!(
@@ -302,8 +309,10 @@ impl<'a> ChainRunner<'a> {
                     skill: Arc::clone(&skill),
                     is_starting: true,
                     step_result: None,
+                    goto: None,
                 });
             }
+            executed_steps += 1;
 
             // Build the prompt with previous context
             let previous_context = if chain.pass_full_response {
@@ -364,22 +373,33 @@ impl<'a> ChainRunner<'a> {
                 }
             };
 
-            let result = adapter
-                .run(&step_job, self.work_dir, &agent_config, event_tx.clone())
-                .await;
+            let run_future = adapter.run(&step_job, self.work_dir, &agent_config, event_tx.clone());
+            let result = match agent_config.timeout_secs.filter(|secs| *secs > 0) {
+                Some(secs) => tokio::time::timeout(std::time::Duration::from_secs(secs), run_future)
+                    .await
+                    .unwrap_or_else(|_| Err(anyhow::anyhow!("timed out after {}s", secs))),
+                None => run_future.await,
+            };
 
+            let mut next = state::NextStep::Index(step_index + 1);
+            let mut skip_next = false;
             match result {
                 Ok(agent_result) => {
                     // Extract Copy fields before moving owned fields
                     let agent_success = agent_result.success;
                     let files_changed = agent_result.changed_files.len();
+                    let usage = agent_result.usage();
                     // Move owned fields instead of cloning
                     let agent_error = agent_result.error;
                     last_output = agent_result.output_text;
 
-                    let job_result = last_output
+                    let result_parser = self.config.result_parser_for(&step.skill);
+                    let mut job_result = last_output
                         .as_ref()
-                        .and_then(|text| crate::JobResult::parse(text));
+                        .and_then(|text| result_parser.parse(text));
+                    if let Some(ref mut jr) = job_result {
+                        jr.usage = usage;
+                    }
 
                     if let Some(ref jr) = job_result {
                         // Clone from reference - unavoidable as jr is borrowed
@@ -393,6 +413,48 @@ impl<'a> ChainRunner<'a> {
                         }
                     }
 
+                    let action = if step.on_state.is_empty() {
+                        None
+                    } else {
+                        let mut own_states = if !chain.states.is_empty() {
+                            state::detect_states(&chain.states, &last_output)
+                        } else {
+                            state::detect_states_from_skill(self.config, &skill, &last_output)
+                        };
+                        if let Some(s) = job_result.as_ref().and_then(|jr| jr.state.as_ref()) {
+                            if !own_states.contains(s) {
+                                own_states.insert(0, s.clone());
+                            }
+                        }
+                        state::step_action(step, &own_states)
+                    };
+                    let action = match action {
+                        Some(StepAction::Goto(target)) if executed_steps >= max_executed_steps => {
+                            let _ = event_tx
+                                .send(LogEvent::system(format!(
+                                    "Step cap ({}) reached, not jumping to '{}'",
+                                    max_executed_steps, target
+                                )))
+                                .await;
+                            None
+                        }
+                        action => action,
+                    };
+                    skip_next = matches!(action, Some(StepAction::Skip));
+                    next = match state::next_step(chain, step_index, action) {
+                        Ok(next) => next,
+                        Err(e) => {
+                            let _ = event_tx.send(LogEvent::error(e)).await;
+                            next
+                        }
+                    };
+                    let goto = match (action, next) {
+                        (Some(StepAction::Goto(_)), state::NextStep::Index(target_idx)) => {
+                            Some(Arc::from(chain.steps[target_idx].skill.as_str()))
+                        }
+                        _ => None,
+                    };
+
                     let step_result = ChainStepResult {
                         skill: Arc::clone(&skill),
                         step_index,
@@ -413,8 +475,19 @@ impl<'a> ChainRunner<'a> {
                             skill: Arc::clone(&skill),
                             is_starting: false,
                             step_result: Some(step_result.clone()),
+                            goto: goto.clone(),
                         });
                     }
+                    if let Some(target) = &goto {
+                        let _ = event_tx
+                            .send(LogEvent::system(format!(
+                                "Branching from step {} ({}) to '{}'",
+                                step_index + 1,
+                                &skill,
+                                target
+                            )))
+                            .await;
+                    }
 
                     step_results.push(step_result);
                     last_skill = Some(skill.to_string());
@@ -461,7 +534,41 @@ impl<'a> ChainRunner<'a> {
                 }
             }
 
-            step_index += 1;
+            match next {
+                state::NextStep::Stop => {
+                    let _ = event_tx
+                        .send(LogEvent::system(format!(
+                            "Chain stopped after step {} ({}) by on_state",
+                            step_index + 1,
+                            &skill
+                        )))
+                        .await;
+                    break;
+                }
+                state::NextStep::Index(next_index) => {
+                    // A skip action records the bypassed step like an unmet trigger
+                    if skip_next {
+                        if let Some(skipped) = chain.steps.get(step_index + 1) {
+                            let _ = event_tx
+                                .send(LogEvent::system(format!(
+                                    "Skipping step {} ({}) - on_state skip",
+                                    step_index + 2,
+                                    &skipped.skill
+                                )))
+                                .await;
+                            step_results.push(ChainStepResult {
+                                skill: Arc::from(skipped.skill.as_str()),
+                                step_index: step_index + 1,
+                                skipped: true,
+                                job_result: None,
+                                agent_result: None,
+                                full_response: None,
+                            });
+                        }
+                    }
+                    step_index = next_index;
+                }
+            }
         }
 
         let _ = event_tx
@@ -483,3 +590,135 @@ impl<'a> ChainRunner<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ScopeDefinition;
+    use crate::agent::{AgentResult, AgentRunner};
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+
+    /// Answers each skill with a canned output and records the order of runs
+    struct StubAgent {
+        outputs: HashMap<&'static str, &'static str>,
+        runs: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl AgentRunner for StubAgent {
+        async fn run(
+            &self,
+            job: &Job,
+            _worktree: &Path,
+            _config: &AgentConfig,
+            _event_tx: mpsc::Sender<LogEvent>,
+        ) -> Result<AgentResult> {
+            self.runs.lock().unwrap().push(job.skill.clone());
+            Ok(AgentResult {
+                success: true,
+                error: None,
+                changed_files: Vec::new(),
+                cost_usd: None,
+                input_tokens: None,
+                output_tokens: None,
+                cache_read_tokens: None,
+                cache_write_tokens: None,
+                duration_ms: None,
+                tool_calls: None,
+                sent_prompt: None,
+                output_text: self.outputs.get(job.skill.as_str()).map(|s| s.to_string()),
+                structured_output: None,
+                session_id: None,
+            })
+        }
+
+        fn id(&self) -> &str {
+            "claude"
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+    }
+
+    fn chain() -> ModeChain {
+        let toml = r#"
+            [[states]]
+            id = "needs_tests"
+            patterns = ["NEEDS TESTS"]
+
+            [[steps]]
+            skill = "review"
+            on_state = { needs_tests = { goto = "write-tests" } }
+
+            [[steps]]
+            skill = "fix"
+
+            [[steps]]
+            skill = "write-tests"
+        "#;
+        toml::from_str(toml).expect("chain")
+    }
+
+    /// Run `chain()` with `review` answering `review_output`; returns the skills that ran
+    fn run_with_review_output(review_output: &'static str) -> (ChainResult, Vec<String>) {
+        let stub = Arc::new(StubAgent {
+            outputs: HashMap::from([("review", review_output)]),
+            runs: Mutex::new(Vec::new()),
+        });
+        let mut adapters: HashMap<String, Arc<dyn AgentRunner>> = HashMap::new();
+        adapters.insert("claude".to_string(), stub.clone());
+        let registry = AgentRegistry::with_adapters(adapters);
+
+        let config = Config::default();
+        let work_dir = tempfile::tempdir().expect("tempdir");
+        let job = Job::new(
+            1,
+            "review".to_string(),
+            ScopeDefinition::file(PathBuf::from("src/lib.rs")),
+            "src/lib.rs".to_string(),
+            None,
+            "claude".to_string(),
+            PathBuf::from("src/lib.rs"),
+            1,
+            None,
+        );
+
+        let (event_tx, _event_rx) = mpsc::channel(256);
+        let runner = ChainRunner::new(&config, &registry, work_dir.path());
+        let result = tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(runner.run_chain("review-fix", &chain(), &job, event_tx, None));
+        let runs = stub.runs.lock().unwrap().clone();
+        (result, runs)
+    }
+
+    #[test]
+    fn branch_taken_runs_the_goto_target_next() {
+        let (result, runs) = run_with_review_output("Looks fine, but NEEDS TESTS");
+
+        assert!(result.success);
+        assert_eq!(runs, vec!["review", "write-tests"]);
+        let executed: Vec<&str> = result
+            .step_results
+            .iter()
+            .filter(|r| !r.skipped)
+            .map(|r| &*r.skill)
+            .collect();
+        assert_eq!(executed, vec!["review", "write-tests"]);
+    }
+
+    #[test]
+    fn branch_not_taken_runs_every_step_in_order() {
+        let (result, runs) = run_with_review_output("Looks fine");
+
+        assert!(result.success);
+        assert_eq!(runs, vec!["review", "fix", "write-tests"]);
+        assert_eq!(result.step_results.len(), 3);
+        assert!(result.step_results.iter().all(|r| !r.skipped));
+    }
+}
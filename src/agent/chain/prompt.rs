@@ -3,7 +3,7 @@
 //! This module handles constructing prompts with accumulated context
 //! and creating step jobs for chain execution.
 
-use crate::config::{ChainStep, Config};
+use crate::config::{ChainStep, Config, append_prompt_suffix};
 use crate::Job;
 
 /// Builds a prompt that includes context from previous chain steps.
@@ -13,6 +13,9 @@ use crate::Job;
 /// 2. The summary from the immediately previous step
 /// 3. Any custom `inject_context` from the step configuration
 /// 4. Chain history (summaries from all prior steps, for later steps)
+/// 5. The step skill's global prompt suffix (see [`Config::global_prompt_suffix_for`])
+///
+/// The step job is handed to the adapter as is, so the suffix has to be added here.
 ///
 /// # Arguments
 ///
@@ -39,7 +42,7 @@ pub fn build_chained_prompt(
         "project"
     };
 
-    let base_prompt = config.build_prompt(
+    let base_prompt = config.render_prompt(
         &step.skill,
         &initial_job.target,
         scope_type,
@@ -74,7 +77,7 @@ pub fn build_chained_prompt(
         }
     }
 
-    prompt
+    append_prompt_suffix(prompt, config.global_prompt_suffix_for(&step.skill))
 }
 
 /// Creates a job for a specific chain step.
@@ -5,7 +5,7 @@
 
 use regex::Regex;
 
-use crate::config::{ChainStep, Config, StateDefinition};
+use crate::config::{ChainStep, Config, ModeChain, StateDefinition, StepAction};
 
 /// Detects states from output text using the chain's state definitions.
 ///
@@ -169,3 +169,89 @@ pub fn should_step_run(step: &ChainStep, detected_states: &[String]) -> bool {
         true
     }
 }
+
+/// Picks the `on_state` action of a completed step.
+///
+/// Detected states are checked in order; the first one with an action wins.
+pub fn step_action<'s>(step: &'s ChainStep, detected_states: &[String]) -> Option<&'s StepAction> {
+    detected_states
+        .iter()
+        .find_map(|state| step.on_state.get(state))
+}
+
+/// Where a chain continues after a step has run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NextStep {
+    /// Continue with this step index (may be past the last step)
+    Index(usize),
+    /// End the chain
+    Stop,
+}
+
+/// Resolves the step following `step_index` given its `on_state` action.
+///
+/// Returns an error naming the target if a `goto` refers to a skill that is
+/// not a step of the chain; callers then continue sequentially.
+pub fn next_step(
+    chain: &ModeChain,
+    step_index: usize,
+    action: Option<&StepAction>,
+) -> Result<NextStep, String> {
+    match action {
+        None => Ok(NextStep::Index(step_index + 1)),
+        Some(StepAction::Stop) => Ok(NextStep::Stop),
+        Some(StepAction::Skip) => Ok(NextStep::Index(step_index + 2)),
+        Some(StepAction::Goto(target)) => chain
+            .steps
+            .iter()
+            .position(|s| &s.skill == target)
+            .map(NextStep::Index)
+            .ok_or_else(|| format!("goto target '{}' not found in chain", target)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain() -> ModeChain {
+        let toml = r#"
+            [[steps]]
+            skill = "review"
+            on_state = { needs_tests = { goto = "write-tests" }, done = "stop" }
+
+            [[steps]]
+            skill = "fix"
+
+            [[steps]]
+            skill = "write-tests"
+        "#;
+        toml::from_str(toml).expect("chain")
+    }
+
+    #[test]
+    fn branch_taken_jumps_to_goto_target() {
+        let chain = chain();
+        let detected = vec!["unrelated".to_string(), "needs_tests".to_string()];
+        let action = step_action(&chain.steps[0], &detected);
+        assert_eq!(action, Some(&StepAction::Goto("write-tests".to_string())));
+        assert_eq!(next_step(&chain, 0, action), Ok(NextStep::Index(2)));
+    }
+
+    #[test]
+    fn branch_not_taken_continues_or_stops() {
+        let chain = chain();
+        assert_eq!(step_action(&chain.steps[0], &[]), None);
+        assert_eq!(next_step(&chain, 0, None), Ok(NextStep::Index(1)));
+
+        let done = vec!["done".to_string()];
+        let action = step_action(&chain.steps[0], &done);
+        assert_eq!(next_step(&chain, 0, action), Ok(NextStep::Stop));
+
+        assert_eq!(
+            next_step(&chain, 0, Some(&StepAction::Skip)),
+            Ok(NextStep::Index(2))
+        );
+        assert!(next_step(&chain, 0, Some(&StepAction::Goto("nope".to_string()))).is_err());
+    }
+}
@@ -81,4 +81,36 @@ pub struct ChainProgressEvent {
     pub is_starting: bool,
     /// Step result (only present when is_starting is false)
     pub step_result: Option<ChainStepResult>,
+    /// Skill the chain jumps to next because of an `on_state` goto
+    /// (only present when is_starting is false)
+    pub goto: Option<Arc<str>>,
+}
+
+impl ChainResult {
+    /// JSON view of the chain result (used by `kyco chain run`).
+    pub fn to_json(&self) -> serde_json::Value {
+        let steps: Vec<serde_json::Value> = self
+            .step_results
+            .iter()
+            .map(|step| {
+                serde_json::json!({
+                    "skill": step.skill.as_ref(),
+                    "step_index": step.step_index,
+                    "skipped": step.skipped,
+                    "success": step.agent_result.as_ref().map(|r| r.success),
+                    "error": step.agent_result.as_ref().and_then(|r| r.error.clone()),
+                    "files_changed": step.agent_result.as_ref().map(|r| r.files_changed),
+                    "result": step.job_result,
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "chain_name": self.chain_name,
+            "success": self.success,
+            "final_state": self.final_state,
+            "accumulated_summaries": self.accumulated_summaries,
+            "steps": steps,
+        })
+    }
 }
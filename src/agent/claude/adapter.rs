@@ -40,7 +40,7 @@ impl ClaudeAdapter {
             .replace("{target}", &job.target)
             .replace("{mode}", &job.skill)
             .replace("{description}", description)
-            .replace("{scope_type}", "file")
+            .replace("{scope_type}", job.scope_type())
             .replace("{ide_context}", ide_context)
     }
 
@@ -213,7 +213,7 @@ impl AgentRunner for ClaudeAdapter {
             .current_dir(worktree)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
-            .envs(&config.env)
+            .envs(config.env_for_job(&job.env))
             .spawn()
             .with_context(|| format!("Failed to spawn {}", binary))?;
 
@@ -250,6 +250,7 @@ impl AgentRunner for ClaudeAdapter {
             cache_read_tokens: None,
             cache_write_tokens: None,
             duration_ms: None,
+            tool_calls: None,
             sent_prompt: Some(prompt.clone()),
             output_text: None,
             structured_output: None,
@@ -296,6 +297,7 @@ impl AgentRunner for ClaudeAdapter {
                                     events.push(LogEvent::text(text.clone()));
                                 }
                                 ContentBlock::ToolUse { name, input, .. } => {
+                                    *result.tool_calls.get_or_insert(0) += 1;
                                     let summary = format_tool_call(name, input);
                                     events.push(LogEvent::tool_call(name.clone(), summary));
                                 }
@@ -47,7 +47,7 @@ impl CodexAdapter {
             .replace("{target}", &job.target)
             .replace("{mode}", &job.skill)
             .replace("{description}", description)
-            .replace("{scope_type}", "file")
+            .replace("{scope_type}", job.scope_type())
             .replace("{ide_context}", ide_context);
 
         let mut prompt = String::new();
@@ -207,7 +207,7 @@ impl AgentRunner for CodexAdapter {
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
-            .envs(&config.env)
+            .envs(config.env_for_job(&job.env))
             .spawn()
             .with_context(|| format!("Failed to spawn {}", binary))?;
 
@@ -247,6 +247,7 @@ impl AgentRunner for CodexAdapter {
             cache_read_tokens: None,
             cache_write_tokens: None,
             duration_ms: None,
+            tool_calls: None,
             sent_prompt: Some(prompt.clone()),
             output_text: None,
             structured_output: None,
@@ -0,0 +1,381 @@
+//! Generic subprocess adapter for user-defined agents (`sdk = "custom"`)
+//!
+//! The agent's `command_template` is run through `sh -c` in the job's worktree.
+//! The prompt is written to stdin and to a temporary file (never put in argv or the
+//! environment, so large prompts can't hit `E2BIG`). `{prompt_file}`, `{file}` and
+//! `{cwd}` are replaced with shell-quoted values, and the same values are exported
+//! as `KYCO_PROMPT_FILE`, `KYCO_FILE` and `KYCO_CWD`. Stdout is captured as the job
+//! output, so a trailing `---kyco` block is parsed into a `JobResult` like for any
+//! other agent.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::io::Write;
+use std::path::Path;
+use std::process::Stdio;
+use tempfile::NamedTempFile;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::Command;
+use tokio::sync::mpsc;
+
+use super::runner::{AgentResult, AgentRunner};
+use crate::agent::process_registry;
+use crate::{AgentConfig, Job, LogEvent};
+
+/// Adapter that runs an arbitrary CLI agent from a command template
+pub struct CustomCommandAdapter {
+    id: String,
+    command_template: String,
+}
+
+impl CustomCommandAdapter {
+    /// Create an adapter for agent `id` running `command_template`
+    pub fn new(id: impl Into<String>, command_template: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            command_template: command_template.into(),
+        }
+    }
+
+    /// Build the prompt for a job using the skill template from config
+    pub(crate) fn build_prompt(&self, job: &Job, config: &AgentConfig) -> String {
+        let template = config.get_skill_template(&job.skill);
+        let description = job.description.as_deref().unwrap_or("");
+        let ide_context = job.ide_context.as_deref().unwrap_or("");
+        let task = template
+            .prompt_template
+            .replace("{file}", &job.source_file.display().to_string())
+            .replace("{line}", &job.source_line.to_string())
+            .replace("{target}", &job.target)
+            .replace("{mode}", &job.skill)
+            .replace("{description}", description)
+            .replace("{scope_type}", job.scope_type())
+            .replace("{ide_context}", ide_context);
+
+        let mut system_prompt = template.system_prompt.unwrap_or_default();
+        if job.git_worktree_path.is_some() {
+            system_prompt.push_str(
+                "\n\nIMPORTANT: You are working in an isolated Git worktree. When you have \
+                 completed the task, commit all your changes with a descriptive commit message. \
+                 Do NOT push.",
+            );
+        }
+        if let Some(schema) = &config.output_schema {
+            if !schema.trim().is_empty() {
+                system_prompt.push_str("\n\n");
+                system_prompt.push_str(schema.trim());
+            }
+        }
+
+        let mut prompt = String::new();
+        if !system_prompt.trim().is_empty() {
+            prompt.push_str("## System Instructions\n\n");
+            prompt.push_str(system_prompt.trim());
+            prompt.push_str("\n\n");
+        }
+
+        // KYCo cannot enforce tool restrictions on an opaque process; state them instead
+        if !config.disallowed_tools.is_empty() {
+            prompt.push_str("## Restrictions\n\n");
+            prompt.push_str(&format!(
+                "Do not use these tools: {}.\n",
+                config.disallowed_tools.join(", ")
+            ));
+            if blocks_writes(config) {
+                prompt.push_str("Do not modify any files.\n");
+            }
+            prompt.push('\n');
+        }
+
+        prompt.push_str("## Task\n\n");
+        prompt.push_str(&task);
+        prompt
+    }
+
+    /// Substitute `{prompt_file}`, `{file}` and `{cwd}` in one pass (values are never
+    /// re-expanded)
+    fn render_command(&self, prompt_file: &str, file: &str, cwd: &str) -> String {
+        let mut out = String::with_capacity(self.command_template.len());
+        let mut rest = self.command_template.as_str();
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            let after = &rest[start..];
+            let value = [
+                ("{prompt_file}", prompt_file),
+                ("{file}", file),
+                ("{cwd}", cwd),
+            ]
+                .into_iter()
+                .find(|(placeholder, _)| after.starts_with(placeholder));
+            match value {
+                Some((placeholder, value)) => {
+                    out.push_str(&shell_quote(value));
+                    rest = &after[placeholder.len()..];
+                }
+                None => {
+                    out.push('{');
+                    rest = &after[1..];
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+fn blocks_writes(config: &AgentConfig) -> bool {
+    config
+        .disallowed_tools
+        .iter()
+        .any(|t| t == "Write" || t == "Edit")
+}
+
+/// Quote `s` as a single POSIX shell word
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+/// Leading `VAR=value` words of a command line set the environment, not the program
+fn is_env_assignment(word: &str) -> bool {
+    word.split_once('=').is_some_and(|(name, _)| {
+        name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+/// Write the job's prompt to a fresh temporary file, removed when it is dropped
+fn write_prompt_file(job_id: u64, prompt: &str) -> Result<NamedTempFile> {
+    let mut file = tempfile::Builder::new()
+        .prefix(&format!("kyco_job_{}_", job_id))
+        .suffix(".prompt.md")
+        .tempfile()
+        .context("Failed to create prompt file")?;
+    file.write_all(prompt.as_bytes())
+        .and_then(|()| file.flush())
+        .with_context(|| format!("Failed to write prompt file {}", file.path().display()))?;
+    Ok(file)
+}
+
+#[async_trait]
+impl AgentRunner for CustomCommandAdapter {
+    async fn run(
+        &self,
+        job: &Job,
+        worktree: &Path,
+        config: &AgentConfig,
+        event_tx: mpsc::Sender<LogEvent>,
+    ) -> Result<AgentResult> {
+        struct ProcessGuard(Option<u64>);
+        impl Drop for ProcessGuard {
+            fn drop(&mut self) {
+                if let Some(job_id) = self.0 {
+                    process_registry::unregister(job_id);
+                }
+            }
+        }
+
+        let job_id = job.id;
+        let started = std::time::Instant::now();
+        let prompt = self.build_prompt(job, config);
+        let prompt_file = write_prompt_file(job_id, &prompt)?;
+        let prompt_path = prompt_file.path().display().to_string();
+        let file = job.source_file.display().to_string();
+        let cwd = worktree.display().to_string();
+        let command = self.render_command(&prompt_path, &file, &cwd);
+
+        let _ = event_tx
+            .send(
+                LogEvent::system(format!("Running custom agent: {}", self.command_template))
+                    .for_job(job_id),
+            )
+            .await;
+
+        let mut child = Command::new("sh")
+            .arg("-c")
+            .arg(&command)
+            .current_dir(worktree)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            // The executor drops this future on timeout; don't leave the agent running
+            .kill_on_drop(true)
+            .envs(config.env_for_job(&job.env))
+            .env("KYCO_PROMPT_FILE", &prompt_path)
+            .env("KYCO_FILE", &file)
+            .env("KYCO_CWD", &cwd)
+            .env("KYCO_JOB_ID", job_id.to_string())
+            .env("KYCO_DISALLOWED_TOOLS", config.disallowed_tools.join(","))
+            .env(
+                "KYCO_READ_ONLY",
+                if blocks_writes(config) { "1" } else { "0" },
+            )
+            .spawn()
+            .with_context(|| format!("Failed to spawn custom agent '{}'", self.id))?;
+
+        let _process_guard = ProcessGuard(child.id().map(|pid| {
+            process_registry::register(job_id, pid, self.id());
+            job_id
+        }));
+
+        // The prompt is also offered on stdin; agents that don't read it just close the pipe
+        if let Some(mut stdin) = child.stdin.take() {
+            let prompt = prompt.clone();
+            tokio::spawn(async move {
+                let _ = stdin.write_all(prompt.as_bytes()).await;
+            });
+        }
+
+        let stdout = child
+            .stdout
+            .take()
+            .context("Failed to capture stdout pipe")?;
+        let stderr = child
+            .stderr
+            .take()
+            .context("Failed to capture stderr pipe")?;
+
+        let event_tx_clone = event_tx.clone();
+        let stderr_task = tokio::spawn(async move {
+            let mut stderr_reader = BufReader::new(stderr).lines();
+            while let Ok(Some(line)) = stderr_reader.next_line().await {
+                let _ = event_tx_clone
+                    .send(LogEvent::error(format!("stderr: {}", line)).for_job(job_id))
+                    .await;
+            }
+        });
+
+        let mut output_text = String::new();
+        let mut reader = BufReader::new(stdout).lines();
+        while let Ok(Some(line)) = reader.next_line().await {
+            if !line.trim().is_empty() {
+                let _ = event_tx.send(LogEvent::text(&line).for_job(job_id)).await;
+            }
+            output_text.push_str(&line);
+            output_text.push('\n');
+        }
+
+        let status = child.wait().await?;
+        let _ = stderr_task.await;
+
+        let error = if status.success() {
+            let _ = event_tx
+                .send(LogEvent::system(format!("Job #{} completed", job_id)).for_job(job_id))
+                .await;
+            None
+        } else {
+            let _ = event_tx
+                .send(
+                    LogEvent::error(format!("Job #{} failed: {}", job_id, status)).for_job(job_id),
+                )
+                .await;
+            Some(format!("Process exited with status: {}", status))
+        };
+
+        Ok(AgentResult {
+            success: error.is_none(),
+            error,
+            changed_files: Vec::new(),
+            cost_usd: None,
+            input_tokens: None,
+            output_tokens: None,
+            cache_read_tokens: None,
+            cache_write_tokens: None,
+            duration_ms: Some(started.elapsed().as_millis() as u64),
+            tool_calls: None,
+            sent_prompt: Some(prompt),
+            output_text: Some(output_text).filter(|text| !text.trim().is_empty()),
+            structured_output: None,
+            session_id: None,
+        })
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn is_available(&self) -> bool {
+        let Some(program) = self
+            .command_template
+            .split_whitespace()
+            .find(|word| !is_env_assignment(word))
+        else {
+            return false;
+        };
+        std::process::Command::new("which")
+            .arg(program)
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support;
+
+    fn job() -> Job {
+        Job {
+            skill: "review".to_string(),
+            description: Some("check it".to_string()),
+            agent_id: "mine".to_string(),
+            ..test_support::job(3)
+        }
+    }
+
+    #[test]
+    fn render_quotes_values_without_reexpanding_them() {
+        let adapter =
+            CustomCommandAdapter::new("mine", "agent --cwd {cwd} -f {prompt_file} {other}");
+        let command = adapter.render_command("it's {file}", "a.rs", "/tmp/w t");
+        assert_eq!(
+            command,
+            "agent --cwd '/tmp/w t' -f 'it'\\''s {file}' {other}"
+        );
+    }
+
+    #[test]
+    fn availability_skips_leading_env_assignments() {
+        assert!(is_env_assignment("FOO=1"));
+        assert!(is_env_assignment("_X2="));
+        assert!(!is_env_assignment("--opt=1"));
+        assert!(!is_env_assignment("agent"));
+
+        let adapter = CustomCommandAdapter::new("mine", "FOO=1 BAR=x sh -c true");
+        assert!(adapter.is_available());
+        let adapter = CustomCommandAdapter::new("mine", "FOO=1 kyco-no-such-agent {prompt_file}");
+        assert!(!adapter.is_available());
+    }
+
+    #[test]
+    fn disallowed_tools_are_stated_in_the_prompt() {
+        let adapter = CustomCommandAdapter::new("mine", "agent {prompt_file}");
+        let mut config = AgentConfig::default();
+        config.disallowed_tools = vec!["Write".to_string(), "Bash".to_string()];
+        let prompt = adapter.build_prompt(&job(), &config);
+        assert!(prompt.contains("Do not use these tools: Write, Bash."));
+        assert!(prompt.contains("Do not modify any files."));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn stdout_becomes_the_job_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let adapter = CustomCommandAdapter::new(
+            "mine",
+            "cmp -s {prompt_file} - && test \"$KYCO_FILE\" = {file} && \
+             printf 'working\\n---kyco\\nstatus: done\\n---\\n'",
+        );
+        let (tx, _rx) = mpsc::channel(64);
+        let result = tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(adapter.run(&job(), dir.path(), &AgentConfig::default(), tx))
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(
+            result.output_text.as_deref(),
+            Some("working\n---kyco\nstatus: done\n---\n")
+        );
+    }
+}
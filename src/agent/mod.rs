@@ -13,6 +13,7 @@
 //! - **CLI Adapters** - Backend-specific implementations:
 //!   - [`ClaudeAdapter`] - Claude Code CLI
 //!   - [`CodexAdapter`] - Codex CLI
+//!   - [`CustomCommandAdapter`] - any CLI agent, from an `sdk = "custom"` command template
 //! - **Bridge Adapters (optional)** - SDK-style session control:
 //!   - [`ClaudeBridgeAdapter`]
 //!   - [`CodexBridgeAdapter`]
@@ -35,8 +36,10 @@
 
 pub mod bridge;
 mod chain;
+mod custom;
 pub mod process_registry;
 mod registry;
+pub mod retry;
 mod runner;
 
 mod claude;
@@ -44,9 +47,13 @@ mod codex;
 mod terminal;
 
 pub use bridge::{BridgeClient, BridgeProcess, ClaudeBridgeAdapter, CodexBridgeAdapter};
-pub use chain::{ChainProgressEvent, ChainResult, ChainRunner, ChainStepResult};
+pub use chain::{
+    build_chained_prompt, create_step_job, render_chain_diagram, ChainDiagramFormat,
+    ChainProgressEvent, ChainResult, ChainRunner, ChainStepResult,
+};
+pub use custom::CustomCommandAdapter;
 pub use registry::{AgentRegistry, DEFAULT_TERMINAL_SUFFIX};
-pub use runner::{AgentResult, AgentRunner};
+pub use runner::{run_or_resume, AgentResult, AgentRunner};
 
 pub use claude::{ClaudeAdapter, StreamEvent};
 pub use codex::CodexAdapter;
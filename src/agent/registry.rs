@@ -8,6 +8,7 @@ use std::sync::Arc;
 use crate::{AgentConfig, SdkType};
 
 use super::bridge::{ClaudeBridgeAdapter, CodexBridgeAdapter};
+use super::custom::CustomCommandAdapter;
 use super::runner::AgentRunner;
 
 /// Central registry for managing agent adapters.
@@ -57,14 +58,26 @@ impl AgentRegistry {
 
     /// Retrieves an adapter appropriate for the given agent configuration.
     ///
-    /// Tries by ID first, then falls back to SDK type.
+    /// Tries by ID first, then builds a [`CustomCommandAdapter`] for `sdk = "custom"`
+    /// agents with a `command_template`, then falls back to SDK type.
     pub fn get_for_config(&self, config: &AgentConfig) -> Option<Arc<dyn AgentRunner>> {
         if let Some(adapter) = self.get(&config.id) {
             return Some(adapter);
         }
+        if let Some(template) = Self::custom_template(config) {
+            return Some(Arc::new(CustomCommandAdapter::new(&config.id, template)));
+        }
         self.get_for_sdk_type(config.sdk_type)
     }
 
+    fn custom_template(config: &AgentConfig) -> Option<&str> {
+        config
+            .command_template
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| config.sdk_type == SdkType::Custom && !t.is_empty())
+    }
+
     /// Lists all available adapters.
     ///
     /// Availability is determined by each adapter (e.g., CLI binary present).
@@ -106,7 +119,7 @@ impl AgentRegistry {
         self.get_for_sdk_type(cli_type)
     }
 
-    /// Legacy: Register adapter (for backwards compatibility)
+    /// Registers (or replaces) an adapter under its own ID.
     pub fn register(&mut self, adapter: Arc<dyn AgentRunner>) {
         self.adapters.insert(adapter.id().to_string(), adapter);
     }
@@ -161,4 +174,18 @@ mod tests {
         assert!(all.contains(&"claude"));
         assert!(all.contains(&"codex"));
     }
+
+    #[test]
+    fn custom_agent_with_template_gets_a_subprocess_adapter() {
+        let mut config = AgentConfig::default();
+        config.id = "mine".to_string();
+        config.sdk_type = SdkType::Custom;
+
+        let registry = AgentRegistry::new();
+        // No template: legacy fallback to Claude
+        assert_eq!(registry.get_for_config(&config).unwrap().id(), "claude");
+
+        config.command_template = Some("my-agent -f {prompt_file}".to_string());
+        assert_eq!(registry.get_for_config(&config).unwrap().id(), "mine");
+    }
 }
@@ -0,0 +1,157 @@
+//! Retry classification for agent errors, shared by the bridge adapters.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static RETRY_AFTER_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)retry[- ]after\s*[:=]?\s*(\d+)\s*(s|sec|secs|second|seconds|m|min|mins|minute|minutes)?",
+    )
+    .expect("valid retry-after regex")
+});
+
+/// How an adapter should react to an error reported by the SDK
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// Rate limited (HTTP 429): back off per the agent's `RetryPolicy`, then retry
+    RateLimited,
+    /// Dropped connection or similar: retry (continuing the session if possible)
+    Transient,
+    /// Anything else: fail the job immediately
+    Fatal,
+}
+
+/// Classify an SDK error by its optional error `code` and message.
+pub fn classify_error(code: Option<&str>, message: &str) -> ErrorClass {
+    if is_rate_limited(code, message) {
+        ErrorClass::RateLimited
+    } else if is_transient(message) {
+        ErrorClass::Transient
+    } else {
+        ErrorClass::Fatal
+    }
+}
+
+fn is_rate_limited(code: Option<&str>, message: &str) -> bool {
+    if code.is_some_and(|c| c.eq_ignore_ascii_case("429") || c.eq_ignore_ascii_case("rate_limit")) {
+        return true;
+    }
+    if message.contains("429") {
+        return true;
+    }
+    let msg = message.to_ascii_lowercase();
+    msg.contains("rate limit") || msg.contains("too many requests")
+}
+
+fn is_transient(message: &str) -> bool {
+    let msg = message.to_ascii_lowercase();
+    msg.contains("reconnect")
+        || msg.contains("connection")
+        || msg.contains("network")
+        || msg.contains("timeout")
+        || msg.contains("reset")
+        || msg.contains("econnreset")
+        || msg.contains("epipe")
+        || msg.contains("socket")
+        || msg.contains("closed")
+        || msg.contains("failed to start")
+        || msg.contains("bridge")
+        || msg.contains("refused")
+        || msg.contains("disconnected")
+}
+
+/// Extract a `Retry-After` hint (seconds or minutes) from an error message.
+pub fn parse_retry_after_ms(message: &str) -> Option<u64> {
+    let caps = RETRY_AFTER_RE.captures(message)?;
+    let value: u64 = caps.get(1)?.as_str().parse().ok()?;
+    let unit = caps.get(2).map(|m| m.as_str().to_ascii_lowercase());
+    match unit.as_deref() {
+        None | Some("s" | "sec" | "secs" | "second" | "seconds") => {
+            Some(value.saturating_mul(1000))
+        }
+        Some("m" | "min" | "mins" | "minute" | "minutes") => Some(value.saturating_mul(60_000)),
+        Some(_) => None,
+    }
+}
+
+/// Add up to 10% (max 1s) of random jitter so parallel jobs don't retry in lockstep.
+pub fn add_jitter_ms(delay_ms: u64) -> u64 {
+    let max_jitter = (delay_ms / 10).min(1_000);
+    if max_jitter == 0 {
+        return delay_ms;
+    }
+    let mut buf = [0u8; 8];
+    if getrandom::getrandom(&mut buf).is_ok() {
+        let r = u64::from_le_bytes(buf);
+        delay_ms.saturating_add(r % (max_jitter + 1))
+    } else {
+        delay_ms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_rate_limits_by_code() {
+        assert_eq!(
+            classify_error(Some("429"), "anything"),
+            ErrorClass::RateLimited
+        );
+        assert_eq!(
+            classify_error(Some("rate_limit"), "anything"),
+            ErrorClass::RateLimited
+        );
+        assert_eq!(
+            classify_error(Some("RATE_LIMIT"), "anything"),
+            ErrorClass::RateLimited
+        );
+    }
+
+    #[test]
+    fn classifies_rate_limits_by_message() {
+        for msg in [
+            "HTTP 429 Too Many Requests",
+            "Rate limit exceeded",
+            "Too many requests",
+        ] {
+            assert_eq!(classify_error(None, msg), ErrorClass::RateLimited, "{msg}");
+        }
+        // A rate limit wins over connection-ish wording
+        assert_eq!(
+            classify_error(None, "connection throttled: 429"),
+            ErrorClass::RateLimited
+        );
+    }
+
+    #[test]
+    fn classifies_transient_and_fatal_errors() {
+        // "Reconnecting... 1/5" is the actual message from Codex SDK
+        for msg in [
+            "Reconnecting... 1/5",
+            "Connection lost",
+            "Network error",
+            "timeout",
+            "ECONNRESET",
+            "socket closed",
+        ] {
+            assert_eq!(classify_error(None, msg), ErrorClass::Transient, "{msg}");
+        }
+        for msg in ["Invalid API key", "File not found"] {
+            assert_eq!(classify_error(None, msg), ErrorClass::Fatal, "{msg}");
+        }
+        assert_eq!(
+            classify_error(Some("invalid_request"), "bad schema"),
+            ErrorClass::Fatal
+        );
+    }
+
+    #[test]
+    fn parses_retry_after_ms() {
+        assert_eq!(parse_retry_after_ms("Retry-After: 5"), Some(5_000));
+        assert_eq!(parse_retry_after_ms("retry after 2s"), Some(2_000));
+        assert_eq!(parse_retry_after_ms("retry-after=3 mins"), Some(180_000));
+        assert_eq!(parse_retry_after_ms("no hint here"), None);
+    }
+}
@@ -29,7 +29,7 @@ use async_trait::async_trait;
 use std::path::Path;
 use tokio::sync::mpsc;
 
-use crate::{AgentConfig, Job, LogEvent};
+use crate::{AgentConfig, Job, LogEvent, UsageStats};
 
 /// Result of an agent execution.
 ///
@@ -76,6 +76,9 @@ pub struct AgentResult {
     /// Wall-clock time from agent start to completion.
     pub duration_ms: Option<u64>,
 
+    /// Number of tool calls the agent made (if tracked by the adapter).
+    pub tool_calls: Option<u64>,
+
     /// The prompt that was sent to the model.
     ///
     /// Useful for debugging and logging the exact instructions given to the agent.
@@ -100,6 +103,29 @@ pub struct AgentResult {
     pub session_id: Option<String>,
 }
 
+impl AgentResult {
+    /// Token/cost usage reported by the agent, or `None` if nothing was reported.
+    pub fn usage(&self) -> Option<UsageStats> {
+        if self.input_tokens.is_none()
+            && self.output_tokens.is_none()
+            && self.cache_read_tokens.is_none()
+            && self.cost_usd.is_none()
+            && self.duration_ms.is_none()
+            && self.tool_calls.is_none()
+        {
+            return None;
+        }
+        Some(UsageStats {
+            input_tokens: self.input_tokens.unwrap_or(0),
+            output_tokens: self.output_tokens.unwrap_or(0),
+            cached_tokens: self.cache_read_tokens.unwrap_or(0),
+            cost_usd: self.cost_usd,
+            duration_ms: self.duration_ms,
+            tool_calls: self.tool_calls,
+        })
+    }
+}
+
 /// Trait for agent adapters.
 ///
 /// Implement this trait to add support for a new AI coding agent. Each adapter
@@ -147,6 +173,28 @@ pub trait AgentRunner: Send + Sync {
         event_tx: mpsc::Sender<LogEvent>,
     ) -> Result<AgentResult>;
 
+    /// Reattach to an existing agent session and let it continue.
+    ///
+    /// Used by `kyco job resume` after KYCo died mid-run: instead of replaying the
+    /// original prompt, the agent is told to pick up the conversation identified by
+    /// `session_id`.
+    ///
+    /// # Errors
+    ///
+    /// The default implementation returns an error; agents that cannot resume a
+    /// session (e.g. Codex) keep it.
+    async fn resume(
+        &self,
+        job: &Job,
+        session_id: &str,
+        worktree: &Path,
+        config: &AgentConfig,
+        event_tx: mpsc::Sender<LogEvent>,
+    ) -> Result<AgentResult> {
+        let _ = (job, session_id, worktree, config, event_tx);
+        anyhow::bail!("Agent '{}' does not support resuming sessions", self.id())
+    }
+
     /// Get the unique identifier for this agent.
     ///
     /// Returns a string like `"claude"` or `"codex"` that matches
@@ -160,3 +208,168 @@ pub trait AgentRunner: Send + Sync {
     /// with unavailable agents.
     fn is_available(&self) -> bool;
 }
+
+/// Run a job, or resume its stored session when a resume was requested.
+///
+/// Jobs re-queued by `kyco job resume` carry `resume_requested` and the agent
+/// session id captured before the crash; everything else starts fresh.
+pub async fn run_or_resume(
+    adapter: &dyn AgentRunner,
+    job: &Job,
+    worktree: &Path,
+    config: &AgentConfig,
+    event_tx: mpsc::Sender<LogEvent>,
+) -> Result<AgentResult> {
+    match job.bridge_session_id.as_deref().filter(|_| job.resume_requested) {
+        Some(session_id) => {
+            adapter
+                .resume(job, session_id, worktree, config, event_tx)
+                .await
+        }
+        None => adapter.run(job, worktree, config, event_tx).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ScopeDefinition;
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+
+    /// Records which entry point was used and with which session id.
+    #[derive(Default)]
+    struct MockAdapter {
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl MockAdapter {
+        fn result() -> AgentResult {
+            AgentResult {
+                success: true,
+                error: None,
+                changed_files: Vec::new(),
+                cost_usd: None,
+                input_tokens: None,
+                output_tokens: None,
+                cache_read_tokens: None,
+                cache_write_tokens: None,
+                duration_ms: None,
+                tool_calls: None,
+                sent_prompt: None,
+                output_text: None,
+                structured_output: None,
+                session_id: None,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl AgentRunner for MockAdapter {
+        async fn run(
+            &self,
+            _job: &Job,
+            _worktree: &Path,
+            _config: &AgentConfig,
+            _event_tx: mpsc::Sender<LogEvent>,
+        ) -> Result<AgentResult> {
+            self.calls.lock().unwrap().push("run".to_string());
+            Ok(Self::result())
+        }
+
+        async fn resume(
+            &self,
+            _job: &Job,
+            session_id: &str,
+            _worktree: &Path,
+            _config: &AgentConfig,
+            _event_tx: mpsc::Sender<LogEvent>,
+        ) -> Result<AgentResult> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("resume:{}", session_id));
+            Ok(Self::result())
+        }
+
+        fn id(&self) -> &str {
+            "claude"
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+    }
+
+    /// Adapter that keeps the trait's default `resume`.
+    struct RunOnlyAdapter;
+
+    #[async_trait]
+    impl AgentRunner for RunOnlyAdapter {
+        async fn run(
+            &self,
+            _job: &Job,
+            _worktree: &Path,
+            _config: &AgentConfig,
+            _event_tx: mpsc::Sender<LogEvent>,
+        ) -> Result<AgentResult> {
+            Ok(MockAdapter::result())
+        }
+
+        fn id(&self) -> &str {
+            "codex"
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+    }
+
+    fn job_with_session(resume_requested: bool) -> Job {
+        let mut job = Job::new(
+            7,
+            "fix".to_string(),
+            ScopeDefinition::file(PathBuf::from("src/lib.rs")),
+            "src/lib.rs".to_string(),
+            None,
+            "claude".to_string(),
+            PathBuf::from("src/lib.rs"),
+            1,
+            None,
+        );
+        job.bridge_session_id = Some("sess-123".to_string());
+        job.resume_requested = resume_requested;
+        job
+    }
+
+    fn invoke(adapter: &dyn AgentRunner, job: &Job) -> Result<AgentResult> {
+        let (tx, _rx) = mpsc::channel(8);
+        futures::executor::block_on(run_or_resume(
+            adapter,
+            job,
+            Path::new("."),
+            &AgentConfig::default(),
+            tx,
+        ))
+    }
+
+    #[test]
+    fn resume_requested_job_resumes_stored_session() {
+        let adapter = MockAdapter::default();
+        invoke(&adapter, &job_with_session(true)).unwrap();
+        assert_eq!(*adapter.calls.lock().unwrap(), vec!["resume:sess-123"]);
+    }
+
+    #[test]
+    fn stored_session_without_resume_request_runs_normally() {
+        let adapter = MockAdapter::default();
+        invoke(&adapter, &job_with_session(false)).unwrap();
+        assert_eq!(*adapter.calls.lock().unwrap(), vec!["run"]);
+    }
+
+    #[test]
+    fn default_resume_is_unsupported() {
+        let err = invoke(&RunOnlyAdapter, &job_with_session(true)).unwrap_err();
+        assert!(err.to_string().contains("does not support resuming"));
+    }
+}
@@ -82,7 +82,7 @@ impl TerminalAdapter {
             .replace("{target}", &job.target)
             .replace("{mode}", &job.skill)
             .replace("{description}", description)
-            .replace("{scope_type}", "file")
+            .replace("{scope_type}", job.scope_type())
             .replace("{ide_context}", ide_context)
     }
 
@@ -230,6 +230,7 @@ impl AgentRunner for TerminalAdapter {
             cache_read_tokens: None,
             cache_write_tokens: None,
             duration_ms: None,
+            tool_calls: None,
             sent_prompt: Some(prompt.clone()),
             output_text: None,
             structured_output: None,
@@ -0,0 +1,454 @@
+//! Whole-project JSON bundles for backup and sharing
+//!
+//! A [`ProjectBundle`] carries a project together with its findings, artifacts,
+//! flow edges, active memory and jobs. Importing a bundle recreates everything
+//! under a (possibly new) project id, remapping ids that already exist in the
+//! target database.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::{Artifact, BugBountyJob, BugBountyManager, Finding, FlowEdge, Project, ProjectMemory};
+
+/// Current bundle format version
+pub const PROJECT_BUNDLE_VERSION: u32 = 1;
+
+/// A job <-> finding link as stored in a bundle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleJobLink {
+    pub job_id: String,
+    pub finding_id: String,
+    pub link_type: String,
+}
+
+/// Serializable snapshot of one project and everything that belongs to it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectBundle {
+    pub version: u32,
+    pub exported_at: i64,
+    pub project: Project,
+    #[serde(default)]
+    pub findings: Vec<Finding>,
+    #[serde(default)]
+    pub artifacts: Vec<Artifact>,
+    #[serde(default)]
+    pub flow_edges: Vec<FlowEdge>,
+    #[serde(default)]
+    pub memory: Vec<ProjectMemory>,
+    #[serde(default)]
+    pub jobs: Vec<BugBountyJob>,
+    #[serde(default)]
+    pub job_links: Vec<BundleJobLink>,
+}
+
+/// Options for [`BugBountyManager::import_project`]
+#[derive(Debug, Clone, Default)]
+pub struct BundleImportOptions {
+    /// Import under this project id instead of the one stored in the bundle
+    pub project_id: Option<String>,
+}
+
+/// What [`BugBountyManager::import_project`] created
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BundleImportResult {
+    pub project_id: String,
+    pub findings: usize,
+    pub artifacts: usize,
+    pub flow_edges: usize,
+    pub memory: usize,
+    pub jobs: usize,
+    /// Findings, jobs or artifacts whose id was changed to avoid a collision
+    pub remapped_ids: usize,
+}
+
+/// Rows created so far by an import, so a failed import can be undone
+#[derive(Default)]
+struct CreatedRows {
+    project_id: Option<String>,
+    jobs: Vec<String>,
+    artifacts: Vec<String>,
+}
+
+impl BugBountyManager {
+    /// Snapshot a project and all of its data into a [`ProjectBundle`]
+    pub fn export_project(&self, project_id: &str) -> Result<ProjectBundle> {
+        let project = self
+            .get_project(project_id)?
+            .ok_or_else(|| anyhow::anyhow!("Project not found: {}", project_id))?;
+
+        let findings = self.list_findings_by_project(project_id)?;
+        let jobs = self.jobs().list_by_project(project_id)?;
+
+        let mut artifacts = Vec::new();
+        let mut flow_edges = Vec::new();
+        let mut job_links = Vec::new();
+        let mut seen_artifacts = HashSet::new();
+        for finding in &findings {
+            for artifact in self.list_artifacts_by_finding(&finding.id)? {
+                if seen_artifacts.insert(artifact.id.clone()) {
+                    artifacts.push(artifact);
+                }
+            }
+            flow_edges.extend(self.get_flow_trace(&finding.id)?.edges);
+            for (job_id, link_type) in self.job_findings().list_links_for_finding(&finding.id)? {
+                job_links.push(BundleJobLink {
+                    job_id,
+                    finding_id: finding.id.clone(),
+                    link_type,
+                });
+            }
+        }
+        for job in &jobs {
+            for artifact in self.artifacts().list_by_job(&job.id)? {
+                if seen_artifacts.insert(artifact.id.clone()) {
+                    artifacts.push(artifact);
+                }
+            }
+        }
+
+        Ok(ProjectBundle {
+            version: PROJECT_BUNDLE_VERSION,
+            exported_at: chrono::Utc::now().timestamp_millis(),
+            project,
+            findings,
+            artifacts,
+            flow_edges,
+            memory: self.memory().list_by_project(project_id)?,
+            jobs,
+            job_links,
+        })
+    }
+
+    /// Recreate a project from a [`ProjectBundle`]
+    ///
+    /// Fails if the target project id already exists. Finding, job and artifact
+    /// ids that are taken by other projects get fresh ids, and every reference to
+    /// them inside the bundle is rewritten. If any step fails, everything created
+    /// so far is removed again.
+    pub fn import_project(
+        &self,
+        bundle: &ProjectBundle,
+        options: &BundleImportOptions,
+    ) -> Result<BundleImportResult> {
+        if bundle.version > PROJECT_BUNDLE_VERSION {
+            anyhow::bail!(
+                "Bundle version {} is newer than supported version {}",
+                bundle.version,
+                PROJECT_BUNDLE_VERSION
+            );
+        }
+
+        let project_id = options
+            .project_id
+            .clone()
+            .unwrap_or_else(|| bundle.project.id.clone());
+        if self.get_project(&project_id)?.is_some() {
+            anyhow::bail!(
+                "Project '{}' already exists; import it under another id",
+                project_id
+            );
+        }
+
+        let mut created = CreatedRows::default();
+        match self.import_bundle_rows(bundle, &project_id, &mut created) {
+            Ok(result) => Ok(result),
+            Err(err) => {
+                self.undo_import(&created);
+                Err(err)
+            }
+        }
+    }
+
+    fn import_bundle_rows(
+        &self,
+        bundle: &ProjectBundle,
+        project_id: &str,
+        created: &mut CreatedRows,
+    ) -> Result<BundleImportResult> {
+        let old_project_id = bundle.project.id.as_str();
+        let mut result = BundleImportResult {
+            project_id: project_id.to_string(),
+            ..Default::default()
+        };
+
+        let mut project = bundle.project.clone();
+        project.id = project_id.to_string();
+        self.create_project(&project)
+            .with_context(|| format!("Failed to create project '{}'", project_id))?;
+        created.project_id = Some(project_id.to_string());
+
+        // Findings: keep the numbering, swap in the new project prefix when the
+        // id carries the old one, and fall back to the next free number.
+        let mut finding_ids: HashMap<String, String> = HashMap::new();
+        let mut taken: HashSet<String> = HashSet::new();
+        let mut next_number = self.next_finding_number(project_id)?;
+        for finding in &bundle.findings {
+            let mut new_id = match finding.id.strip_prefix(old_project_id) {
+                Some(rest) if rest.starts_with('-') => format!("{project_id}{rest}"),
+                _ => finding.id.clone(),
+            };
+            if taken.contains(&new_id) || self.get_finding(&new_id)?.is_some() {
+                loop {
+                    new_id = Finding::generate_id(project_id, next_number);
+                    next_number += 1;
+                    if !taken.contains(&new_id) && self.get_finding(&new_id)?.is_none() {
+                        break;
+                    }
+                }
+                result.remapped_ids += 1;
+            }
+            taken.insert(new_id.clone());
+            finding_ids.insert(finding.id.clone(), new_id);
+        }
+        let remap_finding = |id: &str| {
+            finding_ids
+                .get(id)
+                .cloned()
+                .unwrap_or_else(|| id.to_string())
+        };
+
+        for finding in &bundle.findings {
+            let mut finding = finding.clone();
+            finding.id = remap_finding(&finding.id);
+            finding.project_id = project_id.to_string();
+            finding.duplicate_of = finding.duplicate_of.as_deref().map(remap_finding);
+            self.create_finding(&finding)
+                .with_context(|| format!("Failed to import finding {}", finding.id))?;
+            result.findings += 1;
+        }
+
+        // Jobs: UUIDs rarely collide, but importing the same bundle twice would
+        let mut job_ids: HashMap<String, String> = HashMap::new();
+        for job in &bundle.jobs {
+            let mut job = job.clone();
+            if self.jobs().get(&job.id)?.is_some() {
+                let new_id = uuid::Uuid::new_v4().to_string();
+                job_ids.insert(job.id.clone(), new_id.clone());
+                job.id = new_id;
+                result.remapped_ids += 1;
+            }
+            job.project_id = Some(project_id.to_string());
+            // The in-memory job id only means something to the GUI that ran it
+            job.kyco_job_id = None;
+            self.jobs()
+                .upsert(&job)
+                .with_context(|| format!("Failed to import job {}", job.id))?;
+            created.jobs.push(job.id);
+            result.jobs += 1;
+        }
+        let remap_job = |id: &str| job_ids.get(id).cloned().unwrap_or_else(|| id.to_string());
+
+        for artifact in &bundle.artifacts {
+            let mut artifact = artifact.clone();
+            if self.artifacts().get(&artifact.id)?.is_some() {
+                artifact.id = uuid::Uuid::new_v4().to_string();
+                result.remapped_ids += 1;
+            }
+            artifact.finding_id = artifact.finding_id.as_deref().map(remap_finding);
+            artifact.job_id = artifact.job_id.as_deref().map(remap_job);
+            self.create_artifact(&artifact)
+                .with_context(|| format!("Failed to import artifact {}", artifact.id))?;
+            created.artifacts.push(artifact.id);
+            result.artifacts += 1;
+        }
+
+        for edge in &bundle.flow_edges {
+            let mut edge = edge.clone();
+            edge.id = None;
+            edge.finding_id = remap_finding(&edge.finding_id);
+            self.create_flow_edge(&edge)
+                .with_context(|| format!("Failed to import flow edge for {}", edge.finding_id))?;
+            result.flow_edges += 1;
+        }
+
+        for link in &bundle.job_links {
+            self.job_findings().link(
+                &remap_job(&link.job_id),
+                &remap_finding(&link.finding_id),
+                &link.link_type,
+            )?;
+        }
+
+        for mem in &bundle.memory {
+            let mut mem = mem.clone();
+            mem.id = None;
+            mem.project_id = project_id.to_string();
+            mem.source_job_id = mem.source_job_id.as_deref().map(remap_job);
+            self.memory()
+                .create(&mem)
+                .with_context(|| format!("Failed to import memory entry '{}'", mem.title))?;
+            result.memory += 1;
+        }
+
+        Ok(result)
+    }
+
+    /// Best-effort removal of a partially imported project
+    fn undo_import(&self, created: &CreatedRows) {
+        for artifact_id in &created.artifacts {
+            let _ = self.artifacts().delete(artifact_id);
+        }
+        for job_id in &created.jobs {
+            let _ = self.jobs().delete(job_id);
+        }
+        // Findings, flow edges, links and memory cascade with the project
+        if let Some(project_id) = created.project_id.as_deref() {
+            let _ = self.projects().delete(project_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bugbounty::{ArtifactType, CodeLocation, FlowKind, MemorySourceKind};
+    use tempfile::tempdir;
+
+    fn test_manager() -> BugBountyManager {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test_bugbounty.db");
+        std::mem::forget(dir);
+        BugBountyManager::with_path(&db_path).unwrap()
+    }
+
+    fn seed_project(manager: &BugBountyManager) {
+        manager
+            .create_project(&Project::new("acme", "programs/acme"))
+            .unwrap();
+
+        let first = Finding::new("acme-VULN-001", "acme", "IDOR in /api/users");
+        let mut second = Finding::new("acme-VULN-002", "acme", "IDOR in /api/users/{id}");
+        second.duplicate_of = Some(first.id.clone());
+        manager.create_finding(&first).unwrap();
+        manager.create_finding(&second).unwrap();
+
+        let job = BugBountyJob::new("job-1")
+            .with_project_id("acme")
+            .with_kyco_job_id(7);
+        manager.jobs().upsert(&job).unwrap();
+        manager
+            .job_findings()
+            .link("job-1", "acme-VULN-001", "discovered")
+            .unwrap();
+
+        manager
+            .create_artifact(
+                &Artifact::new("evidence/req.txt", ArtifactType::HttpRequest)
+                    .with_finding("acme-VULN-001")
+                    .with_job("job-1"),
+            )
+            .unwrap();
+        manager
+            .create_flow_edge(&FlowEdge::new(
+                "acme-VULN-001",
+                CodeLocation::new("src/api.rs").with_line(10),
+                CodeLocation::new("src/db.rs").with_line(42),
+                FlowKind::Dataflow,
+            ))
+            .unwrap();
+        manager
+            .memory()
+            .create(
+                &ProjectMemory::note("acme", MemorySourceKind::Manual, "Auth is header based")
+                    .with_source_job("job-1"),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn export_import_round_trip_remaps_ids() {
+        let manager = test_manager();
+        seed_project(&manager);
+
+        let bundle = manager.export_project("acme").unwrap();
+        let json = serde_json::to_string(&bundle).unwrap();
+        let bundle: ProjectBundle = serde_json::from_str(&json).unwrap();
+
+        // Importing under the same id is refused
+        assert!(
+            manager
+                .import_project(&bundle, &BundleImportOptions::default())
+                .is_err()
+        );
+
+        let options = BundleImportOptions {
+            project_id: Some("acme-copy".to_string()),
+        };
+        let result = manager.import_project(&bundle, &options).unwrap();
+        assert_eq!(result.project_id, "acme-copy");
+        assert_eq!(result.findings, 2);
+        assert_eq!(result.artifacts, 1);
+        assert_eq!(result.flow_edges, 1);
+        assert_eq!(result.memory, 1);
+        assert_eq!(result.jobs, 1);
+        // The job and the artifact already exist under their original ids
+        assert_eq!(result.remapped_ids, 2);
+
+        let copy = manager.export_project("acme-copy").unwrap();
+        let ids: Vec<&str> = copy.findings.iter().map(|f| f.id.as_str()).collect();
+        assert!(ids.contains(&"acme-copy-VULN-001"));
+        assert!(ids.contains(&"acme-copy-VULN-002"));
+        let dup = manager.get_finding("acme-copy-VULN-002").unwrap().unwrap();
+        assert_eq!(dup.duplicate_of.as_deref(), Some("acme-copy-VULN-001"));
+
+        let job = &copy.jobs[0];
+        assert_ne!(job.id, "job-1");
+        assert_eq!(job.kyco_job_id, None);
+        assert_eq!(copy.job_links.len(), 1);
+        assert_eq!(copy.job_links[0].job_id, job.id);
+        assert_eq!(copy.artifacts[0].job_id.as_deref(), Some(job.id.as_str()));
+        assert_eq!(copy.flow_edges[0].finding_id, "acme-copy-VULN-001");
+        assert_eq!(
+            copy.memory[0].source_job_id.as_deref(),
+            Some(job.id.as_str())
+        );
+
+        // The original project is untouched
+        let original = manager.export_project("acme").unwrap();
+        assert_eq!(original.findings.len(), 2);
+        assert_eq!(original.jobs[0].id, "job-1");
+    }
+
+    #[test]
+    fn import_into_empty_db_keeps_ids() {
+        let source = test_manager();
+        seed_project(&source);
+        let bundle = source.export_project("acme").unwrap();
+
+        let target = test_manager();
+        let result = target
+            .import_project(&bundle, &BundleImportOptions::default())
+            .unwrap();
+        assert_eq!(result.remapped_ids, 0);
+        assert!(target.get_finding("acme-VULN-001").unwrap().is_some());
+        assert!(target.jobs().get("job-1").unwrap().is_some());
+        assert!(
+            target
+                .job_findings()
+                .is_linked("job-1", "acme-VULN-001")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn failed_import_is_rolled_back() {
+        let manager = test_manager();
+        seed_project(&manager);
+        let mut bundle = manager.export_project("acme").unwrap();
+        // A flow edge pointing at a finding that is not in the bundle violates
+        // the foreign key after findings and jobs were already created
+        bundle.flow_edges[0].finding_id = "missing-VULN-001".to_string();
+
+        let target = test_manager();
+        assert!(
+            target
+                .import_project(&bundle, &BundleImportOptions::default())
+                .is_err()
+        );
+        assert!(target.get_project("acme").unwrap().is_none());
+        assert!(target.get_finding("acme-VULN-001").unwrap().is_none());
+        assert!(target.jobs().get("job-1").unwrap().is_none());
+    }
+}
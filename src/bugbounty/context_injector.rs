@@ -7,13 +7,14 @@
 //! - Project metadata
 
 use anyhow::Result;
+use serde::Serialize;
 
 use super::{
     BugBountyManager, Finding, FindingStatus, MemoryType, Project, ProjectMemory, Severity,
 };
 
 /// Context to inject into agent prompts
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct InjectedContext {
     /// Project context (name, platform, etc.)
     pub project_info: Option<String>,
@@ -147,6 +148,61 @@ impl ContextInjector {
         Ok(ctx)
     }
 
+    /// Describe findings explicitly linked to a job (verification/triage targets)
+    pub fn focus_findings(&self, project_id: &str, finding_ids: &[String]) -> Option<String> {
+        let ids: Vec<&str> = finding_ids
+            .iter()
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .collect();
+        if ids.is_empty() {
+            return None;
+        }
+
+        let mut lines = Vec::new();
+        lines.push("These findings are explicitly linked to this job (verification/triage targets).".to_string());
+        lines.push("If you update an existing finding, include its `id` in `next_context.findings[]`.".to_string());
+        lines.push(String::new());
+
+        for fid in ids {
+            match self.manager.get_finding(fid).ok().flatten() {
+                Some(f) if f.project_id == project_id => {
+                    let sev = f
+                        .severity
+                        .map(|s| s.as_str().to_uppercase())
+                        .unwrap_or_else(|| "-".to_string());
+                    lines.push(format!(
+                        "- **{}** [{}] {} ({})",
+                        f.id,
+                        sev,
+                        f.title,
+                        f.status.as_str()
+                    ));
+                    if let Some(ref scenario) = f.attack_scenario {
+                        lines.push(format!("  - Attack: {}", scenario));
+                    }
+                    if !f.affected_assets.is_empty() {
+                        lines.push(format!("  - Assets: {}", f.affected_assets.join(", ")));
+                    }
+                    if let Some(ref taint) = f.taint_path {
+                        lines.push(format!("  - Taint: {}", taint));
+                    }
+                }
+                Some(f) => {
+                    lines.push(format!(
+                        "- **{}** (belongs to project `{}`; current job project is `{}`)",
+                        fid, f.project_id, project_id
+                    ));
+                }
+                None => {
+                    lines.push(format!("- **{}** (not found)", fid));
+                }
+            }
+        }
+
+        Some(lines.join("\n"))
+    }
+
     /// Build context for a specific file within a project
     pub fn for_file(&self, project_id: &str, file_path: &str) -> Result<InjectedContext> {
         let mut ctx = self.for_project(project_id)?;
@@ -361,7 +417,7 @@ Required per finding (when you emit a finding):
 Optional:
 - id (include when updating an existing finding)
 - severity (critical|high|medium|low|info)
-- cwe_id, cvss_score, affected_assets[], taint_path
+- cwe_id, cvss_score, cvss_vector, affected_assets[], references[], taint_path
 
 **flow_edges** (array) - cross-file trace edges (optional).
 - finding_id (optional)
@@ -586,4 +642,33 @@ mod tests {
         assert!(summary.contains("1 high"));
         assert!(summary.contains("VULN-001"));
     }
+
+    #[test]
+    fn test_focus_findings() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager = BugBountyManager::with_path(&dir.path().join("bb.db")).unwrap();
+        for id in ["proj-a", "proj-b"] {
+            manager.create_project(&Project::new(id, format!("programs/{id}"))).unwrap();
+        }
+        manager
+            .create_finding(
+                &Finding::new("A-001", "proj-a", "IDOR in invoices")
+                    .with_severity(Severity::High)
+                    .with_attack_scenario("Swap invoice id"),
+            )
+            .unwrap();
+        manager
+            .create_finding(&Finding::new("B-001", "proj-b", "Open redirect"))
+            .unwrap();
+
+        let injector = ContextInjector::new(manager);
+        assert!(injector.focus_findings("proj-a", &[" ".to_string()]).is_none());
+
+        let ids = ["A-001", "B-001", "X-404"].map(String::from);
+        let focus = injector.focus_findings("proj-a", &ids).unwrap();
+        assert!(focus.contains("- **A-001** [HIGH] IDOR in invoices (raw)"));
+        assert!(focus.contains("  - Attack: Swap invoice id"));
+        assert!(focus.contains("- **B-001** (belongs to project `proj-b`"));
+        assert!(focus.contains("- **X-404** (not found)"));
+    }
 }
@@ -74,10 +74,65 @@ impl BugBountyDb {
             )
             .unwrap_or(0);
 
-        // Future migrations go here
-        // if version < 2 { ... }
+        if version < 2 {
+            conn.execute_batch(
+                r#"
+                ALTER TABLE findings ADD COLUMN cvss_vector TEXT;
+                INSERT OR IGNORE INTO bb_schema_version VALUES (2);
+                "#,
+            )
+            .context("Failed to migrate bugbounty db to v2 (cvss_vector)")?;
+        }
+
+        if version < 3 {
+            conn.execute_batch(
+                r#"
+                ALTER TABLE findings ADD COLUMN manual_priority INTEGER;
+                INSERT OR IGNORE INTO bb_schema_version VALUES (3);
+                "#,
+            )
+            .context("Failed to migrate bugbounty db to v3 (manual_priority)")?;
+        }
+
+        if version < 4 {
+            conn.execute_batch(
+                r#"
+                ALTER TABLE findings ADD COLUMN duplicate_of TEXT;
+                INSERT OR IGNORE INTO bb_schema_version VALUES (4);
+                "#,
+            )
+            .context("Failed to migrate bugbounty db to v4 (duplicate_of)")?;
+        }
 
-        let _ = version; // Suppress unused warning for now
+        if version < 5 {
+            conn.execute_batch(
+                r#"
+                ALTER TABLE artifacts ADD COLUMN size_bytes INTEGER;
+                INSERT OR IGNORE INTO bb_schema_version VALUES (5);
+                "#,
+            )
+            .context("Failed to migrate bugbounty db to v5 (artifact size_bytes)")?;
+        }
+
+        if version < 6 {
+            conn.execute_batch(
+                r#"
+                ALTER TABLE findings ADD COLUMN computed_priority INTEGER;
+                INSERT OR IGNORE INTO bb_schema_version VALUES (6);
+                "#,
+            )
+            .context("Failed to migrate bugbounty db to v6 (computed_priority)")?;
+        }
+
+        if version < 7 {
+            conn.execute_batch(
+                r#"
+                ALTER TABLE findings ADD COLUMN references_json TEXT;
+                INSERT OR IGNORE INTO bb_schema_version VALUES (7);
+                "#,
+            )
+            .context("Failed to migrate bugbounty db to v7 (finding references)")?;
+        }
 
         Ok(())
     }
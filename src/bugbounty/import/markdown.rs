@@ -0,0 +1,268 @@
+//! Markdown report importer
+//!
+//! Ingests loosely structured, hand-written finding notes:
+//!
+//! ```markdown
+//! ## IDOR in /api/users
+//! Severity: high
+//!
+//! Any user can read other profiles by changing the id.
+//!
+//! Affected:
+//! - src/api/users.rs:42
+//! - /api/users/{id}
+//! ```
+//!
+//! Each `##` heading starts a finding. Sections without a `Severity:` line or an
+//! `Affected:` list are not findings (e.g. "## Summary") and are skipped.
+
+use super::{map_severity, ImportResult};
+use crate::bugbounty::Finding;
+use anyhow::{Context, Result};
+use std::path::Path;
+
+/// Result of Markdown import
+pub type MarkdownResult = ImportResult;
+
+/// One `##` section of the report
+#[derive(Debug)]
+struct Section {
+    title: String,
+    line: usize,
+    body: Vec<String>,
+}
+
+/// Import findings from a Markdown report file
+pub fn import_markdown(path: &Path, project_id: &str, start_number: u32) -> Result<MarkdownResult> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read Markdown file: {}", path.display()))?;
+
+    Ok(import_markdown_str(&content, project_id, start_number))
+}
+
+/// Import findings from a Markdown report string
+pub fn import_markdown_str(content: &str, project_id: &str, start_number: u32) -> MarkdownResult {
+    let mut result = ImportResult::new();
+    let mut finding_number = start_number;
+
+    for section in split_sections(content) {
+        match parse_section(&section, &mut result) {
+            Some(mut finding) => {
+                finding.id = Finding::generate_id(project_id, finding_number);
+                finding.project_id = project_id.to_string();
+                finding_number += 1;
+                result.add_finding(finding);
+            }
+            None => result.skipped += 1,
+        }
+    }
+
+    if result.findings.is_empty() {
+        result.add_warning("No findings found in Markdown report");
+    }
+
+    result
+}
+
+/// Whether content looks like a Markdown report rather than tool JSON output
+pub fn looks_like_markdown(content: &str) -> bool {
+    let trimmed = content.trim_start();
+    trimmed.starts_with('#') && content.lines().any(|l| section_heading(l).is_some())
+}
+
+/// Split content into `##` sections, ignoring headings inside code fences
+fn split_sections(content: &str) -> Vec<Section> {
+    let mut sections = Vec::new();
+    let mut current: Option<Section> = None;
+    let mut in_fence = false;
+
+    for (idx, line) in content.lines().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+
+        if !in_fence {
+            if let Some(title) = section_heading(line) {
+                sections.extend(current.take());
+                current = Some(Section {
+                    title: title.trim().trim_matches('#').trim().to_string(),
+                    line: idx + 1,
+                    body: Vec::new(),
+                });
+                continue;
+            }
+        }
+
+        if let Some(section) = current.as_mut() {
+            section.body.push(line.to_string());
+        }
+    }
+
+    sections.extend(current);
+    sections
+}
+
+/// Title text of a level-2 heading (`## Title`), possibly empty
+fn section_heading(line: &str) -> Option<&str> {
+    let rest = line.strip_prefix("##")?;
+    (rest.is_empty() || rest.starts_with(' ')).then_some(rest)
+}
+
+/// Turn a section into a finding (id/project are filled in by the caller)
+fn parse_section(section: &Section, result: &mut ImportResult) -> Option<Finding> {
+    if section.title.is_empty() {
+        result.add_warning(format!("Line {}: heading without a title", section.line));
+        return None;
+    }
+
+    let mut finding = Finding::new("", "", section.title.clone());
+    let mut has_severity_line = false;
+    let mut in_affected = false;
+    let mut notes: Vec<&str> = Vec::new();
+
+    for line in &section.body {
+        let trimmed = line.trim();
+
+        if in_affected {
+            if let Some(item) = bullet_item(trimmed) {
+                finding = finding.with_affected_asset(strip_code(item));
+                continue;
+            }
+            if trimmed.is_empty() && finding.affected_assets.is_empty() {
+                continue;
+            }
+            in_affected = false;
+        }
+
+        if let Some((label, value)) = labelled(trimmed) {
+            if label == "severity" {
+                has_severity_line = true;
+                match map_severity(value.split_whitespace().next().unwrap_or("")) {
+                    Some(severity) => finding.severity = Some(severity),
+                    None => result
+                        .add_warning(format!("{}: unknown severity '{}'", section.title, value)),
+                }
+                continue;
+            }
+            if label.starts_with("affected") {
+                in_affected = true;
+                for asset in value.split(',').map(strip_code).filter(|a| !a.is_empty()) {
+                    finding = finding.with_affected_asset(asset);
+                }
+                continue;
+            }
+        }
+
+        notes.push(line);
+    }
+
+    if !has_severity_line && finding.affected_assets.is_empty() {
+        result.add_warning(format!(
+            "Line {}: '{}' has no Severity or Affected entries",
+            section.line, section.title
+        ));
+        return None;
+    }
+
+    let notes = notes.join("\n");
+    let notes = notes.trim();
+    if !notes.is_empty() {
+        finding.notes = Some(notes.to_string());
+    }
+    Some(finding)
+}
+
+/// Split `Label: value` lines, tolerating list markers and bold (`- **Severity:** High`)
+fn labelled(line: &str) -> Option<(String, &str)> {
+    let line = bullet_item(line).unwrap_or(line);
+    let (label, value) = line.split_once(':')?;
+    let label = label.trim().trim_matches(|c| c == '*' || c == '_').trim();
+    if label.is_empty() || label.len() > 32 {
+        return None;
+    }
+    let value = value.trim().trim_matches(|c| c == '*' || c == '_').trim();
+    Some((label.to_lowercase(), value))
+}
+
+fn bullet_item(line: &str) -> Option<&str> {
+    ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))
+        .map(str::trim)
+}
+
+fn strip_code(value: &str) -> String {
+    value.trim().trim_matches('`').trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bugbounty::Severity;
+
+    const SAMPLE_REPORT: &str = r#"# Nextcloud audit notes
+
+## Summary
+Three issues worth reporting.
+
+## IDOR in /api/users
+Severity: high
+
+Any user can read other profiles by changing the id.
+
+Affected:
+- `src/api/users.rs:42`
+- /api/users/{id}
+
+## Reflected XSS in search
+- **Severity:** Medium
+- **Affected:** web/search.php, web/results.php
+
+```markdown
+## Not a heading inside a fence
+```
+
+##
+Severity: low
+"#;
+
+    #[test]
+    fn test_import_markdown_report() {
+        let result = import_markdown_str(SAMPLE_REPORT, "test-project", 3);
+        assert_eq!(result.findings.len(), 2);
+        assert_eq!(result.skipped, 2);
+
+        let idor = &result.findings[0];
+        assert_eq!(idor.id, "test-project-VULN-003");
+        assert_eq!(idor.project_id, "test-project");
+        assert_eq!(idor.title, "IDOR in /api/users");
+        assert_eq!(idor.severity, Some(Severity::High));
+        assert_eq!(
+            idor.affected_assets,
+            vec![
+                "src/api/users.rs:42".to_string(),
+                "/api/users/{id}".to_string()
+            ]
+        );
+        assert!(
+            idor.notes
+                .as_deref()
+                .is_some_and(|n| n.contains("changing the id"))
+        );
+
+        let xss = &result.findings[1];
+        assert_eq!(xss.id, "test-project-VULN-004");
+        assert_eq!(xss.severity, Some(Severity::Medium));
+        assert_eq!(
+            xss.affected_assets,
+            vec!["web/search.php".to_string(), "web/results.php".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_looks_like_markdown() {
+        assert!(looks_like_markdown(SAMPLE_REPORT));
+        assert!(!looks_like_markdown("{\"runs\": []}"));
+        assert!(!looks_like_markdown("# Title only\nno sections"));
+    }
+}
@@ -6,13 +6,16 @@
 //! - CodeQL SARIF output
 //! - Snyk JSON output
 //! - Nuclei JSON/JSONL output
+//! - Hand-written Markdown reports
 
+mod markdown;
 mod memory_semgrep;
 mod nuclei;
 mod sarif;
 mod semgrep;
 mod snyk;
 
+pub use markdown::{import_markdown, looks_like_markdown, MarkdownResult};
 pub use memory_semgrep::import_semgrep_memory;
 pub use nuclei::{import_nuclei, NucleiResult};
 pub use sarif::{import_sarif, SarifResult};
@@ -166,6 +166,13 @@ impl NucleiCveId {
             NucleiCveId::Multiple(v) => v.first().cloned(),
         }
     }
+
+    pub fn as_vec(&self) -> Vec<String> {
+        match self {
+            NucleiCveId::Single(s) => vec![s.clone()],
+            NucleiCveId::Multiple(v) => v.clone(),
+        }
+    }
 }
 
 /// CWE ID can be string or array
@@ -347,14 +354,13 @@ pub fn import_nuclei_str(content: &str, project_id: &str, start_number: u32) ->
             f.preconditions = Some(precond);
         }
 
-        // Add references to notes if available
+        // CVE IDs and reference URLs become the finding's references
         if let Some(ref info) = finding.info {
+            if let Some(cves) = info.classification.as_ref().and_then(|c| c.cve_id.as_ref()) {
+                f = f.with_references(cves.as_vec());
+            }
             if let Some(ref refs) = info.reference {
-                let refs_str = refs.as_vec().join("\n");
-                if !refs_str.is_empty() {
-                    let current = f.attack_scenario.unwrap_or_default();
-                    f.attack_scenario = Some(format!("{}\n\nReferences:\n{}", current, refs_str));
-                }
+                f = f.with_references(refs.as_vec());
             }
 
             // Add remediation if available
@@ -423,6 +429,13 @@ mod tests {
         assert!(f1.impact.as_ref().unwrap().contains("CVE-2021-44228"));
         assert!(f1.impact.as_ref().unwrap().contains("10.0"));
         assert!(f1.taint_path.as_ref().unwrap().contains("curl"));
+        assert_eq!(
+            f1.references,
+            vec![
+                "CVE-2021-44228",
+                "https://nvd.nist.gov/vuln/detail/CVE-2021-44228"
+            ]
+        );
 
         // Second finding: Admin panel
         let f2 = &result.findings[1];
@@ -58,6 +58,8 @@ pub struct SarifRule {
     pub full_description: Option<SarifMessage>,
     #[serde(rename = "defaultConfiguration")]
     pub default_configuration: Option<SarifConfiguration>,
+    #[serde(rename = "helpUri")]
+    pub help_uri: Option<String>,
     pub properties: Option<SarifRuleProperties>,
 }
 
@@ -295,6 +297,10 @@ pub fn import_sarif_str(content: &str, project_id: &str, start_number: u32) -> R
                 finding = finding.with_affected_asset(&asset);
             }
 
+            if let Some(help_uri) = rule.and_then(|r| r.help_uri.as_ref()) {
+                finding = finding.with_references([help_uri.as_str()]);
+            }
+
             result.add_finding(finding);
 
             // Process code flows for taint tracking
@@ -369,6 +375,7 @@ mod tests {
                         "defaultConfiguration": {
                             "level": "warning"
                         },
+                        "helpUri": "https://semgrep.dev/r/go.lang.security.audit.xss.direct-response-write",
                         "properties": {
                             "precision": "high",
                             "cwe": "79"
@@ -432,5 +439,9 @@ mod tests {
         assert_eq!(finding.severity, Some(Severity::Medium));
         assert_eq!(finding.confidence, Some(Confidence::High));
         assert_eq!(finding.cwe_id, Some("CWE-79".to_string()));
+        assert_eq!(
+            finding.references,
+            vec!["https://semgrep.dev/r/go.lang.security.audit.xss.direct-response-write"]
+        );
     }
 }
@@ -263,6 +263,15 @@ pub fn import_semgrep_str(
             finding = finding.with_preconditions(&preconditions);
         }
 
+        if let Some(references) = item
+            .extra
+            .metadata
+            .as_ref()
+            .and_then(|m| m.references.as_ref())
+        {
+            finding = finding.with_references(references.iter().cloned());
+        }
+
         result.add_finding(finding);
 
         // Process dataflow trace for taint tracking
@@ -339,6 +348,7 @@ mod tests {
                     "impact": "HIGH",
                     "likelihood": "HIGH",
                     "owasp": ["A03:2021 - Injection"],
+                    "references": ["https://owasp.org/Top10/A03_2021-Injection"],
                     "technology": ["go"],
                     "vulnerability_class": ["SQL Injection"]
                 },
@@ -392,5 +402,9 @@ mod tests {
         assert_eq!(finding.confidence, Some(Confidence::High));
         assert_eq!(finding.cwe_id, Some("CWE-89".to_string()));
         assert!(finding.attack_scenario.as_ref().unwrap().contains("SQL"));
+        assert_eq!(
+            finding.references,
+            vec!["https://owasp.org/Top10/A03_2021-Injection"]
+        );
     }
 }
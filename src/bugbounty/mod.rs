@@ -41,24 +41,41 @@
 //! let raw_findings = manager.list_findings_by_status(FindingStatus::Raw)?;
 //! ```
 
+mod bundle;
 mod db;
 pub mod context_injector;
 pub mod import;
 pub mod models;
 pub mod notes;
 pub mod next_context;
+mod report;
 mod repository;
+mod scope_diff;
 mod scope_parser;
 
+pub use bundle::{
+    BundleImportOptions, BundleImportResult, BundleJobLink, PROJECT_BUNDLE_VERSION, ProjectBundle,
+};
 pub use context_injector::{ContextInjector, InjectedContext};
 pub use db::BugBountyDb;
 pub use import::{ImportResult, MemoryImportResult, import_sarif, import_semgrep, import_semgrep_memory};
 pub use models::*;
-pub use next_context::NextContext;
+pub use next_context::{NextContext, OutputValidation};
+pub use report::{ReportFormat, render_project_report};
 pub use repository::*;
+pub use scope_diff::{diff_scopes, ScopeDiff};
 pub use scope_parser::{parse_scope_file, parse_scope_markdown};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+
+/// What [`BugBountyManager::merge_findings_into`] moved to the canonical finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MergedFindings {
+    pub artifacts_moved: usize,
+    pub jobs_moved: usize,
+    /// Findings that were duplicates of the merged finding
+    pub duplicates_moved: usize,
+}
 
 /// Central manager for BugBounty tracking
 ///
@@ -259,10 +276,134 @@ impl BugBountyManager {
         self.findings().get(id)
     }
 
+    pub fn clone_finding(&self, id: &str, new_title: Option<&str>) -> Result<Finding> {
+        self.findings().clone_finding(id, new_title)
+    }
+
     pub fn set_finding_status(&self, id: &str, status: FindingStatus) -> Result<()> {
         self.findings().set_status(id, status)
     }
 
+    /// Move several findings to `status`, one at a time.
+    ///
+    /// Each id gets its own result: a missing finding or an illegal transition
+    /// (see [`FindingStatus::can_transition_to`]) fails that id only.
+    /// `duplicate` is not supported here since it needs a canonical finding.
+    pub fn set_findings_status(
+        &self,
+        ids: &[String],
+        status: FindingStatus,
+    ) -> Vec<(String, Result<()>)> {
+        ids.iter()
+            .map(|id| (id.clone(), self.set_finding_status_checked(id, status)))
+            .collect()
+    }
+
+    fn set_finding_status_checked(&self, id: &str, status: FindingStatus) -> Result<()> {
+        if status == FindingStatus::Duplicate {
+            anyhow::bail!("use `kyco finding set-status {} duplicate --of <id>`", id);
+        }
+        let finding = self
+            .get_finding(id)?
+            .ok_or_else(|| anyhow::anyhow!("Finding not found: {}", id))?;
+        if !finding.status.can_transition_to(status) {
+            anyhow::bail!(
+                "{} is closed ({}); it can only be reopened as raw",
+                id,
+                finding.status.as_str()
+            );
+        }
+        self.set_finding_status(id, status)
+    }
+
+    /// Move a finding to the Duplicate column, pointing at its canonical finding
+    pub fn mark_finding_duplicate(&self, id: &str, canonical_id: &str) -> Result<()> {
+        let canonical = self.check_duplicate_target(id, canonical_id)?;
+        let mut conn = self.db.conn();
+        let tx = conn
+            .transaction()
+            .context("Failed to start marking the duplicate")?;
+        FindingRepository::repoint_duplicates_in(&tx, id, &canonical.id)?;
+        FindingRepository::mark_duplicate_in(&tx, id, &canonical.id)?;
+        tx.commit().context("Failed to commit the duplicate")
+    }
+
+    /// Merge a duplicate finding into its canonical finding.
+    ///
+    /// Linked jobs, artifacts and duplicates are re-pointed to the canonical
+    /// finding and the duplicate is marked `Duplicate` with `duplicate_of` set.
+    pub fn merge_findings_into(&self, dup_id: &str, canonical_id: &str) -> Result<MergedFindings> {
+        let canonical = self.check_duplicate_target(dup_id, canonical_id)?;
+
+        // All or nothing: a failure half-way must not leave links split between both
+        let mut conn = self.db.conn();
+        let tx = conn
+            .transaction()
+            .context("Failed to start finding merge")?;
+        let artifacts_moved = ArtifactRepository::reassign_finding_in(&tx, dup_id, &canonical.id)?;
+        let jobs_moved = JobFindingRepository::reassign_finding_in(&tx, dup_id, &canonical.id)?;
+        let duplicates_moved =
+            FindingRepository::repoint_duplicates_in(&tx, dup_id, &canonical.id)?;
+        FindingRepository::mark_duplicate_in(&tx, dup_id, &canonical.id)?;
+        tx.commit().context("Failed to commit finding merge")?;
+
+        Ok(MergedFindings {
+            artifacts_moved,
+            jobs_moved,
+            duplicates_moved,
+        })
+    }
+
+    /// Validate a duplicate -> canonical pairing and return the canonical finding
+    fn check_duplicate_target(&self, dup_id: &str, canonical_id: &str) -> Result<Finding> {
+        let dup = self
+            .get_finding(dup_id)?
+            .ok_or_else(|| anyhow::anyhow!("Finding not found: {}", dup_id))?;
+        let canonical = self
+            .get_finding(canonical_id)?
+            .ok_or_else(|| anyhow::anyhow!("Finding not found: {}", canonical_id))?;
+
+        if dup.id == canonical.id {
+            anyhow::bail!("A finding cannot be a duplicate of itself");
+        }
+        if dup.project_id != canonical.project_id {
+            anyhow::bail!(
+                "{} belongs to project '{}', but {} belongs to '{}'",
+                dup.id,
+                dup.project_id,
+                canonical.id,
+                canonical.project_id
+            );
+        }
+        if let Some(target) = canonical.duplicate_of.as_deref() {
+            anyhow::bail!(
+                "{} is itself a duplicate of {}; use that finding instead",
+                canonical.id,
+                target
+            );
+        }
+        Ok(canonical)
+    }
+
+    /// Pin a finding within its Kanban column (`None` clears the pin)
+    pub fn set_finding_priority(&self, id: &str, priority: Option<i32>) -> Result<()> {
+        self.findings().set_manual_priority(id, priority)
+    }
+
+    /// Recompute and store a finding's `computed_priority` from its flow trace.
+    ///
+    /// `entry_points` are the globs from `settings.bugbounty.entry_point_patterns`;
+    /// see [`Finding::reachability_priority`] for the scoring.
+    pub fn recompute_priority(&self, finding_id: &str, entry_points: &[String]) -> Result<i32> {
+        let finding = self
+            .get_finding(finding_id)?
+            .ok_or_else(|| anyhow::anyhow!("Finding not found: {}", finding_id))?;
+        let trace = self.get_flow_trace(finding_id)?;
+        let priority = finding.reachability_priority(&trace, entry_points);
+        self.findings().set_computed_priority(finding_id, Some(priority))?;
+        Ok(priority)
+    }
+
     pub fn list_findings_by_project(&self, project_id: &str) -> Result<Vec<Finding>> {
         self.findings().list_by_project(project_id)
     }
@@ -271,11 +412,48 @@ impl BugBountyManager {
         self.findings().list_by_status(status)
     }
 
+    /// Full-text search over findings, optionally limited to one project
+    pub fn search_findings(&self, query: &str, project_id: Option<&str>) -> Result<Vec<Finding>> {
+        self.findings().search(query, project_id)
+    }
+
     /// Get the next available finding number for a project
     pub fn next_finding_number(&self, project_id: &str) -> Result<u32> {
         self.findings().next_number(project_id)
     }
 
+    // Job links
+    /// Link a job to a finding; returns `false` if the link already existed.
+    ///
+    /// An existing link keeps its original relation.
+    pub fn link_finding_job(
+        &self,
+        finding_id: &str,
+        job_id: &str,
+        link_type: &str,
+    ) -> Result<bool> {
+        if self.get_finding(finding_id)?.is_none() {
+            anyhow::bail!("Finding not found: {}", finding_id);
+        }
+        if self.jobs().get(job_id)?.is_none() {
+            anyhow::bail!("Job not found: {}", job_id);
+        }
+        if self.job_findings().is_linked(job_id, finding_id)? {
+            return Ok(false);
+        }
+        self.job_findings().link(job_id, finding_id, link_type)?;
+        Ok(true)
+    }
+
+    /// Remove a job link; returns `false` if there was nothing to remove.
+    pub fn unlink_finding_job(&self, finding_id: &str, job_id: &str) -> Result<bool> {
+        if !self.job_findings().is_linked(job_id, finding_id)? {
+            return Ok(false);
+        }
+        self.job_findings().unlink(job_id, finding_id)?;
+        Ok(true)
+    }
+
     // Artifacts
     pub fn create_artifact(&self, artifact: &Artifact) -> Result<()> {
         self.artifacts().create(artifact)
@@ -294,6 +472,24 @@ impl BugBountyManager {
         self.flow_edges().get_trace(finding_id)
     }
 
+    /// Render all findings of a project as one report (sorted by severity, then status)
+    pub fn export_project_report(&self, project_id: &str, format: ReportFormat) -> Result<String> {
+        let project = self
+            .get_project(project_id)?
+            .ok_or_else(|| anyhow::anyhow!("Project not found: {}", project_id))?;
+
+        let findings = self
+            .list_findings_by_project(project_id)?
+            .into_iter()
+            .map(|finding| {
+                let trace = self.get_flow_trace(&finding.id)?;
+                Ok((finding, trace))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(render_project_report(&project, &findings, format))
+    }
+
     // ============================================
     // NextContext processing
     // ============================================
@@ -339,9 +535,14 @@ impl BugBountyManager {
             if incoming.cvss_score.is_some() {
                 existing.cvss_score = incoming.cvss_score;
             }
+            if incoming.cvss_vector.is_some() {
+                existing.cvss_vector = incoming.cvss_vector;
+            }
             if !incoming.affected_assets.is_empty() {
                 existing.affected_assets = incoming.affected_assets;
             }
+            // References accumulate across runs instead of being replaced
+            existing = existing.with_references(incoming.references);
             if incoming.taint_path.is_some() {
                 existing.taint_path = incoming.taint_path;
             }
@@ -457,7 +658,7 @@ impl BugBountyManager {
         if !ctx.memory.is_empty() {
             let memory_entries = ctx.to_memory(project_id, job_id);
             for mem in memory_entries {
-                // Deduplication: check if same type + file + line exists
+                // Deduplication: same type + file + line, or same normalized text without a line
                 if !self.memory().exists_duplicate(&mem)? {
                     self.memory().create(&mem)?;
                 }
@@ -566,6 +767,18 @@ impl BugBountyManager {
         Ok(result)
     }
 
+    /// Import findings from a hand-written Markdown report
+    pub fn import_markdown(&self, path: &std::path::Path, project_id: &str) -> Result<ImportResult> {
+        let start_number = self.next_finding_number(project_id)?;
+        let result = import::import_markdown(path, project_id, start_number)?;
+
+        for finding in &result.findings {
+            self.create_finding(finding)?;
+        }
+
+        Ok(result)
+    }
+
     /// Auto-detect format and import findings from a file
     pub fn import_auto(&self, path: &std::path::Path, project_id: &str) -> Result<ImportResult> {
         let content = std::fs::read_to_string(path)?;
@@ -589,9 +802,12 @@ impl BugBountyManager {
         {
             // Snyk JSON (deps)
             self.import_snyk(path, project_id)
+        } else if import::looks_like_markdown(&content) {
+            // Hand-written report with `## Finding` sections
+            self.import_markdown(path, project_id)
         } else {
             anyhow::bail!(
-                "Could not detect file format. Use --format sarif, semgrep, snyk, or nuclei"
+                "Could not detect file format. Use --format sarif, semgrep, snyk, nuclei, or markdown"
             )
         }
     }
@@ -639,6 +855,344 @@ mod tests {
         assert!(file_path.canonicalize().unwrap().starts_with(&matched_root));
     }
 
+    #[test]
+    fn test_clone_finding_resets_status_and_keeps_assessment() {
+        let manager = test_manager();
+        let project = Project::new("clone-project", "programs/clone-project");
+        manager.create_project(&project).unwrap();
+
+        let source = Finding::new("clone-project-VULN-001", "clone-project", "SQLi in search")
+            .with_status(FindingStatus::Verified)
+            .with_severity(Severity::High)
+            .with_cwe("CWE-89")
+            .with_attack_scenario("Inject via q param");
+        manager.create_finding(&source).unwrap();
+
+        let clone = manager
+            .clone_finding("clone-project-VULN-001", Some("SQLi in export"))
+            .unwrap();
+        assert_eq!(clone.id, "clone-project-VULN-002");
+        assert_eq!(clone.title, "SQLi in export");
+        assert_eq!(clone.status, FindingStatus::Raw);
+        assert_eq!(clone.severity, Some(Severity::High));
+        assert_eq!(clone.cwe_id.as_deref(), Some("CWE-89"));
+        assert_eq!(clone.attack_scenario.as_deref(), Some("Inject via q param"));
+        assert!(clone.notes.unwrap().contains("Variant of clone-project-VULN-001"));
+
+        let stored = manager.get_finding(&clone.id).unwrap().unwrap();
+        assert_eq!(stored.status, FindingStatus::Raw);
+        assert!(manager.clone_finding("missing", None).is_err());
+    }
+
+    #[test]
+    fn test_bulk_status_change_reports_per_id() {
+        let manager = test_manager();
+        manager
+            .create_project(&Project::new("bulk-project", "programs/bulk-project"))
+            .unwrap();
+        manager
+            .create_finding(&Finding::new("B-1", "bulk-project", "Open redirect"))
+            .unwrap();
+        manager
+            .create_finding(
+                &Finding::new("B-2", "bulk-project", "Self XSS")
+                    .with_status(FindingStatus::FalsePositive),
+            )
+            .unwrap();
+
+        let ids = ["B-1", "missing", "B-2"].map(String::from);
+        let results = manager.set_findings_status(&ids, FindingStatus::Verified);
+
+        assert!(results[0].1.is_ok());
+        assert!(results[1].1.is_err());
+        assert!(results[2].1.is_err());
+        let b1 = manager.get_finding("B-1").unwrap().unwrap();
+        assert_eq!(b1.status, FindingStatus::Verified);
+        let b2 = manager.get_finding("B-2").unwrap().unwrap();
+        assert_eq!(b2.status, FindingStatus::FalsePositive);
+
+        // Closed findings can still be reopened
+        let reopened = manager.set_findings_status(&ids[2..], FindingStatus::Raw);
+        assert!(reopened[0].1.is_ok());
+    }
+
+    #[test]
+    fn test_cvss_vector_roundtrips_through_db() {
+        let manager = test_manager();
+        let project = Project::new("cvss-project", "programs/cvss-project");
+        manager.create_project(&project).unwrap();
+
+        let finding = Finding::new("cvss-project-VULN-001", "cvss-project", "Stored XSS")
+            .with_cvss_vector("AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N")
+            .unwrap();
+        manager.create_finding(&finding).unwrap();
+
+        let stored = manager.get_finding(&finding.id).unwrap().unwrap();
+        assert_eq!(stored.cvss_score, Some(6.1));
+        assert_eq!(stored.severity, Some(Severity::Medium));
+        assert_eq!(
+            stored.cvss_vector.as_deref(),
+            Some("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N")
+        );
+    }
+
+    #[test]
+    fn test_manual_priority_roundtrips_through_db() {
+        let manager = test_manager();
+        let project = Project::new("pin-project", "programs/pin-project");
+        manager.create_project(&project).unwrap();
+
+        let finding = Finding::new("pin-project-VULN-001", "pin-project", "Open redirect")
+            .with_manual_priority(2);
+        manager.create_finding(&finding).unwrap();
+        let stored = manager.get_finding(&finding.id).unwrap().unwrap();
+        assert_eq!(stored.manual_priority, Some(2));
+
+        manager.set_finding_priority(&finding.id, None).unwrap();
+        let stored = manager.get_finding(&finding.id).unwrap().unwrap();
+        assert_eq!(stored.manual_priority, None);
+    }
+
+    #[test]
+    fn test_references_roundtrip_after_v7_migration() {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test_bugbounty.db");
+
+        // Roll a fresh database back to schema v6, with a finding stored before v7
+        {
+            let manager = BugBountyManager::with_path(&db_path).unwrap();
+            let project = Project::new("refs-project", "programs/refs-project");
+            manager.create_project(&project).unwrap();
+            let old = Finding::new("refs-project-VULN-001", "refs-project", "Old");
+            manager.create_finding(&old).unwrap();
+            manager
+                .db
+                .conn()
+                .execute_batch(
+                    "ALTER TABLE findings DROP COLUMN references_json;
+                     DELETE FROM bb_schema_version WHERE version = 7;",
+                )
+                .unwrap();
+        }
+
+        let manager = BugBountyManager::with_path(&db_path).unwrap();
+        let old = manager.get_finding("refs-project-VULN-001").unwrap();
+        assert!(old.unwrap().references.is_empty());
+
+        let references = [
+            "CVE-2024-1234",
+            "https://example.com/advisory",
+            "https://example.com/poc",
+        ];
+        let finding = Finding::new("refs-project-VULN-002", "refs-project", "SSRF")
+            .with_references(references);
+        manager.create_finding(&finding).unwrap();
+
+        let stored = manager.get_finding(&finding.id).unwrap().unwrap();
+        assert_eq!(stored.references, references);
+        let listed = manager.list_findings_by_project("refs-project").unwrap();
+        let listed = listed.iter().find(|f| f.id == finding.id).unwrap();
+        assert_eq!(listed.references, references);
+    }
+
+    #[test]
+    fn test_recompute_priority_prefers_flows_from_entry_points() {
+        let manager = test_manager();
+        let project = Project::new("reach-project", "programs/reach-project");
+        manager.create_project(&project).unwrap();
+        let entry_points = vec!["**/controllers/**".to_string()];
+
+        let external = Finding::new("reach-project-VULN-001", "reach-project", "SQLi in search")
+            .with_severity(Severity::Medium);
+        let internal = Finding::new("reach-project-VULN-002", "reach-project", "SQLi in cron")
+            .with_severity(Severity::High);
+        manager.create_finding(&external).unwrap();
+        manager.create_finding(&internal).unwrap();
+        manager
+            .create_flow_edge(&FlowEdge::taint(
+                &external.id,
+                CodeLocation::new("app/controllers/search.rb").with_line(12),
+                CodeLocation::new("app/models/query.rb").with_line(40),
+            ))
+            .unwrap();
+        manager
+            .create_flow_edge(&FlowEdge::taint(
+                &internal.id,
+                CodeLocation::new("app/jobs/cleanup.rb").with_line(3),
+                CodeLocation::new("app/models/query.rb").with_line(40),
+            ))
+            .unwrap();
+
+        let external_score = manager.recompute_priority(&external.id, &entry_points).unwrap();
+        let internal_score = manager.recompute_priority(&internal.id, &entry_points).unwrap();
+        assert!(external_score > internal_score);
+
+        let mut column = manager.list_findings_by_project("reach-project").unwrap();
+        assert!(column.iter().all(|f| f.computed_priority.is_some()));
+        column.sort_by_key(Finding::priority_key);
+        assert_eq!(column[0].id, external.id);
+        assert!(manager.recompute_priority("reach-project-VULN-404", &entry_points).is_err());
+    }
+
+    #[test]
+    fn test_link_and_unlink_job_are_idempotent() {
+        let manager = test_manager();
+        let project = Project::new("link-project", "programs/link-project");
+        manager.create_project(&project).unwrap();
+        let finding = Finding::new("link-project-VULN-001", "link-project", "SSRF");
+        manager.create_finding(&finding).unwrap();
+        manager.jobs().ensure_exists("42", Some("link-project")).unwrap();
+
+        assert!(manager.link_finding_job(&finding.id, "42", "verified").unwrap());
+        assert!(!manager.link_finding_job(&finding.id, "42", "related").unwrap());
+        assert_eq!(
+            manager.job_findings().list_jobs_for_finding(&finding.id).unwrap(),
+            vec!["42".to_string()]
+        );
+
+        assert!(manager.unlink_finding_job(&finding.id, "42").unwrap());
+        assert!(!manager.unlink_finding_job(&finding.id, "42").unwrap());
+        assert!(manager.job_findings().list_jobs_for_finding(&finding.id).unwrap().is_empty());
+
+        assert!(manager.link_finding_job("missing", "42", "related").is_err());
+
+        // Unknown jobs are reported, not created
+        assert!(manager.link_finding_job(&finding.id, "43", "related").is_err());
+        assert!(manager.jobs().get("43").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_merge_findings_into_moves_jobs_and_artifacts() {
+        let manager = test_manager();
+        let project = Project::new("dup-project", "programs/dup-project");
+        manager.create_project(&project).unwrap();
+        let canonical = Finding::new("dup-project-VULN-001", "dup-project", "IDOR in /api/users");
+        let dup = Finding::new("dup-project-VULN-002", "dup-project", "IDOR on user endpoint");
+        manager.create_finding(&canonical).unwrap();
+        manager.create_finding(&dup).unwrap();
+
+        manager.jobs().ensure_exists("7", Some("dup-project")).unwrap();
+        manager.jobs().ensure_exists("8", Some("dup-project")).unwrap();
+        manager.link_finding_job(&canonical.id, "7", "discovered").unwrap();
+        manager.link_finding_job(&dup.id, "7", "related").unwrap();
+        manager.link_finding_job(&dup.id, "8", "verified").unwrap();
+        let artifact =
+            Artifact::new("evidence/req.http", ArtifactType::HttpRequest).with_finding(&dup.id);
+        manager.create_artifact(&artifact).unwrap();
+
+        let merged = manager.merge_findings_into(&dup.id, &canonical.id).unwrap();
+        assert_eq!(
+            merged,
+            MergedFindings {
+                artifacts_moved: 1,
+                jobs_moved: 1,
+                duplicates_moved: 0,
+            }
+        );
+
+        let artifacts = manager.list_artifacts_by_finding(&canonical.id).unwrap();
+        assert_eq!(artifacts.len(), 1);
+        assert_eq!(artifacts[0].path, "evidence/req.http");
+        assert!(manager.list_artifacts_by_finding(&dup.id).unwrap().is_empty());
+
+        let mut jobs = manager.job_findings().list_jobs_for_finding(&canonical.id).unwrap();
+        jobs.sort();
+        assert_eq!(jobs, vec!["7".to_string(), "8".to_string()]);
+        assert!(manager.job_findings().list_jobs_for_finding(&dup.id).unwrap().is_empty());
+
+        let stored = manager.get_finding(&dup.id).unwrap().unwrap();
+        assert_eq!(stored.status, FindingStatus::Duplicate);
+        assert_eq!(stored.duplicate_of.as_deref(), Some(canonical.id.as_str()));
+
+        // Chains and self-references are rejected
+        assert!(manager.merge_findings_into(&canonical.id, &canonical.id).is_err());
+        let third = Finding::new("dup-project-VULN-003", "dup-project", "Another IDOR");
+        manager.create_finding(&third).unwrap();
+        assert!(manager.merge_findings_into(&third.id, &dup.id).is_err());
+    }
+
+    #[test]
+    fn test_merge_repoints_existing_duplicates() {
+        let manager = test_manager();
+        let project = Project::new("chain-project", "programs/chain-project");
+        manager.create_project(&project).unwrap();
+        let canonical = Finding::new("chain-project-VULN-001", "chain-project", "XSS in search");
+        let middle = Finding::new("chain-project-VULN-002", "chain-project", "XSS on /search");
+        let leaf = Finding::new("chain-project-VULN-003", "chain-project", "Reflected XSS");
+        for finding in [&canonical, &middle, &leaf] {
+            manager.create_finding(finding).unwrap();
+        }
+        manager.mark_finding_duplicate(&leaf.id, &middle.id).unwrap();
+
+        let merged = manager.merge_findings_into(&middle.id, &canonical.id).unwrap();
+        assert_eq!(merged.duplicates_moved, 1);
+
+        let leaf = manager.get_finding(&leaf.id).unwrap().unwrap();
+        assert_eq!(leaf.status, FindingStatus::Duplicate);
+        assert_eq!(leaf.duplicate_of.as_deref(), Some(canonical.id.as_str()));
+    }
+
+    #[test]
+    fn test_leaving_duplicate_clears_duplicate_of() {
+        let manager = test_manager();
+        let project = Project::new("reopen-project", "programs/reopen-project");
+        manager.create_project(&project).unwrap();
+        let canonical = Finding::new("reopen-project-VULN-001", "reopen-project", "SQLi");
+        let dup = Finding::new("reopen-project-VULN-002", "reopen-project", "SQLi again");
+        manager.create_finding(&canonical).unwrap();
+        manager.create_finding(&dup).unwrap();
+        manager.mark_finding_duplicate(&dup.id, &canonical.id).unwrap();
+
+        manager.set_finding_status(&dup.id, FindingStatus::Raw).unwrap();
+        let stored = manager.get_finding(&dup.id).unwrap().unwrap();
+        assert_eq!(stored.status, FindingStatus::Raw);
+        assert_eq!(stored.duplicate_of, None);
+
+        // The reopened finding can now be used as a canonical target
+        manager.mark_finding_duplicate(&canonical.id, &dup.id).unwrap();
+    }
+
+    #[test]
+    fn test_verify_artifacts_records_size_and_reports_missing_files() {
+        let dir = tempdir().unwrap();
+        let manager = BugBountyManager::with_path(&dir.path().join("test_bugbounty.db")).unwrap();
+        let root = dir.path().join("programs/evidence-project");
+        std::fs::create_dir_all(root.join("evidence")).unwrap();
+        std::fs::write(root.join("evidence/req.http"), "GET / HTTP/1.1\n").unwrap();
+
+        let project = Project::new("evidence-project", root.to_string_lossy().to_string());
+        manager.create_project(&project).unwrap();
+        let finding = Finding::new("evidence-project-VULN-001", "evidence-project", "SSRF");
+        manager.create_finding(&finding).unwrap();
+        let present =
+            Artifact::new("evidence/req.http", ArtifactType::HttpRequest).with_finding(&finding.id);
+        let missing = Artifact::new("evidence/gone.png", ArtifactType::Screenshot)
+            .with_finding(&finding.id)
+            .with_hash("abc123");
+        manager.create_artifact(&present).unwrap();
+        manager.create_artifact(&missing).unwrap();
+
+        let artifacts = manager.artifacts().list_by_project("evidence-project").unwrap();
+        assert_eq!(artifacts.len(), 2);
+        let checks: Vec<ArtifactCheck> = artifacts
+            .iter()
+            .map(|a| manager.artifacts().verify(a, &root).unwrap())
+            .collect();
+
+        let ok = checks.iter().find(|c| c.artifact.id == present.id).unwrap();
+        assert!(ok.exists);
+        assert_eq!(ok.resolved_path, root.join("evidence/req.http"));
+        let stored = manager.artifacts().get(&present.id).unwrap().unwrap();
+        assert_eq!(stored.size_bytes, Some(15));
+        assert_eq!(stored.hash, ok.artifact.hash);
+
+        let dangling = checks.iter().find(|c| c.artifact.id == missing.id).unwrap();
+        assert!(!dangling.exists);
+        let stored = manager.artifacts().get(&missing.id).unwrap().unwrap();
+        assert_eq!(stored.size_bytes, None);
+        assert_eq!(stored.hash.as_deref(), Some("abc123"));
+    }
+
     #[test]
     fn test_process_next_context_upsert_preserves_status() {
         let dir = tempdir().unwrap();
@@ -676,7 +1230,9 @@ mod tests {
                 confidence: Some("high".to_string()),
                 cwe_id: Some("CWE-123".to_string()),
                 cvss_score: None,
+                cvss_vector: None,
                 affected_assets: vec!["src/main.rs:1".to_string()],
+                references: Vec::new(),
                 taint_path: Some("a -> b".to_string()),
             }],
             flow_edges: vec![FlowEdgeOutput {
@@ -868,4 +1424,78 @@ mod tests {
         assert_eq!(trace.edges.len(), 1);
         assert!(trace.summary().contains("handler.rs"));
     }
+
+    #[test]
+    fn test_search_findings_matches_text_fields_and_scopes_by_project() {
+        let manager = test_manager();
+        manager.create_project(&Project::new("alpha", "/alpha")).unwrap();
+        manager.create_project(&Project::new("beta", "/beta")).unwrap();
+
+        manager
+            .create_finding(&Finding::new("alpha-VULN-001", "alpha", "SQL injection in search"))
+            .unwrap();
+        manager
+            .create_finding(
+                &Finding::new("alpha-VULN-002", "alpha", "IDOR on invoices")
+                    .with_attack_scenario("Swap the invoice_id to read another tenant's PDF"),
+            )
+            .unwrap();
+        let mut noted = Finding::new("beta-VULN-001", "beta", "Open redirect")
+            .with_impact("Phishing via trusted INVOICE links");
+        noted.notes = Some("100% reproducible".to_string());
+        manager.create_finding(&noted).unwrap();
+
+        let ids = |findings: Vec<Finding>| {
+            let mut ids: Vec<String> = findings.into_iter().map(|f| f.id).collect();
+            ids.sort();
+            ids
+        };
+
+        // Case-insensitive across title, attack scenario and impact
+        assert_eq!(
+            ids(manager.search_findings("invoice", None).unwrap()),
+            vec!["alpha-VULN-002", "beta-VULN-001"]
+        );
+        assert_eq!(
+            ids(manager.search_findings("invoice", Some("alpha")).unwrap()),
+            vec!["alpha-VULN-002"]
+        );
+        assert_eq!(
+            ids(manager.search_findings("sql", None).unwrap()),
+            vec!["alpha-VULN-001"]
+        );
+
+        // Notes are searched and LIKE wildcards match literally
+        assert_eq!(
+            ids(manager.search_findings("100%", None).unwrap()),
+            vec!["beta-VULN-001"]
+        );
+        assert!(manager.search_findings("invoice%tenant", None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_memory_without_line_dedupes_by_normalized_text() {
+        let manager = test_manager();
+        let project = Project::new("mem-project", "/tmp/mem-project");
+        manager.create_project(&project).unwrap();
+
+        let note =
+            ProjectMemory::note("mem-project", MemorySourceKind::Manual, "Admin API is internal.");
+        assert!(!manager.memory().exists_duplicate(&note).unwrap());
+        manager.memory().create(&note).unwrap();
+
+        let same =
+            ProjectMemory::note("mem-project", MemorySourceKind::Agent, "admin api  is internal");
+        assert!(manager.memory().exists_duplicate(&same).unwrap());
+
+        // Same text attached to a file is a different entry
+        let at_file = same.clone().with_file("src/admin.rs");
+        assert!(!manager.memory().exists_duplicate(&at_file).unwrap());
+        manager.memory().create(&at_file).unwrap();
+        assert!(manager.memory().exists_duplicate(&at_file).unwrap());
+
+        let other =
+            ProjectMemory::note("mem-project", MemorySourceKind::Manual, "Admin API is public");
+        assert!(!manager.memory().exists_duplicate(&other).unwrap());
+    }
 }
@@ -79,6 +79,9 @@ pub struct Artifact {
     pub description: Option<String>,
     /// SHA256 hash for deduplication
     pub hash: Option<String>,
+    /// File size in bytes, recorded when the file was last verified on disk
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<u64>,
     /// Created timestamp (ms since epoch)
     pub created_at: i64,
 }
@@ -94,6 +97,7 @@ impl Artifact {
             path: path.into(),
             description: None,
             hash: None,
+            size_bytes: None,
             created_at: chrono::Utc::now().timestamp_millis(),
         }
     }
@@ -129,6 +133,16 @@ impl Artifact {
         self.hash = Some(hash.into());
         self
     }
+
+    /// Resolve the artifact path against the project root (absolute paths are kept)
+    pub fn resolve_path(&self, project_root: &std::path::Path) -> std::path::PathBuf {
+        let path = std::path::Path::new(&self.path);
+        if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            project_root.join(path)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,239 @@
+//! CVSS v3.x base vector parsing and scoring
+
+use anyhow::{Result, bail};
+
+use super::finding::Severity;
+
+/// Temporal and environmental metrics: accepted in pasted vectors but not scored
+const IGNORED_METRICS: &[&str] = &[
+    "E", "RL", "RC", "CR", "IR", "AR", "MAV", "MAC", "MPR", "MUI", "MS", "MC", "MI", "MA",
+];
+
+/// A parsed CVSS v3.x base vector (AV/AC/PR/UI/S/C/I/A)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CvssVector {
+    pub attack_vector: char,
+    pub attack_complexity: char,
+    pub privileges_required: char,
+    pub user_interaction: char,
+    pub scope: char,
+    pub confidentiality: char,
+    pub integrity: char,
+    pub availability: char,
+}
+
+impl CvssVector {
+    /// Parse a vector like `CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H`.
+    ///
+    /// The `CVSS:3.x/` prefix is optional; all eight base metrics are required.
+    pub fn parse(vector: &str) -> Result<Self> {
+        let vector = vector.trim();
+        let mut parts = vector.split('/').peekable();
+        if let Some(prefix) = parts.peek().copied().and_then(|p| p.strip_prefix("CVSS:")) {
+            if prefix != "3.0" && prefix != "3.1" {
+                bail!(
+                    "Unsupported CVSS version '{}' (expected 3.0 or 3.1)",
+                    prefix
+                );
+            }
+            parts.next();
+        }
+
+        let mut metrics: [Option<char>; 8] = [None; 8];
+        for part in parts {
+            let Some((key, value)) = part.split_once(':') else {
+                bail!("Invalid CVSS metric '{}' in '{}'", part, vector);
+            };
+            if IGNORED_METRICS.contains(&key) {
+                continue;
+            }
+            let (slot, allowed) = match key {
+                "AV" => (0, "NALP"),
+                "AC" => (1, "LH"),
+                "PR" => (2, "NLH"),
+                "UI" => (3, "NR"),
+                "S" => (4, "UC"),
+                "C" => (5, "HLN"),
+                "I" => (6, "HLN"),
+                "A" => (7, "HLN"),
+                _ => bail!("Unknown CVSS metric '{}' in '{}'", key, vector),
+            };
+            let mut chars = value.chars();
+            let value = match (chars.next(), chars.next()) {
+                (Some(c), None) if allowed.contains(c) => c,
+                _ => bail!("Invalid value '{}' for CVSS metric {}", value, key),
+            };
+            if metrics[slot].replace(value).is_some() {
+                bail!("Duplicate CVSS metric {} in '{}'", key, vector);
+            }
+        }
+
+        let names = ["AV", "AC", "PR", "UI", "S", "C", "I", "A"];
+        let missing: Vec<&str> = names
+            .iter()
+            .zip(&metrics)
+            .filter(|(_, m)| m.is_none())
+            .map(|(n, _)| *n)
+            .collect();
+        if !missing.is_empty() {
+            bail!(
+                "CVSS vector '{}' is missing base metrics: {}",
+                vector,
+                missing.join(", ")
+            );
+        }
+
+        let m = metrics.map(|m| m.unwrap_or_default());
+        Ok(Self {
+            attack_vector: m[0],
+            attack_complexity: m[1],
+            privileges_required: m[2],
+            user_interaction: m[3],
+            scope: m[4],
+            confidentiality: m[5],
+            integrity: m[6],
+            availability: m[7],
+        })
+    }
+
+    /// CVSS v3.1 base score (0.0 - 10.0)
+    pub fn base_score(&self) -> f64 {
+        let changed = self.scope == 'C';
+        let cia = |c: char| match c {
+            'H' => 0.56,
+            'L' => 0.22,
+            _ => 0.0,
+        };
+        let av = match self.attack_vector {
+            'N' => 0.85,
+            'A' => 0.62,
+            'L' => 0.55,
+            _ => 0.2,
+        };
+        let ac = if self.attack_complexity == 'L' {
+            0.77
+        } else {
+            0.44
+        };
+        let pr = match (self.privileges_required, changed) {
+            ('N', _) => 0.85,
+            ('L', false) => 0.62,
+            ('L', true) => 0.68,
+            (_, false) => 0.27,
+            (_, true) => 0.5,
+        };
+        let ui = if self.user_interaction == 'N' {
+            0.85
+        } else {
+            0.62
+        };
+
+        let iss = 1.0
+            - (1.0 - cia(self.confidentiality))
+                * (1.0 - cia(self.integrity))
+                * (1.0 - cia(self.availability));
+        let impact = if changed {
+            7.52 * (iss - 0.029) - 3.25 * (iss - 0.02).powi(15)
+        } else {
+            6.42 * iss
+        };
+        if impact <= 0.0 {
+            return 0.0;
+        }
+
+        let exploitability = 8.22 * av * ac * pr * ui;
+        if changed {
+            round_up((1.08 * (impact + exploitability)).min(10.0))
+        } else {
+            round_up((impact + exploitability).min(10.0))
+        }
+    }
+
+    /// Qualitative severity for the base score (0.0 maps to Info)
+    pub fn severity(&self) -> Severity {
+        severity_for_score(self.base_score())
+    }
+}
+
+impl std::fmt::Display for CvssVector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CVSS:3.1/AV:{}/AC:{}/PR:{}/UI:{}/S:{}/C:{}/I:{}/A:{}",
+            self.attack_vector,
+            self.attack_complexity,
+            self.privileges_required,
+            self.user_interaction,
+            self.scope,
+            self.confidentiality,
+            self.integrity,
+            self.availability
+        )
+    }
+}
+
+/// Map a CVSS score to the qualitative severity rating scale
+pub fn severity_for_score(score: f64) -> Severity {
+    if score >= 9.0 {
+        Severity::Critical
+    } else if score >= 7.0 {
+        Severity::High
+    } else if score >= 4.0 {
+        Severity::Medium
+    } else if score > 0.0 {
+        Severity::Low
+    } else {
+        Severity::Info
+    }
+}
+
+/// CVSS v3.1 Roundup: smallest one-decimal value >= input, robust to float error
+fn round_up(value: f64) -> f64 {
+    let scaled = (value * 100_000.0).round() as i64;
+    if scaled % 10_000 == 0 {
+        scaled as f64 / 100_000.0
+    } else {
+        ((scaled / 10_000) + 1) as f64 / 10.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(vector: &str) -> f64 {
+        CvssVector::parse(vector)
+            .expect("valid vector")
+            .base_score()
+    }
+
+    #[test]
+    fn test_base_scores_match_reference_calculator() {
+        assert_eq!(score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H"), 10.0);
+        assert_eq!(score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"), 9.8);
+        assert_eq!(score("AV:N/AC:L/PR:L/UI:N/S:U/C:H/I:N/A:N"), 6.5);
+        assert_eq!(score("CVSS:3.0/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N"), 6.1);
+        assert_eq!(score("AV:P/AC:H/PR:H/UI:R/S:U/C:L/I:N/A:N"), 1.6);
+        assert_eq!(score("AV:L/AC:L/PR:L/UI:N/S:U/C:N/I:N/A:N"), 0.0);
+    }
+
+    #[test]
+    fn test_severity_and_normalized_display() {
+        let vector = CvssVector::parse("A:N/I:L/C:L/S:C/UI:R/PR:N/AC:L/AV:N/E:P").unwrap();
+        assert_eq!(vector.severity(), Severity::Medium);
+        assert_eq!(
+            vector.to_string(),
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N"
+        );
+    }
+
+    #[test]
+    fn test_invalid_vectors_are_rejected() {
+        assert!(CvssVector::parse("CVSS:2.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").is_err());
+        assert!(CvssVector::parse("AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H").is_err());
+        assert!(CvssVector::parse("AV:X/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").is_err());
+        assert!(CvssVector::parse("AV:N/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").is_err());
+        assert!(CvssVector::parse("AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/FOO:1").is_err());
+        assert!(CvssVector::parse("").is_err());
+    }
+}
@@ -2,6 +2,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::cvss::CvssVector;
+use super::flow_edge::FlowTrace;
+
 /// Severity levels for findings
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -14,6 +17,15 @@ pub enum Severity {
 }
 
 impl Severity {
+    /// All severities, most severe first
+    pub const ALL: [Severity; 5] = [
+        Severity::Critical,
+        Severity::High,
+        Severity::Medium,
+        Severity::Low,
+        Severity::Info,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Severity::Critical => "critical",
@@ -34,6 +46,17 @@ impl Severity {
             _ => None,
         }
     }
+
+    /// Sort rank (0 = most severe)
+    pub fn rank(&self) -> u8 {
+        match self {
+            Severity::Critical => 0,
+            Severity::High => 1,
+            Severity::Medium => 2,
+            Severity::Low => 3,
+            Severity::Info => 4,
+        }
+    }
 }
 
 /// Finding status - represents Kanban columns
@@ -67,6 +90,22 @@ pub enum FindingStatus {
 }
 
 impl FindingStatus {
+    /// All statuses in Kanban column order
+    pub const ALL: [FindingStatus; 12] = [
+        FindingStatus::Raw,
+        FindingStatus::NeedsRepro,
+        FindingStatus::Verified,
+        FindingStatus::ReportDraft,
+        FindingStatus::Submitted,
+        FindingStatus::Triaged,
+        FindingStatus::Accepted,
+        FindingStatus::Paid,
+        FindingStatus::Duplicate,
+        FindingStatus::WontFix,
+        FindingStatus::FalsePositive,
+        FindingStatus::OutOfScope,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             FindingStatus::Raw => "raw",
@@ -114,6 +153,13 @@ impl FindingStatus {
         )
     }
 
+    /// Whether a finding may move from this status to `next`.
+    ///
+    /// Terminal findings are closed: they can only be reopened as `raw`.
+    pub fn can_transition_to(&self, next: FindingStatus) -> bool {
+        !self.is_terminal() || next == FindingStatus::Raw
+    }
+
     /// Returns true if this finding is actionable (needs attention)
     pub fn is_actionable(&self) -> bool {
         matches!(
@@ -217,6 +263,14 @@ pub struct Finding {
     pub severity: Option<Severity>,
     /// Current status (Kanban column)
     pub status: FindingStatus,
+    /// Analyst-set priority; when set it pins the card above severity ordering
+    /// (lower values sort first)
+    #[serde(default)]
+    pub manual_priority: Option<i32>,
+    /// Triage score derived from the flow trace and reachability (higher sorts
+    /// first); set by `BugBountyManager::recompute_priority`
+    #[serde(default)]
+    pub computed_priority: Option<i32>,
 
     // Structured output fields (from security-audit profile)
     /// How an attacker would exploit this
@@ -235,14 +289,23 @@ pub struct Finding {
     pub cwe_id: Option<String>,
     /// CVSS score if calculated
     pub cvss_score: Option<f64>,
+    /// Normalized CVSS v3.1 vector (the score is derived from it when set)
+    #[serde(default)]
+    pub cvss_vector: Option<String>,
     /// Affected assets (endpoints, domains, modules)
     pub affected_assets: Vec<String>,
+    /// External references: CVE IDs, advisories, PoC URLs
+    #[serde(default)]
+    pub references: Vec<String>,
     /// Taint path: Entry -> ... -> Sink
     pub taint_path: Option<String>,
 
     // Metadata
     /// Reason if marked false positive
     pub fp_reason: Option<String>,
+    /// Canonical finding this one duplicates (set with status Duplicate)
+    #[serde(default)]
+    pub duplicate_of: Option<String>,
     /// Additional notes
     pub notes: Option<String>,
     /// Path to source file (notes/findings/VULN-XXX.md)
@@ -268,6 +331,8 @@ impl Finding {
             title: title.into(),
             severity: None,
             status: FindingStatus::Raw,
+            manual_priority: None,
+            computed_priority: None,
             attack_scenario: None,
             preconditions: None,
             reachability: None,
@@ -275,9 +340,12 @@ impl Finding {
             confidence: None,
             cwe_id: None,
             cvss_score: None,
+            cvss_vector: None,
             affected_assets: Vec::new(),
+            references: Vec::new(),
             taint_path: None,
             fp_reason: None,
+            duplicate_of: None,
             notes: None,
             source_file: None,
             created_at: now,
@@ -290,6 +358,61 @@ impl Finding {
         format!("{project_id}-VULN-{:03}", number)
     }
 
+    /// Sort key for ordering cards within a Kanban column.
+    ///
+    /// Pinned findings (`manual_priority`) come first, then the highest
+    /// `computed_priority` (never computed last), then severity (Critical to Info,
+    /// unset last), then most recently updated.
+    #[allow(clippy::type_complexity)]
+    pub fn priority_key(
+        &self,
+    ) -> (
+        bool,
+        i32,
+        std::cmp::Reverse<Option<i32>>,
+        u8,
+        std::cmp::Reverse<i64>,
+    ) {
+        (
+            self.manual_priority.is_none(),
+            self.manual_priority.unwrap_or(0),
+            std::cmp::Reverse(self.computed_priority),
+            self.severity.map(|s| s.rank()).unwrap_or(u8::MAX),
+            std::cmp::Reverse(self.updated_at),
+        )
+    }
+
+    /// Compute the triage score used for `computed_priority` (higher = write up first).
+    ///
+    /// Exposure dominates: a trace whose source node lies in a file matching one of
+    /// `entry_points` (globs relative to the project root) is externally reachable,
+    /// a trace that only starts in other files is an internal flow. Without a trace
+    /// the analyst's `reachability` is all there is to go on. Severity breaks ties
+    /// within the same exposure level.
+    pub fn reachability_priority(&self, trace: &FlowTrace, entry_points: &[String]) -> i32 {
+        let patterns: Vec<glob::Pattern> = entry_points
+            .iter()
+            .filter_map(|p| glob::Pattern::new(p).ok())
+            .collect();
+        let external_source = trace.sources().iter().any(|source| {
+            let file = source.file.replace('\\', "/");
+            let file = file.trim_start_matches("./");
+            patterns.iter().any(|p| p.matches(file))
+        });
+
+        let exposure = match (external_source, self.reachability) {
+            (true, Some(Reachability::AuthRequired)) => 2,
+            (true, _) => 3,
+            (false, Some(Reachability::Public)) => 2,
+            (false, Some(Reachability::InternalOnly)) => 0,
+            (false, Some(Reachability::AuthRequired)) => 1,
+            (false, _) if trace.edges.is_empty() => 1,
+            (false, _) => 0,
+        };
+        let severity = self.severity.map(|s| 5 - i32::from(s.rank())).unwrap_or(0);
+        exposure * 10 + severity
+    }
+
     // Builder methods
     pub fn with_severity(mut self, severity: Severity) -> Self {
         self.severity = Some(severity);
@@ -301,6 +424,11 @@ impl Finding {
         self
     }
 
+    pub fn with_manual_priority(mut self, priority: i32) -> Self {
+        self.manual_priority = Some(priority);
+        self
+    }
+
     pub fn with_attack_scenario(mut self, scenario: impl Into<String>) -> Self {
         self.attack_scenario = Some(scenario.into());
         self
@@ -326,11 +454,46 @@ impl Finding {
         self
     }
 
+    /// Set the CVSS vector and derive `cvss_score` from it.
+    ///
+    /// Severity is filled in from the score only when not already set.
+    pub fn with_cvss_vector(mut self, vector: &str) -> anyhow::Result<Self> {
+        let parsed = CvssVector::parse(vector)?;
+        self.cvss_score = Some(parsed.base_score());
+        self.cvss_vector = Some(parsed.to_string());
+        if self.severity.is_none() {
+            self.severity = Some(parsed.severity());
+        }
+        Ok(self)
+    }
+
+    /// Parsed CVSS vector, if one is stored and valid
+    pub fn cvss(&self) -> Option<CvssVector> {
+        self.cvss_vector
+            .as_deref()
+            .and_then(|v| CvssVector::parse(v).ok())
+    }
+
     pub fn with_affected_asset(mut self, asset: impl Into<String>) -> Self {
         self.affected_assets.push(asset.into());
         self
     }
 
+    /// Add external references (CVE IDs, advisory or PoC URLs), skipping blanks and duplicates
+    pub fn with_references<I, S>(mut self, references: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        for reference in references {
+            let reference = reference.into().trim().to_string();
+            if !reference.is_empty() && !self.references.contains(&reference) {
+                self.references.push(reference);
+            }
+        }
+        self
+    }
+
     pub fn with_taint_path(mut self, taint_path: impl Into<String>) -> Self {
         self.taint_path = Some(taint_path.into());
         self
@@ -342,6 +505,26 @@ impl Finding {
     }
 }
 
+/// Link target for a finding reference: URLs as-is, CVE and GHSA IDs resolved to
+/// their public advisory pages, anything else `None`.
+pub fn reference_url(reference: &str) -> Option<String> {
+    let reference = reference.trim();
+    if reference.starts_with("https://") || reference.starts_with("http://") {
+        return Some(reference.to_string());
+    }
+    let upper = reference.to_ascii_uppercase();
+    if upper.starts_with("CVE-") {
+        Some(format!("https://nvd.nist.gov/vuln/detail/{upper}"))
+    } else if upper.starts_with("GHSA-") {
+        Some(format!(
+            "https://github.com/advisories/{}",
+            reference.to_ascii_lowercase()
+        ))
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -360,6 +543,128 @@ mod tests {
         assert_eq!(finding.cwe_id, Some("CWE-639".to_string()));
     }
 
+    #[test]
+    fn test_with_references_skips_blanks_and_duplicates() {
+        let finding = Finding::new("VULN-001", "proj", "RCE")
+            .with_references(["CVE-2021-44228", " ", "https://example.com/advisory"])
+            .with_references(vec!["CVE-2021-44228".to_string()]);
+        assert_eq!(
+            finding.references,
+            vec!["CVE-2021-44228", "https://example.com/advisory"]
+        );
+    }
+
+    #[test]
+    fn test_reference_url_resolves_advisory_ids() {
+        assert_eq!(
+            reference_url("cve-2021-44228").as_deref(),
+            Some("https://nvd.nist.gov/vuln/detail/CVE-2021-44228")
+        );
+        assert_eq!(
+            reference_url("GHSA-jfh8-c2jp-5v3q").as_deref(),
+            Some("https://github.com/advisories/ghsa-jfh8-c2jp-5v3q")
+        );
+        assert_eq!(
+            reference_url("https://example.com/poc").as_deref(),
+            Some("https://example.com/poc")
+        );
+        assert_eq!(reference_url("internal ticket 42"), None);
+    }
+
+    #[test]
+    fn test_references_default_when_missing_from_json() {
+        let mut value = serde_json::to_value(Finding::new("VULN-001", "proj", "t")).unwrap();
+        value.as_object_mut().unwrap().remove("references");
+        let finding: Finding = serde_json::from_value(value).unwrap();
+        assert!(finding.references.is_empty());
+    }
+
+    #[test]
+    fn test_priority_key_orders_pinned_then_severity_then_recency() {
+        let mut low = Finding::new("VULN-001", "proj", "low").with_severity(Severity::Low);
+        low.updated_at = 100;
+        let mut critical_old =
+            Finding::new("VULN-002", "proj", "crit old").with_severity(Severity::Critical);
+        critical_old.updated_at = 100;
+        let mut critical_new =
+            Finding::new("VULN-003", "proj", "crit new").with_severity(Severity::Critical);
+        critical_new.updated_at = 200;
+        let mut unrated = Finding::new("VULN-004", "proj", "unrated");
+        unrated.updated_at = 300;
+        let mut pinned = Finding::new("VULN-005", "proj", "pinned")
+            .with_severity(Severity::Info)
+            .with_manual_priority(1);
+        pinned.updated_at = 0;
+
+        let mut column = vec![low, unrated, critical_old, pinned, critical_new];
+        column.sort_by_key(Finding::priority_key);
+
+        let ids: Vec<&str> = column.iter().map(|f| f.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec!["VULN-005", "VULN-003", "VULN-002", "VULN-001", "VULN-004"]
+        );
+    }
+
+    #[test]
+    fn test_reachability_priority_ranks_entry_point_flows_above_internal_ones() {
+        use crate::bugbounty::models::flow_edge::{CodeLocation, FlowEdge};
+
+        let entry_points = vec!["**/routes/**".to_string()];
+        let trace_from = |file: &str| {
+            let mut trace = FlowTrace::new("VULN-001");
+            trace.add_edge(FlowEdge::taint(
+                "VULN-001",
+                CodeLocation::new(file).with_line(3),
+                CodeLocation::new("src/db.rs").with_line(9),
+            ));
+            trace
+        };
+        let low = Finding::new("VULN-001", "proj", "sqli").with_severity(Severity::Low);
+        let critical = Finding::new("VULN-002", "proj", "rce").with_severity(Severity::Critical);
+
+        let external_low =
+            low.reachability_priority(&trace_from("./src/routes/users.rs"), &entry_points);
+        let internal_critical =
+            critical.reachability_priority(&trace_from("src/jobs/cron.rs"), &entry_points);
+        let untraced_critical =
+            critical.reachability_priority(&FlowTrace::new("VULN-002"), &entry_points);
+
+        assert!(external_low > untraced_critical);
+        assert!(untraced_critical > internal_critical);
+        assert!(
+            critical.reachability_priority(&trace_from("src/routes/a.rs"), &entry_points)
+                > external_low
+        );
+    }
+
+    #[test]
+    fn test_with_cvss_vector_derives_score() {
+        let finding = Finding::new("VULN-002", "proj", "RCE")
+            .with_cvss_vector("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H")
+            .unwrap();
+        assert_eq!(finding.cvss_score, Some(9.8));
+        assert_eq!(finding.severity, Some(Severity::Critical));
+        assert_eq!(
+            finding.cvss_vector.as_deref(),
+            Some("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H")
+        );
+
+        // An explicit severity is kept
+        let finding = Finding::new("VULN-003", "proj", "XSS")
+            .with_severity(Severity::High)
+            .with_cvss_vector("AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N")
+            .unwrap();
+        assert_eq!(finding.cvss_score, Some(6.1));
+        assert_eq!(finding.severity, Some(Severity::High));
+
+        assert!(
+            Finding::new("VULN-004", "proj", "bad")
+                .with_cvss_vector("AV:N/AC:L")
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_status_parsing() {
         assert_eq!(FindingStatus::from_str("raw"), Some(FindingStatus::Raw));
@@ -371,6 +676,19 @@ mod tests {
         assert_eq!(FindingStatus::from_str("invalid"), None);
     }
 
+    #[test]
+    fn test_all_variants_round_trip() {
+        for status in FindingStatus::ALL {
+            assert_eq!(FindingStatus::from_str(status.as_str()), Some(status));
+        }
+        for (idx, status) in FindingStatus::ALL.iter().enumerate() {
+            assert_eq!(status.column_index() as usize, idx);
+        }
+        for severity in Severity::ALL {
+            assert_eq!(Severity::from_str(severity.as_str()), Some(severity));
+        }
+    }
+
     #[test]
     fn test_terminal_status() {
         assert!(FindingStatus::Paid.is_terminal());
@@ -378,4 +696,12 @@ mod tests {
         assert!(!FindingStatus::Raw.is_terminal());
         assert!(!FindingStatus::Verified.is_terminal());
     }
+
+    #[test]
+    fn test_terminal_status_only_reopens_to_raw() {
+        assert!(FindingStatus::Raw.can_transition_to(FindingStatus::Verified));
+        assert!(FindingStatus::Verified.can_transition_to(FindingStatus::WontFix));
+        assert!(!FindingStatus::Paid.can_transition_to(FindingStatus::Verified));
+        assert!(FindingStatus::FalsePositive.can_transition_to(FindingStatus::Raw));
+    }
 }
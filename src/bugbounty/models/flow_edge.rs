@@ -212,6 +212,127 @@ impl FlowTrace {
             format!("{} -> ... ({} hops) -> {}", entry, self.edges.len(), sink)
         }
     }
+
+    /// Whether the edges form a cycle (e.g. a recursive call path)
+    pub fn has_cycle(&self) -> bool {
+        self.topological_order().is_none()
+    }
+
+    /// Locations in dependency order (sources before sinks), or None if cyclic.
+    ///
+    /// Locations are identified by (file, line, symbol); ties keep first-seen order.
+    pub fn topological_order(&self) -> Option<Vec<CodeLocation>> {
+        let (nodes, adjacency) = self.graph();
+        let mut in_degree = vec![0usize; nodes.len()];
+        for targets in &adjacency {
+            for &to in targets {
+                in_degree[to] += 1;
+            }
+        }
+
+        let mut ready: std::collections::VecDeque<usize> =
+            (0..nodes.len()).filter(|&n| in_degree[n] == 0).collect();
+        let mut order = Vec::with_capacity(nodes.len());
+        while let Some(node) = ready.pop_front() {
+            order.push(nodes[node].clone());
+            for &to in &adjacency[node] {
+                in_degree[to] -= 1;
+                if in_degree[to] == 0 {
+                    ready.push_back(to);
+                }
+            }
+        }
+
+        (order.len() == nodes.len()).then_some(order)
+    }
+
+    /// Locations with no incoming edge, i.e. where data enters the traced flow.
+    ///
+    /// A trace that is one big cycle has no such node; its first edge's source is
+    /// used instead.
+    pub fn sources(&self) -> Vec<&CodeLocation> {
+        let (nodes, adjacency) = self.graph();
+        let mut has_incoming = vec![false; nodes.len()];
+        for &to in adjacency.iter().flatten() {
+            has_incoming[to] = true;
+        }
+
+        let sources: Vec<&CodeLocation> = nodes
+            .iter()
+            .zip(&has_incoming)
+            .filter(|(_, incoming)| !**incoming)
+            .map(|(node, _)| *node)
+            .collect();
+        if sources.is_empty() {
+            self.entry_point().into_iter().collect()
+        } else {
+            sources
+        }
+    }
+
+    /// Indices into `edges` of the edges that lie on a cycle
+    pub fn cycle_edges(&self) -> Vec<usize> {
+        let (nodes, adjacency) = self.graph();
+        let index_of = |loc: &CodeLocation| nodes.iter().position(|n| same_node(n, loc));
+
+        // An edge from -> to is on a cycle iff `from` is reachable from `to`
+        let reaches = |start: usize, goal: usize| {
+            let mut seen = vec![false; nodes.len()];
+            let mut stack = vec![start];
+            while let Some(node) = stack.pop() {
+                if node == goal {
+                    return true;
+                }
+                if !std::mem::replace(&mut seen[node], true) {
+                    stack.extend(&adjacency[node]);
+                }
+            }
+            false
+        };
+
+        self.edges
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| match (index_of(&e.from), index_of(&e.to)) {
+                (Some(from), Some(to)) => reaches(to, from),
+                _ => false,
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Distinct locations (first-seen order) and their outgoing adjacency lists
+    fn graph(&self) -> (Vec<&CodeLocation>, Vec<Vec<usize>>) {
+        fn intern<'a>(
+            nodes: &mut Vec<&'a CodeLocation>,
+            adjacency: &mut Vec<Vec<usize>>,
+            loc: &'a CodeLocation,
+        ) -> usize {
+            if let Some(i) = nodes.iter().position(|n| same_node(n, loc)) {
+                return i;
+            }
+            nodes.push(loc);
+            adjacency.push(Vec::new());
+            nodes.len() - 1
+        }
+
+        let mut nodes = Vec::new();
+        let mut adjacency = Vec::new();
+        for edge in &self.edges {
+            let from = intern(&mut nodes, &mut adjacency, &edge.from);
+            let to = intern(&mut nodes, &mut adjacency, &edge.to);
+            if !adjacency[from].contains(&to) {
+                adjacency[from].push(to);
+            }
+        }
+
+        (nodes, adjacency)
+    }
+}
+
+/// Graph identity of a location: (file, line, symbol); column and snippet are ignored
+fn same_node(a: &CodeLocation, b: &CodeLocation) -> bool {
+    a.file == b.file && a.line == b.line && a.symbol == b.symbol
 }
 
 #[cfg(test)]
@@ -252,4 +373,48 @@ mod tests {
         assert_eq!(trace.sink().unwrap().file, "src/db.rs");
         assert!(trace.summary().contains("2 hops"));
     }
+
+    #[test]
+    fn test_flow_trace_acyclic_topological_order() {
+        let mut trace = FlowTrace::new("VULN-001");
+        let input = CodeLocation::new("src/routes.rs").with_line(10);
+        let check = CodeLocation::new("src/auth.rs").with_line(5);
+        let service = CodeLocation::new("src/service.rs").with_line(20);
+        let sink = CodeLocation::new("src/db.rs").with_line(30).with_symbol("query");
+
+        // Listed out of order on purpose; columns do not split nodes
+        trace.add_edge(FlowEdge::taint("VULN-001", service.clone(), sink.clone()));
+        trace.add_edge(FlowEdge::taint("VULN-001", input.clone(), service.clone().with_column(4)));
+        trace.add_edge(FlowEdge::authz("VULN-001", input.clone(), check.clone()));
+        trace.add_edge(FlowEdge::authz("VULN-001", check, service));
+
+        assert!(!trace.has_cycle());
+        assert!(trace.cycle_edges().is_empty());
+        let order: Vec<String> = trace
+            .topological_order()
+            .unwrap()
+            .iter()
+            .map(|l| l.file.clone())
+            .collect();
+        assert_eq!(
+            order,
+            vec!["src/routes.rs", "src/auth.rs", "src/service.rs", "src/db.rs"]
+        );
+    }
+
+    #[test]
+    fn test_flow_trace_detects_recursive_cycle() {
+        let mut trace = FlowTrace::new("VULN-002");
+        let entry = CodeLocation::new("src/parser.rs").with_line(1).with_symbol("parse");
+        let recurse = CodeLocation::new("src/parser.rs").with_line(40).with_symbol("parse_expr");
+        let sink = CodeLocation::new("src/eval.rs").with_line(9).with_symbol("eval");
+
+        trace.add_edge(FlowEdge::taint("VULN-002", entry.clone(), recurse.clone()));
+        trace.add_edge(FlowEdge::taint("VULN-002", recurse.clone(), entry));
+        trace.add_edge(FlowEdge::taint("VULN-002", recurse, sink));
+
+        assert!(trace.has_cycle());
+        assert!(trace.topological_order().is_none());
+        assert_eq!(trace.cycle_edges(), vec![0, 1]);
+    }
 }
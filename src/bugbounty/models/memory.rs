@@ -319,8 +319,14 @@ impl ProjectMemory {
         })
     }
 
+    /// Title and content as compared for entries without a line (see [`normalize_memory_text`])
+    pub fn normalized_text(&self) -> String {
+        normalize_memory_text(&self.title, self.content.as_deref())
+    }
+
     /// Check if this memory entry is a duplicate of another
-    /// Duplicates have same type, file, and line
+    /// Duplicates have same type, file, and line; entries without a line
+    /// also need the same normalized text
     pub fn is_duplicate_of(&self, other: &ProjectMemory) -> bool {
         if self.memory_type != other.memory_type {
             return false;
@@ -342,15 +348,28 @@ impl ProjectMemory {
         }
 
         // For source/sink/note, check file + line
-        match (&self.file_path, &other.file_path) {
-            (Some(a), Some(b)) if a == b => {
-                self.line_start == other.line_start
-            }
-            _ => false,
+        if self.line_start.is_some() && other.line_start.is_some() {
+            return self.file_path.is_some()
+                && self.file_path == other.file_path
+                && self.line_start == other.line_start;
         }
+
+        // No line (general project notes): same file (or none) and same text
+        self.file_path == other.file_path
+            && self.line_start == other.line_start
+            && self.normalized_text() == other.normalized_text()
     }
 }
 
+/// Lowercase title and content and collapse whitespace, ignoring a trailing period.
+///
+/// Used to dedupe memory entries that have no file/line to match on.
+pub fn normalize_memory_text(title: &str, content: Option<&str>) -> String {
+    let text = format!("{} {}", title, content.unwrap_or("")).to_lowercase();
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    text.trim_end_matches('.').to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -421,4 +440,17 @@ mod tests {
         assert!(mem1.is_duplicate_of(&mem2));
         assert!(!mem1.is_duplicate_of(&mem3));
     }
+
+    #[test]
+    fn test_locationless_duplicate_detection_uses_normalized_text() {
+        let mem1 = ProjectMemory::note("test", MemorySourceKind::Manual, "Auth uses  JWT.");
+        let mem2 = ProjectMemory::note("test", MemorySourceKind::Agent, "auth uses jwt");
+        let mem3 = ProjectMemory::note("test", MemorySourceKind::Agent, "auth uses sessions");
+        let mem4 = ProjectMemory::note("test", MemorySourceKind::Agent, "auth uses jwt")
+            .with_file("src/auth.rs");
+
+        assert!(mem1.is_duplicate_of(&mem2));
+        assert!(!mem1.is_duplicate_of(&mem3));
+        assert!(!mem1.is_duplicate_of(&mem4));
+    }
 }
@@ -1,6 +1,7 @@
 //! Data models for BugBounty tracking
 
 mod artifact;
+mod cvss;
 mod finding;
 mod flow_edge;
 mod job;
@@ -8,8 +9,12 @@ mod memory;
 mod project;
 
 pub use artifact::{Artifact, ArtifactType};
-pub use finding::{Confidence, Finding, FindingStatus, Reachability, Severity};
+pub use cvss::{CvssVector, severity_for_score};
+pub use finding::{Confidence, Finding, FindingStatus, Reachability, Severity, reference_url};
 pub use flow_edge::{CodeLocation, FlowEdge, FlowKind, FlowTrace};
 pub use job::BugBountyJob;
-pub use memory::{MemoryConfidence, MemoryLocation, MemorySourceKind, MemoryType, ProjectMemory};
+pub use memory::{
+    MemoryConfidence, MemoryLocation, MemorySourceKind, MemoryType, ProjectMemory,
+    normalize_memory_text,
+};
 pub use project::{Project, ProjectMetadata, ProjectScope, ToolPolicy};
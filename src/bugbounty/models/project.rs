@@ -1,6 +1,7 @@
 //! Project model for BugBounty programs
 
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 /// A BugBounty program/project
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -108,6 +109,81 @@ impl Project {
     }
 }
 
+impl ProjectScope {
+    /// Whether any scope entry is a file path/glob rather than a domain or URL.
+    ///
+    /// Path-based checks are skipped entirely for scopes without such entries.
+    pub fn has_path_rules(&self) -> bool {
+        self.in_scope
+            .iter()
+            .chain(&self.out_of_scope)
+            .any(|entry| path_rule(entry).is_some())
+    }
+
+    /// Check a path (relative to the project root) against the path-style entries.
+    ///
+    /// Out-of-scope entries win. Without any path-style in-scope entry, every path
+    /// that is not explicitly excluded counts as in scope.
+    pub fn path_in_scope(&self, rel_path: &Path) -> bool {
+        let rel = rel_path.to_string_lossy().replace('\\', "/");
+        let rel = rel.trim_start_matches("./");
+
+        if self
+            .out_of_scope
+            .iter()
+            .filter_map(|entry| path_rule(entry))
+            .any(|rule| path_rule_matches(rule, rel))
+        {
+            return false;
+        }
+
+        let mut in_rules = self
+            .in_scope
+            .iter()
+            .filter_map(|entry| path_rule(entry))
+            .peekable();
+        if in_rules.peek().is_none() {
+            return true;
+        }
+        in_rules.any(|rule| path_rule_matches(rule, rel))
+    }
+}
+
+/// Extract the path/glob part of a scope entry, if it is one.
+///
+/// Entries are free-form bullets (e.g. "`src/api/` - REST handlers"), so only the
+/// first token is considered. URLs and bare domains are not path rules.
+fn path_rule(entry: &str) -> Option<&str> {
+    let token = entry.split_whitespace().next()?.trim_matches('`');
+    if token.contains("://") || !token.contains('/') {
+        return None;
+    }
+    let token = token.trim_start_matches("./").trim_end_matches('/');
+    (!token.is_empty()).then_some(token)
+}
+
+/// Match a relative path against a rule: globs match the path or any parent
+/// directory, plain rules match the path itself or anything below it.
+fn path_rule_matches(rule: &str, rel: &str) -> bool {
+    if rule.contains(['*', '?', '[']) {
+        let Ok(pattern) = glob::Pattern::new(rule) else {
+            return false;
+        };
+        let mut candidate = rel;
+        loop {
+            if pattern.matches(candidate) {
+                return true;
+            }
+            match candidate.rfind('/') {
+                Some(idx) => candidate = &candidate[..idx],
+                None => return false,
+            }
+        }
+    }
+
+    rel == rule || rel.strip_prefix(rule).is_some_and(|rest| rest.starts_with('/'))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,4 +204,39 @@ mod tests {
         assert_eq!(project.platform, Some("intigriti".to_string()));
         assert_eq!(project.target_name, Some("myapp".to_string()));
     }
+
+    #[test]
+    fn test_path_in_scope() {
+        let scope = ProjectScope {
+            in_scope: vec![
+                "*.example.com".to_string(),
+                "`src/api/` - REST handlers".to_string(),
+                "lib/**/*.rs".to_string(),
+            ],
+            out_of_scope: vec![
+                "src/api/legacy/".to_string(),
+                "https://example.com/docs".to_string(),
+            ],
+            ..Default::default()
+        };
+
+        assert!(scope.has_path_rules());
+        assert!(scope.path_in_scope(Path::new("src/api/users.rs")));
+        assert!(scope.path_in_scope(Path::new("./lib/core/mod.rs")));
+        assert!(!scope.path_in_scope(Path::new("src/api/legacy/old.rs")));
+        assert!(!scope.path_in_scope(Path::new("src/apix/users.rs")));
+        assert!(!scope.path_in_scope(Path::new("README.md")));
+    }
+
+    #[test]
+    fn test_domain_only_scope_has_no_path_rules() {
+        let scope = ProjectScope {
+            in_scope: vec!["api.example.com".to_string()],
+            out_of_scope: vec!["https://status.example.com/".to_string()],
+            ..Default::default()
+        };
+
+        assert!(!scope.has_path_rules());
+        assert!(scope.path_in_scope(Path::new("anything/at/all.rs")));
+    }
 }
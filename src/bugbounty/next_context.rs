@@ -72,10 +72,18 @@ pub struct FindingOutput {
     /// CVSS score if calculated (optional)
     pub cvss_score: Option<f64>,
 
+    /// CVSS v3.x vector (optional; overrides cvss_score when valid)
+    #[serde(default)]
+    pub cvss_vector: Option<String>,
+
     /// Affected assets/endpoints
     #[serde(default)]
     pub affected_assets: Vec<String>,
 
+    /// External references (CVE IDs, advisory or PoC URLs)
+    #[serde(default)]
+    pub references: Vec<String>,
+
     /// Taint path summary
     pub taint_path: Option<String>,
 }
@@ -229,6 +237,26 @@ impl MemoryOutput {
     }
 }
 
+/// Outcome of checking raw agent output for an ingestible `next_context`.
+#[derive(Debug, Clone, Serialize)]
+pub struct OutputValidation {
+    /// Where the checked candidate came from (`job_result`, `json_block`, `yaml_block`, `json`, `yaml`)
+    pub source: Option<&'static str>,
+    /// Parsed context when the candidate was structurally valid
+    pub context: Option<NextContext>,
+    /// Problems that prevent ingestion (`path: reason`)
+    pub errors: Vec<String>,
+    /// Problems that are tolerated but lose data (unknown keys, unrecognized enum values)
+    pub warnings: Vec<String>,
+}
+
+impl OutputValidation {
+    /// True when a non-empty `next_context` would be ingested
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty() && self.context.as_ref().is_some_and(|ctx| !ctx.is_empty())
+    }
+}
+
 impl NextContext {
     /// Parse next_context from JSON string
     pub fn from_json(json: &str) -> Result<Self> {
@@ -240,6 +268,136 @@ impl NextContext {
         serde_json::from_value(value).context("Failed to parse next_context JSON value")
     }
 
+    /// Parse next_context from a JSON value, reporting every structural problem.
+    ///
+    /// Unlike `from_value`, each list entry is checked on its own so errors name
+    /// the offending element and field (e.g. `findings[1]: missing field `title``).
+    /// Returns the parsed context plus warnings, or all errors found.
+    pub fn from_value_detailed(
+        value: &serde_json::Value,
+    ) -> std::result::Result<(Self, Vec<String>), Vec<String>> {
+        let Some(object) = value.as_object() else {
+            return Err(vec![format!(
+                "next_context must be a mapping, got {}",
+                json_kind(value)
+            )]);
+        };
+
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+
+        for (key, field) in object {
+            match key.as_str() {
+                "findings" => {
+                    check_list::<FindingOutput>(key, field, &mut errors);
+                    if let Some(items) = field.as_array() {
+                        for (idx, item) in items.iter().enumerate() {
+                            check_enum_fields(idx, item, &mut warnings);
+                        }
+                    }
+                }
+                "flow_edges" => check_list::<FlowEdgeOutput>(key, field, &mut errors),
+                "artifacts" => check_list::<ArtifactOutput>(key, field, &mut errors),
+                "memory" => check_list::<MemoryOutput>(key, field, &mut errors),
+                "state" | "summary" => {
+                    if !(field.is_string() || field.is_null()) {
+                        errors.push(format!(
+                            "{key}: expected a string, got {}",
+                            json_kind(field)
+                        ));
+                    }
+                }
+                _ => warnings.push(format!("{key}: unknown field (ignored)")),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        serde_json::from_value(value.clone())
+            .map(|ctx| (ctx, warnings))
+            .map_err(|e| vec![e.to_string()])
+    }
+
+    /// Check raw agent output the way ingestion reads it and explain why nothing
+    /// would be ingested.
+    ///
+    /// Candidates are tried in ingestion order (the result block's `next_context`,
+    /// then [`NextContext::extract_from_text`]) with the same parsers, so `Ok` means
+    /// ingestion picks up a non-empty context. `Err` carries the errors instead.
+    pub fn validate_output(text: &str) -> std::result::Result<OutputValidation, OutputValidation> {
+        let mut rejected: Vec<(&'static str, std::result::Result<serde_json::Value, String>)> =
+            Vec::new();
+
+        if let Some(value) = crate::JobResult::parse(text).and_then(|r| r.next_context) {
+            if let Ok(ctx) = Self::from_value(value.clone()) {
+                return Self::accepted("job_result", ctx, &value);
+            }
+            rejected.push(("job_result", Ok(value)));
+        }
+
+        for (source, format, raw) in Self::text_candidates(text) {
+            if let Ok(ctx) = format.parse(&raw) {
+                let value = format.to_value(&raw).unwrap_or_default();
+                return Self::accepted(source, ctx, &value);
+            }
+            let value = format.to_value(&raw);
+            // Whole-text fallbacks only explain anything when they are a mapping;
+            // plain prose is valid YAML (a string) and would only add noise.
+            let whole_text = matches!(source, "json" | "yaml");
+            if whole_text && !matches!(value, Ok(serde_json::Value::Object(_))) {
+                continue;
+            }
+            rejected.push((source, value));
+        }
+
+        let Some((source, value)) = rejected.into_iter().next() else {
+            return Err(OutputValidation {
+                source: None,
+                context: None,
+                errors: vec!["no next_context block found".to_string()],
+                warnings: Vec::new(),
+            });
+        };
+        let errors = match value {
+            Ok(value) => Self::from_value_detailed(&value)
+                .err()
+                .unwrap_or_else(|| vec!["not parseable as next_context".to_string()]),
+            Err(e) => vec![format!("not parseable: {e}")],
+        };
+        Err(OutputValidation {
+            source: Some(source),
+            context: None,
+            errors,
+            warnings: Vec::new(),
+        })
+    }
+
+    /// Report for a candidate ingestion would use; an empty context is an error.
+    fn accepted(
+        source: &'static str,
+        ctx: Self,
+        value: &serde_json::Value,
+    ) -> std::result::Result<OutputValidation, OutputValidation> {
+        let warnings = Self::from_value_detailed(value)
+            .map(|(_, warnings)| warnings)
+            .unwrap_or_default();
+        let empty = ctx.is_empty();
+        let mut outcome = OutputValidation {
+            source: Some(source),
+            context: Some(ctx),
+            errors: Vec::new(),
+            warnings,
+        };
+        if empty {
+            outcome.errors.push(
+                "next_context is empty (no findings, edges, artifacts or memory)".to_string(),
+            );
+            return Err(outcome);
+        }
+        Ok(outcome)
+    }
+
     /// Parse next_context from YAML string
     pub fn from_yaml(yaml: &str) -> Result<Self> {
         serde_yaml::from_str(yaml).context("Failed to parse next_context YAML")
@@ -249,31 +407,24 @@ impl NextContext {
     ///
     /// Looks for JSON or YAML blocks containing next_context data
     pub fn extract_from_text(text: &str) -> Option<Self> {
-        // Try to find JSON block
+        Self::text_candidates(text)
+            .into_iter()
+            .find_map(|(_, format, raw)| format.parse(&raw).ok())
+    }
+
+    /// Text candidates in the order `extract_from_text` tries them: a JSON block,
+    /// a YAML block, then the entire text as JSON and as YAML.
+    fn text_candidates(text: &str) -> Vec<(&'static str, TextFormat, String)> {
+        let mut candidates = Vec::new();
         if let Some(json) = Self::extract_json_block(text) {
-            if let Ok(ctx) = Self::from_json(&json) {
-                return Some(ctx);
-            }
+            candidates.push(("json_block", TextFormat::Json, json));
         }
-
-        // Try to find YAML block
         if let Some(yaml) = Self::extract_yaml_block(text) {
-            if let Ok(ctx) = Self::from_yaml(&yaml) {
-                return Some(ctx);
-            }
-        }
-
-        // Try to parse the entire text as JSON
-        if let Ok(ctx) = Self::from_json(text) {
-            return Some(ctx);
+            candidates.push(("yaml_block", TextFormat::Yaml, yaml));
         }
-
-        // Try to parse the entire text as YAML
-        if let Ok(ctx) = Self::from_yaml(text) {
-            return Some(ctx);
-        }
-
-        None
+        candidates.push(("json", TextFormat::Json, text.to_string()));
+        candidates.push(("yaml", TextFormat::Yaml, text.to_string()));
+        candidates
     }
 
     fn extract_json_block(text: &str) -> Option<String> {
@@ -519,10 +670,19 @@ impl FindingOutput {
             finding.cvss_score = Some(score);
         }
 
+        if let Some(ref vector) = self.cvss_vector {
+            match finding.clone().with_cvss_vector(vector) {
+                Ok(updated) => finding = updated,
+                Err(e) => tracing::warn!("Ignoring CVSS vector for '{}': {}", self.title, e),
+            }
+        }
+
         for asset in &self.affected_assets {
             finding = finding.with_affected_asset(asset.clone());
         }
 
+        finding = finding.with_references(self.references.iter().cloned());
+
         if let Some(ref s) = self.taint_path {
             finding = finding.with_taint_path(s);
         }
@@ -611,10 +771,160 @@ impl ArtifactOutput {
     }
 }
 
+/// Syntax of a `next_context` candidate found in agent output
+#[derive(Debug, Clone, Copy)]
+enum TextFormat {
+    Json,
+    Yaml,
+}
+
+impl TextFormat {
+    fn parse(self, raw: &str) -> Result<NextContext> {
+        match self {
+            TextFormat::Json => NextContext::from_json(raw),
+            TextFormat::Yaml => NextContext::from_yaml(raw),
+        }
+    }
+
+    fn to_value(self, raw: &str) -> std::result::Result<serde_json::Value, String> {
+        match self {
+            TextFormat::Json => serde_json::from_str(raw).map_err(|e| e.to_string()),
+            TextFormat::Yaml => serde_yaml::from_str(raw).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// Deserialize each entry of a `next_context` list on its own, recording failures by index.
+fn check_list<T: serde::de::DeserializeOwned>(
+    key: &str,
+    field: &serde_json::Value,
+    errors: &mut Vec<String>,
+) {
+    match field {
+        serde_json::Value::Null => {}
+        serde_json::Value::Array(items) => {
+            for (idx, item) in items.iter().enumerate() {
+                if let Err(e) = serde_json::from_value::<T>(item.clone()) {
+                    errors.push(format!("{key}[{idx}]: {e}"));
+                }
+            }
+        }
+        other => errors.push(format!("{key}: expected a list, got {}", json_kind(other))),
+    }
+}
+
+/// Warn about finding enum values that `to_finding` would silently drop.
+fn check_enum_fields(idx: usize, item: &serde_json::Value, warnings: &mut Vec<String>) {
+    let field = |name: &str| item.get(name).and_then(|v| v.as_str()).map(str::trim);
+
+    if let Some(v) = field("severity").filter(|v| Severity::from_str(v).is_none()) {
+        warnings.push(format!(
+            "findings[{idx}].severity: unrecognized value `{v}` (ignored)"
+        ));
+    }
+    if let Some(v) = field("confidence").filter(|v| Confidence::from_str(v).is_none()) {
+        warnings.push(format!(
+            "findings[{idx}].confidence: unrecognized value `{v}` (ignored)"
+        ));
+    }
+    if let Some(v) = field("reachability").filter(|v| Reachability::from_str(v).is_none()) {
+        warnings.push(format!(
+            "findings[{idx}].reachability: unrecognized value `{v}` (ignored)"
+        ));
+    }
+}
+
+fn json_kind(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "a boolean",
+        serde_json::Value::Number(_) => "a number",
+        serde_json::Value::String(_) => "a string",
+        serde_json::Value::Array(_) => "a list",
+        serde_json::Value::Object(_) => "a mapping",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from_value_detailed_names_failing_fields() {
+        let value = serde_json::json!({
+            "findings": [
+                { "title": "ok", "severity": "severe" },
+                { "severity": "high" }
+            ],
+            "flow_edges": "not a list",
+            "extra": true
+        });
+        let errors = NextContext::from_value_detailed(&value).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(
+            errors
+                .iter()
+                .any(|e| e.starts_with("findings[1]:") && e.contains("title"))
+        );
+        assert!(errors.contains(&"flow_edges: expected a list, got a string".to_string()));
+
+        let value = serde_json::json!({
+            "findings": [{ "title": "ok", "severity": "severe" }],
+            "extra": true
+        });
+        let (ctx, warnings) = NextContext::from_value_detailed(&value).unwrap();
+        assert_eq!(ctx.findings.len(), 1);
+        assert_eq!(warnings.len(), 2);
+        assert!(
+            warnings.contains(
+                &"findings[0].severity: unrecognized value `severe` (ignored)".to_string()
+            )
+        );
+        assert!(warnings.contains(&"extra: unknown field (ignored)".to_string()));
+    }
+
+    #[test]
+    fn test_validate_output_reports_source_and_problems() {
+        let valid = "Done.\n```json\n{\"findings\": [{\"title\": \"IDOR\"}]}\n```";
+        let report = NextContext::validate_output(valid).unwrap();
+        assert!(report.is_valid());
+        assert_eq!(report.source, Some("json_block"));
+
+        let broken = "```json\n{\"findings\": [{\"severity\": \"high\"}]}\n```";
+        let report = NextContext::validate_output(broken).unwrap_err();
+        assert!(!report.is_valid());
+        assert_eq!(report.source, Some("json_block"));
+        assert!(report.errors[0].contains("title"));
+
+        let report =
+            NextContext::validate_output("I looked around and found nothing.").unwrap_err();
+        assert_eq!(
+            report.errors,
+            vec!["no next_context block found".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_validate_output_agrees_with_extract_from_text() {
+        let outputs = [
+            "```json\n{\"findings\": [{\"title\": \"IDOR\"}]}\n```",
+            // Broken JSON block, but ingestion falls through to the YAML block
+            "```json\n{\"findings\": [\n```\nfindings:\n  - title: SSRF\n",
+            "findings:\n  - severity: high\n",
+            "Note: nothing to report",
+            "I looked around and found nothing.",
+        ];
+        for output in outputs {
+            let ingested = NextContext::extract_from_text(output).filter(|ctx| !ctx.is_empty());
+            let report = NextContext::validate_output(output);
+            assert_eq!(report.is_ok(), ingested.is_some(), "{output:?}");
+        }
+
+        let report = NextContext::validate_output(outputs[1]).unwrap();
+        assert_eq!(report.source, Some("yaml_block"));
+        assert_eq!(report.context.unwrap().findings[0].title, "SSRF");
+    }
+
     #[test]
     fn test_parse_json() {
         let json = r#"{
@@ -691,7 +1001,9 @@ This should be fixed immediately.
             confidence: Some("high".to_string()),
             cwe_id: Some("CWE-639".to_string()),
             cvss_score: Some(7.5),
+            cvss_vector: None,
             affected_assets: vec!["/api/test".to_string()],
+            references: vec!["CVE-2024-0001".to_string()],
             taint_path: Some("input -> sink".to_string()),
         };
 
@@ -700,5 +1012,6 @@ This should be fixed immediately.
         assert_eq!(finding.title, "Test Finding");
         assert_eq!(finding.severity, Some(Severity::High));
         assert_eq!(finding.confidence, Some(Confidence::High));
+        assert_eq!(finding.references, vec!["CVE-2024-0001"]);
     }
 }
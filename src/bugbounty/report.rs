@@ -0,0 +1,379 @@
+//! Combined project reports (all findings of a project in one document)
+
+use super::{Finding, FlowTrace, Project, reference_url};
+
+/// Output format for project reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "markdown" | "md" => Some(ReportFormat::Markdown),
+            "html" | "htm" => Some(ReportFormat::Html),
+            _ => None,
+        }
+    }
+}
+
+/// Render a project report. Findings are sorted by severity, then Kanban status;
+/// each finding is paired with its flow trace (may be empty).
+pub fn render_project_report(
+    project: &Project,
+    findings: &[(Finding, FlowTrace)],
+    format: ReportFormat,
+) -> String {
+    let mut sorted: Vec<&(Finding, FlowTrace)> = findings.iter().collect();
+    sorted.sort_by_key(|(f, _)| {
+        (
+            f.severity.map(|s| s.rank()).unwrap_or(u8::MAX),
+            f.status.column_index(),
+            f.id.clone(),
+        )
+    });
+
+    match format {
+        ReportFormat::Markdown => render_markdown(project, &sorted),
+        ReportFormat::Html => render_html(project, &sorted),
+    }
+}
+
+fn project_title(project: &Project) -> &str {
+    project.target_name.as_deref().unwrap_or(&project.id)
+}
+
+fn severity_label(finding: &Finding) -> &'static str {
+    finding.severity.map(|s| s.as_str()).unwrap_or("unrated")
+}
+
+fn render_markdown(project: &Project, findings: &[&(Finding, FlowTrace)]) -> String {
+    let mut s = String::new();
+
+    s.push_str(&format!(
+        "# Security Report: {}\n\n",
+        project_title(project)
+    ));
+    s.push_str(&format!("**Project:** {}  \n", project.id));
+    if let Some(ref platform) = project.platform {
+        s.push_str(&format!("**Platform:** {}  \n", platform));
+    }
+    s.push_str(&format!("**Findings:** {}\n\n", findings.len()));
+
+    if findings.is_empty() {
+        s.push_str("No findings recorded for this project.\n");
+        return s;
+    }
+
+    s.push_str("## Contents\n\n");
+    s.push_str("| # | ID | Title | Severity | Status |\n");
+    s.push_str("|---|----|-------|----------|--------|\n");
+    for (i, (f, _)) in findings.iter().enumerate() {
+        s.push_str(&format!(
+            "| {} | [{}](#{}) | {} | {} | {} |\n",
+            i + 1,
+            escape_table_cell(&f.id),
+            anchor(&f.id),
+            escape_table_cell(&f.title),
+            severity_label(f),
+            f.status.as_str()
+        ));
+    }
+    s.push('\n');
+
+    for (f, trace) in findings {
+        s.push_str(&format!("<a id=\"{}\"></a>\n\n", anchor(&f.id)));
+        s.push_str(&format!("## {}: {}\n\n", f.id, f.title));
+        s.push_str(&format!("**Severity:** {}  \n", severity_label(f)));
+        s.push_str(&format!("**Status:** {}  \n", f.status.as_str()));
+        if let Some(ref cwe) = f.cwe_id {
+            s.push_str(&format!("**CWE:** {}  \n", cwe));
+        }
+        if let Some(score) = f.cvss_score {
+            match f.cvss_vector.as_deref() {
+                Some(vector) => s.push_str(&format!("**CVSS:** {:.1} (`{}`)  \n", score, vector)),
+                None => s.push_str(&format!("**CVSS:** {:.1}  \n", score)),
+            }
+        }
+
+        s.push_str("\n### Attack Scenario\n\n");
+        s.push_str(f.attack_scenario.as_deref().unwrap_or("(not specified)"));
+        s.push_str("\n\n### Impact\n\n");
+        s.push_str(f.impact.as_deref().unwrap_or("(not specified)"));
+        s.push_str("\n\n");
+
+        if !f.affected_assets.is_empty() {
+            s.push_str("### Affected Assets\n\n");
+            for asset in &f.affected_assets {
+                s.push_str(&format!("- `{}`\n", asset));
+            }
+            s.push('\n');
+        }
+
+        if !f.references.is_empty() {
+            s.push_str("### References\n\n");
+            for reference in &f.references {
+                match reference_url(reference) {
+                    Some(url) if url == *reference => s.push_str(&format!("- <{}>\n", url)),
+                    Some(url) => s.push_str(&format!("- [{}]({})\n", reference, url)),
+                    None => s.push_str(&format!("- {}\n", reference)),
+                }
+            }
+            s.push('\n');
+        }
+
+        if let Some(flow) = flow_summary(f, trace) {
+            s.push_str(&format!("### Flow\n\n`{}`\n\n", flow));
+            if trace.has_cycle() {
+                s.push_str("_The flow trace contains a cycle (recursive path)._\n\n");
+            }
+        }
+    }
+
+    s
+}
+
+fn render_html(project: &Project, findings: &[&(Finding, FlowTrace)]) -> String {
+    let title = escape_html(project_title(project));
+    let mut s = String::new();
+
+    s.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    s.push_str(&format!("<title>Security Report: {}</title>\n", title));
+    s.push_str("</head>\n<body>\n");
+    s.push_str(&format!("<h1>Security Report: {}</h1>\n", title));
+    s.push_str(&format!(
+        "<p><strong>Project:</strong> {}",
+        escape_html(&project.id)
+    ));
+    if let Some(ref platform) = project.platform {
+        s.push_str(&format!(
+            "<br>\n<strong>Platform:</strong> {}",
+            escape_html(platform)
+        ));
+    }
+    s.push_str(&format!(
+        "<br>\n<strong>Findings:</strong> {}</p>\n",
+        findings.len()
+    ));
+
+    if findings.is_empty() {
+        s.push_str("<p>No findings recorded for this project.</p>\n</body>\n</html>\n");
+        return s;
+    }
+
+    s.push_str("<h2>Contents</h2>\n<table>\n");
+    s.push_str("<tr><th>#</th><th>ID</th><th>Title</th><th>Severity</th><th>Status</th></tr>\n");
+    for (i, (f, _)) in findings.iter().enumerate() {
+        s.push_str(&format!(
+            "<tr><td>{}</td><td><a href=\"#{}\">{}</a></td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            i + 1,
+            anchor(&f.id),
+            escape_html(&f.id),
+            escape_html(&f.title),
+            severity_label(f),
+            f.status.as_str()
+        ));
+    }
+    s.push_str("</table>\n");
+
+    for (f, trace) in findings {
+        s.push_str(&format!(
+            "<h2 id=\"{}\">{}: {}</h2>\n<p>",
+            anchor(&f.id),
+            escape_html(&f.id),
+            escape_html(&f.title)
+        ));
+        s.push_str(&format!(
+            "<strong>Severity:</strong> {}<br>\n<strong>Status:</strong> {}",
+            severity_label(f),
+            f.status.as_str()
+        ));
+        if let Some(ref cwe) = f.cwe_id {
+            s.push_str(&format!("<br>\n<strong>CWE:</strong> {}", escape_html(cwe)));
+        }
+        if let Some(score) = f.cvss_score {
+            s.push_str(&format!("<br>\n<strong>CVSS:</strong> {:.1}", score));
+            if let Some(ref vector) = f.cvss_vector {
+                s.push_str(&format!(" (<code>{}</code>)", escape_html(vector)));
+            }
+        }
+        s.push_str("</p>\n");
+
+        s.push_str(&format!(
+            "<h3>Attack Scenario</h3>\n<p>{}</p>\n",
+            escape_html(f.attack_scenario.as_deref().unwrap_or("(not specified)"))
+        ));
+        s.push_str(&format!(
+            "<h3>Impact</h3>\n<p>{}</p>\n",
+            escape_html(f.impact.as_deref().unwrap_or("(not specified)"))
+        ));
+
+        if !f.affected_assets.is_empty() {
+            s.push_str("<h3>Affected Assets</h3>\n<ul>\n");
+            for asset in &f.affected_assets {
+                s.push_str(&format!("<li><code>{}</code></li>\n", escape_html(asset)));
+            }
+            s.push_str("</ul>\n");
+        }
+
+        if !f.references.is_empty() {
+            s.push_str("<h3>References</h3>\n<ul>\n");
+            for reference in &f.references {
+                match reference_url(reference) {
+                    Some(url) => s.push_str(&format!(
+                        "<li><a href=\"{}\">{}</a></li>\n",
+                        escape_html(&url),
+                        escape_html(reference)
+                    )),
+                    None => s.push_str(&format!("<li>{}</li>\n", escape_html(reference))),
+                }
+            }
+            s.push_str("</ul>\n");
+        }
+
+        if let Some(flow) = flow_summary(f, trace) {
+            s.push_str(&format!(
+                "<h3>Flow</h3>\n<p><code>{}</code></p>\n",
+                escape_html(&flow)
+            ));
+            if trace.has_cycle() {
+                s.push_str("<p><em>The flow trace contains a cycle (recursive path).</em></p>\n");
+            }
+        }
+    }
+
+    s.push_str("</body>\n</html>\n");
+    s
+}
+
+/// Stored flow trace summary, falling back to the finding's taint path
+fn flow_summary(finding: &Finding, trace: &FlowTrace) -> Option<String> {
+    if !trace.edges.is_empty() {
+        return Some(trace.summary());
+    }
+    finding
+        .taint_path
+        .as_deref()
+        .filter(|t| !t.trim().is_empty())
+        .map(str::to_string)
+}
+
+fn anchor(id: &str) -> String {
+    id.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// Keep free text inside one Markdown table cell: `|` ends a cell, a newline ends the row
+fn escape_table_cell(s: &str) -> String {
+    s.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bugbounty::{FindingStatus, Severity};
+
+    fn findings() -> Vec<(Finding, FlowTrace)> {
+        let low = Finding::new("p-VULN-001", "p", "Verbose errors").with_severity(Severity::Low);
+        let crit = Finding::new("p-VULN-002", "p", "RCE via <upload>")
+            .with_severity(Severity::Critical)
+            .with_status(FindingStatus::Verified)
+            .with_affected_asset("/api/upload")
+            .with_references(["CVE-2024-1234", "https://example.com/poc"])
+            .with_taint_path("upload() -> exec()");
+        let high = Finding::new("p-VULN-003", "p", "IDOR").with_severity(Severity::High);
+        vec![
+            (low, FlowTrace::new("p-VULN-001")),
+            (crit, FlowTrace::new("p-VULN-002")),
+            (high, FlowTrace::new("p-VULN-003")),
+        ]
+    }
+
+    #[test]
+    fn test_markdown_report_sorted_by_severity_with_toc() {
+        let project = Project::new("p", "programs/p");
+        let report = render_project_report(&project, &findings(), ReportFormat::Markdown);
+
+        let crit = report.find("## p-VULN-002").unwrap();
+        let high = report.find("## p-VULN-003").unwrap();
+        let low = report.find("## p-VULN-001").unwrap();
+        assert!(crit < high && high < low);
+
+        assert!(report.contains("| 1 | [p-VULN-002](#p-vuln-002) |"));
+        assert!(report.contains("- `/api/upload`"));
+        assert!(report.contains("`upload() -> exec()`"));
+        assert!(report.contains(
+            "- [CVE-2024-1234](https://nvd.nist.gov/vuln/detail/CVE-2024-1234)"
+        ));
+        assert!(report.contains("- <https://example.com/poc>"));
+    }
+
+    #[test]
+    fn test_markdown_report_escapes_table_cells() {
+        let project = Project::new("p", "programs/p");
+        let finding = Finding::new("p-VULN|004", "p", "SQLi in a|b\nfilter");
+        let report = render_project_report(
+            &project,
+            &[(finding, FlowTrace::new("p-VULN|004"))],
+            ReportFormat::Markdown,
+        );
+
+        assert!(report.contains(
+            "| 1 | [p-VULN\\|004](#p-vuln-004) | SQLi in a\\|b filter | unrated | raw |"
+        ));
+    }
+
+    #[test]
+    fn test_html_report_escapes_content() {
+        let project = Project::new("p", "programs/p");
+        let report = render_project_report(&project, &findings(), ReportFormat::Html);
+
+        assert!(report.starts_with("<!DOCTYPE html>"));
+        assert!(report.contains("RCE via &lt;upload&gt;"));
+        assert!(report.contains("<h2 id=\"p-vuln-002\">"));
+        assert!(!report.contains("<upload>"));
+        assert!(report.contains("<a href=\"https://example.com/poc\">https://example.com/poc</a>"));
+    }
+
+    #[test]
+    fn test_report_notes_cyclic_flow_traces() {
+        use crate::bugbounty::{CodeLocation, FlowEdge};
+
+        let project = Project::new("p", "programs/p");
+        let a = CodeLocation::new("src/a.rs").with_line(1);
+        let b = CodeLocation::new("src/b.rs").with_line(2);
+        let mut trace = FlowTrace::new("p-VULN-004");
+        trace.add_edge(FlowEdge::taint("p-VULN-004", a.clone(), b.clone()));
+        trace.add_edge(FlowEdge::taint("p-VULN-004", b, a));
+        let cyclic = vec![(Finding::new("p-VULN-004", "p", "Recursive parse"), trace)];
+
+        let report = render_project_report(&project, &cyclic, ReportFormat::Markdown);
+        assert!(report.contains("contains a cycle"));
+
+        let report = render_project_report(&project, &findings(), ReportFormat::Markdown);
+        assert!(!report.contains("contains a cycle"));
+    }
+
+    #[test]
+    fn test_report_format_parsing() {
+        assert_eq!(ReportFormat::from_str("md"), Some(ReportFormat::Markdown));
+        assert_eq!(ReportFormat::from_str("HTML"), Some(ReportFormat::Html));
+        assert_eq!(ReportFormat::from_str("pdf"), None);
+    }
+}
@@ -1,13 +1,13 @@
 //! Repository implementations for BugBounty data access
 
 use anyhow::{Context, Result};
-use rusqlite::{OptionalExtension, params};
+use rusqlite::{Connection, OptionalExtension, params};
 
 use super::db::BugBountyDb;
 use super::models::{
     Artifact, ArtifactType, BugBountyJob, CodeLocation, Confidence, Finding, FindingStatus,
     FlowEdge, FlowKind, FlowTrace, MemoryConfidence, MemoryLocation, MemorySourceKind, MemoryType,
-    Project, ProjectMemory, Reachability, Severity,
+    Project, ProjectMemory, Reachability, Severity, normalize_memory_text,
 };
 
 // ============================================
@@ -318,12 +318,14 @@ impl FindingRepository {
                 id, project_id, title, severity, status,
                 attack_scenario, preconditions, reachability, impact, confidence,
                 cwe_id, cvss_score, affected_assets_json, taint_path,
-                fp_reason, notes, source_file, created_at, updated_at
+                fp_reason, notes, source_file, created_at, updated_at, cvss_vector,
+                manual_priority, duplicate_of, computed_priority, references_json
             ) VALUES (
                 ?1, ?2, ?3, ?4, ?5,
                 ?6, ?7, ?8, ?9, ?10,
                 ?11, ?12, ?13, ?14,
-                ?15, ?16, ?17, ?18, ?19
+                ?15, ?16, ?17, ?18, ?19, ?20,
+                ?21, ?22, ?23, ?24
             )
             "#,
             params![
@@ -346,6 +348,11 @@ impl FindingRepository {
                 finding.source_file,
                 finding.created_at,
                 finding.updated_at,
+                finding.cvss_vector,
+                finding.manual_priority,
+                finding.duplicate_of,
+                finding.computed_priority,
+                serde_json::to_string(&finding.references).ok(),
             ],
         ).context("Failed to create finding")?;
         Ok(())
@@ -359,7 +366,8 @@ impl FindingRepository {
             SELECT id, project_id, title, severity, status,
                    attack_scenario, preconditions, reachability, impact, confidence,
                    cwe_id, cvss_score, affected_assets_json, taint_path,
-                   fp_reason, notes, source_file, created_at, updated_at
+                   fp_reason, notes, source_file, created_at, updated_at, cvss_vector,
+                   manual_priority, duplicate_of, computed_priority, references_json
             FROM findings WHERE id = ?1
             "#,
         )?;
@@ -376,22 +384,83 @@ impl FindingRepository {
     }
 
     /// Set the status of a finding (Kanban column change)
+    ///
+    /// Leaving the Duplicate column clears `duplicate_of`.
     pub fn set_status(&self, id: &str, status: FindingStatus) -> Result<()> {
         let conn = self.db.conn();
         let now = chrono::Utc::now().timestamp_millis();
         conn.execute(
-            "UPDATE findings SET status = ?2, updated_at = ?3 WHERE id = ?1",
-            params![id, status.as_str(), now],
+            "UPDATE findings SET status = ?2, updated_at = ?3,
+                duplicate_of = CASE WHEN ?2 = ?4 THEN duplicate_of ELSE NULL END
+             WHERE id = ?1",
+            params![id, status.as_str(), now, FindingStatus::Duplicate.as_str()],
         ).context("Failed to update finding status")?;
         Ok(())
     }
 
+    /// Pin a finding within its Kanban column, or clear the pin with `None`
+    pub fn set_manual_priority(&self, id: &str, priority: Option<i32>) -> Result<()> {
+        let conn = self.db.conn();
+        let now = chrono::Utc::now().timestamp_millis();
+        conn.execute(
+            "UPDATE findings SET manual_priority = ?2, updated_at = ?3 WHERE id = ?1",
+            params![id, priority, now],
+        ).context("Failed to update finding priority")?;
+        Ok(())
+    }
+
+    /// Store the computed triage score; unlike a pin this does not touch `updated_at`
+    pub fn set_computed_priority(&self, id: &str, priority: Option<i32>) -> Result<()> {
+        let conn = self.db.conn();
+        conn.execute(
+            "UPDATE findings SET computed_priority = ?2 WHERE id = ?1",
+            params![id, priority],
+        ).context("Failed to update computed finding priority")?;
+        Ok(())
+    }
+
+    /// Mark a finding as a duplicate of `canonical_id` (moves it to the Duplicate column)
+    pub fn mark_duplicate(&self, id: &str, canonical_id: &str) -> Result<()> {
+        Self::mark_duplicate_in(&self.db.conn(), id, canonical_id)
+    }
+
+    /// [`Self::mark_duplicate`] on `conn`, e.g. inside a transaction
+    pub(crate) fn mark_duplicate_in(conn: &Connection, id: &str, canonical_id: &str) -> Result<()> {
+        let now = chrono::Utc::now().timestamp_millis();
+        conn.execute(
+            "UPDATE findings SET status = ?2, duplicate_of = ?3, updated_at = ?4 WHERE id = ?1",
+            params![id, FindingStatus::Duplicate.as_str(), canonical_id, now],
+        ).context("Failed to mark finding as duplicate")?;
+        Ok(())
+    }
+
+    /// Point every duplicate of `from_id` at `to_id`; returns the number re-pointed
+    pub fn repoint_duplicates(&self, from_id: &str, to_id: &str) -> Result<usize> {
+        Self::repoint_duplicates_in(&self.db.conn(), from_id, to_id)
+    }
+
+    /// [`Self::repoint_duplicates`] on `conn`, e.g. inside a transaction
+    pub(crate) fn repoint_duplicates_in(
+        conn: &Connection,
+        from_id: &str,
+        to_id: &str,
+    ) -> Result<usize> {
+        let now = chrono::Utc::now().timestamp_millis();
+        let moved = conn
+            .execute(
+                "UPDATE findings SET duplicate_of = ?2, updated_at = ?3 WHERE duplicate_of = ?1",
+                params![from_id, to_id, now],
+            )
+            .context("Failed to re-point duplicates")?;
+        Ok(moved)
+    }
+
     /// Mark a finding as false positive with reason
     pub fn mark_false_positive(&self, id: &str, reason: &str) -> Result<()> {
         let conn = self.db.conn();
         let now = chrono::Utc::now().timestamp_millis();
         conn.execute(
-            "UPDATE findings SET status = 'false_positive', fp_reason = ?2, updated_at = ?3 WHERE id = ?1",
+            "UPDATE findings SET status = 'false_positive', fp_reason = ?2, duplicate_of = NULL, updated_at = ?3 WHERE id = ?1",
             params![id, reason, now],
         ).context("Failed to mark finding as FP")?;
         Ok(())
@@ -405,7 +474,8 @@ impl FindingRepository {
             SELECT id, project_id, title, severity, status,
                    attack_scenario, preconditions, reachability, impact, confidence,
                    cwe_id, cvss_score, affected_assets_json, taint_path,
-                   fp_reason, notes, source_file, created_at, updated_at
+                   fp_reason, notes, source_file, created_at, updated_at, cvss_vector,
+                   manual_priority, duplicate_of, computed_priority, references_json
             FROM findings WHERE project_id = ?1 ORDER BY created_at DESC
             "#,
         )?;
@@ -427,7 +497,8 @@ impl FindingRepository {
             SELECT id, project_id, title, severity, status,
                    attack_scenario, preconditions, reachability, impact, confidence,
                    cwe_id, cvss_score, affected_assets_json, taint_path,
-                   fp_reason, notes, source_file, created_at, updated_at
+                   fp_reason, notes, source_file, created_at, updated_at, cvss_vector,
+                   manual_priority, duplicate_of, computed_priority, references_json
             FROM findings WHERE status = ?1 ORDER BY updated_at DESC
             "#,
         )?;
@@ -441,6 +512,43 @@ impl FindingRepository {
         Ok(findings)
     }
 
+    /// Case-insensitive substring search across title, attack scenario, impact and notes
+    ///
+    /// `%` and `_` in the query match literally. Results are ordered by most recently updated.
+    pub fn search(&self, query: &str, project_id: Option<&str>) -> Result<Vec<Finding>> {
+        let escaped = query
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        let pattern = format!("%{}%", escaped);
+
+        let conn = self.db.conn();
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT id, project_id, title, severity, status,
+                   attack_scenario, preconditions, reachability, impact, confidence,
+                   cwe_id, cvss_score, affected_assets_json, taint_path,
+                   fp_reason, notes, source_file, created_at, updated_at, cvss_vector,
+                   manual_priority, duplicate_of, computed_priority, references_json
+            FROM findings
+            WHERE (?2 IS NULL OR project_id = ?2)
+              AND (title LIKE ?1 ESCAPE '\'
+                   OR attack_scenario LIKE ?1 ESCAPE '\'
+                   OR impact LIKE ?1 ESCAPE '\'
+                   OR notes LIKE ?1 ESCAPE '\')
+            ORDER BY updated_at DESC
+            "#,
+        )?;
+
+        let findings = stmt.query_map(params![pattern, project_id], |row| {
+            Ok(self.row_to_finding(row))
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+
+        Ok(findings)
+    }
+
     /// Get the next available finding number for a project
     pub fn next_number(&self, project_id: &str) -> Result<u32> {
         let conn = self.db.conn();
@@ -462,7 +570,9 @@ impl FindingRepository {
                 title = ?2, severity = ?3, status = ?4,
                 attack_scenario = ?5, preconditions = ?6, reachability = ?7, impact = ?8, confidence = ?9,
                 cwe_id = ?10, cvss_score = ?11, affected_assets_json = ?12, taint_path = ?13,
-                fp_reason = ?14, notes = ?15, source_file = ?16, updated_at = ?17
+                fp_reason = ?14, notes = ?15, source_file = ?16, updated_at = ?17,
+                cvss_vector = ?18, manual_priority = ?19, duplicate_of = ?20,
+                computed_priority = ?21, references_json = ?22
             WHERE id = ?1
             "#,
             params![
@@ -483,6 +593,11 @@ impl FindingRepository {
                 finding.notes,
                 finding.source_file,
                 now,
+                finding.cvss_vector,
+                finding.manual_priority,
+                finding.duplicate_of,
+                finding.computed_priority,
+                serde_json::to_string(&finding.references).ok(),
             ],
         ).context("Failed to update finding")?;
         Ok(())
@@ -495,6 +610,50 @@ impl FindingRepository {
         Ok(())
     }
 
+    /// Copy a finding as a new Raw finding (for tracking variants).
+    ///
+    /// Severity, CWE, scenario and the other assessment fields are preserved;
+    /// status, FP reason and timestamps start fresh.
+    pub fn clone_finding(&self, id: &str, new_title: Option<&str>) -> Result<Finding> {
+        let source = self
+            .get(id)?
+            .with_context(|| format!("Finding not found: {}", id))?;
+
+        // next_number is COUNT-based, so skip ahead past ids left behind by deletions
+        let mut number = self.next_number(&source.project_id)?;
+        let mut new_id = Finding::generate_id(&source.project_id, number);
+        while self.get(&new_id)?.is_some() {
+            number += 1;
+            new_id = Finding::generate_id(&source.project_id, number);
+        }
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let title = new_title
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{} (variant)", source.title));
+        let variant_note = format!("Variant of {}", source.id);
+        let notes = match source.notes.as_deref().filter(|n| !n.trim().is_empty()) {
+            Some(existing) => format!("{}\n\n{}", existing, variant_note),
+            None => variant_note,
+        };
+
+        let finding = Finding {
+            id: new_id,
+            title,
+            status: FindingStatus::Raw,
+            fp_reason: None,
+            duplicate_of: None,
+            notes: Some(notes),
+            created_at: now,
+            updated_at: now,
+            ..source
+        };
+        self.create(&finding)?;
+        Ok(finding)
+    }
+
     // Helper to convert a row to a Finding
     fn row_to_finding(&self, row: &rusqlite::Row) -> Finding {
         Finding {
@@ -506,6 +665,8 @@ impl FindingRepository {
             status: row.get::<_, String>(4).ok()
                 .and_then(|s| FindingStatus::from_str(&s))
                 .unwrap_or(FindingStatus::Raw),
+            manual_priority: row.get(20).ok().flatten(),
+            computed_priority: row.get(22).ok().flatten(),
             attack_scenario: row.get(5).ok().flatten(),
             preconditions: row.get(6).ok().flatten(),
             reachability: row.get::<_, Option<String>>(7).ok().flatten()
@@ -515,11 +676,16 @@ impl FindingRepository {
                 .and_then(|s| Confidence::from_str(&s)),
             cwe_id: row.get(10).ok().flatten(),
             cvss_score: row.get(11).ok().flatten(),
+            cvss_vector: row.get(19).ok().flatten(),
             affected_assets: row.get::<_, Option<String>>(12).ok().flatten()
                 .and_then(|s| serde_json::from_str(&s).ok())
                 .unwrap_or_default(),
+            references: row.get::<_, Option<String>>(23).ok().flatten()
+                .and_then(|s| serde_json::from_str(&s).ok())
+                .unwrap_or_default(),
             taint_path: row.get(13).ok().flatten(),
             fp_reason: row.get(14).ok().flatten(),
+            duplicate_of: row.get(21).ok().flatten(),
             notes: row.get(15).ok().flatten(),
             source_file: row.get(16).ok().flatten(),
             created_at: row.get(17).unwrap_or(0),
@@ -537,6 +703,51 @@ pub struct ArtifactRepository {
     db: BugBountyDb,
 }
 
+/// Outcome of checking an artifact's file on disk
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ArtifactCheck {
+    pub artifact: Artifact,
+    /// Path the artifact resolved to (project root + relative path)
+    pub resolved_path: std::path::PathBuf,
+    /// False when the referenced file no longer exists (dangling reference)
+    pub exists: bool,
+}
+
+const ARTIFACT_COLUMNS: &str = "a.id, a.finding_id, a.job_id, a.type, a.path, a.description, \
+a.hash, a.created_at, a.size_bytes";
+
+fn row_to_artifact(row: &rusqlite::Row<'_>) -> rusqlite::Result<Artifact> {
+    Ok(Artifact {
+        id: row.get(0)?,
+        finding_id: row.get(1)?,
+        job_id: row.get(2)?,
+        artifact_type: row.get::<_, String>(3)
+            .map(|s| ArtifactType::from_str(&s).unwrap_or(ArtifactType::Other))?,
+        path: row.get(4)?,
+        description: row.get(5)?,
+        hash: row.get(6)?,
+        created_at: row.get(7)?,
+        size_bytes: row.get::<_, Option<i64>>(8)?.map(|n| n.max(0) as u64),
+    })
+}
+
+/// SHA-256 of a file via `shasum` (macOS) or `sha256sum` (Linux); None if neither works
+fn file_sha256(path: &std::path::Path) -> Option<String> {
+    let attempts: [(&str, &[&str]); 2] = [("shasum", &["-a", "256"]), ("sha256sum", &[])];
+    attempts.into_iter().find_map(|(program, args)| {
+        let output = std::process::Command::new(program)
+            .args(args)
+            .arg(path)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())?;
+        String::from_utf8_lossy(&output.stdout)
+            .split_whitespace()
+            .next()
+            .map(str::to_string)
+    })
+}
+
 impl ArtifactRepository {
     pub fn new(db: BugBountyDb) -> Self {
         Self { db }
@@ -584,8 +795,9 @@ impl ArtifactRepository {
 
         conn.execute(
             r#"
-            INSERT INTO artifacts (id, finding_id, job_id, type, path, description, hash, created_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            INSERT INTO artifacts
+                (id, finding_id, job_id, type, path, description, hash, created_at, size_bytes)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
             "#,
             params![
                 artifact.id,
@@ -596,6 +808,7 @@ impl ArtifactRepository {
                 artifact.description,
                 artifact.hash,
                 artifact.created_at,
+                artifact.size_bytes.map(|n| n as i64),
             ],
         ).context("Failed to create artifact")?;
         Ok(())
@@ -604,23 +817,10 @@ impl ArtifactRepository {
     /// Get an artifact by ID
     pub fn get(&self, id: &str) -> Result<Option<Artifact>> {
         let conn = self.db.conn();
-        let mut stmt = conn.prepare(
-            "SELECT id, finding_id, job_id, type, path, description, hash, created_at FROM artifacts WHERE id = ?1",
-        )?;
+        let sql = format!("SELECT {} FROM artifacts a WHERE a.id = ?1", ARTIFACT_COLUMNS);
+        let mut stmt = conn.prepare(&sql)?;
 
-        let result = stmt.query_row(params![id], |row| {
-            Ok(Artifact {
-                id: row.get(0)?,
-                finding_id: row.get(1)?,
-                job_id: row.get(2)?,
-                artifact_type: row.get::<_, String>(3)
-                    .map(|s| ArtifactType::from_str(&s).unwrap_or(ArtifactType::Other))?,
-                path: row.get(4)?,
-                description: row.get(5)?,
-                hash: row.get(6)?,
-                created_at: row.get(7)?,
-            })
-        });
+        let result = stmt.query_row(params![id], row_to_artifact);
 
         match result {
             Ok(artifact) => Ok(Some(artifact)),
@@ -632,25 +832,15 @@ impl ArtifactRepository {
     /// List artifacts by finding
     pub fn list_by_finding(&self, finding_id: &str) -> Result<Vec<Artifact>> {
         let conn = self.db.conn();
-        let mut stmt = conn.prepare(
-            "SELECT id, finding_id, job_id, type, path, description, hash, created_at FROM artifacts WHERE finding_id = ?1 ORDER BY created_at",
-        )?;
+        let sql = format!(
+            "SELECT {} FROM artifacts a WHERE a.finding_id = ?1 ORDER BY a.created_at",
+            ARTIFACT_COLUMNS
+        );
+        let mut stmt = conn.prepare(&sql)?;
 
-        let artifacts = stmt.query_map(params![finding_id], |row| {
-            Ok(Artifact {
-                id: row.get(0)?,
-                finding_id: row.get(1)?,
-                job_id: row.get(2)?,
-                artifact_type: row.get::<_, String>(3)
-                    .map(|s| ArtifactType::from_str(&s).unwrap_or(ArtifactType::Other))?,
-                path: row.get(4)?,
-                description: row.get(5)?,
-                hash: row.get(6)?,
-                created_at: row.get(7)?,
-            })
-        })?
-        .filter_map(|r| r.ok())
-        .collect();
+        let artifacts = stmt.query_map(params![finding_id], row_to_artifact)?
+            .filter_map(|r| r.ok())
+            .collect();
 
         Ok(artifacts)
     }
@@ -658,35 +848,107 @@ impl ArtifactRepository {
     /// List artifacts by job
     pub fn list_by_job(&self, job_id: &str) -> Result<Vec<Artifact>> {
         let conn = self.db.conn();
-        let mut stmt = conn.prepare(
-            "SELECT id, finding_id, job_id, type, path, description, hash, created_at FROM artifacts WHERE job_id = ?1 ORDER BY created_at",
-        )?;
+        let sql = format!(
+            "SELECT {} FROM artifacts a WHERE a.job_id = ?1 ORDER BY a.created_at",
+            ARTIFACT_COLUMNS
+        );
+        let mut stmt = conn.prepare(&sql)?;
 
-        let artifacts = stmt.query_map(params![job_id], |row| {
-            Ok(Artifact {
-                id: row.get(0)?,
-                finding_id: row.get(1)?,
-                job_id: row.get(2)?,
-                artifact_type: row.get::<_, String>(3)
-                    .map(|s| ArtifactType::from_str(&s).unwrap_or(ArtifactType::Other))?,
-                path: row.get(4)?,
-                description: row.get(5)?,
-                hash: row.get(6)?,
-                created_at: row.get(7)?,
-            })
-        })?
-        .filter_map(|r| r.ok())
-        .collect();
+        let artifacts = stmt.query_map(params![job_id], row_to_artifact)?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(artifacts)
+    }
+
+    /// List artifacts of a project (attached to one of its findings or jobs)
+    pub fn list_by_project(&self, project_id: &str) -> Result<Vec<Artifact>> {
+        let conn = self.db.conn();
+        let sql = format!(
+            r#"
+            SELECT {} FROM artifacts a
+            LEFT JOIN findings f ON f.id = a.finding_id
+            LEFT JOIN jobs j ON j.id = a.job_id
+            WHERE f.project_id = ?1 OR j.project_id = ?1
+            ORDER BY a.created_at
+            "#,
+            ARTIFACT_COLUMNS
+        );
+        let mut stmt = conn.prepare(&sql)?;
+
+        let artifacts = stmt.query_map(params![project_id], row_to_artifact)?
+            .filter_map(|r| r.ok())
+            .collect();
 
         Ok(artifacts)
     }
 
+    /// Check that an artifact's file exists under `project_root`.
+    ///
+    /// For existing files the size is recorded and a missing hash is computed; both are
+    /// written back to the database. Missing files are reported, not deleted.
+    pub fn verify(
+        &self,
+        artifact: &Artifact,
+        project_root: &std::path::Path,
+    ) -> Result<ArtifactCheck> {
+        let resolved_path = artifact.resolve_path(project_root);
+        let mut artifact = artifact.clone();
+
+        let Some(metadata) = std::fs::metadata(&resolved_path).ok().filter(|m| m.is_file()) else {
+            return Ok(ArtifactCheck {
+                artifact,
+                resolved_path,
+                exists: false,
+            });
+        };
+
+        artifact.size_bytes = Some(metadata.len());
+        if artifact.hash.as_deref().is_none_or(|h| h.trim().is_empty()) {
+            artifact.hash = file_sha256(&resolved_path);
+        }
+
+        self.db
+            .conn()
+            .execute(
+                "UPDATE artifacts SET size_bytes = ?2, hash = ?3 WHERE id = ?1",
+                params![artifact.id, artifact.size_bytes.map(|n| n as i64), artifact.hash],
+            )
+            .context("Failed to update artifact")?;
+
+        Ok(ArtifactCheck {
+            artifact,
+            resolved_path,
+            exists: true,
+        })
+    }
+
     /// Delete an artifact
     pub fn delete(&self, id: &str) -> Result<()> {
         let conn = self.db.conn();
         conn.execute("DELETE FROM artifacts WHERE id = ?1", params![id])?;
         Ok(())
     }
+
+    /// Move all artifacts of one finding to another; returns the number moved
+    pub fn reassign_finding(&self, from_finding_id: &str, to_finding_id: &str) -> Result<usize> {
+        Self::reassign_finding_in(&self.db.conn(), from_finding_id, to_finding_id)
+    }
+
+    /// [`Self::reassign_finding`] on `conn`, e.g. inside a transaction
+    pub(crate) fn reassign_finding_in(
+        conn: &Connection,
+        from_finding_id: &str,
+        to_finding_id: &str,
+    ) -> Result<usize> {
+        let moved = conn
+            .execute(
+                "UPDATE artifacts SET finding_id = ?2 WHERE finding_id = ?1",
+                params![from_finding_id, to_finding_id],
+            )
+            .context("Failed to reassign artifacts")?;
+        Ok(moved)
+    }
 }
 
 // ============================================
@@ -1022,6 +1284,13 @@ impl JobRepository {
 
         Ok(jobs)
     }
+
+    /// Delete a job (links cascade, artifacts keep their finding)
+    pub fn delete(&self, id: &str) -> Result<()> {
+        let conn = self.db.conn();
+        conn.execute("DELETE FROM jobs WHERE id = ?1", params![id])?;
+        Ok(())
+    }
 }
 
 // ============================================
@@ -1081,6 +1350,21 @@ impl JobFindingRepository {
         Ok(ids)
     }
 
+    /// List `(job_id, link_type)` pairs linked to a finding
+    pub fn list_links_for_finding(&self, finding_id: &str) -> Result<Vec<(String, String)>> {
+        let conn = self.db.conn();
+        let mut stmt = conn.prepare(
+            "SELECT job_id, link_type FROM job_findings WHERE finding_id = ?1 ORDER BY created_at",
+        )?;
+
+        let links = stmt
+            .query_map(params![finding_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(links)
+    }
+
     pub fn unlink(&self, job_id: &str, finding_id: &str) -> Result<()> {
         let conn = self.db.conn();
         conn.execute(
@@ -1091,6 +1375,36 @@ impl JobFindingRepository {
         Ok(())
     }
 
+    /// Move all job links of one finding to another; returns the number of jobs moved.
+    ///
+    /// Jobs already linked to the target keep their existing link type.
+    pub fn reassign_finding(&self, from_finding_id: &str, to_finding_id: &str) -> Result<usize> {
+        Self::reassign_finding_in(&self.db.conn(), from_finding_id, to_finding_id)
+    }
+
+    /// [`Self::reassign_finding`] on `conn`, e.g. inside a transaction
+    pub(crate) fn reassign_finding_in(
+        conn: &Connection,
+        from_finding_id: &str,
+        to_finding_id: &str,
+    ) -> Result<usize> {
+        let moved = conn
+            .execute(
+                r#"
+                INSERT OR IGNORE INTO job_findings (job_id, finding_id, link_type, created_at)
+                SELECT job_id, ?2, link_type, created_at FROM job_findings WHERE finding_id = ?1
+                "#,
+                params![from_finding_id, to_finding_id],
+            )
+            .context("Failed to reassign job links")?;
+        conn.execute(
+            "DELETE FROM job_findings WHERE finding_id = ?1",
+            params![from_finding_id],
+        )
+        .context("Failed to remove old job links")?;
+        Ok(moved)
+    }
+
     pub fn is_linked(&self, job_id: &str, finding_id: &str) -> Result<bool> {
         let conn = self.db.conn();
         let count: i64 = conn.query_row(
@@ -1321,23 +1635,31 @@ impl MemoryRepository {
             return Ok(count > 0);
         }
 
-        // For entries without file/line, check by title (notes, context)
-        if mem.file_path.is_none() {
-            let count: i64 = conn.query_row(
-                r#"
-                SELECT COUNT(*) FROM project_memory
-                WHERE project_id = ?1
-                  AND memory_type = ?2
-                  AND title = ?3
-                  AND is_active = 1
-                "#,
-                params![mem.project_id, mem.memory_type.as_str(), mem.title,],
-                |row| row.get(0),
-            )?;
-            return Ok(count > 0);
-        }
-
-        Ok(false)
+        // Without a line (general notes, file-level notes) compare the normalized
+        // text of entries at the same file, or with no file at all
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT title, content FROM project_memory
+            WHERE project_id = ?1
+              AND memory_type = ?2
+              AND file_path IS ?3
+              AND line_start IS NULL
+              AND is_active = 1
+            "#,
+        )?;
+        let text = mem.normalized_text();
+        let found = stmt
+            .query_map(
+                params![mem.project_id, mem.memory_type.as_str(), mem.file_path],
+                |row| {
+                    let title: String = row.get(0)?;
+                    let content: Option<String> = row.get(1)?;
+                    Ok(normalize_memory_text(&title, content.as_deref()))
+                },
+            )?
+            .filter_map(|r| r.ok())
+            .any(|existing| existing == text);
+        Ok(found)
     }
 
     /// Delete a memory entry
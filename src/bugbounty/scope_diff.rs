@@ -0,0 +1,89 @@
+//! Scope comparison for BugBounty programs
+//!
+//! Programs change their scope over time. This compares two parsed scopes and
+//! reports which in-scope / out-of-scope entries were added or removed.
+
+use serde::Serialize;
+
+use super::models::ProjectScope;
+
+/// Differences between two `ProjectScope`s (entries compared case-insensitively)
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct ScopeDiff {
+    /// Entries newly listed as in scope
+    pub added_in_scope: Vec<String>,
+    /// Entries no longer listed as in scope
+    pub removed_in_scope: Vec<String>,
+    /// Entries newly listed as out of scope
+    pub added_out_of_scope: Vec<String>,
+    /// Entries no longer listed as out of scope
+    pub removed_out_of_scope: Vec<String>,
+}
+
+impl ScopeDiff {
+    /// True when both scopes list the same entries
+    pub fn is_empty(&self) -> bool {
+        self.added_in_scope.is_empty()
+            && self.removed_in_scope.is_empty()
+            && self.added_out_of_scope.is_empty()
+            && self.removed_out_of_scope.is_empty()
+    }
+
+    /// True when the scope shrank (entries removed from in-scope or added to out-of-scope)
+    pub fn narrows_scope(&self) -> bool {
+        !self.removed_in_scope.is_empty() || !self.added_out_of_scope.is_empty()
+    }
+}
+
+/// Compare an old and a new scope.
+pub fn diff_scopes(old: &ProjectScope, new: &ProjectScope) -> ScopeDiff {
+    ScopeDiff {
+        added_in_scope: missing_from(&new.in_scope, &old.in_scope),
+        removed_in_scope: missing_from(&old.in_scope, &new.in_scope),
+        added_out_of_scope: missing_from(&new.out_of_scope, &old.out_of_scope),
+        removed_out_of_scope: missing_from(&old.out_of_scope, &new.out_of_scope),
+    }
+}
+
+/// Entries of `items` not present in `other` (case-insensitive)
+fn missing_from(items: &[String], other: &[String]) -> Vec<String> {
+    items
+        .iter()
+        .filter(|item| !other.iter().any(|o| o.trim().eq_ignore_ascii_case(item.trim())))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scope(in_scope: &[&str], out_of_scope: &[&str]) -> ProjectScope {
+        ProjectScope {
+            in_scope: in_scope.iter().map(|s| s.to_string()).collect(),
+            out_of_scope: out_of_scope.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reports_added_and_removed_entries() {
+        let old = scope(&["*.example.com", "api.example.com"], &["blog.example.com"]);
+        let new = scope(&["*.example.com", "app.example.com"], &["Blog.example.com", "legacy.example.com"]);
+
+        let diff = diff_scopes(&old, &new);
+        assert_eq!(diff.added_in_scope, vec!["app.example.com"]);
+        assert_eq!(diff.removed_in_scope, vec!["api.example.com"]);
+        assert_eq!(diff.added_out_of_scope, vec!["legacy.example.com"]);
+        assert!(diff.removed_out_of_scope.is_empty());
+        assert!(diff.narrows_scope());
+    }
+
+    #[test]
+    fn identical_scopes_have_empty_diff() {
+        let old = scope(&["api.example.com"], &[]);
+        let diff = diff_scopes(&old, &old.clone());
+        assert!(diff.is_empty());
+        assert!(!diff.narrows_scope());
+    }
+}
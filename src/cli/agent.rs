@@ -2,8 +2,17 @@
 
 use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
+use crate::agent::{AgentRegistry, BridgeClient};
 use crate::config::Config;
+use crate::{AgentConfig, Job, LogEvent, ScopeDefinition, SdkType, SkillTemplate};
+
+/// Skill name used for the `agent test` round-trip.
+const AGENT_TEST_SKILL: &str = "agent-test";
+/// What the health check asks the agent to do
+const AGENT_TEST_PROMPT: &str = "Reply with exactly the word OK and nothing else.";
 
 /// Resolve the config path - uses global config (~/.kyco/config.toml) as default,
 /// but allows override via --config flag for project-local configs.
@@ -76,3 +85,244 @@ pub fn agent_get_command(
     }
     Ok(())
 }
+
+#[derive(Debug, serde::Serialize)]
+pub(super) struct AgentTestReport {
+    agent: String,
+    sdk: String,
+    model: Option<String>,
+    adapter: String,
+    bridge_version: Option<String>,
+    pub(super) success: bool,
+    reply: Option<String>,
+    pub(super) duration_ms: u64,
+    pub(super) error: Option<String>,
+}
+
+/// Run a trivial prompt through an agent to confirm the SDK bridge and
+/// credentials work.
+///
+/// The run cannot touch the repo: Claude gets no tools (`dontAsk` with nothing
+/// allowed) and Codex runs in the read-only sandbox without approvals. Custom
+/// agents are only told so in the prompt. On timeout the bridge session is
+/// interrupted so it does not keep running in the background.
+///
+/// Returns an error (non-zero exit) when the round-trip did not succeed.
+pub async fn agent_test_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    name: &str,
+    timeout: Duration,
+    json: bool,
+) -> Result<()> {
+    let cfg = load_or_init_config(work_dir, config_override)?;
+    let report = run_agent_test(work_dir, &cfg, name, timeout).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!("Agent:    {}", report.agent);
+        println!("SDK:      {}", report.sdk);
+        println!("Model:    {}", report.model.as_deref().unwrap_or("(default)"));
+        println!("Adapter:  {}", report.adapter);
+        println!(
+            "Bridge:   {}",
+            report
+                .bridge_version
+                .as_deref()
+                .map(|v| format!("running (v{v})"))
+                .unwrap_or_else(|| "not reachable".to_string())
+        );
+        println!("Duration: {}ms", report.duration_ms);
+        if let Some(reply) = report.reply.as_deref().filter(|r| !r.is_empty()) {
+            println!("Reply:    {}", reply);
+        }
+        if let Some(error) = report.error.as_deref() {
+            println!("Error:    {}", error);
+        }
+        println!("Result:   {}", if report.success { "OK" } else { "FAILED" });
+    }
+
+    if !report.success {
+        anyhow::bail!("Agent '{}' health check failed", name);
+    }
+    Ok(())
+}
+
+/// Lock `agent_config` down for the health check and build the job it runs.
+///
+/// The instruction goes in the job description, which every adapter sends, and the
+/// job targets a single (virtual) file so no adapter asks the agent to modify a
+/// directory.
+fn prepare_agent_test(work_dir: &Path, agent_config: &mut AgentConfig) -> Job {
+    agent_config.max_turns = 1;
+    agent_config.allowed_tools.clear();
+    agent_config.disallowed_tools = ["Bash", "Edit", "Write", "Read", "Glob", "Grep", "WebFetch"]
+        .iter()
+        .map(|t| t.to_string())
+        .collect();
+    agent_config.mcp_servers.clear();
+    agent_config.read_only = true;
+    agent_config.allow_dangerous_bypass = false;
+    match agent_config.sdk_type {
+        SdkType::Codex => {
+            agent_config.sandbox = Some("read-only".to_string());
+            agent_config.ask_for_approval = Some("never".to_string());
+        }
+        // Deny every tool that is not explicitly allowed (none are)
+        _ => agent_config.permission_mode = "dontAsk".to_string(),
+    }
+    agent_config.skill_templates.insert(
+        AGENT_TEST_SKILL.to_string(),
+        SkillTemplate {
+            prompt_template: "{description}".to_string(),
+            system_prompt: Some(
+                "This is a connectivity check. Do not use tools and do not read or change files."
+                    .to_string(),
+            ),
+            default_agent: None,
+            disallowed_tools: Vec::new(),
+            allowed_tools: Vec::new(),
+            output_states: Vec::new(),
+            state_prompt: None,
+        },
+    );
+
+    let source_file = work_dir.join(AGENT_TEST_SKILL);
+    let mut job = Job::new(
+        0,
+        AGENT_TEST_SKILL.to_string(),
+        ScopeDefinition::file(source_file.clone()),
+        source_file.display().to_string(),
+        Some(AGENT_TEST_PROMPT.to_string()),
+        agent_config.id.clone(),
+        source_file,
+        1,
+        None,
+    );
+    job.workspace_path = Some(work_dir.to_path_buf());
+    job
+}
+
+/// The `agent test` round-trip without the output, shared with `kyco doctor`.
+///
+/// Errors only when the agent or its adapter does not exist; a failed run is
+/// reported through `success`/`error`.
+pub(super) async fn run_agent_test(
+    work_dir: &Path,
+    cfg: &Config,
+    name: &str,
+    timeout: Duration,
+) -> Result<AgentTestReport> {
+    let Some(mut agent_config) = cfg.get_agent(name) else {
+        anyhow::bail!("Agent not found: {}", name);
+    };
+
+    let registry = AgentRegistry::new();
+    let Some(adapter) = registry.get_for_config(&agent_config) else {
+        anyhow::bail!(
+            "No adapter found for agent '{}' (sdk: {})",
+            name,
+            agent_config.sdk_type.default_name()
+        );
+    };
+
+    let job = prepare_agent_test(work_dir, &mut agent_config);
+
+    // The adapter lazily starts the bridge; drain its log events so it never blocks,
+    // keeping the session id in case the run has to be interrupted.
+    let (log_tx, mut log_rx) = tokio::sync::mpsc::channel::<LogEvent>(100);
+    let session_id = Arc::new(Mutex::new(None::<String>));
+    let log_drain = tokio::spawn({
+        let session_id = Arc::clone(&session_id);
+        async move {
+            while let Some(event) = log_rx.recv().await {
+                if let Some(id) = event
+                    .tool_args
+                    .as_ref()
+                    .and_then(|args| args.get("session_id"))
+                    .and_then(|v| v.as_str())
+                {
+                    if let Ok(mut guard) = session_id.lock() {
+                        *guard = Some(id.to_string());
+                    }
+                }
+            }
+        }
+    });
+
+    let started = Instant::now();
+    let outcome = tokio::time::timeout(
+        timeout,
+        adapter.run(&job, work_dir, &agent_config, log_tx),
+    )
+    .await;
+    let duration_ms = started.elapsed().as_millis() as u64;
+    log_drain.abort();
+
+    let session_id = session_id.lock().ok().and_then(|guard| guard.clone());
+    if outcome.is_err() {
+        if let Some(session_id) = session_id {
+            let sdk_type = agent_config.sdk_type;
+            let _ = tokio::task::spawn_blocking(move || {
+                let client = BridgeClient::new();
+                match sdk_type {
+                    SdkType::Codex => client.interrupt_codex(&session_id),
+                    _ => client.interrupt_claude(&session_id),
+                }
+            })
+            .await;
+        }
+    }
+
+    let (success, reply, error) = match outcome {
+        Ok(Ok(result)) => {
+            let reply = result.output_text.map(|t| t.trim().to_string());
+            (result.success, reply, result.error)
+        }
+        Ok(Err(e)) => (false, None, Some(format!("{e:#}"))),
+        Err(_) => (
+            false,
+            None,
+            Some(format!("Timed out after {}s", timeout.as_secs())),
+        ),
+    };
+
+    Ok(AgentTestReport {
+        agent: name.to_string(),
+        sdk: agent_config.sdk_type.default_name().to_string(),
+        model: agent_config.model.clone(),
+        adapter: adapter.id().to_string(),
+        bridge_version: BridgeClient::new().health_check().ok().map(|h| h.version),
+        success,
+        reply,
+        duration_ms,
+        error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::{ClaudeBridgeAdapter, CodexBridgeAdapter};
+
+    #[test]
+    fn agent_test_prompt_is_a_read_only_ping() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+
+        let mut claude = AgentConfig::claude_default();
+        let job = prepare_agent_test(tmp.path(), &mut claude);
+        let prompt = ClaudeBridgeAdapter::new().build_prompt(&job, &claude);
+        assert!(prompt.ends_with(AGENT_TEST_PROMPT), "got: {}", prompt);
+        assert!(!prompt.contains("modify"), "got: {}", prompt);
+        assert_eq!(claude.permission_mode, "dontAsk");
+        assert!(claude.allowed_tools.is_empty());
+
+        let mut codex = AgentConfig::codex_default();
+        let job = prepare_agent_test(tmp.path(), &mut codex);
+        let prompt = CodexBridgeAdapter::new().build_prompt(&job, &codex, tmp.path());
+        assert!(prompt.contains(AGENT_TEST_PROMPT), "got: {}", prompt);
+        assert!(!prompt.contains("modify"), "got: {}", prompt);
+        assert_eq!(codex.sandbox.as_deref(), Some("read-only"));
+    }
+}
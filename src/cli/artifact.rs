@@ -0,0 +1,68 @@
+//! Artifact commands (verify that evidence files referenced by findings still exist).
+
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use super::project::{resolve_project_id, resolve_project_root};
+use crate::bugbounty::{ArtifactCheck, BugBountyManager};
+
+/// Verify every artifact of a project and report dangling references
+pub fn verify(work_dir: &Path, project: Option<String>, json: bool) -> Result<()> {
+    let project_id = resolve_project_id(project)?;
+
+    let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
+    let project = manager
+        .get_project(&project_id)?
+        .ok_or_else(|| anyhow::anyhow!("Project not found: {}", project_id))?;
+    let root = resolve_project_root(work_dir, &project.root_path);
+
+    let checks = manager
+        .artifacts()
+        .list_by_project(&project_id)?
+        .iter()
+        .map(|artifact| manager.artifacts().verify(artifact, &root))
+        .collect::<Result<Vec<ArtifactCheck>>>()?;
+    let dangling: Vec<&ArtifactCheck> = checks.iter().filter(|c| !c.exists).collect();
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "project_id": project_id,
+                "project_root": root,
+                "total": checks.len(),
+                "dangling": dangling,
+            }))?
+        );
+    } else {
+        println!(
+            "Checked {} artifact(s) under {}",
+            checks.len(),
+            root.display()
+        );
+        if dangling.is_empty() {
+            println!("All artifact files exist.");
+        } else {
+            println!("\nDangling artifacts ({}):", dangling.len());
+            for check in &dangling {
+                let owner = check
+                    .artifact
+                    .finding_id
+                    .as_deref()
+                    .or(check.artifact.job_id.as_deref())
+                    .unwrap_or("-");
+                println!(
+                    "  {:<40} {:<14} {}",
+                    owner,
+                    check.artifact.artifact_type.as_str(),
+                    check.artifact.path
+                );
+            }
+        }
+    }
+
+    if !dangling.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
@@ -3,9 +3,15 @@
 use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 
+use crate::agent::{
+    render_chain_diagram, AgentRegistry, ChainDiagramFormat, ChainProgressEvent, ChainRunner,
+};
 use crate::config::{ChainStep, Config, ModeChain};
+use crate::{Job, LogEvent, LogEventKind, ScopeDefinition};
 
 const AUTH_HEADER: &str = "X-KYCO-Token";
+/// How long `chain run` waits for buffered log lines after the chain returns
+const LOG_DRAIN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
 
 /// Resolve the config path - uses global config (~/.kyco/config.toml) as default,
 /// but allows override via --config flag for project-local configs.
@@ -146,6 +152,7 @@ pub fn chain_set_command(
                 agent: None,
                 inject_context: None,
                 loop_to: None,
+                on_state: Default::default(),
             })
             .collect();
     }
@@ -194,3 +201,129 @@ pub fn chain_delete_command(
     println!("Chain deleted: {}", name);
     Ok(())
 }
+
+pub fn chain_diagram_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    name: &str,
+    format: &str,
+) -> Result<()> {
+    let Some(format) = ChainDiagramFormat::parse(format) else {
+        anyhow::bail!("Unknown diagram format: {} (expected mermaid or dot)", format);
+    };
+    let (cfg, _) = load_or_init_config(work_dir, config_override)?;
+    let Some(chain) = cfg.chain.get(name) else {
+        anyhow::bail!("Chain not found: {}", name);
+    };
+
+    print!("{}", render_chain_diagram(name, chain, format));
+    Ok(())
+}
+
+/// Run a chain headlessly (no GUI) against a file and block until it finishes.
+///
+/// Step progress and errors go to stderr; the final `ChainResult` is printed as
+/// JSON on stdout. Returns an error (non-zero exit) when the chain did not succeed.
+#[allow(clippy::too_many_arguments)]
+pub async fn chain_run_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    name: &str,
+    file: &Path,
+    line_start: usize,
+    line_end: Option<usize>,
+    prompt: Option<String>,
+    show_logs: bool,
+) -> Result<()> {
+    let (cfg, _) = load_or_init_config(work_dir, config_override)?;
+    let Some(chain) = cfg.get_chain(name).cloned() else {
+        anyhow::bail!("Chain not found: {}", name);
+    };
+    if line_start == 0 {
+        anyhow::bail!("--line-start must be >= 1");
+    }
+
+    let source_file = if file.is_absolute() {
+        file.to_path_buf()
+    } else {
+        work_dir.join(file)
+    };
+    if !source_file.exists() {
+        anyhow::bail!("File not found: {}", source_file.display());
+    }
+
+    let target = match line_end.filter(|end| *end != line_start) {
+        Some(end) => format!("{}:{}-{}", source_file.display(), line_start, end),
+        None => format!("{}:{}", source_file.display(), line_start),
+    };
+    let mut job = Job::new(
+        0,
+        name.to_string(),
+        ScopeDefinition::file(source_file.clone()),
+        target,
+        prompt,
+        cfg.settings.gui.default_agent.clone(),
+        source_file,
+        line_start,
+        None,
+    );
+    job.workspace_path = Some(work_dir.to_path_buf());
+
+    let (log_tx, mut log_rx) = tokio::sync::mpsc::channel::<LogEvent>(100);
+    let mut log_printer = tokio::spawn(async move {
+        while let Some(event) = log_rx.recv().await {
+            if show_logs || event.kind == LogEventKind::Error {
+                eprintln!("[{}] {}", event.kind, event.summary);
+            }
+        }
+    });
+
+    let (progress_tx, progress_rx) = std::sync::mpsc::channel::<ChainProgressEvent>();
+    let progress_printer = std::thread::spawn(move || {
+        while let Ok(progress) = progress_rx.recv() {
+            let step = progress.step_index + 1;
+            if progress.is_starting {
+                eprintln!(
+                    "step {}/{} {} ...",
+                    step, progress.total_steps, progress.skill
+                );
+                continue;
+            }
+            let outcome = match progress.step_result.as_ref() {
+                Some(r) if r.skipped => "skipped".to_string(),
+                Some(r) => match r.agent_result.as_ref() {
+                    Some(a) if a.success => format!("ok ({} files changed)", a.files_changed),
+                    Some(a) => format!("failed: {}", a.error.as_deref().unwrap_or("unknown error")),
+                    None => "done".to_string(),
+                },
+                None => "done".to_string(),
+            };
+            eprintln!(
+                "step {}/{} {} {}",
+                step, progress.total_steps, progress.skill, outcome
+            );
+        }
+    });
+
+    let registry = AgentRegistry::new();
+    let runner = ChainRunner::new(&cfg, &registry, work_dir);
+    let result = runner
+        .run_chain(name, &chain, &job, log_tx, Some(progress_tx))
+        .await;
+
+    // A sender kept alive by a lingering agent task would keep the printer waiting forever
+    if tokio::time::timeout(LOG_DRAIN_TIMEOUT, &mut log_printer)
+        .await
+        .is_err()
+    {
+        log_printer.abort();
+    }
+    let _ = progress_printer.join();
+
+    println!("{}", serde_json::to_string_pretty(&result.to_json())?);
+
+    if !result.success {
+        anyhow::bail!("Chain '{}' failed", name);
+    }
+    Ok(())
+}
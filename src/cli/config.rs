@@ -0,0 +1,115 @@
+//! Config inspection commands (read-only).
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+use super::job::http::{http_post_json, load_gui_http_settings};
+use crate::config::{Config, IssueSeverity};
+
+/// Load the merged config; an explicit `--config` path must already exist.
+pub(super) fn load_config(work_dir: &Path, config_override: Option<&PathBuf>) -> Result<Config> {
+    load_config_with(work_dir, config_override, Config::load)
+}
+
+/// Like [`load_config`], but never creates or rewrites the global config.
+pub(super) fn load_config_read_only(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+) -> Result<Config> {
+    load_config_with(work_dir, config_override, Config::load_read_only)
+}
+
+fn load_config_with(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    load_global: fn() -> Result<Config>,
+) -> Result<Config> {
+    let mut cfg = match config_override {
+        None => load_global()?,
+        Some(p) => {
+            let path = if p.is_absolute() {
+                p.clone()
+            } else {
+                work_dir.join(p)
+            };
+            if !path.exists() {
+                anyhow::bail!("Config file not found: {}", path.display());
+            }
+            Config::from_file(&path)?
+        }
+    };
+    cfg.discover_skills(Some(work_dir));
+    Ok(cfg)
+}
+
+/// Ask the running GUI to reload its config from disk and print what it loaded.
+pub fn config_reload_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    json: bool,
+) -> Result<()> {
+    let (port, token) = load_gui_http_settings(work_dir, config_override);
+    let url = format!("http://127.0.0.1:{port}/ctl/config/reload");
+    let value = http_post_json(&url, token.as_deref(), serde_json::json!({}))?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&value)?);
+        return Ok(());
+    }
+
+    let count = |key: &str| value.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+    println!(
+        "Config reloaded: {} modes, {} skills, {} chains, {} agents",
+        count("modes"),
+        count("skills"),
+        count("chains"),
+        count("agents")
+    );
+    Ok(())
+}
+
+/// Check modes, skills, chains, agents, and aliases for consistency.
+///
+/// Exits with code 1 if any error-level issue is found; warnings alone pass.
+pub fn config_validate_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    json: bool,
+) -> Result<()> {
+    let cfg = load_config(work_dir, config_override)?;
+    let issues = cfg.validate();
+    let errors = issues
+        .iter()
+        .filter(|i| i.severity == IssueSeverity::Error)
+        .count();
+    let warnings = issues.len() - errors;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "valid": errors == 0,
+                "errors": errors,
+                "warnings": warnings,
+                "issues": issues,
+            }))?
+        );
+    } else {
+        for issue in &issues {
+            println!(
+                "{:<7} {}: {}",
+                issue.severity, issue.location, issue.message
+            );
+        }
+        if issues.is_empty() {
+            println!("Config OK");
+        } else {
+            println!("{} error(s), {} warning(s)", errors, warnings);
+        }
+    }
+
+    if errors > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
@@ -0,0 +1,461 @@
+//! `kyco doctor`: run independent setup checks and report pass/warn/fail per check.
+//!
+//! Each check reuses the piece of KYCo it is about (`GitManager::new`, config
+//! loading + `Config::validate`, the agent adapters' availability checks and,
+//! with `--test-agents`, the `agent test` round-trip, the GUI's ctl port
+//! settings), so a passing doctor means those code paths work.
+
+use anyhow::Result;
+use serde::Serialize;
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::agent::run_agent_test;
+use super::config::load_config_read_only;
+use super::job::http::load_gui_http_settings;
+use crate::SdkType;
+use crate::agent::AgentRegistry;
+use crate::config::{Config, IssueSeverity};
+use crate::git::GitManager;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl CheckStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "pass",
+            CheckStatus::Warn => "warn",
+            CheckStatus::Fail => "fail",
+        }
+    }
+}
+
+/// Result of one doctor check
+#[derive(Debug, Clone, Serialize)]
+struct Check {
+    name: String,
+    status: CheckStatus,
+    detail: String,
+}
+
+impl Check {
+    fn new(name: impl Into<String>, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Run all checks and print one line per check plus a summary.
+///
+/// Exits with code 1 if any check failed; warnings alone pass. The config is
+/// loaded read-only, so a diagnostic never creates or rewrites it.
+pub async fn doctor_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    test_agents: bool,
+    json: bool,
+) -> Result<()> {
+    let mut checks = vec![check_git(work_dir)];
+
+    match load_config_read_only(work_dir, config_override) {
+        Ok(cfg) => {
+            checks.push(check_config(&cfg));
+            let mut agent_checks = check_agents(&cfg);
+            if test_agents {
+                run_agent_tests(work_dir, &cfg, &mut agent_checks).await;
+            }
+            summarize_agents(&mut agent_checks);
+            checks.extend(agent_checks);
+        }
+        Err(e) => checks.push(Check::new(
+            "config",
+            CheckStatus::Fail,
+            format!("failed to load: {e:#}"),
+        )),
+    }
+
+    checks.push(check_kyco_dir(work_dir));
+    let (port, _) = load_gui_http_settings(work_dir, config_override);
+    checks.push(check_ctl_port(port, ping_kyco_gui));
+
+    let count = |status: CheckStatus| checks.iter().filter(|c| c.status == status).count();
+    let (passed, warnings, failed) = (
+        count(CheckStatus::Pass),
+        count(CheckStatus::Warn),
+        count(CheckStatus::Fail),
+    );
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "ok": failed == 0,
+                "passed": passed,
+                "warnings": warnings,
+                "failed": failed,
+                "checks": checks,
+            }))?
+        );
+    } else {
+        for check in &checks {
+            println!(
+                "{:<5} {:<20} {}",
+                check.status.as_str(),
+                check.name,
+                check.detail
+            );
+        }
+        println!(
+            "\n{} passed, {} warning(s), {} failed",
+            passed, warnings, failed
+        );
+    }
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn binary_on_path(name: &str) -> bool {
+    std::process::Command::new("which")
+        .arg(name)
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+fn check_git(work_dir: &Path) -> Check {
+    if !binary_on_path("git") {
+        return Check::new("git", CheckStatus::Fail, "git not found on PATH");
+    }
+    match GitManager::new(work_dir) {
+        Ok(git) => {
+            let detail = match git.current_branch() {
+                Ok(branch) => format!("{} (branch {})", work_dir.display(), branch),
+                Err(_) => work_dir.display().to_string(),
+            };
+            Check::new("git", CheckStatus::Pass, detail)
+        }
+        Err(e) => Check::new("git", CheckStatus::Fail, format!("{e:#}")),
+    }
+}
+
+fn check_config(cfg: &Config) -> Check {
+    let issues = cfg.validate();
+    let errors = issues
+        .iter()
+        .filter(|i| i.severity == IssueSeverity::Error)
+        .count();
+    let warnings = issues.len() - errors;
+
+    if errors > 0 {
+        let first = issues
+            .iter()
+            .find(|i| i.severity == IssueSeverity::Error)
+            .map(|i| format!("{}: {}", i.location, i.message))
+            .unwrap_or_default();
+        Check::new(
+            "config",
+            CheckStatus::Fail,
+            format!("{errors} error(s), first: {first} (see `kyco config validate`)"),
+        )
+    } else if warnings > 0 {
+        Check::new(
+            "config",
+            CheckStatus::Warn,
+            format!("{warnings} warning(s) (see `kyco config validate`)"),
+        )
+    } else {
+        Check::new(
+            "config",
+            CheckStatus::Pass,
+            format!(
+                "{} modes, {} skills, {} chains, {} agents",
+                cfg.mode.len(),
+                cfg.skill.len(),
+                cfg.chain.len(),
+                cfg.agent.len()
+            ),
+        )
+    }
+}
+
+/// One check per configured agent. A missing agent CLI only warns (unused agents
+/// are common); [`summarize_agents`] fails when no agent is usable at all.
+fn check_agents(cfg: &Config) -> Vec<Check> {
+    let registry = AgentRegistry::new();
+    let node_found = binary_on_path("node");
+    let mut names: Vec<&String> = cfg.agent.keys().collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .filter_map(|name| cfg.get_agent(name))
+        .map(|agent| {
+            let name = format!("agent:{}", agent.id);
+            let Some(adapter) = registry.get_for_config(&agent) else {
+                return Check::new(
+                    name,
+                    CheckStatus::Warn,
+                    format!("no adapter for sdk '{}'", agent.sdk_type.default_name()),
+                );
+            };
+            let has_template = agent
+                .command_template
+                .as_deref()
+                .is_some_and(|t| !t.trim().is_empty());
+            match agent.sdk_type {
+                SdkType::Custom if has_template && adapter.is_available() => Check::new(
+                    name,
+                    CheckStatus::Pass,
+                    "command from command_template found on PATH",
+                ),
+                SdkType::Custom if has_template => Check::new(
+                    name,
+                    CheckStatus::Warn,
+                    "command from command_template not found on PATH",
+                ),
+                sdk => {
+                    let cli = sdk.default_binary();
+                    sdk_agent_check(
+                        name,
+                        cli,
+                        binary_on_path(cli),
+                        adapter.is_available(),
+                        node_found,
+                    )
+                }
+            }
+        })
+        .collect()
+}
+
+/// SDK agents need their CLI on PATH, plus a running SDK bridge or node to start one.
+fn sdk_agent_check(
+    name: String,
+    cli: &str,
+    cli_found: bool,
+    bridge_reachable: bool,
+    node_found: bool,
+) -> Check {
+    if !cli_found {
+        return Check::new(
+            name,
+            CheckStatus::Warn,
+            format!("`{cli}` CLI not found on PATH"),
+        );
+    }
+    if bridge_reachable {
+        Check::new(
+            name,
+            CheckStatus::Pass,
+            format!("`{cli}` found on PATH, SDK bridge reachable"),
+        )
+    } else if node_found {
+        Check::new(
+            name,
+            CheckStatus::Pass,
+            format!("`{cli}` found on PATH, node found (SDK bridge starts on demand)"),
+        )
+    } else {
+        Check::new(
+            name,
+            CheckStatus::Warn,
+            format!("`{cli}` found on PATH, but node is not (required by the SDK bridge)"),
+        )
+    }
+}
+
+/// Timeout for each `--test-agents` round-trip
+const AGENT_TEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Run the `agent test` round-trip for every agent that passed its local checks;
+/// a failed round-trip downgrades the agent to a warning.
+///
+/// This is the same read-only "reply OK" ping as `kyco agent test`: tools are
+/// denied and the job targets a virtual file, so no agent can change the repo.
+async fn run_agent_tests(work_dir: &Path, cfg: &Config, checks: &mut [Check]) {
+    for check in checks
+        .iter_mut()
+        .filter(|check| check.status == CheckStatus::Pass)
+    {
+        let Some(name) = check.name.strip_prefix("agent:") else {
+            continue;
+        };
+        match run_agent_test(work_dir, cfg, name, AGENT_TEST_TIMEOUT).await {
+            Ok(report) if report.success => {
+                check.detail = format!(
+                    "{}; test prompt answered in {}ms",
+                    check.detail, report.duration_ms
+                );
+            }
+            Ok(report) => {
+                check.status = CheckStatus::Warn;
+                check.detail = format!(
+                    "test prompt failed: {}",
+                    report.error.as_deref().unwrap_or("no reply")
+                );
+            }
+            Err(e) => {
+                check.status = CheckStatus::Warn;
+                check.detail = format!("test prompt failed: {e:#}");
+            }
+        }
+    }
+}
+
+/// Add a failing `agents` check when no agent is configured or none can run
+fn summarize_agents(checks: &mut Vec<Check>) {
+    if checks.is_empty() {
+        checks.push(Check::new(
+            "agents",
+            CheckStatus::Fail,
+            "no agents configured",
+        ));
+    } else if checks.iter().all(|c| c.status != CheckStatus::Pass) {
+        checks.push(Check::new(
+            "agents",
+            CheckStatus::Fail,
+            "none of the configured agents can run",
+        ));
+    }
+}
+
+/// `.kyco` must be writable (job store, worktrees); a missing dir is created on first run.
+fn check_kyco_dir(work_dir: &Path) -> Check {
+    let dir = work_dir.join(".kyco");
+    let (probe_dir, missing) = if dir.is_dir() {
+        (dir.as_path(), false)
+    } else {
+        (work_dir, true)
+    };
+
+    let probe = probe_dir.join(format!(".kyco-doctor-{}", std::process::id()));
+    let writable = std::fs::write(&probe, b"ok").is_ok();
+    let _ = std::fs::remove_file(&probe);
+
+    match (writable, missing) {
+        (true, false) => Check::new(".kyco", CheckStatus::Pass, dir.display().to_string()),
+        (true, true) => Check::new(
+            ".kyco",
+            CheckStatus::Warn,
+            format!(
+                "{} does not exist yet (created on first run)",
+                dir.display()
+            ),
+        ),
+        (false, _) => Check::new(
+            ".kyco",
+            CheckStatus::Fail,
+            format!("{} is not writable", probe_dir.display()),
+        ),
+    }
+}
+
+/// The ctl port must be free, or already held by a running KYCo GUI.
+///
+/// `ping` returns the version of a KYCo GUI answering on the port, if any.
+fn check_ctl_port(port: u16, ping: impl Fn(u16) -> Option<String>) -> Check {
+    let name = "ctl port";
+    if TcpListener::bind(("127.0.0.1", port)).is_ok() {
+        return Check::new(name, CheckStatus::Pass, format!("{port} is free"));
+    }
+    match ping(port) {
+        Some(version) => Check::new(
+            name,
+            CheckStatus::Pass,
+            format!("{port} is used by a running KYCo GUI (v{version})"),
+        ),
+        None => Check::new(
+            name,
+            CheckStatus::Fail,
+            format!("{port} is in use by another process (change settings.gui.http_port)"),
+        ),
+    }
+}
+
+fn ping_kyco_gui(port: u16) -> Option<String> {
+    let agent = ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(2))
+        .build();
+    let value: serde_json::Value = agent
+        .get(&format!("http://127.0.0.1:{port}/ctl/ping"))
+        .call()
+        .ok()?
+        .into_json()
+        .ok()?;
+    value
+        .get("version")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kyco_dir_warns_when_missing_and_passes_when_writable() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(check_kyco_dir(dir.path()).status, CheckStatus::Warn);
+
+        std::fs::create_dir(dir.path().join(".kyco")).unwrap();
+        assert_eq!(check_kyco_dir(dir.path()).status, CheckStatus::Pass);
+        assert_eq!(
+            std::fs::read_dir(dir.path().join(".kyco")).unwrap().count(),
+            0
+        );
+    }
+
+    #[test]
+    fn missing_agent_cli_warns_even_with_node() {
+        let check = |cli_found, bridge, node| {
+            sdk_agent_check("a".to_string(), "claude", cli_found, bridge, node)
+        };
+
+        let missing = check(false, false, true);
+        assert_eq!(missing.status, CheckStatus::Warn);
+        assert!(missing.detail.contains("`claude` CLI not found"));
+        assert_eq!(check(false, true, true).status, CheckStatus::Warn);
+
+        assert_eq!(check(true, true, false).status, CheckStatus::Pass);
+        assert_eq!(check(true, false, true).status, CheckStatus::Pass);
+        assert_eq!(check(true, false, false).status, CheckStatus::Warn);
+    }
+
+    #[test]
+    fn no_usable_agent_fails() {
+        let mut checks = vec![Check::new("agent:claude", CheckStatus::Warn, "missing")];
+        summarize_agents(&mut checks);
+        assert_eq!(checks.last().map(|c| c.status), Some(CheckStatus::Fail));
+
+        let mut checks = vec![Check::new("agent:claude", CheckStatus::Pass, "ok")];
+        summarize_agents(&mut checks);
+        assert_eq!(checks.len(), 1);
+    }
+
+    #[test]
+    fn bound_ctl_port_fails_unless_kyco_answers() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        assert_eq!(check_ctl_port(port, |_| None).status, CheckStatus::Fail);
+        let check = check_ctl_port(port, |_| Some("1.2.3".to_string()));
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert!(check.detail.contains("v1.2.3"));
+
+        drop(listener);
+        assert_eq!(check_ctl_port(port, |_| None).status, CheckStatus::Pass);
+    }
+}
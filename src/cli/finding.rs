@@ -3,8 +3,10 @@
 use anyhow::{bail, Context, Result};
 
 use crate::bugbounty::{
-    BugBountyJob, BugBountyManager, Confidence, Finding, FindingStatus, Severity,
+    BugBountyJob, BugBountyManager, Confidence, Finding, FindingStatus, NextContext, Severity,
+    reference_url,
 };
+use crate::cli::project::resolve_project_root;
 use std::path::Path;
 
 /// List findings
@@ -15,14 +17,16 @@ pub fn list(
     search: Option<String>,
     json: bool,
 ) -> Result<()> {
+    // Validate filters before touching the database
+    let status = status.as_deref().map(parse_status).transpose()?;
+    let severity = severity.as_deref().map(parse_severity).transpose()?;
+
     let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
 
     let mut findings = if let Some(ref project_id) = project {
         manager.list_findings_by_project(project_id)?
-    } else if let Some(ref status_str) = status {
+    } else if let Some(status) = status {
         // Slightly faster path for "status-only" queries across projects.
-        let status = FindingStatus::from_str(status_str)
-            .ok_or_else(|| anyhow::anyhow!("Invalid status: {}", status_str))?;
         manager.list_findings_by_status(status)?
     } else {
         // List all findings from all projects
@@ -35,16 +39,15 @@ pub fn list(
     };
 
     // Filter by status if specified (supports project+status)
-    if let Some(ref status_str) = status {
-        let status = FindingStatus::from_str(status_str)
-            .ok_or_else(|| anyhow::anyhow!("Invalid status: {}", status_str))?;
+    if let Some(status) = status {
         findings.retain(|f| f.status == status);
+        // A single status is one Kanban column: show it in triage order
+        findings.sort_by_key(Finding::priority_key);
     }
 
     // Filter by severity if specified
-    if let Some(ref sev_str) = severity {
-        let sev = Severity::from_str(sev_str);
-        findings.retain(|f| f.severity == sev);
+    if let Some(sev) = severity {
+        findings.retain(|f| f.severity == Some(sev));
     }
 
     // Search filter
@@ -75,17 +78,19 @@ pub fn list(
 
         // Print table header
         println!(
-            "{:<12} {:<10} {:<12} {:<40} {:<20}",
-            "ID", "SEVERITY", "STATUS", "TITLE", "PROJECT"
+            "{:<12} {:<10} {:<12} {:<5} {:<40} {:<20}",
+            "ID", "SEVERITY", "STATUS", "PRIO", "TITLE", "PROJECT"
         );
-        println!("{}", "-".repeat(94));
+        println!("{}", "-".repeat(100));
 
         for f in findings {
+            let prio = f.computed_priority.map(|p| p.to_string());
             println!(
-                "{:<12} {:<10} {:<12} {:<40} {:<20}",
+                "{:<12} {:<10} {:<12} {:<5} {:<40} {:<20}",
                 f.id,
                 f.severity.map(|s| s.as_str()).unwrap_or("-"),
                 f.status.as_str(),
+                prio.as_deref().unwrap_or("-"),
                 truncate(&f.title, 38),
                 truncate(&f.project_id, 18),
             );
@@ -95,6 +100,62 @@ pub fn list(
     Ok(())
 }
 
+/// Parse a `--status` value, listing the valid values on error.
+fn parse_status(s: &str) -> Result<FindingStatus> {
+    FindingStatus::from_str(s.trim()).ok_or_else(|| {
+        let valid: Vec<&str> = FindingStatus::ALL.iter().map(|v| v.as_str()).collect();
+        anyhow::anyhow!("Invalid status: '{}'. Valid values: {}", s, valid.join(", "))
+    })
+}
+
+/// Parse a `--severity` value, listing the valid values on error.
+fn parse_severity(s: &str) -> Result<Severity> {
+    Severity::from_str(s.trim()).ok_or_else(|| {
+        let valid: Vec<&str> = Severity::ALL.iter().map(|v| v.as_str()).collect();
+        anyhow::anyhow!("Invalid severity: '{}'. Valid values: {}", s, valid.join(", "))
+    })
+}
+
+/// Full-text search across findings
+pub fn search(query: &str, project: Option<&str>, json: bool) -> Result<()> {
+    let query = query.trim();
+    if query.is_empty() {
+        bail!("Search query must not be empty");
+    }
+
+    let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
+    let findings = manager.search_findings(query, project)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&findings)?);
+        return Ok(());
+    }
+
+    if findings.is_empty() {
+        println!("No findings match '{}'.", query);
+        return Ok(());
+    }
+
+    println!(
+        "{:<20} {:<10} {:<12} {:<40}",
+        "ID", "SEVERITY", "STATUS", "TITLE"
+    );
+    println!("{}", "-".repeat(85));
+
+    for f in &findings {
+        println!(
+            "{:<20} {:<10} {:<12} {:<40}",
+            truncate(&f.id, 18),
+            f.severity.map(|s| s.as_str()).unwrap_or("-"),
+            f.status.as_str(),
+            truncate(&f.title, 38),
+        );
+    }
+    println!("\n{} finding(s)", findings.len());
+
+    Ok(())
+}
+
 /// Show a finding by ID
 pub fn show(id: &str, json: bool) -> Result<()> {
     let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
@@ -114,6 +175,15 @@ pub fn show(id: &str, json: bool) -> Result<()> {
             finding.severity.map(|s| s.as_str()).unwrap_or("-")
         );
         println!("Status:        {}", finding.status.as_str());
+        if let Some(ref canonical) = finding.duplicate_of {
+            println!("Duplicate of:  {}", canonical);
+        }
+        if let Some(priority) = finding.manual_priority {
+            println!("Priority:      {} (pinned)", priority);
+        }
+        if let Some(priority) = finding.computed_priority {
+            println!("Computed prio: {}", priority);
+        }
         println!(
             "Confidence:    {}",
             finding.confidence.map(|c| c.as_str()).unwrap_or("-")
@@ -145,10 +215,27 @@ pub fn show(id: &str, json: bool) -> Result<()> {
             }
         }
 
+        if !finding.references.is_empty() {
+            println!("\nReferences:");
+            for reference in &finding.references {
+                match reference_url(reference).filter(|url| url != reference) {
+                    Some(url) => println!("  - {} ({})", reference, url),
+                    None => println!("  - {}", reference),
+                }
+            }
+        }
+
         if let Some(ref cwe) = finding.cwe_id {
             println!("\nCWE: {}", cwe);
         }
 
+        if let Some(score) = finding.cvss_score {
+            match finding.cvss_vector.as_deref() {
+                Some(vector) => println!("CVSS: {:.1} ({})", score, vector),
+                None => println!("CVSS: {:.1}", score),
+            }
+        }
+
         if let Some(ref taint_path) = finding.taint_path {
             println!("\nTaint Path:");
             println!("  {}", taint_path);
@@ -211,6 +298,7 @@ pub fn create(
     impact: Option<String>,
     confidence: Option<String>,
     cwe: Option<String>,
+    cvss: Option<String>,
     assets: Vec<String>,
     write_notes: bool,
     json: bool,
@@ -264,6 +352,10 @@ pub fn create(
         finding = finding.with_cwe(c);
     }
 
+    if let Some(ref v) = cvss {
+        finding = finding.with_cvss_vector(v)?;
+    }
+
     for asset in assets {
         finding = finding.with_affected_asset(asset);
     }
@@ -293,20 +385,64 @@ pub fn create(
     Ok(())
 }
 
+/// Clone a finding as a new Raw variant
+pub fn clone(id: &str, title: Option<&str>, json: bool) -> Result<()> {
+    let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
+
+    let finding = manager.clone_finding(id, title)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&finding)?);
+    } else {
+        println!("Cloned {} -> {}", id, finding.id);
+        println!("Title:  {}", finding.title);
+        println!("Status: {}", finding.status.as_str());
+    }
+
+    Ok(())
+}
+
 /// Set the status of a finding (Kanban column change)
-pub fn set_status(id: &str, status_str: &str) -> Result<()> {
+///
+/// Moving to `duplicate` records the canonical finding; it is read from stdin
+/// when `duplicate_of` is not given.
+pub fn set_status(id: &str, status_str: &str, duplicate_of: Option<&str>) -> Result<()> {
     let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
 
-    let status = FindingStatus::from_str(status_str).ok_or_else(|| {
-        anyhow::anyhow!(
-            "Invalid status: '{}'. Valid values: raw, needs_repro, verified, report_draft, submitted, triaged, accepted, paid, duplicate, wont_fix, false_positive, out_of_scope",
-            status_str
-        )
-    })?;
+    let status = parse_status(status_str)?;
 
     // Check finding exists
-    if manager.get_finding(id)?.is_none() {
+    let Some(finding) = manager.get_finding(id)? else {
         bail!("Finding not found: {}", id);
+    };
+    // A single id keeps its old behavior: closed findings still move, with a warning
+    if !finding.status.can_transition_to(status) {
+        eprintln!(
+            "Warning: {} was closed ({}); bulk moves (--to) only reopen it as raw",
+            id,
+            finding.status.as_str()
+        );
+    }
+
+    if status == FindingStatus::Duplicate {
+        let canonical_id = match duplicate_of {
+            Some(canonical_id) => canonical_id.trim().to_string(),
+            None => {
+                println!("Duplicate of which finding? (ID)");
+                let mut input = String::new();
+                std::io::stdin().read_line(&mut input)?;
+                input.trim().to_string()
+            }
+        };
+        if canonical_id.is_empty() {
+            bail!("A canonical finding ID is required to mark {} as duplicate", id);
+        }
+        manager.mark_finding_duplicate(id, &canonical_id)?;
+        println!("Updated {} -> duplicate of {}", id, canonical_id);
+        return Ok(());
+    }
+    if duplicate_of.is_some() {
+        bail!("--of only applies when the status is 'duplicate'");
     }
 
     manager.set_finding_status(id, status)?;
@@ -315,6 +451,130 @@ pub fn set_status(id: &str, status_str: &str) -> Result<()> {
     Ok(())
 }
 
+/// Move several findings to a status: the listed `ids`, plus every finding of
+/// `project` currently in `from_status`. Prints one line per finding and fails
+/// at the end if any of them could not be moved.
+pub fn set_status_bulk(
+    status_str: &str,
+    ids: &[String],
+    project: Option<&str>,
+    from_status: Option<&str>,
+) -> Result<()> {
+    let status = parse_status(status_str)?;
+    let from_status = from_status.map(parse_status).transpose()?;
+    if project.is_some() && from_status.is_none() {
+        bail!("--project only selects findings together with --from-status");
+    }
+
+    let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
+
+    let mut targets: Vec<String> = ids
+        .iter()
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .collect();
+    if let (Some(project_id), Some(from)) = (project, from_status) {
+        if manager.get_project(project_id)?.is_none() {
+            bail!("Project not found: {}", project_id);
+        }
+        for finding in manager.list_findings_by_project(project_id)? {
+            if finding.status == from && !targets.contains(&finding.id) {
+                targets.push(finding.id);
+            }
+        }
+    }
+    if targets.is_empty() {
+        bail!("No findings to update (use --ids, or --project with --from-status)");
+    }
+
+    let results = manager.set_findings_status(&targets, status);
+    let mut failed = 0;
+    for (id, result) in &results {
+        match result {
+            Ok(()) => println!("ok      {} -> {}", id, status.as_str()),
+            Err(e) => {
+                failed += 1;
+                println!("failed  {}: {}", id, e);
+            }
+        }
+    }
+    println!("\n{} updated, {} failed", results.len() - failed, failed);
+
+    if failed > 0 {
+        bail!("{} of {} finding(s) could not be updated", failed, results.len());
+    }
+    Ok(())
+}
+
+/// Merge a duplicate finding into its canonical finding
+pub fn merge(id: &str, into: &str) -> Result<()> {
+    let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
+
+    let merged = manager.merge_findings_into(id, into)?;
+    println!("Merged {} into {}", id, into);
+    println!(
+        "Moved {} artifact(s), {} job link(s) and {} duplicate(s)",
+        merged.artifacts_moved, merged.jobs_moved, merged.duplicates_moved
+    );
+
+    Ok(())
+}
+
+/// Pin a finding within its Kanban column, or clear the pin
+pub fn set_priority(id: &str, priority: Option<i32>) -> Result<()> {
+    let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
+
+    // Check finding exists
+    if manager.get_finding(id)?.is_none() {
+        bail!("Finding not found: {}", id);
+    }
+
+    manager.set_finding_priority(id, priority)?;
+    match priority {
+        Some(p) => println!("Pinned {} at priority {}", id, p),
+        None => println!("Cleared priority for {}", id),
+    }
+
+    Ok(())
+}
+
+/// Recompute the reachability-based priority of findings from their flow traces
+pub fn reprioritize(ids: &[String], project: Option<&str>, json: bool) -> Result<()> {
+    if ids.is_empty() && project.is_none() {
+        bail!("Pass finding IDs or --project");
+    }
+
+    let config = crate::config::Config::load_cached().context("Failed to load config")?;
+    let entry_points = &config.settings.bugbounty.entry_point_patterns;
+    let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
+
+    let mut targets = ids.to_vec();
+    if let Some(project_id) = project {
+        targets.extend(
+            manager
+                .list_findings_by_project(project_id)?
+                .into_iter()
+                .map(|f| f.id),
+        );
+    }
+
+    let mut results = Vec::with_capacity(targets.len());
+    for id in &targets {
+        let priority = manager.recompute_priority(id, entry_points)?;
+        results.push(serde_json::json!({ "id": id, "computed_priority": priority }));
+        if !json {
+            println!("{:<24} {}", id, priority);
+        }
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&results)?);
+    } else {
+        println!("\nRecomputed priority for {} finding(s)", targets.len());
+    }
+    Ok(())
+}
+
 /// Mark a finding as false positive
 pub fn mark_fp(id: &str, reason: &str) -> Result<()> {
     let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
@@ -400,6 +660,12 @@ fn export_markdown(f: &Finding) -> String {
     if let Some(ref cwe) = f.cwe_id {
         s.push_str(&format!("**CWE:** {}  \n", cwe));
     }
+    if let Some(score) = f.cvss_score {
+        match f.cvss_vector.as_deref() {
+            Some(vector) => s.push_str(&format!("**CVSS:** {:.1} (`{}`)  \n", score, vector)),
+            None => s.push_str(&format!("**CVSS:** {:.1}  \n", score)),
+        }
+    }
 
     s.push_str("\n## Attack Scenario\n\n");
     s.push_str(f.attack_scenario.as_deref().unwrap_or("(not specified)"));
@@ -421,6 +687,14 @@ fn export_markdown(f: &Finding) -> String {
         s.push('\n');
     }
 
+    if !f.references.is_empty() {
+        s.push_str("## References\n\n");
+        for reference in &f.references {
+            s.push_str(&format!("- {}\n", markdown_reference(reference)));
+        }
+        s.push('\n');
+    }
+
     if let Some(ref taint) = f.taint_path {
         s.push_str("## Flow\n\n");
         s.push_str(&format!("```\n{}\n```\n\n", taint));
@@ -454,6 +728,15 @@ fn export_intigriti(f: &Finding) -> String {
         s.push('\n');
     }
 
+    if !f.references.is_empty() {
+        s.push_str("References\n\n");
+        for reference in &f.references {
+            let url = reference_url(reference).unwrap_or_else(|| reference.clone());
+            s.push_str(&format!("    {}\n", url));
+        }
+        s.push('\n');
+    }
+
     s
 }
 
@@ -481,9 +764,25 @@ fn export_hackerone(f: &Finding) -> String {
         }
     }
 
+    if !f.references.is_empty() {
+        s.push_str("\n## References\n\n");
+        for reference in &f.references {
+            s.push_str(&format!("* {}\n", markdown_reference(reference)));
+        }
+    }
+
     s
 }
 
+/// A reference as a Markdown link when it resolves to a URL
+fn markdown_reference(reference: &str) -> String {
+    match reference_url(reference) {
+        Some(url) if url == reference => format!("<{}>", url),
+        Some(url) => format!("[{}]({})", reference, url),
+        None => reference.to_string(),
+    }
+}
+
 /// Export a finding to `notes/findings/<id>.md` under the project root
 pub fn export_notes(
     work_dir: &Path,
@@ -538,6 +837,62 @@ pub fn export_notes(
     Ok(())
 }
 
+/// Check raw agent output for an ingestible next_context (no DB writes).
+///
+/// Returns whether the output is valid; the caller picks the exit code.
+pub fn validate_output(file: &str, json_output: bool) -> Result<bool> {
+    let output = if file == "-" {
+        let mut buf = String::new();
+        std::io::Read::read_to_string(&mut std::io::stdin(), &mut buf)
+            .context("Failed to read stdin")?;
+        buf
+    } else {
+        std::fs::read_to_string(file).with_context(|| format!("Failed to read {}", file))?
+    };
+
+    let (report, valid) = match NextContext::validate_output(&output) {
+        Ok(report) => (report, true),
+        Err(report) => (report, false),
+    };
+
+    if json_output {
+        let value = serde_json::json!({
+            "valid": valid,
+            "source": report.source,
+            "findings": report.context.as_ref().map_or(0, |c| c.findings.len()),
+            "flow_edges": report.context.as_ref().map_or(0, |c| c.flow_edges.len()),
+            "artifacts": report.context.as_ref().map_or(0, |c| c.artifacts.len()),
+            "memory": report.context.as_ref().map_or(0, |c| c.memory.len()),
+            "errors": report.errors,
+            "warnings": report.warnings,
+        });
+        println!("{}", serde_json::to_string_pretty(&value)?);
+    } else {
+        match (&report.context, valid) {
+            (Some(ctx), true) => println!(
+                "Valid next_context (from {}): {} findings, {} flow edges, {} artifacts, {} memory entries",
+                report.source.unwrap_or("-"),
+                ctx.findings.len(),
+                ctx.flow_edges.len(),
+                ctx.artifacts.len(),
+                ctx.memory.len(),
+            ),
+            _ => match report.source {
+                Some(source) => println!("Invalid next_context (from {})", source),
+                None => println!("No next_context found"),
+            },
+        }
+        for error in &report.errors {
+            println!("  error:   {}", error);
+        }
+        for warning in &report.warnings {
+            println!("  warning: {}", warning);
+        }
+    }
+
+    Ok(valid)
+}
+
 /// Extract findings from a completed job's output
 pub fn extract_from_job(job_id: u64, project: Option<String>, json_output: bool) -> Result<()> {
     use crate::config::Config;
@@ -640,7 +995,7 @@ pub fn extract_from_job(job_id: u64, project: Option<String>, json_output: bool)
     Ok(())
 }
 
-/// Import findings from tool output (SARIF/Semgrep/Snyk/Nuclei)
+/// Import findings from tool output (SARIF/Semgrep/Snyk/Nuclei) or Markdown reports
 pub fn import(path: &str, project: &str, format: &str, json_output: bool) -> Result<()> {
     let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
 
@@ -663,9 +1018,10 @@ pub fn import(path: &str, project: &str, format: &str, json_output: bool) -> Res
         "semgrep" => manager.import_semgrep(path, project)?,
         "nuclei" => manager.import_nuclei(path, project)?,
         "snyk" => manager.import_snyk(path, project)?,
+        "markdown" | "md" => manager.import_markdown(path, project)?,
         "auto" => manager.import_auto(path, project)?,
         _ => bail!(
-            "Unknown format: {}. Use: sarif, semgrep, snyk, nuclei, auto",
+            "Unknown format: {}. Use: sarif, semgrep, snyk, nuclei, markdown, auto",
             format
         ),
     };
@@ -901,16 +1257,12 @@ pub fn link_job(finding_id: &str, job_id: &str, link_type: &str) -> Result<()> {
     // Resolve job ID - support both BugBounty job ID and KYCo job ID (with # prefix)
     let resolved_job_id = resolve_job_id(&manager, job_id)?;
 
-    // Check if already linked
-    if manager.job_findings().is_linked(&resolved_job_id, finding_id)? {
+    if manager.link_finding_job(finding_id, &resolved_job_id, link_type)? {
+        println!("Linked {} <-> {} (type: {})", finding_id, job_id, link_type);
+    } else {
         println!("Already linked: {} <-> {}", finding_id, job_id);
-        return Ok(());
     }
 
-    // Create link
-    manager.job_findings().link(&resolved_job_id, finding_id, link_type)?;
-    println!("Linked {} <-> {} (type: {})", finding_id, job_id, link_type);
-
     Ok(())
 }
 
@@ -926,14 +1278,20 @@ pub fn unlink_job(finding_id: &str, job_id: &str) -> Result<()> {
     // Resolve job ID
     let resolved_job_id = resolve_job_id(&manager, job_id)?;
 
-    // Remove link
-    manager.job_findings().unlink(&resolved_job_id, finding_id)?;
-    println!("Unlinked {} <-> {}", finding_id, job_id);
+    if manager.unlink_finding_job(finding_id, &resolved_job_id)? {
+        println!("Unlinked {} <-> {}", finding_id, job_id);
+    } else {
+        println!("Not linked: {} <-> {}", finding_id, job_id);
+    }
 
     Ok(())
 }
 
 /// Resolve job ID - supports BugBounty job ID or KYCo job ID (with # prefix)
+///
+/// Only existing job rows resolve: the executor records a row when a job runs for
+/// a BugBounty project, so a KYCo job without one is reported as not found rather
+/// than linked through a made-up row.
 fn resolve_job_id(manager: &BugBountyManager, job_id: &str) -> Result<String> {
     let kyco_job_id = if let Some(kyco_id_str) = job_id.strip_prefix('#') {
         Some(
@@ -949,7 +1307,14 @@ fn resolve_job_id(manager: &BugBountyManager, job_id: &str) -> Result<String> {
         if let Some(bb_job) = manager.jobs().get_by_kyco_job_id(kyco_id)? {
             return Ok(bb_job.id);
         }
-        bail!("No BugBounty job found for KYCo job #{}", kyco_id);
+        let fallback_id = kyco_id.to_string();
+        if manager.jobs().get(&fallback_id)?.is_some() {
+            return Ok(fallback_id);
+        }
+        bail!(
+            "No BugBounty job found for KYCo job #{} (it never ran for a BugBounty project)",
+            kyco_id
+        );
     }
 
     // Otherwise assume it's a BugBounty job ID directly
@@ -986,16 +1351,6 @@ impl NotesWriteAction {
     }
 }
 
-fn resolve_project_root(work_dir: &Path, root_path: &str) -> std::path::PathBuf {
-    let root = std::path::Path::new(root_path);
-    let abs = if root.is_absolute() {
-        root.to_path_buf()
-    } else {
-        work_dir.join(root)
-    };
-    abs.canonicalize().unwrap_or(abs)
-}
-
 fn resolve_notes_file_path(
     project_root: &Path,
     finding: &Finding,
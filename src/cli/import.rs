@@ -5,40 +5,7 @@ use std::path::{Path, PathBuf};
 
 use crate::bugbounty::{BugBountyManager, Finding, ImportResult};
 use crate::cli::job::ctl_create_jobs;
-
-fn load_active_project_id() -> Option<String> {
-    let path = dirs::home_dir()?.join(".kyco").join("active_project");
-    let content = std::fs::read_to_string(path).ok()?;
-    let trimmed = content.trim();
-    if trimmed.is_empty() {
-        None
-    } else {
-        Some(trimmed.to_string())
-    }
-}
-
-fn resolve_project_id(project: Option<String>) -> Result<String> {
-    if let Some(id) = project {
-        let trimmed = id.trim();
-        if !trimmed.is_empty() {
-            return Ok(trimmed.to_string());
-        }
-    }
-
-    if let Some(id) = load_active_project_id() {
-        return Ok(id);
-    }
-
-    bail!(
-        "No project specified and no active project selected.\nUse --project <id> or run: kyco project select <id>"
-    )
-}
-
-fn resolve_project_root(work_dir: &Path, project_root_path: &str) -> PathBuf {
-    let raw = PathBuf::from(project_root_path);
-    let abs = if raw.is_absolute() { raw } else { work_dir.join(raw) };
-    abs.canonicalize().unwrap_or(abs)
-}
+use crate::cli::project::{resolve_project_id, resolve_project_root};
 
 fn parse_path_and_line(raw: &str) -> Option<(String, Option<usize>)> {
     let trimmed = raw.trim();
@@ -184,6 +151,7 @@ pub fn import_tool(
         "semgrep" => manager.import_semgrep(input_path, &project_id)?,
         "nuclei" => manager.import_nuclei(input_path, &project_id)?,
         "snyk" => manager.import_snyk(input_path, &project_id)?,
+        "markdown" => manager.import_markdown(input_path, &project_id)?,
         "auto" => manager.import_auto(input_path, &project_id)?,
         _ => bail!("Unknown import format: {}", format),
     };
@@ -1,11 +1,11 @@
 //! Init command implementation
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
 use std::path::Path;
 use std::path::PathBuf;
 use tracing::info;
 
-use crate::config::INTERNAL_DEFAULTS_TOML;
+use crate::config::{starter_template, StarterTemplate, INTERNAL_DEFAULTS_TOML, STARTER_TEMPLATES};
 
 /// Settings template with documentation (settings section only)
 /// The agents come from INTERNAL_DEFAULTS_TOML
@@ -18,6 +18,12 @@ const SETTINGS_TEMPLATE: &str = r#"# KYCo Configuration - Know Your Codebase
 # STRUCTURE:
 # - [settings] - Global configuration options
 # - [agent.*] - AI backend configurations (claude, codex)
+#   Any other CLI agent: sdk = "custom" plus command_template, e.g.
+#     [agent.mine]
+#     sdk = "custom"
+#     command_template = "my-agent --cwd {cwd} --prompt-file {prompt_file}"
+#   {prompt_file}, {file} and {cwd} are shell-quoted; the prompt is also sent on
+#   stdin, and stdout becomes the job output.
 # - [chain.*] - Sequential skill execution pipelines (user-defined)
 #
 # SKILLS:
@@ -40,6 +46,14 @@ const SETTINGS_TEMPLATE: &str = r#"# KYCo Configuration - Know Your Codebase
 #   auto_allow          - Automatically approve tool call requests (default: false)
 #   use_worktree        - Run jobs in isolated Git worktrees (default: false)
 #   max_jobs_per_file   - Max concurrent jobs per file when not using worktrees (default: 1)
+#   dirty_repo_policy   - Uncommitted changes before a worktree job: warn, block, stash, ignore (default: warn)
+#   auto_apply          - Merge successful worktree jobs automatically (default: false)
+#   auto_apply_max_files - Skip auto-apply above this many changed files (default: 10, 0 = no limit)
+#   auto_apply_max_lines - Skip auto-apply above this many changed lines (default: 300, 0 = no limit)
+#   merge_as_commit     - Apply worktree jobs as one squashed commit on the base branch (default: false)
+#   max_retries         - Retries when an agent is rate limited before the job fails (default: 20)
+#   base_backoff_ms     - First rate-limit retry delay, doubling per attempt up to 60s (default: 2000)
+#   job_id_strategy     - How job ids are allocated: sequential, random (default: sequential)
 
 [settings]
 # Per-agent limit: 4 means up to 4 Claude AND 4 Codex jobs can run simultaneously
@@ -51,6 +65,19 @@ use_worktree = false
 # Set to 1 to prevent agents from overwriting each other's changes
 # When a job is blocked, it shows as "Blocked" in the GUI with the blocking job ID
 max_jobs_per_file = 1
+# Worktrees branch from HEAD: warn when local uncommitted changes would be left out
+dirty_repo_policy = "warn"
+# Auto-apply successful worktree jobs; large changes still wait for manual review
+auto_apply = false
+auto_apply_max_files = 10
+auto_apply_max_lines = 300
+# Land applied jobs as a single commit (with the job's message) instead of merging its branch
+merge_as_commit = false
+# Back off and retry rate-limited agent runs (HTTP 429); other errors fail immediately
+max_retries = 20
+base_backoff_ms = 2000
+# Use "random" when several kyco processes create jobs in the same repository
+job_id_strategy = "sequential"
 
 # GUI / IDE extension communication (local HTTP server)
 [settings.gui]
@@ -58,6 +85,9 @@ http_port = 9876
 # Optional: Shared secret for IDE extension requests (sent as `X-KYCO-Token`)
 # Leave empty to disable auth (recommended for local development)
 http_token = ""
+# Global event log: "all" or "system" (system/error/permission only), capped at max_log_events
+log_level = "all"
+max_log_events = 500
 
 # Claude Agent SDK plugins (local allowlist)
 #
@@ -91,6 +121,48 @@ pub fn build_default_config() -> String {
     format!("{}{}", SETTINGS_TEMPLATE, INTERNAL_DEFAULTS_TOML)
 }
 
+/// Build a config from the defaults with a starter template merged on top.
+///
+/// Template tables are merged key by key; scalars and arrays replace the default.
+/// The result is re-serialized, so the default config's comments are not kept.
+pub fn build_template_config(template: &StarterTemplate) -> Result<String> {
+    let mut config: toml::Table =
+        toml::from_str(&build_default_config()).context("Failed to parse default config")?;
+    let overlay: toml::Table = toml::from_str(template.toml)
+        .with_context(|| format!("Failed to parse template '{}'", template.name))?;
+    merge_tables(&mut config, overlay);
+
+    Ok(format!(
+        "# KYCo Configuration - starter template '{}'\n\
+         # {}\n\
+         # For the fully commented default config, run `kyco init --force` \
+         without --template.\n\n{}",
+        template.name,
+        template.description,
+        toml::to_string_pretty(&config)?
+    ))
+}
+
+fn merge_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(table)) => {
+                merge_tables(existing, table);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Print the embedded starter templates (`kyco init --list-templates`)
+pub fn list_templates() {
+    for template in STARTER_TEMPLATES {
+        println!("{:<10} {}", template.name, template.description);
+    }
+}
+
 /// Ensures the global config file exists (~/.kyco/config.toml), creating it if missing.
 /// This is called automatically when a new workspace is registered.
 /// Returns true if config was created, false if it already existed or couldn't be created.
@@ -128,7 +200,19 @@ pub async fn init_command(
     _work_dir: &Path,
     config_path: Option<PathBuf>,
     force: bool,
+    template: Option<&str>,
 ) -> Result<()> {
+    let template = match template {
+        Some(name) => match starter_template(name) {
+            Some(template) => Some(template),
+            None => {
+                let names: Vec<&str> = STARTER_TEMPLATES.iter().map(|t| t.name).collect();
+                bail!("Unknown template: {}. Available: {}", name, names.join(", "));
+            }
+        },
+        None => None,
+    };
+
     let config_path = config_path.unwrap_or_else(|| {
         dirs::home_dir()
             .unwrap_or_else(|| PathBuf::from("."))
@@ -149,9 +233,43 @@ pub async fn init_command(
         }
     }
 
-    let config_content = build_default_config();
+    let config_content = match template {
+        Some(template) => build_template_config(template)?,
+        None => build_default_config(),
+    };
     std::fs::write(&config_path, config_content)?;
-    println!("Created: {}", config_path.display());
+    match template {
+        Some(template) => println!(
+            "Created: {} (template: {})",
+            config_path.display(),
+            template.name
+        ),
+        None => println!("Created: {}", config_path.display()),
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn every_template_merges_into_a_loadable_config() {
+        for template in STARTER_TEMPLATES {
+            let content = build_template_config(template).unwrap();
+            let config: Config = toml::from_str(&content)
+                .unwrap_or_else(|e| panic!("template '{}': {}", template.name, e));
+            // Default agents survive the merge
+            assert!(config.agent.contains_key("claude"), "{}", template.name);
+        }
+
+        let security = build_template_config(starter_template("Security").unwrap()).unwrap();
+        let config: Config = toml::from_str(&security).unwrap();
+        assert!(config.settings.use_worktree);
+        assert!(config.mode.contains_key("attack-surface"));
+        assert_eq!(config.chain["triage"].steps.len(), 2);
+        assert!(config.chain.contains_key("audit-file"));
+    }
+}
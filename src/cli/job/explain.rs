@@ -0,0 +1,246 @@
+//! `kyco job explain`: print the prompt a job would send, without running it.
+
+use anyhow::{Context, Result, bail};
+use std::path::{Path, PathBuf};
+
+use crate::agent::{
+    ClaudeBridgeAdapter, CodexBridgeAdapter, CustomCommandAdapter, build_chained_prompt,
+    create_step_job,
+};
+use crate::config::Config;
+use crate::{AgentConfig, Job, ScopeDefinition, SdkType};
+
+/// Inputs for a dry-run prompt explanation (mirrors `kyco job start`)
+#[derive(Debug, Clone)]
+pub struct JobExplainArgs {
+    pub file: String,
+    pub skill: String,
+    pub line_start: usize,
+    pub line_end: Option<usize>,
+    pub prompt: Option<String>,
+    pub agent: Option<String>,
+    pub json: bool,
+}
+
+/// Resolve the prompt, agent and tool lists for a skill/chain and print them.
+///
+/// Read-only: no job is created and no agent or GUI is contacted.
+pub fn job_explain_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    args: JobExplainArgs,
+) -> Result<()> {
+    let cfg = load_config(work_dir, config_override)?;
+    if args.line_start == 0 {
+        bail!("--line-start must be >= 1");
+    }
+
+    let source_file = if Path::new(&args.file).is_absolute() {
+        PathBuf::from(&args.file)
+    } else {
+        work_dir.join(&args.file)
+    };
+    if !source_file.exists() {
+        bail!("File not found: {}", source_file.display());
+    }
+
+    let target = match args.line_end.filter(|end| *end != args.line_start) {
+        Some(end) => format!("{}:{}-{}", source_file.display(), args.line_start, end),
+        None => format!("{}:{}", source_file.display(), args.line_start),
+    };
+    let scope = if source_file.is_dir() {
+        ScopeDefinition::dir(source_file.clone())
+    } else {
+        ScopeDefinition::file(source_file.clone())
+    };
+
+    // Chains explain their first step; everything else is a single skill/mode
+    let chain = cfg.get_chain(&args.skill);
+    let (skill, step_agent) = match chain {
+        Some(chain) => {
+            let Some(first) = chain.steps.first() else {
+                bail!("Chain '{}' has no steps", args.skill);
+            };
+            (first.skill.clone(), first.agent.clone())
+        }
+        None => {
+            if cfg.get_mode_or_chain(&args.skill).is_none() {
+                bail!("Unknown skill, mode or chain: {}", args.skill);
+            }
+            (args.skill.clone(), None)
+        }
+    };
+
+    let agent_id = args
+        .agent
+        .clone()
+        .or(step_agent)
+        .unwrap_or_else(|| cfg.get_agent_for_mode(&skill).into_owned());
+    let Some(agent_config) = cfg.get_agent_for_job(&agent_id, &skill) else {
+        bail!("Agent not found: {}", agent_id);
+    };
+
+    let job = Job::new(
+        0,
+        args.skill.clone(),
+        scope,
+        target,
+        args.prompt.clone(),
+        agent_id.clone(),
+        source_file,
+        args.line_start,
+        None,
+    );
+
+    // Hand the adapter the same job the executor would
+    let agent_job = match chain {
+        Some(chain) => {
+            let step = &chain.steps[0];
+            let prompt = build_chained_prompt(&cfg, &job, step, &None, &[]);
+            create_step_job(&cfg, &job, step, &prompt)
+        }
+        None => cfg.job_with_prompt_suffix(&job),
+    };
+    let (system_prompt, user_prompt) = render_agent_prompts(work_dir, &agent_job, &agent_config);
+    let system_prompt = system_prompt.unwrap_or_default();
+
+    if args.json {
+        let out = serde_json::json!({
+            "skill": args.skill,
+            "step_skill": chain.map(|_| skill.as_str()),
+            "agent_id": agent_id,
+            "sdk_type": agent_config.sdk_type.default_name(),
+            "permission_mode": agent_config.permission_mode,
+            "allowed_tools": agent_config.allowed_tools,
+            "disallowed_tools": agent_config.disallowed_tools,
+            "system_prompt": system_prompt,
+            "prompt": user_prompt,
+        });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+
+    match chain {
+        Some(_) => println!("Chain: {} (step 1: {})", args.skill, skill),
+        None => println!("Skill: {}", skill),
+    }
+    println!(
+        "Agent: {} ({})",
+        agent_id,
+        agent_config.sdk_type.default_name()
+    );
+    println!("Permission mode: {}", agent_config.permission_mode);
+    println!(
+        "Allowed tools: {}",
+        format_tools(&agent_config.allowed_tools)
+    );
+    println!(
+        "Disallowed tools: {}",
+        format_tools(&agent_config.disallowed_tools)
+    );
+    println!();
+    println!("=== SYSTEM PROMPT ===");
+    println!("{}", system_prompt);
+    println!();
+    println!("=== USER PROMPT ===");
+    println!("{}", user_prompt);
+    Ok(())
+}
+
+/// Render the prompts the agent's adapter would send for `job`.
+///
+/// Picks the adapter like [`crate::agent::AgentRegistry::get_for_config`]. Codex and
+/// custom agents get no separate system prompt; their instructions are part of the
+/// user prompt.
+fn render_agent_prompts(
+    work_dir: &Path,
+    job: &Job,
+    agent_config: &AgentConfig,
+) -> (Option<String>, String) {
+    let custom_template = agent_config
+        .command_template
+        .as_deref()
+        .filter(|t| !t.trim().is_empty());
+    match (agent_config.sdk_type, custom_template) {
+        (SdkType::Custom, Some(template)) => {
+            let adapter = CustomCommandAdapter::new(&agent_config.id, template);
+            (None, adapter.build_prompt(job, agent_config))
+        }
+        (SdkType::Codex, _) => {
+            let adapter = CodexBridgeAdapter::new();
+            (None, adapter.build_prompt(job, agent_config, work_dir))
+        }
+        _ => {
+            let adapter = ClaudeBridgeAdapter::new();
+            (
+                adapter.build_system_prompt(job, agent_config),
+                adapter.build_prompt(job, agent_config),
+            )
+        }
+    }
+}
+
+fn format_tools(tools: &[String]) -> String {
+    if tools.is_empty() {
+        "(all)".to_string()
+    } else {
+        tools.join(", ")
+    }
+}
+
+/// Load config without writing defaults (explain must stay read-only)
+pub(super) fn load_config(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+) -> Result<Config> {
+    let Some(path) = config_override else {
+        return Config::load_read_only();
+    };
+    let path = if path.is_absolute() {
+        path.clone()
+    } else {
+        work_dir.join(path)
+    };
+    Config::from_file(&path).with_context(|| format!("Failed to load {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_agent_prompts;
+    use crate::domain::test_support;
+    use crate::{AgentConfig, Job, ScopeDefinition};
+    use std::path::{Path, PathBuf};
+
+    fn job(agent_id: &str) -> Job {
+        Job {
+            skill: "refactor".to_string(),
+            scope: ScopeDefinition::file(PathBuf::from("src/main.rs")),
+            target: "src/main.rs:42".to_string(),
+            description: Some("fix the bug".to_string()),
+            agent_id: agent_id.to_string(),
+            source_file: PathBuf::from("src/main.rs"),
+            source_line: 42,
+            ..test_support::job(0)
+        }
+    }
+
+    #[test]
+    fn explain_renders_the_prompt_each_adapter_sends() {
+        let work_dir = Path::new("/nonexistent");
+
+        let (system, user) =
+            render_agent_prompts(work_dir, &job("claude"), &AgentConfig::claude_default());
+        assert!(
+            user.starts_with("/refactor on file src/main.rs:42"),
+            "got: {user}"
+        );
+        assert!(user.ends_with("fix the bug"), "got: {user}");
+        assert!(system.is_none_or(|s| !s.contains("/refactor")));
+
+        let (system, user) =
+            render_agent_prompts(work_dir, &job("codex"), &AgentConfig::codex_default());
+        assert!(system.is_none());
+        assert!(user.contains("## Task"), "got: {user}");
+        assert!(user.contains("fix the bug"), "got: {user}");
+    }
+}
@@ -44,16 +44,16 @@ fn resolve_config_path(work_dir: &Path, config_override: Option<&PathBuf>) -> Pa
     }
 }
 
-pub(super) fn load_gui_http_settings(
+pub(crate) fn load_gui_http_settings(
     work_dir: &Path,
     config_override: Option<&PathBuf>,
 ) -> (u16, Option<String>) {
-    // If using default global config, use Config::load() which handles auto-init
+    // If using default global config, use Config::load_cached() which handles auto-init
     let config = if config_override.is_none() {
-        Config::load().ok()
+        Config::load_cached().ok()
     } else {
         let config_path = resolve_config_path(work_dir, config_override);
-        Config::from_file(&config_path).ok()
+        Config::from_file_cached(&config_path).ok()
     };
 
     let port = config
@@ -73,7 +73,7 @@ fn with_auth(mut req: ureq::Request, token: Option<&str>) -> ureq::Request {
     req
 }
 
-pub(super) fn http_get_json(url: &str, token: Option<&str>) -> Result<serde_json::Value> {
+pub(crate) fn http_get_json(url: &str, token: Option<&str>) -> Result<serde_json::Value> {
     let req = with_auth(ureq::get(url), token);
     let resp = req.call().map_err(|e| match e {
         ureq::Error::Status(code, resp) => {
@@ -89,7 +89,7 @@ pub(super) fn http_get_json(url: &str, token: Option<&str>) -> Result<serde_json
     Ok(json)
 }
 
-pub(super) fn http_post_json(
+pub(crate) fn http_post_json(
     url: &str,
     token: Option<&str>,
     payload: serde_json::Value,
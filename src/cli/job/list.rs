@@ -2,14 +2,27 @@
 
 use anyhow::{Context, Result};
 use std::collections::HashSet;
+use std::io::IsTerminal;
 use std::path::PathBuf;
 
 use crate::bugbounty::BugBountyManager;
-use crate::Job;
+use crate::{Job, JobStatus};
 
 use super::http::{http_get_json, load_gui_http_settings};
 use super::types::JobsListResponse;
 
+/// Order of the status groups in human output: active work first, then finished jobs
+const STATUS_GROUP_ORDER: [JobStatus; 8] = [
+    JobStatus::Running,
+    JobStatus::Blocked,
+    JobStatus::Queued,
+    JobStatus::Pending,
+    JobStatus::Done,
+    JobStatus::Failed,
+    JobStatus::Rejected,
+    JobStatus::Merged,
+];
+
 pub fn job_list_command(
     work_dir: &std::path::Path,
     config_override: Option<&PathBuf>,
@@ -19,14 +32,45 @@ pub fn job_list_command(
     status_filter: Option<&str>,
     state_filter: Option<&str>,
     limit: Option<usize>,
+    offset: usize,
     search: Option<&str>,
     mode_filter: Option<&str>,
 ) -> Result<()> {
+    // The server filters by status and sorts newest first; it can only page for us when no
+    // client-side filter would drop jobs from the returned page afterwards.
+    let mut params = Vec::new();
+    if let Some(status) = status_filter {
+        let markers = status_markers_matching(status);
+        if markers.is_empty() {
+            let valid: Vec<&str> = JobStatus::ALL.iter().map(|s| s.as_marker()).collect();
+            anyhow::bail!("Unknown status '{}'. Use: {}", status, valid.join(", "));
+        }
+        params.push(format!("status={}", markers.join(",")));
+    }
+    let server_paging = project_filter.is_none()
+        && finding_filter.is_none()
+        && state_filter.is_none()
+        && mode_filter.is_none()
+        && search.is_none();
+    if server_paging {
+        if offset > 0 {
+            params.push(format!("offset={offset}"));
+        }
+        if let Some(n) = limit {
+            params.push(format!("limit={n}"));
+        }
+    }
+
     let (port, token) = load_gui_http_settings(work_dir, config_override);
-    let url = format!("http://127.0.0.1:{port}/ctl/jobs");
+    let mut url = format!("http://127.0.0.1:{port}/ctl/jobs");
+    if !params.is_empty() {
+        url.push('?');
+        url.push_str(&params.join("&"));
+    }
     let value = http_get_json(&url, token.as_deref())?;
     let parsed: JobsListResponse =
         serde_json::from_value(value).context("Invalid /ctl/jobs response")?;
+    let server_total = parsed.total;
 
     let (bb_manager, allowed_bb_job_ids): (Option<BugBountyManager>, Option<HashSet<String>>) =
         if let Some(finding_id) = finding_filter {
@@ -79,13 +123,6 @@ pub fn job_list_command(
             }
         }
 
-        if let Some(status) = status_filter {
-            let job_status = format!("{}", job.status).to_lowercase();
-            if !job_status.contains(&status.to_lowercase()) {
-                continue;
-            }
-        }
-
         if let Some(state) = state_filter {
             let want = state.to_lowercase();
             let have = job
@@ -121,11 +158,18 @@ pub fn job_list_command(
         jobs.push(job);
     }
 
-    // Sort by ID descending (newest first)
-    jobs.sort_by(|a, b| b.id.cmp(&a.id));
+    // Newest first (creation time, then id: random ids carry no order)
+    jobs.sort_by_key(|job| std::cmp::Reverse(job.creation_order()));
 
-    if let Some(n) = limit {
-        jobs.truncate(n);
+    let total = match server_total {
+        Some(total) if server_paging => total,
+        _ => jobs.len(),
+    };
+    if !server_paging {
+        jobs.drain(..offset.min(jobs.len()));
+        if let Some(n) = limit {
+            jobs.truncate(n);
+        }
     }
 
     if json {
@@ -160,52 +204,183 @@ pub fn job_list_command(
     if let Some(n) = limit {
         filters.push(format!("limit={}", n));
     }
+    if offset > 0 {
+        filters.push(format!("offset={}", offset));
+    }
 
+    let count = if jobs.len() == total {
+        total.to_string()
+    } else {
+        format!("{} of {}", jobs.len(), total)
+    };
     if filters.is_empty() {
-        println!("Jobs ({}):\n", jobs.len());
+        println!("Jobs ({}):\n", count);
     } else {
-        println!("Jobs ({}, {}):\n", jobs.len(), filters.join(", "));
+        println!("Jobs ({}, {}):\n", count, filters.join(", "));
     }
 
-    for job in jobs {
-        let project_suffix = job
-            .bugbounty_project_id
-            .as_deref()
-            .map(|p| format!(" (bb:{})", p))
-            .unwrap_or_default();
-        println!(
-            "  #{} [{}] {} - {}{}",
-            job.id, job.status, job.skill, job.target, project_suffix
-        );
-        if let Some(desc) = &job.description {
-            if !desc.trim().is_empty() {
-                let truncated = if desc.chars().count() > 100 {
-                    let truncate_at = desc
-                        .char_indices()
-                        .nth(97)
-                        .map(|(i, _)| i)
-                        .unwrap_or(desc.len());
-                    format!("{}...", &desc[..truncate_at])
-                } else {
-                    desc.clone()
-                };
-                println!("    {}", truncated.trim());
-            }
+    let color = std::io::stdout().is_terminal();
+    for (status, group) in group_by_status(jobs) {
+        println!("{} ({}):\n", status_label(status, color), group.len());
+        for job in group {
+            print_job(&job, color);
         }
-        if let Some(err) = &job.error_message {
-            println!("    Error: {}", err);
+    }
+
+    Ok(())
+}
+
+/// Markers of every status containing `filter` (case-insensitive), e.g. "fail" -> ["failed"]
+fn status_markers_matching(filter: &str) -> Vec<&'static str> {
+    let filter = filter.to_lowercase();
+    JobStatus::ALL
+        .iter()
+        .map(|status| status.as_marker())
+        .filter(|marker| marker.contains(&filter))
+        .collect()
+}
+
+/// Split jobs into status groups in [`STATUS_GROUP_ORDER`], each oldest first
+fn group_by_status(jobs: Vec<Job>) -> Vec<(JobStatus, Vec<Job>)> {
+    let mut groups: Vec<(JobStatus, Vec<Job>)> = STATUS_GROUP_ORDER
+        .iter()
+        .map(|&status| (status, Vec::new()))
+        .collect();
+    for job in jobs {
+        if let Some((_, group)) = groups.iter_mut().find(|(status, _)| *status == job.status) {
+            group.push(job);
         }
-        // Show session ID for session continuation
-        if let Some(session_id) = &job.bridge_session_id {
-            let short_id = if session_id.len() > 20 {
-                format!("{}...", &session_id[..17])
+    }
+    groups.retain(|(_, group)| !group.is_empty());
+    for (_, group) in &mut groups {
+        group.sort_by_key(|job| job.creation_order());
+    }
+    groups
+}
+
+/// Status token, colored by status when writing to a TTY
+fn status_label(status: JobStatus, color: bool) -> String {
+    if !color {
+        return status.to_string();
+    }
+    let code = match status {
+        JobStatus::Running => "36",
+        JobStatus::Blocked | JobStatus::Queued => "33",
+        JobStatus::Pending => "2",
+        JobStatus::Done | JobStatus::Merged => "32",
+        JobStatus::Failed => "31",
+        JobStatus::Rejected => "35",
+    };
+    format!("\x1b[{code}m{status}\x1b[0m")
+}
+
+fn print_job(job: &Job, color: bool) {
+    let project_suffix = job
+        .bugbounty_project_id
+        .as_deref()
+        .map(|p| format!(" (bb:{})", p))
+        .unwrap_or_default();
+    println!(
+        "  #{} [{}] {} - {}{}",
+        job.id,
+        status_label(job.status, color),
+        job.skill,
+        job.target,
+        project_suffix
+    );
+    if let Some(desc) = &job.description {
+        if !desc.trim().is_empty() {
+            let truncated = if desc.chars().count() > 100 {
+                let truncate_at = desc
+                    .char_indices()
+                    .nth(97)
+                    .map(|(i, _)| i)
+                    .unwrap_or(desc.len());
+                format!("{}...", &desc[..truncate_at])
             } else {
-                session_id.clone()
+                desc.clone()
             };
-            println!("    Session: {}", short_id);
+            println!("    {}", truncated.trim());
         }
-        println!();
     }
+    if let Some(err) = &job.error_message {
+        println!("    Error: {}", err);
+    }
+    // Show session ID for session continuation
+    if let Some(session_id) = &job.bridge_session_id {
+        let short_id = if session_id.len() > 20 {
+            format!("{}...", &session_id[..17])
+        } else {
+            session_id.clone()
+        };
+        println!("    Session: {}", short_id);
+    }
+    println!();
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support;
+
+    fn job(id: u64, status: JobStatus) -> Job {
+        Job {
+            status,
+            ..test_support::job(id)
+        }
+    }
+
+    #[test]
+    fn groups_follow_status_order_and_sort_by_id() {
+        let jobs = vec![
+            job(7, JobStatus::Done),
+            job(3, JobStatus::Failed),
+            job(9, JobStatus::Queued),
+            job(2, JobStatus::Done),
+            job(5, JobStatus::Running),
+            job(4, JobStatus::Queued),
+        ];
+
+        let groups: Vec<(JobStatus, Vec<u64>)> = group_by_status(jobs)
+            .into_iter()
+            .map(|(status, group)| (status, group.iter().map(|j| j.id).collect()))
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                (JobStatus::Running, vec![5]),
+                (JobStatus::Queued, vec![4, 9]),
+                (JobStatus::Done, vec![2, 7]),
+                (JobStatus::Failed, vec![3]),
+            ]
+        );
+    }
+
+    #[test]
+    fn groups_sort_random_ids_by_creation_time() {
+        let mut first = job(900, JobStatus::Done);
+        first.created_at = chrono::DateTime::UNIX_EPOCH;
+        let groups = group_by_status(vec![job(12, JobStatus::Done), first]);
+        let ids: Vec<u64> = groups[0].1.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![900, 12]);
+    }
+
+    #[test]
+    fn status_filter_matches_markers_by_substring() {
+        assert_eq!(status_markers_matching("FAIL"), vec!["failed"]);
+        assert_eq!(
+            status_markers_matching("ed"),
+            vec!["queued", "blocked", "failed", "rejected", "merged"]
+        );
+        assert!(status_markers_matching("aborted").is_empty());
+    }
+
+    #[test]
+    fn status_is_colored_only_for_a_tty() {
+        assert_eq!(status_label(JobStatus::Failed, false), "failed");
+        assert_eq!(
+            status_label(JobStatus::Failed, true),
+            "\x1b[31mfailed\x1b[0m"
+        );
+    }
 }
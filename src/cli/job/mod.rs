@@ -1,21 +1,28 @@
 //! Job control commands (talk to a running KYCo GUI over the local /ctl API).
 
-mod http;
+mod explain;
+pub(crate) mod http;
 mod list;
+mod tree;
 mod types;
 
 use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
-use crate::{Job, JobId, JobStatus};
+use crate::{Job, JobId, JobStatus, LogEvent, LogEventKind, UsageStats};
 use crate::bugbounty::NextContext;
 
 use http::{http_get_json, http_post_json, load_gui_http_settings};
-use types::{JobContinueResponse, JobCreateResponse, JobGetResponse};
+use types::{
+    JobContinueBatchResponse, JobContinueResponse, JobCreateResponse, JobGetResponse,
+    JobLogsResponse,
+};
 
 // Re-export public API
+pub use explain::{JobExplainArgs, job_explain_command};
 pub use list::job_list_command;
+pub use tree::job_tree_command;
 pub use types::JobStartArgs;
 
 pub(crate) fn ctl_create_jobs(
@@ -122,6 +129,19 @@ fn collect_files_recursively(dir: &Path, out: &mut Vec<PathBuf>) -> Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn agents_file_skips_comments_and_merges_without_duplicates() {
+        let file_agents = parse_agents_file(
+            "# benchmark fan-out\nclaude\n\n  codex  # fast\nclaude-opus\nclaude\n",
+        );
+        assert_eq!(file_agents, vec!["claude", "codex", "claude-opus", "claude"]);
+
+        let cli_agents = ["codex".to_string(), " gemini ".to_string()];
+        let merged = merge_agent_ids(&cli_agents, &file_agents);
+        assert_eq!(merged, vec!["codex", "gemini", "claude", "claude-opus"]);
+        assert!(parse_agents_file("# nothing here\n\n").is_empty());
+    }
+
     #[test]
     fn resolves_dot_slash_relative_to_cwd() -> Result<()> {
         let work_dir = tempfile::tempdir()?;
@@ -195,6 +215,49 @@ mod tests {
         assert_eq!(resolved, vec![cwd_file.canonicalize()?]);
         Ok(())
     }
+
+    #[test]
+    fn log_event_lines_are_colored_only_when_requested() {
+        let event = LogEvent::error("Build failed");
+        let plain = format_log_event(&event, false);
+        assert!(plain.ends_with("error      Build failed"));
+        assert!(!plain.contains('\x1b'));
+
+        let colored = format_log_event(&event, true);
+        assert!(colored.contains("\x1b[31merror"));
+    }
+
+    #[test]
+    fn wait_any_returns_on_first_terminal_job_and_all_on_the_last() {
+        let statuses = [JobStatus::Running, JobStatus::Done, JobStatus::Queued];
+        assert!(wait_satisfied(&statuses, true));
+        assert!(!wait_satisfied(&statuses, false));
+
+        let statuses = [JobStatus::Failed, JobStatus::Merged, JobStatus::Rejected];
+        assert!(wait_satisfied(&statuses, false));
+        assert!(!wait_satisfied(&[JobStatus::Running], true));
+    }
+}
+
+/// Agent ids from an `--agents-file`: one per line, blanks and `#` comments ignored.
+fn parse_agents_file(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// `--agents` followed by file-listed agents, trimmed and deduplicated in order.
+fn merge_agent_ids(cli_agents: &[String], file_agents: &[String]) -> Vec<String> {
+    let mut agents: Vec<String> = Vec::new();
+    for id in cli_agents.iter().chain(file_agents).map(|s| s.trim()) {
+        if !id.is_empty() && !agents.iter().any(|a| a == id) {
+            agents.push(id.to_string());
+        }
+    }
+    agents
 }
 
 fn expand_input_files(work_dir: &Path, inputs: &[String]) -> Result<Vec<PathBuf>> {
@@ -302,6 +365,9 @@ pub fn job_get_command(
     if let Some(err) = &job.error_message {
         println!("Error: {}", err);
     }
+    if let Some(usage) = job.usage_string() {
+        println!("Usage: {}", usage);
+    }
     // Show session ID for session continuation
     if let Some(session_id) = &job.bridge_session_id {
         println!("Session: {}", session_id);
@@ -326,19 +392,63 @@ pub fn job_start_command(
         .collect::<Vec<_>>();
     let uses_input = !input.is_empty();
 
+    let dir_path_raw = args.dir_path.as_deref().map(str::trim).filter(|s| !s.is_empty());
+
+    let job_env =
+        Job::parse_env_assignments(&args.env).map_err(|e| anyhow::anyhow!("--env: {}", e))?;
+    let job_env = if job_env.is_empty() { None } else { Some(job_env) };
+
+    let agents = match args.agents_file.as_deref() {
+        Some(raw) => {
+            let cwd = std::env::current_dir().unwrap_or_else(|_| work_dir.to_path_buf());
+            let path = resolve_existing_path(work_dir, &cwd, raw)?;
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read --agents-file {}", path.display()))?;
+            let file_agents = parse_agents_file(&content);
+            if file_agents.is_empty() {
+                anyhow::bail!("--agents-file {} lists no agents", path.display());
+            }
+            let agents = merge_agent_ids(&args.agents, &file_agents);
+            let cfg = explain::load_config(work_dir, config_override)?;
+            let unknown: Vec<&str> = agents
+                .iter()
+                .filter(|id| !cfg.agent.contains_key(id.as_str()))
+                .map(String::as_str)
+                .collect();
+            if !unknown.is_empty() {
+                anyhow::bail!("Unknown agent(s): {} (not in config)", unknown.join(", "));
+            }
+            agents
+        }
+        None => merge_agent_ids(&args.agents, &[]),
+    };
+
+    // Validated and normalized by /ctl job creation, like every other creation path
+    let auto_select = (!args.auto_select.is_empty())
+        .then(|| crate::AutoSelectPolicy::new(args.auto_select.clone()));
+
     if uses_input && args.file_path.as_deref().is_some_and(|s| !s.trim().is_empty()) {
         anyhow::bail!("Use either --file or --input (not both)");
     }
+    if uses_input && dir_path_raw.is_some() {
+        anyhow::bail!("Use either --dir or --input (not both)");
+    }
     if args.batch && !uses_input {
         anyhow::bail!("--batch requires --input");
     }
 
-    // Validate: need either --file/--input or --prompt (or both)
-    let file_path_raw = args.file_path.as_deref().map(str::trim).filter(|s| !s.is_empty());
+    // Validate: need either --file/--dir/--input or --prompt (or both)
+    // A directory passed via --file is treated like --dir.
+    let file_path_raw = args
+        .file_path
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .or(dir_path_raw);
     let prompt_provided = args.prompt.as_deref().map(str::trim).filter(|s| !s.is_empty()).is_some();
 
     if file_path_raw.is_none() && !uses_input && !prompt_provided {
-        anyhow::bail!("Either --file/--input or --prompt (or both) must be provided");
+        anyhow::bail!("Either --file/--dir/--input or --prompt (or both) must be provided");
     }
 
     if let Some(start) = args.line_start {
@@ -374,7 +484,14 @@ pub fn job_start_command(
     let cwd = std::env::current_dir().unwrap_or_else(|_| work_dir.to_path_buf());
     let single_file_path: Option<String> = if let Some(raw) = file_path_raw {
         let resolved_path = resolve_existing_path(work_dir, &cwd, raw)?;
-        if !resolved_path.is_file() {
+        if dir_path_raw.is_some() && !resolved_path.is_dir() {
+            anyhow::bail!("Path is not a directory: {}", resolved_path.display());
+        }
+        if resolved_path.is_dir() {
+            if args.line_start.is_some() || args.line_end.is_some() {
+                anyhow::bail!("--line-start/--line-end cannot be used with a directory target");
+            }
+        } else if !resolved_path.is_file() {
             anyhow::bail!("Path is not a file: {}", resolved_path.display());
         }
         let resolved_path = resolved_path.canonicalize().unwrap_or(resolved_path);
@@ -385,13 +502,6 @@ pub fn job_start_command(
         None
     };
 
-    let agents = args
-        .agents
-        .iter()
-        .map(|s| s.trim().to_string())
-        .filter(|s| !s.is_empty())
-        .collect::<Vec<_>>();
-
     let bugbounty_finding_ids = args
         .bugbounty_finding_ids
         .iter()
@@ -434,6 +544,12 @@ pub fn job_start_command(
                 "session_id": args.session_id.clone(),
                 "fork_session": args.fork_session,
                 "permission_mode": effective_permission_mode.clone(),
+                "env": job_env.clone(),
+                "ignore_budget": args.ignore_budget,
+                "auto_select": auto_select.clone(),
+                "context_lines": args.context_lines,
+                "tags": args.tags.clone(),
+                "base": args.base.clone(),
             });
             let parsed = ctl_create_jobs(work_dir, config_override, payload)?;
             batch_results.push((Some(path.display().to_string()), parsed));
@@ -455,6 +571,12 @@ pub fn job_start_command(
             "session_id": args.session_id.clone(),
             "fork_session": args.fork_session,
             "permission_mode": effective_permission_mode,
+            "env": job_env,
+            "ignore_budget": args.ignore_budget,
+            "auto_select": auto_select,
+            "context_lines": args.context_lines,
+            "tags": args.tags.clone(),
+            "base": args.base.clone(),
         });
         let parsed = ctl_create_jobs(work_dir, config_override, payload)?;
         batch_results.push((single_file_path.clone(), parsed));
@@ -547,6 +669,41 @@ pub fn job_abort_command(
     Ok(())
 }
 
+pub fn job_abort_all_command(work_dir: &Path, config_override: Option<&PathBuf>) -> Result<()> {
+    let (port, token) = load_gui_http_settings(work_dir, config_override);
+    let url = format!("http://127.0.0.1:{port}/ctl/jobs/abort-all");
+    let resp = http_post_json(&url, token.as_deref(), serde_json::json!({}))?;
+    let aborted: Vec<JobId> = resp
+        .get("aborted")
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default();
+    if aborted.is_empty() {
+        println!("No running or queued jobs to abort");
+    } else {
+        let ids: Vec<String> = aborted.iter().map(|id| format!("#{}", id)).collect();
+        println!("Abort requested for {} job(s): {}", aborted.len(), ids.join(", "));
+    }
+    Ok(())
+}
+
+/// Pause (`paused = true`) or resume starting queued jobs in the running GUI
+pub fn queue_pause_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    paused: bool,
+) -> Result<()> {
+    let (port, token) = load_gui_http_settings(work_dir, config_override);
+    let action = if paused { "pause" } else { "resume" };
+    let url = format!("http://127.0.0.1:{port}/ctl/queue/{action}");
+    let _ = http_post_json(&url, token.as_deref(), serde_json::json!({}))?;
+    if paused {
+        println!("Queue paused (running jobs continue; resume with: kyco queue resume)");
+    } else {
+        println!("Queue resumed");
+    }
+    Ok(())
+}
+
 pub fn job_kill_command(
     work_dir: &Path,
     config_override: Option<&PathBuf>,
@@ -584,6 +741,18 @@ pub fn job_delete_command(
     Ok(())
 }
 
+pub fn job_archive_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    job_id: JobId,
+) -> Result<()> {
+    let (port, token) = load_gui_http_settings(work_dir, config_override);
+    let url = format!("http://127.0.0.1:{port}/ctl/jobs/{job_id}/archive");
+    let _ = http_post_json(&url, token.as_deref(), serde_json::json!({}))?;
+    println!("Archived job #{}", job_id);
+    Ok(())
+}
+
 pub fn job_continue_command(
     work_dir: &Path,
     config_override: Option<&PathBuf>,
@@ -620,35 +789,216 @@ pub fn job_continue_command(
     Ok(())
 }
 
-pub fn job_wait_command(
+/// Send several follow-up prompts that run one after another in the same session.
+pub fn job_continue_batch_command(
     work_dir: &Path,
     config_override: Option<&PathBuf>,
     job_id: JobId,
+    prompts: Vec<String>,
+    stop_on_failure: bool,
+    json: bool,
+    fork: bool,
+    plan: bool,
+) -> Result<()> {
+    let prompts: Vec<String> = prompts
+        .into_iter()
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect();
+    if prompts.is_empty() {
+        anyhow::bail!("Missing prompts");
+    }
+
+    let (port, token) = load_gui_http_settings(work_dir, config_override);
+    let url = format!("http://127.0.0.1:{port}/ctl/jobs/{job_id}/continue_batch");
+    let payload = serde_json::json!({
+        "prompts": prompts,
+        "stop_on_failure": stop_on_failure,
+        "fork_session": fork,
+        "plan_mode": plan
+    });
+    let value = http_post_json(&url, token.as_deref(), payload)?;
+    let parsed: JobContinueBatchResponse = serde_json::from_value(value)
+        .context("Invalid /ctl/jobs/{id}/continue_batch response")?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&parsed)?);
+    } else {
+        let ids: Vec<String> = parsed.job_ids.iter().map(|id| format!("#{id}")).collect();
+        println!("Created continuation jobs {}", ids.join(", "));
+    }
+
+    Ok(())
+}
+
+/// Wait until jobs reach a terminal status: all of them, or with `any` the first one.
+///
+/// Every id is polled each interval. A single id prints the job itself (as before);
+/// several ids print one line (or JSON entry) per job with its final status.
+pub fn job_wait_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    job_ids: &[JobId],
+    any: bool,
     timeout: Option<Duration>,
     poll_interval: Duration,
     json: bool,
 ) -> Result<()> {
+    if job_ids.is_empty() {
+        anyhow::bail!("Missing job ids");
+    }
     let deadline = timeout.map(|t| Instant::now() + t);
 
     loop {
-        let job = fetch_job(work_dir, config_override, job_id)?;
-        if is_terminal_status(job.status) {
+        let jobs = job_ids
+            .iter()
+            .map(|&id| fetch_job(work_dir, config_override, id))
+            .collect::<Result<Vec<_>>>()?;
+        let statuses: Vec<JobStatus> = jobs.iter().map(|job| job.status).collect();
+
+        if wait_satisfied(&statuses, any) {
+            if let [job] = jobs.as_slice() {
+                if json {
+                    println!("{}", serde_json::to_string_pretty(job)?);
+                } else {
+                    println!("#{} [{}] {} - {}", job.id, job.status, job.skill, job.target);
+                }
+                return Ok(());
+            }
+
             if json {
-                println!("{}", serde_json::to_string_pretty(&job)?);
+                let entries: Vec<serde_json::Value> = jobs
+                    .iter()
+                    .map(|job| {
+                        serde_json::json!({
+                            "id": job.id,
+                            "status": job.status,
+                            "finished": is_terminal_status(job.status),
+                        })
+                    })
+                    .collect();
+                let finished: Vec<JobId> = jobs
+                    .iter()
+                    .filter(|job| is_terminal_status(job.status))
+                    .map(|job| job.id)
+                    .collect();
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "mode": if any { "any" } else { "all" },
+                        "finished": finished,
+                        "jobs": entries,
+                    }))?
+                );
             } else {
-                println!("#{} [{}] {} - {}", job.id, job.status, job.skill, job.target);
+                for job in &jobs {
+                    if any && !is_terminal_status(job.status) {
+                        continue;
+                    }
+                    println!("#{} [{}] {} - {}", job.id, job.status, job.skill, job.target);
+                }
             }
             return Ok(());
         }
 
         if deadline.is_some_and(|d| Instant::now() >= d) {
-            anyhow::bail!("Timed out waiting for job #{}", job_id);
+            let pending: Vec<String> = jobs
+                .iter()
+                .filter(|job| !is_terminal_status(job.status))
+                .map(|job| format!("#{}", job.id))
+                .collect();
+            anyhow::bail!("Timed out waiting for job {}", pending.join(", "));
         }
 
         std::thread::sleep(poll_interval);
     }
 }
 
+/// Whether `job wait` can return: every status terminal, or with `any` at least one.
+fn wait_satisfied(statuses: &[JobStatus], any: bool) -> bool {
+    if any {
+        statuses.iter().any(|&s| is_terminal_status(s))
+    } else {
+        statuses.iter().all(|&s| is_terminal_status(s))
+    }
+}
+
+/// Print a job's LogEvents; with `follow`, keep polling until the job finishes.
+pub fn job_logs_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    job_id: JobId,
+    follow: bool,
+    poll_interval: Duration,
+    json: bool,
+) -> Result<()> {
+    use std::io::IsTerminal;
+
+    let (port, token) = load_gui_http_settings(work_dir, config_override);
+    let color = !json && std::io::stdout().is_terminal();
+    let mut since: Option<i64> = None;
+    // Events at exactly `since` that were already printed (the server bound is inclusive)
+    let mut seen_at_since = 0;
+
+    loop {
+        let url = match since {
+            Some(since) => format!("http://127.0.0.1:{port}/ctl/jobs/{job_id}/logs?since={since}"),
+            None => format!("http://127.0.0.1:{port}/ctl/jobs/{job_id}/logs"),
+        };
+        let value = http_get_json(&url, token.as_deref())?;
+        let parsed: JobLogsResponse =
+            serde_json::from_value(value).context("Invalid /ctl/jobs/{id}/logs response")?;
+
+        for event in parsed.events.iter().skip(seen_at_since) {
+            if json {
+                println!("{}", serde_json::to_string(event)?);
+            } else {
+                println!("{}", format_log_event(event, color));
+            }
+        }
+        if let Some(last) = parsed.events.last() {
+            since = last.timestamp.timestamp_nanos_opt().or(since);
+            seen_at_since = parsed
+                .events
+                .iter()
+                .filter(|e| e.timestamp.timestamp_nanos_opt() == since)
+                .count();
+        }
+
+        if !follow || is_terminal_status(parsed.status) {
+            if follow && !json {
+                println!("#{} finished [{}]", job_id, parsed.status);
+            }
+            return Ok(());
+        }
+
+        std::thread::sleep(poll_interval);
+    }
+}
+
+/// One log line: `HH:MM:SS kind summary`, colored by kind when writing to a TTY.
+fn format_log_event(event: &LogEvent, color: bool) -> String {
+    let time = event
+        .timestamp
+        .with_timezone(&chrono::Local)
+        .format("%H:%M:%S");
+    let kind = format!("{:<10}", event.kind.to_string());
+    let kind = if color {
+        let code = match event.kind {
+            LogEventKind::Error => "31",
+            LogEventKind::Permission => "33",
+            LogEventKind::System => "36",
+            LogEventKind::ToolCall | LogEventKind::ToolOutput => "34",
+            LogEventKind::Thought => "2",
+            LogEventKind::Text => "0",
+        };
+        format!("\x1b[{code}m{kind}\x1b[0m")
+    } else {
+        kind
+    };
+    format!("{time} {kind} {}", event.summary)
+}
+
 pub fn job_output_command(
     work_dir: &Path,
     config_override: Option<&PathBuf>,
@@ -660,6 +1010,7 @@ pub fn job_output_command(
     artifacts: bool,
     summary: bool,
     state: bool,
+    usage: bool,
 ) -> Result<()> {
     let job = fetch_job(work_dir, config_override, job_id)?;
 
@@ -668,6 +1019,11 @@ pub fn job_output_command(
         return Ok(());
     }
 
+    if usage {
+        print_usage(&job);
+        return Ok(());
+    }
+
     if next_context || findings || flow || artifacts {
         let ctx = job
             .result
@@ -751,6 +1107,49 @@ pub fn job_output_command(
     Ok(())
 }
 
+/// Print the job's token/cost usage, falling back to the job-level token fields
+/// for jobs persisted before `JobResult.usage` existed.
+fn print_usage(job: &Job) {
+    let usage = job
+        .result
+        .as_ref()
+        .and_then(|r| r.usage.clone())
+        .or_else(|| {
+            if job.input_tokens.is_none() && job.output_tokens.is_none() && job.cost_usd.is_none() {
+                return None;
+            }
+            Some(UsageStats {
+                input_tokens: job.input_tokens.unwrap_or(0),
+                output_tokens: job.output_tokens.unwrap_or(0),
+                cached_tokens: job.cache_read_tokens.unwrap_or(0),
+                cost_usd: job.cost_usd,
+                ..Default::default()
+            })
+        });
+
+    let Some(usage) = usage else {
+        println!("No usage recorded for job #{}", job.id);
+        return;
+    };
+
+    println!("input_tokens:  {}", usage.input_tokens);
+    println!("output_tokens: {}", usage.output_tokens);
+    println!("cached_tokens: {}", usage.cached_tokens);
+    println!("total_tokens:  {}", usage.total_tokens());
+    match usage.cost_usd {
+        Some(cost) => println!("cost_usd:      {:.4}", cost),
+        None => println!("cost_usd:      -"),
+    }
+    match usage.duration_ms {
+        Some(ms) => println!("duration:      {:.1}s", ms as f64 / 1000.0),
+        None => println!("duration:      -"),
+    }
+    match usage.tool_calls {
+        Some(calls) => println!("tool_calls:    {}", calls),
+        None => println!("tool_calls:    -"),
+    }
+}
+
 fn fetch_job(work_dir: &Path, config_override: Option<&PathBuf>, job_id: JobId) -> Result<Job> {
     let (port, token) = load_gui_http_settings(work_dir, config_override);
     let url = format!("http://127.0.0.1:{port}/ctl/jobs/{job_id}");
@@ -772,13 +1171,17 @@ pub fn job_merge_command(
     config_override: Option<&PathBuf>,
     job_id: JobId,
     message: Option<String>,
+    force: bool,
 ) -> Result<()> {
     let (port, token) = load_gui_http_settings(work_dir, config_override);
     let url = format!("http://127.0.0.1:{port}/ctl/jobs/{job_id}/merge");
-    let payload = match message {
+    let mut payload = match message {
         Some(msg) => serde_json::json!({ "message": msg }),
         None => serde_json::json!({}),
     };
+    if force {
+        payload["force"] = serde_json::Value::Bool(true);
+    }
     let value = http_post_json(&url, token.as_deref(), payload)?;
 
     let status = value
@@ -876,6 +1279,69 @@ pub fn job_restart_command(
     }
 }
 
+pub fn job_retry_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    job_id: JobId,
+    same_worktree: bool,
+) -> Result<()> {
+    let (port, token) = load_gui_http_settings(work_dir, config_override);
+    let url = format!("http://127.0.0.1:{port}/ctl/jobs/{job_id}/retry");
+    let value = http_post_json(
+        &url,
+        token.as_deref(),
+        serde_json::json!({ "same_worktree": same_worktree }),
+    )?;
+
+    let status = value
+        .get("status")
+        .and_then(|s| s.as_str())
+        .unwrap_or("unknown");
+
+    if status == "ok" {
+        let new_job_id = value
+            .get("new_job_id")
+            .and_then(|id| id.as_u64())
+            .unwrap_or(0);
+        let reused = value
+            .get("same_worktree")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if reused {
+            println!("Retrying job #{} as #{} (same worktree)", job_id, new_job_id);
+        } else {
+            println!("Retrying job #{} as #{}", job_id, new_job_id);
+        }
+        Ok(())
+    } else {
+        let error = value
+            .get("error")
+            .and_then(|e| e.as_str())
+            .unwrap_or("unknown_error");
+        let message = value
+            .get("message")
+            .and_then(|m| m.as_str())
+            .unwrap_or("Retry failed");
+        anyhow::bail!("{}: {}", error, message)
+    }
+}
+
+pub fn job_resume_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    job_id: JobId,
+) -> Result<()> {
+    let (port, token) = load_gui_http_settings(work_dir, config_override);
+    let url = format!("http://127.0.0.1:{port}/ctl/jobs/{job_id}/resume");
+    let value = http_post_json(&url, token.as_deref(), serde_json::json!({}))?;
+    let session_id = value
+        .get("session_id")
+        .and_then(|s| s.as_str())
+        .unwrap_or("-");
+    println!("Resuming job #{} (session {})", job_id, session_id);
+    Ok(())
+}
+
 pub fn job_diff_command(
     work_dir: &Path,
     config_override: Option<&PathBuf>,
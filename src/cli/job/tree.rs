@@ -0,0 +1,158 @@
+//! Job lineage tree (`continued_from` / `retried_from`).
+
+use anyhow::{Context, Result};
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::{Job, JobId};
+
+use super::http::{http_get_json, load_gui_http_settings};
+use super::types::JobsListResponse;
+
+pub fn job_tree_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    root: Option<JobId>,
+) -> Result<()> {
+    let (port, token) = load_gui_http_settings(work_dir, config_override);
+    let url = format!("http://127.0.0.1:{port}/ctl/jobs");
+    let value = http_get_json(&url, token.as_deref())?;
+    let parsed: JobsListResponse =
+        serde_json::from_value(value).context("Invalid /ctl/jobs response")?;
+
+    let tree = render_job_tree(&parsed.jobs, root)?;
+    if tree.is_empty() {
+        println!("No jobs found.");
+    } else {
+        print!("{}", tree);
+    }
+    Ok(())
+}
+
+/// The job a job was derived from, and how.
+fn parent_of(job: &Job) -> Option<(JobId, &'static str)> {
+    job.continued_from
+        .map(|id| (id, "continue"))
+        .or(job.retried_from.map(|id| (id, "retry")))
+}
+
+/// Render jobs as an indented lineage tree, one line per job.
+///
+/// Jobs whose parent is unknown (deleted/archived) become roots. A visited set
+/// guards against cycles; jobs only reachable through a cycle are printed as
+/// extra roots so nothing is silently dropped.
+fn render_job_tree(jobs: &[Job], root: Option<JobId>) -> Result<String> {
+    let by_id: BTreeMap<JobId, &Job> = jobs.iter().map(|j| (j.id, j)).collect();
+    let mut children: BTreeMap<JobId, Vec<JobId>> = BTreeMap::new();
+    for job in jobs {
+        if let Some((parent, _)) = parent_of(job).filter(|(p, _)| by_id.contains_key(p)) {
+            children.entry(parent).or_default().push(job.id);
+        }
+    }
+    for ids in children.values_mut() {
+        ids.sort_unstable();
+    }
+
+    let mut out = String::new();
+    let mut visited: HashSet<JobId> = HashSet::new();
+
+    if let Some(root) = root {
+        if !by_id.contains_key(&root) {
+            anyhow::bail!("Job #{} not found", root);
+        }
+        render_subtree(root, 0, &by_id, &children, &mut visited, &mut out);
+        return Ok(out);
+    }
+
+    let roots = by_id.values().filter(|job| {
+        parent_of(job).is_none_or(|(parent, _)| !by_id.contains_key(&parent))
+    });
+    for job in roots {
+        render_subtree(job.id, 0, &by_id, &children, &mut visited, &mut out);
+    }
+    for &id in by_id.keys() {
+        if !visited.contains(&id) {
+            render_subtree(id, 0, &by_id, &children, &mut visited, &mut out);
+        }
+    }
+
+    Ok(out)
+}
+
+fn render_subtree(
+    id: JobId,
+    depth: usize,
+    by_id: &BTreeMap<JobId, &Job>,
+    children: &BTreeMap<JobId, Vec<JobId>>,
+    visited: &mut HashSet<JobId>,
+    out: &mut String,
+) {
+    let Some(job) = by_id.get(&id) else {
+        return;
+    };
+    let indent = "  ".repeat(depth);
+    if !visited.insert(id) {
+        out.push_str(&format!("{}#{} (cycle)\n", indent, id));
+        return;
+    }
+
+    let relation = match parent_of(job) {
+        Some((_, kind)) if depth > 0 => format!(" [{}]", kind),
+        _ => String::new(),
+    };
+    out.push_str(&format!(
+        "{}#{} {} {}{}\n",
+        indent, job.id, job.status, job.skill, relation
+    ));
+
+    for &child in children.get(&id).into_iter().flatten() {
+        render_subtree(child, depth + 1, by_id, children, visited, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::JobStatus;
+    use crate::domain::test_support;
+
+    fn job(id: JobId, continued_from: Option<JobId>, retried_from: Option<JobId>) -> Job {
+        Job {
+            skill: "review".to_string(),
+            status: JobStatus::Done,
+            continued_from,
+            retried_from,
+            ..test_support::job(id)
+        }
+    }
+
+    #[test]
+    fn renders_continuations_and_retries_as_children() {
+        let jobs = vec![
+            job(1, None, None),
+            job(2, Some(1), None),
+            job(3, None, Some(2)),
+            job(4, None, None),
+            // Parent no longer exists: shown as a root
+            job(5, Some(99), None),
+        ];
+
+        let tree = render_job_tree(&jobs, None).unwrap();
+        assert_eq!(
+            tree,
+            "#1 done review\n  #2 done review [continue]\n    #3 done review [retry]\n\
+             #4 done review\n#5 done review\n"
+        );
+
+        let subtree = render_job_tree(&jobs, Some(2)).unwrap();
+        assert_eq!(subtree, "#2 done review\n  #3 done review [retry]\n");
+        assert!(render_job_tree(&jobs, Some(42)).is_err());
+    }
+
+    #[test]
+    fn cycles_terminate() {
+        let jobs = vec![job(1, Some(2), None), job(2, Some(1), None)];
+        let tree = render_job_tree(&jobs, None).unwrap();
+        assert_eq!(tree, "#1 done review\n  #2 done review [continue]\n    #1 (cycle)\n");
+    }
+}
@@ -1,10 +1,13 @@
 //! Type definitions for job control API responses.
 
-use crate::{Job, JobId};
+use crate::{Job, JobId, JobStatus, LogEvent};
 
 #[derive(Debug, serde::Deserialize)]
 pub(super) struct JobsListResponse {
     pub jobs: Vec<Job>,
+    /// Matching jobs before paging (absent from older GUIs)
+    #[serde(default)]
+    pub total: Option<usize>,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -12,6 +15,12 @@ pub(super) struct JobGetResponse {
     pub job: Job,
 }
 
+#[derive(Debug, serde::Deserialize)]
+pub(super) struct JobLogsResponse {
+    pub status: JobStatus,
+    pub events: Vec<LogEvent>,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub(crate) struct JobCreateResponse {
     pub job_ids: Vec<JobId>,
@@ -24,10 +33,17 @@ pub(super) struct JobContinueResponse {
     pub job_id: JobId,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub(super) struct JobContinueBatchResponse {
+    pub job_ids: Vec<JobId>,
+}
+
 /// Arguments for starting a new job via the CLI.
 #[derive(Debug, Clone)]
 pub struct JobStartArgs {
     pub file_path: Option<String>,
+    /// Directory to target as a whole (alternative to `file_path`)
+    pub dir_path: Option<String>,
     pub input: Vec<String>,
     pub batch: bool,
     pub line_start: Option<usize>,
@@ -39,6 +55,8 @@ pub struct JobStartArgs {
     pub bugbounty_finding_ids: Vec<String>,
     pub agent: Option<String>,
     pub agents: Vec<String>,
+    /// File with one agent id per line, merged with `agents`
+    pub agents_file: Option<String>,
     pub queue: bool,
     pub force_worktree: bool,
     pub json: bool,
@@ -50,4 +68,16 @@ pub struct JobStartArgs {
     pub plan_mode: bool,
     /// Permission mode override (default, acceptEdits, bypassPermissions, plan)
     pub permission_mode: Option<String>,
+    /// Extra per-job environment variables as `KEY=VALUE`
+    pub env: Vec<String>,
+    /// Start even if the daily budget is exhausted
+    pub ignore_budget: bool,
+    /// Preferred result states for multi-agent auto-selection (empty = manual comparison)
+    pub auto_select: Vec<String>,
+    /// Lines of surrounding code to include around `line_start..line_end`
+    pub context_lines: usize,
+    /// Labels to attach to the created jobs
+    pub tags: Vec<String>,
+    /// Branch or commit to create the job's worktree from
+    pub base: Option<String>,
 }
@@ -4,7 +4,7 @@ use anyhow::{bail, Context, Result};
 use std::path::Path;
 
 use crate::bugbounty::{
-    import_semgrep_memory, BugBountyManager, MemorySourceKind, MemoryType,
+    import_semgrep_memory, BugBountyManager, MemorySourceKind, MemoryType, ProjectMemory,
 };
 
 /// List memory entries
@@ -65,6 +65,51 @@ pub fn list(
     Ok(())
 }
 
+/// Add a manual memory entry (skipped if an equivalent entry already exists)
+pub fn add(
+    project: &str,
+    memory_type: &str,
+    text: &str,
+    file: Option<String>,
+    line: Option<u32>,
+) -> Result<()> {
+    let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
+
+    // Verify project exists
+    let _project = manager
+        .get_project(project)?
+        .ok_or_else(|| anyhow::anyhow!("Project not found: {}", project))?;
+
+    let mem_type = MemoryType::from_str(memory_type)
+        .ok_or_else(|| anyhow::anyhow!("Invalid memory type: {}", memory_type))?;
+    if mem_type == MemoryType::Dataflow {
+        bail!("Dataflow entries need from/to locations; import them from a tool instead");
+    }
+
+    let text = text.trim();
+    if text.is_empty() {
+        bail!("Memory text must not be empty");
+    }
+
+    let mut mem = ProjectMemory::new(project, mem_type, MemorySourceKind::Manual, text);
+    if let Some(file) = file {
+        mem = mem.with_file(file);
+    }
+    if let Some(line) = line {
+        mem = mem.with_line(line);
+    }
+
+    if manager.memory().exists_duplicate(&mem)? {
+        println!("Skipped: an equivalent {} entry already exists", mem_type.as_str());
+        return Ok(());
+    }
+
+    let id = manager.memory().create(&mem)?;
+    println!("Added memory entry {} ({}: {})", id, mem_type.as_str(), mem.title);
+
+    Ok(())
+}
+
 /// Import memory from external tools (semgrep, codeql)
 pub fn import(tool: &str, file: &str, project: &str) -> Result<()> {
     let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
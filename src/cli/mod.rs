@@ -1,7 +1,10 @@
 //! CLI command implementations
 
 pub mod agent;
+pub mod artifact;
 pub mod chain;
+pub mod config;
+pub mod doctor;
 pub mod finding;
 pub mod import;
 pub mod init;
@@ -12,4 +15,7 @@ pub mod project;
 pub mod scope;
 pub mod session;
 pub mod skill;
+pub mod stats;
 pub mod status;
+pub mod version;
+pub mod worktree;
@@ -3,7 +3,7 @@
 use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 
-use crate::config::{Config, ModeConfig, ModeSessionType};
+use crate::config::{Config, ModeConfig, ModeSessionType, StepAction};
 
 const AUTH_HEADER: &str = "X-KYCO-Token";
 
@@ -107,11 +107,15 @@ pub fn mode_get_command(
 pub struct ModeSetArgs {
     pub name: String,
     pub prompt: Option<String>,
+    pub prompt_file: Option<PathBuf>,
     pub system_prompt: Option<String>,
+    pub system_prompt_file: Option<PathBuf>,
     pub agent: Option<String>,
     pub aliases: Vec<String>,
     pub session_mode: Option<String>,
     pub max_turns: Option<u32>,
+    pub max_output_tokens: Option<u32>,
+    pub timeout_secs: Option<u64>,
     pub model: Option<String>,
     pub disallowed_tools: Vec<String>,
     pub output_states: Vec<String>,
@@ -120,11 +124,39 @@ pub struct ModeSetArgs {
     pub json: bool,
 }
 
+/// Take a text field either inline (`--<flag>`) or from `--<flag>-file`.
+///
+/// Relative file paths resolve against the current directory.
+fn inline_or_file(
+    inline: Option<String>,
+    file: Option<&Path>,
+    flag: &str,
+) -> Result<Option<String>> {
+    match (inline, file) {
+        (Some(_), Some(_)) => {
+            anyhow::bail!("--{flag} and --{flag}-file are mutually exclusive")
+        }
+        (Some(text), None) => Ok(Some(text)),
+        (None, Some(path)) => std::fs::read_to_string(path)
+            .map(Some)
+            .with_context(|| format!("Failed to read --{flag}-file {}", path.display())),
+        (None, None) => Ok(None),
+    }
+}
+
 pub fn mode_set_command(
     work_dir: &Path,
     config_override: Option<&PathBuf>,
     args: ModeSetArgs,
 ) -> Result<()> {
+    // Read files before touching the config so a bad path leaves it unchanged
+    let prompt = inline_or_file(args.prompt, args.prompt_file.as_deref(), "prompt")?;
+    let system_prompt = inline_or_file(
+        args.system_prompt,
+        args.system_prompt_file.as_deref(),
+        "system-prompt",
+    )?;
+
     let (mut cfg, config_path) = load_or_init_config(work_dir, config_override)?;
 
     let mut mode = cfg.mode.remove(&args.name).unwrap_or_else(|| ModeConfig {
@@ -136,6 +168,8 @@ pub fn mode_set_command(
         system_prompt: None,
         session_mode: ModeSessionType::Oneshot,
         max_turns: 0,
+        max_output_tokens: None,
+        timeout_secs: None,
         model: None,
         disallowed_tools: Vec::new(),
         claude: None,
@@ -143,14 +177,18 @@ pub fn mode_set_command(
         aliases: Vec::new(),
         output_states: Vec::new(),
         state_prompt: None,
+        result_parser: None,
+        result_pattern: None,
         allowed_tools: Vec::new(),
         use_worktree: None,
+        auto_merge: None,
+        skip_global_suffix: false,
     });
 
-    if let Some(prompt) = args.prompt {
+    if let Some(prompt) = prompt {
         mode.prompt = Some(prompt);
     }
-    if let Some(system_prompt) = args.system_prompt {
+    if let Some(system_prompt) = system_prompt {
         mode.system_prompt = Some(system_prompt);
     }
     if let Some(agent) = args.agent {
@@ -168,6 +206,16 @@ pub fn mode_set_command(
     if let Some(max_turns) = args.max_turns {
         mode.max_turns = max_turns;
     }
+    if let Some(max_output_tokens) = args.max_output_tokens {
+        if max_output_tokens == 0 {
+            anyhow::bail!("--max-output-tokens must be positive");
+        }
+        mode.max_output_tokens = Some(max_output_tokens);
+    }
+    if let Some(timeout_secs) = args.timeout_secs {
+        // 0 clears the limit (unlimited)
+        mode.timeout_secs = Some(timeout_secs).filter(|s| *s > 0);
+    }
     if let Some(model) = args.model {
         mode.model = Some(model);
     }
@@ -211,3 +259,243 @@ pub fn mode_delete_command(
     println!("Mode deleted: {}", name);
     Ok(())
 }
+
+pub fn mode_rename_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    old_name: &str,
+    new_name: &str,
+    force: bool,
+) -> Result<()> {
+    let (mut cfg, config_path) = load_or_init_config(work_dir, config_override)?;
+    let updated_steps = rename_mode(&mut cfg, old_name, new_name, force)?;
+    save_config(&cfg, &config_path)?;
+    notify_gui_config_changed(&cfg);
+
+    println!("Mode renamed: {} -> {}", old_name, new_name);
+    if updated_steps > 0 {
+        println!("Updated {} chain step reference(s)", updated_steps);
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn mode_copy_command(
+    work_dir: &Path,
+    config_override: Option<&PathBuf>,
+    src: &str,
+    dst: &str,
+    force: bool,
+    agent: Option<String>,
+    model: Option<String>,
+    json: bool,
+) -> Result<()> {
+    let (mut cfg, config_path) = load_or_init_config(work_dir, config_override)?;
+    let mode = copy_mode(&mut cfg, src, dst, force, agent, model)?;
+    save_config(&cfg, &config_path)?;
+    notify_gui_config_changed(&cfg);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&mode)?);
+    } else {
+        println!("Mode copied: {} -> {}", src, dst.trim());
+    }
+    Ok(())
+}
+
+/// Deep-clone mode `src` into `dst`, applying the given overrides.
+///
+/// The copy is a user mode (version 0) and gets no aliases, since aliases must stay unique.
+fn copy_mode(
+    cfg: &mut Config,
+    src: &str,
+    dst: &str,
+    force: bool,
+    agent: Option<String>,
+    model: Option<String>,
+) -> Result<ModeConfig> {
+    let dst = dst.trim();
+    if dst.is_empty() {
+        anyhow::bail!("New mode name must not be empty");
+    }
+    if src == dst {
+        anyhow::bail!("Cannot copy mode {} onto itself", src);
+    }
+    if cfg.mode.contains_key(dst) && !force {
+        anyhow::bail!("Mode already exists: {} (use --force to overwrite)", dst);
+    }
+    let Some(mut mode) = cfg.mode.get(src).cloned() else {
+        anyhow::bail!("Mode not found: {}", src);
+    };
+
+    mode.version = 0;
+    mode.aliases.clear();
+    if let Some(agent) = agent {
+        mode.agent = Some(agent);
+    }
+    if let Some(model) = model {
+        mode.model = Some(model);
+    }
+
+    cfg.mode.insert(dst.to_string(), mode.clone());
+    Ok(mode)
+}
+
+/// Rename a mode and rewrite everything that refers to it by name.
+///
+/// Chain steps (`skill`, `loop_to` and `on_state` gotos) and `[alias.mode]` targets pointing at
+/// `old_name` are updated. Returns the number of chain steps that changed.
+fn rename_mode(cfg: &mut Config, old_name: &str, new_name: &str, force: bool) -> Result<usize> {
+    let new_name = new_name.trim();
+    if new_name.is_empty() {
+        anyhow::bail!("New mode name must not be empty");
+    }
+    if old_name == new_name {
+        anyhow::bail!("Mode is already named {}", new_name);
+    }
+    if cfg.mode.contains_key(new_name) && !force {
+        anyhow::bail!("Mode already exists: {} (use --force to overwrite)", new_name);
+    }
+    let Some(mode) = cfg.mode.remove(old_name) else {
+        anyhow::bail!("Mode not found: {}", old_name);
+    };
+    cfg.mode.insert(new_name.to_string(), mode);
+
+    let mut updated_steps = 0;
+    for chain in cfg.chain.values_mut() {
+        for step in &mut chain.steps {
+            let mut changed = false;
+            if step.skill == old_name {
+                step.skill = new_name.to_string();
+                changed = true;
+            }
+            if step.loop_to.as_deref() == Some(old_name) {
+                step.loop_to = Some(new_name.to_string());
+                changed = true;
+            }
+            for action in step.on_state.values_mut() {
+                if let StepAction::Goto(target) = action {
+                    if target == old_name {
+                        *target = new_name.to_string();
+                        changed = true;
+                    }
+                }
+            }
+            if changed {
+                updated_steps += 1;
+            }
+        }
+    }
+
+    for target in cfg.alias.mode.values_mut() {
+        if target == old_name {
+            *target = new_name.to_string();
+        }
+    }
+
+    Ok(updated_steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        toml::from_str(
+            r#"
+            [mode.review]
+            prompt = "Review {file}"
+
+            [mode.fix]
+            prompt = "Fix {file}"
+
+            [chain.review-fix]
+            steps = [
+                { skill = "review" },
+                { skill = "fix", loop_to = "review" },
+            ]
+
+            [alias.mode]
+            r = "review"
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn rename_rewrites_chain_steps_and_aliases() {
+        let mut cfg = config();
+        let updated = rename_mode(&mut cfg, "review", "audit", false).unwrap();
+
+        assert_eq!(updated, 2);
+        assert!(!cfg.mode.contains_key("review"));
+        assert!(cfg.mode.contains_key("audit"));
+        let steps = &cfg.chain["review-fix"].steps;
+        assert_eq!(steps[0].skill, "audit");
+        assert_eq!(steps[1].skill, "fix");
+        assert_eq!(steps[1].loop_to.as_deref(), Some("audit"));
+        assert_eq!(cfg.alias.mode["r"], "audit");
+    }
+
+    #[test]
+    fn prompt_file_is_read_and_exclusive_with_inline() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("prompt.md");
+        std::fs::write(&path, "Review {file}\nSay \"done\" when finished.\n").unwrap();
+
+        let text = inline_or_file(None, Some(&path), "prompt").unwrap();
+        assert_eq!(
+            text.as_deref(),
+            Some("Review {file}\nSay \"done\" when finished.\n")
+        );
+        assert_eq!(
+            inline_or_file(Some("inline".into()), None, "prompt").unwrap().as_deref(),
+            Some("inline")
+        );
+        assert!(inline_or_file(Some("inline".into()), Some(&path), "prompt").is_err());
+        assert!(inline_or_file(None, Some(&dir.path().join("missing.md")), "prompt").is_err());
+    }
+
+    #[test]
+    fn rename_refuses_to_overwrite_without_force() {
+        let mut cfg = config();
+        assert!(rename_mode(&mut cfg, "review", "fix", false).is_err());
+        assert!(cfg.mode.contains_key("review"));
+
+        rename_mode(&mut cfg, "review", "fix", true).unwrap();
+        assert_eq!(cfg.mode.len(), 1);
+        assert_eq!(cfg.mode["fix"].prompt.as_deref(), Some("Review {file}"));
+        assert!(rename_mode(&mut cfg, "missing", "other", false).is_err());
+    }
+
+    #[test]
+    fn copy_clones_mode_with_overrides_and_keeps_source() {
+        let mut cfg = config();
+        cfg.mode.get_mut("review").unwrap().version = 3;
+        cfg.mode.get_mut("review").unwrap().aliases = vec!["rv".to_string()];
+
+        let copy = copy_mode(
+            &mut cfg,
+            "review",
+            "review-strict",
+            false,
+            None,
+            Some("opus".to_string()),
+        )
+        .unwrap();
+        assert_eq!(copy.prompt.as_deref(), Some("Review {file}"));
+        assert_eq!(copy.model.as_deref(), Some("opus"));
+        assert_eq!(copy.version, 0);
+        assert!(copy.aliases.is_empty());
+        assert_eq!(cfg.mode["review"].aliases, vec!["rv".to_string()]);
+        assert!(cfg.mode["review"].model.is_none());
+
+        assert!(copy_mode(&mut cfg, "review", "review", true, None, None).is_err());
+        assert!(copy_mode(&mut cfg, "review", "fix", false, None, None).is_err());
+        assert!(copy_mode(&mut cfg, "missing", "other", false, None, None).is_err());
+
+        copy_mode(&mut cfg, "review", "fix", true, Some("codex".to_string()), None).unwrap();
+        assert_eq!(cfg.mode["fix"].prompt.as_deref(), Some("Review {file}"));
+        assert_eq!(cfg.mode["fix"].agent.as_deref(), Some("codex"));
+    }
+}
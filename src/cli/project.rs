@@ -1,10 +1,11 @@
 //! CLI commands for managing BugBounty projects
 
 use anyhow::{bail, Context, Result};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::bugbounty::{
-    parse_scope_file, BugBountyJob, BugBountyManager, Project, ProjectMetadata, ToolPolicy,
+    parse_scope_file, BugBountyJob, BugBountyManager, BundleImportOptions, ContextInjector,
+    Project, ProjectBundle, ProjectMetadata, ReportFormat, ToolPolicy,
 };
 
 /// List all projects
@@ -263,76 +264,75 @@ pub fn show(id: &str, json: bool) -> Result<()> {
     Ok(())
 }
 
-/// Discover projects from BugBounty/programs/ directory
-pub fn discover(path: Option<String>, dry_run: bool) -> Result<()> {
+/// Program directory layouts scanned by `kyco project discover` when none are given
+const DEFAULT_DISCOVER_LAYOUTS: &[&str] = &["BugBounty/programs/*", "programs/*"];
+
+/// Discover projects from program directories (default: `BugBounty/programs/*`)
+///
+/// Each `layouts` entry is a glob relative to `path`; matching directories that
+/// contain a `scope.md` or `CLAUDE.md` become projects named after the directory.
+pub fn discover(path: Option<String>, layouts: &[String], dry_run: bool) -> Result<()> {
     let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
 
     let base_path = path.unwrap_or_else(|| ".".to_string());
     let base = Path::new(&base_path);
     let base_abs = base.canonicalize().unwrap_or_else(|_| base.to_path_buf());
 
-    // Look for BugBounty/programs/*/ or programs/*/
-    let patterns = [
-        base.join("BugBounty/programs"),
-        base.join("programs"),
-    ];
-
-    let mut discovered: Vec<(String, std::path::PathBuf, Option<String>, Option<String>)> =
-        Vec::new();
-
-    for pattern_base in &patterns {
-        if !pattern_base.exists() {
-            continue;
-        }
-
-        // List directories in programs/
-        if let Ok(entries) = std::fs::read_dir(pattern_base) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if !path.is_dir() {
-                    continue;
-                }
-
-                // Check if it looks like a project (has scope.md or CLAUDE.md)
-                let has_scope = path.join("scope.md").exists();
-                let has_claude = path.join("CLAUDE.md").exists();
-
-                if has_scope || has_claude {
-                    let dir_name = path
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .unwrap_or("unknown");
-
-                    // Parse platform-target from directory name
-                    let (platform, target) = if let Some((p, t)) = dir_name.split_once('-') {
-                        (Some(p.to_string()), Some(t.to_string()))
-                    } else {
-                        (None, Some(dir_name.to_string()))
-                    };
-
-                    discovered.push((dir_name.to_string(), path.clone(), platform, target));
-                }
-            }
-        }
-    }
+    let layouts: Vec<String> = if layouts.is_empty() {
+        DEFAULT_DISCOVER_LAYOUTS
+            .iter()
+            .map(|l| l.to_string())
+            .collect()
+    } else {
+        layouts.to_vec()
+    };
+    let discovered = discover_program_dirs(base, &layouts)?;
 
     if discovered.is_empty() {
         println!("No projects discovered.");
-        println!("Looking in: {:?}", patterns);
+        println!("Looking in: {}", layouts.join(", "));
         println!("\nExpected structure:");
         println!("  BugBounty/programs/<platform>-<target>/");
         println!("  with scope.md or CLAUDE.md file");
         return Ok(());
     }
 
-    println!("Discovered {} project(s):\n", discovered.len());
+    let mut new = Vec::new();
+    let mut known = Vec::new();
+    for dir in discovered {
+        let id = dir
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown")
+            .to_string();
+        if manager.get_project(&id)?.is_some() {
+            known.push((id, dir));
+        } else {
+            new.push((id, dir));
+        }
+    }
 
-    for (id, path, platform, _target) in &discovered {
+    if !known.is_empty() {
+        println!("Already known ({}):", known.len());
+        for (id, dir) in &known {
+            println!("  {} -> {}", id, dir.display());
+        }
+        println!();
+    }
+
+    if new.is_empty() {
+        println!("No new projects.");
+        return Ok(());
+    }
+
+    println!("New ({}):", new.len());
+    for (id, dir) in &new {
+        let (platform, _) = infer_platform_target(id);
         println!(
             "  {} ({}) -> {}",
             id,
             platform.as_deref().unwrap_or("unknown"),
-            path.display()
+            dir.display()
         );
     }
 
@@ -343,34 +343,23 @@ pub fn discover(path: Option<String>, dry_run: bool) -> Result<()> {
 
     println!();
 
-    // Create projects
     let mut created = 0;
-    let mut skipped = 0;
-
-    for (id, path, platform, target) in discovered {
-        // Check if already exists
-        if manager.get_project(&id)?.is_some() {
-            println!("  Skipped {} (already exists)", id);
-            skipped += 1;
-            continue;
-        }
-
-        let root_path = normalize_root_path(&base_abs, &path);
+    for (id, dir) in new {
+        let root_path = normalize_root_path(&base_abs, &dir);
         let mut project = Project::new(&id, root_path);
+        let (platform, target) = infer_platform_target(&id);
         if let Some(p) = platform {
             project = project.with_platform(p);
         }
-        if let Some(t) = target {
-            project = project.with_target_name(t);
-        }
+        project = project.with_target_name(target);
 
-        if let Some(scope) = load_project_scope(&path) {
+        if let Some(scope) = load_project_scope(&dir) {
             project.scope = Some(scope);
         }
-        if let Some(policy) = infer_tool_policy(&path) {
+        if let Some(policy) = infer_tool_policy(&dir) {
             project.tool_policy = Some(policy);
         }
-        if let Some(metadata) = infer_project_metadata(&path) {
+        if let Some(metadata) = infer_project_metadata(&dir) {
             project.metadata = Some(metadata);
         }
 
@@ -379,11 +368,46 @@ pub fn discover(path: Option<String>, dry_run: bool) -> Result<()> {
         created += 1;
     }
 
-    println!("\nCreated: {}  Skipped: {}", created, skipped);
+    println!("\nCreated: {}  Already known: {}", created, known.len());
 
     Ok(())
 }
 
+/// Directories under `base` matching any of the `layouts` globs that look like a
+/// program (contain `scope.md` or `CLAUDE.md`), sorted and deduplicated.
+fn discover_program_dirs(base: &Path, layouts: &[String]) -> Result<Vec<PathBuf>> {
+    let mut dirs = Vec::new();
+    for layout in layouts {
+        // Escape the base so only the layout part is treated as a glob
+        let pattern = format!(
+            "{}/{}",
+            glob::Pattern::escape(&base.to_string_lossy()),
+            layout.trim_end_matches('/')
+        );
+        let entries =
+            glob::glob(&pattern).with_context(|| format!("Invalid layout glob: {layout}"))?;
+        for dir in entries.flatten() {
+            let is_program = dir.join("scope.md").is_file() || dir.join("CLAUDE.md").is_file();
+            if dir.is_dir() && is_program {
+                dirs.push(dir);
+            }
+        }
+    }
+    dirs.sort();
+    dirs.dedup();
+    Ok(dirs)
+}
+
+/// Split a `<platform>-<target>` directory name; without a dash the whole name is the target.
+fn infer_platform_target(dir_name: &str) -> (Option<String>, String) {
+    match dir_name.split_once('-') {
+        Some((platform, target)) if !platform.is_empty() && !target.is_empty() => {
+            (Some(platform.to_string()), target.to_string())
+        }
+        _ => (None, dir_name.to_string()),
+    }
+}
+
 /// Select a project as the active project
 pub fn select(id: &str) -> Result<()> {
     let manager = BugBountyManager::new()?;
@@ -403,6 +427,47 @@ pub fn select(id: &str) -> Result<()> {
     Ok(())
 }
 
+/// The project saved by [`select`], if any
+pub(crate) fn load_active_project_id() -> Option<String> {
+    let path = dirs::home_dir()?.join(".kyco").join("active_project");
+    let content = std::fs::read_to_string(path).ok()?;
+    let trimmed = content.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+/// `--project` if given, else the active project
+pub(crate) fn resolve_project_id(project: Option<String>) -> Result<String> {
+    if let Some(id) = project {
+        let trimmed = id.trim();
+        if !trimmed.is_empty() {
+            return Ok(trimmed.to_string());
+        }
+    }
+
+    if let Some(id) = load_active_project_id() {
+        return Ok(id);
+    }
+
+    bail!(
+        "No project specified and no active project selected.\nUse --project <id> or run: kyco project select <id>"
+    )
+}
+
+/// Absolute project root; a relative `root_path` is taken relative to `work_dir`
+pub(crate) fn resolve_project_root(work_dir: &Path, root_path: &str) -> PathBuf {
+    let raw = PathBuf::from(root_path);
+    let abs = if raw.is_absolute() {
+        raw
+    } else {
+        work_dir.join(raw)
+    };
+    abs.canonicalize().unwrap_or(abs)
+}
+
 /// Initialize a new project
 pub fn init(id: &str, path: &str, platform: Option<String>) -> Result<()> {
     let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
@@ -478,6 +543,104 @@ pub fn delete(id: &str, yes: bool) -> Result<()> {
     Ok(())
 }
 
+/// Export all findings of a project as a single report
+pub fn report(id: &str, format: &str, out: Option<String>) -> Result<()> {
+    let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
+
+    let Some(format) = ReportFormat::from_str(format) else {
+        bail!("Unknown format: {}. Use: md, html", format);
+    };
+    let content = manager.export_project_report(id, format)?;
+
+    if let Some(path) = out {
+        std::fs::write(&path, &content)?;
+        println!("Report written to: {}", path);
+    } else {
+        println!("{}", content);
+    }
+
+    Ok(())
+}
+
+/// Write a project bundle (project, findings, artifacts, flows, memory, jobs)
+pub fn export(id: &str, out: Option<String>) -> Result<()> {
+    let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
+
+    let bundle = manager.export_project(id)?;
+    let content = serde_json::to_string_pretty(&bundle)?;
+
+    if let Some(path) = out {
+        std::fs::write(&path, &content)?;
+        println!(
+            "Exported {} ({} findings, {} artifacts, {} jobs) to: {}",
+            id,
+            bundle.findings.len(),
+            bundle.artifacts.len(),
+            bundle.jobs.len(),
+            path
+        );
+    } else {
+        println!("{}", content);
+    }
+
+    Ok(())
+}
+
+/// Recreate a project from a bundle written by [`export`]
+pub fn import(file: &str, project_id: Option<String>) -> Result<()> {
+    let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
+
+    let content =
+        std::fs::read_to_string(file).with_context(|| format!("Failed to read {}", file))?;
+    let bundle: ProjectBundle = serde_json::from_str(&content)
+        .with_context(|| format!("{} is not a project bundle", file))?;
+
+    let result = manager.import_project(&bundle, &BundleImportOptions { project_id })?;
+
+    println!("Imported project: {}", result.project_id);
+    println!("  Findings:   {}", result.findings);
+    println!("  Artifacts:  {}", result.artifacts);
+    println!("  Flow edges: {}", result.flow_edges);
+    println!("  Memory:     {}", result.memory);
+    println!("  Jobs:       {}", result.jobs);
+    if result.remapped_ids > 0 {
+        println!("  ({} ids were already taken and got new ids)", result.remapped_ids);
+    }
+
+    Ok(())
+}
+
+/// Print the context the executor would inject into a job's prompt
+///
+/// Mirrors the executor: file-specific context when `file` is given, project
+/// context otherwise, plus the linked findings as focus findings.
+pub fn context(id: &str, finding_ids: &[String], file: Option<&str>, json: bool) -> Result<()> {
+    let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
+
+    if manager.get_project(id)?.is_none() {
+        bail!("Project not found: {}", id);
+    }
+
+    let injector = ContextInjector::new(manager);
+    let mut ctx = match file.map(str::trim).filter(|f| !f.is_empty()) {
+        Some(file) => injector.for_file(id, file)?,
+        None => injector.for_project(id)?,
+    };
+    ctx.focus_findings = injector.focus_findings(id, finding_ids);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&ctx)?);
+    } else if ctx.is_empty() {
+        println!("No context would be injected for project {}.", id);
+    } else {
+        let prompt = ctx.to_system_prompt();
+        println!("{}", prompt.trim_end());
+        eprintln!("\n({} chars)", prompt.chars().count());
+    }
+
+    Ok(())
+}
+
 /// Generate project overview
 pub fn overview(
     project: Option<String>,
@@ -972,3 +1135,55 @@ fn extract_links_from_text(content: &str) -> Vec<String> {
     }
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discover_program_dirs_honors_layouts() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path();
+        for (dir, marker) in [
+            ("BugBounty/programs/hackerone-acme", Some("scope.md")),
+            ("BugBounty/programs/intigriti-shop", Some("CLAUDE.md")),
+            ("BugBounty/programs/notes", None),
+            ("targets/bugcrowd-corp", Some("scope.md")),
+        ] {
+            std::fs::create_dir_all(base.join(dir)).unwrap();
+            if let Some(marker) = marker {
+                std::fs::write(base.join(dir).join(marker), "# Scope\n").unwrap();
+            }
+        }
+
+        let defaults: Vec<String> = DEFAULT_DISCOVER_LAYOUTS
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+        let names = |dirs: Vec<PathBuf>| -> Vec<String> {
+            dirs.iter()
+                .map(|d| d.file_name().unwrap().to_string_lossy().to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            names(discover_program_dirs(base, &defaults).unwrap()),
+            ["hackerone-acme", "intigriti-shop"]
+        );
+        assert_eq!(
+            names(discover_program_dirs(base, &["targets/*/".to_string()]).unwrap()),
+            ["bugcrowd-corp"]
+        );
+        assert!(discover_program_dirs(base, &["[".to_string()]).is_err());
+    }
+
+    #[test]
+    fn infer_platform_target_splits_on_first_dash() {
+        assert_eq!(
+            infer_platform_target("hackerone-acme-corp"),
+            (Some("hackerone".to_string()), "acme-corp".to_string())
+        );
+        assert_eq!(infer_platform_target("nextcloud"), (None, "nextcloud".to_string()));
+        assert_eq!(infer_platform_target("-odd"), (None, "-odd".to_string()));
+    }
+}
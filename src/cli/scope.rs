@@ -1,7 +1,11 @@
 //! CLI commands for scope and tool policy management
 
 use anyhow::{bail, Context, Result};
-use crate::bugbounty::BugBountyManager;
+use std::path::Path;
+
+use crate::bugbounty::{
+    diff_scopes, parse_scope_file, BugBountyManager, FindingStatus, ProjectScope,
+};
 
 /// Show scope for a project
 pub fn show(project: Option<String>, json: bool) -> Result<()> {
@@ -194,26 +198,166 @@ pub fn policy(project: Option<String>, json: bool) -> Result<()> {
     Ok(())
 }
 
+/// Compare two scope files and flag findings whose assets became out of scope
+pub fn diff(old: &str, new: &str, project: Option<String>, json: bool) -> Result<()> {
+    let old_scope = parse_scope_file(Path::new(old))?;
+    let new_scope = parse_scope_file(Path::new(new))?;
+    let scope_diff = diff_scopes(&old_scope, &new_scope);
+
+    // Findings are checked for --project or the active project; without either the diff
+    // stands alone.
+    let mut affected: Vec<AffectedFinding> = Vec::new();
+    let mut checked_project: Option<String> = None;
+    if scope_diff.narrows_scope() {
+        let manager = BugBountyManager::new().context("Failed to initialize BugBounty database")?;
+        let project_id = match project {
+            Some(id) => Some(id),
+            None => active_project_id()?,
+        };
+        if let Some(project_id) = project_id {
+            if manager.get_project(&project_id)?.is_none() {
+                bail!("Project not found: {}", project_id);
+            }
+            for finding in manager.list_findings_by_project(&project_id)? {
+                let excluded: Vec<String> = finding
+                    .affected_assets
+                    .iter()
+                    .filter(|asset| is_newly_excluded(&old_scope, &new_scope, asset))
+                    .cloned()
+                    .collect();
+                if !excluded.is_empty() {
+                    affected.push(AffectedFinding {
+                        id: finding.id,
+                        title: finding.title,
+                        status: finding.status,
+                        assets: excluded,
+                    });
+                }
+            }
+            checked_project = Some(project_id);
+        }
+    }
+
+    if json {
+        let output = serde_json::json!({
+            "diff": scope_diff,
+            "project_id": checked_project,
+            "affected_findings": affected,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    if scope_diff.is_empty() {
+        println!("No scope changes.");
+        return Ok(());
+    }
+
+    let sections = [
+        ("In Scope (added)", "+", &scope_diff.added_in_scope),
+        ("In Scope (removed)", "-", &scope_diff.removed_in_scope),
+        ("Out of Scope (added)", "+", &scope_diff.added_out_of_scope),
+        ("Out of Scope (removed)", "-", &scope_diff.removed_out_of_scope),
+    ];
+    for (title, marker, entries) in sections {
+        if entries.is_empty() {
+            continue;
+        }
+        println!("{}:", title);
+        for entry in entries {
+            println!("  {} {}", marker, entry);
+        }
+        println!();
+    }
+
+    match checked_project {
+        Some(project_id) if affected.is_empty() => {
+            println!("No findings in '{}' affected by the scope change.", project_id);
+        }
+        Some(project_id) => {
+            println!("⚠ Findings in '{}' with newly out-of-scope assets:", project_id);
+            for finding in &affected {
+                println!(
+                    "  {} {} ({})",
+                    finding.id,
+                    finding.title,
+                    finding.assets.join(", ")
+                );
+            }
+        }
+        None if scope_diff.narrows_scope() => {
+            println!("Tip: Pass --project <id> to check findings against the narrowed scope.");
+        }
+        None => {}
+    }
+
+    Ok(())
+}
+
+/// A finding with affected assets that the new scope moved out of scope
+#[derive(serde::Serialize)]
+struct AffectedFinding {
+    id: String,
+    title: String,
+    status: FindingStatus,
+    assets: Vec<String>,
+}
+
+/// Scope status of an asset: Some(true) in scope, Some(false) out of scope, None unknown
+fn scope_status(scope: &ProjectScope, asset: &str) -> Option<bool> {
+    let target = normalize_url_for_matching(asset);
+    if scope
+        .out_of_scope
+        .iter()
+        .any(|oos| matches_scope_pattern(&target, oos))
+    {
+        return Some(false);
+    }
+    if scope.in_scope.iter().any(|is| matches_scope_pattern(&target, is)) {
+        return Some(true);
+    }
+    None
+}
+
+/// An asset is newly excluded if it is now out of scope, or was in scope and no longer is
+fn is_newly_excluded(old: &ProjectScope, new: &ProjectScope, asset: &str) -> bool {
+    match (scope_status(old, asset), scope_status(new, asset)) {
+        (Some(false), _) => false,
+        (_, Some(false)) => true,
+        (Some(true), None) => true,
+        _ => false,
+    }
+}
+
 /// Resolve project ID from argument or active project
 fn resolve_project_id(manager: &BugBountyManager, project: Option<String>) -> Result<String> {
     if let Some(id) = project {
         return Ok(id);
     }
 
-    // Try to get active project from ~/.kyco/active_project
-    if let Some(home) = dirs::home_dir() {
-        let path = home.join(".kyco").join("active_project");
-        if path.exists() {
-            let id = std::fs::read_to_string(&path)?.trim().to_string();
-            if !id.is_empty() {
-                return Ok(id);
-            }
-        }
+    if let Some(id) = active_project_id()? {
+        return Ok(id);
     }
 
     bail!("No project specified and no active project selected.\nUse --project <id> or run: kyco project select <id>")
 }
 
+/// The active project from ~/.kyco/active_project, if one is selected
+fn active_project_id() -> Result<Option<String>> {
+    let Some(home) = dirs::home_dir() else {
+        return Ok(None);
+    };
+    let path = home.join(".kyco").join("active_project");
+    if !path.exists() {
+        return Ok(None);
+    }
+    let id = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?
+        .trim()
+        .to_string();
+    Ok((!id.is_empty()).then_some(id))
+}
+
 /// Normalize URL for scope matching (extract domain/host)
 fn normalize_url_for_matching(url: &str) -> String {
     let url = url.trim();
@@ -283,6 +427,24 @@ mod tests {
         assert!(matches_scope_pattern("api.example.com", "https://api.example.com"));
     }
 
+    #[test]
+    fn test_is_newly_excluded() {
+        let old = ProjectScope {
+            in_scope: vec!["*.example.com".to_string(), "legacy.test".to_string()],
+            ..Default::default()
+        };
+        let new = ProjectScope {
+            in_scope: vec!["*.example.com".to_string()],
+            out_of_scope: vec!["admin.example.com".to_string()],
+            ..Default::default()
+        };
+
+        assert!(is_newly_excluded(&old, &new, "https://admin.example.com/login"));
+        assert!(is_newly_excluded(&old, &new, "legacy.test"));
+        assert!(!is_newly_excluded(&old, &new, "api.example.com"));
+        assert!(!is_newly_excluded(&old, &new, "unrelated.org"));
+    }
+
     #[test]
     fn test_normalize_url() {
         assert_eq!(
@@ -0,0 +1,257 @@
+//! Stats export commands (read the stats database, print CSV or JSON).
+
+use anyhow::{Context, Result, bail};
+
+use crate::stats::{DashboardFilter, DashboardSummary, StatsManager, TimeRange};
+
+/// Columns of the CSV export; one row per mode/chain, per agent, and a total row
+const CSV_HEADER: &str = "kind,name,jobs,succeeded_jobs,success_rate,primary_agent,cost_usd,\
+avg_duration_ms,input_tokens,output_tokens,cache_read_tokens,cache_write_tokens";
+
+/// Print the stats dashboard for `range` as CSV or JSON on stdout
+pub fn export(
+    range: &str,
+    agent: Option<String>,
+    mode: Option<String>,
+    workspace: Option<String>,
+    format: &str,
+) -> Result<()> {
+    let range: TimeRange = range.parse().map_err(anyhow::Error::msg)?;
+    let filter = DashboardFilter {
+        agent,
+        mode_or_chain: mode,
+        workspace,
+    };
+
+    let manager = StatsManager::new().context("Failed to open stats database")?;
+    let summary = manager.query().get_dashboard(range, &filter)?;
+
+    match format {
+        "csv" => print!("{}", dashboard_csv(&summary)),
+        "json" => println!(
+            "{}",
+            serde_json::to_string_pretty(&dashboard_json(&summary, range, &filter))?
+        ),
+        other => bail!("Unknown format: {}. Use: csv, json", other),
+    }
+    Ok(())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn success_rate(succeeded: u64, total: u64) -> f64 {
+    if total == 0 {
+        100.0
+    } else {
+        succeeded as f64 / total as f64 * 100.0
+    }
+}
+
+fn dashboard_csv(summary: &DashboardSummary) -> String {
+    let mut out = String::new();
+    out.push_str(CSV_HEADER);
+    out.push('\n');
+
+    let mut row = |kind: &str,
+                   name: &str,
+                   jobs: u64,
+                   succeeded: u64,
+                   primary_agent: &str,
+                   cost_usd: f64,
+                   avg_duration_ms: u64,
+                   tokens: [u64; 4]| {
+        out.push_str(&format!(
+            "{},{},{},{},{:.1},{},{:.4},{},{},{},{},{}\n",
+            kind,
+            csv_field(name),
+            jobs,
+            succeeded,
+            success_rate(succeeded, jobs),
+            csv_field(primary_agent),
+            cost_usd,
+            avg_duration_ms,
+            tokens[0],
+            tokens[1],
+            tokens[2],
+            tokens[3],
+        ));
+    };
+
+    for mode in &summary.modes {
+        row(
+            "mode",
+            &mode.name,
+            mode.total_jobs,
+            mode.succeeded_jobs,
+            &mode.primary_agent,
+            mode.avg_cost_usd * mode.total_jobs as f64,
+            mode.avg_duration_ms,
+            [
+                mode.tokens.input,
+                mode.tokens.output,
+                mode.tokens.cache_read,
+                mode.tokens.cache_write,
+            ],
+        );
+    }
+    for agent in &summary.agents {
+        row(
+            "agent",
+            &agent.name,
+            agent.jobs,
+            agent.succeeded_jobs,
+            "",
+            agent.cost_usd,
+            agent.avg_duration_ms,
+            [
+                agent.tokens.input,
+                agent.tokens.output,
+                agent.tokens.cache_read,
+                agent.tokens.cache_write,
+            ],
+        );
+    }
+
+    let succeeded = summary.succeeded_jobs.current as u64;
+    row(
+        "total",
+        "",
+        succeeded + summary.failed_jobs.current as u64,
+        succeeded,
+        "",
+        summary.total_cost.current,
+        summary.avg_duration_ms.current as u64,
+        [
+            summary.tokens.input,
+            summary.tokens.output,
+            summary.tokens.cache_read,
+            summary.tokens.cache_write,
+        ],
+    );
+    out
+}
+
+fn dashboard_json(
+    summary: &DashboardSummary,
+    range: TimeRange,
+    filter: &DashboardFilter,
+) -> serde_json::Value {
+    let tokens = |t: &crate::stats::TokenBreakdown| {
+        serde_json::json!({
+            "input": t.input,
+            "output": t.output,
+            "cache_read": t.cache_read,
+            "cache_write": t.cache_write,
+        })
+    };
+    let trend = |t: &crate::stats::TrendValue| serde_json::json!({ "current": t.current, "previous": t.previous });
+
+    serde_json::json!({
+        "range": range.short_name(),
+        "filter": {
+            "agent": filter.agent,
+            "mode": filter.mode_or_chain,
+            "workspace": filter.workspace,
+        },
+        "totals": {
+            "succeeded_jobs": trend(&summary.succeeded_jobs),
+            "failed_jobs": trend(&summary.failed_jobs),
+            "total_cost_usd": trend(&summary.total_cost),
+            "total_tokens": trend(&summary.total_tokens),
+            "avg_duration_ms": trend(&summary.avg_duration_ms),
+            "total_duration_ms": trend(&summary.total_duration_ms),
+            "tool_calls": trend(&summary.total_tool_calls),
+            "file_accesses": trend(&summary.total_file_accesses),
+            "tokens": tokens(&summary.tokens),
+        },
+        "modes": summary.modes.iter().map(|m| serde_json::json!({
+            "name": m.name,
+            "jobs": m.total_jobs,
+            "succeeded_jobs": m.succeeded_jobs,
+            "success_rate": m.success_rate(),
+            "primary_agent": m.primary_agent,
+            "avg_cost_usd": m.avg_cost_usd,
+            "avg_duration_ms": m.avg_duration_ms,
+            "last_used": m.last_used,
+            "tokens": tokens(&m.tokens),
+        })).collect::<Vec<_>>(),
+        "agents": summary.agents.iter().map(|a| serde_json::json!({
+            "name": a.name,
+            "jobs": a.jobs,
+            "succeeded_jobs": a.succeeded_jobs,
+            "cost_usd": a.cost_usd,
+            "avg_duration_ms": a.avg_duration_ms,
+            "tokens": tokens(&a.tokens),
+        })).collect::<Vec<_>>(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::{AgentStats, ModeChainStats, TokenBreakdown, TrendValue};
+
+    #[test]
+    fn csv_has_header_then_mode_agent_and_total_rows() {
+        let tokens = TokenBreakdown {
+            input: 100,
+            output: 50,
+            cache_read: 10,
+            cache_write: 5,
+        };
+        let summary = DashboardSummary {
+            succeeded_jobs: TrendValue {
+                current: 3.0,
+                previous: 0.0,
+            },
+            failed_jobs: TrendValue {
+                current: 1.0,
+                previous: 0.0,
+            },
+            total_cost: TrendValue {
+                current: 0.5,
+                previous: 0.0,
+            },
+            tokens: tokens.clone(),
+            modes: vec![ModeChainStats {
+                name: "review, strict".to_string(),
+                total_jobs: 4,
+                succeeded_jobs: 3,
+                primary_agent: "claude".to_string(),
+                avg_cost_usd: 0.125,
+                avg_duration_ms: 1200,
+                tokens: tokens.clone(),
+                last_used: 0,
+            }],
+            agents: vec![AgentStats {
+                name: "claude".to_string(),
+                jobs: 4,
+                succeeded_jobs: 3,
+                cost_usd: 0.5,
+                tokens,
+                avg_duration_ms: 1200,
+            }],
+            ..Default::default()
+        };
+
+        let csv = dashboard_csv(&summary);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], CSV_HEADER);
+        let columns = CSV_HEADER.split(',').count();
+        assert_eq!(columns, 12);
+        assert_eq!(
+            lines[1],
+            "mode,\"review, strict\",4,3,75.0,claude,0.5000,1200,100,50,10,5"
+        );
+        assert_eq!(lines[2], "agent,claude,4,3,75.0,,0.5000,1200,100,50,10,5");
+        assert!(lines[3].starts_with("total,,4,3,75.0,,0.5000,"));
+        assert_eq!(lines[3].split(',').count(), columns);
+    }
+}
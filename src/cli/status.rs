@@ -1,7 +1,9 @@
 //! Status command implementation
 
 use anyhow::Result;
+use std::io::{IsTerminal, Write};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use crate::config::Config;
 use crate::{Job, JobStatus};
@@ -14,33 +16,29 @@ struct JobsListResponse {
 }
 
 /// Show the status of all jobs
+///
+/// With `watch`, redraw the list every interval until every listed job is finished.
+/// The screen is only cleared on a TTY; otherwise snapshots are appended.
 pub async fn status_command(
     work_dir: &Path,
     config_override: Option<&PathBuf>,
     filter: Option<String>,
+    since: Option<String>,
+    tag: Option<String>,
+    json_lines: bool,
+    watch: Option<Duration>,
 ) -> Result<()> {
     let (port, token) = load_gui_http_settings(work_dir, config_override);
-    let url = format!("http://127.0.0.1:{port}/ctl/jobs");
-
-    let mut req = ureq::get(&url);
-    if let Some(token) = token.as_deref() {
-        req = req.set(AUTH_HEADER, token);
+    let mut url = format!("http://127.0.0.1:{port}/ctl/jobs");
+    if let Some(since) = since.as_deref() {
+        // Resolve relative durations locally so the cutoff is "now" on this side
+        let cutoff = Job::parse_since(since, chrono::Utc::now()).map_err(anyhow::Error::msg)?;
+        url.push_str("?since=");
+        url.push_str(&cutoff.to_rfc3339_opts(chrono::SecondsFormat::Secs, true));
     }
 
-    let resp = req.call().map_err(|e| match e {
-        ureq::Error::Status(code, resp) => {
-            let body = resp.into_string().unwrap_or_default();
-            anyhow::anyhow!("HTTP {code}: {body}")
-        }
-        other => anyhow::anyhow!(other),
-    })?;
-
-    let body = resp.into_string()?;
-    let parsed: JobsListResponse = serde_json::from_str(&body)?;
-
-    let mut jobs = parsed.jobs;
-    if let Some(status_filter) = filter {
-        let target_status = match status_filter.to_lowercase().as_str() {
+    let status_filter = match filter {
+        Some(status_filter) => match status_filter.to_lowercase().as_str() {
             "pending" => Some(JobStatus::Pending),
             "queued" => Some(JobStatus::Queued),
             "blocked" => Some(JobStatus::Blocked),
@@ -53,34 +51,121 @@ pub async fn status_command(
                 eprintln!("Unknown status: {}", status_filter);
                 return Ok(());
             }
-        };
-        jobs.retain(|j| Some(j.status) == target_status);
+        },
+        None => None,
+    };
+
+    let fetch = || -> Result<Vec<Job>> {
+        let mut jobs = fetch_jobs(&url, token.as_deref())?;
+        if let Some(target_status) = status_filter {
+            jobs.retain(|j| j.status == target_status);
+        }
+        if let Some(tag) = tag.as_deref() {
+            jobs.retain(|j| j.has_tag(tag));
+        }
+        Ok(jobs)
+    };
+
+    let Some(interval) = watch else {
+        let jobs = fetch()?;
+        if json_lines {
+            let stdout = std::io::stdout();
+            write_json_lines(&jobs, &mut stdout.lock())?;
+        } else {
+            print!("{}", format_jobs(&jobs));
+        }
+        return Ok(());
+    };
+
+    let redraw = std::io::stdout().is_terminal();
+    loop {
+        let jobs = fetch()?;
+        let now = chrono::Local::now().format("%H:%M:%S");
+        let mut stdout = std::io::stdout().lock();
+        if redraw {
+            // Clear screen and move the cursor home
+            write!(stdout, "\x1b[2J\x1b[H")?;
+        }
+        writeln!(
+            stdout,
+            "kyco status - {} (every {}s, Ctrl+C to stop)\n",
+            now,
+            interval.as_secs_f64()
+        )?;
+        write!(stdout, "{}", format_jobs(&jobs))?;
+        stdout.flush()?;
+        drop(stdout);
+
+        if jobs.iter().all(Job::is_finished) {
+            println!("All jobs finished.");
+            return Ok(());
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+fn fetch_jobs(url: &str, token: Option<&str>) -> Result<Vec<Job>> {
+    let mut req = ureq::get(url);
+    if let Some(token) = token {
+        req = req.set(AUTH_HEADER, token);
     }
 
+    let resp = req.call().map_err(|e| match e {
+        ureq::Error::Status(code, resp) => {
+            let body = resp.into_string().unwrap_or_default();
+            anyhow::anyhow!("HTTP {code}: {body}")
+        }
+        other => anyhow::anyhow!(other),
+    })?;
+
+    let body = resp.into_string()?;
+    let parsed: JobsListResponse = serde_json::from_str(&body)?;
+    Ok(parsed.jobs)
+}
+
+/// The human-readable job list printed by `kyco status`
+pub fn format_jobs(jobs: &[Job]) -> String {
+    use std::fmt::Write as _;
+
     if jobs.is_empty() {
-        println!("No jobs found.");
-        return Ok(());
+        return "No jobs found.\n".to_string();
     }
 
-    println!("Jobs ({}):\n", jobs.len());
+    let mut out = format!("Jobs ({}):\n\n", jobs.len());
     for job in jobs {
-        println!(
+        let _ = writeln!(
+            out,
             "  #{} [{}] {} - {}",
             job.id, job.status, job.skill, job.target
         );
 
+        if !job.tags.is_empty() {
+            let _ = writeln!(out, "    Tags: {}", job.tags.join(", "));
+        }
+
         if let Some(desc) = job.description.as_deref().filter(|d| !d.trim().is_empty()) {
-            println!("    {}", desc.trim());
+            let _ = writeln!(out, "    {}", desc.trim());
         }
 
         if let Some(err) = job.error_message.as_deref() {
-            println!("    Error: {}", err);
+            let _ = writeln!(out, "    Error: {}", err);
         }
 
-        println!();
+        out.push('\n');
     }
+    out
+}
 
-    Ok(())
+/// Write one compact JSON object per job (NDJSON), using the `Job` serde field names.
+///
+/// An empty job list writes nothing. Returns the number of lines written.
+pub fn write_json_lines(jobs: &[Job], out: &mut impl Write) -> Result<usize> {
+    for job in jobs {
+        serde_json::to_writer(&mut *out, job)?;
+        out.write_all(b"\n")?;
+    }
+    out.flush()?;
+    Ok(jobs.len())
 }
 
 /// Resolve the config path - uses global config (~/.kyco/config.toml) as default,
@@ -97,12 +182,12 @@ fn load_gui_http_settings(
     work_dir: &Path,
     config_override: Option<&PathBuf>,
 ) -> (u16, Option<String>) {
-    // If using default global config, use Config::load() which handles auto-init
+    // If using default global config, use Config::load_cached() which handles auto-init
     let config = if config_override.is_none() {
-        Config::load().ok()
+        Config::load_cached().ok()
     } else {
         let config_path = resolve_config_path(work_dir, config_override);
-        Config::from_file(&config_path).ok()
+        Config::from_file_cached(&config_path).ok()
     };
 
     let port = config
@@ -114,3 +199,61 @@ fn load_gui_http_settings(
 
     (port, token)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support::job;
+
+    #[test]
+    fn format_jobs_lists_tags_and_errors() {
+        assert_eq!(format_jobs(&[]), "No jobs found.\n");
+
+        let mut job = job(4);
+        job.tags = vec!["auth".to_string(), "api".to_string()];
+        job.error_message = Some("boom".to_string());
+
+        let text = format_jobs(&[job]);
+        assert!(text.starts_with("Jobs (1):\n\n  #4 [pending] fix - src/lib.rs\n"));
+        assert!(text.contains("    Tags: auth, api\n"));
+        assert!(text.contains("    Error: boom\n"));
+    }
+
+    #[test]
+    fn writes_one_line_per_job() {
+        let jobs: Vec<Job> = [(1, "review"), (2, "fix"), (3, "implement")]
+            .into_iter()
+            .map(|(id, skill)| Job {
+                skill: skill.to_string(),
+                ..job(id)
+            })
+            .collect();
+        let mut out = Vec::new();
+
+        let written = write_json_lines(&jobs, &mut out).expect("write json lines");
+        let text = String::from_utf8(out).expect("utf8");
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(written, jobs.len());
+        assert_eq!(lines.len(), jobs.len());
+
+        for (line, job) in lines.iter().zip(&jobs) {
+            // Compact, self-contained objects using the Job serde field names
+            assert!(!line.contains('\n'));
+            let parsed: Job = serde_json::from_str(line).expect("line parses as Job");
+            assert_eq!(parsed.id, job.id);
+            assert_eq!(parsed.skill, job.skill);
+
+            let value: serde_json::Value = serde_json::from_str(line).expect("json");
+            assert_eq!(value, serde_json::to_value(job).expect("to_value"));
+        }
+    }
+
+    #[test]
+    fn empty_job_list_writes_nothing() {
+        let mut out = Vec::new();
+        let written = write_json_lines(&[], &mut out).expect("write json lines");
+        assert_eq!(written, 0);
+        assert!(out.is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+//! Version CLI command.
+//!
+//! `kyco version --check` queries the same GitHub release source as the GUI's
+//! update checker.
+
+use anyhow::{Result, bail};
+
+use crate::gui::update::{UpdateStatus, check_for_update};
+
+/// Exit code used by `kyco version --check` when a newer release exists
+pub const EXIT_UPDATE_AVAILABLE: i32 = 10;
+
+const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Print the version, optionally checking for a newer release
+pub fn version_command(check: bool, json: bool) -> Result<()> {
+    if !check {
+        if json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({ "current": CURRENT_VERSION }))?
+            );
+        } else {
+            println!("kyco {}", CURRENT_VERSION);
+        }
+        return Ok(());
+    }
+
+    let (latest, info) = match check_for_update() {
+        UpdateStatus::UpToDate => (CURRENT_VERSION.to_string(), None),
+        UpdateStatus::UpdateAvailable(info) => (info.version.clone(), Some(info)),
+        UpdateStatus::Error(e) => bail!("Update check failed: {}", e),
+        other => bail!("Unexpected update status: {:?}", other),
+    };
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "current": CURRENT_VERSION,
+                "latest": latest,
+                "update_available": info.is_some(),
+                "release_url": info.as_ref().map(|i| i.release_url.as_str()),
+                "download_url": info.as_ref().map(|i| i.download_url.as_str()),
+            }))?
+        );
+    } else {
+        println!("Current: {}", CURRENT_VERSION);
+        println!("Latest:  {}", latest);
+        match &info {
+            Some(info) => {
+                println!("Update available");
+                if !info.release_url.is_empty() {
+                    println!("Release:  {}", info.release_url);
+                }
+                println!("Download: {}", info.download_url);
+            }
+            None => println!("Up to date"),
+        }
+    }
+
+    if info.is_some() {
+        std::process::exit(EXIT_UPDATE_AVAILABLE);
+    }
+    Ok(())
+}
@@ -0,0 +1,56 @@
+//! Worktree maintenance CLI commands.
+//!
+//! Cleans up `.kyco/worktrees` entries left behind by deleted, merged, rejected or
+//! failed jobs.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use super::job::http::{http_get_json, load_gui_http_settings};
+use crate::Job;
+use crate::git::{GitManager, find_git_root};
+use crate::job::JobManager;
+
+/// Remove worktrees whose job no longer exists or was merged, rejected or failed
+pub fn prune(work_dir: &Path, config_override: Option<&PathBuf>, dry_run: bool) -> Result<()> {
+    let repo_root = find_git_root(work_dir).unwrap_or_else(|| work_dir.to_path_buf());
+    let git = GitManager::new(&repo_root)?;
+
+    let jobs = known_jobs(work_dir, config_override)?;
+    let pruned = git.prune_worktrees(&jobs, dry_run)?;
+    if pruned.is_empty() {
+        println!("No orphaned worktrees found.");
+        return Ok(());
+    }
+
+    for worktree in &pruned {
+        if dry_run {
+            println!("Would remove {}", worktree.path.display());
+        } else {
+            println!("Removed {}", worktree.path.display());
+        }
+    }
+
+    if dry_run {
+        println!("{} worktree(s) would be removed (dry run)", pruned.len());
+    } else {
+        println!("{} worktree(s) removed", pruned.len());
+    }
+    Ok(())
+}
+
+/// Every job that may own a worktree: the persisted store and archive, overlaid
+/// with the live list from a running KYCo (whose store may lag behind).
+fn known_jobs(work_dir: &Path, config_override: Option<&PathBuf>) -> Result<Vec<Job>> {
+    let mut jobs = JobManager::read_snapshot(work_dir).context("Failed to read job store")?;
+
+    let (port, token) = load_gui_http_settings(work_dir, config_override);
+    let url = format!("http://127.0.0.1:{port}/ctl/jobs");
+    if let Ok(value) = http_get_json(&url, token.as_deref()) {
+        let live: Vec<Job> =
+            serde_json::from_value(value["jobs"].clone()).context("Invalid /ctl/jobs response")?;
+        jobs.retain(|job| live.iter().all(|l| l.id != job.id));
+        jobs.extend(live);
+    }
+    Ok(jobs)
+}
@@ -1,17 +1,40 @@
 //! CLI command definitions for kyco.
 
 use clap::Subcommand;
+use std::path::PathBuf;
 
 #[derive(Subcommand)]
 pub enum Commands {
     /// Run the GUI (receives selections from IDE extensions via HTTP)
     Gui,
 
+    /// Run the control API and job executor headless (no window)
+    Serve {
+        /// HTTP port (default: settings.gui.http_port)
+        #[arg(long)]
+        port: Option<u16>,
+    },
+
     /// Show the status of all jobs
     Status {
         /// Show only jobs with this status
         #[arg(long)]
         filter: Option<String>,
+        /// Show only jobs created or updated since then (e.g. 30m, 2h, 1d, or a timestamp)
+        #[arg(long)]
+        since: Option<String>,
+        /// Show only jobs carrying this tag
+        #[arg(long)]
+        tag: Option<String>,
+        /// Print one compact JSON object per job (NDJSON)
+        #[arg(long)]
+        json_lines: bool,
+        /// Keep redrawing the list until all jobs are finished (Ctrl+C to stop)
+        #[arg(long, conflicts_with = "json_lines")]
+        watch: bool,
+        /// Seconds between redraws with --watch
+        #[arg(long, default_value_t = 2, value_name = "SECS", requires = "watch")]
+        interval: u64,
     },
 
     /// Initialize a new .kyco/config.toml configuration file
@@ -19,6 +42,12 @@ pub enum Commands {
         /// Overwrite existing config file
         #[arg(long)]
         force: bool,
+        /// Seed the config from a starter template (minimal, security, refactor)
+        #[arg(long, value_name = "NAME")]
+        template: Option<String>,
+        /// List the available starter templates and exit
+        #[arg(long, conflicts_with_all = ["force", "template"])]
+        list_templates: bool,
     },
 
     /// Control jobs in a running KYCo GUI (local /ctl API)
@@ -27,6 +56,12 @@ pub enum Commands {
         command: JobCommands,
     },
 
+    /// Pause or resume starting queued jobs in a running KYCo GUI
+    Queue {
+        #[command(subcommand)]
+        command: QueueCommands,
+    },
+
     /// Manage modes in `.kyco/config.toml` (DEPRECATED: use skill instead)
     Mode {
         #[command(subcommand)]
@@ -51,6 +86,12 @@ pub enum Commands {
         command: ChainCommands,
     },
 
+    /// Inspect the merged configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
     /// Manage security findings (BugBounty Kanban)
     Finding {
         #[command(subcommand)]
@@ -81,11 +122,49 @@ pub enum Commands {
         command: MemoryCommands,
     },
 
+    /// Check evidence artifacts of findings and jobs
+    Artifact {
+        #[command(subcommand)]
+        command: ArtifactCommands,
+    },
+
     /// Manage agent sessions (Claude/Codex)
     Session {
         #[command(subcommand)]
         command: SessionCommands,
     },
+
+    /// Maintain job worktrees under `.kyco/worktrees`
+    Worktree {
+        #[command(subcommand)]
+        command: WorktreeCommands,
+    },
+
+    /// Export job statistics (the GUI stats dashboard)
+    Stats {
+        #[command(subcommand)]
+        command: StatsCommands,
+    },
+
+    /// Check the setup: git, config, agents, .kyco writability, ctl port
+    Doctor {
+        /// Also send each usable agent the read-only `kyco agent test` ping
+        #[arg(long)]
+        test_agents: bool,
+        /// Print JSON output
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Print the kyco version
+    Version {
+        /// Check GitHub releases for a newer version (exit code 10 if outdated)
+        #[arg(long)]
+        check: bool,
+        /// Print JSON output
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -101,7 +180,7 @@ pub enum JobCommands {
         /// Filter by linked finding ID (requires BugBounty DB)
         #[arg(long)]
         finding: Option<String>,
-        /// Filter by status (pending, queued, running, completed, failed, aborted)
+        /// Filter by status (pending, queued, blocked, running, done, failed, rejected, merged)
         #[arg(long, short = 's')]
         status: Option<String>,
         /// Filter by agent result state (job.result.state)
@@ -110,6 +189,9 @@ pub enum JobCommands {
         /// Limit number of results
         #[arg(long, short = 'n')]
         limit: Option<usize>,
+        /// Skip this many jobs (newest first) before applying --limit
+        #[arg(long, default_value_t = 0)]
+        offset: usize,
         /// Search in job description/prompt
         #[arg(long, short = 'q')]
         search: Option<String>,
@@ -117,6 +199,12 @@ pub enum JobCommands {
         #[arg(long, visible_alias = "mode")]
         skill: Option<String>,
     },
+    /// Show continuation/retry lineage as an indented tree
+    Tree {
+        /// Only show the subtree below this job
+        #[arg(long)]
+        root: Option<u64>,
+    },
     /// Get a single job by ID
     Get {
         job_id: u64,
@@ -129,6 +217,9 @@ pub enum JobCommands {
         /// File path (relative to --path, or absolute). Optional if --prompt is provided.
         #[arg(long)]
         file: Option<String>,
+        /// Directory to operate on as a whole (the agent may read/modify any file within it)
+        #[arg(long, conflicts_with = "file")]
+        dir: Option<String>,
         /// One or more input files / glob patterns (repeatable, comma-separated)
         #[arg(long, value_delimiter = ',')]
         input: Vec<String>,
@@ -159,6 +250,9 @@ pub enum JobCommands {
         /// Optional list of agents for parallel execution (comma-separated)
         #[arg(long, value_delimiter = ',')]
         agents: Vec<String>,
+        /// Read more agents from a file (one id per line, `#` comments allowed)
+        #[arg(long, value_name = "PATH")]
+        agents_file: Option<String>,
         /// Create as pending only (do not queue immediately)
         #[arg(long)]
         pending: bool,
@@ -180,11 +274,31 @@ pub enum JobCommands {
         /// Permission mode: default, acceptEdits, bypassPermissions, plan
         #[arg(long, value_name = "MODE")]
         permission_mode: Option<String>,
+        /// Extra environment variable for this job only (repeatable; overrides agent env)
+        #[arg(long = "env", value_name = "KEY=VALUE")]
+        env: Vec<String>,
+        /// Start even if the daily budget (settings.budget) is exhausted
+        #[arg(long)]
+        ignore_budget: bool,
+        /// With --agents: pick the winner automatically, preferring these result states in order
+        #[arg(long, value_delimiter = ',', value_name = "STATES")]
+        auto_select: Vec<String>,
+        /// Include N lines before/after --line-start..--line-end in the prompt
+        #[arg(long, default_value_t = 0, value_name = "N")]
+        context_lines: usize,
+        /// Label the job (repeatable, comma-separated)
+        #[arg(long = "tag", value_delimiter = ',', value_name = "TAG")]
+        tags: Vec<String>,
+        /// Create the job's worktree from this branch or commit instead of HEAD
+        #[arg(long, value_name = "REF")]
+        base: Option<String>,
     },
     /// Queue a job (set status=queued)
     Queue { job_id: u64 },
     /// Abort/stop a job (graceful, waits for agent)
     Abort { job_id: u64 },
+    /// Abort every running, queued or blocked job
+    AbortAll,
     /// Kill a job immediately (forceful, does not wait)
     Kill { job_id: u64 },
     /// Delete a job from the GUI list
@@ -194,6 +308,32 @@ pub enum JobCommands {
         #[arg(long)]
         cleanup_worktree: bool,
     },
+    /// Move a finished job to the archive (.kyco/jobs_archive.json)
+    Archive { job_id: u64 },
+    /// Print the prompt, agent and tool lists a job would use, without running it
+    Explain {
+        /// File or directory path (relative to --path, or absolute)
+        #[arg(long)]
+        file: String,
+        /// Skill, mode or chain name (chains explain their first step)
+        #[arg(long, visible_alias = "mode")]
+        skill: String,
+        /// Start line (1-indexed)
+        #[arg(long, default_value_t = 1)]
+        line_start: usize,
+        /// End line (1-indexed)
+        #[arg(long)]
+        line_end: Option<usize>,
+        /// Optional prompt/description text
+        #[arg(long)]
+        prompt: Option<String>,
+        /// Agent id (defaults to the skill's configured agent)
+        #[arg(long)]
+        agent: Option<String>,
+        /// Print JSON instead of human output
+        #[arg(long)]
+        json: bool,
+    },
     /// Continue a session job with a follow-up prompt (creates a new job)
     Continue {
         job_id: u64,
@@ -213,9 +353,36 @@ pub enum JobCommands {
         #[arg(long, short = 'P')]
         plan: bool,
     },
+    /// Run several follow-up prompts in sequence on a session job
+    ContinueBatch {
+        job_id: u64,
+        /// Follow-up prompts, comma-separated, run in order
+        #[arg(long, value_delimiter = ',', required = true)]
+        prompts: Vec<String>,
+        /// Skip the remaining prompts if a step fails
+        #[arg(long)]
+        stop_on_failure: bool,
+        /// Print JSON response
+        #[arg(long)]
+        json: bool,
+        /// Fork the session instead of continuing it (creates a branch)
+        #[arg(long)]
+        fork: bool,
+        /// Enable plan mode for every continuation (Claude only)
+        #[arg(long, short = 'P')]
+        plan: bool,
+    },
     /// Wait until a job reaches a terminal state
     Wait {
-        job_id: u64,
+        /// Job IDs to wait for
+        #[arg(required = true, num_args = 1..)]
+        job_ids: Vec<u64>,
+        /// Return once every job has finished (default)
+        #[arg(long, conflicts_with = "any")]
+        all: bool,
+        /// Return as soon as the first job finishes, printing which one
+        #[arg(long)]
+        any: bool,
         /// Timeout in seconds
         #[arg(long)]
         timeout_secs: Option<u64>,
@@ -226,6 +393,19 @@ pub enum JobCommands {
         #[arg(long)]
         json: bool,
     },
+    /// Print a job's log events (tool calls, errors, system messages)
+    Logs {
+        job_id: u64,
+        /// Keep polling and print new events until the job finishes
+        #[arg(long, short = 'f')]
+        follow: bool,
+        /// Poll interval in milliseconds (with --follow)
+        #[arg(long, default_value_t = 500)]
+        poll_ms: u64,
+        /// Print events as JSON lines
+        #[arg(long)]
+        json: bool,
+    },
     /// Print a job's output / result
     Output {
         job_id: u64,
@@ -250,6 +430,9 @@ pub enum JobCommands {
         /// Print parsed `result.state`
         #[arg(long)]
         state: bool,
+        /// Print token/cost usage breakdown
+        #[arg(long)]
+        usage: bool,
     },
     /// Merge a job's changes into the base branch
     Merge {
@@ -257,11 +440,23 @@ pub enum JobCommands {
         /// Custom commit message (optional)
         #[arg(long, short = 'm')]
         message: Option<String>,
+        /// Merge even if the base branch moved since the worktree was created
+        #[arg(long)]
+        force: bool,
     },
     /// Reject a job's changes and cleanup its worktree
     Reject { job_id: u64 },
     /// Restart a failed or rejected job with the same parameters
     Restart { job_id: u64 },
+    /// Re-run a failed job as a new job linked to the original
+    Retry {
+        job_id: u64,
+        /// Reuse the original job's worktree if it still exists
+        #[arg(long)]
+        same_worktree: bool,
+    },
+    /// Resume a job whose run died (e.g. KYCo crashed) by reattaching to its agent session
+    Resume { job_id: u64 },
     /// Show the diff of a job's changes
     Diff {
         job_id: u64,
@@ -292,9 +487,15 @@ pub enum ModeCommands {
         /// Prompt template
         #[arg(long)]
         prompt: Option<String>,
+        /// Read the prompt template from a file
+        #[arg(long, conflicts_with = "prompt")]
+        prompt_file: Option<PathBuf>,
         /// System prompt
         #[arg(long)]
         system_prompt: Option<String>,
+        /// Read the system prompt from a file
+        #[arg(long, conflicts_with = "system_prompt")]
+        system_prompt_file: Option<PathBuf>,
         /// Default agent id
         #[arg(long)]
         agent: Option<String>,
@@ -307,6 +508,12 @@ pub enum ModeCommands {
         /// Max turns (0 = unlimited)
         #[arg(long)]
         max_turns: Option<u32>,
+        /// Max output tokens per response (must be positive)
+        #[arg(long)]
+        max_output_tokens: Option<u32>,
+        /// Abort agent runs after this many seconds (0 = unlimited)
+        #[arg(long)]
+        timeout_secs: Option<u64>,
         /// Model override
         #[arg(long)]
         model: Option<String>,
@@ -328,6 +535,31 @@ pub enum ModeCommands {
     },
     /// Delete a mode
     Delete { name: String },
+    /// Rename a mode, updating chain steps and aliases that reference it
+    Rename {
+        old_name: String,
+        new_name: String,
+        /// Overwrite an existing mode with the new name
+        #[arg(long)]
+        force: bool,
+    },
+    /// Copy a mode under a new name as a starting point for a variant
+    Copy {
+        src: String,
+        dst: String,
+        /// Overwrite an existing mode named `dst`
+        #[arg(long)]
+        force: bool,
+        /// Default agent id for the copy
+        #[arg(long)]
+        agent: Option<String>,
+        /// Model override for the copy
+        #[arg(long)]
+        model: Option<String>,
+        /// Print JSON for the saved mode
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -444,6 +676,16 @@ pub enum AgentCommands {
         #[arg(long)]
         json: bool,
     },
+    /// Send a trivial prompt to verify the agent's SDK setup works
+    Test {
+        name: String,
+        /// Give up after this many seconds
+        #[arg(long, default_value_t = 60)]
+        timeout_secs: u64,
+        /// Print JSON output
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -497,6 +739,32 @@ pub enum ChainCommands {
     },
     /// Delete a chain
     Delete { name: String },
+    /// Print a graph of the chain steps, trigger conditions and loop edges
+    Diagram {
+        name: String,
+        /// Output format (mermaid, dot)
+        #[arg(long, short = 'f', default_value = "mermaid")]
+        format: String,
+    },
+    /// Run a chain headlessly against a file and print the result as JSON
+    Run {
+        name: String,
+        /// Target file (relative to the repository root or absolute)
+        #[arg(long)]
+        file: String,
+        /// First line of the target range (1-based)
+        #[arg(long, default_value_t = 1)]
+        line_start: usize,
+        /// Last line of the target range
+        #[arg(long)]
+        line_end: Option<usize>,
+        /// Task description passed to the first step
+        #[arg(long)]
+        prompt: Option<String>,
+        /// Also stream agent log events to stderr
+        #[arg(long)]
+        logs: bool,
+    },
 }
 
 // ============================================
@@ -523,6 +791,17 @@ pub enum FindingCommands {
         #[arg(long)]
         json: bool,
     },
+    /// Full-text search across finding titles, attack scenarios, impact and notes
+    Search {
+        /// Text to search for (case-insensitive)
+        query: String,
+        /// Limit to a project ID
+        #[arg(long)]
+        project: Option<String>,
+        /// Print JSON output
+        #[arg(long)]
+        json: bool,
+    },
     /// Show a finding by ID
     Show {
         /// Finding ID (e.g., VULN-001)
@@ -557,6 +836,9 @@ pub enum FindingCommands {
         /// CWE ID (e.g., CWE-639)
         #[arg(long)]
         cwe: Option<String>,
+        /// CVSS v3.1 vector (e.g., CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H); derives the score
+        #[arg(long)]
+        cvss: Option<String>,
         /// Affected assets (comma-separated)
         #[arg(long, value_delimiter = ',')]
         assets: Vec<String>,
@@ -567,12 +849,67 @@ pub enum FindingCommands {
         #[arg(long)]
         json: bool,
     },
+    /// Copy a finding as a new Raw finding (track variants of a bug class)
+    Clone {
+        /// Finding ID to copy
+        id: String,
+        /// Title for the new finding (default: "<title> (variant)")
+        #[arg(long, short = 't')]
+        title: Option<String>,
+        /// Print JSON output
+        #[arg(long)]
+        json: bool,
+    },
     /// Update finding status (move in Kanban)
     SetStatus {
+        /// Finding ID (omit when moving several findings with --to)
+        #[arg(required_unless_present = "to")]
+        id: Option<String>,
+        /// New status (raw, needs_repro, verified, report_draft, submitted, triaged, accepted, paid, duplicate, wont_fix, false_positive, out_of_scope)
+        #[arg(required_unless_present = "to")]
+        status: Option<String>,
+        /// Canonical finding ID when marking as duplicate (prompted for if omitted)
+        #[arg(long)]
+        of: Option<String>,
+        /// Move several findings (--ids and/or --from-status) to this status
+        #[arg(long, conflicts_with_all = ["id", "status", "of"])]
+        to: Option<String>,
+        /// Findings to move with --to (comma-separated)
+        #[arg(long, value_delimiter = ',', requires = "to")]
+        ids: Vec<String>,
+        /// Move every finding of --project currently in this status
+        #[arg(long, requires_all = ["to", "project"])]
+        from_status: Option<String>,
+        /// Project ID for --from-status
+        #[arg(long, requires = "from_status")]
+        project: Option<String>,
+    },
+    /// Merge a duplicate into its canonical finding (moves linked jobs and artifacts)
+    Merge {
+        /// Duplicate finding ID
+        id: String,
+        /// Canonical finding ID
+        #[arg(long)]
+        into: String,
+    },
+    /// Pin a finding within its Kanban column (lower sorts first)
+    Priority {
         /// Finding ID
         id: String,
-        /// New status (raw, needs_repro, verified, report_draft, submitted, triaged, accepted, paid, duplicate, wont_fix, false_positive, out_of_scope)
-        status: String,
+        /// Priority value; omit to clear the pin
+        #[arg(allow_negative_numbers = true)]
+        priority: Option<i32>,
+    },
+    /// Recompute the flow-trace based priority (entry points from settings.bugbounty)
+    Reprioritize {
+        /// Finding IDs
+        ids: Vec<String>,
+        /// Recompute every finding of this project
+        #[arg(long)]
+        project: Option<String>,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
     },
     /// Link an existing job to a finding
     Link {
@@ -582,8 +919,8 @@ pub enum FindingCommands {
         /// Job identifier (KYCo job id like "123" or BugBounty job id UUID)
         #[arg(long)]
         job: String,
-        /// Link type (e.g. discovered, related, verification)
-        #[arg(long, default_value = "related")]
+        /// Link type (e.g. discovered, related, verified)
+        #[arg(long, visible_alias = "relation", default_value = "related")]
         link_type: String,
     },
     /// Remove a job link from a finding
@@ -638,12 +975,12 @@ pub enum FindingCommands {
     },
     /// Import findings from SARIF or Semgrep output
     Import {
-        /// Path to the file to import (SARIF/Semgrep/Snyk/Nuclei JSON)
+        /// Path to the file to import (SARIF/Semgrep/Snyk/Nuclei JSON or a Markdown report)
         file: String,
         /// Target project ID
         #[arg(long)]
         project: String,
-        /// Input format (sarif, semgrep, snyk, nuclei, auto)
+        /// Input format (sarif, semgrep, snyk, nuclei, markdown, auto)
         #[arg(long, short = 'f', default_value = "auto")]
         format: String,
         /// Print JSON output
@@ -662,6 +999,14 @@ pub enum FindingCommands {
         #[arg(long)]
         json: bool,
     },
+    /// Check raw agent output for a valid next_context and explain any problems
+    ValidateOutput {
+        /// File with the agent's raw output ("-" for stdin)
+        file: String,
+        /// Print JSON output
+        #[arg(long)]
+        json: bool,
+    },
     /// Extract findings from a completed job's output
     ExtractFromJob {
         /// Job ID to extract findings from
@@ -807,7 +1152,33 @@ pub enum ImportCommands {
         #[arg(long)]
         json: bool,
     },
-    /// Auto-detect and import output (SARIF/Semgrep/Snyk/Nuclei)
+    /// Import a hand-written Markdown report (`## Title`, `Severity:`, `Affected:` lists)
+    Markdown {
+        /// Path to the Markdown report
+        file: String,
+        /// Project ID (uses active project if not specified)
+        #[arg(long)]
+        project: Option<String>,
+        /// Create one verify job per imported finding
+        #[arg(long)]
+        create_jobs: bool,
+        /// Queue created jobs immediately (default: create as pending only)
+        #[arg(long)]
+        queue: bool,
+        /// Skill/chain to run for verify jobs (only used with --create-jobs)
+        #[arg(long, default_value = "flow-trace")]
+        skill: String,
+        /// Primary agent id (e.g. "claude")
+        #[arg(long)]
+        agent: Option<String>,
+        /// Optional list of agents for parallel execution (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        agents: Vec<String>,
+        /// Print JSON output
+        #[arg(long)]
+        json: bool,
+    },
+    /// Auto-detect and import output (SARIF/Semgrep/Snyk/Nuclei/Markdown)
     Auto {
         /// Path to the file to import
         file: String,
@@ -859,6 +1230,10 @@ pub enum ProjectCommands {
         /// Path to scan (defaults to current directory)
         #[arg(long)]
         path: Option<String>,
+        /// Glob for program directories, relative to the path (repeatable;
+        /// default: BugBounty/programs/* and programs/*)
+        #[arg(long = "layout", value_name = "GLOB")]
+        layouts: Vec<String>,
         /// Dry run - show what would be discovered without creating
         #[arg(long)]
         dry_run: bool,
@@ -903,6 +1278,47 @@ pub enum ProjectCommands {
         #[arg(long)]
         json: bool,
     },
+    /// Preview the BugBounty context an agent job would receive
+    Context {
+        /// Project ID
+        id: String,
+        /// Findings linked to the job (repeatable, comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        finding: Vec<String>,
+        /// Target file, relative to the project root (narrows known findings and memory)
+        #[arg(long)]
+        file: Option<String>,
+        /// Print the context sections as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Export all findings of a project as one report
+    Report {
+        /// Project ID
+        id: String,
+        /// Output format (md, html)
+        #[arg(long, short = 'f', default_value = "md")]
+        format: String,
+        /// Output file path (stdout if not specified)
+        #[arg(long, short = 'o')]
+        out: Option<String>,
+    },
+    /// Export a project with all findings, artifacts, flows, memory and jobs as JSON
+    Export {
+        /// Project ID
+        id: String,
+        /// Output file path (stdout if not specified)
+        #[arg(long, short = 'o')]
+        out: Option<String>,
+    },
+    /// Import a project bundle written by `project export`
+    Import {
+        /// Bundle file path
+        file: String,
+        /// Import under this project ID instead of the one in the bundle
+        #[arg(long = "as", value_name = "ID")]
+        project_id: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -933,6 +1349,19 @@ pub enum ScopeCommands {
         #[arg(long)]
         json: bool,
     },
+    /// Compare two scope files and flag findings on newly excluded assets
+    Diff {
+        /// Previous scope file (e.g., scope.md from an older snapshot)
+        old: String,
+        /// Current scope file
+        new: String,
+        /// Project ID whose findings should be checked (uses active project if not specified)
+        #[arg(long)]
+        project: Option<String>,
+        /// Print JSON output
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -952,6 +1381,24 @@ pub enum MemoryCommands {
         #[arg(long)]
         json: bool,
     },
+    /// Add a memory entry by hand (source, sink, note, context)
+    Add {
+        /// Project ID
+        #[arg(long)]
+        project: String,
+        /// Entry type (source, sink, note, context)
+        #[arg(long, short = 't', default_value = "note")]
+        r#type: String,
+        /// The memory text
+        #[arg(long)]
+        text: String,
+        /// File the entry refers to
+        #[arg(long)]
+        file: Option<String>,
+        /// Line in --file
+        #[arg(long, requires = "file")]
+        line: Option<u32>,
+    },
     /// Import memory from external tools
     Import {
         /// Tool to import from (semgrep, codeql)
@@ -963,6 +1410,7 @@ pub enum MemoryCommands {
         project: String,
     },
     /// Delete a memory entry by ID
+    #[command(visible_alias = "rm")]
     Delete {
         /// Memory entry ID
         id: i64,
@@ -1007,3 +1455,75 @@ pub enum SessionCommands {
         json: bool,
     },
 }
+
+#[derive(Subcommand)]
+pub enum QueueCommands {
+    /// Stop starting queued jobs (running jobs continue)
+    Pause,
+    /// Start queued jobs again
+    Resume,
+}
+
+#[derive(Subcommand)]
+pub enum ArtifactCommands {
+    /// Check that artifact files still exist, recording size and hash
+    /// (exit code 1 if any artifact is dangling)
+    Verify {
+        /// Project ID (uses active project if not specified)
+        #[arg(long)]
+        project: Option<String>,
+        /// Print JSON output
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum WorktreeCommands {
+    /// Remove worktrees whose job was deleted, merged, rejected or failed
+    Prune {
+        /// Only list what would be removed
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum StatsCommands {
+    /// Print the dashboard (per-mode rows, per-agent totals, tokens and cost)
+    Export {
+        /// Time range: 15m, 30m, 1h, 3h, 8h, 1d, 3d, 7d, 30d, 90d, all (or e.g. last7days)
+        #[arg(long, default_value = "30d")]
+        range: String,
+        /// Only count jobs run by this agent
+        #[arg(long)]
+        agent: Option<String>,
+        /// Only count jobs of this mode or chain
+        #[arg(long, visible_alias = "skill")]
+        mode: Option<String>,
+        /// Only count jobs from this workspace path
+        #[arg(long)]
+        workspace: Option<String>,
+        /// Output format (csv, json)
+        #[arg(long, short = 'f', default_value = "csv")]
+        format: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommands {
+    /// Check modes, skills, chains, agents, and aliases for consistency
+    /// (exit code 1 if any errors are found)
+    Validate {
+        /// Print JSON output
+        #[arg(long)]
+        json: bool,
+    },
+    /// Make the running GUI reload its config without restarting
+    /// (jobs that are already running keep their agent settings)
+    Reload {
+        /// Print JSON output
+        #[arg(long)]
+        json: bool,
+    },
+}
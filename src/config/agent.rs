@@ -78,4 +78,28 @@ pub struct AgentConfigToml {
     /// Default is false for safety.
     #[serde(default)]
     pub allow_dangerous_bypass: bool,
+
+    /// Audit-only agent: never allowed to write, regardless of mode/skill.
+    ///
+    /// When true, Write/Edit are always disallowed and Codex runs with
+    /// `sandbox = "read-only"`. This is a hard override applied after mode/skill
+    /// tool settings, so a permissive mode cannot re-enable writes.
+    #[serde(default)]
+    pub read_only: bool,
+
+    /// Maximum concurrent jobs for this agent.
+    ///
+    /// Tightens `settings.max_concurrent_jobs` (the per-agent default) for agents
+    /// with stricter rate limits; it can never raise the limit above it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent: Option<usize>,
+
+    /// Shell command for `sdk = "custom"` agents, run in the job's worktree.
+    ///
+    /// Placeholders `{prompt_file}`, `{file}` and `{cwd}` are replaced with
+    /// shell-quoted values; the prompt is also sent on stdin, and stdout becomes
+    /// the job output. There is no `{prompt}` placeholder (`kyco config validate`
+    /// reports it): prompts can be too large for a command line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command_template: Option<String>,
 }
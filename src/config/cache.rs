@@ -0,0 +1,124 @@
+//! Process-level read-through cache for config loading
+//!
+//! Parsing the TOML and merging internal defaults is repeated by many CLI
+//! subcommands and GUI views. The cache keeps the last loaded `Config` per path
+//! and reuses it while the file's modification time and size are unchanged.
+//! `save_to_file` writes via temp file + rename, which always produces a new
+//! fingerprint, so saves invalidate the cache.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+
+use super::Config;
+
+/// Identifies one on-disk version of a config file (modification time and size).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FileFingerprint {
+    modified: SystemTime,
+    len: u64,
+}
+
+impl FileFingerprint {
+    /// Stat `path`; `None` if it does not exist or has no modification time.
+    pub(crate) fn of(path: &Path) -> Option<Self> {
+        let metadata = std::fs::metadata(path).ok()?;
+        Some(Self {
+            modified: metadata.modified().ok()?,
+            len: metadata.len(),
+        })
+    }
+}
+
+static CONFIG_CACHE: Lazy<Mutex<HashMap<PathBuf, (FileFingerprint, Config)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn cached(path: &Path, fingerprint: FileFingerprint) -> Option<Config> {
+    let cache = CONFIG_CACHE.lock().ok()?;
+    cache
+        .get(path)
+        .filter(|(cached_fp, _)| *cached_fp == fingerprint)
+        .map(|(_, config)| config.clone())
+}
+
+fn store(path: &Path, config: &Config) {
+    // Re-stat after loading: `Config::load` may have rewritten the file
+    let Some(fingerprint) = FileFingerprint::of(path) else {
+        return;
+    };
+    if let Ok(mut cache) = CONFIG_CACHE.lock() {
+        cache.insert(path.to_path_buf(), (fingerprint, config.clone()));
+    }
+}
+
+impl Config {
+    /// Like [`Config::load`], but reuses the last result while the global config
+    /// file is unchanged on disk.
+    pub fn load_cached() -> Result<Self> {
+        let path = Self::global_config_path();
+        if let Some(config) = FileFingerprint::of(&path).and_then(|fp| cached(&path, fp)) {
+            return Ok(config);
+        }
+
+        let config = Self::load()?;
+        store(&path, &config);
+        Ok(config)
+    }
+
+    /// Like [`Config::from_file`], but reuses the last result while `path` is
+    /// unchanged on disk.
+    pub fn from_file_cached(path: &Path) -> Result<Self> {
+        if let Some(config) = FileFingerprint::of(path).and_then(|fp| cached(path, fp)) {
+            return Ok(config);
+        }
+
+        let config = Self::from_file(path)?;
+        store(path, &config);
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saving_the_file_busts_the_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+
+        let mut config = Config::with_defaults();
+        config.settings.max_concurrent_jobs = 2;
+        config.save_to_file(&path).unwrap();
+
+        let first = Config::from_file_cached(&path).unwrap();
+        assert_eq!(first.settings.max_concurrent_jobs, 2);
+        assert!(cached(&path, FileFingerprint::of(&path).unwrap()).is_some());
+
+        // Atomic save (temp file + rename) with a different value of the same length
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        config.settings.max_concurrent_jobs = 7;
+        config.save_to_file(&path).unwrap();
+        assert_eq!(
+            Config::from_file_cached(&path).unwrap().settings.max_concurrent_jobs,
+            7
+        );
+
+        // In-place edit of the same size is caught via the modification time
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        std::fs::write(
+            &path,
+            content.replace("max_concurrent_jobs = 7", "max_concurrent_jobs = 9"),
+        )
+        .unwrap();
+        assert_eq!(
+            Config::from_file_cached(&path).unwrap().settings.max_concurrent_jobs,
+            9
+        );
+    }
+}
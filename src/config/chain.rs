@@ -1,5 +1,7 @@
 //! Skill chain configuration types
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use super::ModeConfig;
@@ -28,6 +30,20 @@ fn default_case_insensitive() -> bool {
     true
 }
 
+/// What to do after a step when one of its `on_state` states is detected
+///
+/// In TOML: `on_state = { needs_tests = { goto = "write-tests" }, done = "stop" }`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepAction {
+    /// Continue at the step running this skill
+    Goto(String),
+    /// End the chain here (successfully)
+    Stop,
+    /// Skip the next step
+    Skip,
+}
+
 /// A step in a skill chain
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChainStep {
@@ -53,6 +69,10 @@ pub struct ChainStep {
     /// The chain will restart from that step. Use with max_loops to prevent infinite loops.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub loop_to: Option<String>,
+    /// Branch on states detected in this step's own output once it completes
+    /// Goto jumps count towards the chain's step cap (see `max_loops`).
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub on_state: HashMap<String, StepAction>,
 }
 
 /// A chain of modes to execute sequentially
@@ -77,7 +97,8 @@ pub struct ModeChain {
     #[serde(default = "default_pass_full_response")]
     pub pass_full_response: bool,
     /// Maximum number of loop iterations (default: 1)
-    /// Prevents infinite loops when using loop_to in steps
+    /// Prevents infinite loops when using loop_to in steps. `on_state` gotos are
+    /// capped at `steps.len() * (max_loops + 1)` executed steps per chain run.
     #[serde(default = "default_max_loops")]
     pub max_loops: u32,
 
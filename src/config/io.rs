@@ -148,6 +148,18 @@ impl Config {
         Ok(config)
     }
 
+    /// Load the global configuration like [`Config::load`], but never write to disk.
+    ///
+    /// A missing file yields the built-in defaults, and merged internal defaults
+    /// are not saved back. For commands that must stay read-only.
+    pub fn load_read_only() -> Result<Self> {
+        let global_path = Self::global_config_path();
+        if !global_path.exists() {
+            return Ok(Self::with_defaults());
+        }
+        Self::from_file(&global_path)
+    }
+
     /// Load configuration from a directory (legacy compatibility)
     /// Now just loads the global config, ignoring the directory parameter
     pub fn from_dir(_dir: &Path) -> Result<Self> {
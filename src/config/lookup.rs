@@ -9,7 +9,7 @@ use super::scope::ScopeConfig;
 use super::skill::SkillConfig;
 use super::target::TargetConfig;
 use super::Config;
-use crate::{AgentConfig, SdkType};
+use crate::{AgentConfig, Job, ResultParser, RetryPolicy, SdkType};
 
 /// Either a skill or a chain (borrowed)
 #[derive(Debug, Clone, Copy)]
@@ -105,6 +105,8 @@ impl Config {
                 sandbox: toml.sandbox.clone(),
                 ask_for_approval: toml.ask_for_approval.clone(),
                 max_turns: 0,
+                max_output_tokens: None,
+                timeout_secs: None,
                 system_prompt_mode: toml.system_prompt_mode,
                 skill_templates,
                 env: toml.env.clone(),
@@ -120,6 +122,12 @@ impl Config {
                 output_schema,
                 structured_output_schema,
                 allow_dangerous_bypass: toml.allow_dangerous_bypass,
+                read_only: toml.read_only,
+                command_template: toml.command_template.clone(),
+                retry: RetryPolicy {
+                    max_retries: self.settings.max_retries,
+                    base_backoff_ms: self.settings.base_backoff_ms,
+                },
             }
         })
     }
@@ -145,6 +153,9 @@ impl Config {
     /// This merges the base agent config with mode/skill-specific allowed/disallowed tools.
     /// Mode/skill tools take precedence over agent tools when specified.
     /// Checks modes first, then falls back to skills.
+    ///
+    /// Precedence (lowest to highest): agent tools < mode/skill tools < agent `read_only`.
+    /// A read-only agent never gets Write/Edit back, whatever the mode allows.
     pub fn get_agent_for_job(&self, agent_id: &str, mode: &str) -> Option<AgentConfig> {
         let mut agent_config = self.get_agent(agent_id)?;
 
@@ -186,6 +197,8 @@ impl Config {
             }
 
             agent_config.max_turns = mode_config.max_turns;
+            agent_config.max_output_tokens = mode_config.max_output_tokens.filter(|n| *n > 0);
+            agent_config.timeout_secs = mode_config.timeout_secs.filter(|n| *n > 0);
             if mode_config.model.is_some() {
                 agent_config.model = mode_config.model.clone();
             }
@@ -231,6 +244,8 @@ impl Config {
             }
 
             agent_config.max_turns = skill_config.kyco.max_turns;
+            agent_config.max_output_tokens = skill_config.kyco.max_output_tokens.filter(|n| *n > 0);
+            agent_config.timeout_secs = skill_config.kyco.timeout_secs.filter(|n| *n > 0);
             if skill_config.kyco.model.is_some() {
                 agent_config.model = skill_config.kyco.model.clone();
             }
@@ -260,6 +275,7 @@ impl Config {
             }
         }
 
+        enforce_read_only(&mut agent_config);
         Some(agent_config)
     }
 
@@ -268,6 +284,38 @@ impl Config {
         self.mode.get(mode)
     }
 
+    /// Get the result parser configured for a skill or mode.
+    ///
+    /// Invalid parser settings fall back to the default parser with a warning.
+    pub fn result_parser_for(&self, name: &str) -> ResultParser {
+        let (kind, pattern) = if let Some(skill) = self.skill.get(name) {
+            (
+                skill.kyco.result_parser.as_deref(),
+                skill.kyco.result_pattern.as_deref(),
+            )
+        } else if let Some(mode) = self.mode.get(name) {
+            (mode.result_parser.as_deref(), mode.result_pattern.as_deref())
+        } else {
+            (None, None)
+        };
+
+        ResultParser::from_config(kind, pattern).unwrap_or_else(|e| {
+            tracing::warn!("Invalid result parser for '{}': {}", name, e);
+            ResultParser::Default
+        })
+    }
+
+    /// Whether jobs of a skill or mode opted into gated auto-merge.
+    pub fn auto_merge_for(&self, name: &str) -> bool {
+        if let Some(skill) = self.skill.get(name) {
+            return skill.kyco.auto_merge.unwrap_or(false);
+        }
+        self.mode
+            .get(name)
+            .and_then(|mode| mode.auto_merge)
+            .unwrap_or(false)
+    }
+
     /// Get scope configuration
     pub fn get_scope(&self, scope: &str) -> Option<&ScopeConfig> {
         self.scope.get(scope)
@@ -303,9 +351,41 @@ impl Config {
         }
     }
 
+    /// `settings.global_prompt_suffix` for a mode or skill, or `None` when it is unset,
+    /// blank, or the mode opted out with `skip_global_suffix`.
+    pub fn global_prompt_suffix_for(&self, mode: &str) -> Option<&str> {
+        if self.mode.get(mode).is_some_and(|m| m.skip_global_suffix) {
+            return None;
+        }
+        self.settings
+            .global_prompt_suffix
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+    }
+
+    /// `job` as it is handed to the agent adapter: its description ends with the
+    /// global prompt suffix of the job's mode/skill.
+    ///
+    /// Adapters build their prompt from the description, so this is how the suffix
+    /// reaches Claude, Codex and custom agents. Chain steps get it from
+    /// [`crate::agent::build_chained_prompt`] instead.
+    pub fn job_with_prompt_suffix(&self, job: &Job) -> Job {
+        let mut job = job.clone();
+        if let Some(suffix) = self.global_prompt_suffix_for(&job.skill) {
+            let description = job.description.take().filter(|d| !d.trim().is_empty());
+            job.description = Some(match description {
+                Some(description) => append_prompt_suffix(description, Some(suffix)),
+                None => suffix.to_string(),
+            });
+        }
+        job
+    }
+
     /// Build prompt for a job using mode/skill, target, and scope configs
     ///
-    /// Checks modes first, then falls back to skills.
+    /// Checks modes first, then falls back to skills. The global prompt suffix is
+    /// appended after the rendered template.
     pub fn build_prompt(
         &self,
         mode: &str,
@@ -313,6 +393,21 @@ impl Config {
         scope: &str,
         file: &str,
         description: &str,
+    ) -> String {
+        append_prompt_suffix(
+            self.render_prompt(mode, target, scope, file, description),
+            self.global_prompt_suffix_for(mode),
+        )
+    }
+
+    /// Render the mode/skill template only, without the global prompt suffix
+    pub(crate) fn render_prompt(
+        &self,
+        mode: &str,
+        target: &str,
+        scope: &str,
+        file: &str,
+        description: &str,
     ) -> String {
         // Check modes first (legacy), then skills (new filesystem-based)
         let template = if let Some(mode_config) = self.mode.get(mode) {
@@ -418,6 +513,8 @@ impl Config {
             }
 
             agent_config.max_turns = skill_config.kyco.max_turns;
+            agent_config.max_output_tokens = skill_config.kyco.max_output_tokens.filter(|n| *n > 0);
+            agent_config.timeout_secs = skill_config.kyco.timeout_secs.filter(|n| *n > 0);
             if skill_config.kyco.model.is_some() {
                 agent_config.model = skill_config.kyco.model.clone();
             }
@@ -447,6 +544,7 @@ impl Config {
             }
         }
 
+        enforce_read_only(&mut agent_config);
         Some(agent_config)
     }
 
@@ -513,3 +611,135 @@ impl Config {
         self.skill.get(skill).and_then(|s| s.get_system_prompt())
     }
 }
+
+/// Append `suffix` to a prompt, separated by a blank line
+pub(crate) fn append_prompt_suffix(mut prompt: String, suffix: Option<&str>) -> String {
+    if let Some(suffix) = suffix {
+        let trimmed = prompt.trim_end().len();
+        prompt.truncate(trimmed);
+        prompt.push_str("\n\n");
+        prompt.push_str(suffix);
+    }
+    prompt
+}
+
+/// Hard override for `read_only` agents, applied after mode/skill tool settings.
+///
+/// Write/Edit are forced into `disallowed_tools` (and dropped from `allowed_tools`),
+/// Codex runs in the read-only sandbox, Claude loses auto-accepted edits, and the
+/// dangerous bypass flag is ignored since it would skip these restrictions.
+fn enforce_read_only(agent_config: &mut AgentConfig) {
+    if !agent_config.read_only {
+        return;
+    }
+
+    const WRITE_TOOLS: [&str; 2] = ["Write", "Edit"];
+    agent_config
+        .allowed_tools
+        .retain(|t| !WRITE_TOOLS.contains(&t.as_str()));
+    for tool in WRITE_TOOLS {
+        if !agent_config.disallowed_tools.iter().any(|t| t == tool) {
+            agent_config.disallowed_tools.push(tool.to_string());
+        }
+    }
+    agent_config.allow_dangerous_bypass = false;
+
+    match agent_config.sdk_type {
+        SdkType::Codex => agent_config.sandbox = Some("read-only".to_string()),
+        _ => {
+            if matches!(
+                agent_config.permission_mode.as_str(),
+                "acceptEdits" | "bypassPermissions"
+            ) {
+                agent_config.permission_mode = "default".to_string();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with(agent_toml: &str) -> Config {
+        let mut cfg = Config::with_defaults();
+        cfg.agent
+            .insert("auditor".to_string(), toml::from_str(agent_toml).unwrap());
+        cfg.mode.insert(
+            "yolo-fix".to_string(),
+            toml::from_str(
+                r#"
+                prompt = "Fix {target}"
+                allowed_tools = ["Read", "Write", "Edit", "Bash"]
+
+                [claude]
+                permission_mode = "bypassPermissions"
+
+                [codex]
+                sandbox = "danger-full-access"
+                "#,
+            )
+            .unwrap(),
+        );
+        cfg
+    }
+
+    #[test]
+    fn read_only_agent_blocks_writes_even_for_permissive_mode() {
+        let cfg = config_with("sdk = \"claude\"\nread_only = true\nallow_dangerous_bypass = true");
+        let agent = cfg.get_agent_for_job("auditor", "yolo-fix").unwrap();
+
+        assert_eq!(agent.allowed_tools, vec!["Read", "Bash"]);
+        assert!(agent.disallowed_tools.iter().any(|t| t == "Write"));
+        assert!(agent.disallowed_tools.iter().any(|t| t == "Edit"));
+        assert_eq!(agent.permission_mode, "default");
+        assert!(!agent.allow_dangerous_bypass);
+
+        let cfg = config_with("sdk = \"codex\"\nread_only = true");
+        let agent = cfg.get_agent_for_job("auditor", "yolo-fix").unwrap();
+        assert_eq!(agent.sandbox.as_deref(), Some("read-only"));
+    }
+
+    #[test]
+    fn writable_agent_keeps_mode_tools() {
+        let cfg = config_with("sdk = \"codex\"");
+        let agent = cfg.get_agent_for_job("auditor", "yolo-fix").unwrap();
+
+        assert!(agent.allowed_tools.iter().any(|t| t == "Write"));
+        assert_eq!(agent.sandbox.as_deref(), Some("danger-full-access"));
+    }
+
+    #[test]
+    fn global_prompt_suffix_is_appended_unless_the_mode_skips_it() {
+        let mut cfg = config_with("sdk = \"claude\"");
+        cfg.settings.global_prompt_suffix = Some("Never touch vendor/.\n".to_string());
+
+        let prompt = cfg.build_prompt("yolo-fix", "bugs", "file", "src/lib.rs", "");
+        assert_eq!(prompt, "Fix bugs\n\nNever touch vendor/.");
+        let mut job = Job::new(
+            1,
+            "yolo-fix".to_string(),
+            crate::ScopeDefinition::file("src/lib.rs".into()),
+            "src/lib.rs".to_string(),
+            Some("Keep it small.".to_string()),
+            "auditor".to_string(),
+            "src/lib.rs".into(),
+            1,
+            None,
+        );
+        assert_eq!(
+            cfg.job_with_prompt_suffix(&job).description.as_deref(),
+            Some("Keep it small.\n\nNever touch vendor/.")
+        );
+        job.description = None;
+        assert_eq!(
+            cfg.job_with_prompt_suffix(&job).description.as_deref(),
+            Some("Never touch vendor/.")
+        );
+
+        cfg.mode.get_mut("yolo-fix").unwrap().skip_global_suffix = true;
+        let prompt = cfg.build_prompt("yolo-fix", "bugs", "file", "src/lib.rs", "");
+        assert_eq!(prompt, "Fix bugs");
+        assert_eq!(cfg.job_with_prompt_suffix(&job).description, None);
+    }
+}
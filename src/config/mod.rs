@@ -2,6 +2,7 @@
 
 mod agent;
 mod alias;
+mod cache;
 mod chain;
 mod internal;
 mod io;
@@ -14,12 +15,15 @@ mod skill_discovery;
 mod skill_parser;
 mod skill_registry;
 mod target;
+mod templates;
 mod token;
+mod validate;
 
 pub use agent::AgentConfigToml;
 pub use alias::AliasConfig;
-pub use chain::{ChainStep, ModeChain, ModeOrChain, ModeOrChainRef, StateDefinition};
+pub use chain::{ChainStep, ModeChain, ModeOrChain, ModeOrChainRef, StateDefinition, StepAction};
 pub use internal::{InternalDefaults, INTERNAL_DEFAULTS_TOML};
+pub use templates::{STARTER_TEMPLATES, StarterTemplate, starter_template};
 pub use mode::{ClaudeModeOptions, CodexModeOptions, ModeConfig, ModeSessionType};
 pub use scope::ScopeConfig;
 pub use skill::{
@@ -33,12 +37,16 @@ pub use skill_discovery::{
 pub use skill_parser::{create_skill_template, parse_skill_content, parse_skill_file, SkillParseError};
 pub use skill_registry::{RegistrySkill, SkillRegistry};
 pub use lookup::SkillOrChainRef;
+pub(crate) use cache::FileFingerprint;
+pub(crate) use lookup::append_prompt_suffix;
 pub use settings::{
-    default_orchestrator_system_prompt, default_structured_output_schema, GuiSettings,
+    default_orchestrator_system_prompt, default_structured_output_schema, BudgetSettings,
+    BugBountySettings, DirtyRepoPolicy, GuiSettings, JobIdStrategy, NotifySettings,
     OrchestratorSettings, RegistrySettings, Settings, VoiceSettings,
 };
 pub use target::TargetConfig;
 pub use token::generate_http_token;
+pub use validate::{ConfigIssue, IssueSeverity};
 
 use std::collections::HashMap;
 
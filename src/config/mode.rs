@@ -107,6 +107,16 @@ pub struct ModeConfig {
     #[serde(default)]
     pub max_turns: u32,
 
+    /// Maximum output tokens per model response (passed to the SDK where supported)
+    /// Must be positive when set; useful to keep summary/analysis modes concise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<u32>,
+
+    /// Abort the agent run after this many seconds (None / 0 = unlimited).
+    /// Timed-out jobs fail with "timed out after Ns".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+
     /// Optional model override for this mode (e.g., "sonnet", "opus", "haiku")
     #[serde(default)]
     pub model: Option<String>,
@@ -139,6 +149,16 @@ pub struct ModeConfig {
     #[serde(default)]
     pub state_prompt: Option<String>,
 
+    /// Parser for the agent's result: "default", "json", "yaml", or "regex"
+    /// Use for agents/commands that don't emit KYCo's `---` YAML summary block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result_parser: Option<String>,
+
+    /// Regex with named groups (state, summary, details, title, status, ...)
+    /// used when `result_parser = "regex"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result_pattern: Option<String>,
+
     /// Legacy: allowed_tools (deprecated, use disallowed_tools instead)
     #[serde(default)]
     pub allowed_tools: Vec<String>,
@@ -149,6 +169,16 @@ pub struct ModeConfig {
     /// - Some(false): Never run in worktree (overrides global)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub use_worktree: Option<bool>,
+
+    /// Merge completed worktree jobs of this mode automatically when all gates pass
+    /// (job done, non-empty diff, no merge conflicts). Opt-in and independent of
+    /// `settings.auto_apply`; intended for trusted, narrowly-scoped modes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_merge: Option<bool>,
+
+    /// Do not append `settings.global_prompt_suffix` to this mode's prompt
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub skip_global_suffix: bool,
 }
 
 impl ModeConfig {
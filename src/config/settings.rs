@@ -1,11 +1,17 @@
 //! Settings configuration types
 
+mod budget;
+mod bugbounty;
 mod gui;
+mod notify;
 mod orchestrator;
 mod registry;
 mod voice;
 
+pub use budget::BudgetSettings;
+pub use bugbounty::BugBountySettings;
 pub use gui::{default_structured_output_schema, GuiSettings};
+pub use notify::NotifySettings;
 pub use orchestrator::{default_orchestrator_system_prompt, OrchestratorSettings};
 pub(crate) use orchestrator::is_legacy_orchestrator_system_prompt;
 pub use registry::RegistrySettings;
@@ -13,6 +19,8 @@ pub use voice::VoiceSettings;
 
 use serde::{Deserialize, Serialize};
 
+use crate::RetryPolicy;
+
 /// General settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Settings {
@@ -41,6 +49,39 @@ pub struct Settings {
     #[serde(default = "default_max_jobs_per_file")]
     pub max_jobs_per_file: usize,
 
+    /// What to do when the main working directory has uncommitted changes
+    /// before a worktree job starts: "warn" (default), "block", "stash", or "ignore".
+    /// Worktrees branch from HEAD, so local edits are not visible to the agent
+    /// and often cause confusing conflicts when the job is applied.
+    #[serde(default)]
+    pub dirty_repo_policy: DirtyRepoPolicy,
+
+    /// Automatically apply (merge) worktree jobs when they complete successfully
+    #[serde(default)]
+    pub auto_apply: bool,
+
+    /// Maximum changed files for auto-apply (0 = no limit)
+    /// Jobs above this threshold wait for manual review even when auto_apply is on.
+    #[serde(default = "default_auto_apply_max_files")]
+    pub auto_apply_max_files: usize,
+
+    /// Maximum changed lines (added + removed) for auto-apply (0 = no limit)
+    #[serde(default = "default_auto_apply_max_lines")]
+    pub auto_apply_max_lines: usize,
+
+    /// Apply worktree jobs as one squashed commit on the base branch (using the
+    /// job's commit message) instead of merging the worktree branch's history
+    #[serde(default)]
+    pub merge_as_commit: bool,
+
+    /// Retries when an agent run is rate limited before the job fails (0 = no retries)
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+
+    /// Delay before the first rate-limit retry in milliseconds; doubles per attempt (max 60s)
+    #[serde(default = "default_base_backoff_ms")]
+    pub base_backoff_ms: u64,
+
     /// GUI settings
     #[serde(default)]
     pub gui: GuiSettings,
@@ -52,6 +93,34 @@ pub struct Settings {
     /// Claude-specific settings
     #[serde(default)]
     pub claude: ClaudeSettings,
+
+    /// Notification hooks fired when jobs finish
+    #[serde(default)]
+    pub notify: NotifySettings,
+
+    /// Daily cost/token budget; queued jobs are held once it is exhausted
+    #[serde(default)]
+    pub budget: BudgetSettings,
+
+    /// BugBounty tracker settings (entry points used for finding prioritization)
+    #[serde(default)]
+    pub bugbounty: BugBountySettings,
+
+    /// Instructions appended to every job prompt after the mode/skill template
+    /// (modes can opt out with `skip_global_suffix`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub global_prompt_suffix: Option<String>,
+
+    /// Store `.kyco/job_manager.json` gzip-compressed (as `job_manager.json.gz`)
+    #[serde(default)]
+    pub compress_job_store: bool,
+
+    /// How new job ids are allocated: "sequential" (default) or "random".
+    /// Random ids make collisions (and reused worktree paths) unlikely when several
+    /// kyco processes create jobs for the same repository. Uniqueness is only
+    /// checked against the jobs this process has loaded, not the persisted store.
+    #[serde(default)]
+    pub job_id_strategy: JobIdStrategy,
 }
 
 /// Claude-specific settings
@@ -65,6 +134,35 @@ pub struct ClaudeSettings {
     pub allowed_plugin_paths: Vec<String>,
 }
 
+/// Preflight behavior for worktree jobs started from a dirty repository
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DirtyRepoPolicy {
+    /// Log a warning and proceed
+    #[default]
+    Warn,
+    /// Fail the job until the working directory is clean
+    Block,
+    /// Stash local changes (`git stash push`) and proceed
+    Stash,
+    /// Skip the check entirely
+    Ignore,
+}
+
+/// How [`crate::job::JobManager`] allocates ids for new jobs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobIdStrategy {
+    /// 1, 2, 3, ... continuing from the highest persisted id
+    #[default]
+    Sequential,
+    /// Random ids, unique among the jobs known to this process.
+    ///
+    /// Another process may still pick the same id between loads; with 53 random
+    /// bits that is unlikely but not ruled out. Lists order jobs by creation time.
+    Random,
+}
+
 fn default_max_concurrent_jobs() -> usize {
     4
 }
@@ -85,6 +183,22 @@ fn default_max_jobs_per_file() -> usize {
     1
 }
 
+fn default_auto_apply_max_files() -> usize {
+    10
+}
+
+fn default_auto_apply_max_lines() -> usize {
+    300
+}
+
+fn default_max_retries() -> u32 {
+    RetryPolicy::default().max_retries
+}
+
+fn default_base_backoff_ms() -> u64 {
+    RetryPolicy::default().base_backoff_ms
+}
+
 impl Default for Settings {
     fn default() -> Self {
         Self {
@@ -93,9 +207,95 @@ impl Default for Settings {
             auto_allow: default_auto_allow(),
             use_worktree: default_use_worktree(),
             max_jobs_per_file: default_max_jobs_per_file(),
+            dirty_repo_policy: DirtyRepoPolicy::default(),
+            auto_apply: false,
+            auto_apply_max_files: default_auto_apply_max_files(),
+            auto_apply_max_lines: default_auto_apply_max_lines(),
+            merge_as_commit: false,
+            max_retries: default_max_retries(),
+            base_backoff_ms: default_base_backoff_ms(),
             gui: GuiSettings::default(),
             registry: RegistrySettings::default(),
             claude: ClaudeSettings::default(),
+            notify: NotifySettings::default(),
+            budget: BudgetSettings::default(),
+            bugbounty: BugBountySettings::default(),
+            global_prompt_suffix: None,
+            compress_job_store: false,
+            job_id_strategy: JobIdStrategy::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Check whether a change of the given size exceeds the auto-apply thresholds.
+    ///
+    /// Returns a human-readable reason when auto-apply must be suppressed.
+    pub fn auto_apply_blocked_reason(&self, files_changed: usize, lines_changed: usize) -> Option<String> {
+        if self.auto_apply_max_files > 0 && files_changed > self.auto_apply_max_files {
+            return Some(format!(
+                "{} files changed (limit {})",
+                files_changed, self.auto_apply_max_files
+            ));
+        }
+        if self.auto_apply_max_lines > 0 && lines_changed > self.auto_apply_max_lines {
+            return Some(format!(
+                "{} lines changed (limit {})",
+                lines_changed, self.auto_apply_max_lines
+            ));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(max_files: usize, max_lines: usize) -> Settings {
+        Settings {
+            auto_apply_max_files: max_files,
+            auto_apply_max_lines: max_lines,
+            ..Settings::default()
         }
     }
+
+    #[test]
+    fn auto_apply_allows_changes_within_both_limits() {
+        let settings = limits(3, 100);
+        assert_eq!(settings.auto_apply_blocked_reason(3, 100), None);
+        assert_eq!(settings.auto_apply_blocked_reason(0, 0), None);
+    }
+
+    #[test]
+    fn auto_apply_blocks_too_many_files() {
+        assert_eq!(
+            limits(3, 100).auto_apply_blocked_reason(4, 10),
+            Some("4 files changed (limit 3)".to_string())
+        );
+    }
+
+    #[test]
+    fn auto_apply_blocks_too_many_lines() {
+        assert_eq!(
+            limits(3, 100).auto_apply_blocked_reason(1, 101),
+            Some("101 lines changed (limit 100)".to_string())
+        );
+    }
+
+    #[test]
+    fn auto_apply_reports_the_file_limit_first() {
+        assert_eq!(
+            limits(3, 100).auto_apply_blocked_reason(4, 101),
+            Some("4 files changed (limit 3)".to_string())
+        );
+    }
+
+    #[test]
+    fn zero_disables_an_auto_apply_limit() {
+        let settings = limits(0, 0);
+        assert_eq!(settings.auto_apply_blocked_reason(1_000, 1_000_000), None);
+        assert_eq!(limits(0, 10).auto_apply_blocked_reason(1_000, 5), None);
+        assert_eq!(limits(2, 0).auto_apply_blocked_reason(1, 1_000_000), None);
+    }
 }
@@ -0,0 +1,72 @@
+//! Daily cost/token budget enforced by the job scheduler
+
+use serde::{Deserialize, Serialize};
+
+/// Daily spend limits checked before a queued job is started
+///
+/// Totals come from the stats database (`~/.kyco/stats.db`) for the current UTC day.
+/// When a limit is reached, queued jobs stay Queued until the next day or until
+/// they are started with `kyco job start --ignore-budget`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BudgetSettings {
+    /// Maximum total cost in USD per day (None = unlimited)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_cost_usd_per_day: Option<f64>,
+
+    /// Maximum input + output tokens per day (None = unlimited)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens_per_day: Option<u64>,
+}
+
+impl BudgetSettings {
+    /// Whether any budget limit is configured
+    pub fn is_enabled(&self) -> bool {
+        self.max_cost_usd_per_day.is_some() || self.max_tokens_per_day.is_some()
+    }
+
+    /// Check today's totals against the limits.
+    ///
+    /// Returns a human-readable reason when the budget is exhausted.
+    pub fn exceeded_reason(&self, tokens_today: u64, cost_today: f64) -> Option<String> {
+        if let Some(max_cost) = self.max_cost_usd_per_day {
+            if cost_today >= max_cost {
+                return Some(format!(
+                    "${:.2} spent today (limit ${:.2})",
+                    cost_today, max_cost
+                ));
+            }
+        }
+        if let Some(max_tokens) = self.max_tokens_per_day {
+            if tokens_today >= max_tokens {
+                return Some(format!(
+                    "{} tokens used today (limit {})",
+                    tokens_today, max_tokens
+                ));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unlimited_budget_never_exceeds() {
+        let budget = BudgetSettings::default();
+        assert!(!budget.is_enabled());
+        assert_eq!(budget.exceeded_reason(u64::MAX, 1e9), None);
+    }
+
+    #[test]
+    fn cost_and_token_limits_are_inclusive() {
+        let budget = BudgetSettings {
+            max_cost_usd_per_day: Some(5.0),
+            max_tokens_per_day: Some(1_000),
+        };
+        assert_eq!(budget.exceeded_reason(999, 4.99), None);
+        assert!(budget.exceeded_reason(999, 5.0).unwrap().contains("limit $5.00"));
+        assert!(budget.exceeded_reason(1_000, 0.0).unwrap().contains("limit 1000"));
+    }
+}
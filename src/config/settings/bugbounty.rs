@@ -0,0 +1,36 @@
+//! BugBounty tracking settings
+
+use serde::{Deserialize, Serialize};
+
+/// Settings for the BugBounty finding tracker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BugBountySettings {
+    /// Glob patterns (relative to the project root) for files that receive external
+    /// input, e.g. HTTP route handlers. Findings whose flow trace starts in a matching
+    /// file get a higher computed priority than purely internal flows.
+    #[serde(default = "default_entry_point_patterns")]
+    pub entry_point_patterns: Vec<String>,
+}
+
+impl Default for BugBountySettings {
+    fn default() -> Self {
+        Self {
+            entry_point_patterns: default_entry_point_patterns(),
+        }
+    }
+}
+
+fn default_entry_point_patterns() -> Vec<String> {
+    [
+        "**/routes/**",
+        "**/controllers/**",
+        "**/handlers/**",
+        "**/api/**",
+        "**/views.py",
+        "**/*_controller.*",
+        "**/*Controller.*",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect()
+}
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use super::orchestrator::OrchestratorSettings;
 use super::voice::VoiceSettings;
+use crate::LogLevel;
 
 /// GUI-specific settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -46,6 +47,15 @@ pub struct GuiSettings {
     #[serde(default)]
     pub http_token: String,
 
+    /// Which global log events to keep: "all" (default) or "system"
+    /// (system, error and permission events only)
+    #[serde(default)]
+    pub log_level: LogLevel,
+
+    /// Maximum number of global log events kept in memory (oldest are evicted)
+    #[serde(default = "default_gui_max_log_events")]
+    pub max_log_events: usize,
+
     /// Voice input settings
     #[serde(default)]
     pub voice: VoiceSettings,
@@ -122,7 +132,9 @@ pub fn default_structured_output_schema() -> String {
           "confidence": { "type": "string", "minLength": 1, "description": "high|medium|low (or UNKNOWN - <reason>)" },
           "cwe_id": { "type": "string", "description": "CWE identifier (e.g., CWE-89)" },
           "cvss_score": { "type": "number", "description": "Optional CVSS score (e.g., 7.5)" },
+          "cvss_vector": { "type": "string", "description": "Optional CVSS v3.1 vector (e.g., CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H)" },
           "affected_assets": { "type": "array", "items": { "type": "string" }, "description": "Affected files/endpoints" },
+          "references": { "type": "array", "items": { "type": "string" }, "description": "CVE IDs, advisory or PoC URLs" },
           "taint_path": { "type": "string", "description": "Data flow from source to sink" }
         }
       }
@@ -199,6 +211,10 @@ fn default_gui_http_port() -> u16 {
     9876
 }
 
+fn default_gui_max_log_events() -> usize {
+    500
+}
+
 impl Default for GuiSettings {
     fn default() -> Self {
         Self {
@@ -209,6 +225,8 @@ impl Default for GuiSettings {
             structured_output_schema: default_structured_output_schema(),
             http_port: default_gui_http_port(),
             http_token: String::new(),
+            log_level: LogLevel::default(),
+            max_log_events: default_gui_max_log_events(),
             voice: VoiceSettings::default(),
             orchestrator: OrchestratorSettings::default(),
         }
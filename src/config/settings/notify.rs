@@ -0,0 +1,31 @@
+//! Notification hooks fired when jobs finish
+
+use serde::{Deserialize, Serialize};
+
+/// Notification hooks fired when a job reaches Done, Failed, or Merged
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NotifySettings {
+    /// Shell command run via `sh -c` when a job finishes
+    /// The job is described by the KYCO_JOB_ID, KYCO_STATUS and KYCO_MODE environment variables.
+    /// Example: "notify-send \"kyco\" \"job $KYCO_JOB_ID $KYCO_STATUS\""
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_complete_command: Option<String>,
+
+    /// URL that receives a JSON POST when a job finishes
+    /// Body: {"job_id": 42, "status": "done", "mode": "review"}
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_complete_webhook: Option<String>,
+}
+
+impl NotifySettings {
+    /// Whether any notification hook is configured
+    pub fn is_enabled(&self) -> bool {
+        self.on_complete_command
+            .as_deref()
+            .is_some_and(|c| !c.trim().is_empty())
+            || self
+                .on_complete_webhook
+                .as_deref()
+                .is_some_and(|u| !u.trim().is_empty())
+    }
+}
@@ -53,8 +53,10 @@ Your job is to help the user run KYCo jobs (skills/chains) safely and iterativel
 
 ## Top-level Commands
 - GUI: `kyco gui` (or just `kyco`)
-- Status (GUI must be running): `kyco status [--filter pending|queued|running|done|failed|rejected|merged]`
-- Init config: `kyco init [--force]`
+- Status (GUI must be running): `kyco status [--filter pending|queued|running|done|failed|rejected|merged] [--since 30m|2h|1d|<timestamp>] [--tag <tag>] [--watch [--interval 2]]`
+- Init config: `kyco init [--force] [--template minimal|security|refactor]` (`kyco init --list-templates` lists them)
+- Reload the running GUI's config after editing it: `kyco config reload`
+- Usage/cost report: `kyco stats export [--range 7d] [--agent <id>] [--mode <skill>] [--format csv|json]`
 - Jobs: `kyco job ...`
 - Skills: `kyco skill ...`
 - Chains: `kyco chain ...`
@@ -102,7 +104,7 @@ Your job is to help the user run KYCo jobs (skills/chains) safely and iterativel
 
 ### Starting Jobs
 - Start a job (creates + queues by default):
-  `kyco job start --file <path> --skill <skill_or_chain> --prompt "<what to do>" [--project <id>] [--finding VULN-001,VULN-002] [--agent <id>] [--agents a,b] [--line-start N --line-end M] [--force-worktree] [--pending]`
+  `kyco job start --file <path> --skill <skill_or_chain> --prompt "<what to do>" [--project <id>] [--finding VULN-001,VULN-002] [--agent <id>] [--agents a,b] [--line-start N --line-end M [--context-lines K]] [--tag <tag>] [--base <branch-or-commit>] [--force-worktree] [--pending]`
 - Batch job creation from many inputs (repeatable, supports globs/dirs):
   `kyco job start --input "src/**/*.rs,README.md" --batch --skill <skill_or_chain> --prompt "<what to do>" [--pending]`
 - Use `--pending` to create without auto-queueing (review first in GUI)
@@ -110,9 +112,9 @@ Your job is to help the user run KYCo jobs (skills/chains) safely and iterativel
 
 ### Monitoring Jobs
 - List jobs:
-  `kyco job list [--status pending|queued|running|done|failed|rejected|merged] [--state <result_state>] [--project <id>] [--finding <id>] [--skill <name>] [--search "<q>"] [--limit N]`
+  `kyco job list [--status pending|queued|running|done|failed|rejected|merged] [--state <result_state>] [--project <id>] [--finding <id>] [--skill <name>] [--search "<q>"] [--limit N] [--offset N]`
 - Get job details: `kyco job get <job_id> [--json]`
-- Wait for completion: `kyco job wait <job_id>`
+- Wait for completion: `kyco job wait <job_id>` (several ids: `--all` or `--any`)
 - View diff: `kyco job diff <job_id> [--json]`
 - Get output:
   - Full: `kyco job output <job_id>`
@@ -128,6 +130,7 @@ Your job is to help the user run KYCo jobs (skills/chains) safely and iterativel
 - Queue a pending job: `kyco job queue <job_id>`
 - Abort gracefully: `kyco job abort <job_id>`
 - Kill immediately: `kyco job kill <job_id>`
+- Stop everything: `kyco job abort-all`; hold the queue: `kyco queue pause` / `kyco queue resume`
 - Restart failed job: `kyco job restart <job_id>`
 - Continue session: `kyco job continue <job_id> --prompt "<follow-up>" [--pending]`
 
@@ -142,16 +145,19 @@ KYCo stores BugBounty state in a global DB (`~/.kyco/bugbounty.db`) and can opti
 ### Projects
 - List projects: `kyco project list [--platform hackerone|intigriti|bugcrowd]`
 - Show project (scope + policy + stats): `kyco project show <id>`
-- Discover projects in a repo: `kyco project discover [--path <dir>] [--dry-run]`
+- Discover projects in a repo: `kyco project discover [--path <dir>] [--layout <glob>]... [--dry-run]`
 - Select active project (used as default for many commands): `kyco project select <id>`
 - Create project: `kyco project init --id <id> --root <path> [--platform ...]`
 - Generate overview: `kyco project overview [--project <id>] [--output <file>] [--update-global]`
+- Back up / share a whole project as JSON: `kyco project export <id> [--out <file>]`, restore with `kyco project import <file> [--as <new-id>]`
+- Project memory (injected into later jobs): `kyco memory list --project <id>`, `kyco memory add --project <id> --type note --text "..." [--file <path> [--line N]]`, `kyco memory rm <memory_id>`
 
 ### Findings
 - List: `kyco finding list [--project <id>] [--status raw|needs_repro|verified|...] [--severity critical|high|medium|low|info] [--search "<q>"]`
 - Show: `kyco finding show <id>`
 - Create: `kyco finding create --title "<title>" --project <id> [--severity ...] [--attack-scenario "..."] [--impact "..."] [--cwe CWE-xxx] [--assets a,b] [--write-notes]`
-- Move on kanban: `kyco finding set-status <id> <status>`
+- Move on kanban: `kyco finding set-status <id> <status>` (for `duplicate`, pass `--of <canonical_id>`)
+- Merge a duplicate (moves linked jobs + artifacts): `kyco finding merge <id> --into <canonical_id>`
 - Mark false positive: `kyco finding fp <id> "<reason>"`
 - Delete: `kyco finding delete <id> [-y]`
 - Export report: `kyco finding export <id> --format markdown|intigriti|hackerone [--output <file>]`
@@ -161,13 +167,14 @@ KYCo stores BugBounty state in a global DB (`~/.kyco/bugbounty.db`) and can opti
 - Link/unlink jobs: `kyco finding link --finding <id> --job <job_id> [--link-type related]` / `kyco finding unlink --finding <id> --job <job_id>`
 
 ### Import from external tools
-- Generic: `kyco finding import <file> --project <id> --format sarif|semgrep|snyk|nuclei|auto`
+- Generic: `kyco finding import <file> --project <id> --format sarif|semgrep|snyk|nuclei|markdown|auto`
 - Convenience aliases:
   - `kyco import semgrep <file> [--project <id>] [--create-jobs] [--queue] [--skill <skill>] [--agents a,b]`
   - `kyco import codeql <file>  [--project <id>] [--create-jobs] [--queue] ...`
   - `kyco import sarif <file>  [--project <id>] [--create-jobs] [--queue] ...`
   - `kyco import snyk <file>   [--project <id>] [--create-jobs] [--queue] ...`
   - `kyco import nuclei <file> [--project <id>] [--create-jobs] [--queue] ...`
+  - `kyco import markdown <file> [--project <id>] [--create-jobs] [--queue] ...` (hand-written `## Title` / `Severity:` / `Affected:` notes)
   - `kyco import auto <file>   [--project <id>] [--create-jobs] [--queue] ...`
 If `--create-jobs` is used, KYCo creates one verification job per imported finding (pending by default unless `--queue` is set), linking the job to the finding and project.
 
@@ -251,7 +258,7 @@ Your job is to help the user run KYCo jobs (skills/chains) safely and iterativel
 ### Monitoring Jobs
 - List jobs: `kyco job list [--status queued|running|done|failed] [--skill <name>]`
 - Get job details: `kyco job get <job_id> [--json]`
-- Wait for completion: `kyco job wait <job_id>`
+- Wait for completion: `kyco job wait <job_id>` (several ids: `--all` or `--any`)
 - View diff: `kyco job diff <job_id> [--json]`
 - Get output:
   - Full: `kyco job output <job_id>`
@@ -262,6 +269,7 @@ Your job is to help the user run KYCo jobs (skills/chains) safely and iterativel
 - Queue a pending job: `kyco job queue <job_id>`
 - Abort gracefully: `kyco job abort <job_id>`
 - Kill immediately: `kyco job kill <job_id>`
+- Stop everything: `kyco job abort-all`; hold the queue: `kyco queue pause` / `kyco queue resume`
 - Restart failed job: `kyco job restart <job_id>`
 - Continue session: `kyco job continue <job_id> --prompt "<follow-up>" [--pending]`
 
@@ -91,6 +91,14 @@ pub struct SkillKycoExtensions {
     #[serde(default)]
     pub max_turns: u32,
 
+    /// Maximum output tokens per model response (passed to the SDK where supported)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<u32>,
+
+    /// Abort the agent run after this many seconds (None / 0 = unlimited)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+
     /// Model override
     #[serde(default)]
     pub model: Option<String>,
@@ -111,10 +119,22 @@ pub struct SkillKycoExtensions {
     #[serde(default)]
     pub state_prompt: Option<String>,
 
+    /// Result parser: "default", "json", "yaml", or "regex"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result_parser: Option<String>,
+
+    /// Regex with named groups used when `result_parser = "regex"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result_pattern: Option<String>,
+
     /// Force running in a git worktree for this skill
     #[serde(default)]
     pub use_worktree: Option<bool>,
 
+    /// Auto-merge completed worktree jobs when all gates pass (opt-in)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_merge: Option<bool>,
+
     /// Claude SDK specific options
     #[serde(default)]
     pub claude: Option<ClaudeSkillOptions>,
@@ -263,10 +283,14 @@ impl SkillConfig {
             || self.kyco.agent.is_some()
             || self.kyco.session_mode != SkillSessionType::Oneshot
             || self.kyco.max_turns != 0
+            || self.kyco.max_output_tokens.is_some()
+            || self.kyco.timeout_secs.is_some()
             || self.kyco.model.is_some()
             || !self.kyco.disallowed_tools.is_empty()
             || !self.kyco.output_states.is_empty()
             || self.kyco.use_worktree.is_some()
+            || self.kyco.auto_merge.is_some()
+            || self.kyco.result_parser.is_some()
         {
             output.push_str("x-kyco:\n");
 
@@ -294,6 +318,14 @@ impl SkillConfig {
                 output.push_str(&format!("  max_turns: {}\n", self.kyco.max_turns));
             }
 
+            if let Some(max_output_tokens) = self.kyco.max_output_tokens {
+                output.push_str(&format!("  max_output_tokens: {}\n", max_output_tokens));
+            }
+
+            if let Some(timeout_secs) = self.kyco.timeout_secs {
+                output.push_str(&format!("  timeout_secs: {}\n", timeout_secs));
+            }
+
             if let Some(ref model) = self.kyco.model {
                 output.push_str(&format!("  model: {}\n", model));
             }
@@ -325,6 +357,19 @@ impl SkillConfig {
             if let Some(use_worktree) = self.kyco.use_worktree {
                 output.push_str(&format!("  use_worktree: {}\n", use_worktree));
             }
+
+            if let Some(auto_merge) = self.kyco.auto_merge {
+                output.push_str(&format!("  auto_merge: {}\n", auto_merge));
+            }
+
+            if let Some(ref parser) = self.kyco.result_parser {
+                output.push_str(&format!("  result_parser: {}\n", parser));
+            }
+
+            if let Some(ref pattern) = self.kyco.result_pattern {
+                // Single-quoted YAML scalar: only `'` needs escaping (as `''`)
+                output.push_str(&format!("  result_pattern: '{}'\n", pattern.replace('\'', "''")));
+            }
         }
 
         output.push_str("---\n\n");
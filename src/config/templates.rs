@@ -0,0 +1,39 @@
+//! Starter configs for `kyco init --template`
+//!
+//! Each template is a TOML fragment embedded at compile-time from
+//! `assets/templates/` and merged on top of the default config.
+
+/// An embedded starter config
+#[derive(Debug, Clone, Copy)]
+pub struct StarterTemplate {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub toml: &'static str,
+}
+
+/// All starter templates, in the order `kyco init --list-templates` prints them
+pub const STARTER_TEMPLATES: &[StarterTemplate] = &[
+    StarterTemplate {
+        name: "minimal",
+        description: "Built-in agents only; jobs wait until you queue them",
+        toml: include_str!("../../assets/templates/minimal.toml"),
+    },
+    StarterTemplate {
+        name: "security",
+        description: "Read-only recon modes and a triage chain for bug bounty reviews",
+        toml: include_str!("../../assets/templates/security.toml"),
+    },
+    StarterTemplate {
+        name: "refactor",
+        description: "Worktree refactors merged as one commit, plus a test-coverage chain",
+        toml: include_str!("../../assets/templates/refactor.toml"),
+    },
+];
+
+/// Look up a starter template by name (case-insensitive)
+pub fn starter_template(name: &str) -> Option<&'static StarterTemplate> {
+    let name = name.trim();
+    STARTER_TEMPLATES
+        .iter()
+        .find(|t| t.name.eq_ignore_ascii_case(name))
+}
@@ -0,0 +1,415 @@
+//! Read-only consistency checks across agents, modes, skills, chains, and aliases
+
+use std::collections::{BTreeMap, HashSet};
+
+use serde::Serialize;
+
+use super::{Config, StepAction};
+
+/// How serious a configuration issue is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueSeverity {
+    /// Suspicious but still usable
+    Warning,
+    /// Will fail or misbehave at runtime
+    Error,
+}
+
+impl std::fmt::Display for IssueSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IssueSeverity::Warning => f.pad("warning"),
+            IssueSeverity::Error => f.pad("error"),
+        }
+    }
+}
+
+/// A single problem found by [`Config::validate`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigIssue {
+    pub severity: IssueSeverity,
+    /// Where the problem is, e.g. `chain.review-fix.steps[1]`
+    pub location: String,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    fn error(location: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: IssueSeverity::Error,
+            location: location.into(),
+            message: message.into(),
+        }
+    }
+
+    fn warning(location: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: IssueSeverity::Warning,
+            location: location.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Config {
+    /// Check that chains, modes, skills, and aliases reference things that exist.
+    ///
+    /// Issues are returned sorted by location; an empty list means the config is consistent.
+    pub fn validate(&self) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+
+        let agent_aliases = self.agent_alias_owners(&mut issues);
+        let skill_aliases = self.skill_alias_owners(&mut issues);
+
+        let agent_exists = |name: &str| {
+            self.agent.keys().any(|a| a.eq_ignore_ascii_case(name))
+                || agent_aliases.contains_key(&name.to_ascii_lowercase())
+        };
+        let skill_exists = |name: &str| {
+            self.skill.contains_key(name)
+                || self.mode.contains_key(name)
+                || skill_aliases.contains_key(name)
+        };
+
+        for (name, agent) in sorted(&self.agent) {
+            // The prompt is never put on the command line (it can exceed ARG_MAX)
+            if agent
+                .command_template
+                .as_deref()
+                .is_some_and(|t| t.contains("{prompt}"))
+            {
+                issues.push(ConfigIssue::error(
+                    format!("agent.{}", name),
+                    "{prompt} is not supported; use {prompt_file} or stdin",
+                ));
+            }
+        }
+
+        for (name, mode) in sorted(&self.mode) {
+            let location = format!("mode.{}", name);
+            if let Some(agent) = mode.agent.as_deref().filter(|a| !agent_exists(a)) {
+                issues.push(ConfigIssue::error(
+                    &location,
+                    format!("agent '{}' is not defined", agent),
+                ));
+            }
+            if mode.max_output_tokens == Some(0) {
+                issues.push(ConfigIssue::error(&location, "max_output_tokens must be positive"));
+            }
+            match mode.prompt.as_deref() {
+                None => issues.push(ConfigIssue::warning(&location, "no prompt template")),
+                Some(p) if p.trim().is_empty() => {
+                    issues.push(ConfigIssue::warning(&location, "prompt template is empty"))
+                }
+                Some(_) => {}
+            }
+        }
+
+        for (name, skill) in sorted(&self.skill) {
+            let location = format!("skill.{}", name);
+            if let Some(agent) = skill.kyco.agent.as_deref().filter(|a| !agent_exists(a)) {
+                issues.push(ConfigIssue::error(
+                    &location,
+                    format!("agent '{}' is not defined", agent),
+                ));
+            }
+            if skill.kyco.max_output_tokens == Some(0) {
+                issues.push(ConfigIssue::error(&location, "max_output_tokens must be positive"));
+            }
+            if skill.instructions.trim().is_empty() {
+                issues.push(ConfigIssue::warning(&location, "instructions are empty"));
+            }
+        }
+
+        for (name, chain) in sorted(&self.chain) {
+            let location = format!("chain.{}", name);
+            if chain.steps.is_empty() {
+                issues.push(ConfigIssue::error(&location, "chain has no steps"));
+            }
+            let step_skills: HashSet<&str> = chain.steps.iter().map(|s| s.skill.as_str()).collect();
+            let state_ids: HashSet<&str> = chain.states.iter().map(|s| s.id.as_str()).collect();
+
+            for (idx, step) in chain.steps.iter().enumerate() {
+                let location = format!("{}.steps[{}]", location, idx);
+                if !skill_exists(&step.skill) {
+                    issues.push(ConfigIssue::error(
+                        &location,
+                        format!("skill '{}' is not defined", step.skill),
+                    ));
+                }
+                if let Some(agent) = step.agent.as_deref().filter(|a| !agent_exists(a)) {
+                    issues.push(ConfigIssue::error(
+                        &location,
+                        format!("agent '{}' is not defined", agent),
+                    ));
+                }
+                if let Some(target) = step.loop_to.as_deref() {
+                    if !step_skills.contains(target) {
+                        issues.push(ConfigIssue::error(
+                            &location,
+                            format!("loop_to '{}' is not a step in this chain", target),
+                        ));
+                    }
+                }
+                for target in step.on_state.values().filter_map(|action| match action {
+                    StepAction::Goto(target) => Some(target),
+                    _ => None,
+                }) {
+                    if !step_skills.contains(target.as_str()) {
+                        issues.push(ConfigIssue::error(
+                            &location,
+                            format!("on_state goto '{}' is not a step in this chain", target),
+                        ));
+                    }
+                }
+                if !state_ids.is_empty() {
+                    let referenced = step
+                        .trigger_on
+                        .iter()
+                        .chain(step.skip_on.iter())
+                        .flatten()
+                        .chain(step.on_state.keys());
+                    for state in referenced.filter(|s| !state_ids.contains(s.as_str())) {
+                        issues.push(ConfigIssue::warning(
+                            &location,
+                            format!("state '{}' is not defined in chain states", state),
+                        ));
+                    }
+                }
+            }
+        }
+
+        for (alias, target) in sorted(&self.alias.agent) {
+            if !self.agent.keys().any(|a| a.eq_ignore_ascii_case(target)) {
+                issues.push(ConfigIssue::error(
+                    format!("alias.agent.{}", alias),
+                    format!("target agent '{}' is not defined", target),
+                ));
+            }
+        }
+        for (alias, target) in sorted(&self.alias.mode) {
+            if !self.skill.contains_key(target.as_str()) && !self.mode.contains_key(target.as_str())
+            {
+                issues.push(ConfigIssue::error(
+                    format!("alias.mode.{}", alias),
+                    format!("target mode '{}' is not defined", target),
+                ));
+            }
+        }
+
+        issues.sort_by(|a, b| a.location.cmp(&b.location));
+        issues
+    }
+
+    /// Map of lowercased agent alias -> owning agent, reporting duplicates and shadowing.
+    fn agent_alias_owners(&self, issues: &mut Vec<ConfigIssue>) -> BTreeMap<String, String> {
+        let mut claims: Vec<(String, String, String)> = Vec::new();
+        for (name, agent) in sorted(&self.agent) {
+            for alias in &agent.aliases {
+                claims.push((
+                    alias.to_ascii_lowercase(),
+                    name.clone(),
+                    format!("agent.{}", name),
+                ));
+            }
+        }
+        for (alias, target) in sorted(&self.alias.agent) {
+            claims.push((
+                alias.to_ascii_lowercase(),
+                target.clone(),
+                format!("alias.agent.{}", alias),
+            ));
+        }
+
+        let is_name = |alias: &str| self.agent.keys().any(|a| a.eq_ignore_ascii_case(alias));
+        collect_alias_owners(claims, "agent", is_name, issues)
+    }
+
+    /// Map of mode/skill alias -> owning mode or skill, reporting duplicates and shadowing.
+    fn skill_alias_owners(&self, issues: &mut Vec<ConfigIssue>) -> BTreeMap<String, String> {
+        let mut claims: Vec<(String, String, String)> = Vec::new();
+        for (name, mode) in sorted(&self.mode) {
+            for alias in &mode.aliases {
+                claims.push((alias.clone(), name.clone(), format!("mode.{}", name)));
+            }
+        }
+        for (name, skill) in sorted(&self.skill) {
+            for alias in &skill.kyco.aliases {
+                claims.push((alias.clone(), name.clone(), format!("skill.{}", name)));
+            }
+        }
+        for (alias, target) in sorted(&self.alias.mode) {
+            claims.push((
+                alias.clone(),
+                target.clone(),
+                format!("alias.mode.{}", alias),
+            ));
+        }
+
+        let is_name = |alias: &str| {
+            self.mode.contains_key(alias)
+                || self.skill.contains_key(alias)
+                || self.chain.contains_key(alias)
+        };
+        collect_alias_owners(claims, "mode", is_name, issues)
+    }
+}
+
+/// Resolve `(alias, owner, location)` claims into an alias map.
+///
+/// An alias claimed for two different owners, or one that shadows an existing name,
+/// is reported as an error; the first claim wins.
+fn collect_alias_owners(
+    claims: Vec<(String, String, String)>,
+    kind: &str,
+    is_name: impl Fn(&str) -> bool,
+    issues: &mut Vec<ConfigIssue>,
+) -> BTreeMap<String, String> {
+    let mut owners: BTreeMap<String, String> = BTreeMap::new();
+    for (alias, owner, location) in claims {
+        if is_name(&alias) && !alias.eq_ignore_ascii_case(&owner) {
+            issues.push(ConfigIssue::error(
+                &location,
+                format!("alias '{}' collides with an existing {} name", alias, kind),
+            ));
+            continue;
+        }
+        match owners.get(&alias) {
+            Some(existing) if !existing.eq_ignore_ascii_case(&owner) => {
+                issues.push(ConfigIssue::error(
+                    &location,
+                    format!(
+                        "alias '{}' points to '{}' but is already used for '{}'",
+                        alias, owner, existing
+                    ),
+                ));
+            }
+            Some(_) => {}
+            None => {
+                owners.insert(alias, owner);
+            }
+        }
+    }
+    owners
+}
+
+fn sorted<V>(map: &std::collections::HashMap<String, V>) -> Vec<(&String, &V)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consistent_config_has_no_issues() {
+        let cfg: Config = toml::from_str(
+            r#"
+            [agent.claude]
+            aliases = ["c"]
+
+            [mode.review]
+            agent = "c"
+            prompt = "Review {target}"
+            aliases = ["r"]
+
+            [chain.review-loop]
+            steps = [
+                { skill = "review" },
+                { skill = "r", agent = "claude", loop_to = "review" },
+            ]
+
+            [alias.mode]
+            rev = "review"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(cfg.validate(), Vec::new());
+    }
+
+    #[test]
+    fn reports_unresolved_references_and_alias_collisions() {
+        let cfg: Config = toml::from_str(
+            r#"
+            [agent.claude]
+
+            [agent.mine]
+            sdk = "custom"
+            command_template = "my-agent --prompt {prompt}"
+
+            [mode.review]
+            agent = "claude"
+            prompt = "Review {target}"
+            aliases = ["r"]
+
+            [mode.fix]
+            agent = "gpt"
+            prompt = "  "
+            aliases = ["r", "review"]
+            max_output_tokens = 0
+
+            [chain.broken]
+            steps = [
+                { skill = "review" },
+                { skill = "missing", agent = "nobody", loop_to = "elsewhere" },
+            ]
+
+            [chain.empty]
+            steps = []
+            "#,
+        )
+        .unwrap();
+
+        let issues = cfg.validate();
+        let summary: Vec<(IssueSeverity, &str, &str)> = issues
+            .iter()
+            .map(|i| (i.severity, i.location.as_str(), i.message.as_str()))
+            .collect();
+
+        use IssueSeverity::{Error, Warning};
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    Error,
+                    "agent.mine",
+                    "{prompt} is not supported; use {prompt_file} or stdin"
+                ),
+                (
+                    Error,
+                    "chain.broken.steps[1]",
+                    "skill 'missing' is not defined"
+                ),
+                (
+                    Error,
+                    "chain.broken.steps[1]",
+                    "agent 'nobody' is not defined"
+                ),
+                (
+                    Error,
+                    "chain.broken.steps[1]",
+                    "loop_to 'elsewhere' is not a step in this chain"
+                ),
+                (Error, "chain.empty", "chain has no steps"),
+                (
+                    Error,
+                    "mode.fix",
+                    "alias 'review' collides with an existing mode name"
+                ),
+                (Error, "mode.fix", "agent 'gpt' is not defined"),
+                (Error, "mode.fix", "max_output_tokens must be positive"),
+                (Warning, "mode.fix", "prompt template is empty"),
+                (
+                    Error,
+                    "mode.review",
+                    "alias 'r' points to 'review' but is already used for 'fix'"
+                ),
+            ]
+        );
+        assert!(issues.iter().any(|i| i.severity == IssueSeverity::Error));
+    }
+}
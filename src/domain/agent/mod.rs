@@ -6,11 +6,14 @@
 mod templates;
 mod types;
 
-pub use types::{CliType, SdkType, SystemPromptMode};
+pub use types::{CliType, RetryPolicy, SdkType, SystemPromptMode};
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Environment variable the Claude SDK reads for its per-response output token cap
+const CLAUDE_MAX_OUTPUT_TOKENS_ENV: &str = "CLAUDE_CODE_MAX_OUTPUT_TOKENS";
+
 /// MCP (Model Context Protocol) Server configuration
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct McpServerConfig {
@@ -129,6 +132,14 @@ pub struct AgentConfig {
     #[serde(default)]
     pub max_turns: u32,
 
+    /// Maximum output tokens per model response (set from the mode/skill)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<u32>,
+
+    /// Wall-clock limit for a single agent run in seconds (set from the mode/skill)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+
     /// How to handle system prompts
     #[serde(default)]
     pub system_prompt_mode: SystemPromptMode,
@@ -183,6 +194,20 @@ pub struct AgentConfig {
     /// Default is false for safety.
     #[serde(default)]
     pub allow_dangerous_bypass: bool,
+
+    /// Agent may never write (Write/Edit disallowed, Codex sandbox read-only).
+    ///
+    /// Enforced by `Config::get_agent_for_job` after mode/skill overrides.
+    #[serde(default)]
+    pub read_only: bool,
+
+    /// Shell command template for `SdkType::Custom` agents (see `AgentConfigToml`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command_template: Option<String>,
+
+    /// Rate-limit retry budget, filled in from global settings by `Config::get_agent`
+    #[serde(skip)]
+    pub retry: RetryPolicy,
 }
 
 impl Default for AgentConfig {
@@ -203,6 +228,8 @@ impl AgentConfig {
             sandbox: None,
             ask_for_approval: None,
             max_turns: 0,
+            max_output_tokens: None,
+            timeout_secs: None,
             system_prompt_mode: SystemPromptMode::Append,
             skill_templates: templates::default_skill_templates(),
             env: HashMap::new(),
@@ -214,6 +241,9 @@ impl AgentConfig {
             output_schema: None,
             structured_output_schema: None,
             allow_dangerous_bypass: false,
+            read_only: false,
+            command_template: None,
+            retry: RetryPolicy::default(),
         }
     }
 
@@ -228,6 +258,8 @@ impl AgentConfig {
             sandbox: None,
             ask_for_approval: None,
             max_turns: 0,
+            max_output_tokens: None,
+            timeout_secs: None,
             system_prompt_mode: SystemPromptMode::Append,
             skill_templates: templates::default_skill_templates(),
             env: HashMap::new(),
@@ -239,6 +271,9 @@ impl AgentConfig {
             output_schema: None,
             structured_output_schema: None,
             allow_dangerous_bypass: false,
+            read_only: false,
+            command_template: None,
+            retry: RetryPolicy::default(),
         }
     }
 
@@ -270,6 +305,28 @@ impl AgentConfig {
         self.get_skill_template(mode)
     }
 
+    /// Environment for the agent process, including SDK settings derived from config.
+    ///
+    /// Claude reads its output cap from `CLAUDE_CODE_MAX_OUTPUT_TOKENS`; an explicit
+    /// value in `env` takes precedence over `max_output_tokens`.
+    pub fn effective_env(&self) -> HashMap<String, String> {
+        let mut env = self.env.clone();
+        if let (SdkType::Claude, Some(max_output_tokens)) =
+            (self.sdk_type, self.max_output_tokens.filter(|n| *n > 0))
+        {
+            env.entry(CLAUDE_MAX_OUTPUT_TOKENS_ENV.to_string())
+                .or_insert_with(|| max_output_tokens.to_string());
+        }
+        env
+    }
+
+    /// [`Self::effective_env`] with per-job variables layered on top (job values win).
+    pub fn env_for_job(&self, job_env: &HashMap<String, String>) -> HashMap<String, String> {
+        let mut env = self.effective_env();
+        env.extend(job_env.iter().map(|(k, v)| (k.clone(), v.clone())));
+        env
+    }
+
     /// Get the binary name for CLI-based adapters (fallback to SDK type name)
     pub fn get_binary(&self) -> String {
         self.sdk_type.default_name().to_string()
@@ -285,3 +342,50 @@ impl AgentConfig {
         Vec::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Job;
+
+    #[test]
+    fn job_env_overrides_agent_env() {
+        let mut config = AgentConfig::claude_default();
+        config.max_output_tokens = Some(4096);
+        config
+            .env
+            .insert("FEATURE_X".to_string(), "off".to_string());
+        config.env.insert("AGENT_ONLY".to_string(), "1".to_string());
+
+        let job_env = Job::parse_env_assignments(&[
+            "FEATURE_X=on".to_string(),
+            "JOB_ONLY=a=b".to_string(),
+            format!("{}=8192", CLAUDE_MAX_OUTPUT_TOKENS_ENV),
+        ])
+        .unwrap();
+        let env = config.env_for_job(&job_env);
+
+        assert_eq!(env["FEATURE_X"], "on");
+        assert_eq!(env["AGENT_ONLY"], "1");
+        assert_eq!(env["JOB_ONLY"], "a=b");
+        assert_eq!(env[CLAUDE_MAX_OUTPUT_TOKENS_ENV], "8192");
+        assert_eq!(config.env_for_job(&HashMap::new()), config.effective_env());
+    }
+
+    #[test]
+    fn invalid_job_env_is_rejected() {
+        for raw in [
+            "NO_EQUALS",
+            "=value",
+            "1ABC=x",
+            "BAD-NAME=x",
+            "KYCO_BUGBOUNTY_ENFORCE=0",
+        ] {
+            assert!(
+                Job::parse_env_assignments(&[raw.to_string()]).is_err(),
+                "{} should be rejected",
+                raw
+            );
+        }
+    }
+}
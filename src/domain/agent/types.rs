@@ -13,7 +13,8 @@ pub enum SdkType {
     Codex,
     /// Legacy: Gemini (not supported, will use Claude)
     Gemini,
-    /// Legacy: Custom (not supported, will use Claude)
+    /// External CLI agent run via the agent's `command_template`
+    /// (without a template it falls back to Claude)
     Custom,
 }
 
@@ -71,3 +72,70 @@ pub enum SystemPromptMode {
 
 // SessionMode removed - all agents now use persistent sessions by default.
 // This enables automatic retry/resume when connections drop.
+
+/// Upper bound for a single backoff delay
+const MAX_BACKOFF_MS: u64 = 60_000;
+
+/// Rate-limit retry budget for an agent run (from `settings.max_retries` and
+/// `settings.base_backoff_ms`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries before the job fails (0 = fail on the first rate limit)
+    pub max_retries: u32,
+    /// Delay before the first retry; doubles with each attempt up to 60s
+    pub base_backoff_ms: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 20,
+            base_backoff_ms: 2_000,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before retry number `attempt` (1-based).
+    ///
+    /// A server-provided `Retry-After` hint wins over the exponential schedule;
+    /// either way the delay is capped at 60s.
+    pub fn delay_ms(&self, attempt: u32, retry_after_ms: Option<u64>) -> u64 {
+        if let Some(ms) = retry_after_ms {
+            return ms.clamp(1_000, MAX_BACKOFF_MS);
+        }
+        let factor = 1u64
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u64::MAX);
+        self.base_backoff_ms
+            .saturating_mul(factor)
+            .min(MAX_BACKOFF_MS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RetryPolicy;
+
+    #[test]
+    fn retry_delay_doubles_and_caps() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            base_backoff_ms: 2_000,
+        };
+        let delays: Vec<u64> = (1..=7).map(|n| policy.delay_ms(n, None)).collect();
+        assert_eq!(
+            delays,
+            [2_000, 4_000, 8_000, 16_000, 32_000, 60_000, 60_000]
+        );
+        assert_eq!(policy.delay_ms(200, None), 60_000);
+    }
+
+    #[test]
+    fn retry_delay_clamps_retry_after() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.delay_ms(1, Some(500)), 1_000);
+        assert_eq!(policy.delay_ms(1, Some(5_000)), 5_000);
+        assert_eq!(policy.delay_ms(1, Some(90_000)), 60_000);
+    }
+}
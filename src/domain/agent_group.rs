@@ -7,7 +7,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use super::JobId;
+use super::{Job, JobId, JobStatus};
 
 /// Unique identifier for an agent run group
 pub type AgentGroupId = u64;
@@ -47,6 +47,68 @@ impl std::fmt::Display for GroupStatus {
     }
 }
 
+/// Policy for picking a group's winner without the comparison popup
+///
+/// Once every job in the group has finished, the Done job whose result state
+/// (or, if no state was reported, result status) appears earliest in
+/// `preferred_states` wins. Ties and jobs without a preferred state fall back
+/// to the fewest changed files, then the lowest job id.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoSelectPolicy {
+    /// Result states in order of preference (e.g. `["success", "partial"]`)
+    #[serde(default)]
+    pub preferred_states: Vec<String>,
+}
+
+impl AutoSelectPolicy {
+    /// Create a policy from an ordered list of preferred states
+    pub fn new(preferred_states: Vec<String>) -> Self {
+        Self { preferred_states }
+    }
+
+    /// The same policy with states trimmed and empty entries dropped
+    pub fn normalized(self) -> Self {
+        Self {
+            preferred_states: self
+                .preferred_states
+                .into_iter()
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Pick the winning job among `jobs`; `None` if no job is Done
+    pub fn pick(&self, jobs: &[&Job]) -> Option<JobId> {
+        jobs.iter()
+            .filter(|j| j.status == JobStatus::Done)
+            .min_by_key(|j| (self.state_rank(j), changed_file_count(j), j.id))
+            .map(|j| j.id)
+    }
+
+    /// Position of the job's result state in the preference list (unmatched sorts last)
+    fn state_rank(&self, job: &Job) -> usize {
+        let state = job
+            .result
+            .as_ref()
+            .and_then(|r| r.state.as_deref().or(r.status.as_deref()));
+        state
+            .and_then(|s| {
+                self.preferred_states
+                    .iter()
+                    .position(|p| p.eq_ignore_ascii_case(s.trim()))
+            })
+            .unwrap_or(self.preferred_states.len())
+    }
+}
+
+fn changed_file_count(job: &Job) -> usize {
+    job.stats
+        .as_ref()
+        .map(|s| s.files_changed)
+        .unwrap_or(job.changed_files.len())
+}
+
 /// A group of jobs that process the same prompt with different agents
 ///
 /// This enables the multi-agent workflow where users can:
@@ -80,6 +142,10 @@ pub struct AgentRunGroup {
     /// The job selected by the user (if status is Selected or Merged)
     pub selected_job: Option<JobId>,
 
+    /// Pick the winner automatically once all jobs finish (None = manual comparison)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_select: Option<AutoSelectPolicy>,
+
     /// When this group was created
     pub created_at: DateTime<Utc>,
 
@@ -100,6 +166,7 @@ impl AgentRunGroup {
             agent_names: Vec::new(),
             status: GroupStatus::Running,
             selected_job: None,
+            auto_select: None,
             created_at: now,
             updated_at: now,
         }
@@ -1,7 +1,8 @@
-use chrono::Utc;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
-use super::{Job, JobId, JobResult, JobStats, JobStatus, MAX_JOB_LOG_EVENTS};
+use super::{Job, JobId, JobResult, JobStats, JobStatus, ResultParser, MAX_JOB_LOG_EVENTS};
 use crate::domain::{LogEvent, ScopeDefinition};
 
 impl Job {
@@ -35,7 +36,9 @@ impl Job {
             git_worktree_path: None,
             branch_name: None,
             base_branch: None,
+            base_ref: None,
             changed_files: Vec::new(),
+            out_of_scope_files: Vec::new(),
             log_events: Vec::new(),
             error_message: None,
             source_file,
@@ -55,10 +58,15 @@ impl Job {
             group_id: None,
             ide_context: None,
             force_worktree: false,
+            ignore_budget: false,
             is_repl: false,
             bridge_session_id: None,
+            resume_requested: false,
+            retried_from: None,
+            continued_from: None,
             fork_session: false,
             permission_mode: None,
+            env: HashMap::new(),
             blocked_by: None,
             blocked_file: None,
             chain_step_history: Vec::new(),
@@ -67,10 +75,119 @@ impl Job {
             chain_name: None,
             bugbounty_project_id: None,
             bugbounty_finding_ids: Vec::new(),
+            tags: Vec::new(),
             structured_output: None,
         }
     }
 
+    /// Check that `key` is usable as a per-job environment variable name.
+    ///
+    /// Names must look like `[A-Za-z_][A-Za-z0-9_]*`. The `KYCO_BUGBOUNTY_*` variables
+    /// carry scope enforcement and cannot be overridden per job.
+    pub fn validate_env_key(key: &str) -> Result<(), String> {
+        let mut chars = key.chars();
+        let valid = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid {
+            return Err(format!("invalid environment variable name '{}'", key));
+        }
+        if key.starts_with("KYCO_BUGBOUNTY_") {
+            return Err(format!("environment variable '{}' is reserved", key));
+        }
+        Ok(())
+    }
+
+    /// Parse `KEY=VALUE` strings into a per-job environment map (later entries win).
+    pub fn parse_env_assignments(raw: &[String]) -> Result<HashMap<String, String>, String> {
+        let mut env = HashMap::new();
+        for entry in raw {
+            let Some((key, value)) = entry.split_once('=') else {
+                return Err(format!("invalid env '{}': expected KEY=VALUE", entry));
+            };
+            let key = key.trim();
+            Self::validate_env_key(key)?;
+            env.insert(key.to_string(), value.to_string());
+        }
+        Ok(env)
+    }
+
+    /// Resolve a `--since` value to a cutoff time.
+    ///
+    /// Accepts a duration back from `now` with an `s`/`m`/`h`/`d` suffix (`30m`, `2h`)
+    /// or a timestamp (RFC 3339, or `YYYY-MM-DD[THH:MM:SS]` read as UTC).
+    pub fn parse_since(raw: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+        let raw = raw.trim();
+        let invalid = || {
+            format!(
+                "invalid since '{}': expected a duration like 30m, 2h, 1d or a timestamp",
+                raw
+            )
+        };
+
+        if let Ok(ts) = DateTime::parse_from_rfc3339(raw) {
+            return Ok(ts.with_timezone(&Utc));
+        }
+        if let Ok(ts) = NaiveDateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M:%S") {
+            return Ok(ts.and_utc());
+        }
+        if let Ok(date) = NaiveDate::parse_from_str(raw, "%Y-%m-%d") {
+            return Ok(date.and_hms_opt(0, 0, 0).ok_or_else(invalid)?.and_utc());
+        }
+
+        let unit = raw.chars().last().ok_or_else(invalid)?;
+        let unit_secs: i64 = match unit {
+            's' => 1,
+            'm' => 60,
+            'h' => 60 * 60,
+            'd' => 24 * 60 * 60,
+            _ => return Err(invalid()),
+        };
+        let amount: i64 = raw[..raw.len() - unit.len_utf8()]
+            .parse()
+            .ok()
+            .filter(|n| *n >= 0)
+            .ok_or_else(invalid)?;
+        amount
+            .checked_mul(unit_secs)
+            .and_then(chrono::Duration::try_seconds)
+            .and_then(|d| now.checked_sub_signed(d))
+            .ok_or_else(invalid)
+    }
+
+    /// Normalize user-supplied tags: split on commas, trim, lowercase, drop
+    /// empties and duplicates (first occurrence wins, order preserved).
+    pub fn normalize_tags<S: AsRef<str>>(raw: &[S]) -> Vec<String> {
+        let mut tags: Vec<String> = Vec::new();
+        for tag in raw.iter().flat_map(|r| r.as_ref().split(',')) {
+            let tag = tag.trim().to_lowercase();
+            if !tag.is_empty() && !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        tags
+    }
+
+    /// Whether the job carries `tag` (case-insensitive).
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tag = tag.trim();
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// Whether the job was created or updated at or after `cutoff`.
+    pub fn active_since(&self, cutoff: DateTime<Utc>) -> bool {
+        self.created_at >= cutoff || self.updated_at >= cutoff
+    }
+
+    /// Sort key for "oldest first": creation time, then id.
+    ///
+    /// Ids only follow creation order with `job_id_strategy = "sequential"`; random
+    /// ids say nothing about age, so lists and the queue order by this key instead.
+    pub fn creation_order(&self) -> (DateTime<Utc>, JobId) {
+        (self.created_at, self.id)
+    }
+
     /// Update the job status
     pub fn set_status(&mut self, status: JobStatus) {
         self.status = status;
@@ -132,11 +249,26 @@ impl Job {
         )
     }
 
+    /// Check if the job targets a whole directory instead of a single file
+    pub fn is_dir_target(&self) -> bool {
+        self.scope.dir_path.is_some()
+    }
+
+    /// Scope type used for the `{scope_type}` prompt placeholder ("directory" or "file")
+    pub fn scope_type(&self) -> &'static str {
+        if self.is_dir_target() { "directory" } else { "file" }
+    }
+
     /// Parse agent output and extract the ---kyco result block
     pub fn parse_result(&mut self, output: &str) {
         self.result = JobResult::parse(output);
     }
 
+    /// Parse agent output with a mode-specific result parser
+    pub fn parse_result_with(&mut self, output: &str, parser: &ResultParser) {
+        self.result = parser.parse(output);
+    }
+
     /// Update stats with file change information
     pub fn set_file_stats(
         &mut self,
@@ -158,6 +290,40 @@ impl Job {
         }
     }
 
+    /// Check whether the agent reported any token usage or cost for this job
+    pub fn has_usage(&self) -> bool {
+        self.input_tokens.is_some()
+            || self.output_tokens.is_some()
+            || self.cache_read_tokens.is_some()
+            || self.cache_write_tokens.is_some()
+            || self.cost_usd.is_some()
+    }
+
+    /// Get a formatted token/cost summary (e.g., "12.4k in, 1.2k out, 80.1k cached, $0.0421")
+    pub fn usage_string(&self) -> Option<String> {
+        if !self.has_usage() {
+            return None;
+        }
+
+        let mut parts = Vec::new();
+        if let Some(input) = self.input_tokens {
+            parts.push(format!("{} in", format_token_count(input)));
+        }
+        if let Some(output) = self.output_tokens {
+            parts.push(format!("{} out", format_token_count(output)));
+        }
+        if let Some(cached) = self.cache_read_tokens.filter(|n| *n > 0) {
+            parts.push(format!("{} cached", format_token_count(cached)));
+        }
+        if let Some(written) = self.cache_write_tokens.filter(|n| *n > 0) {
+            parts.push(format!("{} cache write", format_token_count(written)));
+        }
+        if let Some(cost) = self.cost_usd {
+            parts.push(format!("${:.4}", cost));
+        }
+        Some(parts.join(", "))
+    }
+
     /// Get a formatted duration string (e.g., "1m 23s", "45s")
     pub fn duration_string(&self) -> Option<String> {
         let duration = self.stats.as_ref()?.duration?;
@@ -170,3 +336,113 @@ impl Job {
         }
     }
 }
+
+/// Format a token count compactly (e.g., 950, 12.4k, 1.2M)
+fn format_token_count(tokens: u64) -> String {
+    if tokens >= 1_000_000 {
+        format!("{:.1}M", tokens as f64 / 1_000_000.0)
+    } else if tokens >= 1_000 {
+        format!("{:.1}k", tokens as f64 / 1_000.0)
+    } else {
+        tokens.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-05-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn parse_since_accepts_duration_suffixes() {
+        let now = now();
+        let cases = [
+            ("45s", chrono::Duration::seconds(45)),
+            ("30m", chrono::Duration::minutes(30)),
+            ("2h", chrono::Duration::hours(2)),
+            ("1d", chrono::Duration::days(1)),
+            (" 0m ", chrono::Duration::zero()),
+        ];
+        for (raw, back) in cases {
+            assert_eq!(Job::parse_since(raw, now).unwrap(), now - back, "{raw}");
+        }
+    }
+
+    #[test]
+    fn parse_since_accepts_timestamps() {
+        let now = now();
+        let expected = DateTime::parse_from_rfc3339("2024-04-30T08:15:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        for raw in [
+            "2024-04-30T08:15:00Z",
+            "2024-04-30T10:15:00+02:00",
+            "2024-04-30T08:15:00",
+        ] {
+            assert_eq!(Job::parse_since(raw, now).unwrap(), expected, "{raw}");
+        }
+        assert_eq!(
+            Job::parse_since("2024-04-30", now).unwrap(),
+            expected - chrono::Duration::minutes(8 * 60 + 15)
+        );
+    }
+
+    #[test]
+    fn parse_since_rejects_garbage() {
+        let now = now();
+        for raw in ["", "m", "30", "30w", "-5m", "1.5h", "yesterday", "9999999999999999d"] {
+            assert!(Job::parse_since(raw, now).is_err(), "{raw}");
+        }
+    }
+
+    #[test]
+    fn normalize_tags_splits_trims_and_dedupes() {
+        let raw = ["auth, api", " ", "API", "ui,,Auth"];
+        assert_eq!(Job::normalize_tags(&raw), vec!["auth", "api", "ui"]);
+
+        let mut job = Job::new(
+            1,
+            "refactor".into(),
+            ScopeDefinition::file(PathBuf::from("a.rs")),
+            "a.rs".into(),
+            None,
+            "claude".into(),
+            PathBuf::from("a.rs"),
+            1,
+            None,
+        );
+        job.tags = Job::normalize_tags(&raw);
+        assert!(job.has_tag("API"));
+        assert!(!job.has_tag("db"));
+    }
+
+    #[test]
+    fn active_since_is_inclusive_of_the_cutoff() {
+        let mut job = Job::new(
+            1,
+            "refactor".into(),
+            ScopeDefinition::file(PathBuf::from("a.rs")),
+            "a.rs".into(),
+            None,
+            "claude".into(),
+            PathBuf::from("a.rs"),
+            1,
+            None,
+        );
+        let cutoff = now();
+        job.created_at = cutoff - chrono::Duration::hours(3);
+        job.updated_at = cutoff;
+        assert!(job.active_since(cutoff));
+
+        job.updated_at = cutoff - chrono::Duration::seconds(1);
+        assert!(!job.active_since(cutoff));
+
+        job.created_at = cutoff;
+        assert!(job.active_since(cutoff));
+    }
+}
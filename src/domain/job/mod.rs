@@ -1,15 +1,18 @@
 mod impls;
 mod parse;
 mod result;
+mod result_parser;
 mod status;
 mod types;
 
-pub use result::JobResult;
+pub use result::{JobResult, UsageStats};
+pub use result_parser::ResultParser;
 pub use status::JobStatus;
 pub use types::{ChainStepSummary, JobStats};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use super::{AgentGroupId, LogEvent, ScopeDefinition};
@@ -78,9 +81,19 @@ pub struct Job {
     /// The base branch from which the worktree was created (for merging back)
     pub base_branch: Option<String>,
 
+    /// Branch or commit requested as the worktree base (`kyco job start --base`)
+    ///
+    /// None branches off the current HEAD. Implies running in a worktree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_ref: Option<String>,
+
     /// Files changed by this job (populated after execution)
     pub changed_files: Vec<PathBuf>,
 
+    /// Changed files outside the BugBounty project's path scope (populated after execution)
+    #[serde(default)]
+    pub out_of_scope_files: Vec<PathBuf>,
+
     /// Log events from the agent execution
     pub log_events: Vec<LogEvent>,
 
@@ -153,6 +166,10 @@ pub struct Job {
     #[serde(default)]
     pub force_worktree: bool,
 
+    /// Start this job even when the daily `settings.budget` is exhausted
+    #[serde(default)]
+    pub ignore_budget: bool,
+
     /// Legacy: Whether this job ran in Terminal REPL mode
     #[serde(default)]
     pub is_repl: bool,
@@ -162,6 +179,19 @@ pub struct Job {
     #[serde(default)]
     pub bridge_session_id: Option<String>,
 
+    /// Reattach to `bridge_session_id` on the next run instead of starting over
+    /// Set by `kyco job resume` for jobs whose agent process died mid-run
+    #[serde(default)]
+    pub resume_requested: bool,
+
+    /// Failed job this one was created from by `kyco job retry`
+    #[serde(default)]
+    pub retried_from: Option<JobId>,
+
+    /// Session job this one continues (follow-up prompt on the same session)
+    #[serde(default)]
+    pub continued_from: Option<JobId>,
+
     /// Whether to fork the session instead of continuing it
     #[serde(default)]
     pub fork_session: bool,
@@ -171,6 +201,11 @@ pub struct Job {
     #[serde(default)]
     pub permission_mode: Option<String>,
 
+    /// Extra environment variables for this job only
+    /// Merged on top of the agent config's `env` (job values win)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub env: HashMap<String, String>,
+
     /// Job ID that is blocking this job (when status is Blocked)
     /// This happens when another job holds a file lock on the same file
     #[serde(default)]
@@ -205,6 +240,10 @@ pub struct Job {
     #[serde(default)]
     pub bugbounty_finding_ids: Vec<String>,
 
+    /// Free-form labels for organizing and filtering jobs (normalized, see [`Job::normalize_tags`])
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
     /// SDK Structured Output (validated JSON from json_schema outputFormat)
     /// Used for displaying findings, memory, and other structured data
     #[serde(default)]
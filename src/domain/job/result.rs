@@ -24,6 +24,50 @@ pub struct JobResult {
     pub next_context: Option<serde_json::Value>,
     /// Raw text output when no structured YAML is found
     pub raw_text: Option<String>,
+    /// Token/cost usage reported by the agent for this run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<UsageStats>,
+}
+
+/// Token, cost and tool usage of an agent run (as reported by the SDK)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UsageStats {
+    /// Fresh (non-cached) input tokens
+    pub input_tokens: u64,
+    /// Output tokens generated
+    pub output_tokens: u64,
+    /// Input tokens served from the prompt cache
+    pub cached_tokens: u64,
+    /// Total cost in USD
+    pub cost_usd: Option<f64>,
+    /// Wall-clock duration in milliseconds
+    pub duration_ms: Option<u64>,
+    /// Number of tool calls made by the agent
+    pub tool_calls: Option<u64>,
+}
+
+impl UsageStats {
+    /// Total tokens (input + output + cached)
+    pub fn total_tokens(&self) -> u64 {
+        self.input_tokens + self.output_tokens + self.cached_tokens
+    }
+
+    /// Add another run's usage (e.g. to total a chain's steps)
+    pub fn merge(&mut self, other: &UsageStats) {
+        fn sum<T: std::ops::Add<Output = T> + Copy>(a: Option<T>, b: Option<T>) -> Option<T> {
+            match (a, b) {
+                (Some(a), Some(b)) => Some(a + b),
+                (a, b) => a.or(b),
+            }
+        }
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cached_tokens += other.cached_tokens;
+        self.cost_usd = sum(self.cost_usd, other.cost_usd);
+        self.duration_ms = sum(self.duration_ms, other.duration_ms);
+        self.tool_calls = sum(self.tool_calls, other.tool_calls);
+    }
 }
 
 impl JobResult {
@@ -32,7 +76,8 @@ impl JobResult {
     /// Supports multiple formats:
     /// 1. Standard YAML front matter with `---` markers
     /// 2. Legacy `---kyco` markers (backwards compatibility)
-    /// 3. Falls back to raw text if no YAML structure found
+    /// 3. JSON in a fenced `kyco-result` block, or after a `KYCO_RESULT:` marker
+    /// 4. Falls back to raw text if no YAML structure found
     ///
     /// ```yaml
     /// ---
@@ -57,6 +102,10 @@ impl JobResult {
             .unwrap_or(Cow::Borrowed(trimmed));
         let output = output.as_ref().trim();
 
+        // Explicitly labelled JSON wins over any YAML-looking prose around it
+        if let Some(result) = Self::parse_fenced_json_block(output) {
+            return Some(result);
+        }
         if let Some(result) = Self::parse_yaml_block(output, "---kyco") {
             return Some(result);
         }
@@ -80,7 +129,7 @@ impl JobResult {
         None
     }
 
-    fn parse_json_block(output: &str) -> Option<Self> {
+    pub(super) fn parse_json_block(output: &str) -> Option<Self> {
         let raw = output.trim();
         if !raw.starts_with('{') {
             return None;
@@ -139,7 +188,7 @@ impl JobResult {
         if has_structured { Some(result) } else { None }
     }
 
-    fn parse_yaml_content(yaml_content: &str) -> Option<Self> {
+    pub(super) fn parse_yaml_content(yaml_content: &str) -> Option<Self> {
         let yaml_content = yaml_content.trim();
 
         if yaml_content.is_empty() || yaml_content.len() < 5 {
@@ -218,6 +267,53 @@ impl JobResult {
         if has_structured { Some(result) } else { None }
     }
 
+    /// Parse JSON from the last ```` ```kyco-result ```` fence or `KYCO_RESULT:` marker.
+    ///
+    /// After the marker, the JSON may sit in a ```` ```json ```` fence (same or next
+    /// line) or follow as a bare object. Prose around the block is ignored.
+    fn parse_fenced_json_block(output: &str) -> Option<Self> {
+        let lines: Vec<&str> = output.lines().collect();
+
+        for (idx, line) in lines.iter().enumerate().rev() {
+            let trimmed = line.trim();
+            let json = if trimmed == "```kyco-result" {
+                fenced_block_body(&lines, idx + 1)
+            } else if let Some(rest) = trimmed.strip_prefix("KYCO_RESULT:") {
+                let rest = rest.trim();
+                if is_json_fence(rest) {
+                    fenced_block_body(&lines, idx + 1)
+                } else if rest.is_empty() {
+                    lines
+                        .iter()
+                        .enumerate()
+                        .skip(idx + 1)
+                        .find(|(_, l)| !l.trim().is_empty())
+                        .and_then(|(next, l)| {
+                            if is_json_fence(l.trim()) {
+                                fenced_block_body(&lines, next + 1)
+                            } else {
+                                leading_json_object(&lines[next..].join("\n"))
+                            }
+                        })
+                } else {
+                    let tail = std::iter::once(rest)
+                        .chain(lines[idx + 1..].iter().copied())
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    leading_json_object(&tail)
+                }
+            } else {
+                continue;
+            };
+
+            if let Some(result) = json.and_then(|json| Self::parse_json_block(&json)) {
+                return Some(result);
+            }
+        }
+
+        None
+    }
+
     /// Parse a YAML block with a specific start marker
     fn parse_yaml_block(output: &str, start_marker: &str) -> Option<Self> {
         let end_marker = "---";
@@ -282,9 +378,61 @@ impl JobResult {
     }
 }
 
+fn is_json_fence(line: &str) -> bool {
+    line == "```json" || line == "```"
+}
+
+/// Lines from `start` up to the closing ```` ``` ```` fence, if it exists.
+fn fenced_block_body(lines: &[&str], start: usize) -> Option<String> {
+    let len = lines.get(start..)?.iter().position(|l| l.trim() == "```")?;
+    Some(lines[start..start + len].join("\n"))
+}
+
+/// The first complete JSON object at the start of `text`, ignoring what follows.
+fn leading_json_object(text: &str) -> Option<String> {
+    let text = text.trim_start();
+    if !text.starts_with('{') {
+        return None;
+    }
+    let mut stream = serde_json::Deserializer::from_str(text).into_iter::<serde_json::Value>();
+    stream.next()?.ok()?;
+    Some(text[..stream.byte_offset()].to_string())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::JobResult;
+    use super::{JobResult, UsageStats};
+
+    #[test]
+    fn usage_defaults_for_old_persisted_results_and_merges() {
+        let old: JobResult = serde_json::from_str(r#"{"title":"t","status":"success"}"#).unwrap();
+        assert!(old.usage.is_none());
+
+        let partial: UsageStats = serde_json::from_str(r#"{"input_tokens":10}"#).unwrap();
+        assert_eq!(partial.output_tokens, 0);
+        assert_eq!(partial.cost_usd, None);
+
+        let mut total = UsageStats {
+            input_tokens: 100,
+            output_tokens: 20,
+            cached_tokens: 5,
+            cost_usd: Some(0.5),
+            duration_ms: Some(1000),
+            tool_calls: None,
+        };
+        total.merge(&UsageStats {
+            input_tokens: 1,
+            output_tokens: 2,
+            cached_tokens: 3,
+            cost_usd: Some(0.25),
+            duration_ms: None,
+            tool_calls: Some(4),
+        });
+        assert_eq!(total.total_tokens(), 131);
+        assert_eq!(total.cost_usd, Some(0.75));
+        assert_eq!(total.duration_ms, Some(1000));
+        assert_eq!(total.tool_calls, Some(4));
+    }
 
     #[test]
     fn parse_unwraps_json_string_literal_then_parses_yaml_block() {
@@ -361,4 +509,54 @@ summary: |
         assert_eq!(result.state.as_deref(), Some("implemented"));
         assert!(result.title.is_none());
     }
+
+    #[test]
+    fn parse_prefers_fenced_kyco_result_block_over_prose() {
+        let output = r#"Here is what I did.
+
+---
+title: not the result
+---
+
+```kyco-result
+{"title": "Fix null check", "status": "success", "state": "fixed"}
+```
+
+Let me know if you need anything else."#;
+
+        let result = JobResult::parse(output).expect("parse");
+        assert_eq!(result.title.as_deref(), Some("Fix null check"));
+        assert_eq!(result.state.as_deref(), Some("fixed"));
+        assert!(result.raw_text.is_none());
+    }
+
+    #[test]
+    fn parse_reads_json_after_kyco_result_marker() {
+        let fenced = r#"Done.
+KYCO_RESULT:
+```json
+{"title": "Fenced", "status": "success"}
+```
+Bye"#;
+        let result = JobResult::parse(fenced).expect("parse");
+        assert_eq!(result.title.as_deref(), Some("Fenced"));
+
+        let bare = r#"Done.
+KYCO_RESULT: {"title": "Bare", "state": "done"} trailing prose"#;
+        let result = JobResult::parse(bare).expect("parse");
+        assert_eq!(result.title.as_deref(), Some("Bare"));
+        assert_eq!(result.state.as_deref(), Some("done"));
+    }
+
+    #[test]
+    fn parse_still_accepts_bare_json_output() {
+        let result = JobResult::parse(r#"{"title": "Plain", "summary": "ok"}"#).expect("parse");
+        assert_eq!(result.title.as_deref(), Some("Plain"));
+        assert_eq!(result.summary.as_deref(), Some("ok"));
+
+        // An unlabelled fence is not a result block; the output stays raw text
+        let result = JobResult::parse("```json\n{\"title\": \"x\"}\n```").expect("parse");
+        assert!(result.title.is_none());
+        assert!(result.raw_text.is_some());
+    }
 }
@@ -0,0 +1,221 @@
+//! Pluggable result parsers for agent output.
+//!
+//! KYCo's default parser expects a `---` YAML block (or SDK JSON). Modes whose
+//! agents emit a different format can select another parser via `result_parser`
+//! (and `result_pattern` for regex) to still populate `state`/`summary`/`details`.
+
+use regex::Regex;
+
+use super::JobResult;
+
+/// How to extract a [`JobResult`] from agent output
+#[derive(Debug, Clone, Default)]
+pub enum ResultParser {
+    /// KYCo's structured format (`---` YAML block, `---kyco`, or SDK JSON)
+    #[default]
+    Default,
+    /// A JSON object (whole output, a fenced ```json block, or the last `{...}` object)
+    Json,
+    /// A YAML mapping (whole output or a fenced ```yaml block)
+    Yaml,
+    /// A regex whose named groups (title, summary, details, state, status,
+    /// commit_subject, commit_body) populate the matching fields
+    Regex(Regex),
+}
+
+/// Named groups recognized by [`ResultParser::Regex`]
+const REGEX_FIELDS: &[&str] = &[
+    "title",
+    "commit_subject",
+    "commit_body",
+    "details",
+    "status",
+    "summary",
+    "state",
+];
+
+impl ResultParser {
+    /// Build a parser from mode/skill config (`result_parser` + `result_pattern`).
+    pub fn from_config(kind: Option<&str>, pattern: Option<&str>) -> Result<Self, String> {
+        match kind.map(|k| k.trim().to_lowercase()).as_deref() {
+            None | Some("") | Some("default") | Some("kyco") => Ok(Self::Default),
+            Some("json") => Ok(Self::Json),
+            Some("yaml") | Some("yml") => Ok(Self::Yaml),
+            Some("regex") => {
+                let pattern = pattern
+                    .map(str::trim)
+                    .filter(|p| !p.is_empty())
+                    .ok_or_else(|| "result_parser = \"regex\" requires result_pattern".to_string())?;
+                let re = Regex::new(pattern)
+                    .map_err(|e| format!("Invalid result_pattern: {}", e))?;
+                if !re
+                    .capture_names()
+                    .flatten()
+                    .any(|name| REGEX_FIELDS.contains(&name))
+                {
+                    return Err(format!(
+                        "result_pattern has no named groups for {}",
+                        REGEX_FIELDS.join("/")
+                    ));
+                }
+                Ok(Self::Regex(re))
+            }
+            Some(other) => Err(format!(
+                "Unknown result_parser '{}' (expected default, json, yaml, or regex)",
+                other
+            )),
+        }
+    }
+
+    /// Parse agent output, falling back to raw text when nothing structured is found.
+    pub fn parse(&self, output: &str) -> Option<JobResult> {
+        let trimmed = output.trim();
+        if trimmed.is_empty() {
+            return None;
+        }
+
+        let parsed = match self {
+            Self::Default => return JobResult::parse(output),
+            Self::Json => parse_json(trimmed),
+            Self::Yaml => parse_yaml(trimmed),
+            Self::Regex(re) => parse_regex(re, trimmed),
+        };
+
+        parsed.or_else(|| {
+            Some(JobResult {
+                raw_text: Some(trimmed.to_string()),
+                ..Default::default()
+            })
+        })
+    }
+}
+
+/// Extract the body of the last fenced code block with one of the given languages
+fn fenced_block<'a>(output: &'a str, languages: &[&str]) -> Option<&'a str> {
+    let mut found = None;
+    let mut rest = output;
+    while let Some(start) = rest.find("```") {
+        let after = &rest[start + 3..];
+        let Some(line_end) = after.find('\n') else { break };
+        let lang = after[..line_end].trim().to_lowercase();
+        let body = &after[line_end + 1..];
+        let Some(end) = body.find("```") else { break };
+        if languages.contains(&lang.as_str()) {
+            found = Some(&body[..end]);
+        }
+        rest = &body[end + 3..];
+    }
+    found
+}
+
+fn parse_json(output: &str) -> Option<JobResult> {
+    if let Some(result) = JobResult::parse_json_block(output) {
+        return Some(result);
+    }
+    if let Some(block) = fenced_block(output, &["json"]) {
+        if let Some(result) = JobResult::parse_json_block(block) {
+            return Some(result);
+        }
+    }
+
+    // Last resort: the last `{` that starts a parseable object running to the final `}`
+    let end = output.rfind('}')?;
+    output[..end]
+        .match_indices('{')
+        .rev()
+        .find_map(|(start, _)| JobResult::parse_json_block(&output[start..=end]))
+}
+
+fn parse_yaml(output: &str) -> Option<JobResult> {
+    if let Some(block) = fenced_block(output, &["yaml", "yml"]) {
+        if let Some(result) = JobResult::parse_yaml_content(block) {
+            return Some(result);
+        }
+    }
+    JobResult::parse_yaml_content(output)
+}
+
+fn parse_regex(re: &Regex, output: &str) -> Option<JobResult> {
+    let caps = re.captures(output)?;
+    let get = |name: &str| {
+        caps.name(name)
+            .map(|m| m.as_str().trim().to_string())
+            .filter(|s| !s.is_empty())
+    };
+
+    let result = JobResult {
+        title: get("title"),
+        commit_subject: get("commit_subject"),
+        commit_body: get("commit_body"),
+        details: get("details"),
+        status: get("status"),
+        summary: get("summary"),
+        state: get("state"),
+        ..Default::default()
+    };
+
+    let has_structured = REGEX_FIELDS.iter().any(|name| get(name).is_some());
+    if has_structured { Some(result) } else { None }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResultParser;
+
+    #[test]
+    fn json_parser_finds_fenced_block() {
+        let parser = ResultParser::from_config(Some("json"), None).unwrap();
+        let output = "Done.\n```json\n{\"state\": \"fixed\", \"summary\": \"Patched it\"}\n```\n";
+        let result = parser.parse(output).expect("result");
+        assert_eq!(result.state.as_deref(), Some("fixed"));
+        assert_eq!(result.summary.as_deref(), Some("Patched it"));
+    }
+
+    #[test]
+    fn json_parser_finds_trailing_object() {
+        let parser = ResultParser::Json;
+        let output = "Some notes {not json}\nResult: {\"title\": \"T\", \"state\": \"ok\"}";
+        let result = parser.parse(output).expect("result");
+        assert_eq!(result.title.as_deref(), Some("T"));
+        assert_eq!(result.state.as_deref(), Some("ok"));
+    }
+
+    #[test]
+    fn yaml_parser_reads_plain_mapping() {
+        let parser = ResultParser::from_config(Some("yaml"), None).unwrap();
+        let result = parser
+            .parse("state: issues_found\ndetails: Two bugs")
+            .expect("result");
+        assert_eq!(result.state.as_deref(), Some("issues_found"));
+        assert_eq!(result.details.as_deref(), Some("Two bugs"));
+    }
+
+    #[test]
+    fn regex_parser_uses_named_groups() {
+        let parser = ResultParser::from_config(
+            Some("regex"),
+            Some(r"(?m)^RESULT: (?P<state>\w+)\s*\n(?P<summary>(?s:.*))"),
+        )
+        .unwrap();
+        let result = parser
+            .parse("log line\nRESULT: tests_pass\nAll 12 tests passed.")
+            .expect("result");
+        assert_eq!(result.state.as_deref(), Some("tests_pass"));
+        assert_eq!(result.summary.as_deref(), Some("All 12 tests passed."));
+    }
+
+    #[test]
+    fn falls_back_to_raw_text() {
+        let result = ResultParser::Json.parse("no structure here").expect("result");
+        assert!(result.state.is_none());
+        assert_eq!(result.raw_text.as_deref(), Some("no structure here"));
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        assert!(ResultParser::from_config(Some("xml"), None).is_err());
+        assert!(ResultParser::from_config(Some("regex"), None).is_err());
+        assert!(ResultParser::from_config(Some("regex"), Some("(?P<other>.*)")).is_err());
+        assert!(ResultParser::from_config(None, None).is_ok());
+    }
+}
@@ -23,6 +23,25 @@ pub enum JobStatus {
 }
 
 impl JobStatus {
+    /// All statuses, in lifecycle order
+    pub const ALL: [JobStatus; 8] = [
+        JobStatus::Pending,
+        JobStatus::Queued,
+        JobStatus::Blocked,
+        JobStatus::Running,
+        JobStatus::Done,
+        JobStatus::Failed,
+        JobStatus::Rejected,
+        JobStatus::Merged,
+    ];
+
+    /// Parse a status marker (case-insensitive), the inverse of [`JobStatus::as_marker`]
+    pub fn from_marker(s: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.as_marker().eq_ignore_ascii_case(s.trim()))
+    }
+
     /// Get the status marker string used in comments
     pub fn as_marker(&self) -> &'static str {
         match self {
@@ -1,6 +1,17 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+/// Which global log events the UI keeps (`settings.gui.log_level`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    /// Keep every event
+    #[default]
+    All,
+    /// Keep system, error and permission events; drop agent chatter
+    System,
+}
+
 /// The kind of log event from an agent
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -132,4 +143,41 @@ impl LogEvent {
         self.tool_args = Some(args);
         self
     }
+
+    /// Whether this event is kept at the given log level
+    pub fn matches_level(&self, level: LogLevel) -> bool {
+        match level {
+            LogLevel::All => true,
+            LogLevel::System => matches!(
+                self.kind,
+                LogEventKind::System | LogEventKind::Error | LogEventKind::Permission
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn system_level_keeps_only_system_error_and_permission_events() {
+        let kept = [
+            LogEvent::system("started"),
+            LogEvent::error("failed"),
+            LogEvent::permission("approve Bash?"),
+        ];
+        let dropped = [
+            LogEvent::thought("thinking"),
+            LogEvent::text("done"),
+            LogEvent::tool_call("Read", "Read src/lib.rs"),
+            LogEvent::tool_output("Read", "42 lines"),
+        ];
+
+        for event in kept.iter().chain(&dropped) {
+            assert!(event.matches_level(LogLevel::All));
+        }
+        assert!(kept.iter().all(|e| e.matches_level(LogLevel::System)));
+        assert!(!dropped.iter().any(|e| e.matches_level(LogLevel::System)));
+    }
 }
@@ -8,13 +8,18 @@ mod log_event;
 mod scope;
 mod target;
 
+#[cfg(test)]
+pub(crate) mod test_support;
+
 pub use agent::{
-    AgentConfig, ClaudeAgentDefinition, CliType, McpServerConfig, ModeTemplate, SdkType,
-    SkillTemplate, SystemPromptMode,
+    AgentConfig, ClaudeAgentDefinition, CliType, McpServerConfig, ModeTemplate, RetryPolicy,
+    SdkType, SkillTemplate, SystemPromptMode,
 };
-pub use agent_group::{AgentGroupId, AgentRunGroup, GroupStatus};
+pub use agent_group::{AgentGroupId, AgentRunGroup, AutoSelectPolicy, GroupStatus};
 pub use comment::{CommentTag, StatusMarker};
-pub use job::{ChainStepSummary, Job, JobId, JobResult, JobStats, JobStatus};
-pub use log_event::{LogEvent, LogEventKind};
+pub use job::{
+    ChainStepSummary, Job, JobId, JobResult, JobStats, JobStatus, ResultParser, UsageStats,
+};
+pub use log_event::{LogEvent, LogEventKind, LogLevel};
 pub use scope::ScopeDefinition;
 pub use target::Target;
@@ -0,0 +1,42 @@
+//! Job and comment tag fixtures shared by unit tests
+
+use std::path::PathBuf;
+
+use chrono::{DateTime, Duration};
+
+use super::{CommentTag, Job, JobId, ScopeDefinition, Target};
+
+/// Pending `fix` job for `claude` on `src/lib.rs`, created `id` seconds after the epoch
+/// (so creation order follows the id)
+pub(crate) fn job(id: JobId) -> Job {
+    let mut job = Job::new(
+        id,
+        "fix".to_string(),
+        ScopeDefinition::file(PathBuf::from("src/lib.rs")),
+        "src/lib.rs".to_string(),
+        None,
+        "claude".to_string(),
+        PathBuf::from("src/lib.rs"),
+        1,
+        None,
+    );
+    job.created_at = DateTime::UNIX_EPOCH + Duration::seconds(id as i64);
+    job
+}
+
+/// `refactor` tag for `claude` on the first line of `file_path`
+pub(crate) fn comment_tag(file_path: PathBuf) -> CommentTag {
+    let description = format!("work on {}", file_path.display());
+    CommentTag {
+        file_path,
+        line_number: 1,
+        raw_line: String::new(),
+        agent: "claude".to_string(),
+        agents: vec!["claude".to_string()],
+        mode: "refactor".to_string(),
+        target: Target::Block,
+        status_marker: None,
+        description: Some(description),
+        job_id: None,
+    }
+}
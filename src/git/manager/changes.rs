@@ -4,8 +4,8 @@ use anyhow::{Context, Result, bail};
 use std::path::Path;
 use std::process::Command;
 
-use super::types::CommitMessage;
-use super::GitManager;
+use super::types::{CommitMessage, GitError};
+use super::{GitManager, merge_tree_write_supported};
 
 impl GitManager {
     /// Merge a worktree branch into the specified base branch
@@ -13,20 +13,83 @@ impl GitManager {
     /// This performs a proper git merge of the worktree's branch into the base branch.
     /// If there are uncommitted changes in the worktree, they are committed first.
     /// The base_branch parameter specifies which branch to merge into.
+    ///
+    /// Fails with [`GitError::BaseDiverged`] if the base branch moved since the
+    /// worktree was created; use [`Self::apply_changes_allow_diverged`] to merge anyway.
+    /// Other expected failures are reported as [`GitError`].
     pub fn apply_changes(
         &self,
         worktree: &Path,
         base_branch: &str,
         commit_message: Option<&CommitMessage>,
     ) -> Result<()> {
+        self.ensure_local_branch(base_branch)?;
+        if let Some(diverged) = self.base_divergence(worktree, base_branch)? {
+            return Err(diverged.into());
+        }
+        self.apply_changes_allow_diverged(worktree, base_branch, commit_message)
+    }
+
+    /// Like [`Self::apply_changes`], but merges even if the base branch has moved.
+    pub fn apply_changes_allow_diverged(
+        &self,
+        worktree: &Path,
+        base_branch: &str,
+        commit_message: Option<&CommitMessage>,
+    ) -> Result<()> {
+        self.merge_worktree_branch(worktree, base_branch, commit_message, false)
+            .map(|_| ())
+    }
+
+    /// Squash-merge a worktree into `base_branch` as a single commit.
+    ///
+    /// Unlike [`Self::apply_changes`], the worktree's own commits are not carried
+    /// over: the base branch gains exactly one commit with `commit_message`.
+    /// Returns the new commit's hash, or `None` if there was nothing to merge
+    /// (checked before divergence, so re-merging an applied worktree is a no-op).
+    pub fn merge_worktree_as_commit(
+        &self,
+        worktree: &Path,
+        base_branch: &str,
+        commit_message: &CommitMessage,
+        allow_diverged: bool,
+    ) -> Result<Option<String>> {
+        if !worktree.exists() {
+            return Err(GitError::WorktreeMissing(worktree.to_path_buf()).into());
+        }
+        self.ensure_local_branch(base_branch)?;
+        if self.nothing_to_merge(worktree, base_branch)? {
+            return Ok(None);
+        }
+        if !allow_diverged {
+            if let Some(diverged) = self.base_divergence(worktree, base_branch)? {
+                return Err(diverged.into());
+            }
+        }
+        self.merge_worktree_branch(worktree, base_branch, Some(commit_message), true)
+    }
+
+    /// Merge the worktree's branch into `base_branch`, restoring the current branch after.
+    ///
+    /// With `squash`, the merge is staged and committed as one commit whose hash is returned.
+    fn merge_worktree_branch(
+        &self,
+        worktree: &Path,
+        base_branch: &str,
+        commit_message: Option<&CommitMessage>,
+        squash: bool,
+    ) -> Result<Option<String>> {
+        if !worktree.exists() {
+            return Err(GitError::WorktreeMissing(worktree.to_path_buf()).into());
+        }
+        self.ensure_local_branch(base_branch)?;
+
         // Avoid merging into a dirty working tree.
         // We ignore untracked files here (e.g., `.kyco/` artifacts) and only block
         // on tracked/staged changes that would make the merge surprising or unsafe.
-        if self.has_tracked_uncommitted_changes()? {
-            bail!(
-                "Cannot apply changes: repository has uncommitted changes. \
-                 Please commit or stash them first."
-            );
+        let dirty = self.tracked_dirty_files()?;
+        if !dirty.is_empty() {
+            return Err(GitError::Dirty(dirty).into());
         }
 
         let status_output = Command::new("git")
@@ -80,8 +143,13 @@ impl GitManager {
             }
         }
 
+        let merge_args: &[&str] = if squash {
+            &["merge", "--squash", &worktree_branch]
+        } else {
+            &["merge", &worktree_branch, "--no-edit"]
+        };
         let merge_output = Command::new("git")
-            .args(["merge", &worktree_branch, "--no-edit"])
+            .args(merge_args)
             .current_dir(self.root())
             .output()
             .context("Failed to merge branch")?;
@@ -90,14 +158,10 @@ impl GitManager {
             let stderr = String::from_utf8_lossy(&merge_output.stderr)
                 .trim()
                 .to_string();
+            let conflicts = self.conflicted_files();
 
             // Try to abort merge so we don't leave the user's repo in a conflicted "merge in progress" state.
-            let aborted = Command::new("git")
-                .args(["merge", "--abort"])
-                .current_dir(self.root())
-                .output()
-                .map(|o| o.status.success())
-                .unwrap_or(false);
+            let aborted = self.abort_merge(squash);
 
             // If merge failed and we changed branches, try to restore original branch.
             if should_restore_branch {
@@ -108,7 +172,13 @@ impl GitManager {
             }
 
             if aborted {
-                bail!("git merge failed (merge was aborted): {}", stderr);
+                if !conflicts.is_empty() {
+                    return Err(GitError::MergeConflict(conflicts).into());
+                }
+                return Err(
+                    GitError::Other(format!("git merge failed (merge was aborted): {}", stderr))
+                        .into(),
+                );
             }
 
             bail!(
@@ -117,6 +187,34 @@ impl GitManager {
             );
         }
 
+        let nothing_staged = || {
+            Command::new("git")
+                .args(["diff", "--cached", "--quiet"])
+                .current_dir(self.root())
+                .status()
+                .is_ok_and(|s| s.success())
+        };
+        let commit = if squash && !nothing_staged() {
+            let fallback = CommitMessage::new("Merge worktree changes", None);
+            match self.commit_staged_in_dir(self.root(), commit_message.unwrap_or(&fallback)) {
+                Ok(true) => Some(self.head_sha()?),
+                Ok(false) => None,
+                Err(e) => {
+                    // Drop the staged squash so the base branch is left as it was
+                    self.abort_merge(true);
+                    if should_restore_branch {
+                        let _ = Command::new("git")
+                            .args(["checkout", &current_branch])
+                            .current_dir(self.root())
+                            .output();
+                    }
+                    return Err(e);
+                }
+            }
+        } else {
+            None
+        };
+
         // Restore the original branch (avoid surprising the user by leaving the repo on base_branch).
         if should_restore_branch {
             let checkout_output = Command::new("git")
@@ -134,7 +232,91 @@ impl GitManager {
             }
         }
 
-        Ok(())
+        Ok(commit)
+    }
+
+    /// Checking out anything but a local branch would detach HEAD and orphan the merge.
+    fn ensure_local_branch(&self, base_branch: &str) -> Result<()> {
+        if self.is_local_branch(base_branch) {
+            Ok(())
+        } else {
+            Err(GitError::NotLocalBranch(base_branch.to_string()).into())
+        }
+    }
+
+    /// Files left unmerged by a failed merge in the main working tree.
+    fn conflicted_files(&self) -> Vec<String> {
+        Command::new("git")
+            .args(["diff", "--name-only", "--diff-filter=U", "-z"])
+            .current_dir(self.root())
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| super::types::parse_null_delimited(&o.stdout))
+            .unwrap_or_default()
+    }
+
+    /// Undo a failed or unwanted merge; a squash merge has no MERGE_HEAD to abort.
+    fn abort_merge(&self, squash: bool) -> bool {
+        let args: &[&str] = if squash {
+            &["reset", "--merge"]
+        } else {
+            &["merge", "--abort"]
+        };
+        Command::new("git")
+            .args(args)
+            .current_dir(self.root())
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    }
+
+    /// Check whether `base_branch` gained commits since the worktree branched off it.
+    ///
+    /// Returns `Some(GitError::BaseDiverged)` when the fork point of the worktree's
+    /// HEAD differs from the current tip of `base_branch`. The fork point is the
+    /// start commit recorded by `create_worktree` for `--base` worktrees, else the
+    /// merge base.
+    pub fn base_divergence(&self, worktree: &Path, base_branch: &str) -> Result<Option<GitError>> {
+        let git = |args: &[&str], dir: &Path| -> Result<String> {
+            let output = Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .with_context(|| format!("Failed to run git {}", args.join(" ")))?;
+            if !output.status.success() {
+                bail!(
+                    "git {} failed: {}",
+                    args.join(" "),
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+            }
+            Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        };
+
+        let base_head = git(&["rev-parse", base_branch], self.root())?;
+        let fork_point = match self.recorded_base_commit(worktree) {
+            Some(sha) => sha,
+            None => git(&["merge-base", base_branch, "HEAD"], worktree)?,
+        };
+        if fork_point == base_head {
+            return Ok(None);
+        }
+
+        let range = format!("{}..{}", fork_point, base_head);
+        let new_commits = git(&["rev-list", "--count", &range], self.root())?
+            .parse()
+            .unwrap_or(0);
+        if new_commits == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(GitError::BaseDiverged {
+            base_branch: base_branch.to_string(),
+            fork_point,
+            base_head,
+            new_commits,
+        }))
     }
 
     /// Commit current changes in the repository root.
@@ -168,6 +350,11 @@ impl GitManager {
             );
         }
 
+        self.commit_staged_in_dir(dir, commit_message)
+    }
+
+    /// Commit whatever is already staged in `dir`. Returns `true` if a commit was created.
+    fn commit_staged_in_dir(&self, dir: &Path, commit_message: &CommitMessage) -> Result<bool> {
         let mut commit_cmd = Command::new("git");
         commit_cmd
             .arg("commit")
@@ -199,4 +386,142 @@ impl GitManager {
 
         bail!("git commit failed: {}", stderr.trim());
     }
+
+    /// List files that would conflict when merging a worktree into `base_branch`.
+    ///
+    /// Uncommitted worktree changes are included via a temporary snapshot commit.
+    /// Uses `git merge-tree --write-tree` (git >= 2.38), so neither the repository's
+    /// working tree nor its index is touched. Fails on older git; the merge itself
+    /// still aborts cleanly on conflicts there.
+    pub fn merge_conflicts(&self, worktree: &Path, base_branch: &str) -> Result<Vec<String>> {
+        if !merge_tree_write_supported() {
+            bail!("conflict preview needs git >= 2.38 (`git merge-tree --write-tree`)");
+        }
+        let head = self.worktree_snapshot_commit(worktree)?;
+
+        let output = Command::new("git")
+            .args([
+                "merge-tree",
+                "--write-tree",
+                "--name-only",
+                "--no-messages",
+                base_branch,
+                &head,
+            ])
+            .current_dir(self.root())
+            .output()
+            .context("Failed to run git merge-tree")?;
+
+        match output.status.code() {
+            Some(0) => Ok(Vec::new()),
+            // Exit code 1: merge has conflicts. First line is the tree id, then conflicted paths.
+            Some(1) => {
+                let mut files: Vec<String> = String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .skip(1)
+                    .map(str::trim)
+                    .filter(|l| !l.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                files.dedup();
+                Ok(files)
+            }
+            _ => bail!(
+                "git merge-tree failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        }
+    }
+
+    /// Whether merging the worktree (uncommitted changes included) would leave
+    /// `base_branch` unchanged, e.g. because its changes were already squash-merged.
+    ///
+    /// Git older than 2.38 cannot merge in memory; then this is `false` and the
+    /// merge runs (a squash with nothing staged creates no commit).
+    pub fn nothing_to_merge(&self, worktree: &Path, base_branch: &str) -> Result<bool> {
+        if !merge_tree_write_supported() {
+            return Ok(false);
+        }
+        let head = self.worktree_snapshot_commit(worktree)?;
+
+        let output = Command::new("git")
+            .args(["merge-tree", "--write-tree", "--no-messages", base_branch, &head])
+            .current_dir(self.root())
+            .output()
+            .context("Failed to run git merge-tree")?;
+        match output.status.code() {
+            Some(0) => {}
+            // Conflicts: there is definitely something (conflicting) to merge
+            Some(1) => return Ok(false),
+            _ => bail!(
+                "git merge-tree failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let merged_tree = stdout.lines().next().unwrap_or_default().trim();
+
+        let base_tree = Command::new("git")
+            .args(["rev-parse", &format!("{}^{{tree}}", base_branch)])
+            .current_dir(self.root())
+            .output()
+            .context("Failed to resolve base tree")?;
+        if !base_tree.status.success() {
+            bail!(
+                "git rev-parse failed: {}",
+                String::from_utf8_lossy(&base_tree.stderr).trim()
+            );
+        }
+        Ok(String::from_utf8_lossy(&base_tree.stdout).trim() == merged_tree)
+    }
+
+    /// Commit id representing the worktree's current state (HEAD if clean).
+    fn worktree_snapshot_commit(&self, worktree: &Path) -> Result<String> {
+        let run = |args: &[&str], index: Option<&Path>| -> Result<String> {
+            let mut cmd = Command::new("git");
+            cmd.args(args).current_dir(worktree);
+            if let Some(index) = index {
+                cmd.env("GIT_INDEX_FILE", index);
+            }
+            // Snapshot commits are never referenced by a branch; don't require user identity.
+            cmd.env("GIT_AUTHOR_NAME", "kyco")
+                .env("GIT_AUTHOR_EMAIL", "kyco@localhost")
+                .env("GIT_COMMITTER_NAME", "kyco")
+                .env("GIT_COMMITTER_EMAIL", "kyco@localhost");
+            let output = cmd
+                .output()
+                .with_context(|| format!("Failed to run git {}", args.join(" ")))?;
+            if !output.status.success() {
+                bail!(
+                    "git {} failed: {}",
+                    args.join(" "),
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+            }
+            Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        };
+
+        let head = run(&["rev-parse", "HEAD"], None)?;
+        if run(&["status", "--porcelain"], None)?.is_empty() {
+            return Ok(head);
+        }
+
+        // Stage everything into a throwaway index so the worktree's own index is untouched.
+        let index = std::env::temp_dir().join(format!(
+            "kyco-snapshot-index-{}-{}",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let result = (|| {
+            run(&["read-tree", "HEAD"], Some(&index))?;
+            run(&["add", "-A"], Some(&index))?;
+            let tree = run(&["write-tree"], Some(&index))?;
+            run(
+                &["commit-tree", &tree, "-p", &head, "-m", "kyco snapshot"],
+                Some(&index),
+            )
+        })();
+        let _ = std::fs::remove_file(&index);
+        result
+    }
 }
@@ -8,11 +8,15 @@ mod worktree;
 #[cfg(test)]
 mod tests;
 
-pub use types::{CommitMessage, DiffReport, DiffSettings, FileDiff, FileStatus, WorktreeInfo};
+pub use types::{
+    CommitMessage, DiffReport, DiffSettings, FileDiff, FileStatus, GitError, ManagedWorktree,
+    WorktreeInfo,
+};
 
 use anyhow::{Context, Result, bail};
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::OnceLock;
 
 /// Find the git repository root for a given path.
 /// Returns None if the path is not inside a git repository.
@@ -37,6 +41,30 @@ pub fn find_git_root(path: &Path) -> Option<PathBuf> {
     }
 }
 
+/// Whether the installed git has `merge-tree --write-tree` (git >= 2.38).
+///
+/// Checked once per process; an unknown version counts as unsupported.
+pub(crate) fn merge_tree_write_supported() -> bool {
+    static SUPPORTED: OnceLock<bool> = OnceLock::new();
+    *SUPPORTED.get_or_init(|| {
+        Command::new("git")
+            .arg("--version")
+            .output()
+            .ok()
+            .and_then(|output| parse_git_version(&String::from_utf8_lossy(&output.stdout)))
+            .is_some_and(|version| version >= (2, 38))
+    })
+}
+
+/// `(major, minor)` from `git --version` output, e.g. "git version 2.39.2 (Apple Git-143)"
+fn parse_git_version(output: &str) -> Option<(u32, u32)> {
+    let version = output.trim().strip_prefix("git version ")?;
+    let mut parts = version.split(|c: char| !c.is_ascii_digit());
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
 /// Manages Git operations for KYCo
 #[derive(Clone)]
 pub struct GitManager {
@@ -53,7 +81,7 @@ impl GitManager {
         let root = root.into();
 
         if !root.join(".git").exists() {
-            bail!("Not a git repository: {}", root.display());
+            return Err(GitError::NotARepo(root).into());
         }
 
         let worktrees_dir = root.join(".kyco").join("worktrees");
@@ -110,6 +138,38 @@ impl GitManager {
         Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
     }
 
+    /// Resolve a branch, tag or commit to its commit SHA (None if it does not exist)
+    pub fn resolve_commit(&self, rev: &str) -> Option<String> {
+        if rev.is_empty() || rev.starts_with('-') {
+            return None;
+        }
+        let output = Command::new("git")
+            .args(["rev-parse", "--verify", "--quiet"])
+            .arg(format!("{}^{{commit}}", rev))
+            .current_dir(&self.root)
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        let sha = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        (!sha.is_empty()).then_some(sha)
+    }
+
+    /// Whether `name` is a branch under `refs/heads` (not a tag, remote branch or commit)
+    pub fn is_local_branch(&self, name: &str) -> bool {
+        if name.is_empty() || name.starts_with('-') {
+            return false;
+        }
+        Command::new("git")
+            .args(["show-ref", "--verify", "--quiet"])
+            .arg(format!("refs/heads/{}", name))
+            .current_dir(&self.root)
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false)
+    }
+
     /// Check if the repo has uncommitted changes
     pub fn has_uncommitted_changes(&self) -> Result<bool> {
         let output = Command::new("git")
@@ -132,6 +192,57 @@ impl GitManager {
         Ok(!output.stdout.is_empty())
     }
 
+    /// List tracked files with uncommitted (staged or unstaged) changes.
+    ///
+    /// Paths are exactly as git reports them (no quoting); renames list the new path.
+    pub fn tracked_dirty_files(&self) -> Result<Vec<String>> {
+        let output = Command::new("git")
+            .args(["status", "--porcelain", "-z", "--untracked-files=no"])
+            .current_dir(&self.root)
+            .output()
+            .context("Failed to run git status")?;
+
+        if !output.status.success() {
+            bail!(
+                "Failed to run git status: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        Ok(types::parse_porcelain_z(&output.stdout))
+    }
+
+    /// Stash tracked uncommitted changes with the given message.
+    ///
+    /// Returns the stash commit, so callers can tell the user exactly what to restore.
+    pub fn stash_changes(&self, message: &str) -> Result<String> {
+        let output = Command::new("git")
+            .args(["stash", "push", "-m", message])
+            .current_dir(&self.root)
+            .output()
+            .context("Failed to run git stash")?;
+
+        if !output.status.success() {
+            bail!(
+                "Failed to stash changes: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        let output = Command::new("git")
+            .args(["rev-parse", "--short", "stash@{0}"])
+            .current_dir(&self.root)
+            .output()
+            .context("Failed to run git rev-parse")?;
+        if !output.status.success() {
+            bail!(
+                "Failed to resolve the new stash: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
     /// Get the root path
     pub fn root(&self) -> &Path {
         &self.root
@@ -1,7 +1,9 @@
 //! Tests for GitManager
 
-use super::types::{parse_numstat_output, DiffSettings, FileStatus};
-use super::GitManager;
+use super::types::{
+    parse_numstat_output, parse_porcelain_z, CommitMessage, DiffSettings, FileStatus, GitError,
+};
+use super::{parse_git_version, GitManager};
 use std::fs;
 use std::path::Path;
 use std::process::Command;
@@ -69,6 +71,27 @@ fn parse_numstat_output_binary() {
     assert_eq!(results[0], ("image.png".to_string(), 0, 0, true));
 }
 
+#[test]
+fn parse_git_version_reads_major_and_minor() {
+    assert_eq!(parse_git_version("git version 2.39.2 (Apple Git-143)\n"), Some((2, 39)));
+    assert_eq!(parse_git_version("git version 2.38.0.windows.1"), Some((2, 38)));
+    assert_eq!(parse_git_version("git version 1.8"), Some((1, 8)));
+    assert_eq!(parse_git_version("not git"), None);
+}
+
+#[test]
+fn parse_porcelain_z_handles_spaces_and_renames() {
+    let output = b" M src/my file.rs\0R  new name.rs\0old name.rs\0M  \"quoted\".txt\0";
+    assert_eq!(
+        parse_porcelain_z(output),
+        vec![
+            "src/my file.rs".to_string(),
+            "new name.rs".to_string(),
+            "\"quoted\".txt".to_string(),
+        ]
+    );
+}
+
 #[test]
 fn diff_report_basic() {
     let tmp = TempDir::new().expect("tempdir");
@@ -127,3 +150,468 @@ fn diff_file_patch_basic() {
         patch
     );
 }
+
+#[test]
+fn tracked_dirty_files_and_stash() {
+    let tmp = TempDir::new().expect("tempdir");
+    let repo = tmp.path();
+
+    git(repo, &["init"]);
+    git(repo, &["config", "user.email", "test@example.com"]);
+    git(repo, &["config", "user.name", "Test User"]);
+
+    fs::write(repo.join("README.md"), "hello\n").expect("write README");
+    git(repo, &["add", "README.md"]);
+    git(repo, &["commit", "-m", "init"]);
+
+    let gm = GitManager::new(repo).expect("git manager");
+    assert!(gm.tracked_dirty_files().expect("status").is_empty());
+
+    fs::write(repo.join("README.md"), "hello world\n").expect("write README");
+    fs::write(repo.join("untracked.txt"), "new\n").expect("write untracked");
+    assert_eq!(
+        gm.tracked_dirty_files().expect("status"),
+        vec!["README.md".to_string()]
+    );
+
+    let stash = gm.stash_changes("kyco test stash").expect("stash");
+    assert!(!stash.is_empty());
+    assert!(gm.tracked_dirty_files().expect("status").is_empty());
+    assert_eq!(
+        fs::read_to_string(repo.join("README.md")).expect("read README"),
+        "hello\n"
+    );
+}
+
+#[test]
+fn merge_conflicts_detects_uncommitted_worktree_changes() {
+    let tmp = TempDir::new().expect("tempdir");
+    let repo = tmp.path().join("repo");
+    fs::create_dir_all(&repo).expect("mkdir repo");
+
+    git(&repo, &["init"]);
+    git(&repo, &["config", "user.email", "test@example.com"]);
+    git(&repo, &["config", "user.name", "Test User"]);
+    fs::write(repo.join("a.txt"), "one\n").expect("write a");
+    fs::write(repo.join("b.txt"), "two\n").expect("write b");
+    git(&repo, &["add", "."]);
+    git(&repo, &["commit", "-m", "init"]);
+    git(&repo, &["branch", "-m", "main"]);
+
+    let worktree = tmp.path().join("wt");
+    git(
+        &repo,
+        &["worktree", "add", "-b", "kyco/job-1", worktree.to_str().unwrap()],
+    );
+
+    // Diverging edit on main
+    fs::write(repo.join("a.txt"), "main edit\n").expect("write a");
+    git(&repo, &["commit", "-am", "main edit"]);
+
+    let gm = GitManager::new(&repo).expect("git manager");
+
+    // Uncommitted worktree edit to another file merges cleanly
+    fs::write(worktree.join("b.txt"), "worktree edit\n").expect("write b");
+    assert!(gm.merge_conflicts(&worktree, "main").expect("merge-tree").is_empty());
+
+    // Uncommitted worktree edit to the same file conflicts
+    fs::write(worktree.join("a.txt"), "worktree edit\n").expect("write a");
+    assert_eq!(
+        gm.merge_conflicts(&worktree, "main").expect("merge-tree"),
+        vec!["a.txt".to_string()]
+    );
+
+    // The worktree's own index is left alone
+    let status = Command::new("git")
+        .args(["diff", "--cached", "--name-only"])
+        .current_dir(&worktree)
+        .output()
+        .expect("git diff --cached");
+    assert!(status.stdout.is_empty());
+}
+
+#[test]
+fn apply_changes_refuses_when_base_diverged() {
+    let tmp = TempDir::new().expect("tempdir");
+    let repo = tmp.path().join("repo");
+    fs::create_dir_all(&repo).expect("mkdir repo");
+
+    git(&repo, &["init"]);
+    git(&repo, &["config", "user.email", "test@example.com"]);
+    git(&repo, &["config", "user.name", "Test User"]);
+    fs::write(repo.join("a.txt"), "one\n").expect("write a");
+    fs::write(repo.join("b.txt"), "two\n").expect("write b");
+    git(&repo, &["add", "."]);
+    git(&repo, &["commit", "-m", "init"]);
+    git(&repo, &["branch", "-m", "main"]);
+
+    let worktree = tmp.path().join("wt");
+    git(
+        &repo,
+        &["worktree", "add", "-b", "kyco/job-1", worktree.to_str().unwrap()],
+    );
+    git(&worktree, &["config", "user.email", "test@example.com"]);
+    git(&worktree, &["config", "user.name", "Test User"]);
+    fs::write(worktree.join("b.txt"), "worktree edit\n").expect("write b");
+
+    let gm = GitManager::new(&repo).expect("git manager");
+    assert!(gm.base_divergence(&worktree, "main").expect("divergence").is_none());
+
+    // Concurrent edit lands on main after the worktree was created
+    fs::write(repo.join("a.txt"), "main edit\n").expect("write a");
+    git(&repo, &["commit", "-am", "main edit"]);
+    let main_head = gm.head_sha().expect("head");
+
+    let err = gm
+        .apply_changes(&worktree, "main", None)
+        .expect_err("diverged base must be rejected");
+    match err.downcast_ref::<GitError>() {
+        Some(GitError::BaseDiverged {
+            base_branch,
+            base_head,
+            new_commits,
+            ..
+        }) => {
+            assert_eq!(base_branch, "main");
+            assert_eq!(base_head, &main_head);
+            assert_eq!(*new_commits, 1);
+        }
+        other => panic!("expected BaseDiverged, got {:?}", other),
+    }
+    // Nothing was merged
+    assert_eq!(gm.head_sha().expect("head"), main_head);
+
+    gm.apply_changes_allow_diverged(&worktree, "main", None)
+        .expect("forced merge");
+    assert_eq!(
+        fs::read_to_string(repo.join("b.txt")).expect("read b"),
+        "worktree edit\n"
+    );
+    assert_eq!(
+        fs::read_to_string(repo.join("a.txt")).expect("read a"),
+        "main edit\n"
+    );
+}
+
+#[test]
+fn failed_merges_report_typed_git_errors() {
+    let tmp = TempDir::new().expect("tempdir");
+    let repo = tmp.path().join("repo");
+    fs::create_dir_all(&repo).expect("mkdir repo");
+
+    let err = GitManager::new(&repo).err().expect("not a repo yet");
+    assert_eq!(
+        err.downcast_ref::<GitError>(),
+        Some(&GitError::NotARepo(repo.clone()))
+    );
+
+    git(&repo, &["init"]);
+    git(&repo, &["config", "user.email", "test@example.com"]);
+    git(&repo, &["config", "user.name", "Test User"]);
+    fs::write(repo.join("a.txt"), "one\n").expect("write a");
+    fs::write(repo.join("b.txt"), "two\n").expect("write b");
+    git(&repo, &["add", "."]);
+    git(&repo, &["commit", "-m", "init"]);
+    git(&repo, &["branch", "-m", "main"]);
+
+    let worktree = tmp.path().join("wt");
+    git(
+        &repo,
+        &["worktree", "add", "-b", "kyco/job-1", worktree.to_str().unwrap()],
+    );
+    git(&worktree, &["config", "user.email", "test@example.com"]);
+    git(&worktree, &["config", "user.name", "Test User"]);
+    fs::write(worktree.join("a.txt"), "worktree edit\n").expect("write a");
+
+    let gm = GitManager::new(&repo).expect("git manager");
+
+    // Dirty main working tree
+    fs::write(repo.join("b.txt"), "local edit\n").expect("write b");
+    let err = gm
+        .apply_changes_allow_diverged(&worktree, "main", None)
+        .expect_err("dirty repo must be rejected");
+    assert_eq!(
+        err.downcast_ref::<GitError>(),
+        Some(&GitError::Dirty(vec!["b.txt".to_string()]))
+    );
+    git(&repo, &["checkout", "--", "b.txt"]);
+
+    // Conflicting edit on main
+    fs::write(repo.join("a.txt"), "main edit\n").expect("write a");
+    git(&repo, &["commit", "-am", "main edit"]);
+    let err = gm
+        .apply_changes_allow_diverged(&worktree, "main", None)
+        .expect_err("conflict must fail");
+    assert_eq!(
+        err.downcast_ref::<GitError>(),
+        Some(&GitError::MergeConflict(vec!["a.txt".to_string()]))
+    );
+    // The merge was aborted
+    assert!(gm.tracked_dirty_files().expect("status").is_empty());
+
+    let err = gm
+        .apply_changes_allow_diverged(&tmp.path().join("gone"), "main", None)
+        .expect_err("missing worktree");
+    assert!(matches!(
+        err.downcast_ref::<GitError>(),
+        Some(GitError::WorktreeMissing(_))
+    ));
+}
+
+#[test]
+fn merge_worktree_as_commit_creates_a_single_commit() {
+    let tmp = TempDir::new().expect("tempdir");
+    let repo = tmp.path().join("repo");
+    fs::create_dir_all(&repo).expect("mkdir repo");
+
+    git(&repo, &["init"]);
+    git(&repo, &["config", "user.email", "test@example.com"]);
+    git(&repo, &["config", "user.name", "Test User"]);
+    fs::write(repo.join("a.txt"), "one\n").expect("write a");
+    git(&repo, &["add", "."]);
+    git(&repo, &["commit", "-m", "init"]);
+    git(&repo, &["branch", "-m", "main"]);
+    git(&repo, &["checkout", "-b", "feature"]);
+
+    let worktree = tmp.path().join("wt");
+    git(
+        &repo,
+        &["worktree", "add", "-b", "kyco/job-1", worktree.to_str().unwrap(), "main"],
+    );
+    git(&worktree, &["config", "user.email", "test@example.com"]);
+    git(&worktree, &["config", "user.name", "Test User"]);
+    // One committed and one uncommitted change: both must land in the same commit
+    fs::write(worktree.join("a.txt"), "agent edit\n").expect("write a");
+    git(&worktree, &["commit", "-am", "wip"]);
+    fs::write(worktree.join("b.txt"), "new file\n").expect("write b");
+
+    let gm = GitManager::new(&repo).expect("git manager");
+    let message = CommitMessage::new("Apply job #1", Some("Squashed".to_string()));
+    let hash = gm
+        .merge_worktree_as_commit(&worktree, "main", &message, false)
+        .expect("merge as commit")
+        .expect("commit created");
+
+    let rev = |args: &[&str]| {
+        let out = Command::new("git")
+            .args(args)
+            .current_dir(&repo)
+            .output()
+            .expect("git");
+        String::from_utf8_lossy(&out.stdout).trim().to_string()
+    };
+    assert_eq!(rev(&["rev-parse", "main"]), hash);
+    assert_eq!(rev(&["rev-list", "--count", "main"]), "2");
+    assert_eq!(rev(&["log", "-1", "--format=%s", "main"]), "Apply job #1");
+    assert_eq!(rev(&["show", "main:b.txt"]), "new file");
+    // The user's checkout is restored and untouched
+    assert_eq!(gm.current_branch().expect("branch"), "feature");
+    assert_eq!(fs::read_to_string(repo.join("a.txt")).expect("read a"), "one\n");
+
+    // Nothing left to merge: no empty commit
+    assert!(
+        gm.merge_worktree_as_commit(&worktree, "main", &message, false)
+            .expect("second merge")
+            .is_none()
+    );
+    assert_eq!(rev(&["rev-parse", "main"]), hash);
+}
+
+fn prune_test_job(id: u64, status: crate::JobStatus) -> crate::Job {
+    crate::Job {
+        status,
+        ..crate::domain::test_support::job(id)
+    }
+}
+
+#[test]
+fn prune_worktrees_removes_orphaned_and_closed_jobs() {
+    let tmp = TempDir::new().expect("tempdir");
+    let repo = tmp.path();
+
+    git(repo, &["init"]);
+    git(repo, &["config", "user.email", "test@example.com"]);
+    git(repo, &["config", "user.name", "Test User"]);
+    fs::write(repo.join("README.md"), "hello\n").expect("write README");
+    git(repo, &["add", "README.md"]);
+    git(repo, &["commit", "-m", "init"]);
+
+    for name in ["job-1", "job-2", "job-3", "job-5"] {
+        let path = format!(".kyco/worktrees/{}", name);
+        let branch = format!("kyco/{}", name);
+        git(repo, &["worktree", "add", "-b", &branch, &path]);
+    }
+    // Leftover directory git no longer knows about
+    fs::create_dir_all(repo.join(".kyco/worktrees/job-4")).expect("create stale dir");
+
+    let gm = GitManager::new(repo).expect("git manager");
+    let listed = gm.list_worktrees().expect("list worktrees");
+    assert_eq!(
+        listed.iter().map(|w| w.job_id).collect::<Vec<_>>(),
+        vec![Some(1), Some(2), Some(3), Some(4), Some(5)]
+    );
+    assert_eq!(listed[0].branch.as_deref(), Some("kyco/job-1"));
+    assert!(!listed[3].registered);
+
+    // Job 1 is still running, job 2 was merged, jobs 3 and 4 no longer exist,
+    // job 5 is done and awaiting review
+    let jobs = vec![
+        prune_test_job(1, crate::JobStatus::Running),
+        prune_test_job(2, crate::JobStatus::Merged),
+        prune_test_job(5, crate::JobStatus::Done),
+    ];
+
+    let dry_run = gm.prune_worktrees(&jobs, true).expect("dry run");
+    let dry_ids: Vec<_> = dry_run.iter().map(|w| w.job_id).collect();
+    assert_eq!(dry_ids, vec![Some(2), Some(3), Some(4)]);
+    assert!(dry_run.iter().all(|w| w.path.exists()), "dry run must not touch disk");
+
+    let pruned = gm.prune_worktrees(&jobs, false).expect("prune");
+    assert_eq!(pruned.len(), 3);
+    assert!(pruned.iter().all(|w| !w.path.exists()));
+
+    let remaining = gm.list_worktrees().expect("list worktrees");
+    assert_eq!(
+        remaining.iter().map(|w| w.job_id).collect::<Vec<_>>(),
+        vec![Some(1), Some(5)]
+    );
+
+    let branches = Command::new("git")
+        .args(["branch", "--list", "kyco/*"])
+        .current_dir(repo)
+        .output()
+        .expect("git branch");
+    let branches = String::from_utf8_lossy(&branches.stdout);
+    assert!(branches.contains("kyco/job-1"));
+    assert!(branches.contains("kyco/job-5"), "unreviewed work must survive");
+    assert!(!branches.contains("kyco/job-2"));
+    assert!(!branches.contains("kyco/job-3"));
+}
+
+#[test]
+fn create_worktree_uses_suffix_when_job_dir_is_taken() {
+    let tmp = TempDir::new().expect("tempdir");
+    let repo = tmp.path();
+
+    git(repo, &["init"]);
+    git(repo, &["config", "user.email", "test@example.com"]);
+    git(repo, &["config", "user.name", "Test User"]);
+    fs::write(repo.join("README.md"), "hello\n").expect("write README");
+    git(repo, &["add", "README.md"]);
+    git(repo, &["commit", "-m", "init"]);
+
+    let gm = GitManager::new(repo).expect("git manager");
+    let first = gm.create_worktree(7, None).expect("first worktree");
+    let second = gm.create_worktree(7, None).expect("second worktree");
+
+    assert_ne!(first.path, second.path);
+    assert_ne!(first.branch_name, second.branch_name);
+    assert!(first.path.ends_with("job-7"));
+    assert!(second.path.ends_with("job-7-1"));
+    assert!(second.path.join("README.md").exists());
+
+    // An empty leftover directory is reused instead of skipped
+    let empty = repo.join(".kyco/worktrees/job-8");
+    fs::create_dir_all(&empty).expect("create empty dir");
+    let reused = gm.create_worktree(8, None).expect("worktree in empty dir");
+    assert_eq!(reused.path, empty);
+    assert!(reused.path.join("README.md").exists());
+}
+
+#[test]
+fn create_worktree_branches_off_the_requested_base() {
+    let tmp = TempDir::new().expect("tempdir");
+    let repo = tmp.path();
+
+    git(repo, &["init"]);
+    git(repo, &["config", "user.email", "test@example.com"]);
+    git(repo, &["config", "user.name", "Test User"]);
+    fs::write(repo.join("README.md"), "release\n").expect("write README");
+    git(repo, &["add", "README.md"]);
+    git(repo, &["commit", "-m", "init"]);
+    git(repo, &["branch", "release"]);
+    fs::write(repo.join("README.md"), "main\n").expect("write README");
+    git(repo, &["commit", "-am", "main edit"]);
+
+    let gm = GitManager::new(repo).expect("git manager");
+    let info = gm
+        .create_worktree(1, Some("release"))
+        .expect("worktree from release");
+    assert_eq!(info.base_branch, "release");
+    assert_eq!(
+        fs::read_to_string(info.path.join("README.md")).expect("read README"),
+        "release\n"
+    );
+
+    let err = gm
+        .create_worktree(2, Some("no-such-branch"))
+        .err()
+        .expect("unknown base must fail");
+    assert_eq!(
+        err.downcast_ref::<GitError>(),
+        Some(&GitError::InvalidRef("no-such-branch".to_string()))
+    );
+    assert!(gm.resolve_commit("--help").is_none());
+}
+
+#[test]
+fn create_worktree_from_a_commit_merges_into_the_current_branch() {
+    let tmp = TempDir::new().expect("tempdir");
+    let repo = tmp.path();
+
+    git(repo, &["init", "-b", "main"]);
+    git(repo, &["config", "user.email", "test@example.com"]);
+    git(repo, &["config", "user.name", "Test User"]);
+    fs::write(repo.join("README.md"), "release\n").expect("write README");
+    git(repo, &["add", "README.md"]);
+    git(repo, &["commit", "-m", "init"]);
+    let gm = GitManager::new(repo).expect("git manager");
+    let start = gm.resolve_commit("HEAD").expect("init commit");
+    fs::write(repo.join("README.md"), "main\n").expect("write README");
+    git(repo, &["commit", "-am", "main edit"]);
+
+    let info = gm
+        .create_worktree(1, Some(&start))
+        .expect("worktree from commit");
+    assert_eq!(info.base_branch, "main");
+    assert_eq!(info.base_commit, start);
+    assert_eq!(
+        fs::read_to_string(info.path.join("README.md")).expect("read README"),
+        "release\n"
+    );
+
+    fs::write(info.path.join("fix.txt"), "fix\n").expect("write fix");
+    git(&info.path, &["add", "fix.txt"]);
+    git(&info.path, &["commit", "-m", "fix"]);
+
+    // A commit is only the start point, never a merge target
+    let err = gm
+        .apply_changes_allow_diverged(&info.path, &start, None)
+        .expect_err("merging into a commit must fail");
+    assert_eq!(
+        err.downcast_ref::<GitError>(),
+        Some(&GitError::NotLocalBranch(start.clone()))
+    );
+
+    // Divergence is measured from the recorded start commit
+    match gm.base_divergence(&info.path, "main").expect("divergence") {
+        Some(GitError::BaseDiverged {
+            fork_point,
+            new_commits,
+            ..
+        }) => {
+            assert_eq!(fork_point, start);
+            assert_eq!(new_commits, 1);
+        }
+        other => panic!("expected BaseDiverged, got {:?}", other),
+    }
+
+    gm.apply_changes_allow_diverged(&info.path, &info.base_branch, None)
+        .expect("merge into main");
+    assert_eq!(gm.current_branch().expect("current branch"), "main");
+    assert!(repo.join("fix.txt").exists());
+    assert_eq!(
+        fs::read_to_string(repo.join("README.md")).expect("read README"),
+        "main\n"
+    );
+}
@@ -6,12 +6,34 @@ use crate::Job;
 pub struct WorktreeInfo {
     /// Path to the created worktree
     pub path: std::path::PathBuf,
-    /// The base branch from which the worktree was created
+    /// The local branch the worktree merges back into
     pub base_branch: String,
+    /// Commit the worktree branch was started from
+    pub base_commit: String,
     /// The branch name created for this worktree (e.g. "kyco/job-123")
     pub branch_name: String,
 }
 
+/// A worktree found under `.kyco/worktrees`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManagedWorktree {
+    /// Path to the worktree directory
+    pub path: std::path::PathBuf,
+    /// Checked-out branch (None if detached or not registered with git)
+    pub branch: Option<String>,
+    /// Job ID parsed from the directory name (`job-<id>` or `job-<id>-<suffix>`)
+    pub job_id: Option<crate::JobId>,
+    /// Whether git still tracks this worktree (false for leftover directories)
+    pub registered: bool,
+}
+
+/// Parse the job ID from a worktree directory name (`job-12` or `job-12-3`)
+pub(crate) fn parse_worktree_job_id(dir_name: &str) -> Option<crate::JobId> {
+    let rest = dir_name.strip_prefix("job-")?;
+    let id = rest.split('-').next()?;
+    id.parse().ok()
+}
+
 /// Suggested git commit message (subject + optional body).
 #[derive(Debug, Clone)]
 pub struct CommitMessage {
@@ -139,6 +161,64 @@ pub struct DiffReport {
     pub files_changed: usize,
 }
 
+/// Typed `GitManager` failures, carried inside `anyhow::Error`
+///
+/// Callers that want to offer a targeted action (stash, resolve conflicts,
+/// recreate a worktree) can `downcast_ref::<GitError>()`; everyone else just
+/// sees the message.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GitError {
+    #[error("Not a git repository: {}", .0.display())]
+    NotARepo(std::path::PathBuf),
+    /// Every worktree directory/branch name for the job is already taken
+    #[error(
+        "Failed to create worktree for job {job_id} after {retries} retries - all suffixes in use"
+    )]
+    WorktreeExists { job_id: crate::JobId, retries: u32 },
+    /// The job's worktree directory was removed
+    #[error("Worktree no longer exists: {}", .0.display())]
+    WorktreeMissing(std::path::PathBuf),
+    /// The merge was aborted because these files conflict
+    #[error("Merge conflict in {}: {} (merge was aborted)", plural_files(.0.len()), .0.join(", "))]
+    MergeConflict(Vec<String>),
+    /// The base branch gained commits after the worktree was created
+    #[error(
+        "Base branch '{base_branch}' has {new_commits} new commit(s) since the worktree was created; \
+         merging may overwrite concurrent changes"
+    )]
+    BaseDiverged {
+        base_branch: String,
+        /// Commit the worktree branched from
+        fork_point: String,
+        /// Current tip of the base branch
+        base_head: String,
+        new_commits: usize,
+    },
+    /// The main working tree has uncommitted changes to these tracked files
+    #[error(
+        "Cannot apply changes: repository has uncommitted changes ({}). \
+         Please commit or stash them first.",
+        .0.join(", ")
+    )]
+    Dirty(Vec<String>),
+    /// A branch or commit that does not resolve to a commit
+    #[error("Unknown base ref '{0}': not a branch or commit in this repository")]
+    InvalidRef(String),
+    /// Merges only go into local branches; anything else would detach HEAD
+    #[error("Cannot merge into '{0}': not a local branch")]
+    NotLocalBranch(String),
+    #[error("{0}")]
+    Other(String),
+}
+
+fn plural_files(n: usize) -> String {
+    if n == 1 {
+        "1 file".to_string()
+    } else {
+        format!("{} files", n)
+    }
+}
+
 /// Options for diff generation
 #[derive(Debug, Clone, Default)]
 pub struct DiffSettings {
@@ -157,6 +237,27 @@ pub(super) fn parse_null_delimited(output: &[u8]) -> Vec<String> {
         .collect()
 }
 
+/// Parse `git status --porcelain -z` output into the changed paths.
+///
+/// Entries are `XY <path>`; renames and copies carry the original path as an
+/// extra NUL-terminated field, which is skipped so each entry yields its new path.
+pub(super) fn parse_porcelain_z(output: &[u8]) -> Vec<String> {
+    let mut fields = output.split(|&b| b == 0);
+    let mut paths = Vec::new();
+    while let Some(entry) = fields.next() {
+        let (Some(status), Some(path)) = (entry.get(..2), entry.get(3..)) else {
+            continue;
+        };
+        if status.iter().any(|&c| c == b'R' || c == b'C') {
+            fields.next();
+        }
+        if !path.is_empty() {
+            paths.push(String::from_utf8_lossy(path).into_owned());
+        }
+    }
+    paths
+}
+
 /// Parse git diff --numstat -z output
 /// Returns tuples of (path, lines_added, lines_removed, is_binary)
 pub(super) fn parse_numstat_output(output: &[u8]) -> Vec<(String, usize, usize, bool)> {
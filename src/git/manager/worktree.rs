@@ -5,20 +5,30 @@ use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
-use super::{types::WorktreeInfo, GitManager};
-use crate::JobId;
+use super::{
+    GitManager,
+    types::{GitError, ManagedWorktree, WorktreeInfo, parse_worktree_job_id},
+};
+use crate::{Job, JobId, JobStatus};
 
 impl GitManager {
     /// Create a worktree for a job with automatic retry on conflicts.
     /// Returns the worktree path and the base branch it was created from.
-    pub fn create_worktree(&self, job_id: JobId) -> Result<WorktreeInfo> {
-        self.create_worktree_with_retries(job_id, 10)
+    ///
+    /// The worktree branches off `base_ref` (a branch or commit) if given, else the
+    /// current HEAD. An unknown ref fails with [`GitError::InvalidRef`]. The base
+    /// branch to merge into is `base_ref` when it is a local branch, else the
+    /// current branch; the resolved start commit is recorded in the branch config
+    /// (`branch.<name>.kyco-base`) for the divergence check.
+    pub fn create_worktree(&self, job_id: JobId, base_ref: Option<&str>) -> Result<WorktreeInfo> {
+        self.create_worktree_with_retries(job_id, base_ref, 10)
     }
 
     /// Create a worktree for a job with configurable retry count
     pub(super) fn create_worktree_with_retries(
         &self,
         job_id: JobId,
+        base_ref: Option<&str>,
         max_retries: u32,
     ) -> Result<WorktreeInfo> {
         if !self.has_commits() {
@@ -39,7 +49,25 @@ impl GitManager {
             }
         }
 
-        let base_branch = self.current_branch()?;
+        let start_point = match base_ref.map(str::trim).filter(|r| !r.is_empty()) {
+            Some(base) => Some(
+                self.resolve_commit(base)
+                    .map(|sha| (base, sha))
+                    .ok_or_else(|| GitError::InvalidRef(base.to_string()))?,
+            ),
+            None => None,
+        };
+        let base_branch = match start_point {
+            Some((base, _)) if self.is_local_branch(base) => base.to_string(),
+            _ => self.current_branch()?,
+        };
+        let start_point = start_point.map(|(_, sha)| sha);
+        let base_commit = match &start_point {
+            Some(sha) => sha.clone(),
+            None => self
+                .resolve_commit("HEAD")
+                .ok_or_else(|| anyhow!("Failed to resolve HEAD"))?,
+        };
 
         if self.worktrees_dir.exists() {
             #[cfg(unix)]
@@ -63,9 +91,13 @@ impl GitManager {
 
         std::fs::create_dir_all(&self.worktrees_dir)?;
 
+        // Empty leftover directories are reusable: `git worktree add` accepts them.
         let mut existing_worktree_names = HashSet::new();
         if let Ok(entries) = std::fs::read_dir(&self.worktrees_dir) {
             for entry in entries.flatten() {
+                if is_empty_dir(&entry.path()) {
+                    continue;
+                }
                 if let Some(name) = entry.file_name().into_string().ok() {
                     existing_worktree_names.insert(name);
                 }
@@ -107,7 +139,7 @@ impl GitManager {
 
             let worktree_path = self.worktrees_dir.join(&worktree_dir_name);
 
-            if worktree_path.exists() {
+            if worktree_path.exists() && !is_empty_dir(&worktree_path) {
                 existing_worktree_names.insert(worktree_dir_name.clone());
                 continue;
             }
@@ -119,7 +151,9 @@ impl GitManager {
             }
 
             let output = Command::new("git")
-                .args(["branch", &branch_name])
+                .arg("branch")
+                .arg(&branch_name)
+                .args(&start_point)
                 .current_dir(self.root())
                 .output()
                 .context("Failed to create branch")?;
@@ -144,9 +178,17 @@ impl GitManager {
                 .context("Failed to create worktree")?;
 
             if output.status.success() {
+                if start_point.is_some() {
+                    let _ = Command::new("git")
+                        .args(["config", &format!("branch.{}.kyco-base", branch_name)])
+                        .arg(&base_commit)
+                        .current_dir(self.root())
+                        .output();
+                }
                 return Ok(WorktreeInfo {
                     path: worktree_path,
                     base_branch: base_branch.clone(),
+                    base_commit: base_commit.clone(),
                     branch_name,
                 });
             }
@@ -159,7 +201,10 @@ impl GitManager {
                 .current_dir(self.root())
                 .output();
 
-            if stderr.contains("already exists") || stderr.contains("is already checked out") {
+            if stderr.contains("already exists")
+                || stderr.contains("is already checked out")
+                || stderr.contains("already registered")
+            {
                 // Worktree conflict, try next suffix
                 existing_worktree_names.insert(worktree_dir_name);
                 existing_branch_names.insert(branch_name);
@@ -170,11 +215,11 @@ impl GitManager {
             bail!("Failed to create worktree: {}", stderr);
         }
 
-        bail!(
-            "Failed to create worktree for job {} after {} retries - all suffixes in use",
+        Err(GitError::WorktreeExists {
             job_id,
-            max_retries
-        );
+            retries: max_retries,
+        }
+        .into())
     }
 
     /// Remove a worktree for a job (by job ID - legacy method)
@@ -234,6 +279,151 @@ impl GitManager {
         Ok(())
     }
 
+    /// List every worktree under `.kyco/worktrees`.
+    ///
+    /// Combines `git worktree list --porcelain` with a directory listing so that
+    /// leftover directories git no longer tracks are reported too.
+    pub fn list_worktrees(&self) -> Result<Vec<ManagedWorktree>> {
+        let Ok(worktrees_dir) = self.worktrees_dir.canonicalize() else {
+            return Ok(Vec::new());
+        };
+
+        let output = Command::new("git")
+            .args(["worktree", "list", "--porcelain"])
+            .current_dir(self.root())
+            .output()
+            .context("Failed to list worktrees")?;
+
+        if !output.status.success() {
+            bail!(
+                "Failed to list worktrees: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        let mut worktrees: Vec<ManagedWorktree> = Vec::new();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        for block in stdout.split("\n\n") {
+            let mut path = None;
+            let mut branch = None;
+            for line in block.lines() {
+                if let Some(p) = line.strip_prefix("worktree ") {
+                    path = Some(PathBuf::from(p));
+                } else if let Some(b) = line.strip_prefix("branch ") {
+                    branch = Some(b.strip_prefix("refs/heads/").unwrap_or(b).to_string());
+                }
+            }
+            let Some(path) = path else { continue };
+            let path = path.canonicalize().unwrap_or(path);
+            if path.parent() != Some(worktrees_dir.as_path()) {
+                continue;
+            }
+            let job_id = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .and_then(parse_worktree_job_id);
+            worktrees.push(ManagedWorktree {
+                path,
+                branch,
+                job_id,
+                registered: true,
+            });
+        }
+
+        for entry in std::fs::read_dir(&worktrees_dir)?.flatten() {
+            let path = entry.path();
+            if !path.is_dir() || worktrees.iter().any(|w| w.path == path) {
+                continue;
+            }
+            let job_id = entry.file_name().to_str().and_then(parse_worktree_job_id);
+            worktrees.push(ManagedWorktree {
+                path,
+                branch: None,
+                job_id,
+                registered: false,
+            });
+        }
+
+        worktrees.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(worktrees)
+    }
+
+    /// Find worktrees whose job is gone or can no longer use them.
+    ///
+    /// A worktree is orphaned if no job owns it, or its owner is `Merged`,
+    /// `Rejected` or `Failed`. Ownership is via the job's `git_worktree_path` or
+    /// (for jobs without a recorded path) the job ID in the directory name. `Done`
+    /// jobs keep theirs: the changes are still waiting for review.
+    pub fn orphaned_worktrees(&self, jobs: &[Job]) -> Result<Vec<ManagedWorktree>> {
+        let same_path = |a: &Path, b: &Path| a == b || a.canonicalize().ok().as_deref() == Some(b);
+
+        Ok(self
+            .list_worktrees()?
+            .into_iter()
+            .filter(|worktree| {
+                let owner = jobs
+                    .iter()
+                    .find(|job| {
+                        job.git_worktree_path
+                            .as_deref()
+                            .is_some_and(|p| same_path(p, &worktree.path))
+                    })
+                    .or_else(|| {
+                        worktree.job_id.and_then(|id| {
+                            jobs.iter()
+                                .find(|job| job.id == id && job.git_worktree_path.is_none())
+                        })
+                    });
+                owner.is_none_or(|job| {
+                    matches!(
+                        job.status,
+                        JobStatus::Merged | JobStatus::Rejected | JobStatus::Failed
+                    )
+                })
+            })
+            .collect())
+    }
+
+    /// Remove worktrees that no longer belong to an active job.
+    ///
+    /// Returns the affected worktrees. With `dry_run`, nothing is touched on disk.
+    pub fn prune_worktrees(&self, jobs: &[Job], dry_run: bool) -> Result<Vec<ManagedWorktree>> {
+        let orphaned = self.orphaned_worktrees(jobs)?;
+        if dry_run {
+            return Ok(orphaned);
+        }
+
+        for worktree in &orphaned {
+            match worktree.branch.as_deref() {
+                Some(branch) if branch.starts_with("kyco/") => {
+                    self.remove_worktree_by_path_and_branch(&worktree.path, branch)?;
+                }
+                // Never delete branches KYCo didn't create.
+                _ if worktree.registered => {
+                    let _ = Command::new("git")
+                        .args(["worktree", "remove", "--force"])
+                        .arg(&worktree.path)
+                        .current_dir(self.root())
+                        .output();
+                }
+                _ => {}
+            }
+
+            if worktree.path.exists() {
+                std::fs::remove_dir_all(&worktree.path)
+                    .with_context(|| format!("Failed to remove {}", worktree.path.display()))?;
+            }
+        }
+
+        // Drop git's metadata for worktrees whose directories are gone.
+        let _ = Command::new("git")
+            .args(["worktree", "prune"])
+            .current_dir(self.root())
+            .output();
+
+        Ok(orphaned)
+    }
+
     /// Get the list of changed files in a worktree (including modified and new files)
     pub fn changed_files(&self, worktree: &Path) -> Result<Vec<PathBuf>> {
         let mut files = Vec::new();
@@ -297,4 +487,25 @@ impl GitManager {
             .map(PathBuf::from)
             .collect())
     }
+
+    /// Start commit recorded by `create_worktree` for a worktree made from a base ref
+    pub(super) fn recorded_base_commit(&self, worktree: &Path) -> Option<String> {
+        let git = |args: &[&str]| -> Option<String> {
+            let output = Command::new("git")
+                .args(args)
+                .current_dir(worktree)
+                .output()
+                .ok()
+                .filter(|o| o.status.success())?;
+            let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            (!value.is_empty()).then_some(value)
+        };
+        let branch = git(&["rev-parse", "--abbrev-ref", "HEAD"])?;
+        git(&["config", "--get", &format!("branch.{}.kyco-base", branch)])
+    }
+}
+
+/// Whether `path` is an existing directory without any entries
+fn is_empty_dir(path: &Path) -> bool {
+    std::fs::read_dir(path).is_ok_and(|mut entries| entries.next().is_none())
 }
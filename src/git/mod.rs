@@ -2,6 +2,7 @@
 
 mod manager;
 
-pub use manager::CommitMessage;
+pub use manager::{CommitMessage, GitError};
 pub use manager::{DiffReport, DiffSettings, FileDiff, FileStatus};
-pub use manager::{GitManager, WorktreeInfo, find_git_root};
+pub use manager::{GitManager, ManagedWorktree, WorktreeInfo, find_git_root};
+pub(crate) use manager::merge_tree_write_supported;
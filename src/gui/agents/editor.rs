@@ -355,6 +355,19 @@ pub fn render_agent_editor(ui: &mut egui::Ui, state: &mut AgentEditorState<'_>,
                     .small()
                     .color(TEXT_MUTED),
             );
+            ui.add_space(8.0);
+
+            ui.horizontal(|ui| {
+                ui.checkbox(
+                    state.agent_edit_read_only,
+                    RichText::new("Read-only agent").color(TEXT_PRIMARY),
+                );
+            });
+            ui.label(
+                RichText::new("Always blocks Write/Edit (Codex: read-only sandbox), even if a mode allows them")
+                    .small()
+                    .color(TEXT_MUTED),
+            );
             ui.add_space(16.0);
 
             if let Some((msg, is_error)) = &state.agent_edit_status {
@@ -86,6 +86,7 @@ pub fn render_agents_list(ui: &mut egui::Ui, state: &mut AgentEditorState<'_>) {
                 state.agent_edit_allowed_tools.clear();
                 state.agent_edit_disallowed_tools.clear();
                 *state.agent_edit_allow_dangerous_bypass = false;
+                *state.agent_edit_read_only = false;
                 *state.agent_edit_status = None;
             }
         });
@@ -40,6 +40,7 @@ pub fn load_agent_for_editing(state: &mut AgentEditorState<'_>, name: &str) {
             .unwrap_or_default();
         // Safety settings
         *state.agent_edit_allow_dangerous_bypass = agent.allow_dangerous_bypass;
+        *state.agent_edit_read_only = agent.read_only;
         *state.agent_edit_status = None;
     }
 }
@@ -92,13 +93,22 @@ pub fn save_agent_to_config(state: &mut AgentEditorState<'_>, is_new: bool) {
         _ => SystemPromptMode::Append,
     };
 
-    // Preserve fields not editable in the GUI (env, MCP servers, subagents) when updating an existing agent.
-    let (env, mcp_servers, agents) = state
+    // Preserve fields not editable in the GUI (env, MCP servers, subagents, concurrency,
+    // custom command) when updating an existing agent.
+    let (env, mcp_servers, agents, max_concurrent, command_template) = state
         .config
         .agent
         .get(&name)
-        .map(|a| (a.env.clone(), a.mcp_servers.clone(), a.agents.clone()))
-        .unwrap_or_else(|| (HashMap::new(), HashMap::new(), HashMap::new()));
+        .map(|a| {
+            (
+                a.env.clone(),
+                a.mcp_servers.clone(),
+                a.agents.clone(),
+                a.max_concurrent,
+                a.command_template.clone(),
+            )
+        })
+        .unwrap_or_else(|| (HashMap::new(), HashMap::new(), HashMap::new(), None, None));
 
     let model = if state.agent_edit_model.is_empty() {
         None
@@ -147,6 +157,9 @@ pub fn save_agent_to_config(state: &mut AgentEditorState<'_>, is_new: bool) {
         price_cached_input,
         price_output,
         allow_dangerous_bypass: *state.agent_edit_allow_dangerous_bypass,
+        read_only: *state.agent_edit_read_only,
+        max_concurrent,
+        command_template,
     };
 
     state.config.agent.insert(name.clone(), agent_config);
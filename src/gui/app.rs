@@ -6,12 +6,12 @@
 //! - Selection popup for IDE extension input
 //! - Controls for job management
 
-use super::app_popup::{ApplyTarget, ApplyThreadOutcome};
+use super::app_popup::{ApplyTarget, ApplyThreadError, ApplyThreadOutcome};
 use super::detail_panel::ActivityLogFilters;
 use super::diff::DiffState;
 use super::executor::ExecutorEvent;
 use super::groups::ComparisonState;
-use super::http_server::{BatchFile, BatchRequest, SelectionRequest};
+use super::http_server::{BatchFile, BatchRequest, BridgeStatus, SelectionRequest};
 use super::jobs;
 use super::permission::PermissionPopupState;
 use super::selection::{AutocompleteState, SelectionContext};
@@ -25,7 +25,7 @@ use global_hotkey::{GlobalHotKeyManager, hotkey::HotKey};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::atomic::AtomicUsize;
-use std::sync::mpsc::Receiver;
+use std::sync::mpsc::{Receiver, Sender};
 use std::sync::{Arc, Mutex, RwLock};
 
 // Types
@@ -46,20 +46,30 @@ pub struct KycoApp {
     pub(crate) group_manager: Arc<Mutex<GroupManager>>,
     /// Cached jobs for display (updated only when changed)
     pub(crate) cached_jobs: Vec<Job>,
+    /// Archived jobs (loaded when the Archived filter is shown)
+    pub(crate) archived_jobs: Vec<Job>,
     /// Last known job manager generation (for change detection)
     pub(crate) last_job_generation: u64,
     /// Selected job ID
     pub(crate) selected_job_id: Option<u64>,
+    /// Persisted view state (restored on startup, saved on change)
+    pub(crate) ui_state: super::ui_state::UiState,
     /// Job list filter
     pub(crate) job_list_filter: jobs::JobListFilter,
+    /// Only show jobs carrying this tag (None = no tag filter)
+    pub(crate) job_list_tag_filter: Option<String>,
     /// Log events
     pub(crate) logs: Vec<LogEvent>,
     /// Receiver for HTTP selection events from IDE extensions
     pub(crate) http_rx: Receiver<SelectionRequest>,
     /// Receiver for batch processing requests from IDE extensions
     pub(crate) batch_rx: Receiver<BatchRequest>,
+    /// IDE bridge status (port, auth, listening, last selection), shared with the HTTP server
+    pub(crate) bridge_status: Arc<Mutex<BridgeStatus>>,
     /// Receiver for executor events
     pub(crate) executor_rx: Receiver<ExecutorEvent>,
+    /// Sender for events produced off the executor thread (e.g. notification failures)
+    pub(crate) executor_tx: Sender<ExecutorEvent>,
     /// Shared max concurrent jobs (runtime-adjustable)
     pub(crate) max_concurrent_jobs: Arc<AtomicUsize>,
     /// Current selection context (from IDE extension)
@@ -90,9 +100,11 @@ pub struct KycoApp {
     pub(crate) apply_confirm_return_view: ViewMode,
     /// Error message shown in apply confirmation popup
     pub(crate) apply_confirm_error: Option<String>,
+    /// Last merge attempt failed because the base branch moved; next attempt merges anyway
+    pub(crate) apply_confirm_diverged: bool,
     /// Receiver for async apply/merge results
     pub(crate) apply_confirm_rx:
-        Option<std::sync::mpsc::Receiver<Result<ApplyThreadOutcome, String>>>,
+        Option<std::sync::mpsc::Receiver<Result<ApplyThreadOutcome, ApplyThreadError>>>,
     /// Markdown rendering cache (for agent responses)
     pub(crate) commonmark_cache: egui_commonmark::CommonMarkCache,
     /// Comparison popup state for multi-agent results
@@ -110,6 +122,8 @@ pub struct KycoApp {
     pub(crate) permission_mode_overrides: HashMap<JobId, PermissionMode>,
     /// Auto-run enabled
     pub(crate) auto_run: bool,
+    /// Previous auto_run value (to persist toggles to `ui_state`)
+    pub(crate) prev_auto_run: bool,
     /// Auto-allow tool calls (skip permission popup)
     pub(crate) auto_allow: bool,
     /// Log scroll to bottom
@@ -160,6 +174,8 @@ pub struct KycoApp {
     pub(crate) agent_edit_price_output: String,
     /// Agent editor: allow dangerous bypass (--dangerously-skip-permissions / --yolo)
     pub(crate) agent_edit_allow_dangerous_bypass: bool,
+    /// Agent editor: read-only agent (never allowed to write, regardless of mode)
+    pub(crate) agent_edit_read_only: bool,
     /// Skill editor: raw SKILL.md content being edited
     pub(crate) skill_edit_content: String,
     /// Skill editor: folder structure info (scripts/, references/, assets/)
@@ -25,15 +25,34 @@ impl eframe::App for KycoApp {
         if self.selected_job_id != self.prev_selected_job_id {
             self.prev_selected_job_id = self.selected_job_id;
             self.load_inline_diff_for_selected();
+
+            if self.ui_state.last_selected_job_id != self.selected_job_id {
+                self.ui_state.last_selected_job_id = self.selected_job_id;
+                self.ui_state.save(&self.work_dir);
+            }
+        }
+
+        // Persist the auto-run toggle so it survives restarts
+        if self.auto_run != self.prev_auto_run {
+            self.prev_auto_run = self.auto_run;
+            self.ui_state.auto_run = Some(self.auto_run);
+            self.ui_state.save(&self.work_dir);
         }
 
         // Check for HTTP selection events from IDE extensions
         while let Ok(req) = self.http_rx.try_recv() {
+            if let Ok(mut status) = self.bridge_status.lock() {
+                let source = req.file_path.clone().unwrap_or_else(|| "selection".to_string());
+                status.record_received(source);
+            }
             self.on_selection_received(req, ctx);
         }
 
         // Check for batch processing requests from IDE extensions
         while let Ok(req) = self.batch_rx.try_recv() {
+            if let Ok(mut status) = self.bridge_status.lock() {
+                status.record_received(format!("batch ({} files)", req.files.len()));
+            }
             self.on_batch_received(req, ctx);
         }
 
@@ -90,6 +109,9 @@ impl eframe::App for KycoApp {
             }
         }
 
+        // Snapshot bridge status so the status bar doesn't hold the lock while rendering
+        let bridge_status = self.bridge_status.lock().ok().map(|s| s.clone());
+
         // Bottom status bar - MUST be rendered before SidePanel/CentralPanel
         // so that those panels can properly account for the status bar's height
         super::status_bar::render_status_bar(
@@ -108,6 +130,7 @@ impl eframe::App for KycoApp {
                 install_status: &mut self.update_install_status,
                 orchestrator_requested: &mut self.orchestrator_requested,
                 player_stats: self.player_stats.as_ref(),
+                bridge_status: bridge_status.as_ref(),
             },
         );
 
@@ -6,22 +6,30 @@ use super::app::KycoApp;
 use super::app_popup::ApplyTarget;
 use super::app_types::ViewMode;
 use super::jobs;
-use crate::{AgentGroupId, Job};
+use super::runner::JobFollowUps;
+use crate::{AgentGroupId, Job, LogLevel};
 use std::path::PathBuf;
 
-/// Maximum number of log entries to keep in memory (FIFO eviction)
-const MAX_GLOBAL_LOGS: usize = 500;
-
 impl KycoApp {
     // ═══════════════════════════════════════════════════════════════════════
     // Memory Management Helpers
     // ═══════════════════════════════════════════════════════════════════════
 
-    /// Truncate global logs to MAX_GLOBAL_LOGS (FIFO eviction).
+    /// Drop global logs below `gui.log_level` and cap the buffer at
+    /// `gui.max_log_events` (FIFO eviction).
     /// Called periodically to prevent unbounded memory growth.
     pub(crate) fn truncate_logs(&mut self) {
-        if self.logs.len() > MAX_GLOBAL_LOGS {
-            let excess = self.logs.len() - MAX_GLOBAL_LOGS;
+        let (level, max_logs) = self
+            .config
+            .read()
+            .map(|cfg| (cfg.settings.gui.log_level, cfg.settings.gui.max_log_events))
+            .unwrap_or((LogLevel::All, 500));
+
+        if level != LogLevel::All {
+            self.logs.retain(|event| event.matches_level(level));
+        }
+        if self.logs.len() > max_logs {
+            let excess = self.logs.len() - max_logs;
             self.logs.drain(0..excess);
         }
     }
@@ -47,6 +55,7 @@ impl KycoApp {
         self.apply_confirm_target = Some(target);
         self.apply_confirm_return_view = self.view_mode;
         self.apply_confirm_error = None;
+        self.apply_confirm_diverged = false;
         self.apply_confirm_rx = None;
         self.view_mode = ViewMode::ApplyConfirmPopup;
     }
@@ -57,6 +66,17 @@ impl KycoApp {
             .unwrap_or_else(|| self.work_dir.clone())
     }
 
+    /// Finished-job follow-ups shared with the headless server
+    pub(crate) fn follow_ups(&self) -> JobFollowUps<'_> {
+        JobFollowUps {
+            work_dir: &self.work_dir,
+            config: &self.config,
+            job_manager: &self.job_manager,
+            group_manager: &self.group_manager,
+            executor_tx: &self.executor_tx,
+        }
+    }
+
     /// Open the comparison popup for a group
     pub(crate) fn open_comparison_popup(&mut self, group_id: AgentGroupId) {
         // Get the group
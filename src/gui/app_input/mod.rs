@@ -176,9 +176,27 @@ impl KycoApp {
     }
 
     fn handle_diff_view_input(&mut self, i: &egui::InputState) {
+        // Typing into the file filter must not trigger navigation keys
+        if self.diff_state.filter_focused {
+            return;
+        }
         if i.key_pressed(Key::Escape) || i.key_pressed(Key::Q) {
             self.view_mode = self.diff_return_view;
             self.diff_state.clear();
+            return;
+        }
+        if i.key_pressed(Key::Slash) {
+            self.diff_state.focus_filter = true;
+        }
+        if i.key_pressed(Key::S) {
+            self.diff_state.toggle_layout();
+        }
+        if i.key_pressed(Key::N) {
+            if i.modifiers.shift {
+                self.diff_state.prev_file();
+            } else {
+                self.diff_state.next_file();
+            }
         }
     }
 
@@ -187,6 +205,7 @@ impl KycoApp {
             if self.apply_confirm_rx.is_none() {
                 self.apply_confirm_target = None;
                 self.apply_confirm_error = None;
+                self.apply_confirm_diverged = false;
                 self.view_mode = self.apply_confirm_return_view;
             }
         }
@@ -0,0 +1,32 @@
+//! Automatic merge of completed jobs (`auto_merge` modes and `settings.auto_apply`)
+
+use super::super::app::KycoApp;
+use super::super::app_popup::ApplyTarget;
+use crate::{JobId, LogEvent};
+
+impl KycoApp {
+    /// Merge a completed job in the background when it qualifies (see
+    /// `JobFollowUps::auto_merge_plan`) and no other merge is in progress.
+    pub(crate) fn maybe_auto_merge(&mut self, job_id: JobId) {
+        // Don't interfere with a merge the user is currently confirming.
+        let busy = self.apply_confirm_target.is_some() || self.apply_confirm_rx.is_some();
+        let Some(allow_diverged) = self.follow_ups().auto_merge_plan(job_id, busy) else {
+            return;
+        };
+
+        self.apply_confirm_target = Some(ApplyTarget::Single { job_id });
+        self.start_apply_merge(allow_diverged);
+
+        if self.apply_confirm_rx.is_none() {
+            let error = self
+                .apply_confirm_error
+                .take()
+                .unwrap_or_else(|| "unknown error".to_string());
+            self.logs.push(LogEvent::error(format!(
+                "Auto-merge failed for job #{}: {}",
+                job_id, error
+            )));
+            self.apply_confirm_target = None;
+        }
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! Contains job lifecycle operations: queue, apply, reject, kill, delete, etc.
 
+mod auto_merge;
 mod lifecycle;
 mod session;
 
@@ -44,47 +45,7 @@ impl KycoApp {
 
     /// Reject job changes
     pub(crate) fn reject_job(&mut self, job_id: JobId) {
-        let job = match self.job_manager.lock() {
-            Ok(manager) => manager.get(job_id).cloned(),
-            Err(_) => None,
-        };
-
-        let Some(job) = job else {
-            self.logs
-                .push(LogEvent::error(format!("Job #{} not found", job_id)));
-            return;
-        };
-
-        if let Some(worktree) = job.git_worktree_path.clone() {
-            let workspace_root = self.workspace_root_for_job(&job);
-            if let Ok(git) = crate::git::GitManager::new(&workspace_root) {
-                if let Err(e) = git.remove_worktree_by_path(&worktree) {
-                    self.logs.push(LogEvent::error(format!(
-                        "Failed to remove worktree for rejected job: {}",
-                        e
-                    )));
-                }
-            } else {
-                self.logs.push(LogEvent::error(format!(
-                    "Failed to initialize git manager for {}",
-                    workspace_root.display()
-                )));
-            }
-        } else {
-            self.logs.push(LogEvent::system(
-                "Rejected job without worktree (no changes were reverted)".to_string(),
-            ));
-        }
-
-        if let Ok(mut manager) = self.job_manager.lock() {
-            if let Some(j) = manager.get_mut(job_id) {
-                j.set_status(crate::JobStatus::Rejected);
-                j.git_worktree_path = None;
-                j.branch_name = None;
-            }
-        }
-        self.logs
-            .push(LogEvent::system(format!("Rejected job #{}", job_id)));
+        self.follow_ups().reject_job(job_id);
         self.refresh_jobs();
     }
 
@@ -94,46 +55,6 @@ impl KycoApp {
         self.refresh_jobs();
     }
 
-    /// Check if a job's completion means a group is ready for comparison
-    pub(crate) fn check_group_completion(&mut self, job_id: JobId) {
-        // Get the group ID for this job
-        let group_id = {
-            let manager = match self.job_manager.lock() {
-                Ok(m) => m,
-                Err(_) => return,
-            };
-            match manager.get(job_id) {
-                Some(job) => job.group_id,
-                None => return,
-            }
-        };
-
-        let group_id = match group_id {
-            Some(id) => id,
-            None => return, // Job is not part of a group
-        };
-
-        // Collect job references for status update
-        let jobs: Vec<&crate::Job> = self.cached_jobs.iter().collect();
-
-        // Update group status
-        if let Ok(mut gm) = self.group_manager.lock() {
-            gm.update_group_status(group_id, &jobs);
-
-            // Check if group is now in Comparing status
-            if let Some(group) = gm.get(group_id) {
-                if group.status == crate::GroupStatus::Comparing {
-                    // Log that the group is ready
-                    self.logs.push(LogEvent::system(format!(
-                        "Group #{} ready for comparison ({} agents)",
-                        group_id,
-                        group.job_ids.len()
-                    )));
-                }
-            }
-        }
-    }
-
     /// Delete a job from the job manager
     pub(crate) fn delete_job(&mut self, job_id: JobId) {
         if let Ok(mut manager) = self.job_manager.lock() {
@@ -162,6 +83,59 @@ impl KycoApp {
         self.refresh_jobs();
     }
 
+    /// Move a finished job to the archive store
+    pub(crate) fn archive_job(&mut self, job_id: JobId) {
+        let archived = match self.job_manager.lock() {
+            Ok(mut manager) => manager.archive(job_id),
+            Err(_) => return,
+        };
+
+        match archived {
+            Ok(job) => {
+                self.logs.push(LogEvent::system(format!(
+                    "Archived job #{} ({})",
+                    job_id, job.skill
+                )));
+                if self.selected_job_id == Some(job_id) {
+                    self.selected_job_id = None;
+                }
+                if let Ok(mut gm) = self.group_manager.lock() {
+                    gm.remove_job(job_id);
+                }
+                self.permission_mode_overrides.remove(&job_id);
+            }
+            Err(e) => {
+                self.logs.push(LogEvent::error(format!(
+                    "Failed to archive job #{}: {}",
+                    job_id, e
+                )));
+            }
+        }
+
+        self.refresh_jobs();
+        self.refresh_archived_jobs();
+    }
+
+    /// Reload the archived job list from disk
+    pub(crate) fn refresh_archived_jobs(&mut self) {
+        let archived = match self.job_manager.lock() {
+            Ok(manager) => manager.list_archived(),
+            Err(_) => return,
+        };
+        match archived {
+            Ok(mut jobs) => {
+                jobs.reverse();
+                self.archived_jobs = jobs;
+            }
+            Err(e) => {
+                self.logs.push(LogEvent::error(format!(
+                    "Failed to load archived jobs: {}",
+                    e
+                )));
+            }
+        }
+    }
+
     /// Restart a failed or rejected job with the same parameters
     pub(crate) fn restart_job(&mut self, job_id: JobId) {
         let original = match self.job_manager.lock() {
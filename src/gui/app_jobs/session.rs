@@ -58,6 +58,7 @@ impl KycoApp {
             if let Some(job) = manager.get_mut(continuation_id) {
                 job.raw_tag_line = None;
                 job.bridge_session_id = Some(session_id);
+                job.continued_from = Some(job_id);
 
                 // Reuse the same worktree and job context
                 job.git_worktree_path = original.git_worktree_path.clone();
@@ -7,10 +7,11 @@ use super::detail_panel::ActivityLogFilters;
 use super::diff::DiffState;
 use super::executor::ExecutorEvent;
 use super::groups::ComparisonState;
-use super::http_server::{BatchRequest, SelectionRequest};
+use super::http_server::{BatchRequest, BridgeStatus, SelectionRequest};
 use super::jobs;
 use super::permission::PermissionPopupState;
 use super::selection::{AutocompleteState, SelectionContext};
+use super::ui_state::UiState;
 use super::update::UpdateChecker;
 use super::voice::{VoiceConfig, VoiceInputMode, VoiceManager};
 use crate::LogEvent;
@@ -20,11 +21,12 @@ use crate::job::{GroupManager, JobManager};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::atomic::AtomicUsize;
-use std::sync::mpsc::Receiver;
+use std::sync::mpsc::{Receiver, Sender};
 use std::sync::{Arc, Mutex, RwLock};
 
 impl KycoApp {
     /// Create a new GUI application
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         work_dir: PathBuf,
         config: Arc<RwLock<Config>>,
@@ -33,7 +35,9 @@ impl KycoApp {
         group_manager: Arc<Mutex<GroupManager>>,
         http_rx: Receiver<SelectionRequest>,
         batch_rx: Receiver<BatchRequest>,
+        bridge_status: Arc<Mutex<BridgeStatus>>,
         executor_rx: Receiver<ExecutorEvent>,
+        executor_tx: Sender<ExecutorEvent>,
         max_concurrent_jobs: Arc<AtomicUsize>,
     ) -> Self {
         // Extract all values within read lock scope to avoid cloning entire Config.
@@ -176,6 +180,17 @@ impl KycoApp {
         // Clone work_dir once for struct field; move original to voice_manager
         let work_dir_owned = work_dir.clone();
 
+        // Restore the last selected job if it still exists
+        let ui_state = UiState::load(&work_dir);
+        let restored_job_id = ui_state.last_selected_job_id.filter(|id| {
+            job_manager
+                .lock()
+                .map(|manager| manager.get(*id).is_some())
+                .unwrap_or(false)
+        });
+        // A persisted toggle wins over the config default
+        let auto_run_val = ui_state.auto_run.unwrap_or(auto_run_val);
+
         // Start the SDK bridge server (Node.js sidecar for Claude/Codex SDKs)
         let bridge_process = match BridgeProcess::spawn() {
             Ok(process) => {
@@ -195,13 +210,18 @@ impl KycoApp {
             job_manager,
             group_manager,
             cached_jobs: Vec::new(),
+            archived_jobs: Vec::new(),
             last_job_generation: 0,
-            selected_job_id: None,
+            selected_job_id: restored_job_id,
+            ui_state,
             job_list_filter: jobs::JobListFilter::default(),
+            job_list_tag_filter: None,
             logs: vec![LogEvent::system("kyco GUI started")],
             http_rx,
             batch_rx,
+            bridge_status,
             executor_rx,
+            executor_tx,
             max_concurrent_jobs,
             selection: SelectionContext::default(),
             batch_files: Vec::new(),
@@ -217,6 +237,7 @@ impl KycoApp {
             apply_confirm_target: None,
             apply_confirm_return_view: super::app_types::ViewMode::JobList,
             apply_confirm_error: None,
+            apply_confirm_diverged: false,
             apply_confirm_rx: None,
             commonmark_cache: egui_commonmark::CommonMarkCache::default(),
             comparison_state: ComparisonState::default(),
@@ -226,6 +247,7 @@ impl KycoApp {
             bridge_client: BridgeClient::new(),
             permission_mode_overrides: HashMap::new(),
             auto_run: auto_run_val,
+            prev_auto_run: auto_run_val,
             auto_allow: auto_allow_val,
             log_scroll_to_bottom: true,
             activity_log_filters: ActivityLogFilters::default(),
@@ -259,6 +281,7 @@ impl KycoApp {
             agent_edit_price_cached_input: String::new(),
             agent_edit_price_output: String::new(),
             agent_edit_allow_dangerous_bypass: false,
+            agent_edit_read_only: false,
             settings_max_concurrent,
             settings_use_worktree,
             settings_output_schema,
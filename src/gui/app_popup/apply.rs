@@ -1,24 +1,35 @@
 //! Background thread logic for apply/merge operations.
 
-use super::types::{
-    ApplyTarget, ApplyThreadInput, ApplyThreadOutcome,
-};
+use super::types::{ApplyTarget, ApplyThreadError, ApplyThreadInput, ApplyThreadOutcome};
 
-pub(super) fn run_apply_thread(input: ApplyThreadInput) -> Result<ApplyThreadOutcome, String> {
+/// Run the merge; `allow_diverged` skips the base-branch divergence check.
+pub(crate) fn run_apply_thread(
+    input: ApplyThreadInput,
+    allow_diverged: bool,
+) -> Result<ApplyThreadOutcome, ApplyThreadError> {
     match input {
         ApplyThreadInput::Single(input) => {
-            let git =
-                crate::git::GitManager::new(&input.workspace_root).map_err(|e| e.to_string())?;
+            let git = crate::git::GitManager::new(&input.workspace_root)
+                .map_err(ApplyThreadError::from_git)?;
 
             if let Some(worktree_path) = input.worktree_path {
                 let base_branch = input
                     .base_branch
                     .ok_or_else(|| "Job has no base branch recorded".to_string())?;
 
-                git.apply_changes(&worktree_path, &base_branch, Some(&input.commit_message))
-                    .map_err(|e| e.to_string())?;
+                let commit = merge_worktree(
+                    &git,
+                    &worktree_path,
+                    &base_branch,
+                    &input.commit_message,
+                    input.merge_as_commit,
+                    allow_diverged,
+                )?;
 
-                let mut message = format!("Merged job #{}", input.job_id);
+                let mut message = match commit {
+                    Some(hash) => format!("Merged job #{} as commit {}", input.job_id, hash),
+                    None => format!("Merged job #{}", input.job_id),
+                };
                 if let Err(e) = git.remove_worktree_by_path(&worktree_path) {
                     message.push_str(&format!(" (cleanup warning: {})", e));
                 }
@@ -46,20 +57,26 @@ pub(super) fn run_apply_thread(input: ApplyThreadInput) -> Result<ApplyThreadOut
                         group_job_ids: Vec::new(),
                         message: format!("Applied job #{} (no changes to commit)", input.job_id),
                     }),
-                    Err(e) => Err(e.to_string()),
+                    Err(e) => Err(ApplyThreadError::from_git(e)),
                 }
             }
         }
         ApplyThreadInput::Group(input) => {
-            let git =
-                crate::git::GitManager::new(&input.workspace_root).map_err(|e| e.to_string())?;
+            let git = crate::git::GitManager::new(&input.workspace_root)
+                .map_err(ApplyThreadError::from_git)?;
 
-            git.apply_changes(
+            let commit = merge_worktree(
+                &git,
                 &input.selected_worktree_path,
                 &input.base_branch,
-                Some(&input.commit_message),
-            )
-            .map_err(|e| e.to_string())?;
+                &input.commit_message,
+                input.merge_as_commit,
+                allow_diverged,
+            )?;
+            let merged = match commit {
+                Some(hash) => format!("{} as commit {}", input.selected_agent_id, hash),
+                None => input.selected_agent_id.clone(),
+            };
 
             let mut cleanup_warnings = Vec::new();
             for (job_id, worktree_path) in &input.cleanup_worktrees {
@@ -71,13 +88,13 @@ pub(super) fn run_apply_thread(input: ApplyThreadInput) -> Result<ApplyThreadOut
             let message = if cleanup_warnings.is_empty() {
                 format!(
                     "Merged changes from {} and cleaned up {} worktrees",
-                    input.selected_agent_id,
+                    merged,
                     input.cleanup_worktrees.len()
                 )
             } else {
                 format!(
                     "Merged changes from {} (cleanup warnings: {})",
-                    input.selected_agent_id,
+                    merged,
                     cleanup_warnings.join(", ")
                 )
             };
@@ -93,3 +110,25 @@ pub(super) fn run_apply_thread(input: ApplyThreadInput) -> Result<ApplyThreadOut
         }
     }
 }
+
+/// Merge a worktree into `base_branch`; with `as_commit`, as one squashed commit whose
+/// hash is returned.
+pub(crate) fn merge_worktree(
+    git: &crate::git::GitManager,
+    worktree_path: &std::path::Path,
+    base_branch: &str,
+    commit_message: &crate::git::CommitMessage,
+    as_commit: bool,
+    allow_diverged: bool,
+) -> Result<Option<String>, ApplyThreadError> {
+    if as_commit {
+        git.merge_worktree_as_commit(worktree_path, base_branch, commit_message, allow_diverged)
+    } else if allow_diverged {
+        git.apply_changes_allow_diverged(worktree_path, base_branch, Some(commit_message))
+            .map(|()| None)
+    } else {
+        git.apply_changes(worktree_path, base_branch, Some(commit_message))
+            .map(|()| None)
+    }
+    .map_err(ApplyThreadError::from_git)
+}
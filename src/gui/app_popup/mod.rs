@@ -9,11 +9,12 @@ mod render;
 mod types;
 
 pub(crate) use types::{
-    ApplyTarget, ApplyThreadInput, ApplyThreadOutcome, GroupApplyInput, SingleApplyInput,
+    ApplyTarget, ApplyThreadError, ApplyThreadInput, ApplyThreadOutcome, GroupApplyInput,
+    SingleApplyInput,
 };
 
 use super::app::KycoApp;
-use apply::run_apply_thread;
+pub(crate) use apply::{merge_worktree, run_apply_thread};
 use types::ApplyTarget as Target;
 
 impl KycoApp {
@@ -22,6 +23,12 @@ impl KycoApp {
         &self,
         target: &Target,
     ) -> Result<ApplyThreadInput, String> {
+        let merge_as_commit = self
+            .config
+            .read()
+            .map(|config| config.settings.merge_as_commit)
+            .unwrap_or(false);
+
         match target {
             Target::Single { job_id } => {
                 let job = self
@@ -33,13 +40,11 @@ impl KycoApp {
                     .ok_or_else(|| format!("Job #{} not found", job_id))?;
 
                 let workspace_root = self.workspace_root_for_job(&job);
-                Ok(ApplyThreadInput::Single(SingleApplyInput {
-                    job_id: *job_id,
+                Ok(ApplyThreadInput::Single(SingleApplyInput::new(
+                    &job,
                     workspace_root,
-                    worktree_path: job.git_worktree_path.clone(),
-                    base_branch: job.base_branch.clone(),
-                    commit_message: crate::git::CommitMessage::from_job(&job),
-                }))
+                    merge_as_commit,
+                )))
             }
             Target::Group {
                 group_id,
@@ -109,6 +114,7 @@ impl KycoApp {
                     selected_worktree_path,
                     base_branch,
                     commit_message: crate::git::CommitMessage::from_job(&selected_job),
+                    merge_as_commit,
                     cleanup_worktrees,
                     group_job_ids: group.job_ids.clone(),
                 }))
@@ -118,6 +124,15 @@ impl KycoApp {
 
     /// Start the apply/merge operation in a background thread
     pub(crate) fn start_apply_confirm_merge(&mut self) {
+        // A second attempt after a divergence warning means "merge anyway".
+        self.start_apply_merge(self.apply_confirm_diverged);
+    }
+
+    /// Start merging `apply_confirm_target` in the background.
+    ///
+    /// `allow_diverged` skips the check that the base branch has not moved since
+    /// the worktree was created.
+    pub(crate) fn start_apply_merge(&mut self, allow_diverged: bool) {
         if self.apply_confirm_rx.is_some() {
             return;
         }
@@ -140,7 +155,7 @@ impl KycoApp {
         self.apply_confirm_rx = Some(rx);
 
         std::thread::spawn(move || {
-            let result = run_apply_thread(input);
+            let result = run_apply_thread(input, allow_diverged);
             let _ = tx.send(result);
         });
     }
@@ -137,7 +137,16 @@ impl KycoApp {
 
                 if let Some(err) = &self.apply_confirm_error {
                     ui.add_space(8.0);
-                    ui.label(RichText::new(format!("Error: {}", err)).color(ACCENT_RED));
+                    if self.apply_confirm_diverged {
+                        ui.label(RichText::new(format!("Warning: {}", err)).color(ACCENT_RED));
+                        ui.label(
+                            RichText::new("Review the diff, then use \"Merge anyway\" to proceed.")
+                                .small()
+                                .color(TEXT_MUTED),
+                        );
+                    } else {
+                        ui.label(RichText::new(format!("Error: {}", err)).color(ACCENT_RED));
+                    }
                 }
 
                 ui.add_space(12.0);
@@ -162,8 +171,13 @@ impl KycoApp {
                 ui.horizontal(|ui| {
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         let can_merge = !in_progress && validation_error.is_none();
+                        let merge_label = if self.apply_confirm_diverged {
+                            "⚠ Merge anyway"
+                        } else {
+                            "✓ Merge"
+                        };
                         let merge_btn = egui::Button::new(
-                            RichText::new("✓ Merge")
+                            RichText::new(merge_label)
                                 .color(if can_merge { BG_PRIMARY } else { TEXT_MUTED }),
                         )
                         .fill(if can_merge { ACCENT_GREEN } else { BG_SECONDARY });
@@ -197,6 +211,7 @@ impl KycoApp {
                         {
                             self.apply_confirm_target = None;
                             self.apply_confirm_error = None;
+                            self.apply_confirm_diverged = false;
                             self.view_mode = self.apply_confirm_return_view;
                         }
                     });
@@ -1,6 +1,7 @@
 //! Types for apply/merge popup operations.
 
-use crate::{AgentGroupId, JobId};
+use crate::git::GitError;
+use crate::{AgentGroupId, Job, JobId};
 use std::path::PathBuf;
 
 #[derive(Debug, Clone)]
@@ -22,6 +23,49 @@ pub(crate) struct ApplyThreadOutcome {
     pub(in crate::gui) message: String,
 }
 
+/// Failure reported by the apply/merge background thread.
+#[derive(Debug, Clone)]
+pub(crate) struct ApplyThreadError {
+    pub(in crate::gui) message: String,
+    /// Typed cause when the failure came from `GitManager`
+    pub(in crate::gui) git_error: Option<GitError>,
+}
+
+impl ApplyThreadError {
+    pub(super) fn from_git(err: anyhow::Error) -> Self {
+        Self {
+            git_error: err.downcast_ref::<GitError>().cloned(),
+            message: err.to_string(),
+        }
+    }
+
+    /// The base branch moved since the worktree was created; the merge may be retried anyway.
+    pub(in crate::gui) fn base_diverged(&self) -> bool {
+        matches!(self.git_error, Some(GitError::BaseDiverged { .. }))
+    }
+
+    /// What the user can do about the failure, if it is a known git error
+    pub(in crate::gui) fn hint(&self) -> Option<&'static str> {
+        match self.git_error.as_ref()? {
+            GitError::Dirty(_) => Some("Commit or stash your changes, then merge again."),
+            GitError::MergeConflict(_) => {
+                Some("Resolve the conflict on the base branch, or reject the job.")
+            }
+            GitError::WorktreeMissing(_) => Some("Restart the job to recreate its changes."),
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for ApplyThreadError {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            git_error: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) enum ApplyThreadInput {
     Single(SingleApplyInput),
@@ -35,6 +79,20 @@ pub(crate) struct SingleApplyInput {
     pub(super) worktree_path: Option<PathBuf>,
     pub(super) base_branch: Option<String>,
     pub(super) commit_message: crate::git::CommitMessage,
+    pub(super) merge_as_commit: bool,
+}
+
+impl SingleApplyInput {
+    pub(crate) fn new(job: &Job, workspace_root: PathBuf, merge_as_commit: bool) -> Self {
+        Self {
+            job_id: job.id,
+            workspace_root,
+            worktree_path: job.git_worktree_path.clone(),
+            base_branch: job.base_branch.clone(),
+            commit_message: crate::git::CommitMessage::from_job(job),
+            merge_as_commit,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -46,6 +104,7 @@ pub(crate) struct GroupApplyInput {
     pub(super) selected_worktree_path: PathBuf,
     pub(super) base_branch: String,
     pub(super) commit_message: crate::git::CommitMessage,
+    pub(super) merge_as_commit: bool,
     pub(super) cleanup_worktrees: Vec<(JobId, PathBuf)>,
     pub(super) group_job_ids: Vec<JobId>,
 }
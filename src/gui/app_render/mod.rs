@@ -101,13 +101,22 @@ impl KycoApp {
 
     /// Render the job list panel
     pub(crate) fn render_job_list(&mut self, ui: &mut egui::Ui) {
+        let previous_filter = self.job_list_filter;
         let action = jobs::render_job_list(
             ui,
             &self.cached_jobs,
+            &self.archived_jobs,
             &mut self.selected_job_id,
             &mut self.job_list_filter,
+            &mut self.job_list_tag_filter,
         );
 
+        if self.job_list_filter == jobs::JobListFilter::Archived
+            && previous_filter != jobs::JobListFilter::Archived
+        {
+            self.refresh_archived_jobs();
+        }
+
         // Handle actions
         match action {
             jobs::JobListAction::DeleteJob(job_id) => {
@@ -116,6 +125,9 @@ impl KycoApp {
             jobs::JobListAction::DeleteAllFinished => {
                 self.delete_all_finished_jobs();
             }
+            jobs::JobListAction::ArchiveJob(job_id) => {
+                self.archive_job(job_id);
+            }
             jobs::JobListAction::None => {}
         }
     }
@@ -124,6 +136,8 @@ impl KycoApp {
     pub(crate) fn render_detail_panel(&mut self, ui: &mut egui::Ui) {
         use super::detail_panel::{DetailPanelAction, DetailPanelState, render_detail_panel};
 
+        // Archived jobs are shown read-only: their actions are ignored below
+        let viewing_archive = self.job_list_filter == jobs::JobListFilter::Archived;
         let action = {
             let Ok(config) = self.config.read() else {
                 ui.label("Config unavailable");
@@ -131,7 +145,11 @@ impl KycoApp {
             };
             let mut state = DetailPanelState {
                 selected_job_id: self.selected_job_id,
-                cached_jobs: &self.cached_jobs,
+                cached_jobs: if viewing_archive {
+                    &self.archived_jobs
+                } else {
+                    &self.cached_jobs
+                },
                 logs: &self.logs,
                 config: &config,
                 log_scroll_to_bottom: self.log_scroll_to_bottom,
@@ -140,12 +158,13 @@ impl KycoApp {
                 commonmark_cache: &mut self.commonmark_cache,
                 permission_mode_overrides: &self.permission_mode_overrides,
                 diff_content: self.inline_diff_content.as_deref(),
+                diff_layout: &mut self.diff_state.layout,
             };
 
             render_detail_panel(ui, &mut state)
         };
 
-        if let Some(action) = action {
+        if let Some(action) = action.filter(|_| !viewing_archive) {
             match action {
                 DetailPanelAction::Queue(job_id) => self.queue_job(job_id),
                 DetailPanelAction::Apply(job_id) => self.apply_job(job_id),
@@ -169,7 +188,7 @@ impl KycoApp {
 
     /// Render the diff view popup
     pub(crate) fn render_diff_popup(&mut self, ctx: &egui::Context) {
-        if super::diff::render_diff_popup(ctx, &self.diff_state) {
+        if super::diff::render_diff_popup(ctx, &mut self.diff_state) {
             self.view_mode = self.diff_return_view;
             self.diff_state.clear();
         }
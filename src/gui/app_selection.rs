@@ -15,7 +15,7 @@ use tracing::info;
 
 impl KycoApp {
     /// Handle incoming selection from IDE extension
-    pub(crate) fn on_selection_received(&mut self, req: SelectionRequest, ctx: &egui::Context) {
+    pub(crate) fn on_selection_received(&mut self, mut req: SelectionRequest, ctx: &egui::Context) {
         info!(
             "[kyco:gui] Received selection: file={:?}, lines={:?}-{:?}, deps={:?}, tests={:?}, project_root={:?}, git_root={:?}, workspace={:?}",
             req.file_path,
@@ -28,6 +28,21 @@ impl KycoApp {
             req.workspace
         );
 
+        let mut regions = req.regions.take().unwrap_or_default();
+
+        // Multi-region request without an explicit primary: the first region is primary
+        if req.file_path.is_none() {
+            if let Some(first) = regions.first() {
+                req.file_path = Some(first.file_path.clone());
+                req.selected_text = first.selected_text.clone();
+                req.line_start = first.line_start;
+                req.line_end = first.line_end;
+                if regions.len() == 1 {
+                    regions.clear();
+                }
+            }
+        }
+
         // Determine workspace path for SDK cwd resolution
         // Priority: project_root > git_root > workspace > file's directory
         let workspace_path = req
@@ -54,6 +69,8 @@ impl KycoApp {
             related_tests: req.related_tests,
             diagnostics: req.diagnostics,
             workspace_path,
+            regions,
+            context_lines: req.context_lines,
         };
 
         // Show selection popup
@@ -118,6 +118,8 @@ impl KycoApp {
                 related_tests: None,
                 diagnostics: None, // Batch files don't have diagnostics
                 workspace_path,
+                regions: Vec::new(),
+                context_lines: 0,
             };
 
             // Create job(s) for this file
@@ -130,6 +132,7 @@ impl KycoApp {
                 &prompt,
                 &mut self.logs,
                 force_worktree,
+                None,
             ) {
                 total_jobs += result.job_ids.len();
                 if result.group_id.is_some() {
@@ -73,6 +73,7 @@ impl KycoApp {
             &prompt,
             &mut self.logs,
             force_worktree,
+            None,
         ) {
             let selection_info = self
                 .selection
@@ -3,7 +3,7 @@
 //! Contains event handling methods extracted from the main update loop.
 
 mod permission;
-mod stats;
+pub(super) mod stats;
 mod voice;
 mod self_update;
 
@@ -28,24 +28,29 @@ impl KycoApp {
                 ExecutorEvent::JobCompleted(job_id) => {
                     self.logs
                         .push(LogEvent::system(format!("Job #{} completed", job_id)));
-                    // Record job statistics
-                    self.record_job_stats(job_id);
+                    let follow_ups = self.follow_ups();
+                    follow_ups.notify_job_finished(job_id);
                     // Check if this job is part of a group and update group status
-                    self.check_group_completion(job_id);
+                    follow_ups.check_group_completion(job_id);
                     // Reload diff if this is the currently selected job
                     if self.selected_job_id == Some(job_id) {
                         self.load_inline_diff_for_selected();
                     }
+                    // Merge automatically: per-mode gated auto_merge first, then global auto_apply
+                    self.maybe_auto_merge(job_id);
                 }
                 ExecutorEvent::JobFailed(job_id, error) => {
                     self.logs.push(LogEvent::error(format!(
                         "Job #{} failed: {}",
                         job_id, error
                     )));
-                    // Record job statistics (including failures)
-                    self.record_job_stats(job_id);
+                    let follow_ups = self.follow_ups();
+                    follow_ups.notify_job_finished(job_id);
                     // Check if this job is part of a group and update group status
-                    self.check_group_completion(job_id);
+                    follow_ups.check_group_completion(job_id);
+                }
+                ExecutorEvent::JobStatsRecorded(record) => {
+                    self.check_job_achievements(&record);
                 }
                 ExecutorEvent::ChainStepCompleted {
                     job_id,
@@ -184,13 +189,7 @@ impl KycoApp {
                 Ok(outcome) => {
                     match outcome.target {
                         ApplyTarget::Single { job_id } => {
-                            if let Ok(mut jm) = self.job_manager.lock() {
-                                if let Some(job) = jm.get_mut(job_id) {
-                                    job.set_status(crate::JobStatus::Merged);
-                                    job.git_worktree_path = None;
-                                    job.branch_name = None;
-                                }
-                            }
+                            self.follow_ups().mark_merged(job_id);
                         }
                         ApplyTarget::Group {
                             group_id,
@@ -215,6 +214,7 @@ impl KycoApp {
                                 }
                             }
 
+                            self.follow_ups().notify_job_finished(selected_job_id);
                             self.comparison_state.close();
                         }
                     }
@@ -222,11 +222,27 @@ impl KycoApp {
                     self.logs.push(LogEvent::system(outcome.message));
                     self.apply_confirm_target = None;
                     self.apply_confirm_error = None;
-                    self.view_mode = ViewMode::JobList;
+                    self.apply_confirm_diverged = false;
+                    // Auto-apply merges run without the popup; keep the current view then.
+                    if self.view_mode == ViewMode::ApplyConfirmPopup {
+                        self.view_mode = ViewMode::JobList;
+                    }
                     self.refresh_jobs();
                 }
                 Err(err) => {
-                    self.apply_confirm_error = Some(err);
+                    if self.view_mode == ViewMode::ApplyConfirmPopup {
+                        self.apply_confirm_diverged = err.base_diverged();
+                        self.apply_confirm_error = Some(match err.hint() {
+                            Some(hint) => format!("{} {}", err.message, hint),
+                            None => err.message,
+                        });
+                    } else {
+                        self.logs.push(LogEvent::error(format!(
+                            "Auto-apply failed: {}",
+                            err.message
+                        )));
+                        self.apply_confirm_target = None;
+                    }
                 }
             }
         }
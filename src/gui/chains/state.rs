@@ -3,7 +3,7 @@
 use std::collections::HashSet;
 use std::path::Path;
 
-use crate::config::{ChainStep, Config, StateDefinition};
+use crate::config::{ChainStep, Config, StateDefinition, StepAction};
 use crate::gui::app::ViewMode;
 
 /// Parse comma-separated states, trim whitespace, remove empty strings, and deduplicate
@@ -109,6 +109,8 @@ pub struct ChainStepEdit {
     pub agent: String,      // Optional override
     pub inject_context: String,
     pub loop_to: String,    // Mode name to loop back to
+    /// Not editable here yet; carried through so saving keeps it
+    pub on_state: std::collections::HashMap<String, StepAction>,
 }
 
 impl From<&ChainStep> for ChainStepEdit {
@@ -128,6 +130,7 @@ impl From<&ChainStep> for ChainStepEdit {
             agent: step.agent.clone().unwrap_or_default(),
             inject_context: step.inject_context.clone().unwrap_or_default(),
             loop_to: step.loop_to.clone().unwrap_or_default(),
+            on_state: step.on_state.clone(),
         }
     }
 }
@@ -153,6 +156,7 @@ impl ChainStepEdit {
             } else {
                 Some(self.loop_to.trim().to_string())
             },
+            on_state: self.on_state.clone(),
         }
     }
 }
@@ -3,7 +3,7 @@
 use eframe::egui::{self, RichText};
 
 use crate::gui::theme::{BG_SECONDARY, TEXT_MUTED, TEXT_PRIMARY};
-use crate::{Job, LogEvent};
+use crate::{Job, LogEvent, LogLevel};
 
 use super::colors::log_color;
 use super::markdown::render_markdown_inline_colored;
@@ -33,11 +33,16 @@ pub(super) fn render_activity_log_inline(
     available_width: f32,
     commonmark_cache: &mut egui_commonmark::CommonMarkCache,
     filters: &mut ActivityLogFilters,
+    level: LogLevel,
 ) {
+    // Filtering is a view concern: the underlying log buffers are never modified
+    // here. The global buffer is pruned to `level` periodically; apply it now too
+    // so newly appended events are hidden right away.
     let total_log_count = job.log_events.len()
         + logs
             .iter()
             .filter(|e| e.job_id.is_none() || e.job_id == Some(job.id))
+            .filter(|e| e.matches_level(level))
             .count();
 
     let shown_log_count = job
@@ -47,9 +52,8 @@ pub(super) fn render_activity_log_inline(
         .count()
         + logs
             .iter()
-            .filter(|e| {
-                (e.job_id.is_none() || e.job_id == Some(job.id)) && filters.is_enabled(&e.kind)
-            })
+            .filter(|e| filters.matches_job(e, job.id) && filters.is_enabled(&e.kind))
+            .filter(|e| e.matches_level(level))
             .count();
 
     // Use stable id_salt based on job ID to prevent state reset when log count changes
@@ -84,10 +88,19 @@ pub(super) fn render_activity_log_inline(
                     ui.checkbox(&mut filters.show_error, "Error");
                     ui.checkbox(&mut filters.show_permission, "Permission");
 
+                    ui.separator();
+                    ui.checkbox(&mut filters.job_only, format!("Only job #{}", job.id))
+                        .on_hover_text("Hide global log events not tied to this job");
+
                     ui.separator();
                     ui.horizontal(|ui| {
                         if ui.button("Only text").clicked() {
+                            let job_only = filters.job_only;
                             *filters = ActivityLogFilters::default();
+                            filters.job_only = job_only;
+                        }
+                        if ui.button("Errors").clicked() {
+                            filters.set_errors_only();
                         }
                         if ui.button("All").clicked() {
                             filters.show_text = true;
@@ -116,8 +129,9 @@ pub(super) fn render_activity_log_inline(
 
         // Then show global logs filtered by job_id
         for event in logs {
-            if (event.job_id.is_none() || event.job_id == Some(job.id))
+            if filters.matches_job(event, job.id)
                 && filters.is_enabled(&event.kind)
+                && event.matches_level(level)
             {
                 let color = log_color(&event.kind);
                 render_activity_log_event(ui, event, commonmark_cache, color);
@@ -6,7 +6,7 @@ use crate::gui::theme::{BG_SECONDARY, TEXT_DIM, TEXT_MUTED, TEXT_PRIMARY};
 
 use super::colors::status_color;
 use super::prompt::build_prompt_preview;
-use crate::gui::diff::render_diff_content;
+use crate::gui::diff::{DiffLayout, render_diff_content_in_layout};
 
 use super::actions::render_action_buttons;
 use super::activity_log::render_activity_log_inline;
@@ -70,7 +70,12 @@ pub fn render_detail_panel(
                     ui.add_space(4.0);
 
                     if let Some(diff_content) = state.diff_content {
-                        render_diff_section_inline(ui, diff_content, available_width);
+                        render_diff_section_inline(
+                            ui,
+                            diff_content,
+                            state.diff_layout,
+                            available_width,
+                        );
                     } else {
                         ui.add_space(8.0);
                         egui::Frame::NONE
@@ -102,6 +107,7 @@ pub fn render_detail_panel(
                         available_width,
                         state.commonmark_cache,
                         state.activity_log_filters,
+                        state.config.settings.gui.log_level,
                     );
                 });
         } else {
@@ -201,7 +207,12 @@ fn render_prompt_section_collapsible(
 }
 
 /// Render diff section inline (no inner scroll - parent handles scrolling)
-fn render_diff_section_inline(ui: &mut egui::Ui, diff_content: &str, available_width: f32) {
+fn render_diff_section_inline(
+    ui: &mut egui::Ui,
+    diff_content: &str,
+    layout: &mut DiffLayout,
+    available_width: f32,
+) {
     let added = diff_content
         .lines()
         .filter(|l| l.starts_with('+') && !l.starts_with("+++"))
@@ -230,6 +241,21 @@ fn render_diff_section_inline(ui: &mut egui::Ui, diff_content: &str, available_w
                             .small(),
                     );
                 }
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let toggle_label = match layout {
+                        DiffLayout::Unified => "Side by side",
+                        DiffLayout::SideBySide => "Unified",
+                    };
+                    if ui
+                        .small_button(RichText::new(toggle_label).color(TEXT_DIM))
+                        .clicked()
+                    {
+                        *layout = match layout {
+                            DiffLayout::Unified => DiffLayout::SideBySide,
+                            DiffLayout::SideBySide => DiffLayout::Unified,
+                        };
+                    }
+                });
             });
             ui.add_space(4.0);
 
@@ -239,7 +265,7 @@ fn render_diff_section_inline(ui: &mut egui::Ui, diff_content: &str, available_w
                 .inner_margin(4.0)
                 .show(ui, |ui| {
                     ui.set_min_width(available_width - 24.0);
-                    render_diff_content(ui, diff_content);
+                    render_diff_content_in_layout(ui, diff_content, *layout);
                 });
         });
 }
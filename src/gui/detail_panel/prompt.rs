@@ -1,7 +1,7 @@
 //! Prompt building utilities for the detail panel
 
 use crate::Job;
-use crate::config::Config;
+use crate::config::{Config, append_prompt_suffix};
 
 /// Build prompt preview for a job (before it runs)
 pub fn build_prompt_preview(job: &Job, config: &Config) -> String {
@@ -20,7 +20,7 @@ pub fn build_prompt_preview(job: &Job, config: &Config) -> String {
         .replace("{target}", &job.target)
         .replace("{mode}", &job.skill)
         .replace("{description}", description)
-        .replace("{scope_type}", "file")
+        .replace("{scope_type}", job.scope_type())
         .replace("{ide_context}", ide_context);
 
     let mut full_prompt = String::new();
@@ -32,7 +32,10 @@ pub fn build_prompt_preview(job: &Job, config: &Config) -> String {
     }
 
     full_prompt.push_str("=== USER PROMPT ===\n");
-    full_prompt.push_str(&prompt);
+    full_prompt.push_str(&append_prompt_suffix(
+        prompt,
+        config.global_prompt_suffix_for(&job.skill),
+    ));
 
     full_prompt
 }
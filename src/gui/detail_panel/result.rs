@@ -65,6 +65,7 @@ pub(super) fn render_result_section(
                     if !formatted.is_empty() {
                         render_markdown_scroll(ui, &formatted, commonmark_cache);
                     }
+                    render_finding_references(ui, structured);
                 } else if let Some(text) = response_text {
                     // Fallback: show raw response text
                     if has_structured {
@@ -111,6 +112,7 @@ pub(super) fn render_result_section(
                     if !formatted.is_empty() {
                         render_markdown_scroll(ui, &formatted, commonmark_cache);
                     }
+                    render_finding_references(ui, structured);
                 } else if let Some(text) = response_text {
                     ui.label(RichText::new("Response:").small().color(TEXT_MUTED));
                     ui.add_space(4.0);
@@ -133,6 +135,8 @@ pub(super) fn render_result_section(
             }
         });
     }
+
+    render_usage_line(ui, job);
 }
 
 /// Format SDK structured output (findings, memory) as readable markdown
@@ -213,6 +217,38 @@ fn format_structured_output(value: &serde_json::Value) -> String {
     output
 }
 
+/// Render finding references as clickable links below the formatted findings
+fn render_finding_references(ui: &mut egui::Ui, structured: &serde_json::Value) {
+    let Some(findings) = structured.get("findings").and_then(|f| f.as_array()) else {
+        return;
+    };
+    for finding in findings {
+        let Some(references) = finding.get("references").and_then(|r| r.as_array()) else {
+            continue;
+        };
+        if references.is_empty() {
+            continue;
+        }
+        let title = finding.get("title").and_then(|t| t.as_str()).unwrap_or("Untitled Finding");
+        ui.add_space(6.0);
+        ui.label(
+            RichText::new(format!("References: {}", title))
+                .small()
+                .color(TEXT_MUTED),
+        );
+        for reference in references.iter().filter_map(|r| r.as_str()) {
+            match crate::bugbounty::reference_url(reference) {
+                Some(url) => {
+                    ui.hyperlink_to(RichText::new(reference).small(), url);
+                }
+                None => {
+                    ui.label(RichText::new(reference).small().color(TEXT_DIM));
+                }
+            }
+        }
+    }
+}
+
 /// Format a single finding as markdown
 fn format_finding(output: &mut String, finding: &serde_json::Value) {
     let title = finding.get("title").and_then(|t| t.as_str()).unwrap_or("Untitled Finding");
@@ -247,8 +283,27 @@ fn format_finding(output: &mut String, finding: &serde_json::Value) {
         output.push_str(&format!("**Confidence:** {}\n", conf));
     }
 
-    if let Some(score) = cvss {
-        output.push_str(&format!("**CVSS:** {:.1}\n", score));
+    let vector = finding
+        .get("cvss_vector")
+        .and_then(|v| v.as_str())
+        .filter(|v| !v.trim().is_empty());
+    match vector.map(crate::bugbounty::CvssVector::parse) {
+        Some(Ok(parsed)) => output.push_str(&format!(
+            "**CVSS:** {:.1} ({}) `{}`\n",
+            parsed.base_score(),
+            parsed.severity().as_str(),
+            parsed
+        )),
+        Some(Err(e)) => output.push_str(&format!("**CVSS:** invalid vector ({})\n", e)),
+        None => {
+            if let Some(score) = cvss {
+                output.push_str(&format!(
+                    "**CVSS:** {:.1} ({})\n",
+                    score,
+                    crate::bugbounty::severity_for_score(score).as_str()
+                ));
+            }
+        }
     }
 
     // Attack scenario
@@ -521,3 +576,16 @@ fn render_stats_bar(ui: &mut egui::Ui, job: &Job, result: &crate::JobResult) {
         }
     });
 }
+
+/// Render per-job token usage and cost (if the agent reported any)
+fn render_usage_line(ui: &mut egui::Ui, job: &Job) {
+    let Some(usage) = job.usage_string() else {
+        return;
+    };
+    ui.add_space(4.0);
+    ui.label(
+        RichText::new(format!("Tokens: {}", usage))
+            .small()
+            .color(TEXT_MUTED),
+    );
+}
@@ -4,6 +4,7 @@ use std::collections::HashMap;
 
 use crate::agent::bridge::PermissionMode;
 use crate::config::Config;
+use crate::gui::diff::DiffLayout;
 use crate::{AgentGroupId, JobId, LogEvent};
 
 /// Actions that can be triggered from the detail panel
@@ -39,6 +40,8 @@ pub struct ActivityLogFilters {
     pub show_error: bool,
     pub show_system: bool,
     pub show_permission: bool,
+    /// Hide global (unscoped) log events and show only the selected job's activity
+    pub job_only: bool,
 }
 
 impl Default for ActivityLogFilters {
@@ -51,6 +54,7 @@ impl Default for ActivityLogFilters {
             show_error: false,
             show_system: false,
             show_permission: false,
+            job_only: false,
         }
     }
 }
@@ -69,6 +73,24 @@ impl ActivityLogFilters {
         }
     }
 
+    /// Check whether a global log event belongs in the given job's activity view
+    pub(super) fn matches_job(&self, event: &LogEvent, job_id: JobId) -> bool {
+        match event.job_id {
+            Some(id) => id == job_id,
+            None => !self.job_only,
+        }
+    }
+
+    /// Show only errors (useful for spotting what went wrong in a noisy run)
+    pub(super) fn set_errors_only(&mut self) {
+        *self = Self {
+            show_text: false,
+            show_error: true,
+            job_only: self.job_only,
+            ..Self::default()
+        };
+    }
+
     pub(super) fn selected_summary(&self) -> String {
         let mut selected = 0usize;
         let mut label: Option<&'static str> = None;
@@ -90,11 +112,17 @@ impl ActivityLogFilters {
         consider(self.show_error, "Error");
         consider(self.show_permission, "Permission");
 
-        match (selected, label) {
+        let summary = match (selected, label) {
             (0, _) => "None".to_string(),
             (1, Some(name)) => name.to_string(),
             (n, Some(name)) => format!("{name} +{}", n.saturating_sub(1)),
             _ => "Selected".to_string(),
+        };
+
+        if self.job_only {
+            format!("{summary} (job)")
+        } else {
+            summary
         }
     }
 }
@@ -115,4 +143,6 @@ pub struct DetailPanelState<'a> {
     pub permission_mode_overrides: &'a HashMap<JobId, PermissionMode>,
     /// Diff content for the selected job (if available)
     pub diff_content: Option<&'a str>,
+    /// Diff layout, shared with the diff popup
+    pub diff_layout: &'a mut DiffLayout,
 }
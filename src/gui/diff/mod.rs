@@ -4,19 +4,22 @@
 //! background highlighting, and clear visual structure.
 
 mod render;
+mod side_by_side;
 mod state;
 
 use eframe::egui::Color32;
 
 // Re-export public API
-pub use render::{render_diff_content, render_diff_popup};
-pub use state::DiffState;
+pub use render::{render_diff_content, render_diff_content_in_layout, render_diff_popup};
+pub use state::{DiffLayout, DiffState};
 
 // Module-internal re-exports for tests
 #[cfg(test)]
 use render::parse_hunk_header;
 #[cfg(test)]
-use state::extract_file_path;
+use side_by_side::{SideCell, SideRow, align_side_by_side, highlight, syntax_for_path};
+#[cfg(test)]
+use state::{extract_file_path, split_diff_files};
 
 // Background colors for diff lines
 const BG_ADDED: Color32 = Color32::from_rgb(30, 50, 35);
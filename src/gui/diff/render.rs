@@ -7,7 +7,8 @@ use crate::gui::theme::{
     TEXT_MUTED, TEXT_PRIMARY,
 };
 
-use super::state::DiffState;
+use super::side_by_side::{SideRow, Syntax, render_side_by_side, render_side_by_side_inline};
+use super::state::{DiffLayout, DiffState};
 use super::{BG_ADDED, BG_HUNK, BG_REMOVED};
 
 /// Parsed hunk header info
@@ -47,11 +48,15 @@ pub(super) fn parse_hunk_header(line: &str) -> Option<HunkInfo> {
 /// Render the diff view popup
 ///
 /// Returns true if the close button was clicked
-pub fn render_diff_popup(ctx: &egui::Context, diff_state: &DiffState) -> bool {
+pub fn render_diff_popup(ctx: &egui::Context, diff_state: &mut DiffState) -> bool {
     let mut should_close = false;
 
     if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
-        return true;
+        if !diff_state.filter_focused {
+            return true;
+        }
+        // Esc in the filter input clears it instead of closing the popup
+        diff_state.set_filter(String::new());
     }
 
     let screen_rect = ctx.screen_rect();
@@ -71,7 +76,10 @@ pub fn render_diff_popup(ctx: &egui::Context, diff_state: &DiffState) -> bool {
                 .corner_radius(8.0),
         )
         .show(ctx, |ui| {
-            if let Some(path) = &diff_state.file_path {
+            if diff_state.files.len() > 1 {
+                render_file_bar(ui, diff_state);
+                ui.add_space(8.0);
+            } else if let Some(path) = &diff_state.file_path {
                 Frame::group(ui.style())
                     .fill(BG_SECONDARY)
                     .corner_radius(4.0)
@@ -90,7 +98,7 @@ pub fn render_diff_popup(ctx: &egui::Context, diff_state: &DiffState) -> bool {
                 ui.add_space(8.0);
             }
 
-            if let Some(diff) = &diff_state.content {
+            if diff_state.has_content() {
                 let available_height = ui.available_height() - 40.0; // Reserve space for button
 
                 Frame::group(ui.style())
@@ -98,12 +106,18 @@ pub fn render_diff_popup(ctx: &egui::Context, diff_state: &DiffState) -> bool {
                     .corner_radius(4.0)
                     .stroke(Stroke::new(1.0, BG_HIGHLIGHT))
                     .show(ui, |ui| {
-                        ScrollArea::vertical()
-                            .auto_shrink([false, false])
-                            .max_height(available_height)
-                            .show(ui, |ui| {
-                                render_diff_content(ui, diff);
-                            });
+                        if diff_state.layout == DiffLayout::SideBySide {
+                            render_side_by_side_files(ui, diff_state, available_height);
+                        } else if diff_state.files.len() > 1 {
+                            render_filtered_files(ui, diff_state, available_height);
+                        } else if let Some(diff) = &diff_state.content {
+                            ScrollArea::vertical()
+                                .auto_shrink([false, false])
+                                .max_height(available_height)
+                                .show(ui, |ui| {
+                                    render_diff_content(ui, diff);
+                                });
+                        }
                     });
             } else {
                 ui.vertical_centered(|ui| {
@@ -122,6 +136,17 @@ pub fn render_diff_popup(ctx: &egui::Context, diff_state: &DiffState) -> bool {
                     {
                         should_close = true;
                     }
+                    let toggle_label = match diff_state.layout {
+                        DiffLayout::Unified => "Side by side (s)",
+                        DiffLayout::SideBySide => "Unified (s)",
+                    };
+                    if diff_state.has_content()
+                        && ui
+                            .button(RichText::new(toggle_label).color(TEXT_DIM))
+                            .clicked()
+                    {
+                        diff_state.toggle_layout();
+                    }
                 });
             });
         });
@@ -129,6 +154,140 @@ pub fn render_diff_popup(ctx: &egui::Context, diff_state: &DiffState) -> bool {
     should_close
 }
 
+/// Render the file navigation bar (current file, filter input, prev/next)
+fn render_file_bar(ui: &mut egui::Ui, diff_state: &mut DiffState) {
+    let visible = diff_state.visible_files().len();
+    let position = if visible == 0 {
+        0
+    } else {
+        diff_state.current_file + 1
+    };
+    let current_path = diff_state
+        .visible_files()
+        .get(diff_state.current_file)
+        .map(|f| f.path.clone());
+
+    Frame::group(ui.style())
+        .fill(BG_SECONDARY)
+        .corner_radius(4.0)
+        .inner_margin(8.0)
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(RichText::new("📄").size(14.0));
+                let (path, color) = match &current_path {
+                    Some(path) => (path.as_str(), TEXT_PRIMARY),
+                    None => ("No files match filter", TEXT_MUTED),
+                };
+                ui.label(RichText::new(path).monospace().color(color).size(13.0));
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.button("▶").on_hover_text("Next file (n)").clicked() {
+                        diff_state.next_file();
+                    }
+                    ui.label(
+                        RichText::new(format!("{}/{}", position, visible))
+                            .color(TEXT_DIM)
+                            .size(12.0),
+                    );
+                    if ui.button("◀").on_hover_text("Previous file (N)").clicked() {
+                        diff_state.prev_file();
+                    }
+
+                    let mut filter = diff_state.filter.clone();
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut filter)
+                            .hint_text("Filter files (/)")
+                            .desired_width(180.0),
+                    );
+                    if diff_state.focus_filter {
+                        response.request_focus();
+                        diff_state.focus_filter = false;
+                    }
+                    diff_state.filter_focused = response.has_focus();
+                    if response.changed() {
+                        diff_state.set_filter(filter);
+                    }
+                });
+            });
+        });
+}
+
+/// Render only the file sections matching the filter, one scroll area per filter
+fn render_filtered_files(ui: &mut egui::Ui, diff_state: &mut DiffState, max_height: f32) {
+    let scroll_to_current = std::mem::take(&mut diff_state.scroll_to_current);
+    let current = diff_state.current_file;
+
+    ScrollArea::vertical()
+        .id_salt(("diff_filtered", diff_state.filter.trim().to_lowercase()))
+        .auto_shrink([false, false])
+        .max_height(max_height)
+        .show(ui, |ui| {
+            if diff_state.filter.trim().is_empty() && !diff_state.preamble.trim().is_empty() {
+                for line in diff_state.preamble.lines() {
+                    render_header_line(ui, line);
+                }
+            }
+
+            let files = diff_state.visible_files();
+            if files.is_empty() {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(50.0);
+                    ui.label(RichText::new("No files match filter").color(TEXT_MUTED));
+                });
+                return;
+            }
+
+            for (idx, file) in files.iter().enumerate() {
+                if idx > 0 {
+                    ui.add_space(8.0);
+                }
+                if idx == current && scroll_to_current {
+                    ui.scroll_to_cursor(Some(egui::Align::TOP));
+                }
+                render_diff_content(ui, &file.text);
+            }
+        });
+}
+
+/// Render the filtered file sections side by side in one virtualized scroll area
+fn render_side_by_side_files(ui: &mut egui::Ui, diff_state: &mut DiffState, max_height: f32) {
+    let scroll_to_current = std::mem::take(&mut diff_state.scroll_to_current);
+    let current = diff_state.current_file;
+    let filter = diff_state.filter.trim().to_lowercase();
+    let indices = diff_state.visible_file_indices();
+    let aligned = diff_state.aligned();
+
+    let mut rows: Vec<(&SideRow, Option<&'static Syntax>)> = Vec::new();
+    if filter.is_empty() {
+        rows.extend(aligned.preamble.iter().map(|row| (row, None)));
+    }
+    let mut scroll_to_row = None;
+    for (pos, &idx) in indices.iter().enumerate() {
+        if pos == current && scroll_to_current {
+            scroll_to_row = Some(rows.len());
+        }
+        let (file_rows, syntax) = &aligned.files[idx];
+        rows.extend(file_rows.iter().map(|row| (row, *syntax)));
+    }
+
+    if rows.is_empty() {
+        ui.vertical_centered(|ui| {
+            ui.add_space(50.0);
+            ui.label(RichText::new("No files match filter").color(TEXT_MUTED));
+        });
+        return;
+    }
+    render_side_by_side(ui, &rows, max_height, ("diff_side_by_side", filter), scroll_to_row);
+}
+
+/// Render diff content inline in the given layout
+pub fn render_diff_content_in_layout(ui: &mut egui::Ui, diff: &str, layout: DiffLayout) {
+    match layout {
+        DiffLayout::Unified => render_diff_content(ui, diff),
+        DiffLayout::SideBySide => render_side_by_side_inline(ui, diff),
+    }
+}
+
 /// Render diff content with line numbers and colored backgrounds
 ///
 /// This function can be used both in the diff popup and inline in other panels.
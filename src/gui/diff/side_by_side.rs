@@ -0,0 +1,406 @@
+//! Side-by-side diff layout
+//!
+//! Aligns a unified diff into old/new columns and paints only the rows in view,
+//! so large diffs stay scrollable without laying out every line each frame.
+
+use eframe::egui::{
+    self, Align2, Color32, FontId, Rect, ScrollArea, Sense, Stroke, TextFormat, Vec2,
+    text::LayoutJob,
+};
+
+use crate::gui::theme::{
+    ACCENT_CYAN, ACCENT_GREEN, ACCENT_PURPLE, ACCENT_RED, ACCENT_YELLOW, BG_HIGHLIGHT, TEXT_DIM,
+    TEXT_MUTED,
+};
+
+use super::render::parse_hunk_header;
+use super::state::{DiffFile, UNTRACKED_MARKER, split_diff_files};
+use super::{BG_ADDED, BG_HUNK, BG_REMOVED};
+
+const ROW_HEIGHT: f32 = 18.0;
+const NUMBER_WIDTH: f32 = 40.0;
+const FONT_SIZE: f32 = 12.0;
+
+/// One numbered line in either column
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct SideCell {
+    pub num: u32,
+    pub text: String,
+}
+
+/// One row of a side-by-side diff
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum SideRow {
+    /// File header (diff --git, index, ---, +++) or other non-hunk text
+    Header(String),
+    /// Hunk header (@@ ... @@)
+    Hunk(String),
+    /// Old line on the left, new line on the right; either side may be empty
+    Line {
+        left: Option<SideCell>,
+        right: Option<SideCell>,
+        changed: bool,
+    },
+}
+
+/// Keyword/string/comment rules for the simple syntax coloring
+pub(super) struct Syntax {
+    /// Space-separated keywords
+    keywords: &'static str,
+    line_comment: &'static str,
+    quotes: &'static [char],
+}
+
+const RUST: Syntax = Syntax {
+    keywords: "as async await break const continue crate dyn else enum false fn for if impl in \
+                 let loop match mod move mut pub ref return self Self static struct super trait \
+                 true type unsafe use where while",
+    line_comment: "//",
+    quotes: &['"'],
+};
+
+const PYTHON: Syntax = Syntax {
+    keywords: "and as assert async await break class continue def del elif else except False \
+                 finally for from if import in is lambda None not or pass raise return True try \
+                 while with yield",
+    line_comment: "#",
+    quotes: &['"', '\''],
+};
+
+const JAVASCRIPT: Syntax = Syntax {
+    keywords: "async await break case catch class const continue default else export extends \
+                 false for from function if import in instanceof interface let new null return \
+                 switch this throw true try type typeof undefined var while",
+    line_comment: "//",
+    quotes: &['"', '\'', '`'],
+};
+
+const GO: Syntax = Syntax {
+    keywords: "break case chan const continue default defer else false for func go if import \
+                 interface map nil package range return select struct switch true type var",
+    line_comment: "//",
+    quotes: &['"', '`'],
+};
+
+const C_LIKE: Syntax = Syntax {
+    keywords: "break case class const continue default else enum extends false final for if \
+                 import new null package private protected public return static struct switch \
+                 this throw true try void while",
+    line_comment: "//",
+    quotes: &['"', '\''],
+};
+
+const SHELL: Syntax = Syntax {
+    keywords: "case do done elif else esac export fi for function if in local return then while",
+    line_comment: "#",
+    quotes: &['"', '\''],
+};
+
+const CONFIG: Syntax = Syntax {
+    keywords: "false true",
+    line_comment: "#",
+    quotes: &['"', '\''],
+};
+
+/// Pick coloring rules from the file extension (None = plain text)
+pub(super) fn syntax_for_path(path: &str) -> Option<&'static Syntax> {
+    let ext = std::path::Path::new(path).extension()?.to_str()?;
+    match ext.to_ascii_lowercase().as_str() {
+        "rs" => Some(&RUST),
+        "py" => Some(&PYTHON),
+        "js" | "jsx" | "mjs" | "ts" | "tsx" => Some(&JAVASCRIPT),
+        "go" => Some(&GO),
+        "c" | "h" | "cc" | "cpp" | "hpp" | "java" | "kt" | "cs" | "swift" => Some(&C_LIKE),
+        "sh" | "bash" | "zsh" => Some(&SHELL),
+        "toml" | "yaml" | "yml" => Some(&CONFIG),
+        _ => None,
+    }
+}
+
+/// Side-by-side rows for the preamble and every file section, built once per diff
+pub(super) struct AlignedDiff {
+    pub preamble: Vec<SideRow>,
+    pub files: Vec<(Vec<SideRow>, Option<&'static Syntax>)>,
+}
+
+impl AlignedDiff {
+    pub fn new(preamble: &str, files: &[DiffFile]) -> Self {
+        Self {
+            preamble: align_side_by_side(preamble),
+            files: files
+                .iter()
+                .map(|f| (align_side_by_side(&f.text), syntax_for_path(&f.path)))
+                .collect(),
+        }
+    }
+}
+
+/// Align a unified diff into side-by-side rows.
+///
+/// Runs of removed lines are paired with the added lines that follow them; the
+/// longer run's leftover lines get an empty cell on the other side.
+pub(super) fn align_side_by_side(diff: &str) -> Vec<SideRow> {
+    let mut rows = Vec::new();
+    let mut removed: Vec<SideCell> = Vec::new();
+    let mut added: Vec<SideCell> = Vec::new();
+    let mut old_num: u32 = 0;
+    let mut new_num: u32 = 0;
+    let mut in_header = true;
+
+    for line in diff.lines() {
+        if line.starts_with("diff --git ") || line == UNTRACKED_MARKER {
+            in_header = true;
+        }
+
+        if line.starts_with("@@") {
+            flush_changes(&mut rows, &mut removed, &mut added);
+            if let Some(info) = parse_hunk_header(line) {
+                old_num = info.old_start;
+                new_num = info.new_start;
+            }
+            in_header = false;
+            rows.push(SideRow::Hunk(line.to_string()));
+            continue;
+        }
+
+        // "\ No newline at end of file" belongs to neither column
+        if in_header || line.starts_with('\\') {
+            flush_changes(&mut rows, &mut removed, &mut added);
+            rows.push(SideRow::Header(line.to_string()));
+            continue;
+        }
+
+        if let Some(rest) = line.strip_prefix('-') {
+            if !added.is_empty() {
+                flush_changes(&mut rows, &mut removed, &mut added);
+            }
+            removed.push(SideCell {
+                num: old_num,
+                text: rest.to_string(),
+            });
+            old_num += 1;
+        } else if let Some(rest) = line.strip_prefix('+') {
+            added.push(SideCell {
+                num: new_num,
+                text: rest.to_string(),
+            });
+            new_num += 1;
+        } else {
+            flush_changes(&mut rows, &mut removed, &mut added);
+            let text = line.strip_prefix(' ').unwrap_or(line);
+            rows.push(SideRow::Line {
+                left: Some(SideCell {
+                    num: old_num,
+                    text: text.to_string(),
+                }),
+                right: Some(SideCell {
+                    num: new_num,
+                    text: text.to_string(),
+                }),
+                changed: false,
+            });
+            old_num += 1;
+            new_num += 1;
+        }
+    }
+
+    flush_changes(&mut rows, &mut removed, &mut added);
+    rows
+}
+
+fn flush_changes(rows: &mut Vec<SideRow>, removed: &mut Vec<SideCell>, added: &mut Vec<SideCell>) {
+    let len = removed.len().max(added.len());
+    let mut left = removed.drain(..);
+    let mut right = added.drain(..);
+    for _ in 0..len {
+        rows.push(SideRow::Line {
+            left: left.next(),
+            right: right.next(),
+            changed: true,
+        });
+    }
+}
+
+/// Color keywords, strings, comments and numbers; everything else keeps `base`
+pub(super) fn highlight(text: &str, syntax: Option<&Syntax>, base: Color32) -> LayoutJob {
+    let mut job = LayoutJob::default();
+    let Some(syntax) = syntax else {
+        append(&mut job, text, base);
+        return job;
+    };
+
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let (len, color) = if rest.starts_with(syntax.line_comment) {
+            (rest.len(), TEXT_MUTED)
+        } else if syntax.quotes.contains(&c) {
+            (string_len(rest, c), ACCENT_YELLOW)
+        } else if c.is_alphanumeric() || c == '_' {
+            let len = rest
+                .find(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+                .unwrap_or(rest.len());
+            let word = &rest[..len];
+            let color = if syntax.keywords.split_whitespace().any(|k| k == word) {
+                ACCENT_PURPLE
+            } else if c.is_ascii_digit() {
+                ACCENT_CYAN
+            } else {
+                base
+            };
+            (len, color)
+        } else {
+            (c.len_utf8(), base)
+        };
+        append(&mut job, &rest[..len], color);
+        rest = &rest[len..];
+    }
+    job
+}
+
+/// Byte length of the string literal at the start of `s` (unterminated = rest of line)
+fn string_len(s: &str, quote: char) -> usize {
+    let mut escaped = false;
+    for (idx, ch) in s.char_indices().skip(1) {
+        if escaped {
+            escaped = false;
+        } else if ch == '\\' {
+            escaped = true;
+        } else if ch == quote {
+            return idx + ch.len_utf8();
+        }
+    }
+    s.len()
+}
+
+/// Append `text`, extending the previous section when the color is unchanged
+fn append(job: &mut LayoutJob, text: &str, color: Color32) {
+    if let Some(last) = job.sections.last_mut() {
+        if last.format.color == color && last.byte_range.end == job.text.len() {
+            job.text.push_str(text);
+            last.byte_range.end = job.text.len();
+            return;
+        }
+    }
+    job.append(
+        text,
+        0.0,
+        TextFormat::simple(FontId::monospace(FONT_SIZE), color),
+    );
+}
+
+/// Render aligned rows in a virtualized scroll area.
+///
+/// `scroll_to_row` scrolls the given row index to the top on this frame.
+pub(super) fn render_side_by_side(
+    ui: &mut egui::Ui,
+    rows: &[(&SideRow, Option<&'static Syntax>)],
+    max_height: f32,
+    id_salt: impl std::hash::Hash,
+    scroll_to_row: Option<usize>,
+) {
+    let stride = ROW_HEIGHT + ui.spacing().item_spacing.y;
+    let mut scroll = ScrollArea::vertical()
+        .id_salt(id_salt)
+        .auto_shrink([false, false])
+        .max_height(max_height);
+    if let Some(row) = scroll_to_row {
+        scroll = scroll.vertical_scroll_offset(row as f32 * stride);
+    }
+
+    let width = ui.available_width();
+    scroll.show_rows(ui, ROW_HEIGHT, rows.len(), |ui, range| {
+        for (row, syntax) in &rows[range] {
+            render_row(ui, row, *syntax, width);
+        }
+    });
+}
+
+/// Render a whole diff side by side without an inner scroll area (the parent scrolls)
+pub(super) fn render_side_by_side_inline(ui: &mut egui::Ui, diff: &str) {
+    let (preamble, files) = split_diff_files(diff);
+    let aligned = AlignedDiff::new(&preamble, &files);
+    let width = ui.available_width();
+    for row in &aligned.preamble {
+        render_row(ui, row, None, width);
+    }
+    for (rows, syntax) in &aligned.files {
+        for row in rows {
+            render_row(ui, row, *syntax, width);
+        }
+    }
+}
+
+fn render_row(ui: &mut egui::Ui, row: &SideRow, syntax: Option<&'static Syntax>, width: f32) {
+    let (rect, _) = ui.allocate_exact_size(Vec2::new(width, ROW_HEIGHT), Sense::hover());
+    let painter = ui.painter_at(rect);
+    let font = FontId::monospace(FONT_SIZE);
+
+    match row {
+        SideRow::Header(text) => {
+            painter.rect_filled(rect, 0.0, BG_HIGHLIGHT);
+            let pos = rect.left_center() + Vec2::new(8.0, 0.0);
+            painter.text(pos, Align2::LEFT_CENTER, text, font, TEXT_MUTED);
+        }
+        SideRow::Hunk(text) => {
+            painter.rect_filled(rect, 2.0, BG_HUNK);
+            let pos = rect.left_center() + Vec2::new(8.0, 0.0);
+            painter.text(pos, Align2::LEFT_CENTER, text, font, ACCENT_CYAN);
+        }
+        SideRow::Line {
+            left,
+            right,
+            changed,
+        } => {
+            let half = rect.width() / 2.0;
+            let left_rect = Rect::from_min_size(rect.min, Vec2::new(half - 1.0, ROW_HEIGHT));
+            let right_rect = Rect::from_min_size(
+                rect.min + Vec2::new(half + 1.0, 0.0),
+                Vec2::new(half - 1.0, ROW_HEIGHT),
+            );
+            let removed = (BG_REMOVED, ACCENT_RED);
+            let added = (BG_ADDED, ACCENT_GREEN);
+            render_cell(ui, left_rect, left.as_ref(), *changed, removed, syntax);
+            render_cell(ui, right_rect, right.as_ref(), *changed, added, syntax);
+            painter.vline(
+                rect.center().x,
+                rect.y_range(),
+                Stroke::new(1.0, BG_HIGHLIGHT),
+            );
+        }
+    }
+}
+
+fn render_cell(
+    ui: &egui::Ui,
+    rect: Rect,
+    cell: Option<&SideCell>,
+    changed: bool,
+    (bg, fg): (Color32, Color32),
+    syntax: Option<&'static Syntax>,
+) {
+    let painter = ui.painter_at(rect);
+    let Some(cell) = cell else {
+        return;
+    };
+
+    let color = if changed {
+        painter.rect_filled(rect, 0.0, bg);
+        fg
+    } else {
+        TEXT_DIM
+    };
+    painter.text(
+        rect.left_center() + Vec2::new(4.0, 0.0),
+        Align2::LEFT_CENTER,
+        format!("{:4}", cell.num),
+        FontId::monospace(11.0),
+        TEXT_MUTED,
+    );
+
+    let galley = ui.fonts(|f| f.layout_job(highlight(&cell.text, syntax, color)));
+    let pos = egui::pos2(
+        rect.left() + NUMBER_WIDTH,
+        rect.center().y - galley.size().y / 2.0,
+    );
+    painter.galley(pos, galley, color);
+}
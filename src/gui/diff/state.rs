@@ -1,5 +1,29 @@
 //! Diff state management
 
+use super::side_by_side::AlignedDiff;
+
+/// Marker line appended by the app when listing untracked files
+pub(super) const UNTRACKED_MARKER: &str = "--- Untracked files ---";
+
+/// A single file section of a unified diff
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffFile {
+    /// File path (from the `+++` header, falling back to `diff --git`)
+    pub path: String,
+    /// Raw diff text for this file, including its headers
+    pub text: String,
+}
+
+/// How the diff viewer lays out changes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiffLayout {
+    /// Single column unified diff
+    #[default]
+    Unified,
+    /// Old and new lines in aligned columns
+    SideBySide,
+}
+
 /// State for the diff viewer
 #[derive(Default)]
 pub struct DiffState {
@@ -10,35 +34,115 @@ pub struct DiffState {
     /// Diff scroll offset (reserved for future use)
     #[allow(dead_code)]
     pub scroll: f32,
+    /// Text preceding the first file section (e.g. workspace notice)
+    pub preamble: String,
+    /// Content split into per-file sections
+    pub files: Vec<DiffFile>,
+    /// Case-insensitive substring filter on file paths
+    pub filter: String,
+    /// Index of the current file within the filtered list
+    pub current_file: usize,
+    /// Scroll the current file into view on the next frame
+    pub scroll_to_current: bool,
+    /// Whether the filter input has keyboard focus
+    pub filter_focused: bool,
+    /// Request keyboard focus for the filter input on the next frame
+    pub focus_filter: bool,
+    /// Unified or side-by-side (kept across diffs)
+    pub layout: DiffLayout,
+    /// Side-by-side rows, aligned on first use for the current content
+    aligned: Option<AlignedDiff>,
 }
 
 impl DiffState {
     /// Create a new diff state
     pub fn new() -> Self {
-        Self {
-            content: None,
-            file_path: None,
-            scroll: 0.0,
-        }
+        Self::default()
     }
 
     /// Set the diff content to display
     pub fn set_content(&mut self, content: String) {
         self.file_path = extract_file_path(&content);
+        let (preamble, files) = split_diff_files(&content);
+        self.preamble = preamble;
+        self.files = files;
+        self.filter.clear();
+        self.current_file = 0;
+        self.scroll_to_current = false;
+        self.aligned = None;
         self.content = Some(content);
     }
 
-    /// Clear the diff content
+    /// Clear the diff content (the layout choice is kept)
     pub fn clear(&mut self) {
-        self.content = None;
-        self.file_path = None;
-        self.scroll = 0.0;
+        *self = Self {
+            layout: self.layout,
+            ..Self::default()
+        };
+    }
+
+    /// Switch between unified and side-by-side layout
+    pub fn toggle_layout(&mut self) {
+        self.layout = match self.layout {
+            DiffLayout::Unified => DiffLayout::SideBySide,
+            DiffLayout::SideBySide => DiffLayout::Unified,
+        };
+        self.scroll_to_current = true;
+    }
+
+    /// Side-by-side rows for the current content, aligned once and then reused
+    pub(super) fn aligned(&mut self) -> &AlignedDiff {
+        self.aligned
+            .get_or_insert_with(|| AlignedDiff::new(&self.preamble, &self.files))
+    }
+
+    /// Indices (into `files`) of the sections matching the current filter
+    pub(super) fn visible_file_indices(&self) -> Vec<usize> {
+        let needle = self.filter.trim().to_lowercase();
+        (0..self.files.len())
+            .filter(|&i| needle.is_empty() || self.files[i].path.to_lowercase().contains(&needle))
+            .collect()
     }
 
     /// Check if there is content to display
     pub fn has_content(&self) -> bool {
         self.content.is_some()
     }
+
+    /// File sections whose path matches the current filter
+    pub fn visible_files(&self) -> Vec<&DiffFile> {
+        let needle = self.filter.trim().to_lowercase();
+        self.files
+            .iter()
+            .filter(|f| needle.is_empty() || f.path.to_lowercase().contains(&needle))
+            .collect()
+    }
+
+    /// Update the filter, resetting the position within the filtered view
+    pub fn set_filter(&mut self, filter: String) {
+        if self.filter != filter {
+            self.filter = filter;
+            self.current_file = 0;
+        }
+    }
+
+    /// Jump to the next file section (wraps around)
+    pub fn next_file(&mut self) {
+        let count = self.visible_files().len();
+        if count > 0 {
+            self.current_file = (self.current_file + 1) % count;
+            self.scroll_to_current = true;
+        }
+    }
+
+    /// Jump to the previous file section (wraps around)
+    pub fn prev_file(&mut self) {
+        let count = self.visible_files().len();
+        if count > 0 {
+            self.current_file = (self.current_file + count - 1) % count;
+            self.scroll_to_current = true;
+        }
+    }
 }
 
 /// Extract file path from diff header (e.g., "diff --git a/foo.rs b/foo.rs")
@@ -53,3 +157,44 @@ pub(super) fn extract_file_path(diff: &str) -> Option<String> {
     }
     None
 }
+
+/// Split a unified diff into the leading preamble and per-file sections
+pub(super) fn split_diff_files(diff: &str) -> (String, Vec<DiffFile>) {
+    let mut preamble = String::new();
+    let mut sections: Vec<String> = Vec::new();
+
+    for line in diff.lines() {
+        if line.starts_with("diff --git ") || line == UNTRACKED_MARKER {
+            sections.push(String::new());
+        }
+        let target = sections.last_mut().unwrap_or(&mut preamble);
+        target.push_str(line);
+        target.push('\n');
+    }
+
+    let files = sections
+        .into_iter()
+        .map(|text| {
+            let path = if text.starts_with(UNTRACKED_MARKER) {
+                "(untracked)".to_string()
+            } else {
+                section_path(&text)
+            };
+            DiffFile { path, text }
+        })
+        .collect();
+
+    (preamble, files)
+}
+
+fn section_path(text: &str) -> String {
+    match extract_file_path(text) {
+        Some(path) if path != "/dev/null" => path,
+        _ => text
+            .lines()
+            .next()
+            .and_then(|l| l.rsplit_once(" b/"))
+            .map(|(_, b)| b.to_string())
+            .unwrap_or_default(),
+    }
+}
@@ -51,3 +51,119 @@ fn test_parse_hunk_header() {
     let invalid = "not a hunk";
     assert!(parse_hunk_header(invalid).is_none());
 }
+
+const MULTI_FILE_DIFF: &str = "--- Workspace changes (no worktree) ---\n\n\
+diff --git a/src/foo.rs b/src/foo.rs\n--- a/src/foo.rs\n+++ b/src/foo.rs\n@@ -1 +1 @@\n-a\n+b\n\
+diff --git a/docs/bar.md b/docs/bar.md\n--- a/docs/bar.md\n+++ /dev/null\n@@ -1 +0,0 @@\n-gone\n\
+\n--- Untracked files ---\nnew.txt\n";
+
+#[test]
+fn test_split_diff_files() {
+    let (preamble, files) = split_diff_files(MULTI_FILE_DIFF);
+    assert!(preamble.starts_with("--- Workspace changes"));
+    let paths: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(paths, vec!["src/foo.rs", "docs/bar.md", "(untracked)"]);
+    assert!(files[0].text.contains("+b"));
+    assert!(!files[0].text.contains("gone"));
+    assert!(files[2].text.contains("new.txt"));
+}
+
+#[test]
+fn test_diff_state_filter_and_navigation() {
+    let mut state = DiffState::new();
+    state.set_content(MULTI_FILE_DIFF.to_string());
+    assert_eq!(state.visible_files().len(), 3);
+
+    state.next_file();
+    assert_eq!(state.current_file, 1);
+    state.prev_file();
+    state.prev_file();
+    assert_eq!(state.current_file, 2);
+
+    state.set_filter("SRC".to_string());
+    assert_eq!(state.current_file, 0);
+    let visible = state.visible_files();
+    assert_eq!(visible.len(), 1);
+    assert_eq!(visible[0].path, "src/foo.rs");
+
+    state.set_filter("nomatch".to_string());
+    assert!(state.visible_files().is_empty());
+    state.next_file();
+    assert_eq!(state.current_file, 0);
+}
+
+#[test]
+fn test_align_side_by_side_pairs_removed_with_added() {
+    let diff = "diff --git a/src/foo.rs b/src/foo.rs\n--- a/src/foo.rs\n+++ b/src/foo.rs\n\
+@@ -3,4 +3,4 @@\n keep\n-old a\n-old b\n+new a\n tail\n+extra\n";
+    let rows = align_side_by_side(diff);
+    let cell = |num, text: &str| {
+        Some(SideCell {
+            num,
+            text: text.to_string(),
+        })
+    };
+
+    assert!(matches!(&rows[0], SideRow::Header(h) if h.starts_with("diff --git")));
+    assert!(matches!(&rows[2], SideRow::Header(h) if h == "+++ b/src/foo.rs"));
+    assert!(matches!(&rows[3], SideRow::Hunk(_)));
+    assert_eq!(
+        rows[4..].to_vec(),
+        vec![
+            SideRow::Line {
+                left: cell(3, "keep"),
+                right: cell(3, "keep"),
+                changed: false,
+            },
+            SideRow::Line {
+                left: cell(4, "old a"),
+                right: cell(4, "new a"),
+                changed: true,
+            },
+            SideRow::Line {
+                left: cell(5, "old b"),
+                right: None,
+                changed: true,
+            },
+            SideRow::Line {
+                left: cell(6, "tail"),
+                right: cell(5, "tail"),
+                changed: false,
+            },
+            SideRow::Line {
+                left: None,
+                right: cell(6, "extra"),
+                changed: true,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_highlight_colors_keywords_strings_and_comments() {
+    let base = eframe::egui::Color32::WHITE;
+    let rust = syntax_for_path("src/main.rs");
+    assert!(rust.is_some());
+    assert!(syntax_for_path("notes.unknown").is_none());
+
+    let job = highlight("let s = \"fn\"; // fn", rust, base);
+    let spans: Vec<(&str, bool)> = job
+        .sections
+        .iter()
+        .map(|s| (&job.text[s.byte_range.clone()], s.format.color == base))
+        .collect();
+    assert_eq!(
+        spans,
+        vec![
+            ("let", false),
+            (" s = ", true),
+            ("\"fn\"", false),
+            ("; ", true),
+            ("// fn", false),
+        ]
+    );
+
+    // Unknown extensions stay a single plain section
+    let plain = highlight("let x", None, base);
+    assert_eq!(plain.sections.len(), 1);
+}
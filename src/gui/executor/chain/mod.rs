@@ -12,7 +12,7 @@ use crate::bugbounty::{BugBountyJob, BugBountyManager, ContextInjector, NextCont
 use crate::config::Config;
 use crate::git::GitManager;
 use crate::job::JobManager;
-use crate::{ChainStepSummary, Job, JobResult, JobStatus, LogEvent};
+use crate::{ChainStepSummary, Job, JobResult, JobStatus, LogEvent, UsageStats};
 
 use super::ExecutorEvent;
 use super::JobLockGuard;
@@ -212,7 +212,7 @@ pub async fn run_chain_job(
             let _ = event_tx.send(ExecutorEvent::JobFailed(job_id, error));
             return;
         }
-        if !resolved_source_file.is_file() {
+        if !resolved_source_file.is_file() && !(job.is_dir_target() && resolved_source_file.is_dir()) {
             let error = format!(
                 "Invalid job input: source file is not a file: {}",
                 resolved_source_file.display()
@@ -280,8 +280,10 @@ pub async fn run_chain_job(
 
     // Multi-agent jobs always require worktrees for isolation
     let is_multi_agent_job = job.group_id.is_some();
-    let should_use_worktree =
-        config.settings.use_worktree || is_multi_agent_job || job.force_worktree;
+    let should_use_worktree = config.settings.use_worktree
+        || is_multi_agent_job
+        || job.force_worktree
+        || job.base_ref.is_some();
 
     let job_work_dir = job
         .workspace_path
@@ -310,7 +312,8 @@ pub async fn run_chain_job(
                 effective_git_manager,
                 job_id,
                 is_multi_agent_job,
-                job.force_worktree,
+                // No silent fallback to the main tree when a base ref was requested
+                job.force_worktree || job.base_ref.is_some(),
                 &job_work_dir,
                 event_tx,
                 job_manager,
@@ -443,6 +446,15 @@ pub async fn run_chain_job(
                         j.chain_current_step = Some(progress.step_index);
                     } else {
                         j.chain_current_step = Some(progress.step_index + 1);
+                        if let Some(target) = &progress.goto {
+                            let _ = event_tx_progress.send(ExecutorEvent::Log(
+                                LogEvent::system(format!(
+                                    "Chain job #{} branching to '{}'",
+                                    progress_job_id, target
+                                ))
+                                .for_job(progress_job_id),
+                            ));
+                        }
                         if let Some(step_result) = &progress.step_result {
                             let summary = step_result_to_summary(step_result);
                             let state = step_result
@@ -501,7 +513,7 @@ pub async fn run_chain_job(
                 let mut next_context_value: Option<serde_json::Value> = None;
                 let mut result_state: Option<String> = None;
 
-                if let Some(job_result) = JobResult::parse(output) {
+                if let Some(job_result) = config.result_parser_for(&step.skill).parse(output) {
                     result_state = job_result.state.clone();
                     if let Some(ref value) = job_result.next_context {
                         next_context_value = Some(value.clone());
@@ -587,6 +599,16 @@ pub async fn run_chain_job(
 
     let chain_ok = chain_result.success && bugbounty_contract_error.is_none();
     let total_steps = chain_result.step_results.len();
+    // Sum per-step usage so the chain job carries the total spend
+    let chain_usage = chain_result
+        .step_results
+        .iter()
+        .filter_map(|step| step.job_result.as_ref()?.usage.as_ref())
+        .fold(None, |total: Option<UsageStats>, usage| {
+            let mut total = total.unwrap_or_default();
+            total.merge(usage);
+            Some(total)
+        });
     if let Ok(mut manager) = job_manager.lock() {
         if let Some(j) = manager.get_mut(job_id) {
             let mut combined_details = Vec::new();
@@ -628,6 +650,7 @@ pub async fn run_chain_job(
                 state: chain_result.final_state.clone(),
                 next_context: aggregated_value.clone(),
                 raw_text: None,
+                usage: chain_usage,
             });
 
             j.set_file_stats(total_files_changed, 0, 0);
@@ -22,7 +22,7 @@ pub(super) fn setup_chain_worktree(
     job: &mut Job,
 ) -> Option<(PathBuf, bool)> {
     if let Some(git) = git_manager {
-        match git.create_worktree(job_id) {
+        match git.create_worktree(job_id, job.base_ref.as_deref()) {
             Ok(worktree_info) => {
                 let _ = event_tx.send(ExecutorEvent::Log(LogEvent::system(format!(
                     "Created worktree: {}",
@@ -1,5 +1,6 @@
 //! Executor event types for GUI communication
 
+use crate::stats::JobStatsRecord;
 use crate::{ChainStepSummary, LogEvent};
 
 /// Message to send back to GUI
@@ -11,6 +12,8 @@ pub enum ExecutorEvent {
     JobCompleted(u64),
     /// Job failed with error
     JobFailed(u64, String),
+    /// Stats row recorded for a finished job (sent after JobCompleted/JobFailed)
+    JobStatsRecorded(JobStatsRecord),
     /// Chain step completed
     ChainStepCompleted {
         job_id: u64,
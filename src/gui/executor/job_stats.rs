@@ -0,0 +1,193 @@
+//! Stats rows for finished jobs
+//!
+//! Written by the executor as soon as a job finishes, so the daily budget and the
+//! stats dashboard see every job no matter which frontend (GUI or `kyco serve`) runs it.
+
+use crate::Job;
+use crate::config::{AgentConfigToml, Config};
+use crate::stats::{JobStatsRecord, StatsManager};
+
+/// Record the stats row for a finished job (success or failure); returns it for
+/// follow-up checks such as achievements
+pub(super) fn record_job(
+    stats_manager: &StatsManager,
+    job: &Job,
+    config: &Config,
+) -> JobStatsRecord {
+    let record = job_to_stats_record(job, config.agent.get(&job.agent_id));
+    if let Err(e) = stats_manager.recorder().record_job(&record) {
+        tracing::warn!("Failed to record job stats: {}", e);
+    }
+    record
+}
+
+/// Convert a Job to a JobStatsRecord for database storage
+fn job_to_stats_record(job: &Job, agent_config: Option<&AgentConfigToml>) -> JobStatsRecord {
+    let now = chrono::Utc::now().timestamp_millis();
+
+    // Extract token usage from job (if available from bridge response)
+    let (input_tokens, output_tokens, cache_read, cache_write) = extract_token_usage(job);
+
+    // Use real cost if available, otherwise estimate from tokens using agent pricing
+    let cost_usd = job.cost_usd.unwrap_or_else(|| {
+        estimate_cost(
+            input_tokens,
+            output_tokens,
+            cache_read,
+            cache_write,
+            &job.agent_id,
+            agent_config,
+        )
+    });
+
+    // Calculate duration
+    let duration_ms = job
+        .started_at
+        .map(|start| {
+            job.finished_at
+                .unwrap_or(chrono::Utc::now())
+                .signed_duration_since(start)
+                .num_milliseconds() as u64
+        })
+        .unwrap_or(0);
+
+    // Get file stats
+    let (files_changed, lines_added, lines_removed) = job
+        .stats
+        .as_ref()
+        .map(|s| (s.files_changed, s.lines_added, s.lines_removed))
+        .unwrap_or((job.changed_files.len(), 0, 0));
+
+    JobStatsRecord {
+        job_id: job.id,
+        session_id: job.bridge_session_id.clone(),
+        mode: job.skill.clone(),
+        agent_id: job.agent_id.clone(),
+        agent_type: if job.agent_id.contains("codex") {
+            "codex"
+        } else {
+            "claude"
+        }
+        .to_string(),
+        status: format!("{:?}", job.status).to_lowercase(),
+        input_tokens,
+        output_tokens,
+        cache_read_tokens: cache_read,
+        cache_write_tokens: cache_write,
+        cost_usd,
+        duration_ms,
+        files_changed,
+        lines_added,
+        lines_removed,
+        created_at: job.created_at.timestamp_millis(),
+        started_at: job.started_at.map(|t| t.timestamp_millis()),
+        finished_at: job.finished_at.map(|t| t.timestamp_millis()).or(Some(now)),
+        workspace_path: job
+            .workspace_path
+            .as_ref()
+            .map(|p| p.to_string_lossy().to_string()),
+    }
+}
+
+/// Extract token usage from job (uses real values if available, falls back to estimate)
+fn extract_token_usage(job: &Job) -> (u64, u64, u64, u64) {
+    // Use real values from API response if available
+    if let (Some(input), Some(output)) = (job.input_tokens, job.output_tokens) {
+        return (
+            input,
+            output,
+            job.cache_read_tokens.unwrap_or(0),
+            job.cache_write_tokens.unwrap_or(0),
+        );
+    }
+
+    // Fallback: estimate based on response length (~4 chars per token)
+    let output_chars = job.full_response.as_ref().map(|r| r.len()).unwrap_or(0);
+    let input_chars = job.sent_prompt.as_ref().map(|p| p.len()).unwrap_or(100);
+    let input_tokens = (input_chars / 4) as u64;
+    let output_tokens = (output_chars / 4) as u64;
+
+    (input_tokens, output_tokens, 0, 0)
+}
+
+/// Estimate cost based on token usage
+/// Uses agent-specific pricing if configured, otherwise falls back to defaults.
+fn estimate_cost(
+    input: u64,
+    output: u64,
+    cache_read: u64,
+    cache_write: u64,
+    agent_id: &str,
+    agent_config: Option<&AgentConfigToml>,
+) -> f64 {
+    // Get pricing from agent config, or use defaults based on agent type
+    let (price_input, price_cached, price_output) = if let Some(cfg) = agent_config {
+        // Use configured pricing if available
+        let is_codex = agent_id.contains("codex");
+        let default_input = if is_codex { 1.75 } else { 3.00 };
+        let default_cached = if is_codex { 0.175 } else { 0.30 };
+        let default_output = if is_codex { 14.00 } else { 15.00 };
+
+        (
+            cfg.price_input.unwrap_or(default_input),
+            cfg.price_cached_input.unwrap_or(default_cached),
+            cfg.price_output.unwrap_or(default_output),
+        )
+    } else if agent_id.contains("codex") {
+        // Codex/OpenAI GPT-5.2 defaults (per 1M tokens)
+        (1.75, 0.175, 14.00)
+    } else {
+        // Claude Sonnet 4 defaults (per 1M tokens)
+        (3.00, 0.30, 15.00)
+    };
+
+    // Calculate cost (prices are per 1M tokens)
+    // - `input`: uncached input tokens (charged at input rate)
+    // - `cache_write`: tokens written to cache (charged at 1.25x input rate)
+    // - `cache_read`: cached input tokens (discounted)
+    // - `output`: output tokens
+    let input_cost = input as f64 * price_input / 1_000_000.0;
+    let cache_write_cost = cache_write as f64 * price_input * 1.25 / 1_000_000.0;
+    let cache_read_cost = cache_read as f64 * price_cached / 1_000_000.0;
+    let output_cost = output as f64 * price_output / 1_000_000.0;
+
+    input_cost + cache_write_cost + cache_read_cost + output_cost
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JobStatus, ScopeDefinition};
+    use chrono::Utc;
+    use std::path::PathBuf;
+
+    #[test]
+    fn record_job_counts_towards_todays_budget_totals() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let stats_manager =
+            StatsManager::with_path(&dir.path().join("stats.db")).expect("stats db");
+        let mut job = Job::new(
+            7,
+            "fix".to_string(),
+            ScopeDefinition::file(PathBuf::from("src/lib.rs")),
+            "src/lib.rs".to_string(),
+            None,
+            "claude".to_string(),
+            PathBuf::from("src/lib.rs"),
+            1,
+            None,
+        );
+        job.input_tokens = Some(1_000);
+        job.output_tokens = Some(500);
+        job.cost_usd = Some(0.25);
+        job.started_at = Some(Utc::now());
+        job.set_status(JobStatus::Failed);
+
+        let record = record_job(&stats_manager, &job, &Config::default());
+
+        assert_eq!(record.status, "failed");
+        let (tokens, cost) = stats_manager.query().get_today_totals().expect("totals");
+        assert_eq!(tokens, 1_500);
+        assert!((cost - 0.25).abs() < 1e-9);
+    }
+}
@@ -24,8 +24,9 @@ pub fn spawn_log_forwarder(
                 if let Some(session_id) = args.get("session_id").and_then(|v| v.as_str()) {
                     let mut maybe_interrupt: Option<(String, String)> = None;
                     if let Ok(mut manager) = job_manager.lock() {
+                        // Persisted immediately so a crashed run can be resumed
+                        manager.set_session_id(job_id, session_id);
                         if let Some(job) = manager.get_mut(job_id) {
-                            job.bridge_session_id = Some(session_id.to_string());
                             if job.status == JobStatus::Running
                                 && job.cancel_requested
                                 && !job.cancel_sent
@@ -36,7 +37,6 @@ pub fn spawn_log_forwarder(
                                     Some((job.agent_id.clone(), session_id.to_string()));
                             }
                         }
-                        manager.touch();
                     }
 
                     if let Some((agent_id, session_id_owned)) = maybe_interrupt {
@@ -5,22 +5,25 @@
 mod chain;
 mod event;
 mod git_utils;
+mod job_stats;
 mod log_forwarder;
 mod run_job;
 mod worktree_paths;
 mod worktree_setup;
 
+use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::mpsc::Sender;
 use std::sync::{Arc, Mutex, RwLock};
 use std::thread;
 use std::time::Duration;
 
 use crate::agent::AgentRegistry;
-use crate::config::Config;
+use crate::config::{BudgetSettings, Config};
 use crate::git::GitManager;
 use crate::job::JobManager;
+use crate::stats::StatsManager;
 use crate::{Job, JobStatus, LogEvent};
 
 pub use event::ExecutorEvent;
@@ -48,6 +51,69 @@ impl Drop for JobLockGuard {
     }
 }
 
+/// Per-agent `max_concurrent` overrides from the config.
+fn agent_limits(config: &Config) -> HashMap<String, usize> {
+    config
+        .agent
+        .iter()
+        .filter_map(|(id, agent)| agent.max_concurrent.map(|limit| (id.clone(), limit)))
+        .collect()
+}
+
+/// Effective concurrency limit for an agent: its own override, capped by `max_jobs`.
+fn agent_job_limit(overrides: &HashMap<String, usize>, agent_id: &str, max_jobs: usize) -> usize {
+    overrides
+        .get(agent_id)
+        .map_or(max_jobs, |&limit| limit.min(max_jobs))
+}
+
+/// Pick the queued jobs that can start now without exceeding any agent's limit.
+///
+/// Running jobs count against their agent; queued jobs over the limit are left
+/// out (they stay Queued and are reconsidered on the next tick).
+fn select_startable_jobs<'a>(
+    jobs: &[&'a Job],
+    limit_for: impl Fn(&str) -> usize,
+) -> Vec<&'a Job> {
+    let mut active_per_agent: HashMap<&str, usize> = HashMap::new();
+    for job in jobs.iter().filter(|j| j.status == JobStatus::Running) {
+        *active_per_agent.entry(job.agent_id.as_str()).or_insert(0) += 1;
+    }
+
+    let mut queued: Vec<&'a Job> = jobs
+        .iter()
+        .copied()
+        .filter(|j| j.status == JobStatus::Queued)
+        .collect();
+    // Oldest first, so a capped agent's backlog drains in order
+    queued.sort_by_key(|j| j.creation_order());
+
+    queued
+        .into_iter()
+        .filter(|job| {
+            let active = active_per_agent.entry(job.agent_id.as_str()).or_insert(0);
+            if *active < limit_for(&job.agent_id) {
+                *active += 1;
+                true
+            } else {
+                false
+            }
+        })
+        .collect()
+}
+
+/// Check today's recorded usage against the configured daily budget.
+///
+/// Returns the reason when the budget is exhausted. A stats database that cannot
+/// be read never blocks the queue.
+fn budget_exceeded(budget: &BudgetSettings, stats: Option<&StatsManager>) -> Option<String> {
+    if !budget.is_enabled() {
+        return None;
+    }
+    let (tokens_today, cost_today) = stats?.query().get_today_totals().ok()?;
+    budget.exceeded_reason(tokens_today, cost_today)
+}
+
 /// Start the job executor in a background thread
 pub fn start_executor(
     work_dir: PathBuf,
@@ -55,6 +121,7 @@ pub fn start_executor(
     job_manager: Arc<Mutex<JobManager>>,
     event_tx: Sender<ExecutorEvent>,
     max_concurrent_jobs: Arc<AtomicUsize>,
+    queue_paused: Arc<AtomicBool>,
 ) {
     thread::spawn(move || {
         let rt = tokio::runtime::Runtime::new().expect("Failed to create tokio runtime");
@@ -64,6 +131,7 @@ pub fn start_executor(
             job_manager,
             event_tx,
             max_concurrent_jobs,
+            queue_paused,
         ));
     });
 }
@@ -74,16 +142,28 @@ async fn executor_loop(
     job_manager: Arc<Mutex<JobManager>>,
     event_tx: Sender<ExecutorEvent>,
     max_concurrent_jobs: Arc<AtomicUsize>,
+    queue_paused: Arc<AtomicBool>,
 ) {
     let agent_registry = AgentRegistry::new();
     let git_manager = GitManager::new(&work_dir).ok();
+    let stats_manager = StatsManager::new().ok();
 
     // Cache config-derived values to reduce RwLock contention in the hot loop
     let mut cached_use_worktree = config
         .read()
         .map(|cfg| cfg.settings.use_worktree)
         .unwrap_or(false);
+    let mut cached_agent_limits = config
+        .read()
+        .map(|cfg| agent_limits(&cfg))
+        .unwrap_or_default();
+    let mut cached_budget = config
+        .read()
+        .map(|cfg| cfg.settings.budget.clone())
+        .unwrap_or_default();
     let mut config_check_counter = 0u32;
+    // Whether the "budget exceeded" error was already logged for the current hold
+    let mut budget_hold_logged = false;
 
     loop {
         // Only re-read config every 10 iterations (~5 seconds) to reduce lock contention
@@ -94,8 +174,20 @@ async fn executor_loop(
                 .read()
                 .map(|cfg| cfg.settings.use_worktree)
                 .unwrap_or(false);
+            cached_agent_limits = config
+                .read()
+                .map(|cfg| agent_limits(&cfg))
+                .unwrap_or_default();
+            cached_budget = config
+                .read()
+                .map(|cfg| cfg.settings.budget.clone())
+                .unwrap_or_default();
         }
         let should_use_worktree = cached_use_worktree;
+        let budget_reason = budget_exceeded(&cached_budget, stats_manager.as_ref());
+        if budget_reason.is_none() {
+            budget_hold_logged = false;
+        }
 
         let queued_jobs = {
             let Ok(mut manager) = job_manager.lock() else {
@@ -106,12 +198,6 @@ async fn executor_loop(
                 tokio::time::sleep(Duration::from_millis(100)).await;
                 continue;
             };
-            // Count running jobs per agent (concurrency limit is per-agent, not global)
-            let mut running_per_agent: std::collections::HashMap<String, usize> =
-                std::collections::HashMap::new();
-            for job in manager.jobs().iter().filter(|j| j.status == JobStatus::Running) {
-                *running_per_agent.entry(job.agent_id.clone()).or_insert(0) += 1;
-            }
 
             let blocked_jobs: Vec<_> = manager
                 .jobs()
@@ -139,34 +225,38 @@ async fn executor_loop(
                 }
             }
 
-            // Calculate available slots per agent and collect eligible jobs
-            // The max_concurrent_jobs limit applies to EACH agent independently
+            // The max_concurrent_jobs limit applies to EACH agent independently;
+            // an agent's own `max_concurrent` can only tighten it.
             let max_jobs = max_concurrent_jobs.load(Ordering::Relaxed);
+            let mut jobs = manager.jobs();
 
-            // Track how many more jobs we can start per agent
-            let mut slots_per_agent: std::collections::HashMap<String, usize> =
-                std::collections::HashMap::new();
+            // Over budget: queued jobs stay Queued unless they were started with an override
+            if let Some(reason) = &budget_reason {
+                let held = jobs
+                    .iter()
+                    .filter(|j| j.status == JobStatus::Queued && !j.ignore_budget)
+                    .count();
+                if held > 0 && !budget_hold_logged {
+                    budget_hold_logged = true;
+                    let _ = event_tx.send(ExecutorEvent::Log(LogEvent::error(format!(
+                        "budget exceeded: {} - {} queued job(s) held (use --ignore-budget to override)",
+                        reason, held
+                    ))));
+                }
+                jobs.retain(|j| j.status != JobStatus::Queued || j.ignore_budget);
+            }
 
-            let queued_jobs: Vec<Job> = manager
-                .jobs()
-                .iter()
-                .filter(|j| j.status == JobStatus::Queued)
-                .filter(|j| {
-                    let agent = &j.agent_id;
-                    let running = running_per_agent.get(agent).copied().unwrap_or(0);
-                    let used = slots_per_agent.get(agent).copied().unwrap_or(0);
-                    let available = max_jobs.saturating_sub(running).saturating_sub(used);
-                    if available > 0 {
-                        *slots_per_agent.entry(agent.clone()).or_insert(0) += 1;
-                        true
-                    } else {
-                        false
-                    }
-                })
-                .map(|j| (*j).clone())
-                .collect();
+            // Paused queue (`kyco queue pause`): nothing new starts, running jobs continue
+            if queue_paused.load(Ordering::Relaxed) {
+                jobs.retain(|j| j.status != JobStatus::Queued);
+            }
 
-            queued_jobs
+            select_startable_jobs(&jobs, |agent_id| {
+                agent_job_limit(&cached_agent_limits, agent_id, max_jobs)
+            })
+            .into_iter()
+            .cloned()
+            .collect::<Vec<Job>>()
         };
 
         if !queued_jobs.is_empty() {
@@ -175,8 +265,10 @@ async fn executor_loop(
 
             for job in queued_jobs {
                 let is_multi_agent = job.group_id.is_some();
-                let needs_lock_check =
-                    !should_use_worktree && !is_multi_agent && !job.force_worktree;
+                let needs_lock_check = !should_use_worktree
+                    && !is_multi_agent
+                    && !job.force_worktree
+                    && job.base_ref.is_none();
 
                 if needs_lock_check {
                     let Ok(mut manager) = job_manager.lock() else {
@@ -214,9 +306,11 @@ async fn executor_loop(
                 let job_manager = Arc::clone(&job_manager);
                 let agent_registry = agent_registry.clone();
                 let git_manager = git_manager.clone();
+                let stats_manager = stats_manager.clone();
                 let event_tx = event_tx.clone();
 
                 spawn_handles.push(tokio::spawn(async move {
+                    let job_id = job.id;
                     run_job::run_job(
                         &work_dir,
                         &config_snapshot,
@@ -227,6 +321,15 @@ async fn executor_loop(
                         job,
                     )
                     .await;
+
+                    // Record the stats row here so the budget sees the job in every frontend
+                    let finished = job_manager.lock().ok().and_then(|manager| {
+                        manager.get(job_id).filter(|j| j.is_finished()).cloned()
+                    });
+                    if let (Some(stats_manager), Some(job)) = (&stats_manager, finished) {
+                        let record = job_stats::record_job(stats_manager, &job, &config_snapshot);
+                        let _ = event_tx.send(ExecutorEvent::JobStatsRecorded(record));
+                    }
                 }));
             }
 
@@ -238,3 +341,63 @@ async fn executor_loop(
         tokio::time::sleep(Duration::from_millis(500)).await;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::JobId;
+    use crate::domain::test_support;
+
+    fn job(id: JobId, agent: &str, status: JobStatus) -> Job {
+        let mut job = test_support::job(id);
+        job.agent_id = agent.to_string();
+        job.set_status(status);
+        job
+    }
+
+    #[test]
+    fn per_agent_limits_gate_queued_jobs_independently() {
+        let jobs = vec![
+            job(1, "claude", JobStatus::Running),
+            job(2, "claude", JobStatus::Queued),
+            job(3, "claude", JobStatus::Queued),
+            job(4, "codex", JobStatus::Queued),
+            job(5, "codex", JobStatus::Queued),
+            job(6, "codex", JobStatus::Queued),
+        ];
+        let refs: Vec<&Job> = jobs.iter().collect();
+
+        let overrides = HashMap::from([("claude".to_string(), 2), ("codex".to_string(), 10)]);
+        let started: Vec<JobId> =
+            select_startable_jobs(&refs, |agent| agent_job_limit(&overrides, agent, 3))
+                .iter()
+                .map(|j| j.id)
+                .collect();
+
+        // claude: 1 running + 1 started hits its own limit of 2;
+        // codex: its override of 10 is capped by the global 3
+        assert_eq!(started, vec![2, 4, 5, 6]);
+    }
+
+    #[test]
+    fn queued_jobs_start_oldest_first_even_with_random_ids() {
+        let mut oldest = job(900, "claude", JobStatus::Queued);
+        oldest.created_at = chrono::DateTime::UNIX_EPOCH;
+        let jobs = vec![job(12, "claude", JobStatus::Queued), oldest];
+        let refs: Vec<&Job> = jobs.iter().collect();
+
+        let started: Vec<JobId> = select_startable_jobs(&refs, |_| 1)
+            .iter()
+            .map(|j| j.id)
+            .collect();
+        assert_eq!(started, vec![900]);
+    }
+
+    #[test]
+    fn agents_without_override_use_global_limit() {
+        let overrides = HashMap::from([("codex".to_string(), 1)]);
+        assert_eq!(agent_job_limit(&overrides, "claude", 4), 4);
+        assert_eq!(agent_job_limit(&overrides, "codex", 4), 1);
+        assert_eq!(agent_job_limit(&overrides, "codex", 0), 0);
+    }
+}
@@ -1,15 +1,16 @@
 //! Single job execution logic
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc::Sender;
 use std::sync::{Arc, Mutex};
 
 use crate::agent::AgentRegistry;
-use crate::bugbounty::{BugBountyJob, BugBountyManager, ContextInjector};
+use crate::agent::bridge::BridgeClient;
+use crate::bugbounty::{BugBountyJob, BugBountyManager, ContextInjector, ProjectScope};
 use crate::config::Config;
 use crate::git::GitManager;
 use crate::job::JobManager;
-use crate::{Job, JobStatus, LogEvent};
+use crate::{AgentConfig, Job, JobStatus, LogEvent};
 
 use super::ExecutorEvent;
 use super::JobLockGuard;
@@ -54,6 +55,44 @@ fn is_valid_json_schema_object(schema: &str) -> bool {
         .is_some_and(|v| v.is_object())
 }
 
+/// Whether the resolved agent config can not write files (read-only agent or audit mode).
+fn blocks_writes(agent_config: &AgentConfig) -> bool {
+    agent_config.read_only
+        || agent_config.sandbox.as_deref() == Some("read-only")
+        || agent_config
+            .disallowed_tools
+            .iter()
+            .any(|t| t == "Write" || t == "Edit")
+}
+
+/// Paths that fall outside the project's path-based scope.
+///
+/// Relative paths are resolved against `base_dir` (the job's working directory).
+/// Paths outside `project_root` are out of scope as well.
+fn out_of_scope_paths(
+    scope: &ProjectScope,
+    project_root: &Path,
+    base_dir: &Path,
+    paths: &[PathBuf],
+) -> Vec<PathBuf> {
+    paths
+        .iter()
+        .filter(|path| {
+            let abs = if path.is_absolute() {
+                path.to_path_buf()
+            } else {
+                base_dir.join(path)
+            };
+            let abs = abs.canonicalize().unwrap_or(abs);
+            match abs.strip_prefix(project_root) {
+                Ok(rel) => !scope.path_in_scope(rel),
+                Err(_) => true,
+            }
+        })
+        .cloned()
+        .collect()
+}
+
 /// Run a single job (non-chain)
 pub async fn run_job(
     work_dir: &PathBuf,
@@ -121,7 +160,7 @@ pub async fn run_job(
             let _ = event_tx.send(ExecutorEvent::JobFailed(job_id, error));
             return;
         }
-        if !resolved_source_file.is_file() {
+        if !resolved_source_file.is_file() && !(job.is_dir_target() && resolved_source_file.is_dir()) {
             let error = format!(
                 "Invalid job input: source file is not a file: {}",
                 resolved_source_file.display()
@@ -163,60 +202,6 @@ pub async fn run_job(
             .collect()
     }
 
-    fn format_focus_findings(
-        bb: &BugBountyManager,
-        project_id: &str,
-        finding_ids: &[String],
-    ) -> Option<String> {
-        let ids = cleaned_finding_ids(finding_ids);
-        if ids.is_empty() {
-            return None;
-        }
-
-        let mut lines = Vec::new();
-        lines.push("These findings are explicitly linked to this job (verification/triage targets).".to_string());
-        lines.push("If you update an existing finding, include its `id` in `next_context.findings[]`.".to_string());
-        lines.push(String::new());
-
-        for fid in ids {
-            match bb.get_finding(&fid).ok().flatten() {
-                Some(f) if f.project_id == project_id => {
-                    let sev = f
-                        .severity
-                        .map(|s| s.as_str().to_uppercase())
-                        .unwrap_or_else(|| "-".to_string());
-                    lines.push(format!(
-                        "- **{}** [{}] {} ({})",
-                        f.id,
-                        sev,
-                        f.title,
-                        f.status.as_str()
-                    ));
-                    if let Some(ref scenario) = f.attack_scenario {
-                        lines.push(format!("  - Attack: {}", scenario));
-                    }
-                    if !f.affected_assets.is_empty() {
-                        lines.push(format!("  - Assets: {}", f.affected_assets.join(", ")));
-                    }
-                    if let Some(ref taint) = f.taint_path {
-                        lines.push(format!("  - Taint: {}", taint));
-                    }
-                }
-                Some(f) => {
-                    lines.push(format!(
-                        "- **{}** (belongs to project `{}`; current job project is `{}`)",
-                        fid, f.project_id, project_id
-                    ));
-                }
-                None => {
-                    lines.push(format!("- **{}** (not found)", fid));
-                }
-            }
-        }
-
-        Some(lines.join("\n"))
-    }
-
     fn link_requested_findings(
         bb: &BugBountyManager,
         project_id: &str,
@@ -421,11 +406,9 @@ pub async fn run_job(
 
                     match injected_result {
                         Ok(mut injected) => {
-                            if let Some(focus) = format_focus_findings(
-                                &bb,
-                                &project_id,
-                                &job.bugbounty_finding_ids,
-                            ) {
+                            if let Some(focus) = ContextInjector::new(bb.clone())
+                                .focus_findings(&project_id, &job.bugbounty_finding_ids)
+                            {
                                 injected.focus_findings = Some(focus);
                             }
 
@@ -524,6 +507,8 @@ pub async fn run_job(
         Some(false) => false, // Mode/chain explicitly disables worktree
         None => config.settings.use_worktree || is_multi_agent_job || job.force_worktree,
     };
+    // A requested base ref only makes sense in a worktree branched off it
+    let should_use_worktree = should_use_worktree || job.base_ref.is_some();
 
     // Check if we have a custom workspace different from work_dir (before taking ownership)
     let has_custom_workspace = job.workspace_path.as_ref().is_some_and(|p| p != work_dir);
@@ -554,7 +539,9 @@ pub async fn run_job(
                 effective_git_manager,
                 job_id,
                 is_multi_agent_job,
-                job.force_worktree,
+                // No silent fallback to the main tree when a base ref was requested
+                job.force_worktree || job.base_ref.is_some(),
+                config.settings.dirty_repo_policy,
                 &job_work_dir,
                 event_tx,
                 job_manager,
@@ -628,6 +615,9 @@ pub async fn run_job(
         }
     }
 
+    // Path-based scope of the BugBounty project plus its absolute root, if it has one.
+    let mut scope_guard: Option<(ProjectScope, PathBuf)> = None;
+
     // BugBounty hardening (best-effort): convert ToolPolicy into tool-level blocks.
     if let Some(project_id) = bugbounty_project_id.as_deref() {
         if let Ok(bb) = BugBountyManager::new() {
@@ -669,6 +659,9 @@ pub async fn run_job(
                             .env
                             .insert("KYCO_BUGBOUNTY_SCOPE_JSON".to_string(), json);
                     }
+                    if scope.has_path_rules() {
+                        scope_guard = Some((scope.clone(), root_abs.clone()));
+                    }
                 }
                 if let Some(ref policy) = project.tool_policy {
                     if let Ok(json) = serde_json::to_string(policy) {
@@ -704,11 +697,28 @@ pub async fn run_job(
         }
     }
 
+    // Read-only/audit runs cannot change files, so the only scope risk is the target itself.
+    if let Some((scope, project_root)) = scope_guard.as_ref() {
+        if blocks_writes(&agent_config) && !is_prompt_only_job {
+            let target = std::slice::from_ref(&job.source_file);
+            if !out_of_scope_paths(scope, project_root, &worktree_path, target).is_empty() {
+                let _ = event_tx.send(ExecutorEvent::Log(LogEvent::error(format!(
+                    "Job #{}: target {} is outside the scope of BugBounty project {}",
+                    job_id,
+                    job.source_file.display(),
+                    bugbounty_project_id.as_deref().unwrap_or_default()
+                ))));
+            }
+        }
+    }
+
     // All agents now use persistent sessions (SessionMode removed)
     let is_repl = true;
     if let Ok(mut manager) = job_manager.lock() {
         if let Some(j) = manager.get_mut(job_id) {
             j.is_repl = is_repl;
+            // One-shot: a later restart of this job starts a fresh session
+            j.resume_requested = false;
         }
     }
 
@@ -735,11 +745,48 @@ pub async fn run_job(
     // Track git stats info for async calculation after lock release
     let mut git_stats_info: Option<(usize, Option<String>)> = None;
 
-    match adapter
-        .run(&job, &worktree_path, &agent_config, log_tx)
-        .await
-    {
+    let agent_job = config.job_with_prompt_suffix(&job);
+    let run_future = crate::agent::run_or_resume(
+        adapter.as_ref(),
+        &agent_job,
+        &worktree_path,
+        &agent_config,
+        log_tx,
+    );
+    let run_result = match agent_config.timeout_secs.filter(|secs| *secs > 0) {
+        Some(secs) => {
+            match tokio::time::timeout(std::time::Duration::from_secs(secs), run_future).await {
+                Ok(result) => result,
+                Err(_) => {
+                    interrupt_timed_out_session(job_manager, job_id);
+                    Err(anyhow::anyhow!("timed out after {}s", secs))
+                }
+            }
+        }
+        None => run_future.await,
+    };
+
+    match run_result {
         Ok(mut result) => {
+            let result_parser = config.result_parser_for(&job.skill);
+            let out_of_scope_files = scope_guard
+                .as_ref()
+                .map(|(scope, project_root)| {
+                    out_of_scope_paths(scope, project_root, &worktree_path, &result.changed_files)
+                })
+                .unwrap_or_default();
+            if !out_of_scope_files.is_empty() {
+                let listed: Vec<String> = out_of_scope_files
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect();
+                let _ = event_tx.send(ExecutorEvent::Log(LogEvent::error(format!(
+                    "Job #{} changed {} file(s) outside the BugBounty scope: {}",
+                    job_id,
+                    out_of_scope_files.len(),
+                    listed.join(", ")
+                ))));
+            }
             let mut bugbounty_ctx: Option<crate::bugbounty::NextContext> = None;
             let mut bugbounty_next_context_value: Option<serde_json::Value> = None;
             let mut bugbounty_result_state: Option<String> = None;
@@ -789,7 +836,7 @@ pub async fn run_job(
                 // Fallback 1: parse from text output (YAML --- blocks)
                 if bugbounty_ctx.is_none() {
                     if let Some(ref output) = output_text {
-                        if let Some(job_result) = crate::JobResult::parse(output) {
+                        if let Some(job_result) = result_parser.parse(output) {
                             if bugbounty_result_state.is_none() {
                                 bugbounty_result_state = job_result.state.clone();
                             }
@@ -870,9 +917,12 @@ pub async fn run_job(
 
                     // Take output_text to avoid clone; parse_result only needs a reference
                     if let Some(output) = output_text.take() {
-                        j.parse_result(&output);
+                        j.parse_result_with(&output, &result_parser);
                         j.full_response = Some(output);
                     }
+                    if let Some(job_result) = j.result.as_mut() {
+                        job_result.usage = result.usage();
+                    }
 
                     // Move session_id instead of cloning
                     j.bridge_session_id = result.session_id.take();
@@ -892,6 +942,8 @@ pub async fn run_job(
                         git_stats_info = Some((files_changed, j.base_branch.clone()));
                     }
 
+                    j.out_of_scope_files = out_of_scope_files;
+
                     if result.success {
                         j.set_status(JobStatus::Done);
                         j.changed_files = result.changed_files;
@@ -1013,3 +1065,33 @@ pub async fn run_job(
 
     let _ = log_forwarder.await;
 }
+
+/// Best-effort interrupt of the agent session after a mode timeout, so the
+/// bridge stops working on a job that has already been marked failed.
+fn interrupt_timed_out_session(job_manager: &Arc<Mutex<JobManager>>, job_id: crate::JobId) {
+    let session = job_manager.lock().ok().and_then(|manager| {
+        manager
+            .get(job_id)
+            .and_then(|job| Some((job.agent_id.clone(), job.bridge_session_id.clone()?)))
+    });
+    let Some((agent_id, session_id)) = session else {
+        return;
+    };
+
+    tokio::task::spawn_blocking(move || {
+        let client = BridgeClient::new();
+        let agent_id_lower = agent_id.to_ascii_lowercase();
+        let result = if agent_id_lower == "codex" || agent_id_lower.contains("codex") {
+            client
+                .interrupt_codex(&session_id)
+                .or_else(|_| client.interrupt_claude(&session_id))
+        } else {
+            client
+                .interrupt_claude(&session_id)
+                .or_else(|_| client.interrupt_codex(&session_id))
+        };
+        if let Err(e) = result {
+            tracing::debug!("Failed to interrupt timed-out job #{}: {}", job_id, e);
+        }
+    });
+}
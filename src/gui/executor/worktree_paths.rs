@@ -64,7 +64,10 @@ pub(super) fn remap_job_paths_to_worktree(
     remap_scope_paths(job, workspace_root, worktree_root);
 
     // Remap target strings of the form "{path}:{line}" or "{path}:{start}-{end}" (best effort).
-    if let Some(new_target) = remap_target_string(&job.target, workspace_root, worktree_root) {
+    // Directory targets are the bare directory path.
+    if job.is_dir_target() {
+        job.target = job.source_file.display().to_string();
+    } else if let Some(new_target) = remap_target_string(&job.target, workspace_root, worktree_root) {
         job.target = new_target;
     }
 
@@ -186,6 +189,37 @@ mod tests {
         assert_eq!(job.source_file, mapped);
     }
 
+    #[test]
+    fn remaps_directory_targets_into_worktree() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().join("root");
+        let worktree = temp.path().join("worktree");
+        std::fs::create_dir_all(root.join("src/orders")).unwrap();
+        std::fs::create_dir_all(worktree.join("src/orders")).unwrap();
+
+        let original = root.join("src/orders");
+        let mapped = worktree.join("src/orders");
+
+        let mut job = crate::Job::new(
+            1,
+            "refactor".to_string(),
+            ScopeDefinition::dir(original.clone()),
+            original.display().to_string(),
+            None,
+            "claude".to_string(),
+            original.clone(),
+            1,
+            None,
+        );
+
+        let outcome = remap_job_paths_to_worktree(&mut job, &root, &worktree);
+
+        assert!(outcome.remapped);
+        assert_eq!(job.source_file, mapped);
+        assert_eq!(job.scope.dir_path.as_deref(), Some(mapped.as_path()));
+        assert_eq!(job.target, mapped.display().to_string());
+    }
+
     #[test]
     fn does_not_remap_prompt_only_jobs() {
         let temp = tempfile::tempdir().unwrap();
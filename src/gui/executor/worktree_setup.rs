@@ -4,25 +4,134 @@ use std::path::PathBuf;
 use std::sync::mpsc::Sender;
 use std::sync::{Arc, Mutex};
 
+use crate::config::DirtyRepoPolicy;
 use crate::git::GitManager;
 use crate::job::JobManager;
 use crate::{Job, JobStatus, LogEvent};
 
 use super::ExecutorEvent;
 
+/// Maximum number of dirty file names listed in the preflight warning
+const MAX_LISTED_DIRTY_FILES: usize = 5;
+
+/// Check the main working directory for uncommitted changes before branching a worktree.
+///
+/// Worktrees are created from HEAD, so local edits are invisible to the agent and
+/// tend to surface later as apply conflicts. Returns an error message when the
+/// policy blocks the job.
+fn preflight_dirty_check(
+    git: &GitManager,
+    policy: DirtyRepoPolicy,
+    job_id: u64,
+    event_tx: &Sender<ExecutorEvent>,
+) -> Result<(), String> {
+    if policy == DirtyRepoPolicy::Ignore {
+        return Ok(());
+    }
+
+    let dirty = match git.tracked_dirty_files() {
+        Ok(files) => files,
+        Err(e) => {
+            let _ = event_tx.send(ExecutorEvent::Log(
+                LogEvent::error(format!("Preflight git status failed: {}", e)).for_job(job_id),
+            ));
+            return Ok(());
+        }
+    };
+    if dirty.is_empty() {
+        return Ok(());
+    }
+
+    let mut listed = dirty
+        .iter()
+        .take(MAX_LISTED_DIRTY_FILES)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(", ");
+    if dirty.len() > MAX_LISTED_DIRTY_FILES {
+        listed.push_str(&format!(", +{} more", dirty.len() - MAX_LISTED_DIRTY_FILES));
+    }
+
+    match policy {
+        DirtyRepoPolicy::Ignore => Ok(()),
+        DirtyRepoPolicy::Warn => {
+            let _ = event_tx.send(ExecutorEvent::Log(
+                LogEvent::system(format!(
+                    "Warning: working directory has {} uncommitted change(s) ({}); \
+                    the worktree branches from HEAD and won't include them. \
+                    Set dirty_repo_policy = \"stash\" or \"block\" to change this.",
+                    dirty.len(),
+                    listed
+                ))
+                .for_job(job_id),
+            ));
+            Ok(())
+        }
+        DirtyRepoPolicy::Block => Err(format!(
+            "Working directory has {} uncommitted change(s) ({}). \
+            Commit or stash them before starting a worktree job, or change dirty_repo_policy.",
+            dirty.len(),
+            listed
+        )),
+        DirtyRepoPolicy::Stash => {
+            let message = format!("kyco: stashed before job #{}", job_id);
+            match git.stash_changes(&message) {
+                Ok(stash) => {
+                    // The user's edits leave the working tree: say so outside the job log too
+                    tracing::warn!(
+                        "Stashed {} uncommitted change(s) as {} before job #{}: {}",
+                        dirty.len(),
+                        stash,
+                        job_id,
+                        dirty.join(", ")
+                    );
+                    let _ = event_tx.send(ExecutorEvent::Log(
+                        LogEvent::system(format!(
+                            "Stashed {} uncommitted change(s) ({}) as {} before job #{} \
+                            (restore with `git stash apply {}`)",
+                            dirty.len(),
+                            listed,
+                            stash,
+                            job_id,
+                            stash
+                        ))
+                        .for_job(job_id),
+                    ));
+                    Ok(())
+                }
+                Err(e) => Err(format!("Failed to stash uncommitted changes: {}", e)),
+            }
+        }
+    }
+}
+
 /// Setup worktree for a job, returning (worktree_path, is_isolated) or None if failed and required.
+#[allow(clippy::too_many_arguments)]
 pub fn setup_worktree(
     git_manager: Option<&GitManager>,
     job_id: u64,
     is_multi_agent_job: bool,
     force_worktree: bool,
+    dirty_repo_policy: DirtyRepoPolicy,
     job_work_dir: &PathBuf,
     event_tx: &Sender<ExecutorEvent>,
     job_manager: &Arc<Mutex<JobManager>>,
     job: &mut Job,
 ) -> Option<(PathBuf, bool)> {
     if let Some(git) = git_manager {
-        match git.create_worktree(job_id) {
+        if let Err(reason) = preflight_dirty_check(git, dirty_repo_policy, job_id, event_tx) {
+            let _ = event_tx.send(ExecutorEvent::Log(LogEvent::error(reason.clone())));
+            let _ = event_tx.send(ExecutorEvent::JobFailed(job_id, reason.clone()));
+            if let Ok(mut manager) = job_manager.lock() {
+                manager.set_status(job_id, JobStatus::Failed);
+                if let Some(j) = manager.get_mut(job_id) {
+                    j.error_message = Some(reason);
+                }
+            }
+            return None;
+        }
+
+        match git.create_worktree(job_id, job.base_ref.as_deref()) {
             Ok(worktree_info) => {
                 let _ = event_tx.send(ExecutorEvent::Log(LogEvent::system(format!(
                     "Created worktree: {}",
@@ -3,8 +3,9 @@
 //! This module handles the workflow of merging the selected result and
 //! cleaning up all worktrees from the group.
 
+use super::super::app_popup::merge_worktree;
 use crate::git::CommitMessage;
-use crate::git::GitManager;
+use crate::git::{GitManager, merge_tree_write_supported};
 use crate::job::{GroupManager, JobManager};
 use crate::{AgentGroupId, JobStatus};
 
@@ -36,7 +37,8 @@ impl GroupOperationResult {
 ///
 /// This function:
 /// 1. Gets the selected job from the group
-/// 2. Merges its worktree branch into the main branch
+/// 2. Merges its worktree branch into the main branch (as one squashed commit
+///    with `merge_as_commit`, i.e. `settings.merge_as_commit`)
 /// 3. Removes all worktrees for the group (including the selected one)
 /// 4. Marks the group as merged
 pub fn merge_and_cleanup(
@@ -44,6 +46,7 @@ pub fn merge_and_cleanup(
     group_manager: &mut GroupManager,
     job_manager: &mut JobManager,
     git_manager: &GitManager,
+    merge_as_commit: bool,
 ) -> GroupOperationResult {
     let group = match group_manager.get(group_id) {
         Some(g) => g.clone(),
@@ -70,11 +73,43 @@ pub fn merge_and_cleanup(
         None => return GroupOperationResult::error("Selected job has no base branch recorded"),
     };
 
-    let commit_message = CommitMessage::from_job(&selected_job);
-    if let Err(e) = git_manager.apply_changes(&worktree_path, &base_branch, Some(&commit_message)) {
-        return GroupOperationResult::error(format!("Failed to merge changes: {}", e));
+    // A base that moved since the worktree was created is fine as long as the
+    // result still merges cleanly (checked in memory first where git can; without
+    // it a conflicting merge is aborted and reported below)
+    let conflicts = if merge_tree_write_supported() {
+        git_manager.merge_conflicts(&worktree_path, &base_branch)
+    } else {
+        Ok(Vec::new())
+    };
+    match conflicts {
+        Ok(conflicts) if conflicts.is_empty() => {}
+        Ok(conflicts) => {
+            return GroupOperationResult::error(format!(
+                "Merge would conflict in: {}",
+                conflicts.join(", ")
+            ));
+        }
+        Err(e) => {
+            return GroupOperationResult::error(format!("Failed to check for conflicts: {}", e));
+        }
     }
 
+    let commit_message = CommitMessage::from_job(&selected_job);
+    let merged = match merge_worktree(
+        git_manager,
+        &worktree_path,
+        &base_branch,
+        &commit_message,
+        merge_as_commit,
+        true,
+    ) {
+        Ok(Some(hash)) => format!("{} as commit {}", selected_job.agent_id, hash),
+        Ok(None) => selected_job.agent_id.clone(),
+        Err(e) => {
+            return GroupOperationResult::error(format!("Failed to merge changes: {}", e.message));
+        }
+    };
+
     if let Some(job) = job_manager.get_mut(selected_job_id) {
         job.set_status(JobStatus::Merged);
     }
@@ -103,13 +138,13 @@ pub fn merge_and_cleanup(
     if cleanup_errors.is_empty() {
         GroupOperationResult::success(format!(
             "Merged changes from {} and cleaned up {} worktrees",
-            selected_job.agent_id,
+            merged,
             group.job_ids.len()
         ))
     } else {
         GroupOperationResult::success(format!(
             "Merged changes from {} (cleanup warnings: {})",
-            selected_job.agent_id,
+            merged,
             cleanup_errors.join(", ")
         ))
     }
@@ -1,12 +1,21 @@
 //! Job continuation handler.
 
+use std::time::Duration;
+
 use super::super::types::{
-    ControlApiState, ControlJobContinueRequest, ControlJobContinueResponse,
+    ControlApiState, ControlJobContinueBatchRequest, ControlJobContinueBatchResponse,
+    ControlJobContinueRequest, ControlJobContinueResponse,
 };
 use super::super::respond_json;
 use super::{parse_job_id_from_path, ExecutorEvent};
 use crate::gui::jobs;
-use crate::{CommentTag, LogEvent, Target};
+use crate::job::JobManager;
+use crate::{CommentTag, Job, JobId, JobStatus, LogEvent, Target};
+
+/// How often the batch driver checks whether the previous step finished.
+const BATCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Default for how long one batch step may take, from being queued until it finishes.
+const DEFAULT_BATCH_STEP_TIMEOUT_SECS: u64 = 2 * 60 * 60;
 
 pub fn handle_control_job_continue(
     control: &ControlApiState,
@@ -69,20 +78,14 @@ pub fn handle_control_job_continue(
             return;
         };
 
-        let tag = CommentTag {
-            file_path: original.source_file.clone(),
-            line_number: original.source_line,
-            raw_line: format!("// @{}:{} {}", &original.agent_id, &original.skill, prompt),
-            agent: original.agent_id.clone(),
-            agents: vec![original.agent_id.clone()],
-            mode: original.skill.clone(),
-            target: Target::Block,
-            status_marker: None,
-            description: Some(prompt.to_string()),
-            job_id: None,
-        };
-
-        let continuation_id = match manager.create_job_with_range(&tag, &original.agent_id, None) {
+        let continuation_id = match create_continuation(
+            &mut manager,
+            &original,
+            session_id,
+            prompt,
+            req.fork_session,
+            req.plan_mode,
+        ) {
             Ok(id) => id,
             Err(e) => {
                 respond_json(
@@ -94,27 +97,6 @@ pub fn handle_control_job_continue(
             }
         };
 
-        if let Some(job) = manager.get_mut(continuation_id) {
-            job.raw_tag_line = None;
-            job.bridge_session_id = Some(session_id);
-
-            // Apply fork_session and plan_mode from request
-            job.fork_session = req.fork_session;
-            if req.plan_mode {
-                job.permission_mode = Some("plan".to_string());
-            }
-
-            // Reuse the same worktree and job context
-            job.git_worktree_path = original.git_worktree_path.clone();
-            job.branch_name = original.branch_name.clone();
-            job.base_branch = original.base_branch.clone();
-            job.scope = original.scope.clone();
-            job.target = original.target;
-            job.ide_context = original.ide_context;
-            job.force_worktree = original.force_worktree;
-            job.workspace_path = original.workspace_path.clone();
-        }
-
         logs.push(LogEvent::system(format!(
             "Created continuation job #{} (from job #{})",
             continuation_id, job_id
@@ -138,3 +120,354 @@ pub fn handle_control_job_continue(
             .unwrap_or_else(|_| serde_json::json!({ "error": "serialize" })),
     );
 }
+
+/// Create a pending continuation of `original` that resumes `session_id`.
+///
+/// The new job reuses the original's worktree, branch and scope so the agent
+/// keeps working in the same context.
+fn create_continuation(
+    manager: &mut JobManager,
+    original: &Job,
+    session_id: String,
+    prompt: &str,
+    fork_session: bool,
+    plan_mode: bool,
+) -> anyhow::Result<JobId> {
+    let tag = CommentTag {
+        file_path: original.source_file.clone(),
+        line_number: original.source_line,
+        raw_line: format!("// @{}:{} {}", &original.agent_id, &original.skill, prompt),
+        agent: original.agent_id.clone(),
+        agents: vec![original.agent_id.clone()],
+        mode: original.skill.clone(),
+        target: Target::Block,
+        status_marker: None,
+        description: Some(prompt.to_string()),
+        job_id: None,
+    };
+
+    let continuation_id = manager.create_job_with_range(&tag, &original.agent_id, None)?;
+
+    if let Some(job) = manager.get_mut(continuation_id) {
+        job.raw_tag_line = None;
+        job.bridge_session_id = Some(session_id);
+        job.continued_from = Some(original.id);
+
+        // Apply fork_session and plan_mode from request
+        job.fork_session = fork_session;
+        if plan_mode {
+            job.permission_mode = Some("plan".to_string());
+        }
+
+        // Reuse the same worktree and job context
+        job.git_worktree_path = original.git_worktree_path.clone();
+        job.branch_name = original.branch_name.clone();
+        job.base_branch = original.base_branch.clone();
+        job.base_ref = original.base_ref.clone();
+        job.scope = original.scope.clone();
+        job.target = original.target.clone();
+        job.ide_context = original.ide_context.clone();
+        job.force_worktree = original.force_worktree;
+        job.workspace_path = original.workspace_path.clone();
+    }
+
+    Ok(continuation_id)
+}
+
+/// Create an ordered series of continuations and run them one after another.
+///
+/// All jobs are created up front (pending) so the response can list every id;
+/// a background thread then queues each step once the previous one finished,
+/// carrying the session forward.
+pub fn handle_control_job_continue_batch(
+    control: &ControlApiState,
+    path: &str,
+    body: &str,
+    request: tiny_http::Request,
+) {
+    let job_id = match parse_job_id_from_path(path, Some("continue_batch")) {
+        Ok(id) => id,
+        Err(err) => {
+            respond_json(request, 400, serde_json::json!({ "error": err }));
+            return;
+        }
+    };
+
+    let req: ControlJobContinueBatchRequest = match serde_json::from_str(body) {
+        Ok(req) => req,
+        Err(e) => {
+            respond_json(
+                request,
+                400,
+                serde_json::json!({ "error": "invalid_json", "details": e.to_string() }),
+            );
+            return;
+        }
+    };
+
+    let prompts: Vec<&str> = req
+        .prompts
+        .iter()
+        .map(|p| p.trim())
+        .filter(|p| !p.is_empty())
+        .collect();
+    if prompts.is_empty() {
+        respond_json(request, 400, serde_json::json!({ "error": "missing_prompts" }));
+        return;
+    }
+
+    let mut logs: Vec<LogEvent> = Vec::new();
+
+    let job_ids = {
+        let mut manager = match control.job_manager.lock() {
+            Ok(m) => m,
+            Err(_) => {
+                respond_json(
+                    request,
+                    500,
+                    serde_json::json!({ "error": "job_manager_lock" }),
+                );
+                return;
+            }
+        };
+
+        let Some(original) = manager.get(job_id).cloned() else {
+            respond_json(request, 404, serde_json::json!({ "error": "not_found" }));
+            return;
+        };
+
+        let Some(session_id) = original.bridge_session_id.clone() else {
+            respond_json(request, 400, serde_json::json!({ "error": "no_session" }));
+            return;
+        };
+
+        let mut job_ids = Vec::with_capacity(prompts.len());
+        for prompt in &prompts {
+            match create_continuation(
+                &mut manager,
+                &original,
+                session_id.clone(),
+                prompt,
+                req.fork_session,
+                req.plan_mode,
+            ) {
+                Ok(id) => {
+                    // Each step runs after the previous one, so that is its parent
+                    if let (Some(&prev), Some(job)) = (job_ids.last(), manager.get_mut(id)) {
+                        job.continued_from = Some(prev);
+                    }
+                    job_ids.push(id);
+                }
+                Err(e) => {
+                    for id in &job_ids {
+                        manager.remove_job(*id);
+                    }
+                    respond_json(
+                        request,
+                        500,
+                        serde_json::json!({ "error": "create_failed", "details": e.to_string() }),
+                    );
+                    return;
+                }
+            }
+        }
+
+        logs.push(LogEvent::system(format!(
+            "Created {} continuation jobs (from job #{}): {}",
+            job_ids.len(),
+            job_id,
+            job_ids
+                .iter()
+                .map(|id| format!("#{id}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+
+        job_ids
+    };
+
+    for log in &logs {
+        let _ = control.executor_tx.send(ExecutorEvent::Log(log.clone()));
+    }
+
+    let driver_control = control.clone();
+    let driver_ids = job_ids.clone();
+    let stop_on_failure = req.stop_on_failure;
+    let step_timeout = Duration::from_secs(
+        req.step_timeout_secs
+            .filter(|secs| *secs > 0)
+            .unwrap_or(DEFAULT_BATCH_STEP_TIMEOUT_SECS),
+    );
+    std::thread::spawn(move || {
+        drive_continue_batch(&driver_control, &driver_ids, stop_on_failure, step_timeout);
+    });
+
+    respond_json(
+        request,
+        200,
+        serde_json::to_value(ControlJobContinueBatchResponse { job_ids })
+            .unwrap_or_else(|_| serde_json::json!({ "error": "serialize" })),
+    );
+}
+
+/// Queue each batch step after the previous one reaches a terminal state.
+///
+/// A step that does not finish within `step_timeout` stops the batch: the
+/// remaining steps fail with the timeout as their error.
+fn drive_continue_batch(
+    control: &ControlApiState,
+    job_ids: &[JobId],
+    stop_on_failure: bool,
+    step_timeout: Duration,
+) {
+    for (index, &job_id) in job_ids.iter().enumerate() {
+        let mut logs: Vec<LogEvent> = Vec::new();
+
+        if index > 0 {
+            let previous_id = job_ids[index - 1];
+            let previous = match wait_for_finish(control, previous_id, step_timeout) {
+                Ok(previous) => previous,
+                Err(BatchWaitError::Removed) => {
+                    skip_remaining(control, &job_ids[index..], previous_id, "was removed");
+                    return;
+                }
+                Err(BatchWaitError::TimedOut) => {
+                    fail_remaining(
+                        control,
+                        &job_ids[index..],
+                        &format!(
+                            "Batch step #{} did not finish within {}s",
+                            previous_id,
+                            step_timeout.as_secs()
+                        ),
+                    );
+                    return;
+                }
+            };
+
+            let succeeded = matches!(previous.status, JobStatus::Done | JobStatus::Merged);
+            if !succeeded && stop_on_failure {
+                skip_remaining(control, &job_ids[index..], previous_id, "did not succeed");
+                return;
+            }
+
+            // Resume from wherever the previous turn left the session.
+            if let Some(session_id) = previous.bridge_session_id {
+                if let Ok(mut manager) = control.job_manager.lock() {
+                    if let Some(job) = manager.get_mut(job_id) {
+                        job.bridge_session_id = Some(session_id);
+                    }
+                }
+            }
+        }
+
+        jobs::queue_job(&control.job_manager, job_id, &mut logs);
+        for log in &logs {
+            let _ = control.executor_tx.send(ExecutorEvent::Log(log.clone()));
+        }
+    }
+}
+
+/// Why the batch driver stopped waiting for a step
+#[derive(Debug, PartialEq, Eq)]
+enum BatchWaitError {
+    /// The job disappeared (removed, or the job manager lock was poisoned)
+    Removed,
+    /// The job was still unfinished when the step timeout ran out
+    TimedOut,
+}
+
+/// Block until `job_id` is finished, for at most `timeout`.
+fn wait_for_finish(
+    control: &ControlApiState,
+    job_id: JobId,
+    timeout: Duration,
+) -> Result<Job, BatchWaitError> {
+    let deadline = std::time::Instant::now() + timeout;
+    loop {
+        {
+            let manager = control
+                .job_manager
+                .lock()
+                .map_err(|_| BatchWaitError::Removed)?;
+            let job = manager.get(job_id).ok_or(BatchWaitError::Removed)?;
+            if job.is_finished() {
+                return Ok(job.clone());
+            }
+        }
+        let now = std::time::Instant::now();
+        if now >= deadline {
+            return Err(BatchWaitError::TimedOut);
+        }
+        std::thread::sleep(BATCH_POLL_INTERVAL.min(deadline - now));
+    }
+}
+
+/// Fail the remaining batch steps with `error` and report it in the log.
+fn fail_remaining(control: &ControlApiState, job_ids: &[JobId], error: &str) {
+    if let Ok(mut manager) = control.job_manager.lock() {
+        for &id in job_ids {
+            if let Some(job) = manager.get_mut(id) {
+                job.error_message = Some(error.to_string());
+            }
+            manager.set_status(id, JobStatus::Failed);
+        }
+    }
+    let _ = control.executor_tx.send(ExecutorEvent::Log(LogEvent::error(format!(
+        "{}; failed {} remaining continuation job(s)",
+        error,
+        job_ids.len()
+    ))));
+}
+
+/// Reject the remaining batch steps after `previous_id` stopped the batch.
+fn skip_remaining(control: &ControlApiState, job_ids: &[JobId], previous_id: JobId, reason: &str) {
+    if let Ok(mut manager) = control.job_manager.lock() {
+        for &id in job_ids {
+            manager.set_status(id, JobStatus::Rejected);
+        }
+    }
+    let _ = control.executor_tx.send(ExecutorEvent::Log(LogEvent::system(format!(
+        "Skipped {} continuation job(s): job #{} {}",
+        job_ids.len(),
+        previous_id,
+        reason
+    ))));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::test_support::TestServer;
+    use super::drive_continue_batch;
+    use crate::JobStatus;
+    use std::time::Duration;
+
+    #[test]
+    fn batch_driver_fails_remaining_steps_when_a_step_times_out() {
+        let server = TestServer::start();
+        let first = server.add_job("a.rs", JobStatus::Blocked);
+        let second = server.add_job("b.rs", JobStatus::Blocked);
+        let third = server.add_job("c.rs", JobStatus::Blocked);
+
+        drive_continue_batch(
+            &server.control,
+            &[first, second, third],
+            false,
+            Duration::from_millis(50),
+        );
+
+        let manager = server.control.job_manager.lock().unwrap();
+        assert_eq!(manager.get(first).unwrap().status, JobStatus::Queued);
+        for id in [second, third] {
+            let job = manager.get(id).unwrap();
+            assert_eq!(job.status, JobStatus::Failed);
+            assert!(
+                job.error_message
+                    .as_deref()
+                    .is_some_and(|e| e.contains("did not finish")),
+                "{:?}",
+                job.error_message
+            );
+        }
+    }
+}
@@ -5,9 +5,10 @@ use std::path::{Path, PathBuf};
 use super::super::types::{ControlApiState, ControlJobCreateRequest, ControlJobCreateResponse};
 use super::super::respond_json;
 use super::ExecutorEvent;
+use crate::git::GitManager;
 use crate::gui::jobs;
 use crate::gui::selection::SelectionContext;
-use crate::LogEvent;
+use crate::{AutoSelectPolicy, Job, LogEvent, ScopeDefinition};
 
 fn expand_tilde(path: &str) -> Option<PathBuf> {
     let home = dirs::home_dir()?;
@@ -85,6 +86,16 @@ pub fn handle_control_job_create(
         return;
     }
 
+    let job_env = req.env.clone().unwrap_or_default();
+    if let Err(message) = job_env.keys().try_for_each(|key| Job::validate_env_key(key)) {
+        respond_json(
+            request,
+            400,
+            serde_json::json!({ "error": "invalid_env", "message": message }),
+        );
+        return;
+    }
+
     // Validate mode exists (mode or chain), including alias resolution.
     let resolved_mode = match control.config.read() {
         Ok(config) => {
@@ -177,6 +188,21 @@ pub fn handle_control_job_create(
         agents.push(agent);
     }
 
+    // Auto-select picks a winner among several agents' results
+    let auto_select = req.auto_select.clone().map(AutoSelectPolicy::normalized);
+    if auto_select.is_some() && agents.len() < 2 {
+        respond_json(
+            request,
+            400,
+            serde_json::json!({
+                "error": "auto_select_needs_agents",
+                "message": "auto_select requires at least two agents",
+                "agents": agents,
+            }),
+        );
+        return;
+    }
+
     // Normalize and validate file path if provided
     let (abs_path_str, workspace) = if let Some(file_path_raw) = file_path_raw {
         let path = expand_tilde(file_path_raw)
@@ -199,13 +225,13 @@ pub fn handle_control_job_create(
             );
             return;
         }
-        if !abs_path.is_file() {
+        if !abs_path.is_file() && !abs_path.is_dir() {
             respond_json(
                 request,
                 400,
                 serde_json::json!({
                     "error": "path_not_file",
-                    "message": format!("Path is not a file: {}", abs_path.display()),
+                    "message": format!("Path is not a file or directory: {}", abs_path.display()),
                     "file_path": file_path_raw,
                     "resolved_path": abs_path.display().to_string(),
                 }),
@@ -219,6 +245,38 @@ pub fn handle_control_job_create(
         // No file provided - use current work_dir as workspace
         (None, control.work_dir.clone())
     };
+    let dir_target = abs_path_str
+        .as_deref()
+        .map(PathBuf::from)
+        .filter(|p| p.is_dir());
+
+    // Fail early on a base ref the worktree could never be created from
+    let base_ref = req
+        .base
+        .as_deref()
+        .map(str::trim)
+        .filter(|b| !b.is_empty())
+        .map(str::to_string);
+    if let Some(ref base) = base_ref {
+        let resolved = GitManager::new(&workspace)
+            .map_err(|e| e.to_string())
+            .and_then(|git| {
+                git.resolve_commit(base)
+                    .ok_or_else(|| crate::git::GitError::InvalidRef(base.clone()).to_string())
+            });
+        if let Err(message) = resolved {
+            respond_json(
+                request,
+                400,
+                serde_json::json!({
+                    "error": "invalid_base",
+                    "message": message,
+                    "base": base,
+                }),
+            );
+            return;
+        }
+    }
 
     let selection = SelectionContext {
         app_name: Some("CLI".to_string()),
@@ -227,6 +285,7 @@ pub fn handle_control_job_create(
         line_number: req.line_start,
         line_end: req.line_end,
         workspace_path: Some(workspace),
+        context_lines: req.context_lines,
         ..Default::default()
     };
 
@@ -241,7 +300,8 @@ pub fn handle_control_job_create(
         &resolved_mode,
         &prompt,
         &mut logs,
-        req.force_worktree,
+        req.force_worktree || base_ref.is_some(),
+        auto_select,
     );
 
     let Some(created) = created else {
@@ -256,7 +316,10 @@ pub fn handle_control_job_create(
     // Apply session continuation and permission mode fields
     let has_session_fields = req.session_id.as_deref().is_some_and(|s| !s.trim().is_empty())
         || req.fork_session
-        || req.permission_mode.as_deref().is_some_and(|s| !s.trim().is_empty());
+        || req.permission_mode.as_deref().is_some_and(|s| !s.trim().is_empty())
+        || !job_env.is_empty()
+        || req.ignore_budget;
+    let job_tags = Job::normalize_tags(&req.tags);
 
     // Apply optional BugBounty metadata to created jobs (for prompt injection + linking).
     let has_bugbounty_fields = req.bugbounty_project_id.as_deref().is_some_and(|s| !s.trim().is_empty())
@@ -265,10 +328,21 @@ pub fn handle_control_job_create(
             .as_ref()
             .is_some_and(|v| v.iter().any(|s| !s.trim().is_empty()));
 
-    if has_session_fields || has_bugbounty_fields {
+    if has_session_fields
+        || has_bugbounty_fields
+        || dir_target.is_some()
+        || !job_tags.is_empty()
+        || base_ref.is_some()
+    {
         if let Ok(mut manager) = control.job_manager.lock() {
             for job_id in &created.job_ids {
                 if let Some(job) = manager.get_mut(*job_id) {
+                    // Directory targets: the agent works on the whole folder, not a single file
+                    if let Some(ref dir) = dir_target {
+                        job.scope = ScopeDefinition::dir(dir.clone());
+                        job.target = dir.display().to_string();
+                    }
+
                     // Session continuation fields
                     if let Some(ref session_id) = req.session_id {
                         let trimmed = session_id.trim();
@@ -285,6 +359,18 @@ pub fn handle_control_job_create(
                             job.permission_mode = Some(trimmed.to_string());
                         }
                     }
+                    if !job_env.is_empty() {
+                        job.env = job_env.clone();
+                    }
+                    if req.ignore_budget {
+                        job.ignore_budget = true;
+                    }
+                    if !job_tags.is_empty() {
+                        job.tags = job_tags.clone();
+                    }
+                    if base_ref.is_some() {
+                        job.base_ref = base_ref.clone();
+                    }
 
                     // BugBounty fields
                     if let Some(ref project_id) = req.bugbounty_project_id {
@@ -329,3 +415,29 @@ pub fn handle_control_job_create(
         .unwrap_or_else(|_| serde_json::json!({ "error": "serialize" })),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::test_support::TestServer;
+
+    #[test]
+    fn auto_select_needs_several_agents() {
+        let server = TestServer::start();
+        server.control.config.write().unwrap().mode.insert(
+            "fix".to_string(),
+            toml::from_str("prompt = \"Fix {target}\"").unwrap(),
+        );
+        let (status, body) = server.post(
+            "/ctl/jobs",
+            serde_json::json!({
+                "mode": "fix",
+                "prompt": "Fix the null check",
+                "agents": ["claude"],
+                "auto_select": { "preferred_states": ["fixed"] },
+            }),
+        );
+        assert_eq!(status, 400, "{}", body);
+        assert_eq!(body["error"], "auto_select_needs_agents");
+        assert!(server.control.job_manager.lock().unwrap().jobs().is_empty());
+    }
+}
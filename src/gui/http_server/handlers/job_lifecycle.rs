@@ -1,14 +1,100 @@
-//! Job lifecycle handlers: list, get, queue, abort.
+//! Job lifecycle handlers: list, get, queue, abort, archive.
 
-use super::super::types::ControlApiState;
+use super::super::types::{ControlApiState, ControlJobTagsRequest};
 use super::super::respond_json;
-use super::{parse_job_id_from_path, ExecutorEvent};
+use super::{parse_job_id_from_path, percent_decode, ExecutorEvent};
 use crate::agent::bridge::BridgeClient;
-use crate::{Job, JobStatus, LogEvent};
+use crate::{Job, JobId, JobStatus, LogEvent};
+
+/// GET /ctl/jobs[?since=<duration|timestamp>][&tag=<tag>][&status=<s1,s2>][&offset=N][&limit=N]
+/// - jobs sorted by creation time (newest first), optionally only those created or updated at or
+/// after the cutoff (see [`Job::parse_since`]), carrying `tag`, and in one of the
+/// listed statuses. Returns `{ jobs, total }`, where `total` counts all matching jobs
+/// before `offset`/`limit` are applied.
+pub fn handle_control_jobs_list(
+    control: &ControlApiState,
+    query: Option<&str>,
+    request: tiny_http::Request,
+) {
+    let tag = query
+        .unwrap_or("")
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("tag="))
+        .map(percent_decode)
+        .filter(|tag| !tag.is_empty());
+
+    let since = match query
+        .unwrap_or("")
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("since="))
+        .map(|raw| Job::parse_since(&percent_decode(raw), chrono::Utc::now()))
+        .transpose()
+    {
+        Ok(since) => since,
+        Err(details) => {
+            respond_json(
+                request,
+                400,
+                serde_json::json!({ "error": "bad_since", "details": details }),
+            );
+            return;
+        }
+    };
+
+    let param = |key: &str| {
+        query
+            .unwrap_or("")
+            .split('&')
+            .find_map(|pair| pair.strip_prefix(key)?.strip_prefix('='))
+            .filter(|value| !value.is_empty())
+    };
+
+    let statuses = match param("status")
+        .map(|raw| {
+            raw.split(',')
+                .map(|s| JobStatus::from_marker(s).ok_or_else(|| s.to_string()))
+                .collect::<Result<Vec<JobStatus>, String>>()
+        })
+        .transpose()
+    {
+        Ok(statuses) => statuses,
+        Err(unknown) => {
+            respond_json(
+                request,
+                400,
+                serde_json::json!({ "error": "bad_status", "details": unknown }),
+            );
+            return;
+        }
+    };
+
+    let mut page = [("offset", 0usize), ("limit", usize::MAX)];
+    for (key, value) in &mut page {
+        if let Some(raw) = param(key) {
+            match raw.parse::<usize>() {
+                Ok(parsed) => *value = parsed,
+                Err(_) => {
+                    respond_json(
+                        request,
+                        400,
+                        serde_json::json!({ "error": format!("bad_{key}"), "details": raw }),
+                    );
+                    return;
+                }
+            }
+        }
+    }
+    let [(_, offset), (_, limit)] = page;
 
-pub fn handle_control_jobs_list(control: &ControlApiState, request: tiny_http::Request) {
     let jobs: Vec<Job> = match control.job_manager.lock() {
-        Ok(manager) => manager.jobs().into_iter().cloned().collect(),
+        Ok(manager) => manager
+            .jobs()
+            .into_iter()
+            .filter(|job| since.is_none_or(|cutoff| job.active_since(cutoff)))
+            .filter(|job| tag.as_deref().is_none_or(|tag| job.has_tag(tag)))
+            .filter(|job| statuses.as_ref().is_none_or(|s| s.contains(&job.status)))
+            .cloned()
+            .collect(),
         Err(_) => {
             respond_json(
                 request,
@@ -19,7 +105,45 @@ pub fn handle_control_jobs_list(control: &ControlApiState, request: tiny_http::R
         }
     };
 
-    respond_json(request, 200, serde_json::json!({ "jobs": jobs }));
+    let (jobs, total) = paginate_jobs(jobs, offset, limit);
+    respond_json(
+        request,
+        200,
+        serde_json::json!({ "jobs": jobs, "total": total }),
+    );
+}
+
+/// Sort newest first (see [`Job::creation_order`]) and cut out one page; also
+/// returns the unpaged count.
+fn paginate_jobs(mut jobs: Vec<Job>, offset: usize, limit: usize) -> (Vec<Job>, usize) {
+    let total = jobs.len();
+    jobs.sort_by_key(|job| std::cmp::Reverse(job.creation_order()));
+    let page = jobs.into_iter().skip(offset).take(limit).collect();
+    (page, total)
+}
+
+/// GET /ctl/jobs/archived - jobs moved to the archive store (read-only).
+pub fn handle_control_jobs_archived(control: &ControlApiState, request: tiny_http::Request) {
+    let archived = match control.job_manager.lock() {
+        Ok(manager) => manager.list_archived(),
+        Err(_) => {
+            respond_json(
+                request,
+                500,
+                serde_json::json!({ "error": "job_manager_lock" }),
+            );
+            return;
+        }
+    };
+
+    match archived {
+        Ok(jobs) => respond_json(request, 200, serde_json::json!({ "jobs": jobs })),
+        Err(e) => respond_json(
+            request,
+            500,
+            serde_json::json!({ "error": "archive_read", "details": e.to_string() }),
+        ),
+    }
 }
 
 pub fn handle_control_job_get(control: &ControlApiState, path: &str, request: tiny_http::Request) {
@@ -51,6 +175,140 @@ pub fn handle_control_job_get(control: &ControlApiState, path: &str, request: ti
     respond_json(request, 200, serde_json::json!({ "job": job }));
 }
 
+/// GET /ctl/jobs/{id}/logs[?since=<unix nanos>] - the job's accumulated LogEvents.
+///
+/// With `since`, only events at or after that timestamp are returned so clients
+/// can long-poll (`kyco job logs --follow`). The bound is inclusive so events
+/// sharing the last seen timestamp are not lost; clients skip the ones they printed.
+pub fn handle_control_job_logs(
+    control: &ControlApiState,
+    path: &str,
+    query: Option<&str>,
+    request: tiny_http::Request,
+) {
+    let job_id = match parse_job_id_from_path(path, Some("logs")) {
+        Ok(id) => id,
+        Err(err) => {
+            respond_json(request, 400, serde_json::json!({ "error": err }));
+            return;
+        }
+    };
+
+    let since = match query
+        .unwrap_or("")
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("since="))
+        .map(str::parse::<i64>)
+        .transpose()
+    {
+        Ok(since) => since,
+        Err(_) => {
+            respond_json(request, 400, serde_json::json!({ "error": "bad_since" }));
+            return;
+        }
+    };
+
+    let job = match control.job_manager.lock() {
+        Ok(manager) => manager
+            .get(job_id)
+            .map(|job| (job.status, job.log_events.clone())),
+        Err(_) => {
+            respond_json(
+                request,
+                500,
+                serde_json::json!({ "error": "job_manager_lock" }),
+            );
+            return;
+        }
+    };
+
+    let Some((status, events)) = job else {
+        respond_json(request, 404, serde_json::json!({ "error": "not_found" }));
+        return;
+    };
+
+    let events: Vec<LogEvent> = match since {
+        Some(since) => events
+            .into_iter()
+            .filter(|e| e.timestamp.timestamp_nanos_opt().unwrap_or(i64::MAX) >= since)
+            .collect(),
+        None => events,
+    };
+
+    respond_json(
+        request,
+        200,
+        serde_json::json!({ "job_id": job_id, "status": status, "events": events }),
+    );
+}
+
+/// POST /ctl/jobs/{id}/tags - replace (`tags`) and/or edit (`add`, `remove`) the
+/// job's tags. Responds with the resulting tag list.
+pub fn handle_control_job_tags(
+    control: &ControlApiState,
+    path: &str,
+    body: &str,
+    request: tiny_http::Request,
+) {
+    let job_id = match parse_job_id_from_path(path, Some("tags")) {
+        Ok(id) => id,
+        Err(err) => {
+            respond_json(request, 400, serde_json::json!({ "error": err }));
+            return;
+        }
+    };
+
+    let req: ControlJobTagsRequest = match serde_json::from_str(body) {
+        Ok(req) => req,
+        Err(e) => {
+            respond_json(
+                request,
+                400,
+                serde_json::json!({ "error": "invalid_json", "details": e.to_string() }),
+            );
+            return;
+        }
+    };
+
+    let tags = match control.job_manager.lock() {
+        Ok(mut manager) => {
+            let tags = manager.get_mut(job_id).map(|job| {
+                let mut tags = match req.tags {
+                    Some(ref tags) => Job::normalize_tags(tags),
+                    None => job.tags.clone(),
+                };
+                tags.extend(Job::normalize_tags(&req.add));
+                let remove = Job::normalize_tags(&req.remove);
+                tags.retain(|t| !remove.iter().any(|r| r.eq_ignore_ascii_case(t)));
+                job.tags = Job::normalize_tags(&tags);
+                job.updated_at = chrono::Utc::now();
+                job.tags.clone()
+            });
+            if tags.is_some() {
+                manager.touch();
+            }
+            tags
+        }
+        Err(_) => {
+            respond_json(
+                request,
+                500,
+                serde_json::json!({ "error": "job_manager_lock" }),
+            );
+            return;
+        }
+    };
+
+    match tags {
+        Some(tags) => respond_json(
+            request,
+            200,
+            serde_json::json!({ "job_id": job_id, "tags": tags }),
+        ),
+        None => respond_json(request, 404, serde_json::json!({ "error": "not_found" })),
+    }
+}
+
 pub fn handle_control_job_queue(control: &ControlApiState, path: &str, request: tiny_http::Request) {
     let job_id = match parse_job_id_from_path(path, Some("queue")) {
         Ok(id) => id,
@@ -97,6 +355,111 @@ pub fn handle_control_job_queue(control: &ControlApiState, path: &str, request:
     );
 }
 
+/// POST /ctl/jobs/{id}/resume - re-queue a failed job to reattach to its stored agent session.
+pub fn handle_control_job_resume(control: &ControlApiState, path: &str, request: tiny_http::Request) {
+    let job_id = match parse_job_id_from_path(path, Some("resume")) {
+        Ok(id) => id,
+        Err(err) => {
+            respond_json(request, 400, serde_json::json!({ "error": err }));
+            return;
+        }
+    };
+
+    let result = match control.job_manager.lock() {
+        Ok(mut manager) => {
+            if manager.get(job_id).is_none() {
+                respond_json(request, 404, serde_json::json!({ "error": "not_found" }));
+                return;
+            }
+            manager.request_resume(job_id)
+        }
+        Err(_) => {
+            respond_json(
+                request,
+                500,
+                serde_json::json!({ "error": "job_manager_lock" }),
+            );
+            return;
+        }
+    };
+
+    let session_id = match result {
+        Ok(session_id) => session_id,
+        Err(e) => {
+            respond_json(
+                request,
+                400,
+                serde_json::json!({ "error": "not_resumable", "message": e.to_string() }),
+            );
+            return;
+        }
+    };
+
+    let _ = control
+        .executor_tx
+        .send(ExecutorEvent::Log(LogEvent::system(format!(
+            "Resuming job #{} (session {})",
+            job_id, session_id
+        ))));
+
+    respond_json(
+        request,
+        200,
+        serde_json::json!({ "status": "ok", "job_id": job_id, "session_id": session_id }),
+    );
+}
+
+/// POST /ctl/jobs/{id}/archive - move a finished job into the archive store.
+pub fn handle_control_job_archive(control: &ControlApiState, path: &str, request: tiny_http::Request) {
+    let job_id = match parse_job_id_from_path(path, Some("archive")) {
+        Ok(id) => id,
+        Err(err) => {
+            respond_json(request, 400, serde_json::json!({ "error": err }));
+            return;
+        }
+    };
+
+    let archived = match control.job_manager.lock() {
+        Ok(mut manager) => {
+            if manager.get(job_id).is_none() {
+                respond_json(request, 404, serde_json::json!({ "error": "not_found" }));
+                return;
+            }
+            manager.archive(job_id)
+        }
+        Err(_) => {
+            respond_json(
+                request,
+                500,
+                serde_json::json!({ "error": "job_manager_lock" }),
+            );
+            return;
+        }
+    };
+
+    if let Err(e) = archived {
+        respond_json(
+            request,
+            400,
+            serde_json::json!({ "error": "archive_failed", "details": e.to_string() }),
+        );
+        return;
+    }
+
+    if let Ok(mut gm) = control.group_manager.lock() {
+        let _ = gm.remove_job(job_id);
+    }
+
+    let _ = control
+        .executor_tx
+        .send(ExecutorEvent::Log(LogEvent::system(format!(
+            "Archived job #{}",
+            job_id
+        ))));
+
+    respond_json(request, 200, serde_json::json!({ "status": "ok", "job_id": job_id }));
+}
+
 pub fn handle_control_job_abort(control: &ControlApiState, path: &str, request: tiny_http::Request) {
     let job_id = match parse_job_id_from_path(path, Some("abort")) {
         Ok(id) => id,
@@ -106,6 +469,82 @@ pub fn handle_control_job_abort(control: &ControlApiState, path: &str, request:
         }
     };
 
+    match request_abort(control, job_id) {
+        Ok(Some(status)) if is_abortable(status) => respond_json(
+            request,
+            200,
+            serde_json::json!({ "status": "ok", "job_id": job_id }),
+        ),
+        Ok(Some(status)) => respond_json(
+            request,
+            400,
+            serde_json::json!({ "error": "not_abortable", "job_id": job_id, "status": status }),
+        ),
+        Ok(None) => respond_json(request, 404, serde_json::json!({ "error": "not_found" })),
+        Err(err) => respond_json(request, 500, serde_json::json!({ "error": err })),
+    }
+}
+
+/// POST /ctl/jobs/abort-all - abort every running, queued or blocked job.
+pub fn handle_control_jobs_abort_all(control: &ControlApiState, request: tiny_http::Request) {
+    let job_ids: Vec<JobId> = match control.job_manager.lock() {
+        Ok(manager) => manager
+            .jobs()
+            .iter()
+            .filter(|j| {
+                matches!(
+                    j.status,
+                    JobStatus::Running | JobStatus::Queued | JobStatus::Blocked
+                )
+            })
+            .map(|j| j.id)
+            .collect(),
+        Err(_) => {
+            respond_json(
+                request,
+                500,
+                serde_json::json!({ "error": "job_manager_lock" }),
+            );
+            return;
+        }
+    };
+
+    let mut aborted = Vec::with_capacity(job_ids.len());
+    for job_id in job_ids {
+        // A job may have finished between collecting ids and aborting it
+        if let Ok(Some(status)) = request_abort(control, job_id) {
+            if is_abortable(status) {
+                aborted.push(job_id);
+            }
+        }
+    }
+
+    let _ = control.executor_tx.send(ExecutorEvent::Log(LogEvent::system(format!(
+        "Abort requested for all jobs ({} job(s))",
+        aborted.len()
+    ))));
+    respond_json(
+        request,
+        200,
+        serde_json::json!({ "status": "ok", "aborted": aborted }),
+    );
+}
+
+fn is_abortable(status: JobStatus) -> bool {
+    matches!(
+        status,
+        JobStatus::Running | JobStatus::Queued | JobStatus::Pending | JobStatus::Blocked
+    )
+}
+
+/// Abort one job: running jobs get a cancellation request (and a bridge interrupt when the
+/// session is known), jobs that haven't started fail immediately.
+///
+/// Returns the job's status before the abort, or `None` if the job doesn't exist.
+fn request_abort(
+    control: &ControlApiState,
+    job_id: JobId,
+) -> Result<Option<JobStatus>, &'static str> {
     let (agent_id, session_id, status) = match control.job_manager.lock() {
         Ok(mut manager) => match manager.get_mut(job_id) {
             Some(job) => {
@@ -144,25 +583,12 @@ pub fn handle_control_job_abort(control: &ControlApiState, path: &str, request:
 
                 (agent_id, session_id, status)
             }
-            None => {
-                respond_json(request, 404, serde_json::json!({ "error": "not_found" }));
-                return;
-            }
+            None => return Ok(None),
         },
-        Err(_) => {
-            respond_json(
-                request,
-                500,
-                serde_json::json!({ "error": "job_manager_lock" }),
-            );
-            return;
-        }
+        Err(_) => return Err("job_manager_lock"),
     };
 
-    if matches!(
-        status,
-        JobStatus::Running | JobStatus::Queued | JobStatus::Pending | JobStatus::Blocked
-    ) {
+    if is_abortable(status) {
         if let Some(session_id) = session_id.as_deref() {
             let client = BridgeClient::new();
             let agent_id_lower = agent_id.to_ascii_lowercase();
@@ -229,20 +655,9 @@ pub fn handle_control_job_abort(control: &ControlApiState, path: &str, request:
         let _ = control.executor_tx.send(ExecutorEvent::Log(LogEvent::system(
             format!("Abort requested for job #{}", job_id),
         )));
-
-        respond_json(
-            request,
-            200,
-            serde_json::json!({ "status": "ok", "job_id": job_id }),
-        );
-        return;
     }
 
-    respond_json(
-        request,
-        400,
-        serde_json::json!({ "error": "not_abortable", "job_id": job_id, "status": status }),
-    );
+    Ok(Some(status))
 }
 
 /// Kill a job immediately (interrupt + fail without waiting for agent).
@@ -335,3 +750,208 @@ pub fn handle_control_job_kill(control: &ControlApiState, path: &str, request: t
         serde_json::json!({ "error": "not_killable", "job_id": job_id, "status": status }),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::test_support::TestServer;
+    use super::paginate_jobs;
+    use crate::JobStatus;
+    use crate::domain::test_support::job;
+    use chrono::{DateTime, Duration, FixedOffset, SecondsFormat, Utc};
+
+    /// RFC 3339 timestamp `hours` from now in UTC+2, with `:` and `+` percent-encoded
+    fn encoded_cutoff(hours: i64) -> String {
+        let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+        (Utc::now() + Duration::hours(hours))
+            .with_timezone(&offset)
+            .to_rfc3339_opts(SecondsFormat::Secs, false)
+            .replace(':', "%3A")
+            .replace('+', "%2B")
+    }
+
+    fn ids(body: &serde_json::Value) -> Vec<u64> {
+        body["jobs"]
+            .as_array()
+            .expect("jobs array")
+            .iter()
+            .filter_map(|job| job["id"].as_u64())
+            .collect()
+    }
+
+    #[test]
+    fn paginate_jobs_sorts_newest_first_and_counts_every_job() {
+        let jobs = || vec![job(2), job(5), job(3), job(1)];
+
+        let (page, total) = paginate_jobs(jobs(), 0, 2);
+        assert_eq!(total, 4);
+        assert_eq!(page.iter().map(|j| j.id).collect::<Vec<_>>(), vec![5, 3]);
+
+        let (page, total) = paginate_jobs(jobs(), 2, 10);
+        assert_eq!(total, 4);
+        assert_eq!(page.iter().map(|j| j.id).collect::<Vec<_>>(), vec![2, 1]);
+
+        let (page, total) = paginate_jobs(jobs(), 10, 2);
+        assert_eq!(total, 4);
+        assert!(page.is_empty());
+    }
+
+    #[test]
+    fn paginate_jobs_orders_random_ids_by_creation_time() {
+        let mut older = job(900);
+        let mut newer = job(12);
+        older.created_at = DateTime::UNIX_EPOCH;
+        newer.created_at = DateTime::UNIX_EPOCH + Duration::seconds(1);
+        let mut twin = job(40);
+        twin.created_at = newer.created_at;
+
+        let (page, _) = paginate_jobs(vec![older, newer, twin], 0, 10);
+        assert_eq!(page.iter().map(|j| j.id).collect::<Vec<_>>(), vec![40, 12, 900]);
+    }
+
+    #[test]
+    fn jobs_list_filters_by_status_and_pages() {
+        let server = TestServer::start();
+        let first = server.add_job("src/a.rs", JobStatus::Done);
+        server.add_job("src/b.rs", JobStatus::Queued);
+        let third = server.add_job("src/c.rs", JobStatus::Failed);
+
+        let (status, body) = server.get("/ctl/jobs?status=done,failed&limit=1");
+        assert_eq!(status, 200, "{}", body);
+        assert_eq!(body["total"], 2);
+        assert_eq!(ids(&body), vec![third]);
+
+        let (status, body) = server.get("/ctl/jobs?status=done,failed&offset=1&limit=1");
+        assert_eq!(status, 200, "{}", body);
+        assert_eq!(ids(&body), vec![first]);
+
+        let (status, body) = server.get("/ctl/jobs?offset=10");
+        assert_eq!(status, 200, "{}", body);
+        assert_eq!(body["total"], 3);
+        assert!(ids(&body).is_empty());
+    }
+
+    #[test]
+    fn jobs_list_rejects_bad_status_and_page_params() {
+        let server = TestServer::start();
+
+        let (status, body) = server.get("/ctl/jobs?status=done,nope");
+        assert_eq!(status, 400);
+        assert_eq!(body["error"], "bad_status");
+        assert_eq!(body["details"], "nope");
+
+        let (status, body) = server.get("/ctl/jobs?limit=ten");
+        assert_eq!(status, 400);
+        assert_eq!(body["error"], "bad_limit");
+
+        let (status, body) = server.get("/ctl/jobs?offset=-1");
+        assert_eq!(status, 400);
+        assert_eq!(body["error"], "bad_offset");
+    }
+
+    #[test]
+    fn jobs_list_decodes_since() {
+        let server = TestServer::start();
+        let job_id = server.add_job("src/lib.rs", JobStatus::Done);
+
+        let (status, body) = server.get(&format!("/ctl/jobs?since={}", encoded_cutoff(-1)));
+        assert_eq!(status, 200, "{}", body);
+        assert_eq!(body["total"], 1);
+        assert_eq!(body["jobs"][0]["id"], job_id);
+
+        let (status, body) = server.get(&format!("/ctl/jobs?since={}", encoded_cutoff(1)));
+        assert_eq!(status, 200, "{}", body);
+        assert_eq!(body["total"], 0);
+    }
+
+    #[test]
+    fn job_logs_since_includes_events_at_the_cutoff() {
+        let server = TestServer::start();
+        let job_id = server.add_job("src/lib.rs", JobStatus::Running);
+        let at = DateTime::UNIX_EPOCH + Duration::seconds(100);
+        {
+            let mut manager = server.control.job_manager.lock().unwrap();
+            let job = manager.get_mut(job_id).unwrap();
+            job.log_events.clear();
+            for (summary, secs) in [("before", 99), ("first", 100), ("second", 100)] {
+                let mut event = crate::LogEvent::system(summary);
+                event.timestamp = DateTime::UNIX_EPOCH + Duration::seconds(secs);
+                job.log_events.push(event);
+            }
+        }
+
+        let since = at.timestamp_nanos_opt().unwrap();
+        let (status, body) = server.get(&format!("/ctl/jobs/{}/logs?since={}", job_id, since));
+        assert_eq!(status, 200, "{}", body);
+        let summaries: Vec<&str> = body["events"]
+            .as_array()
+            .expect("events array")
+            .iter()
+            .filter_map(|e| e["summary"].as_str())
+            .collect();
+        assert_eq!(summaries, vec!["first", "second"]);
+    }
+
+    #[test]
+    fn job_tags_are_case_insensitive_and_tag_filter_is_decoded() {
+        let server = TestServer::start();
+        let tagged = server.add_job("src/lib.rs", JobStatus::Done);
+        let other = server.add_job("src/main.rs", JobStatus::Done);
+
+        let (status, body) = server.post(
+            &format!("/ctl/jobs/{}/tags", tagged),
+            serde_json::json!({ "add": ["Needs Review", "needs review", "API"] }),
+        );
+        assert_eq!(status, 200, "{}", body);
+        assert_eq!(body["tags"], serde_json::json!(["needs review", "api"]));
+
+        let (status, body) = server.post(
+            &format!("/ctl/jobs/{}/tags", tagged),
+            serde_json::json!({ "add": ["Api"], "remove": ["NEEDS REVIEW"] }),
+        );
+        assert_eq!(status, 200, "{}", body);
+        assert_eq!(body["tags"], serde_json::json!(["api"]));
+
+        let (status, _) = server.post(
+            &format!("/ctl/jobs/{}/tags", other),
+            serde_json::json!({ "tags": ["needs review"] }),
+        );
+        assert_eq!(status, 200);
+
+        let (status, body) = server.get("/ctl/jobs?tag=Needs%20Review");
+        assert_eq!(status, 200, "{}", body);
+        assert_eq!(body["total"], 1);
+        assert_eq!(body["jobs"][0]["id"], other);
+    }
+
+    #[test]
+    fn abort_all_stops_running_queued_and_blocked_jobs_only() {
+        let server = TestServer::start();
+        let running = server.add_job("src/a.rs", JobStatus::Running);
+        let queued = server.add_job("src/b.rs", JobStatus::Queued);
+        let blocked = server.add_job("src/c.rs", JobStatus::Blocked);
+        let pending = server.add_job("src/d.rs", JobStatus::Pending);
+        let done = server.add_job("src/e.rs", JobStatus::Done);
+
+        let (status, body) = server.post("/ctl/jobs/abort-all", serde_json::json!({}));
+        assert_eq!(status, 200, "{}", body);
+        let mut aborted: Vec<u64> = body["aborted"]
+            .as_array()
+            .expect("aborted ids")
+            .iter()
+            .filter_map(|id| id.as_u64())
+            .collect();
+        aborted.sort_unstable();
+        assert_eq!(aborted, vec![running, queued, blocked]);
+
+        let manager = server.control.job_manager.lock().unwrap();
+        let job = |id| manager.get(id).expect("job");
+        // Running jobs only get a cancellation request; the executor stops them
+        assert_eq!(job(running).status, JobStatus::Running);
+        assert!(job(running).cancel_requested);
+        assert_eq!(job(queued).status, JobStatus::Failed);
+        assert_eq!(job(blocked).status, JobStatus::Failed);
+        assert_eq!(job(pending).status, JobStatus::Pending);
+        assert_eq!(job(done).status, JobStatus::Done);
+        assert!(!job(done).cancel_requested);
+    }
+}
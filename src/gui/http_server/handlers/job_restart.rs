@@ -1,11 +1,24 @@
-//! Job restart handler: create a new job from a failed/rejected job's parameters.
+//! Job restart/retry handlers: create a new job from a failed/rejected job's parameters.
 
 use super::super::respond_json;
+use super::super::types::ControlJobRetryRequest;
 use super::{parse_job_id_from_path, ExecutorEvent};
 use crate::gui::http_server::types::ControlApiState;
 use crate::gui::jobs;
-use crate::{CommentTag, JobStatus, LogEvent, Target};
+use crate::job::JobManager;
+use crate::{CommentTag, Job, JobId, JobStatus, LogEvent, Target};
 
+/// What a restart carries over besides the original's file, line, mode, agent and prompt
+#[derive(Debug, Clone, Copy, Default)]
+struct RestartOptions {
+    /// Retry a failed job: link it via `retried_from` and keep its env, permission
+    /// mode, budget override and BugBounty links
+    retry: bool,
+    /// Run in the original job's worktree if it still exists (retry only)
+    same_worktree: bool,
+}
+
+/// POST /ctl/jobs/{id}/restart - re-run a failed or rejected job as a new job.
 pub fn handle_control_job_restart(
     control: &ControlApiState,
     path: &str,
@@ -19,9 +32,60 @@ pub fn handle_control_job_restart(
         }
     };
 
+    restart_job(control, job_id, RestartOptions::default(), request);
+}
+
+/// POST /ctl/jobs/{id}/retry - re-run a failed job as a new job linked via `retried_from`.
+///
+/// The original job stays Failed. With `same_worktree`, the new job runs in the
+/// original worktree (and branch) if that directory still exists.
+pub fn handle_control_job_retry(
+    control: &ControlApiState,
+    path: &str,
+    body: &str,
+    request: tiny_http::Request,
+) {
+    let job_id = match parse_job_id_from_path(path, Some("retry")) {
+        Ok(id) => id,
+        Err(err) => {
+            respond_json(request, 400, serde_json::json!({ "error": err }));
+            return;
+        }
+    };
+
+    let req: ControlJobRetryRequest = if body.trim().is_empty() {
+        ControlJobRetryRequest::default()
+    } else {
+        match serde_json::from_str(body) {
+            Ok(req) => req,
+            Err(e) => {
+                respond_json(
+                    request,
+                    400,
+                    serde_json::json!({ "error": "invalid_json", "details": e.to_string() }),
+                );
+                return;
+            }
+        }
+    };
+
+    let options = RestartOptions {
+        retry: true,
+        same_worktree: req.same_worktree,
+    };
+    restart_job(control, job_id, options, request);
+}
+
+/// Shared by restart and retry: create the new job, queue it and respond.
+fn restart_job(
+    control: &ControlApiState,
+    job_id: JobId,
+    options: RestartOptions,
+    request: tiny_http::Request,
+) {
     let mut logs: Vec<LogEvent> = Vec::new();
 
-    let created_id = {
+    let (created_id, reused_worktree) = {
         let mut manager = match control.job_manager.lock() {
             Ok(m) => m,
             Err(_) => {
@@ -39,69 +103,85 @@ pub fn handle_control_job_restart(
             return;
         };
 
-        // Only allow restart for failed or rejected jobs
-        if !matches!(original.status, JobStatus::Failed | JobStatus::Rejected) {
+        // Restart accepts failed or rejected jobs; retry is for failures only
+        let (allowed, error, message) = if options.retry {
+            (
+                original.status == JobStatus::Failed,
+                "not_retryable",
+                "Only failed jobs can be retried",
+            )
+        } else {
+            (
+                matches!(original.status, JobStatus::Failed | JobStatus::Rejected),
+                "not_restartable",
+                "Only failed or rejected jobs can be restarted",
+            )
+        };
+        if !allowed {
             respond_json(
                 request,
                 400,
                 serde_json::json!({
-                    "error": "not_restartable",
-                    "message": "Only failed or rejected jobs can be restarted",
+                    "error": error,
+                    "message": message,
                     "status": original.status.to_string()
                 }),
             );
             return;
         }
 
-        let description = original.description.clone().unwrap_or_default();
-
-        let tag = CommentTag {
-            file_path: original.source_file.clone(),
-            line_number: original.source_line,
-            raw_line: String::new(),
-            agent: original.agent_id.clone(),
-            agents: vec![original.agent_id.clone()],
-            mode: original.skill.clone(),
-            target: Target::Block,
-            status_marker: None,
-            description: if description.is_empty() {
-                None
-            } else {
-                Some(description)
-            },
-            job_id: None,
+        let new_job_id = match recreate_job(&mut manager, &original) {
+            Ok(id) => id,
+            Err(e) => {
+                respond_json(
+                    request,
+                    500,
+                    serde_json::json!({ "error": "create_failed", "details": e.to_string() }),
+                );
+                return;
+            }
         };
 
-        let new_job_id =
-            match manager.create_job_with_range(&tag, &original.agent_id, None)
-            {
-                Ok(id) => id,
-                Err(e) => {
-                    respond_json(
-                        request,
-                        500,
-                        serde_json::json!({ "error": "create_failed", "details": e.to_string() }),
-                    );
-                    return;
-                }
-            };
-
-        // Copy relevant context from original job to new job
-        if let Some(job) = manager.get_mut(new_job_id) {
-            job.raw_tag_line = None;
-            job.ide_context = original.ide_context;
-            job.force_worktree = original.force_worktree;
-            job.workspace_path = original.workspace_path.clone();
-            job.scope = original.scope.clone();
-            job.target = original.target;
+        let reusable_worktree = original
+            .git_worktree_path
+            .clone()
+            .filter(|_| options.same_worktree)
+            .filter(|p| p.exists());
+        if options.same_worktree && reusable_worktree.is_none() {
+            logs.push(LogEvent::system(format!(
+                "Worktree of job #{} no longer exists, retry #{} gets a fresh one",
+                job_id, new_job_id
+            )));
         }
+        let reused_worktree = reusable_worktree.is_some();
 
-        logs.push(LogEvent::system(format!(
-            "Restarted job #{} as #{}",
-            job_id, new_job_id
-        )));
+        if options.retry {
+            if let Some(job) = manager.get_mut(new_job_id) {
+                job.retried_from = Some(job_id);
+                job.ignore_budget = original.ignore_budget;
+                job.env = original.env.clone();
+                job.permission_mode = original.permission_mode.clone();
+                job.bugbounty_project_id = original.bugbounty_project_id.clone();
+                job.bugbounty_finding_ids = original.bugbounty_finding_ids.clone();
+                if let Some(worktree) = reusable_worktree {
+                    job.git_worktree_path = Some(worktree);
+                    job.branch_name = original.branch_name.clone();
+                    job.base_branch = original.base_branch.clone();
+                    job.git_base_revision = original.git_base_revision.clone();
+                }
+            }
+            logs.push(LogEvent::system(format!(
+                "Retrying failed job #{} as #{}",
+                job_id, new_job_id
+            )));
+        } else {
+            logs.push(LogEvent::system(format!(
+                "Restarted job #{} as #{}",
+                job_id, new_job_id
+            )));
+        }
 
-        new_job_id
+        (new_job_id, reused_worktree)
     };
 
     // Queue the new job immediately
@@ -117,7 +197,92 @@ pub fn handle_control_job_restart(
         serde_json::json!({
             "status": "ok",
             "old_job_id": job_id,
-            "new_job_id": created_id
+            "new_job_id": created_id,
+            "same_worktree": reused_worktree
         }),
     );
 }
+
+/// Create a new job with the original's file, line, mode, agent and prompt.
+fn recreate_job(manager: &mut JobManager, original: &Job) -> anyhow::Result<JobId> {
+    let description = original.description.clone().unwrap_or_default();
+
+    let tag = CommentTag {
+        file_path: original.source_file.clone(),
+        line_number: original.source_line,
+        raw_line: String::new(),
+        agent: original.agent_id.clone(),
+        agents: vec![original.agent_id.clone()],
+        mode: original.skill.clone(),
+        target: Target::Block,
+        status_marker: None,
+        description: if description.is_empty() {
+            None
+        } else {
+            Some(description)
+        },
+        job_id: None,
+    };
+
+    let new_job_id = manager.create_job_with_range(&tag, &original.agent_id, None)?;
+
+    // Copy relevant context from original job to new job
+    if let Some(job) = manager.get_mut(new_job_id) {
+        job.raw_tag_line = None;
+        job.ide_context = original.ide_context.clone();
+        job.force_worktree = original.force_worktree;
+        job.base_ref = original.base_ref.clone();
+        job.workspace_path = original.workspace_path.clone();
+        job.scope = original.scope.clone();
+        job.target = original.target.clone();
+    }
+
+    Ok(new_job_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::test_support::TestServer;
+    use crate::JobStatus;
+
+    #[test]
+    fn retry_goes_through_the_restart_path() {
+        let server = TestServer::start();
+        let failed = server.add_job("src/lib.rs", JobStatus::Failed);
+        let rejected = server.add_job("src/main.rs", JobStatus::Rejected);
+
+        let (status, body) = server.post(
+            &format!("/ctl/jobs/{}/retry", failed),
+            serde_json::json!({ "same_worktree": true }),
+        );
+        assert_eq!(status, 200, "{}", body);
+        assert_eq!(body["old_job_id"], failed);
+        assert_eq!(body["same_worktree"], false);
+
+        let new_id = body["new_job_id"].as_u64().expect("new job id");
+        let manager = server.control.job_manager.lock().unwrap();
+        let retried = manager.get(new_id).expect("retried job");
+        assert_eq!(retried.status, JobStatus::Queued);
+        assert_eq!(retried.retried_from, Some(failed));
+        assert_eq!(retried.source_file, server.path("src/lib.rs"));
+        assert_eq!(manager.get(failed).unwrap().status, JobStatus::Failed);
+        drop(manager);
+
+        // Rejected jobs can be restarted but not retried
+        let (status, body) = server.post(
+            &format!("/ctl/jobs/{}/retry", rejected),
+            serde_json::json!({}),
+        );
+        assert_eq!(status, 400);
+        assert_eq!(body["error"], "not_retryable");
+
+        let (status, body) = server.post(
+            &format!("/ctl/jobs/{}/restart", rejected),
+            serde_json::json!({}),
+        );
+        assert_eq!(status, 200, "{}", body);
+        let new_id = body["new_job_id"].as_u64().expect("new job id");
+        let manager = server.control.job_manager.lock().unwrap();
+        assert_eq!(manager.get(new_id).unwrap().retried_from, None);
+    }
+}
@@ -5,7 +5,7 @@
 use super::super::respond_json;
 use super::super::types::ControlApiState;
 use super::{parse_job_id_from_path, ExecutorEvent};
-use crate::git::{CommitMessage, GitManager};
+use crate::git::{CommitMessage, GitError, GitManager};
 use crate::{JobStatus, LogEvent};
 
 /// Handle POST /ctl/jobs/{id}/merge
@@ -25,14 +25,19 @@ pub fn handle_control_job_merge(
         }
     };
 
-    // Parse optional commit message from body
-    let custom_message: Option<String> = if !body.trim().is_empty() {
-        serde_json::from_str::<serde_json::Value>(body)
-            .ok()
-            .and_then(|v| v.get("message").and_then(|m| m.as_str()).map(String::from))
+    // Parse optional commit message and force flag from body
+    let body_json: Option<serde_json::Value> = if !body.trim().is_empty() {
+        serde_json::from_str(body).ok()
     } else {
         None
     };
+    let custom_message: Option<String> = body_json
+        .as_ref()
+        .and_then(|v| v.get("message").and_then(|m| m.as_str()).map(String::from));
+    let force = body_json
+        .as_ref()
+        .and_then(|v| v.get("force").and_then(|f| f.as_bool()))
+        .unwrap_or(false);
 
     // Get job info
     let job = match control.job_manager.lock() {
@@ -75,6 +80,7 @@ pub fn handle_control_job_merge(
                 j.set_status(JobStatus::Merged);
             }
         }
+        notify_merged(control, job_id);
         let _ = control
             .executor_tx
             .send(ExecutorEvent::Log(LogEvent::system(format!(
@@ -132,18 +138,88 @@ pub fn handle_control_job_merge(
         .map(|msg| CommitMessage::new(msg, None))
         .unwrap_or_else(|| CommitMessage::from_job(&job));
 
+    let merge_as_commit = control
+        .config
+        .read()
+        .map(|config| config.settings.merge_as_commit)
+        .unwrap_or(false);
+
     // Apply changes (merge worktree into base branch)
-    if let Err(e) = git.apply_changes(&worktree_path, &base_branch, Some(&commit_message)) {
-        respond_json(
-            request,
-            500,
-            serde_json::json!({
-                "error": "merge_failed",
-                "message": e.to_string()
-            }),
-        );
-        return;
-    }
+    let result = if merge_as_commit {
+        git.merge_worktree_as_commit(&worktree_path, &base_branch, &commit_message, force)
+    } else if force {
+        git.apply_changes_allow_diverged(&worktree_path, &base_branch, Some(&commit_message))
+            .map(|()| None)
+    } else {
+        git.apply_changes(&worktree_path, &base_branch, Some(&commit_message))
+            .map(|()| None)
+    };
+    let commit = match result {
+        Ok(commit) => commit,
+        Err(e) => {
+            let (status, body) = match e.downcast_ref::<GitError>() {
+                Some(GitError::BaseDiverged {
+                    base_branch,
+                    new_commits,
+                    ..
+                }) => (
+                    409,
+                    serde_json::json!({
+                        "error": "base_diverged",
+                        "message": format!("{} (retry with force to merge anyway)", e),
+                        "job_id": job_id,
+                        "base_branch": base_branch,
+                        "new_commits": new_commits
+                    }),
+                ),
+                Some(GitError::MergeConflict(files)) => (
+                    409,
+                    serde_json::json!({
+                        "error": "merge_conflict",
+                        "message": e.to_string(),
+                        "job_id": job_id,
+                        "files": files
+                    }),
+                ),
+                Some(GitError::Dirty(files)) => (
+                    409,
+                    serde_json::json!({
+                        "error": "repo_dirty",
+                        "message": e.to_string(),
+                        "job_id": job_id,
+                        "files": files
+                    }),
+                ),
+                Some(GitError::NotLocalBranch(base_branch)) => (
+                    409,
+                    serde_json::json!({
+                        "error": "not_local_branch",
+                        "message": e.to_string(),
+                        "job_id": job_id,
+                        "base_branch": base_branch
+                    }),
+                ),
+                Some(GitError::WorktreeMissing(path)) => (
+                    410,
+                    serde_json::json!({
+                        "error": "worktree_missing",
+                        "message": e.to_string(),
+                        "job_id": job_id,
+                        "worktree_path": path
+                    }),
+                ),
+                _ => (
+                    500,
+                    serde_json::json!({
+                        "error": "merge_failed",
+                        "message": e.to_string()
+                    }),
+                ),
+            };
+            respond_json(request, status, body);
+            return;
+        }
+    };
 
     // Cleanup worktree
     let cleanup_warning = match git.remove_worktree_by_path(&worktree_path) {
@@ -159,11 +235,15 @@ pub fn handle_control_job_merge(
             j.branch_name = None;
         }
     }
+    notify_merged(control, job_id);
 
-    let message = match cleanup_warning {
-        Some(warn) => format!("Merged job #{} (cleanup warning: {})", job_id, warn),
+    let mut message = match &commit {
+        Some(hash) => format!("Merged job #{} as commit {}", job_id, hash),
         None => format!("Merged job #{}", job_id),
     };
+    if let Some(warn) = cleanup_warning {
+        message.push_str(&format!(" (cleanup warning: {})", warn));
+    }
 
     let _ = control
         .executor_tx
@@ -175,7 +255,8 @@ pub fn handle_control_job_merge(
         serde_json::json!({
             "status": "ok",
             "job_id": job_id,
-            "message": message
+            "message": message,
+            "commit": commit
         }),
     );
 }
@@ -372,3 +453,18 @@ pub fn handle_control_job_diff(control: &ControlApiState, path: &str, request: t
         }),
     );
 }
+
+/// Fire the `settings.notify` hooks after a job was merged via the control API
+fn notify_merged(control: &ControlApiState, job_id: crate::JobId) {
+    let notify = match control.config.read() {
+        Ok(config) => config.settings.notify.clone(),
+        Err(_) => return,
+    };
+    let job = match control.job_manager.lock() {
+        Ok(manager) => manager.get(job_id).cloned(),
+        Err(_) => None,
+    };
+    if let Some(job) = job {
+        crate::gui::notify::notify_job_finished(&notify, &job, &control.executor_tx);
+    }
+}
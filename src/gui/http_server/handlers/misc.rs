@@ -1,4 +1,6 @@
-//! Miscellaneous control handlers: log, config reload.
+//! Miscellaneous control handlers: log, config reload, queue pause.
+
+use std::sync::atomic::Ordering;
 
 use super::super::types::{ControlApiState, ControlLogRequest};
 use super::super::respond_json;
@@ -38,9 +40,21 @@ pub fn handle_control_log(control: &ControlApiState, body: &str, request: tiny_h
 
 /// Handle config reload request from CLI or orchestrators.
 /// Immediately reloads the config from disk, bypassing the 500ms polling interval.
+/// Always parses the file again: an explicit reload must not trust the config cache.
 pub fn handle_control_config_reload(control: &ControlApiState, request: tiny_http::Request) {
     match Config::from_file(&control.config_path) {
-        Ok(new_config) => {
+        Ok(mut new_config) => {
+            // Skills are not stored in config.toml; rediscover them like the file watcher does
+            new_config.discover_skills(Some(&control.work_dir));
+            let counts = serde_json::json!({
+                "status": "ok",
+                "modes": new_config.mode.len(),
+                "skills": new_config.skill.len(),
+                "chains": new_config.chain.len(),
+                "agents": new_config.agent.len(),
+            });
+
+            // Running jobs resolved their agent config when they started and keep it
             if let Ok(mut guard) = control.config.write() {
                 *guard = new_config;
             }
@@ -50,7 +64,7 @@ pub fn handle_control_config_reload(control: &ControlApiState, request: tiny_htt
                     "Config reloaded via API from {}",
                     control.config_path.display()
                 ))));
-            respond_json(request, 200, serde_json::json!({ "status": "ok" }));
+            respond_json(request, 200, counts);
         }
         Err(e) => {
             let _ = control
@@ -67,3 +81,73 @@ pub fn handle_control_config_reload(control: &ControlApiState, request: tiny_htt
         }
     }
 }
+
+/// POST /ctl/queue/pause and /ctl/queue/resume - stop or resume starting queued jobs.
+///
+/// Running jobs are not affected; queued jobs stay Queued while paused.
+pub fn handle_control_queue_pause(
+    control: &ControlApiState,
+    paused: bool,
+    request: tiny_http::Request,
+) {
+    let was_paused = control.queue_paused.swap(paused, Ordering::Relaxed);
+    if was_paused != paused {
+        let message = if paused {
+            "Queue paused via API (no new jobs will start)"
+        } else {
+            "Queue resumed via API"
+        };
+        let _ = control
+            .executor_tx
+            .send(ExecutorEvent::Log(LogEvent::system(message.to_string())));
+    }
+    respond_json(
+        request,
+        200,
+        serde_json::json!({ "status": "ok", "paused": paused }),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::test_support::TestServer;
+    use super::ExecutorEvent;
+    use std::sync::atomic::Ordering;
+
+    /// Summaries of the log events the handlers sent so far
+    fn drain_logs(server: &TestServer) -> Vec<String> {
+        server
+            .executor_rx
+            .try_iter()
+            .filter_map(|event| match event {
+                ExecutorEvent::Log(log) => Some(log.summary),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn queue_pause_and_resume_toggle_the_flag_and_log_changes_only() {
+        let server = TestServer::start();
+
+        let (status, body) = server.post("/ctl/queue/pause", serde_json::json!({}));
+        assert_eq!(status, 200, "{}", body);
+        assert_eq!(body["paused"], true);
+        assert!(server.control.queue_paused.load(Ordering::Relaxed));
+        assert_eq!(drain_logs(&server).len(), 1);
+
+        // Pausing a paused queue succeeds without logging again
+        let (status, body) = server.post("/ctl/queue/pause", serde_json::json!({}));
+        assert_eq!(status, 200, "{}", body);
+        assert!(drain_logs(&server).is_empty());
+
+        let (status, body) = server.post("/ctl/queue/resume", serde_json::json!({}));
+        assert_eq!(status, 200, "{}", body);
+        assert_eq!(body["paused"], false);
+        assert!(!server.control.queue_paused.load(Ordering::Relaxed));
+        assert_eq!(
+            drain_logs(&server),
+            vec!["Queue resumed via API".to_string()]
+        );
+    }
+}
@@ -16,18 +16,20 @@ pub(super) use super::super::executor::ExecutorEvent;
 
 // Re-export all handlers for use from parent module
 pub use ide::{handle_batch_request, handle_selection_request};
-pub use job_continue::handle_control_job_continue;
+pub use job_continue::{handle_control_job_continue, handle_control_job_continue_batch};
 pub use job_create::handle_control_job_create;
 pub use job_delete::handle_control_job_delete;
 pub use job_lifecycle::{
-    handle_control_job_abort, handle_control_job_get, handle_control_job_kill,
-    handle_control_job_queue, handle_control_jobs_list,
+    handle_control_job_abort, handle_control_job_archive, handle_control_job_get,
+    handle_control_job_kill, handle_control_job_logs, handle_control_job_queue,
+    handle_control_job_resume, handle_control_job_tags, handle_control_jobs_abort_all,
+    handle_control_jobs_archived, handle_control_jobs_list,
 };
-pub use job_restart::handle_control_job_restart;
+pub use job_restart::{handle_control_job_restart, handle_control_job_retry};
 pub use job_worktree::{
     handle_control_job_diff, handle_control_job_merge, handle_control_job_reject,
 };
-pub use misc::{handle_control_config_reload, handle_control_log};
+pub use misc::{handle_control_config_reload, handle_control_log, handle_control_queue_pause};
 
 /// Parse job ID from URL path like `/ctl/jobs/123` or `/ctl/jobs/123/abort`.
 pub(crate) fn parse_job_id_from_path(path: &str, suffix: Option<&str>) -> Result<JobId, &'static str> {
@@ -42,3 +44,47 @@ pub(crate) fn parse_job_id_from_path(path: &str, suffix: Option<&str>) -> Result
     let id_str = trimmed.rsplit('/').next().ok_or("bad_path")?;
     id_str.parse::<JobId>().map_err(|_| "bad_job_id")
 }
+
+/// Decode `%XX` escapes in a query parameter value.
+///
+/// `+` is kept as-is so unescaped timestamp offsets like `+02:00` still parse;
+/// malformed escapes are passed through unchanged.
+pub(crate) fn percent_decode(raw: &str) -> String {
+    let bytes = raw.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .filter(|hex| bytes[i] == b'%' && hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percent_decode_handles_escapes_and_plus() {
+        assert_eq!(
+            percent_decode("2024-05-01T10%3A00%3A00%2B02%3A00"),
+            "2024-05-01T10:00:00+02:00"
+        );
+        assert_eq!(percent_decode("2024-05-01T10:00:00+02:00"), "2024-05-01T10:00:00+02:00");
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz"), "%zz");
+        assert_eq!(percent_decode("%+2"), "%+2");
+    }
+}
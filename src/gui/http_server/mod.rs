@@ -6,28 +6,35 @@
 //! - Control endpoints under /ctl/* (for orchestrators / CLI)
 
 mod handlers;
+#[cfg(test)]
+mod test_support;
 mod types;
 
 use std::io::Read;
 use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
 use std::thread;
 use tiny_http::{Response, Server};
 use tracing::{error, info};
 
 // Re-export public types for external use
 pub use types::{
-    BatchFile, BatchRequest, ControlApiState, ControlJobContinueRequest,
-    ControlJobContinueResponse, ControlJobCreateRequest, ControlJobCreateResponse,
-    ControlJobDeleteRequest, ControlJobDeleteResponse, ControlLogRequest, Dependency, Diagnostic,
-    SelectionRequest,
+    BatchFile, BatchRequest, BridgeStatus, ControlApiState, ControlJobContinueBatchRequest,
+    ControlJobContinueBatchResponse, ControlJobContinueRequest, ControlJobContinueResponse,
+    ControlJobCreateRequest, ControlJobCreateResponse, ControlJobDeleteRequest,
+    ControlJobDeleteResponse, ControlJobRetryRequest, ControlLogRequest, Dependency, Diagnostic, SelectionRegion,
+    SelectionRequest, format_selection_regions,
 };
 
 use handlers::{
     handle_batch_request, handle_control_config_reload, handle_control_job_abort,
-    handle_control_job_continue, handle_control_job_create, handle_control_job_delete,
-    handle_control_job_diff, handle_control_job_get, handle_control_job_kill,
+    handle_control_job_archive, handle_control_job_continue, handle_control_job_continue_batch,
+    handle_control_job_create, handle_control_job_delete, handle_control_job_diff,
+    handle_control_job_get, handle_control_job_kill, handle_control_job_logs,
     handle_control_job_merge, handle_control_job_queue, handle_control_job_reject,
-    handle_control_job_restart, handle_control_jobs_list, handle_control_log,
+    handle_control_job_restart, handle_control_job_resume, handle_control_job_retry,
+    handle_control_job_tags, handle_control_jobs_abort_all, handle_control_jobs_archived,
+    handle_control_jobs_list, handle_control_log, handle_control_queue_pause,
     handle_selection_request,
 };
 
@@ -42,6 +49,7 @@ pub fn start_http_server(
     port: u16,
     auth_token: Option<String>,
     control: ControlApiState,
+    status: Arc<Mutex<BridgeStatus>>,
 ) {
     thread::spawn(move || {
         let bind_addr = format!("127.0.0.1:{}", port);
@@ -55,10 +63,18 @@ pub fn start_http_server(
                     bind_addr,
                     if auth_enabled { "enabled" } else { "disabled" }
                 );
+                if let Ok(mut status) = status.lock() {
+                    status.listening = true;
+                    status.error = None;
+                }
                 s
             }
             Err(e) => {
                 error!("[kyco:http] Failed to start server on {}: {}", bind_addr, e);
+                if let Ok(mut status) = status.lock() {
+                    status.listening = false;
+                    status.error = Some(e.to_string());
+                }
                 return;
             }
         };
@@ -111,11 +127,19 @@ pub fn start_http_server(
                     );
                 }
                 ("GET", "/ctl/jobs") => {
-                    handle_control_jobs_list(&control, request);
+                    let query = url.split_once('?').map(|(_, q)| q);
+                    handle_control_jobs_list(&control, query, request);
+                }
+                ("GET", "/ctl/jobs/archived") => {
+                    handle_control_jobs_archived(&control, request);
                 }
                 ("GET", p) if p.starts_with("/ctl/jobs/") && p.ends_with("/diff") => {
                     handle_control_job_diff(&control, p, request);
                 }
+                ("GET", p) if p.starts_with("/ctl/jobs/") && p.ends_with("/logs") => {
+                    let query = url.split_once('?').map(|(_, q)| q);
+                    handle_control_job_logs(&control, p, query, request);
+                }
                 ("GET", p) if p.starts_with("/ctl/jobs/") => {
                     handle_control_job_get(&control, p, request);
                 }
@@ -129,6 +153,15 @@ pub fn start_http_server(
                     };
                     handle_control_job_create(&control, &body, request);
                 }
+                ("POST", "/ctl/jobs/abort-all") => {
+                    handle_control_jobs_abort_all(&control, request);
+                }
+                ("POST", "/ctl/queue/pause") => {
+                    handle_control_queue_pause(&control, true, request);
+                }
+                ("POST", "/ctl/queue/resume") => {
+                    handle_control_queue_pause(&control, false, request);
+                }
                 ("POST", p) if p.starts_with("/ctl/jobs/") && p.ends_with("/queue") => {
                     handle_control_job_queue(&control, p, request);
                 }
@@ -138,6 +171,9 @@ pub fn start_http_server(
                 ("POST", p) if p.starts_with("/ctl/jobs/") && p.ends_with("/kill") => {
                     handle_control_job_kill(&control, p, request);
                 }
+                ("POST", p) if p.starts_with("/ctl/jobs/") && p.ends_with("/archive") => {
+                    handle_control_job_archive(&control, p, request);
+                }
                 ("POST", p) if p.starts_with("/ctl/jobs/") && p.ends_with("/delete") => {
                     let body = match read_request_body(&mut request) {
                         Ok(body) => body,
@@ -158,6 +194,16 @@ pub fn start_http_server(
                     };
                     handle_control_job_continue(&control, p, &body, request);
                 }
+                ("POST", p) if p.starts_with("/ctl/jobs/") && p.ends_with("/continue_batch") => {
+                    let body = match read_request_body(&mut request) {
+                        Ok(body) => body,
+                        Err(response) => {
+                            let _ = request.respond(response);
+                            continue;
+                        }
+                    };
+                    handle_control_job_continue_batch(&control, p, &body, request);
+                }
                 ("POST", p) if p.starts_with("/ctl/jobs/") && p.ends_with("/merge") => {
                     let body = match read_request_body(&mut request) {
                         Ok(body) => body,
@@ -174,6 +220,29 @@ pub fn start_http_server(
                 ("POST", p) if p.starts_with("/ctl/jobs/") && p.ends_with("/restart") => {
                     handle_control_job_restart(&control, p, request);
                 }
+                ("POST", p) if p.starts_with("/ctl/jobs/") && p.ends_with("/retry") => {
+                    let body = match read_request_body(&mut request) {
+                        Ok(body) => body,
+                        Err(response) => {
+                            let _ = request.respond(response);
+                            continue;
+                        }
+                    };
+                    handle_control_job_retry(&control, p, &body, request);
+                }
+                ("POST", p) if p.starts_with("/ctl/jobs/") && p.ends_with("/resume") => {
+                    handle_control_job_resume(&control, p, request);
+                }
+                ("POST", p) if p.starts_with("/ctl/jobs/") && p.ends_with("/tags") => {
+                    let body = match read_request_body(&mut request) {
+                        Ok(body) => body,
+                        Err(response) => {
+                            let _ = request.respond(response);
+                            continue;
+                        }
+                    };
+                    handle_control_job_tags(&control, p, &body, request);
+                }
                 ("POST", "/ctl/log") => {
                     let body = match read_request_body(&mut request) {
                         Ok(body) => body,
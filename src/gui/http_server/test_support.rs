@@ -0,0 +1,111 @@
+//! Test harness for the control API: a live server on a free port backed by a
+//! temporary job store, so handler tests exercise routing, bodies and status codes.
+
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+use super::{BatchRequest, BridgeStatus, ControlApiState, SelectionRequest, start_http_server};
+use crate::config::Config;
+use crate::domain::test_support::comment_tag;
+use crate::gui::executor::ExecutorEvent;
+use crate::job::{GroupManager, JobManager};
+use crate::{JobId, JobStatus};
+
+pub(crate) struct TestServer {
+    pub url: String,
+    pub control: ControlApiState,
+    /// Kept so handler sends succeed; tests may inspect the emitted events
+    pub executor_rx: Receiver<ExecutorEvent>,
+    _selection_rx: Receiver<SelectionRequest>,
+    _batch_rx: Receiver<BatchRequest>,
+    dir: tempfile::TempDir,
+}
+
+impl TestServer {
+    pub fn start() -> Self {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let (executor_tx, executor_rx) = mpsc::channel();
+        let (selection_tx, selection_rx) = mpsc::channel();
+        let (batch_tx, batch_rx) = mpsc::channel();
+
+        let control = ControlApiState {
+            work_dir: dir.path().to_path_buf(),
+            job_manager: Arc::new(Mutex::new(JobManager::new(dir.path()))),
+            group_manager: Arc::new(Mutex::new(GroupManager::new())),
+            executor_tx,
+            config: Arc::new(RwLock::new(Config::default())),
+            config_path: dir.path().join("config.toml"),
+            queue_paused: Arc::new(AtomicBool::new(false)),
+        };
+
+        let port = free_port();
+        let status = Arc::new(Mutex::new(BridgeStatus::new(port, false)));
+        start_http_server(
+            selection_tx,
+            batch_tx,
+            port,
+            None,
+            control.clone(),
+            Arc::clone(&status),
+        );
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !status.lock().map(|s| s.listening).unwrap_or(false) {
+            assert!(Instant::now() < deadline, "test server did not start");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        Self {
+            url: format!("http://127.0.0.1:{}", port),
+            control,
+            executor_rx,
+            _selection_rx: selection_rx,
+            _batch_rx: batch_rx,
+            dir,
+        }
+    }
+
+    /// Create a job for `file` in the job store and move it to `status`
+    pub fn add_job(&self, file: &str, status: JobStatus) -> JobId {
+        let tag = comment_tag(self.path(file));
+        let mut manager = self.control.job_manager.lock().expect("job manager");
+        let job_id = manager.create_job(&tag, "claude").expect("create job");
+        manager.set_status(job_id, status);
+        job_id
+    }
+
+    pub fn path(&self, relative: &str) -> PathBuf {
+        self.dir.path().join(relative)
+    }
+
+    pub fn get(&self, path: &str) -> (u16, Value) {
+        call(ureq::get(&format!("{}{}", self.url, path)).call())
+    }
+
+    pub fn post(&self, path: &str, body: Value) -> (u16, Value) {
+        call(ureq::post(&format!("{}{}", self.url, path)).send_json(body))
+    }
+}
+
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .map(|addr| addr.port())
+        .expect("free port")
+}
+
+fn call(result: Result<ureq::Response, ureq::Error>) -> (u16, Value) {
+    let response = match result {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(e) => panic!("request failed: {}", e),
+    };
+    let status = response.status();
+    (status, response.into_json().unwrap_or(Value::Null))
+}
@@ -1,6 +1,8 @@
 //! Type definitions for the HTTP server.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
 use std::sync::mpsc::Sender;
 use std::sync::{Arc, Mutex, RwLock};
 
@@ -18,6 +20,44 @@ pub struct ControlApiState {
     pub executor_tx: Sender<ExecutorEvent>,
     pub config: Arc<RwLock<Config>>,
     pub config_path: std::path::PathBuf,
+    /// While set, the executor starts no new jobs (`/ctl/queue/pause`)
+    pub queue_paused: Arc<AtomicBool>,
+}
+
+/// Runtime status of the IDE bridge (the HTTP server IDE extensions talk to).
+///
+/// Shared between the server thread and the GUI so the status bar can show
+/// whether selections can actually arrive.
+#[derive(Debug, Clone, Default)]
+pub struct BridgeStatus {
+    pub port: u16,
+    pub auth_enabled: bool,
+    /// Server bound successfully and is accepting requests
+    pub listening: bool,
+    /// Bind error (e.g. port already in use)
+    pub error: Option<String>,
+    /// When the last selection/batch arrived and what it contained
+    pub last_received: Option<(chrono::DateTime<chrono::Local>, String)>,
+}
+
+impl BridgeStatus {
+    pub fn new(port: u16, auth_enabled: bool) -> Self {
+        Self {
+            port,
+            auth_enabled,
+            ..Default::default()
+        }
+    }
+
+    /// Base URL to configure in the IDE extension
+    pub fn url(&self) -> String {
+        format!("http://127.0.0.1:{}", self.port)
+    }
+
+    /// Record an incoming selection/batch
+    pub fn record_received(&mut self, source: impl Into<String>) {
+        self.last_received = Some((chrono::Local::now(), source.into()));
+    }
 }
 
 /// Dependency location from IDE
@@ -42,6 +82,37 @@ pub struct Diagnostic {
     pub code: Option<String>,
 }
 
+/// One selected code region (used when an IDE sends several regions as one request)
+#[derive(Debug, Clone, Deserialize)]
+pub struct SelectionRegion {
+    pub file_path: String,
+    pub selected_text: Option<String>,
+    pub line_start: Option<usize>,
+    pub line_end: Option<usize>,
+}
+
+/// Format selection regions as markdown for prompt injection
+pub fn format_selection_regions(regions: &[SelectionRegion]) -> String {
+    let mut ctx = String::new();
+    if regions.is_empty() {
+        return ctx;
+    }
+
+    ctx.push_str(&format!("\n### Selected Regions ({}):\n", regions.len()));
+    for (index, region) in regions.iter().enumerate() {
+        let lines = match (region.line_start, region.line_end) {
+            (Some(start), Some(end)) if end != start => format!(":{}-{}", start, end),
+            (Some(start), _) => format!(":{}", start),
+            _ => String::new(),
+        };
+        ctx.push_str(&format!("{}. `{}{}`\n", index + 1, region.file_path, lines));
+        if let Some(text) = region.selected_text.as_deref().filter(|t| !t.trim().is_empty()) {
+            ctx.push_str(&format!("```\n{}\n```\n", text.trim_end()));
+        }
+    }
+    ctx
+}
+
 /// Selection data received from IDE extensions
 #[derive(Debug, Clone, Deserialize)]
 pub struct SelectionRequest {
@@ -61,6 +132,13 @@ pub struct SelectionRequest {
     pub related_tests: Option<Vec<String>>,
     /// Diagnostics (errors, warnings) from the IDE for this file
     pub diagnostics: Option<Vec<Diagnostic>>,
+    /// Multiple selected regions to handle as one job (file_path/selected_text
+    /// describe the primary region; defaults to the first entry when unset)
+    #[serde(default)]
+    pub regions: Option<Vec<SelectionRegion>>,
+    /// Lines before/after the selection to include in the prompt (0 = none)
+    #[serde(default)]
+    pub context_lines: usize,
 }
 
 /// A single file in a batch request
@@ -93,7 +171,7 @@ pub struct BatchRequest {
 /// Control API: create one or more jobs from a file selection.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ControlJobCreateRequest {
-    /// File path (relative to KYCo work_dir, or absolute). Optional if prompt is provided.
+    /// File or directory path (relative to KYCo work_dir, or absolute). Optional if prompt is provided.
     pub file_path: Option<String>,
     pub line_start: Option<usize>,
     pub line_end: Option<usize>,
@@ -125,6 +203,24 @@ pub struct ControlJobCreateRequest {
     /// Permission mode override (default, acceptEdits, bypassPermissions, plan).
     #[serde(default)]
     pub permission_mode: Option<String>,
+    /// Extra environment variables for the job (override the agent's `env`).
+    #[serde(default)]
+    pub env: Option<HashMap<String, String>>,
+    /// Start even if the daily budget (`settings.budget`) is exhausted.
+    #[serde(default)]
+    pub ignore_budget: bool,
+    /// Multi-agent only: pick the winner automatically by result state.
+    #[serde(default)]
+    pub auto_select: Option<crate::AutoSelectPolicy>,
+    /// Lines before/after the selection to include in the prompt (0 = none).
+    #[serde(default)]
+    pub context_lines: usize,
+    /// Labels attached to every created job (see [`crate::Job::normalize_tags`]).
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Branch or commit the job's worktree is created from (implies a worktree).
+    #[serde(default)]
+    pub base: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -133,6 +229,18 @@ pub struct ControlJobCreateResponse {
     pub group_id: Option<crate::AgentGroupId>,
 }
 
+/// Body of POST /ctl/jobs/{id}/tags: `tags` replaces the whole set, then
+/// `add` and `remove` are applied.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ControlJobTagsRequest {
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    #[serde(default)]
+    pub add: Vec<String>,
+    #[serde(default)]
+    pub remove: Vec<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ControlLogRequest {
     pub message: String,
@@ -156,6 +264,38 @@ pub struct ControlJobContinueResponse {
     pub job_id: JobId,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct ControlJobContinueBatchRequest {
+    /// Follow-up prompts, sent in order as separate turns of the same session.
+    pub prompts: Vec<String>,
+    /// Skip the remaining prompts once a step does not finish successfully.
+    #[serde(default)]
+    pub stop_on_failure: bool,
+    /// Fork the session instead of continuing it (creates a branch).
+    #[serde(default)]
+    pub fork_session: bool,
+    /// Enable plan mode for every continuation (Claude only).
+    #[serde(default)]
+    pub plan_mode: bool,
+    /// Seconds a step may take from being queued until it finishes (default: 2 hours);
+    /// on timeout the remaining steps fail.
+    #[serde(default)]
+    pub step_timeout_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ControlJobContinueBatchResponse {
+    /// Created continuation job IDs, in execution order.
+    pub job_ids: Vec<JobId>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ControlJobRetryRequest {
+    /// Run in the original job's worktree if it still exists.
+    #[serde(default)]
+    pub same_worktree: bool,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ControlJobDeleteRequest {
     #[serde(default)]
@@ -223,6 +363,11 @@ impl SelectionRequest {
             }
         }
 
+        // Additional regions (multi-selection)
+        if let Some(ref regions) = self.regions {
+            ctx.push_str(&format_selection_regions(regions));
+        }
+
         // Diagnostics (Errors/Warnings)
         if let Some(ref diagnostics) = self.diagnostics {
             if !diagnostics.is_empty() {
@@ -15,11 +15,16 @@ use crate::{Job, JobStatus};
 use eframe::egui::{self, Color32, RichText, ScrollArea, Stroke};
 
 /// Render the job list panel
+///
+/// `archived_jobs` is only shown (read-only) when the Archived filter is selected.
+/// `tag_filter` narrows either list to jobs carrying that tag.
 pub fn render_job_list(
     ui: &mut egui::Ui,
     cached_jobs: &[Job],
+    archived_jobs: &[Job],
     selected_job_id: &mut Option<u64>,
     filter: &mut JobListFilter,
+    tag_filter: &mut Option<String>,
 ) -> JobListAction {
     let mut action = JobListAction::None;
 
@@ -31,6 +36,7 @@ pub fn render_job_list(
     let count_active = JobListFilter::Active.count(cached_jobs);
     let count_finished = JobListFilter::Finished.count(cached_jobs);
     let count_failed = JobListFilter::Failed.count(cached_jobs);
+    let count_archived = archived_jobs.len();
 
     ui.vertical(|ui| {
         render_header(ui, count_finished, &mut action);
@@ -42,11 +48,26 @@ pub fn render_job_list(
             count_active,
             count_finished,
             count_failed,
+            count_archived,
         );
         ui.add_space(4.0);
+
+        let jobs = if *filter == JobListFilter::Archived {
+            archived_jobs
+        } else {
+            cached_jobs
+        };
+        render_tag_filter(ui, jobs, tag_filter);
         ui.separator();
 
-        render_job_scroll_area(ui, cached_jobs, selected_job_id, filter, &mut action);
+        render_job_scroll_area(
+            ui,
+            jobs,
+            selected_job_id,
+            filter,
+            tag_filter.as_deref(),
+            &mut action,
+        );
     });
 
     action
@@ -94,6 +115,7 @@ fn render_filter_tabs(
     count_active: usize,
     count_finished: usize,
     count_failed: usize,
+    count_archived: usize,
 ) {
     ui.horizontal(|ui| {
         for (filter_option, count) in [
@@ -101,6 +123,7 @@ fn render_filter_tabs(
             (JobListFilter::Active, count_active),
             (JobListFilter::Finished, count_finished),
             (JobListFilter::Failed, count_failed),
+            (JobListFilter::Archived, count_archived),
         ] {
             let is_selected = *filter == filter_option;
             let label = filter_option.label();
@@ -138,18 +161,65 @@ fn render_filter_tabs(
     });
 }
 
+/// Render one toggle chip per tag used by `jobs`; nothing when no job is tagged.
+fn render_tag_filter(ui: &mut egui::Ui, jobs: &[Job], tag_filter: &mut Option<String>) {
+    let mut tags: Vec<&str> = jobs
+        .iter()
+        .flat_map(|j| j.tags.iter().map(String::as_str))
+        .collect();
+    tags.sort_unstable();
+    tags.dedup();
+
+    // Keep a selected tag visible even if no job in this list carries it any more
+    if let Some(selected) = tag_filter.as_deref() {
+        if !tags.contains(&selected) {
+            tags.push(selected);
+        }
+    }
+    if tags.is_empty() {
+        return;
+    }
+
+    ui.horizontal_wrapped(|ui| {
+        ui.label(RichText::new("Tags").small().color(TEXT_MUTED));
+        for tag in tags {
+            let is_selected = tag_filter.as_deref() == Some(tag);
+            let (text_color, bg_color) = if is_selected {
+                (ACCENT_CYAN, BG_HIGHLIGHT)
+            } else {
+                (TEXT_DIM, BG_SECONDARY)
+            };
+            let btn = egui::Button::new(RichText::new(tag).small().color(text_color))
+                .fill(bg_color)
+                .corner_radius(4.0);
+
+            if ui.add(btn).clicked() {
+                *tag_filter = if is_selected {
+                    None
+                } else {
+                    Some(tag.to_string())
+                };
+            }
+        }
+    });
+}
+
 fn render_job_scroll_area(
     ui: &mut egui::Ui,
     cached_jobs: &[Job],
     selected_job_id: &mut Option<u64>,
     filter: &JobListFilter,
+    tag_filter: Option<&str>,
     action: &mut JobListAction,
 ) {
     ScrollArea::vertical()
         .auto_shrink([false, false])
         .show(ui, |ui| {
-            let mut filtered_jobs: Vec<&Job> =
-                cached_jobs.iter().filter(|j| filter.matches(j)).collect();
+            let mut filtered_jobs: Vec<&Job> = cached_jobs
+                .iter()
+                .filter(|j| filter.matches(j))
+                .filter(|j| tag_filter.is_none_or(|tag| j.has_tag(tag)))
+                .collect();
 
             filtered_jobs.sort_by(|a, b| {
                 let priority = |s: JobStatus| match s {
@@ -167,14 +237,17 @@ fn render_job_scroll_area(
                     .then_with(|| b.updated_at.cmp(&a.updated_at))
             });
 
+            let read_only = *filter == JobListFilter::Archived;
             for job in filtered_jobs {
                 let is_selected = *selected_job_id == Some(job.id);
-                let response = render_job_row(ui, job, is_selected, action);
+                let response = render_job_row(ui, job, is_selected, read_only, action);
 
                 // Only handle row click if no button action was triggered
-                // (delete button sets action, which takes priority)
-                if !matches!(action, JobListAction::DeleteJob(_))
-                    && response.interact(egui::Sense::click()).clicked()
+                // (delete/archive buttons set action, which takes priority)
+                if !matches!(
+                    action,
+                    JobListAction::DeleteJob(_) | JobListAction::ArchiveJob(_)
+                ) && response.interact(egui::Sense::click()).clicked()
                 {
                     *selected_job_id = Some(job.id);
                 }
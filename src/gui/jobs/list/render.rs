@@ -143,6 +143,7 @@ pub fn render_job_row(
     ui: &mut egui::Ui,
     job: &Job,
     is_selected: bool,
+    read_only: bool,
     action: &mut JobListAction,
 ) -> egui::Response {
     let bg = if is_selected {
@@ -186,6 +187,10 @@ pub fn render_job_row(
 
                 render_blocked_info(ui, job);
 
+                for tag in &job.tags {
+                    ui.label(RichText::new(format!("#{}", tag)).small().color(ACCENT_CYAN));
+                }
+
                 // Show state if available (for finished jobs)
                 if let Some(ref result) = job.result {
                     if let Some(ref state) = result.state {
@@ -211,7 +216,7 @@ pub fn render_job_row(
                 }
             });
 
-            render_target_row(ui, job, action);
+            render_target_row(ui, job, read_only, action);
         })
         .response
 }
@@ -251,8 +256,8 @@ fn render_time_info(ui: &mut egui::Ui, job: &Job) {
     }
 }
 
-/// Render the target file row with delete button for finished jobs
-fn render_target_row(ui: &mut egui::Ui, job: &Job, action: &mut JobListAction) {
+/// Render the target file row with archive/delete buttons for finished jobs
+fn render_target_row(ui: &mut egui::Ui, job: &Job, read_only: bool, action: &mut JobListAction) {
     let row_width = ui.available_width();
     ui.horizontal(|ui| {
         ui.set_width(row_width);
@@ -262,7 +267,8 @@ fn render_target_row(ui: &mut egui::Ui, job: &Job, action: &mut JobListAction) {
             .and_then(|f| f.to_str())
             .unwrap_or(&job.target);
 
-        let btn_space = if job.is_finished() { 32.0 } else { 0.0 };
+        let show_buttons = job.is_finished() && !read_only;
+        let btn_space = if show_buttons { 52.0 } else { 0.0 };
         let max_filename_width = row_width - btn_space;
 
         let max_chars = ((max_filename_width / 6.5) as usize).saturating_sub(2);
@@ -280,7 +286,7 @@ fn render_target_row(ui: &mut egui::Ui, job: &Job, action: &mut JobListAction) {
         ui.label(RichText::new(&display_target).color(TEXT_DIM))
             .on_hover_text(&job.target);
 
-        if job.is_finished() {
+        if show_buttons {
             let remaining = ui.available_width();
             if remaining > 0.0 {
                 ui.allocate_ui_with_layout(
@@ -301,6 +307,21 @@ fn render_target_row(ui: &mut egui::Ui, job: &Job, action: &mut JobListAction) {
                         {
                             *action = JobListAction::DeleteJob(job.id);
                         }
+
+                        let archive_btn =
+                            egui::Button::new(RichText::new("⤓").color(TEXT_DIM).size(12.0))
+                                .fill(Color32::TRANSPARENT)
+                                .stroke(Stroke::NONE)
+                                .small();
+
+                        if ui
+                            .add(archive_btn)
+                            .on_hover_text("Archive this job")
+                            .on_hover_cursor(egui::CursorIcon::PointingHand)
+                            .clicked()
+                        {
+                            *action = JobListAction::ArchiveJob(job.id);
+                        }
                     },
                 );
             }
@@ -14,6 +14,8 @@ pub enum JobListFilter {
     Finished,
     /// Show only failed jobs
     Failed,
+    /// Show archived jobs (read-only, loaded from the archive store)
+    Archived,
 }
 
 impl JobListFilter {
@@ -24,6 +26,8 @@ impl JobListFilter {
             JobListFilter::Active => !job.is_finished(),
             JobListFilter::Finished => job.is_finished(),
             JobListFilter::Failed => job.status == JobStatus::Failed,
+            // Archived jobs come from a separate list, not the active one
+            JobListFilter::Archived => true,
         }
     }
 
@@ -34,6 +38,7 @@ impl JobListFilter {
             JobListFilter::Active => "Active",
             JobListFilter::Finished => "Done",
             JobListFilter::Failed => "Failed",
+            JobListFilter::Archived => "Archived",
         }
     }
 
@@ -52,4 +57,6 @@ pub enum JobListAction {
     DeleteJob(JobId),
     /// Delete all finished jobs
     DeleteAllFinished,
+    /// Move the specified finished job to the archive
+    ArchiveJob(JobId),
 }
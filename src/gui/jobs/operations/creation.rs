@@ -2,7 +2,7 @@
 
 use super::super::super::selection::SelectionContext;
 use crate::job::{GroupManager, JobManager};
-use crate::{AgentGroupId, CommentTag, JobId, LogEvent, Target};
+use crate::{AgentGroupId, AutoSelectPolicy, CommentTag, JobId, LogEvent, Target};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
@@ -76,7 +76,8 @@ pub fn create_job_from_selection(
 /// Create jobs from selection - supports multi-agent parallel execution
 ///
 /// If `agents` contains multiple agents, a group is created and multiple
-/// jobs are spawned in parallel.
+/// jobs are spawned in parallel. With `auto_select`, the group picks its
+/// winner itself instead of waiting for the comparison popup.
 pub fn create_jobs_from_selection_multi(
     job_manager: &Arc<Mutex<JobManager>>,
     group_manager: &Arc<Mutex<GroupManager>>,
@@ -86,6 +87,7 @@ pub fn create_jobs_from_selection_multi(
     prompt: &str,
     logs: &mut Vec<LogEvent>,
     force_worktree: bool,
+    auto_select: Option<AutoSelectPolicy>,
 ) -> Option<CreateJobsResult> {
     // If no file_path is provided, use workspace or a placeholder for prompt-only jobs
     let file_path = selection.file_path.clone().unwrap_or_else(|| {
@@ -152,7 +154,9 @@ pub fn create_jobs_from_selection_multi(
 
     // Multi-agent - create a group
     let group_id = if let Ok(mut gm) = group_manager.lock() {
-        gm.create_group(prompt.to_string(), mode.to_string(), target.clone())
+        let group_id = gm.create_group(prompt.to_string(), mode.to_string(), target.clone());
+        gm.set_auto_select(group_id, auto_select);
+        group_id
     } else {
         logs.push(LogEvent::error(
             "Failed to acquire group manager lock".to_string(),
@@ -43,6 +43,7 @@ pub mod hotkey;
 pub mod http_server;
 pub mod install;
 pub mod jobs;
+mod notify;
 pub mod skills;
 pub mod output_schema;
 pub mod permission;
@@ -52,6 +53,7 @@ pub mod settings;
 pub mod status_bar;
 pub mod theme;
 mod toast;
+mod ui_state;
 pub mod update;
 pub mod voice;
 
@@ -63,7 +65,7 @@ pub use http_server::{BatchFile, BatchRequest, SelectionRequest};
 pub use permission::{
     PermissionAction, PermissionPopupState, PermissionRequest, render_permission_popup,
 };
-pub use runner::run_gui;
+pub use runner::{run_gui, run_serve};
 pub use selection::SelectionContext;
 pub use update::{UpdateChecker, UpdateInfo, UpdateStatus};
 pub use voice::{VoiceConfig, VoiceEvent, VoiceInputMode, VoiceManager, VoiceState};
@@ -0,0 +1,142 @@
+//! Job completion notification hooks (`settings.notify`)
+//!
+//! Hooks run on a background thread so a slow command or webhook never blocks
+//! the GUI. Failures are reported as log events and never affect the job.
+
+use std::process::Command;
+use std::sync::mpsc::Sender;
+
+use super::executor::ExecutorEvent;
+use crate::config::NotifySettings;
+use crate::{Job, JobId, JobStatus, LogEvent};
+
+/// JSON body POSTed to `on_complete_webhook`
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct JobNotification {
+    pub job_id: JobId,
+    pub status: JobStatus,
+    pub mode: String,
+}
+
+impl JobNotification {
+    pub fn new(job: &Job) -> Self {
+        Self {
+            job_id: job.id,
+            status: job.status,
+            mode: job.skill.clone(),
+        }
+    }
+
+    /// Environment variables passed to `on_complete_command`
+    pub fn env_vars(&self) -> [(&'static str, String); 3] {
+        [
+            ("KYCO_JOB_ID", self.job_id.to_string()),
+            ("KYCO_STATUS", self.status.as_marker().to_string()),
+            ("KYCO_MODE", self.mode.clone()),
+        ]
+    }
+}
+
+/// Fire the configured notification hooks for a finished job.
+///
+/// Only Done, Failed, and Merged jobs trigger notifications; anything else is ignored.
+pub(crate) fn notify_job_finished(
+    settings: &NotifySettings,
+    job: &Job,
+    event_tx: &Sender<ExecutorEvent>,
+) {
+    if !matches!(
+        job.status,
+        JobStatus::Done | JobStatus::Failed | JobStatus::Merged
+    ) || !settings.is_enabled()
+    {
+        return;
+    }
+
+    let settings = settings.clone();
+    let notification = JobNotification::new(job);
+    let event_tx = event_tx.clone();
+
+    std::thread::spawn(move || {
+        if let Some(command) = settings
+            .on_complete_command
+            .as_deref()
+            .filter(|c| !c.trim().is_empty())
+        {
+            if let Err(e) = run_command(command, &notification) {
+                let _ = event_tx.send(ExecutorEvent::Log(LogEvent::error(format!(
+                    "Notification command for job #{} failed: {}",
+                    notification.job_id, e
+                ))));
+            }
+        }
+
+        if let Some(url) = settings
+            .on_complete_webhook
+            .as_deref()
+            .filter(|u| !u.trim().is_empty())
+        {
+            if let Err(e) = post_webhook(url, &notification) {
+                let _ = event_tx.send(ExecutorEvent::Log(LogEvent::error(format!(
+                    "Notification webhook for job #{} failed: {}",
+                    notification.job_id, e
+                ))));
+            }
+        }
+    });
+}
+
+fn run_command(command: &str, notification: &JobNotification) -> Result<(), String> {
+    let output = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .envs(notification.env_vars())
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stderr = stderr.trim();
+    if stderr.is_empty() {
+        Err(output.status.to_string())
+    } else {
+        Err(format!("{}: {}", output.status, stderr))
+    }
+}
+
+fn post_webhook(url: &str, notification: &JobNotification) -> Result<(), String> {
+    ureq::post(url)
+        .timeout(std::time::Duration::from_secs(10))
+        .send_json(notification)
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn notification_serializes_status_in_lowercase() {
+        let notification = JobNotification {
+            job_id: 42,
+            status: JobStatus::Merged,
+            mode: "review".to_string(),
+        };
+
+        assert_eq!(
+            serde_json::to_value(&notification).unwrap(),
+            serde_json::json!({"job_id": 42, "status": "merged", "mode": "review"})
+        );
+        assert_eq!(
+            notification.env_vars(),
+            [
+                ("KYCO_JOB_ID", "42".to_string()),
+                ("KYCO_STATUS", "merged".to_string()),
+                ("KYCO_MODE", "review".to_string()),
+            ]
+        );
+    }
+}
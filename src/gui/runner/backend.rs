@@ -0,0 +1,289 @@
+//! Window-independent part of KYCo: config, job state, control API and executor
+//!
+//! Shared by the GUI (`kyco gui`) and the headless server (`kyco serve`). Nothing
+//! in here touches egui; the caller decides who consumes the executor events.
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use super::super::executor::{ExecutorEvent, start_executor};
+use super::super::http_server::{
+    BatchRequest, BridgeStatus, ControlApiState, SelectionRequest, start_http_server,
+};
+use super::follow_up::JobFollowUps;
+use crate::LogEvent;
+use crate::config::{Config, FileFingerprint};
+use crate::job::{GroupManager, JobManager};
+
+/// Running control API + executor, plus the channels their events arrive on
+pub(super) struct Backend {
+    pub(super) work_dir: PathBuf,
+    pub(super) config: Arc<RwLock<Config>>,
+    /// Whether the config file existed (or was created) at startup
+    pub(super) config_exists: bool,
+    pub(super) job_manager: Arc<Mutex<JobManager>>,
+    pub(super) group_manager: Arc<Mutex<GroupManager>>,
+    pub(super) bridge_status: Arc<Mutex<BridgeStatus>>,
+    /// Single selections from IDE extensions (`POST /selection`)
+    pub(super) selection_rx: mpsc::Receiver<SelectionRequest>,
+    /// Batch requests from IDE extensions (`POST /batch`)
+    pub(super) batch_rx: mpsc::Receiver<BatchRequest>,
+    pub(super) executor_rx: mpsc::Receiver<ExecutorEvent>,
+    /// For consumers that report their own events alongside the executor's
+    pub(super) executor_tx: mpsc::Sender<ExecutorEvent>,
+    pub(super) max_concurrent_jobs: Arc<AtomicUsize>,
+}
+
+impl Backend {
+    /// Load config and job state for `work_dir`, then start the HTTP server, the
+    /// config watcher and the job executor in background threads.
+    ///
+    /// `port_override` replaces `settings.gui.http_port` for this run.
+    pub(super) fn start(
+        work_dir: PathBuf,
+        config_override: Option<PathBuf>,
+        port_override: Option<u16>,
+    ) -> Self {
+        let work_dir = resolve_work_dir(work_dir);
+
+        // Use global config by default (~/.kyco/config.toml), allow override with --config
+        let config_path = match config_override {
+            Some(p) if p.is_absolute() => p,
+            Some(p) => work_dir.join(p),
+            None => Config::global_config_path(),
+        };
+
+        // Load config (auto-creates global config if missing)
+        let config_was_present = config_path.exists();
+        let mut config = match Config::from_file(&config_path) {
+            Ok(cfg) => cfg,
+            Err(_) if !config_was_present => {
+                // Config doesn't exist - use Config::load() which auto-inits global config
+                Config::load().unwrap_or_else(|e| {
+                    warn!(
+                        "[kyco] Failed to initialize config: {}. Falling back to defaults.",
+                        e
+                    );
+                    Config::with_defaults()
+                })
+            }
+            Err(e) => {
+                warn!(
+                    "[kyco] Failed to parse config ({}): {}. Falling back to defaults.",
+                    config_path.display(),
+                    e
+                );
+                Config::with_defaults()
+            }
+        };
+
+        // Discover skills from filesystem (.claude/skills/, .codex/skills/, ~/.kyco/skills/)
+        config.discover_skills(Some(&work_dir));
+
+        // Global config is auto-created by Config::load(), so it always exists after loading
+        let config_exists = config_path.exists();
+        if !config_was_present && config_exists {
+            info!("[kyco] Created {}", config_path.display());
+        }
+
+        let config = Arc::new(RwLock::new(config));
+
+        let mut job_manager =
+            JobManager::load(&work_dir).unwrap_or_else(|_| JobManager::new(&work_dir));
+        if let Ok(cfg) = config.read() {
+            job_manager.set_compress_store(cfg.settings.compress_job_store);
+            job_manager.set_id_strategy(cfg.settings.job_id_strategy);
+        }
+        let job_manager = Arc::new(Mutex::new(job_manager));
+        let group_manager = Arc::new(Mutex::new(GroupManager::new()));
+
+        let (http_port, http_token) = config
+            .read()
+            .map(|cfg| {
+                (
+                    cfg.settings.gui.http_port,
+                    cfg.settings.gui.http_token.clone(),
+                )
+            })
+            .unwrap_or((9876, String::new()));
+        let http_port = port_override.unwrap_or(http_port);
+
+        info!("[kyco] Starting HTTP server on port {}...", http_port);
+
+        // Create channel for HTTP server -> GUI communication (single selection)
+        let (http_tx, selection_rx): (
+            mpsc::Sender<SelectionRequest>,
+            mpsc::Receiver<SelectionRequest>,
+        ) = mpsc::channel();
+
+        // Create channel for batch requests from IDE
+        let (batch_tx, batch_rx): (mpsc::Sender<BatchRequest>, mpsc::Receiver<BatchRequest>) =
+            mpsc::channel();
+
+        // Create channel for executor -> GUI communication
+        let (executor_tx, executor_rx): (
+            mpsc::Sender<ExecutorEvent>,
+            mpsc::Receiver<ExecutorEvent>,
+        ) = mpsc::channel();
+
+        let http_token = Some(http_token).filter(|t| !t.trim().is_empty());
+        let bridge_status = Arc::new(Mutex::new(BridgeStatus::new(
+            http_port,
+            http_token.is_some(),
+        )));
+
+        // Shared between the control API (`/ctl/queue/pause`) and the executor
+        let queue_paused = Arc::new(AtomicBool::new(false));
+
+        // Start HTTP server in background (handles both /selection and /batch)
+        start_http_server(
+            http_tx,
+            batch_tx,
+            http_port,
+            http_token,
+            ControlApiState {
+                work_dir: work_dir.clone(),
+                job_manager: Arc::clone(&job_manager),
+                group_manager: Arc::clone(&group_manager),
+                executor_tx: executor_tx.clone(),
+                config: Arc::clone(&config),
+                config_path: config_path.clone(),
+                queue_paused: Arc::clone(&queue_paused),
+            },
+            Arc::clone(&bridge_status),
+        );
+
+        // Create shared max_concurrent_jobs so GUI can update it at runtime
+        let max_concurrent_jobs = Arc::new(AtomicUsize::new(
+            config
+                .read()
+                .map(|cfg| cfg.settings.max_concurrent_jobs)
+                .unwrap_or(1),
+        ));
+
+        start_config_watch_thread(
+            config_path,
+            work_dir.clone(),
+            Arc::clone(&config),
+            Arc::clone(&max_concurrent_jobs),
+            executor_tx.clone(),
+        );
+
+        // Start job executor in background
+        start_executor(
+            work_dir.clone(),
+            Arc::clone(&config),
+            job_manager.clone(),
+            executor_tx.clone(),
+            Arc::clone(&max_concurrent_jobs),
+            queue_paused,
+        );
+
+        Self {
+            work_dir,
+            config,
+            config_exists,
+            job_manager,
+            group_manager,
+            bridge_status,
+            selection_rx,
+            batch_rx,
+            executor_rx,
+            executor_tx,
+            max_concurrent_jobs,
+        }
+    }
+
+    /// What runs after a job completes or fails (see `JobFollowUps`)
+    pub(super) fn follow_ups(&self) -> JobFollowUps<'_> {
+        JobFollowUps {
+            work_dir: &self.work_dir,
+            config: &self.config,
+            job_manager: &self.job_manager,
+            group_manager: &self.group_manager,
+            executor_tx: &self.executor_tx,
+        }
+    }
+}
+
+/// Absolute form of `work_dir` (empty means the current directory)
+fn resolve_work_dir(work_dir: PathBuf) -> PathBuf {
+    let work_dir = if work_dir.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        work_dir
+    };
+    match work_dir.canonicalize() {
+        Ok(abs) => abs,
+        Err(_) => {
+            if work_dir.is_absolute() {
+                work_dir
+            } else if let Ok(cwd) = std::env::current_dir() {
+                cwd.join(work_dir)
+            } else {
+                work_dir
+            }
+        }
+    }
+}
+
+fn start_config_watch_thread(
+    config_path: PathBuf,
+    work_dir: PathBuf,
+    config: Arc<RwLock<Config>>,
+    max_concurrent_jobs: Arc<AtomicUsize>,
+    event_tx: mpsc::Sender<ExecutorEvent>,
+) {
+    thread::spawn(move || {
+        // Modification time and size: an edit within the mtime granularity that
+        // changes the length is still noticed
+        let mut last_modified = FileFingerprint::of(&config_path);
+
+        loop {
+            thread::sleep(Duration::from_millis(500));
+
+            let modified = FileFingerprint::of(&config_path);
+            if modified.is_none() || modified == last_modified {
+                continue;
+            }
+
+            // Debounce slightly to avoid reading partially-written files.
+            thread::sleep(Duration::from_millis(50));
+
+            match Config::from_file_cached(&config_path) {
+                Ok(mut new_config) => {
+                    // Discover skills from filesystem on reload
+                    new_config.discover_skills(Some(&work_dir));
+
+                    max_concurrent_jobs
+                        .store(new_config.settings.max_concurrent_jobs, Ordering::Relaxed);
+
+                    if let Ok(mut guard) = config.write() {
+                        *guard = new_config;
+                    }
+
+                    let _ = event_tx.send(ExecutorEvent::Log(LogEvent::system(format!(
+                        "Reloaded config from {}",
+                        config_path.display()
+                    ))));
+                    last_modified = modified;
+                }
+                Err(e) => {
+                    let _ = event_tx.send(ExecutorEvent::Log(LogEvent::error(format!(
+                        "Failed to reload config ({}): {}",
+                        config_path.display(),
+                        e
+                    ))));
+                    // Update last_modified to prevent infinite retry loop.
+                    // User must save the file again after fixing the error.
+                    last_modified = modified;
+                }
+            }
+        }
+    });
+}
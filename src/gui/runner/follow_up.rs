@@ -0,0 +1,479 @@
+//! Follow-ups for finished jobs: notifications, group completion and auto-merge
+//!
+//! Shared by the GUI update loop and `kyco serve`, so a headless server notifies,
+//! completes groups and merges the same way the window does. Log lines are sent
+//! as executor events, which both loops already display.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
+use std::sync::{Mutex, RwLock};
+
+use super::super::app_popup::{ApplyThreadError, ApplyThreadInput, SingleApplyInput};
+use super::super::executor::ExecutorEvent;
+use super::super::notify;
+use crate::config::{Config, Settings};
+use crate::git::{DiffSettings, GitManager};
+use crate::job::{GroupManager, JobManager};
+use crate::{GroupStatus, Job, JobId, JobStatus, LogEvent};
+
+/// One auto-merge precondition and whether it held
+pub(crate) struct MergeGate {
+    name: &'static str,
+    ok: bool,
+    detail: String,
+}
+
+impl MergeGate {
+    fn new(name: &'static str, ok: bool, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            ok,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Whether every gate passed, plus a one-line summary for the log
+pub(crate) fn summarize_gates(gates: &[MergeGate]) -> (bool, String) {
+    let passed = gates.iter().all(|gate| gate.ok);
+    let summary = gates
+        .iter()
+        .map(|gate| {
+            let mark = if gate.ok { "✓" } else { "✗" };
+            format!("{} {}: {}", mark, gate.name, gate.detail)
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+    (passed, summary)
+}
+
+/// Gates that depend only on the job and its repository: the job completed
+/// successfully, it is a single worktree job, its diff is non-empty, and it
+/// merges into the base branch without conflicts.
+pub(crate) fn job_merge_gates(job: &Job, workspace_root: &Path) -> Vec<MergeGate> {
+    let mut gates = vec![
+        MergeGate::new(
+            "completed",
+            job.status == JobStatus::Done,
+            job.status.to_string(),
+        ),
+        MergeGate::new(
+            "single job",
+            job.group_id.is_none(),
+            if job.group_id.is_some() {
+                "part of a multi-agent group"
+            } else {
+                "yes"
+            },
+        ),
+    ];
+
+    match (
+        job.git_worktree_path.as_deref(),
+        GitManager::new(workspace_root),
+    ) {
+        (None, _) => gates.push(MergeGate::new("worktree", false, "job has no worktree")),
+        (Some(_), Err(e)) => gates.push(MergeGate::new(
+            "worktree",
+            false,
+            format!("not a git repository ({})", e),
+        )),
+        (Some(worktree_path), Ok(git)) => {
+            let report = git.diff_report(
+                worktree_path,
+                job.base_branch.as_deref(),
+                &DiffSettings {
+                    include_untracked: true,
+                    ..Default::default()
+                },
+            );
+            gates.push(match report {
+                Ok(report) => MergeGate::new(
+                    "diff",
+                    report.files_changed > 0,
+                    format!(
+                        "{} files, {} lines changed",
+                        report.files_changed,
+                        report.total_added + report.total_removed
+                    ),
+                ),
+                Err(e) => MergeGate::new("diff", false, format!("failed to compute diff ({})", e)),
+            });
+
+            let base_branch = job
+                .base_branch
+                .clone()
+                .map(Ok)
+                .unwrap_or_else(|| git.current_branch());
+            gates.push(
+                match base_branch.and_then(|base| git.merge_conflicts(worktree_path, &base)) {
+                    Ok(conflicts) if conflicts.is_empty() => {
+                        MergeGate::new("conflicts", true, "none")
+                    }
+                    Ok(conflicts) => MergeGate::new("conflicts", false, conflicts.join(", ")),
+                    Err(e) => MergeGate::new("conflicts", false, format!("check failed ({})", e)),
+                },
+            );
+        }
+    }
+    gates
+}
+
+/// The job state both loops share, borrowed from `Backend` or `KycoApp`
+pub(crate) struct JobFollowUps<'a> {
+    pub(crate) work_dir: &'a Path,
+    pub(crate) config: &'a RwLock<Config>,
+    pub(crate) job_manager: &'a Mutex<JobManager>,
+    pub(crate) group_manager: &'a Mutex<GroupManager>,
+    pub(crate) executor_tx: &'a Sender<ExecutorEvent>,
+}
+
+impl JobFollowUps<'_> {
+    /// Fire the configured `settings.notify` hooks for a finished job
+    pub(crate) fn notify_job_finished(&self, job_id: JobId) {
+        let notify = match self.config.read() {
+            Ok(config) => config.settings.notify.clone(),
+            Err(_) => return,
+        };
+        if !notify.is_enabled() {
+            return;
+        }
+        if let Some(job) = self.job(job_id) {
+            notify::notify_job_finished(&notify, &job, self.executor_tx);
+        }
+    }
+
+    /// Check if a job's completion means its group is ready for comparison.
+    ///
+    /// When the group's auto-select policy picks a winner, the other results are
+    /// rejected, as the manual merge flow does.
+    pub(crate) fn check_group_completion(&self, job_id: JobId) {
+        let (group_id, group_jobs) = {
+            let manager = match self.job_manager.lock() {
+                Ok(m) => m,
+                Err(_) => return,
+            };
+            let Some(group_id) = manager.get(job_id).and_then(|job| job.group_id) else {
+                return; // Job is not part of a group
+            };
+            let group_jobs: Vec<Job> = manager
+                .jobs()
+                .into_iter()
+                .filter(|job| job.group_id == Some(group_id))
+                .cloned()
+                .collect();
+            (group_id, group_jobs)
+        };
+        let jobs: Vec<&Job> = group_jobs.iter().collect();
+
+        let mut auto_selected: Option<(JobId, Vec<JobId>)> = None;
+        if let Ok(mut gm) = self.group_manager.lock() {
+            if let Some(winner) = gm.update_group_status(group_id, &jobs) {
+                let losers = gm
+                    .job_ids_in_group(group_id)
+                    .into_iter()
+                    .filter(|id| *id != winner)
+                    .collect();
+                auto_selected = Some((winner, losers));
+            } else if let Some(group) = gm.get(group_id) {
+                if group.status == GroupStatus::Comparing {
+                    self.log(LogEvent::system(format!(
+                        "Group #{} ready for comparison ({} agents)",
+                        group_id,
+                        group.job_ids.len()
+                    )));
+                }
+            }
+        }
+
+        if let Some((winner, losers)) = auto_selected {
+            self.log(LogEvent::system(format!(
+                "Group #{} auto-selected job #{}",
+                group_id, winner
+            )));
+            for loser in losers {
+                self.reject_job(loser);
+            }
+        }
+    }
+
+    /// Reject a job: remove its worktree (discarding the changes) and mark it rejected
+    pub(crate) fn reject_job(&self, job_id: JobId) {
+        let Some(job) = self.job(job_id) else {
+            self.log(LogEvent::error(format!("Job #{} not found", job_id)));
+            return;
+        };
+
+        if let Some(worktree) = job.git_worktree_path.as_deref() {
+            let workspace_root = self.workspace_root_for_job(&job);
+            if let Ok(git) = GitManager::new(&workspace_root) {
+                if let Err(e) = git.remove_worktree_by_path(worktree) {
+                    self.log(LogEvent::error(format!(
+                        "Failed to remove worktree for rejected job: {}",
+                        e
+                    )));
+                }
+            } else {
+                self.log(LogEvent::error(format!(
+                    "Failed to initialize git manager for {}",
+                    workspace_root.display()
+                )));
+            }
+        } else {
+            self.log(LogEvent::system(
+                "Rejected job without worktree (no changes were reverted)".to_string(),
+            ));
+        }
+
+        if let Ok(mut manager) = self.job_manager.lock() {
+            if let Some(j) = manager.get_mut(job_id) {
+                j.set_status(JobStatus::Rejected);
+                j.git_worktree_path = None;
+                j.branch_name = None;
+            }
+        }
+        self.log(LogEvent::system(format!("Rejected job #{}", job_id)));
+    }
+
+    /// Decide whether a completed job is merged without review.
+    ///
+    /// A mode/skill with `auto_merge` merges when every gate passes (see
+    /// [`job_merge_gates`]); otherwise `settings.auto_apply` merges changes within
+    /// its file/line limits. `merge_busy` (another merge is running) blocks both.
+    ///
+    /// Returns `Some(allow_diverged)` when the merge should start: the auto-merge
+    /// conflict gate already merged against the current base, so a base that moved
+    /// since the worktree was created (e.g. a parallel job merged first) is fine.
+    pub(crate) fn auto_merge_plan(&self, job_id: JobId, merge_busy: bool) -> Option<bool> {
+        let job = self.job(job_id)?;
+        let (auto_merge, settings) = {
+            let config = self.config.read().ok()?;
+            (config.auto_merge_for(&job.skill), config.settings.clone())
+        };
+
+        if auto_merge && self.auto_merge_gates_pass(&job, merge_busy) {
+            return Some(true);
+        }
+        if settings.auto_apply && self.auto_apply_allowed(&job, &settings, merge_busy) {
+            return Some(false);
+        }
+        None
+    }
+
+    /// Snapshot what merging a single job needs, for `run_apply_thread` on a worker
+    /// thread; call [`Self::mark_merged`] once it succeeded.
+    pub(crate) fn merge_input(&self, job_id: JobId) -> Result<ApplyThreadInput, ApplyThreadError> {
+        let job = self
+            .job(job_id)
+            .ok_or_else(|| format!("Job #{} not found", job_id))?;
+        let merge_as_commit = self
+            .config
+            .read()
+            .map(|config| config.settings.merge_as_commit)
+            .unwrap_or(false);
+
+        let input = SingleApplyInput::new(&job, self.workspace_root_for_job(&job), merge_as_commit);
+        Ok(ApplyThreadInput::Single(input))
+    }
+
+    /// Record a successful single-job merge and notify about it
+    pub(crate) fn mark_merged(&self, job_id: JobId) {
+        if let Ok(mut manager) = self.job_manager.lock() {
+            if let Some(job) = manager.get_mut(job_id) {
+                job.set_status(JobStatus::Merged);
+                job.git_worktree_path = None;
+                job.branch_name = None;
+            }
+        }
+        self.notify_job_finished(job_id);
+    }
+
+    fn auto_merge_gates_pass(&self, job: &Job, merge_busy: bool) -> bool {
+        let mut gates = job_merge_gates(job, &self.workspace_root_for_job(job));
+        gates.push(MergeGate::new(
+            "no merge in progress",
+            !merge_busy,
+            if merge_busy {
+                "another merge is running"
+            } else {
+                "idle"
+            },
+        ));
+
+        let (passed, summary) = summarize_gates(&gates);
+        let message = if passed {
+            format!("Auto-merging job #{} [{}]", job.id, summary)
+        } else {
+            format!(
+                "Auto-merge skipped for job #{} - waiting for manual review [{}]",
+                job.id, summary
+            )
+        };
+        self.log(LogEvent::system(message).for_job(job.id));
+        passed
+    }
+
+    /// `settings.auto_apply` only takes single (non-group) worktree jobs whose
+    /// changes stay within the configured file/line thresholds.
+    fn auto_apply_allowed(&self, job: &Job, settings: &Settings, merge_busy: bool) -> bool {
+        if job.status != JobStatus::Done || job.group_id.is_some() {
+            return false;
+        }
+        let Some(worktree_path) = job.git_worktree_path.as_deref() else {
+            return false;
+        };
+
+        let report = GitManager::new(&self.workspace_root_for_job(job)).and_then(|git| {
+            git.diff_report(
+                worktree_path,
+                job.base_branch.as_deref(),
+                &DiffSettings {
+                    include_untracked: true,
+                    ..Default::default()
+                },
+            )
+        });
+        let report = match report {
+            Ok(report) => report,
+            Err(e) => {
+                self.log(
+                    LogEvent::system(format!(
+                        "Auto-apply skipped for job #{}: failed to compute diff ({})",
+                        job.id, e
+                    ))
+                    .for_job(job.id),
+                );
+                return false;
+            }
+        };
+
+        if report.files_changed == 0 {
+            return false;
+        }
+
+        let lines_changed = report.total_added + report.total_removed;
+        let skipped = if let Some(reason) =
+            settings.auto_apply_blocked_reason(report.files_changed, lines_changed)
+        {
+            Some(format!("{} - waiting for manual review", reason))
+        } else if merge_busy {
+            Some("another merge is in progress".to_string())
+        } else {
+            None
+        };
+        if let Some(reason) = skipped {
+            self.log(
+                LogEvent::system(format!(
+                    "Auto-apply skipped for job #{}: {}",
+                    job.id, reason
+                ))
+                .for_job(job.id),
+            );
+            return false;
+        }
+
+        self.log(
+            LogEvent::system(format!(
+                "Auto-applying job #{} ({} files, {} lines changed)",
+                job.id, report.files_changed, lines_changed
+            ))
+            .for_job(job.id),
+        );
+        true
+    }
+
+    fn job(&self, job_id: JobId) -> Option<Job> {
+        match self.job_manager.lock() {
+            Ok(manager) => manager.get(job_id).cloned(),
+            Err(_) => None,
+        }
+    }
+
+    fn workspace_root_for_job(&self, job: &Job) -> PathBuf {
+        job.workspace_path
+            .clone()
+            .unwrap_or_else(|| self.work_dir.to_path_buf())
+    }
+
+    fn log(&self, event: LogEvent) {
+        let _ = self.executor_tx.send(ExecutorEvent::Log(event));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ScopeDefinition;
+    use crate::git::CommitMessage;
+    use crate::gui::app_popup::{ApplyThreadError, merge_worktree};
+    use std::process::Command;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .expect("run git");
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    fn worktree_job(gm: &GitManager, id: JobId, file: &str) -> Job {
+        let info = gm.create_worktree(id, None).expect("create worktree");
+        std::fs::write(info.path.join(file), "change\n").expect("write change");
+        git(&info.path, &["add", file]);
+        git(&info.path, &["commit", "-m", file]);
+
+        let mut job = Job::new(
+            id,
+            "refactor".to_string(),
+            ScopeDefinition::file(file.into()),
+            file.to_string(),
+            None,
+            "claude".to_string(),
+            file.into(),
+            1,
+            None,
+        );
+        job.status = JobStatus::Done;
+        job.git_worktree_path = Some(info.path);
+        job.base_branch = Some(info.base_branch);
+        job.branch_name = Some(info.branch_name);
+        job
+    }
+
+    #[test]
+    fn parallel_jobs_both_auto_merge() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let repo = tmp.path();
+        git(repo, &["init", "-b", "main"]);
+        git(repo, &["config", "user.email", "test@example.com"]);
+        git(repo, &["config", "user.name", "Test User"]);
+        std::fs::write(repo.join("README.md"), "hello\n").expect("write README");
+        git(repo, &["add", "README.md"]);
+        git(repo, &["commit", "-m", "init"]);
+
+        let gm = GitManager::new(repo).expect("git manager");
+        let jobs = [worktree_job(&gm, 1, "a.txt"), worktree_job(&gm, 2, "b.txt")];
+
+        // Same sequence as `auto_merge_plan` + the apply thread, one job after the other
+        for job in &jobs {
+            let (passed, summary) = summarize_gates(&job_merge_gates(job, repo));
+            assert!(passed, "job #{}: {}", job.id, summary);
+
+            let merged = merge_worktree(
+                &gm,
+                job.git_worktree_path.as_deref().unwrap(),
+                job.base_branch.as_deref().unwrap(),
+                &CommitMessage::from_job(job),
+                false,
+                true,
+            );
+            if let Err(ApplyThreadError { message, .. }) = merged {
+                panic!("job #{} did not merge: {}", job.id, message);
+            }
+        }
+
+        assert!(repo.join("a.txt").exists());
+        assert!(repo.join("b.txt").exists());
+    }
+}
@@ -0,0 +1,476 @@
+//! Headless server (`kyco serve`): the control API and job executor without a window
+//!
+//! Starts the same `Backend` as the GUI and replaces the egui update loop with a
+//! plain event loop; finished jobs get the same follow-ups (notifications, group
+//! completion, auto-merge) through `JobFollowUps`. Decisions the GUI leaves to the
+//! user come from the config instead: `settings.auto_run` queues pending jobs, and
+//! `settings.auto_allow` approves tool calls (they are denied otherwise, since
+//! nobody is there to answer). `Backend` has no file scanner, so jobs only arrive
+//! through the control API.
+
+use anyhow::{Result, bail};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+use super::super::app_popup::{ApplyThreadError, ApplyThreadOutcome, run_apply_thread};
+use super::super::app_update::stats::record_tool_call;
+use super::super::executor::ExecutorEvent;
+use super::backend::Backend;
+use crate::agent::bridge::{BridgeClient, ToolApprovalResponse, ToolDecision};
+use crate::config::Config;
+use crate::job::JobManager;
+use crate::stats::StatsManager;
+use crate::{JobId, JobStatus, LogEvent, LogEventKind};
+
+/// How long to wait for the HTTP server to bind before giving up
+const BIND_TIMEOUT: Duration = Duration::from_secs(5);
+/// Event loop tick; pending tool approvals are polled at most this often
+const TICK: Duration = Duration::from_millis(500);
+
+/// Run the control API and job executor until Ctrl-C.
+///
+/// `port` overrides `settings.gui.http_port`; CLI clients still read the port from
+/// the config, so an override is mainly for orchestrators that talk HTTP directly.
+pub fn run_serve(
+    work_dir: PathBuf,
+    config_override: Option<PathBuf>,
+    port: Option<u16>,
+) -> Result<()> {
+    let backend = Backend::start(work_dir, config_override, port);
+    let url = wait_for_listener(&backend)?;
+    info!(
+        "[kyco:serve] Serving {} on {} (Ctrl-C to stop)",
+        backend.work_dir.display(),
+        url
+    );
+
+    let shutdown = Arc::new(AtomicBool::new(false));
+    watch_ctrl_c(Arc::clone(&shutdown));
+
+    let (merge_tx, merge_rx) = mpsc::channel();
+    let mut server = HeadlessServer {
+        backend,
+        merge_in_progress: None,
+        merge_tx,
+        merge_rx,
+        stats_manager: StatsManager::new().ok(),
+        bridge_client: BridgeClient::new(),
+        approvals: AnsweredApprovals::default(),
+        last_approval_poll: Instant::now(),
+    };
+    server.run(&shutdown);
+
+    let running = server
+        .backend
+        .job_manager
+        .lock()
+        .map(|mut manager| {
+            manager.flush();
+            manager.running_jobs().len()
+        })
+        .unwrap_or(0);
+    if running > 0 {
+        warn!(
+            "[kyco:serve] Stopping with {} running job(s); they will show as interrupted",
+            running
+        );
+    }
+    info!("[kyco:serve] Stopped");
+    Ok(())
+}
+
+/// Block until the HTTP server is listening; fail if it could not bind
+fn wait_for_listener(backend: &Backend) -> Result<String> {
+    let deadline = Instant::now() + BIND_TIMEOUT;
+    loop {
+        if let Ok(status) = backend.bridge_status.lock() {
+            if status.listening {
+                return Ok(status.url());
+            }
+            if let Some(ref error) = status.error {
+                bail!(
+                    "Failed to start HTTP server on port {}: {}",
+                    status.port,
+                    error
+                );
+            }
+        }
+        if Instant::now() >= deadline {
+            bail!("HTTP server did not start within {:?}", BIND_TIMEOUT);
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+}
+
+fn watch_ctrl_c(shutdown: Arc<AtomicBool>) {
+    thread::spawn(move || {
+        let Ok(rt) = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+        else {
+            return;
+        };
+        if rt.block_on(tokio::signal::ctrl_c()).is_ok() {
+            shutdown.store(true, Ordering::Relaxed);
+        }
+    });
+}
+
+/// A finished background merge: the job and what `run_apply_thread` returned
+type MergeResult = (JobId, Result<ApplyThreadOutcome, ApplyThreadError>);
+
+struct HeadlessServer {
+    backend: Backend,
+    /// Job being merged on a worker thread; further auto-merges wait for it
+    merge_in_progress: Option<JobId>,
+    merge_tx: Sender<MergeResult>,
+    merge_rx: Receiver<MergeResult>,
+    stats_manager: Option<StatsManager>,
+    bridge_client: BridgeClient,
+    approvals: AnsweredApprovals,
+    last_approval_poll: Instant,
+}
+
+impl HeadlessServer {
+    fn run(&mut self, shutdown: &AtomicBool) {
+        while !shutdown.load(Ordering::Relaxed) {
+            match self.backend.executor_rx.recv_timeout(TICK) {
+                Ok(event) => self.handle_executor_event(event),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+            self.handle_merge_results();
+            self.drain_ide_requests();
+            self.auto_queue_pending_jobs();
+            self.poll_pending_tool_approvals();
+        }
+    }
+
+    fn handle_executor_event(&mut self, event: ExecutorEvent) {
+        match event {
+            ExecutorEvent::JobStarted(job_id) => {
+                info!("[kyco:serve] Job #{} started", job_id);
+            }
+            ExecutorEvent::JobCompleted(job_id) => {
+                info!("[kyco:serve] Job #{} completed", job_id);
+                let follow_ups = self.backend.follow_ups();
+                follow_ups.notify_job_finished(job_id);
+                follow_ups.check_group_completion(job_id);
+                self.maybe_auto_merge(job_id);
+            }
+            ExecutorEvent::JobFailed(job_id, error) => {
+                warn!("[kyco:serve] Job #{} failed: {}", job_id, error);
+                let follow_ups = self.backend.follow_ups();
+                follow_ups.notify_job_finished(job_id);
+                follow_ups.check_group_completion(job_id);
+            }
+            // The executor already wrote the row; achievements are a GUI feature
+            ExecutorEvent::JobStatsRecorded(_) => {}
+            ExecutorEvent::ChainStepCompleted {
+                job_id,
+                step_index,
+                total_steps,
+                mode,
+                state,
+                step_summary,
+            } => {
+                info!(
+                    "[kyco:serve] Job #{} chain step {}/{} completed: {} (state: {})",
+                    job_id,
+                    step_index + 1,
+                    total_steps,
+                    mode,
+                    state.as_deref().unwrap_or("none")
+                );
+                // Chain progress lives on the job so `/ctl/jobs` reports it
+                if let Ok(mut manager) = self.backend.job_manager.lock() {
+                    if let Some(job) = manager.get_mut(job_id) {
+                        job.chain_current_step = Some(step_index + 1);
+                        if job.chain_step_history.len() <= step_index {
+                            job.chain_step_history.push(step_summary);
+                        }
+                    }
+                }
+            }
+            ExecutorEvent::ChainCompleted {
+                job_id,
+                chain_name,
+                steps_executed,
+                success,
+            } => {
+                if success {
+                    info!(
+                        "[kyco:serve] Job #{} chain '{}' completed: {} steps executed",
+                        job_id, chain_name, steps_executed
+                    );
+                } else {
+                    warn!(
+                        "[kyco:serve] Job #{} chain '{}' failed after {} steps",
+                        job_id, chain_name, steps_executed
+                    );
+                }
+            }
+            ExecutorEvent::Log(event) => {
+                if event.kind == LogEventKind::ToolCall {
+                    if let Some(stats_manager) = &self.stats_manager {
+                        record_tool_call(stats_manager, &self.backend.job_manager, &event);
+                    }
+                }
+                log_event(&event);
+            }
+            ExecutorEvent::PermissionNeeded {
+                job_id,
+                request_id,
+                tool_name,
+                ..
+            } => {
+                self.answer_tool_approval(request_id, &tool_name, Some(job_id));
+            }
+        }
+    }
+
+    /// Merge a completed job on a worker thread when it qualifies; one merge at a time
+    fn maybe_auto_merge(&mut self, job_id: JobId) {
+        let follow_ups = self.backend.follow_ups();
+        let busy = self.merge_in_progress.is_some();
+        let Some(allow_diverged) = follow_ups.auto_merge_plan(job_id, busy) else {
+            return;
+        };
+        let input = match follow_ups.merge_input(job_id) {
+            Ok(input) => input,
+            Err(e) => {
+                warn!(
+                    "[kyco:serve] Auto-merge failed for job #{}: {}",
+                    job_id, e.message
+                );
+                return;
+            }
+        };
+
+        self.merge_in_progress = Some(job_id);
+        let tx = self.merge_tx.clone();
+        thread::spawn(move || {
+            let _ = tx.send((job_id, run_apply_thread(input, allow_diverged)));
+        });
+    }
+
+    /// Finish a merge once its worker thread reports back
+    fn handle_merge_results(&mut self) {
+        while let Ok((job_id, result)) = self.merge_rx.try_recv() {
+            self.merge_in_progress = None;
+            match result {
+                Ok(outcome) => {
+                    self.backend.follow_ups().mark_merged(job_id);
+                    info!("[kyco:serve] {}", outcome.message);
+                }
+                Err(e) => warn!(
+                    "[kyco:serve] Auto-merge failed for job #{}: {}",
+                    job_id, e.message
+                ),
+            }
+        }
+    }
+
+    /// Selections and batches need the GUI's mode picker; point callers at the ctl API
+    fn drain_ide_requests(&mut self) {
+        while let Ok(req) = self.backend.selection_rx.try_recv() {
+            warn!(
+                "[kyco:serve] Ignoring IDE selection ({}): needs the GUI; use `kyco job start`",
+                req.file_path.as_deref().unwrap_or("no file")
+            );
+        }
+        while let Ok(req) = self.backend.batch_rx.try_recv() {
+            warn!(
+                "[kyco:serve] Ignoring IDE batch ({} files): needs the GUI; use `kyco job start`",
+                req.files.len()
+            );
+        }
+    }
+
+    fn auto_queue_pending_jobs(&self) {
+        for job_id in auto_queue_pending_jobs(&self.backend.config, &self.backend.job_manager) {
+            info!("[kyco:serve] Auto-queued job #{}", job_id);
+        }
+    }
+
+    /// Fallback for approval requests whose stream event was missed
+    fn poll_pending_tool_approvals(&mut self) {
+        if self.last_approval_poll.elapsed() < TICK {
+            return;
+        }
+        self.last_approval_poll = Instant::now();
+
+        let Ok(pending) = self.bridge_client.get_pending_tool_approvals() else {
+            return;
+        };
+        self.approvals
+            .retain_pending(pending.iter().map(|approval| approval.request_id.as_str()));
+        for approval in pending {
+            self.answer_tool_approval(approval.request_id, &approval.tool_name, None);
+        }
+    }
+
+    fn answer_tool_approval(&mut self, request_id: String, tool_name: &str, job_id: Option<u64>) {
+        let allow = self
+            .backend
+            .config
+            .read()
+            .map(|cfg| cfg.settings.auto_allow)
+            .unwrap_or(false);
+        let Some(response) = self.approvals.respond(request_id, allow) else {
+            return;
+        };
+
+        let job = job_id
+            .map(|id| format!(" (job #{})", id))
+            .unwrap_or_default();
+        match self.bridge_client.send_tool_approval(&response) {
+            Ok(true) if allow => info!("[kyco:serve] Auto-allowed tool: {}{}", tool_name, job),
+            Ok(true) => warn!(
+                "[kyco:serve] Denied tool {}{}: settings.auto_allow is off",
+                tool_name, job
+            ),
+            Ok(false) => warn!(
+                "[kyco:serve] Tool approval for {}{} rejected by bridge",
+                tool_name, job
+            ),
+            Err(e) => warn!(
+                "[kyco:serve] Failed to answer tool approval for {}{}: {}",
+                tool_name, job, e
+            ),
+        }
+    }
+}
+
+/// Same as the GUI's auto-run toggle, driven by `settings.auto_run`; returns the queued jobs
+fn auto_queue_pending_jobs(config: &RwLock<Config>, job_manager: &Mutex<JobManager>) -> Vec<JobId> {
+    let auto_run = config
+        .read()
+        .map(|cfg| cfg.settings.auto_run)
+        .unwrap_or(false);
+    if !auto_run {
+        return Vec::new();
+    }
+    let Ok(mut manager) = job_manager.lock() else {
+        return Vec::new();
+    };
+    let pending: Vec<JobId> = manager.pending_jobs().iter().map(|j| j.id).collect();
+    for &job_id in &pending {
+        manager.set_status(job_id, JobStatus::Queued);
+    }
+    pending
+}
+
+/// Tool approval requests already answered (stream event and poll may both report one)
+#[derive(Default)]
+struct AnsweredApprovals {
+    request_ids: HashSet<String>,
+}
+
+impl AnsweredApprovals {
+    /// The response to send for a request, or None if it was already answered
+    fn respond(&mut self, request_id: String, allow: bool) -> Option<ToolApprovalResponse> {
+        if !self.request_ids.insert(request_id.clone()) {
+            return None;
+        }
+        Some(ToolApprovalResponse {
+            request_id,
+            decision: if allow {
+                ToolDecision::Allow
+            } else {
+                ToolDecision::Deny
+            },
+            reason: (!allow).then(|| {
+                "kyco serve runs headless: enable settings.auto_allow or use a \
+                 permission mode that needs no approval"
+                    .to_string()
+            }),
+            modified_input: None,
+        })
+    }
+
+    /// Forget answered requests the bridge no longer lists as pending
+    fn retain_pending<'a>(&mut self, pending: impl Iterator<Item = &'a str>) {
+        let pending: HashSet<&str> = pending.collect();
+        self.request_ids.retain(|id| pending.contains(id.as_str()));
+    }
+}
+
+/// Mirror executor log events to the terminal; agent chatter only at debug level
+fn log_event(event: &LogEvent) {
+    let job = event
+        .job_id
+        .map(|id| format!("#{} ", id))
+        .unwrap_or_default();
+    match event.kind {
+        LogEventKind::Error => warn!("[kyco:serve] {}{}", job, event.summary),
+        LogEventKind::System | LogEventKind::Permission => {
+            info!("[kyco:serve] {}{}", job, event.summary)
+        }
+        _ => debug!("[kyco:serve] {}{}", job, event.summary),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support::comment_tag;
+
+    #[test]
+    fn approvals_are_answered_once_until_the_bridge_drops_them() {
+        let mut approvals = AnsweredApprovals::default();
+        assert!(approvals.respond("req-1".to_string(), true).is_some());
+        assert!(approvals.respond("req-1".to_string(), true).is_none());
+
+        assert!(approvals.respond("req-2".to_string(), true).is_some());
+        approvals.retain_pending(["req-2"].into_iter());
+        assert_eq!(approvals.request_ids, HashSet::from(["req-2".to_string()]));
+        assert!(approvals.respond("req-2".to_string(), true).is_none());
+    }
+
+    #[test]
+    fn approvals_follow_auto_allow() {
+        let mut approvals = AnsweredApprovals::default();
+
+        let allowed = approvals.respond("req-1".to_string(), true).unwrap();
+        assert_eq!(allowed.request_id, "req-1");
+        assert!(matches!(allowed.decision, ToolDecision::Allow));
+        assert!(allowed.reason.is_none());
+
+        let denied = approvals.respond("req-2".to_string(), false).unwrap();
+        assert!(matches!(denied.decision, ToolDecision::Deny));
+        assert!(denied.reason.unwrap().contains("settings.auto_allow"));
+    }
+
+    #[test]
+    fn auto_queue_only_runs_with_auto_run() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let mut manager = JobManager::new(tmp.path());
+        let tag = comment_tag(tmp.path().join("main.rs"));
+        let pending = manager.create_job(&tag, "claude").expect("create job");
+        let done = manager.create_job(&tag, "claude").expect("create job");
+        manager.set_status(done, JobStatus::Done);
+        let job_manager = Mutex::new(manager);
+        let status = |job_id| job_manager.lock().unwrap().get(job_id).unwrap().status;
+
+        let mut config = Config::with_defaults();
+        config.settings.auto_run = false;
+        let config = RwLock::new(config);
+        assert!(auto_queue_pending_jobs(&config, &job_manager).is_empty());
+        assert_eq!(status(pending), JobStatus::Pending);
+
+        config.write().unwrap().settings.auto_run = true;
+        assert_eq!(
+            auto_queue_pending_jobs(&config, &job_manager),
+            vec![pending]
+        );
+        assert_eq!(status(pending), JobStatus::Queued);
+        assert_eq!(status(done), JobStatus::Done);
+    }
+}
@@ -0,0 +1,144 @@
+//! Fuzzy (subsequence) scoring for autocomplete candidates
+
+/// Base score for candidates that start with the query
+const PREFIX_SCORE: i64 = 3000;
+/// Base score for candidates that contain the query contiguously
+const SUBSTRING_SCORE: i64 = 2000;
+/// Base score for candidates that only contain the query as a subsequence
+const SUBSEQUENCE_SCORE: i64 = 1000;
+
+/// Points per matched character in a subsequence match
+const MATCH_POINTS: i64 = 10;
+/// Extra points when a matched character starts a word
+const BOUNDARY_BONUS: i64 = 15;
+/// Extra points when a matched character directly follows the previous match
+const CONSECUTIVE_BONUS: i64 = 10;
+
+/// Score how well `candidate` matches `query`, fzf-style.
+///
+/// Both strings are expected to be lowercase already. Returns `None` when the
+/// query characters do not appear in order. Higher is better: prefix matches
+/// always rank above substring matches, which always rank above scattered
+/// subsequence matches. Within a tier, shorter and tighter matches win.
+pub fn fuzzy_score(candidate: &str, query: &str) -> Option<u32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let slack = (candidate.len() as i64 - query.len() as i64).clamp(0, 999);
+
+    if candidate.starts_with(query) {
+        return Some((PREFIX_SCORE - slack) as u32);
+    }
+
+    if let Some(pos) = candidate.find(query) {
+        let boundary_bonus = if is_boundary(candidate, pos) { 200 } else { 0 };
+        let penalty = (pos as i64 + slack).min(799);
+        return Some((SUBSTRING_SCORE + boundary_bonus - penalty) as u32);
+    }
+
+    let score = best_alignment(candidate, query)?;
+
+    Some((SUBSEQUENCE_SCORE + score - slack).clamp(1, SUBSTRING_SCORE - 1) as u32)
+}
+
+/// Best subsequence alignment score of `query` within `candidate`.
+///
+/// Each matched character earns points, more when it starts a word or directly
+/// follows the previous match; skipped characters in between cost points.
+fn best_alignment(candidate: &str, query: &str) -> Option<i64> {
+    let chars: Vec<(usize, char)> = candidate.char_indices().collect();
+    let query: Vec<char> = query.chars().collect();
+
+    // prev[i]: best score with the previous query char matched at position i
+    let mut prev: Vec<Option<i64>> = vec![None; chars.len()];
+    for (qi, &wanted) in query.iter().enumerate() {
+        let mut current: Vec<Option<i64>> = vec![None; chars.len()];
+        for (i, &(byte_pos, ch)) in chars.iter().enumerate() {
+            if ch != wanted {
+                continue;
+            }
+            let boundary = if is_boundary(candidate, byte_pos) {
+                BOUNDARY_BONUS
+            } else {
+                0
+            };
+            let base = MATCH_POINTS + boundary;
+            current[i] = if qi == 0 {
+                Some(base - i as i64)
+            } else {
+                (0..i)
+                    .filter_map(|j| {
+                        let gap = (i - j - 1) as i64;
+                        let step = if gap == 0 { CONSECUTIVE_BONUS } else { -gap };
+                        prev[j].map(|score| score + base + step)
+                    })
+                    .max()
+            };
+        }
+        prev = current;
+    }
+
+    prev.into_iter().flatten().max()
+}
+
+/// Whether the character at `byte_pos` starts a word (`smart-refactor`, `code_review`, ...)
+fn is_boundary(candidate: &str, byte_pos: usize) -> bool {
+    candidate[..byte_pos]
+        .chars()
+        .next_back()
+        .is_none_or(|prev| matches!(prev, '-' | '_' | ' ' | ':' | '/' | '.'))
+}
+
+/// Best score for a name or any of its aliases
+pub fn best_score<'a>(
+    name: &str,
+    aliases: impl IntoIterator<Item = &'a String>,
+    query: &str,
+) -> Option<u32> {
+    std::iter::once(fuzzy_score(&name.to_lowercase(), query))
+        .chain(
+            aliases
+                .into_iter()
+                .map(|alias| fuzzy_score(&alias.to_lowercase(), query)),
+        )
+        .flatten()
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subsequence_matches_and_misses() {
+        assert!(fuzzy_score("smart-refactor", "smrf").is_some());
+        assert!(fuzzy_score("smart-refactor", "refac").is_some());
+        assert!(fuzzy_score("smart-refactor", "fmrs").is_none());
+        assert!(fuzzy_score("review", "reviews").is_none());
+    }
+
+    #[test]
+    fn ranks_prefix_then_substring_then_subsequence() {
+        let query = "ref";
+        let mut candidates = vec!["smart-refactor", "prefetch", "review-fix", "refactor"];
+        candidates.retain(|c| fuzzy_score(c, query).is_some());
+        candidates.sort_by_key(|c| std::cmp::Reverse(fuzzy_score(c, query)));
+
+        assert_eq!(
+            candidates,
+            vec!["refactor", "smart-refactor", "prefetch", "review-fix"]
+        );
+    }
+
+    #[test]
+    fn shorter_prefix_match_wins() {
+        assert!(fuzzy_score("fix", "fi") > fuzzy_score("fix-tests", "fi"));
+    }
+
+    #[test]
+    fn word_boundaries_beat_scattered_matches() {
+        // "sr" hits both word starts in "smart-refactor" but is scattered in "sparser"
+        assert!(fuzzy_score("smart-refactor", "sr") > fuzzy_score("sparser", "sr"));
+    }
+}
@@ -1,5 +1,6 @@
 //! Autocomplete suggestions for modes and agents
 
+mod fuzzy;
 mod parsing;
 
 pub use parsing::{count_agents, is_multi_agent_input, parse_input, parse_input_multi};
@@ -127,111 +128,125 @@ impl AutocompleteState {
             let agent_part = &input_trimmed[..colon_pos];
             let skill_part = &input_trimmed[colon_pos + 1..];
 
+            let mut ranked = Vec::new();
+
             // Skills from filesystem (no legacy modes)
             for (skill_name, skill_config) in &config.skill {
-                let skill_lower = skill_name.to_lowercase();
-                let matches_skill = skill_lower.starts_with(skill_part) || skill_part.is_empty();
-                let matches_alias = skill_config
-                    .kyco
-                    .aliases
-                    .iter()
-                    .any(|a| a.to_lowercase().starts_with(skill_part));
-
-                if matches_skill || matches_alias {
-                    let aliases = if skill_config.kyco.aliases.is_empty() {
-                        String::new()
-                    } else {
-                        format!(" ({})", skill_config.kyco.aliases.join(", "))
-                    };
-                    self.suggestions.push(Suggestion {
+                let Some(score) =
+                    fuzzy::best_score(skill_name, &skill_config.kyco.aliases, skill_part)
+                else {
+                    continue;
+                };
+                let aliases = if skill_config.kyco.aliases.is_empty() {
+                    String::new()
+                } else {
+                    format!(" ({})", skill_config.kyco.aliases.join(", "))
+                };
+                ranked.push((
+                    score,
+                    Suggestion {
                         text: format!("{}:{}", agent_part, skill_name),
                         description: aliases,
                         category: "skill",
-                    });
-                }
+                    },
+                ));
             }
 
             for (chain_name, chain_config) in &config.chain {
-                let chain_lower = chain_name.to_lowercase();
-                let matches_chain = chain_lower.starts_with(skill_part) || skill_part.is_empty();
-
-                if matches_chain {
-                    let desc = chain_config
-                        .description
-                        .clone()
-                        .unwrap_or_else(|| format!("{} steps", chain_config.steps.len()));
-                    self.suggestions.push(Suggestion {
+                let Some(score) = fuzzy::best_score(chain_name, [], skill_part) else {
+                    continue;
+                };
+                let desc = chain_config
+                    .description
+                    .clone()
+                    .unwrap_or_else(|| format!("{} steps", chain_config.steps.len()));
+                ranked.push((
+                    score,
+                    Suggestion {
                         text: format!("{}:{}", agent_part, chain_name),
                         description: format!("[chain] {}", desc),
                         category: "chain",
-                    });
-                }
+                    },
+                ));
             }
+
+            self.push_ranked(ranked);
         } else {
-            // No colon yet - show matching agents and skills
-            for (agent_name, agent_config) in &config.agent {
-                let name_lower = agent_name.to_lowercase();
-                let matches_name = name_lower.starts_with(input_trimmed);
-                let matches_alias = agent_config
-                    .aliases
-                    .iter()
-                    .any(|a| a.to_lowercase().starts_with(input_trimmed));
+            // No colon yet - show matching agents, skills, and chains ranked by match quality
+            let mut ranked = Vec::new();
 
-                if matches_name || matches_alias {
-                    let backend = agent_config.sdk.default_name();
-                    let desc = format!("{} ({})", backend, agent_config.aliases.join(", "));
-                    self.suggestions.push(Suggestion {
+            for (agent_name, agent_config) in &config.agent {
+                let Some(score) =
+                    fuzzy::best_score(agent_name, &agent_config.aliases, input_trimmed)
+                else {
+                    continue;
+                };
+                let backend = agent_config.sdk.default_name();
+                let desc = format!("{} ({})", backend, agent_config.aliases.join(", "));
+                ranked.push((
+                    score,
+                    Suggestion {
                         text: format!("{}:", agent_name),
                         description: desc,
                         category: "agent",
-                    });
-                }
+                    },
+                ));
             }
 
             // Show matching skills (from filesystem only - no legacy modes)
             for (skill_name, skill_config) in &config.skill {
-                let skill_lower = skill_name.to_lowercase();
-                let matches_skill = skill_lower.starts_with(input_trimmed);
-                let matches_alias = skill_config
-                    .kyco
-                    .aliases
-                    .iter()
-                    .any(|a| a.to_lowercase().starts_with(input_trimmed));
-
-                if matches_skill || matches_alias {
-                    let aliases = if skill_config.kyco.aliases.is_empty() {
-                        String::new()
-                    } else {
-                        format!(" ({})", skill_config.kyco.aliases.join(", "))
-                    };
-                    let agent_hint = skill_config.kyco.agent.as_deref().unwrap_or(default_agent);
-                    self.suggestions.push(Suggestion {
+                let Some(score) =
+                    fuzzy::best_score(skill_name, &skill_config.kyco.aliases, input_trimmed)
+                else {
+                    continue;
+                };
+                let aliases = if skill_config.kyco.aliases.is_empty() {
+                    String::new()
+                } else {
+                    format!(" ({})", skill_config.kyco.aliases.join(", "))
+                };
+                let agent_hint = skill_config.kyco.agent.as_deref().unwrap_or(default_agent);
+                ranked.push((
+                    score,
+                    Suggestion {
                         text: skill_name.to_string(),
                         description: format!("default: {}{}", agent_hint, aliases),
                         category: "skill",
-                    });
-                }
+                    },
+                ));
             }
 
             for (chain_name, chain_config) in &config.chain {
-                let chain_lower = chain_name.to_lowercase();
-                if chain_lower.starts_with(input_trimmed) || chain_lower.contains(input_trimmed) {
-                    let desc = chain_config
-                        .description
-                        .clone()
-                        .unwrap_or_else(|| format!("{} steps", chain_config.steps.len()));
-                    self.suggestions.push(Suggestion {
+                let Some(score) = fuzzy::best_score(chain_name, [], input_trimmed) else {
+                    continue;
+                };
+                let desc = chain_config
+                    .description
+                    .clone()
+                    .unwrap_or_else(|| format!("{} steps", chain_config.steps.len()));
+                ranked.push((
+                    score,
+                    Suggestion {
                         text: chain_name.to_string(),
                         description: format!("[chain] {}", desc),
                         category: "chain",
-                    });
-                }
+                    },
+                ));
             }
+
+            self.push_ranked(ranked);
         }
 
         self.show_suggestions = !self.suggestions.is_empty();
     }
 
+    /// Append suggestions best match first; ties are ordered by text for stable output
+    fn push_ranked(&mut self, mut ranked: Vec<(u32, Suggestion)>) {
+        ranked.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.text.cmp(&b.1.text)));
+        self.suggestions
+            .extend(ranked.into_iter().map(|(_, suggestion)| suggestion));
+    }
+
     /// Apply selected suggestion to the input
     /// Returns the new input string
     pub fn apply_suggestion(&mut self, _current_input: &str) -> Option<String> {
@@ -276,3 +291,58 @@ impl AutocompleteState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SkillConfig;
+
+    fn config_with_skills(names: &[&str]) -> Config {
+        let mut config = Config::default();
+        config.agent.clear();
+        config.chain.clear();
+        config.skill = names
+            .iter()
+            .map(|name| {
+                let skill = SkillConfig {
+                    name: name.to_string(),
+                    ..Default::default()
+                };
+                (name.to_string(), skill)
+            })
+            .collect();
+        config
+    }
+
+    fn suggested(state: &AutocompleteState) -> Vec<&str> {
+        state.suggestions.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    #[test]
+    fn fuzzy_input_finds_skill_by_initials() {
+        let config = config_with_skills(&["smart-refactor", "review", "test"]);
+        let mut state = AutocompleteState::default();
+
+        state.update_suggestions("smrf", &config);
+
+        assert_eq!(suggested(&state), vec!["smart-refactor"]);
+    }
+
+    #[test]
+    fn exact_prefix_ranks_above_fuzzy_matches() {
+        let config = config_with_skills(&["smart-refactor", "refactor", "prefetch", "review-fix"]);
+        let mut state = AutocompleteState::default();
+
+        state.update_suggestions("ref", &config);
+        assert_eq!(
+            suggested(&state),
+            vec!["refactor", "smart-refactor", "prefetch", "review-fix"]
+        );
+
+        state.update_suggestions("claude:refac", &config);
+        assert_eq!(
+            suggested(&state),
+            vec!["claude:refactor", "claude:smart-refactor"]
+        );
+    }
+}
@@ -1,7 +1,7 @@
 //! Selection context - information about the current selection from IDE
 
-use crate::gui::http_server::{Dependency, Diagnostic};
-use std::path::PathBuf;
+use crate::gui::http_server::{Dependency, Diagnostic, SelectionRegion, format_selection_regions};
+use std::path::{Path, PathBuf};
 
 /// Information about the current selection context (received from IDE extensions)
 #[derive(Debug, Clone, Default)]
@@ -32,12 +32,20 @@ pub struct SelectionContext {
     pub diagnostics: Option<Vec<Diagnostic>>,
     /// Workspace root path (for SDK cwd resolution)
     pub workspace_path: Option<PathBuf>,
+    /// All regions when the IDE sent several selections as one request
+    pub regions: Vec<SelectionRegion>,
+    /// Lines before/after the selection to include as "Surrounding code" (0 = none)
+    pub context_lines: usize,
 }
 
 impl SelectionContext {
     /// Check if we have any useful selection data
     pub fn has_selection(&self) -> bool {
         self.selected_text.as_ref().map_or(false, |s| !s.is_empty())
+            || self
+                .regions
+                .iter()
+                .any(|r| r.selected_text.as_ref().is_some_and(|s| !s.is_empty()))
     }
 
     /// Format IDE context as markdown for prompt injection
@@ -87,6 +95,12 @@ impl SelectionContext {
             }
         }
 
+        ctx.push_str(&format_selection_regions(&self.regions));
+
+        if let Some(section) = self.surrounding_code_section() {
+            ctx.push_str(&section);
+        }
+
         if let Some(ref diagnostics) = self.diagnostics {
             if !diagnostics.is_empty() {
                 let errors: Vec<_> = diagnostics
@@ -134,4 +148,120 @@ impl SelectionContext {
 
         ctx
     }
+
+    /// Read `context_lines` lines around the selection from the source file
+    fn surrounding_code_section(&self) -> Option<String> {
+        if self.context_lines == 0 {
+            return None;
+        }
+        let start = self.line_number?;
+        let end = self.line_end.unwrap_or(start);
+
+        let mut path = PathBuf::from(self.file_path.as_deref()?);
+        if path.is_relative() {
+            if let Some(ws) = self.workspace_path.as_deref() {
+                path = ws.join(path);
+            }
+        }
+        let content = std::fs::read_to_string(&path).ok()?;
+        let code = surrounding_code(&content, start, end, self.context_lines)?;
+        let fence = code_fence(&code);
+
+        Some(format!(
+            "\n### Surrounding code ({} line{} of context):\n{}{}\n{}{}\n",
+            self.context_lines,
+            if self.context_lines == 1 { "" } else { "s" },
+            fence,
+            code_fence_language(&path),
+            code,
+            fence
+        ))
+    }
+}
+
+/// Lines `start - n ..= end + n` (1-indexed, clamped to the file) prefixed with
+/// their line numbers; selected lines are marked with `>`.
+fn surrounding_code(
+    content: &str,
+    start: usize,
+    end: usize,
+    n: usize,
+) -> Option<String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let start = start.max(1);
+    if start > lines.len() {
+        return None;
+    }
+    let end = end.clamp(start, lines.len());
+    let first = start.saturating_sub(n).max(1);
+    let last = end.saturating_add(n).min(lines.len());
+    let width = last.to_string().len();
+
+    let mut out = String::new();
+    for (idx, line) in lines.iter().enumerate().take(last).skip(first - 1) {
+        let number = idx + 1;
+        let marker = if (start..=end).contains(&number) { '>' } else { ' ' };
+        out.push_str(&format!("{marker}{number:>width$} | {line}\n"));
+    }
+    Some(out)
+}
+
+/// A backtick fence longer than any backtick run in `code` (at least three)
+fn code_fence(code: &str) -> String {
+    let longest_run = code
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or(0);
+    "`".repeat(longest_run.max(2) + 1)
+}
+
+fn code_fence_language(path: &Path) -> &str {
+    path.extension().and_then(|e| e.to_str()).unwrap_or("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILE: &str = "one\ntwo\nthree\nfour\nfive\n";
+
+    #[test]
+    fn surrounding_code_marks_selection_and_clamps_to_file() {
+        assert_eq!(
+            surrounding_code(FILE, 3, 3, 1).unwrap(),
+            " 2 | two\n>3 | three\n 4 | four\n"
+        );
+        // Context past either end of the file is clamped
+        assert_eq!(
+            surrounding_code(FILE, 1, 2, 10).unwrap(),
+            ">1 | one\n>2 | two\n 3 | three\n 4 | four\n 5 | five\n"
+        );
+        assert!(surrounding_code(FILE, 9, 9, 2).is_none());
+    }
+
+    #[test]
+    fn code_fence_outgrows_backtick_runs_in_the_code() {
+        assert_eq!(code_fence("let x = 1;"), "```");
+        assert_eq!(code_fence("/// ```rust\n/// ```"), "````");
+        assert_eq!(code_fence("let s = \"`````\";"), "``````");
+    }
+
+    #[test]
+    fn ide_context_includes_surrounding_code_only_when_requested() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("lib.rs"), FILE).unwrap();
+        let mut selection = SelectionContext {
+            file_path: Some("lib.rs".to_string()),
+            line_number: Some(4),
+            workspace_path: Some(dir.path().to_path_buf()),
+            ..Default::default()
+        };
+        assert!(!selection.format_ide_context().contains("Surrounding code"));
+
+        selection.context_lines = 1;
+        let ctx = selection.format_ide_context();
+        assert!(ctx.contains("### Surrounding code (1 line of context):\n```rs\n"));
+        assert!(ctx.contains(" 3 | three\n>4 | four\n 5 | five\n```"));
+    }
 }
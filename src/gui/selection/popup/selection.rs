@@ -100,11 +100,12 @@ fn render_header(ui: &mut egui::Ui, selection: &SelectionContext) {
                     .file_name()
                     .map(|n| n.to_string_lossy().to_string())
                     .unwrap_or_else(|| file.clone());
-                ui.label(
-                    RichText::new(format!("← {}", filename))
-                        .small()
-                        .color(TEXT_MUTED),
-                );
+                let label = if selection.regions.len() > 1 {
+                    format!("← {} ({} regions)", filename, selection.regions.len())
+                } else {
+                    format!("← {}", filename)
+                };
+                ui.label(RichText::new(label).small().color(TEXT_MUTED));
             }
         });
     });
@@ -1,7 +1,7 @@
 //! Status bar module for the GUI
 //!
-//! Renders the bottom status bar with auto-run toggle, profile info,
-//! settings button, modes button, agents button, and update notifications.
+//! Renders the bottom status bar with auto-run toggle, profile info, IDE bridge
+//! status, settings button, modes button, agents button, and update notifications.
 
 use eframe::egui::{self, RichText};
 
@@ -12,6 +12,7 @@ use crate::stats::PlayerStats;
 const VERSION_TEXT: &str = concat!("kyco v", env!("CARGO_PKG_VERSION"));
 
 use crate::gui::app::ViewMode;
+use crate::gui::http_server::BridgeStatus;
 use crate::gui::theme::{
     ACCENT_CYAN, ACCENT_GREEN, ACCENT_PURPLE, ACCENT_RED, ACCENT_YELLOW, BG_SECONDARY,
     TEXT_DIM, TEXT_MUTED, TEXT_PRIMARY,
@@ -52,6 +53,8 @@ pub struct StatusBarState<'a> {
     pub orchestrator_requested: &'a mut bool,
     /// Player stats for profile display (optional)
     pub player_stats: Option<&'a PlayerStats>,
+    /// IDE bridge status snapshot (optional)
+    pub bridge_status: Option<&'a BridgeStatus>,
 }
 
 /// Render the bottom status bar
@@ -107,6 +110,11 @@ pub fn render_status_bar(ctx: &egui::Context, state: &mut StatusBarState<'_>) {
                     *state.auto_allow = !*state.auto_allow;
                 }
 
+                if let Some(bridge) = state.bridge_status {
+                    ui.add_space(12.0);
+                    render_bridge_status(ui, bridge);
+                }
+
                 // Profile level/title display
                 if let Some(stats) = state.player_stats {
                     ui.add_space(16.0);
@@ -248,3 +256,53 @@ pub fn render_status_bar(ctx: &egui::Context, state: &mut StatusBarState<'_>) {
             });
         });
 }
+
+/// Render the IDE bridge indicator (click copies the URL for the IDE extension)
+fn render_bridge_status(ui: &mut egui::Ui, bridge: &BridgeStatus) {
+    let (text, color) = if bridge.listening {
+        (format!("● Bridge :{}", bridge.port), ACCENT_GREEN)
+    } else if bridge.error.is_some() {
+        (format!("● Bridge :{} down", bridge.port), ACCENT_RED)
+    } else {
+        (format!("○ Bridge :{}", bridge.port), TEXT_MUTED)
+    };
+
+    let mut hover = format!(
+        "IDE bridge: {}\nURL: {}\nAuth: {}",
+        if bridge.listening {
+            "listening"
+        } else if bridge.error.is_some() {
+            "not listening"
+        } else {
+            "starting"
+        },
+        bridge.url(),
+        if bridge.auth_enabled {
+            "enabled (X-KYCO-Token)"
+        } else {
+            "disabled"
+        }
+    );
+    if let Some(err) = &bridge.error {
+        hover.push_str(&format!("\nError: {}", err));
+    }
+    match &bridge.last_received {
+        Some((at, source)) => {
+            hover.push_str(&format!("\nLast selection: {} ({})", at.format("%H:%M:%S"), source))
+        }
+        None => hover.push_str("\nLast selection: none received yet"),
+    }
+    hover.push_str("\n\nClick to copy URL");
+
+    if ui
+        .add(
+            egui::Label::new(RichText::new(text).small().monospace().color(color))
+                .sense(egui::Sense::click()),
+        )
+        .on_hover_text(hover)
+        .on_hover_cursor(egui::CursorIcon::PointingHand)
+        .clicked()
+    {
+        ui.ctx().copy_text(bridge.url());
+    }
+}
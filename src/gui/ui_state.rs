@@ -0,0 +1,91 @@
+//! Persisted GUI view state (`.kyco/gui_state.json`)
+//!
+//! Small, best-effort state that should survive app restarts but doesn't
+//! belong in `config.toml` (e.g., which job was selected last).
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// View state restored on GUI startup
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UiState {
+    /// Job that was selected when the app was last used
+    #[serde(default)]
+    pub last_selected_job_id: Option<u64>,
+
+    /// Last auto-run toggle (Shift+A); `settings.auto_run` is only the default until set
+    #[serde(default)]
+    pub auto_run: Option<bool>,
+}
+
+impl UiState {
+    fn path(work_dir: &Path) -> PathBuf {
+        work_dir.join(".kyco").join("gui_state.json")
+    }
+
+    /// Load state for a workspace, falling back to defaults if missing or unreadable
+    pub fn load(work_dir: &Path) -> Self {
+        std::fs::read_to_string(Self::path(work_dir))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save state for a workspace (best-effort; errors are logged and ignored)
+    pub fn save(&self, work_dir: &Path) {
+        let path = Self::path(work_dir);
+        let result = path
+            .parent()
+            .map(std::fs::create_dir_all)
+            .unwrap_or(Ok(()))
+            .and_then(|_| {
+                let content = serde_json::to_string_pretty(self)
+                    .map_err(std::io::Error::other)?;
+                std::fs::write(&path, content)
+            });
+        if let Err(e) = result {
+            tracing::debug!("Failed to save GUI state to {}: {}", path.display(), e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn round_trips_last_selected_job() {
+        let tmp = TempDir::new().expect("tempdir");
+        assert!(UiState::load(tmp.path()).last_selected_job_id.is_none());
+
+        let state = UiState {
+            last_selected_job_id: Some(42),
+            ..Default::default()
+        };
+        state.save(tmp.path());
+        assert_eq!(UiState::load(tmp.path()).last_selected_job_id, Some(42));
+    }
+
+    #[test]
+    fn auto_run_is_unset_until_toggled() {
+        let tmp = TempDir::new().expect("tempdir");
+        let state_dir = tmp.path().join(".kyco");
+        std::fs::create_dir_all(&state_dir).expect("mkdir");
+        std::fs::write(
+            state_dir.join("gui_state.json"),
+            r#"{"last_selected_job_id": 7}"#,
+        )
+        .expect("write");
+        assert_eq!(UiState::load(tmp.path()).auto_run, None);
+
+        let state = UiState {
+            auto_run: Some(false),
+            ..UiState::load(tmp.path())
+        };
+        state.save(tmp.path());
+        let loaded = UiState::load(tmp.path());
+        assert_eq!(loaded.auto_run, Some(false));
+        assert_eq!(loaded.last_selected_job_id, Some(7));
+    }
+}
@@ -18,7 +18,7 @@ pub(super) fn update_checker_loop(tx: Sender<UpdateStatus>, rx: Receiver<()>) {
 }
 
 /// Execute the update check
-fn do_check() -> UpdateStatus {
+pub(super) fn do_check() -> UpdateStatus {
     let url = format!(
         "https://api.github.com/repos/{}/releases/latest",
         GITHUB_REPO
@@ -12,6 +12,11 @@ use std::time::{Duration, Instant};
 
 pub use install::{install_update, open_url};
 
+/// Query the latest release once (blocking) - used by `kyco version --check`
+pub fn check_for_update() -> UpdateStatus {
+    check::do_check()
+}
+
 /// Current version from Cargo.toml
 pub(crate) const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -6,7 +6,7 @@
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 
-use crate::{AgentGroupId, AgentRunGroup, GroupStatus, Job, JobId, JobStatus};
+use crate::{AgentGroupId, AgentRunGroup, AutoSelectPolicy, GroupStatus, Job, JobId, JobStatus};
 
 /// Manages agent run groups for parallel multi-agent execution
 pub struct GroupManager {
@@ -38,6 +38,13 @@ impl GroupManager {
         }
     }
 
+    /// Let the group pick its winner automatically once all jobs finish
+    pub fn set_auto_select(&mut self, group_id: AgentGroupId, policy: Option<AutoSelectPolicy>) {
+        if let Some(group) = self.groups.get_mut(&group_id) {
+            group.auto_select = policy;
+        }
+    }
+
     /// Get a group by ID
     pub fn get(&self, id: AgentGroupId) -> Option<&AgentRunGroup> {
         self.groups.get(&id)
@@ -73,14 +80,14 @@ impl GroupManager {
     ///
     /// This should be called when a job's status changes to check if
     /// all jobs in the group are now finished.
-    pub fn update_group_status(&mut self, group_id: AgentGroupId, jobs: &[&Job]) {
-        let group = match self.groups.get_mut(&group_id) {
-            Some(g) => g,
-            None => return,
-        };
+    ///
+    /// Returns the winning job when the group has an auto-select policy and
+    /// just picked one; the caller is responsible for rejecting the other jobs.
+    pub fn update_group_status(&mut self, group_id: AgentGroupId, jobs: &[&Job]) -> Option<JobId> {
+        let group = self.groups.get_mut(&group_id)?;
 
         if group.status != GroupStatus::Running {
-            return;
+            return None;
         }
 
         let group_jobs: Vec<_> = jobs
@@ -93,6 +100,14 @@ impl GroupManager {
 
         if all_finished {
             if any_succeeded {
+                let winner = group.auto_select.as_ref().and_then(|policy| {
+                    let candidates: Vec<&Job> = group_jobs.iter().map(|j| **j).collect();
+                    policy.pick(&candidates)
+                });
+                if let Some(job_id) = winner {
+                    group.select_job(job_id);
+                    return Some(job_id);
+                }
                 // At least one job succeeded - ready for comparison
                 group.set_status(GroupStatus::Comparing);
             } else {
@@ -100,6 +115,7 @@ impl GroupManager {
                 group.set_status(GroupStatus::Cancelled);
             }
         }
+        None
     }
 
     /// Select a job as the winning result for a group
@@ -228,3 +244,89 @@ impl Default for GroupManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JobResult, JobStats, ScopeDefinition};
+    use std::path::PathBuf;
+
+    fn done_job(id: JobId, group_id: AgentGroupId, state: Option<&str>, files: usize) -> Job {
+        let mut job = Job::new(
+            id,
+            "fix".to_string(),
+            ScopeDefinition::file(PathBuf::from("src/lib.rs")),
+            "src/lib.rs".to_string(),
+            None,
+            format!("agent-{}", id),
+            PathBuf::from("src/lib.rs"),
+            1,
+            None,
+        );
+        job.group_id = Some(group_id);
+        job.set_status(JobStatus::Done);
+        job.result = Some(JobResult {
+            state: state.map(str::to_string),
+            ..Default::default()
+        });
+        job.stats = Some(JobStats {
+            files_changed: files,
+            ..Default::default()
+        });
+        job
+    }
+
+    fn group_with(gm: &mut GroupManager, jobs: &[&Job], policy: Option<AutoSelectPolicy>) -> AgentGroupId {
+        let group_id = gm.create_group("p".into(), "fix".into(), "src/lib.rs:1".into());
+        for job in jobs {
+            gm.add_job_to_group(group_id, job.id, job.agent_id.clone());
+        }
+        gm.set_auto_select(group_id, policy);
+        group_id
+    }
+
+    #[test]
+    fn auto_select_prefers_earlier_state_then_fewer_files_then_lower_id() {
+        let policy = AutoSelectPolicy::new(vec!["fixed".into(), "partial".into()]);
+
+        let a = done_job(1, 1, Some("partial"), 1);
+        let b = done_job(2, 1, Some("fixed"), 9);
+        let c = done_job(3, 1, Some("fixed"), 2);
+        let d = done_job(4, 1, Some("FIXED"), 2);
+        let e = done_job(5, 1, None, 0);
+        assert_eq!(policy.pick(&[&a, &b, &c, &d, &e]), Some(3));
+
+        // Without any preferred state, the smallest change wins
+        assert_eq!(policy.pick(&[&a, &e]), Some(1));
+        let none_preferred = AutoSelectPolicy::default();
+        assert_eq!(none_preferred.pick(&[&a, &b, &e]), Some(5));
+    }
+
+    #[test]
+    fn auto_select_ignores_jobs_that_did_not_finish_done() {
+        let policy = AutoSelectPolicy::new(vec!["fixed".into()]);
+        let mut failed = done_job(1, 1, Some("fixed"), 0);
+        failed.set_status(JobStatus::Failed);
+        let ok = done_job(2, 1, Some("other"), 5);
+        assert_eq!(policy.pick(&[&failed, &ok]), Some(2));
+        assert_eq!(policy.pick(&[&failed]), None);
+    }
+
+    #[test]
+    fn update_group_status_selects_winner_only_with_policy() {
+        let mut gm = GroupManager::new();
+        let a = done_job(1, 1, Some("fixed"), 3);
+        let b = done_job(2, 1, Some("fixed"), 1);
+        let auto = group_with(&mut gm, &[&a, &b], Some(AutoSelectPolicy::new(vec!["fixed".into()])));
+        assert_eq!(gm.update_group_status(auto, &[&a, &b]), Some(2));
+        let group = gm.get(auto).unwrap();
+        assert_eq!(group.status, GroupStatus::Selected);
+        assert_eq!(group.selected_job, Some(2));
+
+        let c = done_job(3, 2, Some("fixed"), 3);
+        let d = done_job(4, 2, Some("fixed"), 1);
+        let manual = group_with(&mut gm, &[&c, &d], None);
+        assert_eq!(gm.update_group_status(manual, &[&c, &d]), None);
+        assert_eq!(gm.get(manual).unwrap().status, GroupStatus::Comparing);
+    }
+}
@@ -1,16 +1,21 @@
 //! Job manager implementation
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
+use crate::config::JobIdStrategy;
 use crate::git::find_git_root;
 use crate::{CommentTag, Job, JobId, JobStatus, ScopeDefinition};
 
 const JOB_MANAGER_STATE_VERSION: u32 = 1;
+const JOB_ARCHIVE_STATE_VERSION: u32 = 1;
 const JOB_MANAGER_PERSIST_DEBOUNCE: Duration = Duration::from_millis(750);
+/// Random ids stay below 2^53 so JSON clients that parse numbers as f64
+/// (IDE extensions, browser tools talking to `/ctl`) see them exactly.
+const MAX_RANDOM_JOB_ID: JobId = (1 << 53) - 1;
 
 /// Manages job lifecycle (in-memory + persisted snapshot)
 pub struct JobManager {
@@ -20,6 +25,8 @@ pub struct JobManager {
 
     pub(super) jobs: HashMap<JobId, Job>,
     next_id: AtomicU64,
+    /// How `allocate_id` picks ids (`settings.job_id_strategy`).
+    id_strategy: JobIdStrategy,
 
     /// File locks for concurrent job isolation
     file_locks: HashMap<PathBuf, JobId>,
@@ -30,6 +37,10 @@ pub struct JobManager {
 
     /// Path to the persisted job manager state file.
     persist_path: PathBuf,
+    /// Write the state gzip-compressed (`job_manager.json.gz`) instead of plain JSON.
+    compress_store: bool,
+    /// Path to the archive of finished jobs (kept out of the active list).
+    archive_path: PathBuf,
     /// Whether there are unapplied changes since the last persist.
     dirty: bool,
     /// Last time we persisted state to disk (throttled).
@@ -40,14 +51,18 @@ impl JobManager {
     pub fn new(root: impl Into<PathBuf>) -> Self {
         let root = root.into();
         let persist_path = root.join(".kyco").join("job_manager.json");
+        let archive_path = root.join(".kyco").join("jobs_archive.json");
 
         Self {
             root,
             jobs: HashMap::new(),
             next_id: AtomicU64::new(1),
+            id_strategy: JobIdStrategy::default(),
             file_locks: HashMap::new(),
             generation: 0,
             persist_path,
+            compress_store: false,
+            archive_path,
             dirty: false,
             last_persisted_at: None,
         }
@@ -60,13 +75,15 @@ impl JobManager {
     }
 
     /// Create a new job manager (for API compatibility, same as new())
+    ///
+    /// Reads either the plain or the gzip-compressed store, whichever was written last.
     pub fn load(root: &Path) -> Result<Self> {
         let persist_path = root.join(".kyco").join("job_manager.json");
-        if !persist_path.exists() {
+        let Some((store_path, compressed)) = newest_store(&persist_path) else {
             return Ok(Self::new(root));
-        }
+        };
 
-        let content = std::fs::read_to_string(&persist_path)?;
+        let content = read_store(&store_path, compressed)?;
         let snapshot: JobManagerSnapshot = serde_json::from_str(&content)?;
 
         if snapshot.version != JOB_MANAGER_STATE_VERSION {
@@ -75,6 +92,7 @@ impl JobManager {
         }
 
         let mut manager = Self::new(root);
+        manager.compress_store = compressed;
         let mut max_id = 0u64;
         let now = chrono::Utc::now();
 
@@ -120,11 +138,55 @@ impl JobManager {
         Ok(manager)
     }
 
+    /// Switch between the plain and gzip-compressed store (`settings.compress_job_store`).
+    ///
+    /// Changing the format rewrites the store right away and removes the old file.
+    pub fn set_compress_store(&mut self, compress: bool) {
+        if self.compress_store != compress {
+            self.compress_store = compress;
+            self.mark_dirty_and_maybe_persist(true);
+        }
+    }
+
+    /// Choose how new job ids are allocated (`settings.job_id_strategy`).
+    pub fn set_id_strategy(&mut self, strategy: JobIdStrategy) {
+        self.id_strategy = strategy;
+    }
+
+    /// Write unsaved changes now instead of waiting for the persist debounce.
+    ///
+    /// For shutdown paths where the manager is still shared and `Drop` won't run.
+    pub fn flush(&mut self) {
+        if self.dirty {
+            self.mark_dirty_and_maybe_persist(true);
+        }
+    }
+
     /// Allocate the next job ID
     fn allocate_id(&self) -> JobId {
+        if self.id_strategy == JobIdStrategy::Random {
+            if let Some(id) = self.allocate_random_id(random_job_id) {
+                return id;
+            }
+        }
         self.next_id.fetch_add(1, Ordering::SeqCst)
     }
 
+    /// Draw random ids until one is non-zero and not used by a known job.
+    ///
+    /// Only the in-memory jobs (loaded from the store at startup, plus those created
+    /// since) are checked; ids allocated by other processes are not re-read.
+    ///
+    /// Returns `None` if the RNG is unavailable.
+    fn allocate_random_id(&self, mut draw: impl FnMut() -> Option<JobId>) -> Option<JobId> {
+        loop {
+            let id = draw()? & MAX_RANDOM_JOB_ID;
+            if id != 0 && !self.jobs.contains_key(&id) {
+                return Some(id);
+            }
+        }
+    }
+
     /// Insert a job and increment generation
     fn insert_job(&mut self, id: JobId, job: Job) {
         self.jobs.insert(id, job);
@@ -219,6 +281,43 @@ impl JobManager {
         }
     }
 
+    /// Record the agent session id of a job.
+    ///
+    /// A newly seen id is persisted right away (bypassing the debounce) so that
+    /// `kyco job resume` can reattach to the session if KYCo dies mid-run.
+    pub fn set_session_id(&mut self, id: JobId, session_id: &str) {
+        let Some(job) = self.jobs.get_mut(&id) else {
+            return;
+        };
+        let is_new = job.bridge_session_id.as_deref() != Some(session_id);
+        if is_new {
+            job.bridge_session_id = Some(session_id.to_string());
+        }
+        self.generation += 1;
+        self.mark_dirty_and_maybe_persist(is_new);
+    }
+
+    /// Move a job's tag to `line` after edits shifted it.
+    ///
+    /// Finished jobs keep the line they ran against. A `file:line` target is
+    /// rewritten along with `source_line`. Returns whether anything changed.
+    pub fn update_source_line(&mut self, id: JobId, line: usize) -> bool {
+        let Some(job) = self.jobs.get_mut(&id) else {
+            return false;
+        };
+        if job.is_finished() || job.source_line == line {
+            return false;
+        }
+        let old_target = format!("{}:{}", job.source_file.display(), job.source_line);
+        if job.target == old_target {
+            job.target = format!("{}:{}", job.source_file.display(), line);
+        }
+        job.source_line = line;
+        self.generation += 1;
+        self.mark_dirty_and_maybe_persist(false);
+        true
+    }
+
     /// Manually increment the generation counter.
     ///
     /// Call this after directly modifying a job via `get_mut()` to ensure
@@ -283,6 +382,115 @@ impl JobManager {
         removed
     }
 
+    /// Move a finished job into the archive store.
+    ///
+    /// The full job (including its result and usage) is appended to
+    /// `.kyco/jobs_archive.json` and removed from the active list.
+    pub fn archive(&mut self, job_id: JobId) -> Result<Job> {
+        let Some(job) = self.jobs.get(&job_id) else {
+            bail!("Job #{} not found", job_id);
+        };
+        if !job.is_finished() {
+            bail!(
+                "Job #{} is {} - only finished jobs can be archived",
+                job_id,
+                job.status
+            );
+        }
+
+        let mut archived = self.list_archived()?;
+        archived.retain(|j| j.id != job_id);
+        archived.push(job.clone());
+        archived.sort_by_key(|j| j.id);
+        self.write_archive(archived)?;
+
+        self.remove_job(job_id)
+            .ok_or_else(|| anyhow::anyhow!("Job #{} not found", job_id))
+    }
+
+    /// Re-queue a failed job so the executor reattaches to its stored agent session.
+    ///
+    /// Returns the session id that will be resumed.
+    pub fn request_resume(&mut self, job_id: JobId) -> Result<String> {
+        let Some(job) = self.jobs.get_mut(&job_id) else {
+            bail!("Job #{} not found", job_id);
+        };
+        if job.status != JobStatus::Failed {
+            bail!(
+                "Job #{} is {} - only jobs whose run died can be resumed",
+                job_id,
+                job.status
+            );
+        }
+        let Some(session_id) = job.bridge_session_id.clone() else {
+            bail!("Job #{} has no stored session id to resume", job_id);
+        };
+
+        job.resume_requested = true;
+        job.error_message = None;
+        job.finished_at = None;
+        job.set_status(JobStatus::Queued);
+        self.generation += 1;
+        self.mark_dirty_and_maybe_persist(true);
+        Ok(session_id)
+    }
+
+    /// Archived jobs, oldest first (read from disk; empty if none were archived).
+    pub fn list_archived(&self) -> Result<Vec<Job>> {
+        if !self.archive_path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&self.archive_path)?;
+        let snapshot: JobArchiveSnapshot = serde_json::from_str(&content)?;
+        if snapshot.version != JOB_ARCHIVE_STATE_VERSION {
+            bail!(
+                "Unsupported job archive version {} in {}",
+                snapshot.version,
+                self.archive_path.display()
+            );
+        }
+        Ok(snapshot.jobs)
+    }
+
+    /// Active and archived jobs exactly as persisted, without loading a manager.
+    ///
+    /// Unlike [`JobManager::load`], `Running` jobs stay `Running`, so read-only
+    /// tools can tell which jobs another KYCo process is still working on.
+    pub fn read_snapshot(root: &Path) -> Result<Vec<Job>> {
+        let manager = Self::new(root);
+        let mut jobs = match newest_store(&manager.persist_path) {
+            Some((store_path, compressed)) => {
+                let content = read_store(&store_path, compressed)?;
+                let snapshot: JobManagerSnapshot = serde_json::from_str(&content)?;
+                if snapshot.version != JOB_MANAGER_STATE_VERSION {
+                    bail!(
+                        "Unsupported job store version {} in {}",
+                        snapshot.version,
+                        store_path.display()
+                    );
+                }
+                snapshot.jobs
+            }
+            None => Vec::new(),
+        };
+        jobs.extend(manager.list_archived()?);
+        Ok(jobs)
+    }
+
+    fn write_archive(&self, jobs: Vec<Job>) -> Result<()> {
+        if let Some(parent) = self.archive_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let snapshot = JobArchiveSnapshot {
+            version: JOB_ARCHIVE_STATE_VERSION,
+            jobs,
+        };
+        let tmp_path = self.archive_path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, serde_json::to_string_pretty(&snapshot)?)?;
+        std::fs::rename(&tmp_path, &self.archive_path)?;
+        Ok(())
+    }
+
     fn mark_dirty_and_maybe_persist(&mut self, force: bool) {
         self.dirty = true;
 
@@ -327,7 +535,20 @@ impl JobManager {
             jobs,
         };
 
-        let content = serde_json::to_string_pretty(&snapshot)?;
+        let content = if self.compress_store {
+            use std::io::Write;
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(&serde_json::to_vec(&snapshot)?)?;
+            encoder.finish()?
+        } else {
+            serde_json::to_string_pretty(&snapshot)?.into_bytes()
+        };
+        let (store_path, stale_path) = if self.compress_store {
+            (gz_store_path(&self.persist_path), self.persist_path.clone())
+        } else {
+            (self.persist_path.clone(), gz_store_path(&self.persist_path))
+        };
 
         let lock_path = self.persist_path.with_extension("json.lock");
         let lock_file = OpenOptions::new()
@@ -337,13 +558,17 @@ impl JobManager {
             .open(&lock_path)?;
         lock_file.lock_exclusive()?;
 
-        let tmp_path = self.persist_path.with_extension("json.tmp");
+        let tmp_path = store_path.with_extension("tmp");
         std::fs::write(&tmp_path, content)?;
 
-        if self.persist_path.exists() {
-            let _ = std::fs::remove_file(&self.persist_path);
+        if store_path.exists() {
+            let _ = std::fs::remove_file(&store_path);
+        }
+        std::fs::rename(&tmp_path, &store_path)?;
+        // Only one format may remain, otherwise `load` could pick up stale jobs
+        if stale_path.exists() {
+            let _ = std::fs::remove_file(&stale_path);
         }
-        std::fs::rename(&tmp_path, &self.persist_path)?;
 
         let _ = lock_file.unlock();
 
@@ -362,9 +587,238 @@ impl Drop for JobManager {
     }
 }
 
+fn gz_store_path(persist_path: &Path) -> PathBuf {
+    persist_path.with_extension("json.gz")
+}
+
+/// The existing store file (plain or gzip) and whether it is compressed.
+/// If both exist, the most recently modified one wins.
+fn newest_store(persist_path: &Path) -> Option<(PathBuf, bool)> {
+    let modified = |p: &Path| std::fs::metadata(p).and_then(|m| m.modified()).ok();
+    let gz_path = gz_store_path(persist_path);
+    match (modified(persist_path), modified(&gz_path)) {
+        (Some(plain), Some(gz)) if gz > plain => Some((gz_path, true)),
+        (Some(_), _) => Some((persist_path.to_path_buf(), false)),
+        (None, Some(_)) => Some((gz_path, true)),
+        (None, None) => None,
+    }
+}
+
+fn random_job_id() -> Option<JobId> {
+    let mut buf = [0u8; 8];
+    getrandom::getrandom(&mut buf).ok()?;
+    Some(u64::from_le_bytes(buf))
+}
+
+fn read_store(path: &Path, compressed: bool) -> Result<String> {
+    if !compressed {
+        return Ok(std::fs::read_to_string(path)?);
+    }
+    use std::io::Read;
+    let mut content = String::new();
+    flate2::read::GzDecoder::new(std::fs::File::open(path)?).read_to_string(&mut content)?;
+    Ok(content)
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 struct JobManagerSnapshot {
     version: u32,
     next_id: u64,
     jobs: Vec<Job>,
 }
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct JobArchiveSnapshot {
+    version: u32,
+    jobs: Vec<Job>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UsageStats;
+
+    fn finished_job(manager: &mut JobManager, root: &Path) -> JobId {
+        let tag = CommentTag::new_simple(
+            root.join("src/lib.rs"),
+            1,
+            "// @claude#fix".to_string(),
+            "claude".to_string(),
+            "fix".to_string(),
+        );
+        let id = manager.create_job(&tag, "claude").unwrap();
+        let job = manager.get_mut(id).unwrap();
+        job.set_status(JobStatus::Done);
+        job.result = Some(crate::JobResult {
+            title: Some("Tightened checks".to_string()),
+            usage: Some(UsageStats {
+                input_tokens: 42,
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        manager.touch();
+        id
+    }
+
+    #[test]
+    fn update_source_line_skips_finished_jobs() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = JobManager::new(dir.path());
+        let tag = CommentTag::new_simple(
+            dir.path().join("src/lib.rs"),
+            3,
+            "// @claude#fix".to_string(),
+            "claude".to_string(),
+            "fix".to_string(),
+        );
+        let id = manager.create_job(&tag, "claude").unwrap();
+
+        assert!(manager.update_source_line(id, 7));
+        let job = manager.get(id).unwrap();
+        assert_eq!(job.source_line, 7);
+        assert_eq!(
+            job.target,
+            format!("{}:7", dir.path().join("src/lib.rs").display())
+        );
+        assert!(!manager.update_source_line(id, 7));
+
+        let done = finished_job(&mut manager, dir.path());
+        assert!(!manager.update_source_line(done, 9));
+        assert_eq!(manager.get(done).unwrap().source_line, 1);
+    }
+
+    #[test]
+    fn random_id_strategy_yields_unique_ids() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = JobManager::new(dir.path());
+        manager.set_id_strategy(JobIdStrategy::Random);
+
+        let mut ids = std::collections::HashSet::new();
+        for _ in 0..50 {
+            let id = finished_job(&mut manager, dir.path());
+            assert!(id > 0 && id <= MAX_RANDOM_JOB_ID);
+            assert!(ids.insert(id));
+        }
+
+        // Taken ids and zero are skipped until a free id comes up
+        let taken = *ids.iter().next().unwrap();
+        let mut draws = vec![taken, 0, taken | (1 << 60), 7].into_iter();
+        let next = manager.allocate_random_id(|| draws.next());
+        assert_eq!(next, Some(7));
+        assert_eq!(manager.allocate_random_id(|| None), None);
+    }
+
+    #[test]
+    fn compressed_store_round_trips_and_replaces_plain_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let plain = dir.path().join(".kyco/job_manager.json");
+        let gz = dir.path().join(".kyco/job_manager.json.gz");
+
+        let mut manager = JobManager::new(dir.path());
+        let id = finished_job(&mut manager, dir.path());
+        manager.get_mut(id).unwrap().full_response = Some("agent output\n".repeat(2000));
+        manager.touch();
+        assert!(plain.exists());
+
+        manager.set_compress_store(true);
+        assert!(gz.exists());
+        assert!(!plain.exists());
+        assert!(std::fs::metadata(&gz).unwrap().len() < 2000);
+
+        let reloaded = JobManager::load(dir.path()).unwrap();
+        let before = serde_json::to_value(manager.get(id)).unwrap();
+        let after = serde_json::to_value(reloaded.get(id)).unwrap();
+        assert_eq!(before, after);
+        assert_eq!(reloaded.jobs().len(), 1);
+
+        // Turning compression off migrates back to plain JSON
+        manager.set_compress_store(false);
+        assert!(plain.exists());
+        assert!(!gz.exists());
+    }
+
+    #[test]
+    fn archived_jobs_leave_active_list_and_keep_result() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = JobManager::new(dir.path());
+        let done = finished_job(&mut manager, dir.path());
+        let tag = CommentTag::new_simple(
+            dir.path().join("src/main.rs"),
+            3,
+            "// @claude#review".to_string(),
+            "claude".to_string(),
+            "review".to_string(),
+        );
+        let pending = manager.create_job(&tag, "claude").unwrap();
+
+        assert!(manager.archive(pending).is_err());
+        manager.archive(done).unwrap();
+
+        let active: Vec<JobId> = manager.jobs().iter().map(|j| j.id).collect();
+        assert_eq!(active, vec![pending]);
+
+        let archived = JobManager::load(dir.path())
+            .unwrap()
+            .list_archived()
+            .unwrap();
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].id, done);
+        let result = archived[0].result.as_ref().unwrap();
+        assert_eq!(result.title.as_deref(), Some("Tightened checks"));
+        assert_eq!(result.usage.as_ref().map(|u| u.input_tokens), Some(42));
+    }
+
+    #[test]
+    fn snapshot_keeps_running_jobs_and_includes_archive() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = JobManager::new(dir.path());
+        let done = finished_job(&mut manager, dir.path());
+        manager.archive(done).unwrap();
+        let tag = CommentTag::new_simple(
+            dir.path().join("src/main.rs"),
+            3,
+            "// @claude#review".to_string(),
+            "claude".to_string(),
+            "review".to_string(),
+        );
+        let running = manager.create_job(&tag, "claude").unwrap();
+        manager.set_status(running, JobStatus::Running);
+        manager.flush();
+
+        let snapshot = JobManager::read_snapshot(dir.path()).unwrap();
+        let status = |id| snapshot.iter().find(|j| j.id == id).map(|j| j.status);
+        assert_eq!(status(running), Some(JobStatus::Running));
+        assert_eq!(status(done), Some(JobStatus::Done));
+    }
+
+    #[test]
+    fn session_id_survives_restart_and_job_can_be_resumed() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = JobManager::new(dir.path());
+        let tag = CommentTag::new_simple(
+            dir.path().join("src/lib.rs"),
+            1,
+            "// @claude#fix".to_string(),
+            "claude".to_string(),
+            "fix".to_string(),
+        );
+        let id = manager.create_job(&tag, "claude").unwrap();
+        assert!(manager.request_resume(id).is_err());
+
+        manager.set_status(id, JobStatus::Running);
+        manager.set_session_id(id, "sess-42");
+
+        // Simulated crash: reload from disk without a clean shutdown
+        let mut reloaded = JobManager::load(dir.path()).unwrap();
+        let job = reloaded.get(id).unwrap();
+        assert_eq!(job.status, JobStatus::Failed);
+        assert_eq!(job.bridge_session_id.as_deref(), Some("sess-42"));
+
+        assert_eq!(reloaded.request_resume(id).unwrap(), "sess-42");
+        let job = reloaded.get(id).unwrap();
+        assert_eq!(job.status, JobStatus::Queued);
+        assert!(job.resume_requested);
+        assert!(job.error_message.is_none());
+    }
+}
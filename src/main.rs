@@ -7,8 +7,9 @@ use kyco::cli;
 
 mod commands;
 use commands::{
-    AgentCommands, ChainCommands, Commands, FindingCommands, ImportCommands, JobCommands,
-    MemoryCommands, ModeCommands, ProjectCommands, ScopeCommands, SessionCommands, SkillCommands,
+    AgentCommands, ArtifactCommands, ChainCommands, Commands, ConfigCommands, FindingCommands,
+    ImportCommands, JobCommands, MemoryCommands, ModeCommands, ProjectCommands, QueueCommands,
+    ScopeCommands, SessionCommands, SkillCommands, StatsCommands, WorktreeCommands,
 };
 
 #[derive(Parser)]
@@ -51,11 +52,39 @@ async fn main() -> Result<()> {
         Some(Commands::Gui) => {
             kyco::gui::run_gui(work_dir.clone(), config_path.clone())?;
         }
-        Some(Commands::Status { filter }) => {
-            cli::status::status_command(&work_dir, config_path.as_ref(), filter).await?;
+        Some(Commands::Serve { port }) => {
+            kyco::gui::run_serve(work_dir.clone(), config_path.clone(), port)?;
         }
-        Some(Commands::Init { force }) => {
-            cli::init::init_command(&work_dir, config_path.clone(), force).await?;
+        Some(Commands::Status {
+            filter,
+            since,
+            tag,
+            json_lines,
+            watch,
+            interval,
+        }) => {
+            cli::status::status_command(
+                &work_dir,
+                config_path.as_ref(),
+                filter,
+                since,
+                tag,
+                json_lines,
+                watch.then(|| Duration::from_secs(interval.max(1))),
+            )
+            .await?;
+        }
+        Some(Commands::Init {
+            force,
+            template,
+            list_templates,
+        }) => {
+            if list_templates {
+                cli::init::list_templates();
+            } else {
+                cli::init::init_command(&work_dir, config_path.clone(), force, template.as_deref())
+                    .await?;
+            }
         }
         Some(Commands::Job { command }) => match command {
             JobCommands::List {
@@ -65,6 +94,7 @@ async fn main() -> Result<()> {
                 status,
                 state,
                 limit,
+                offset,
                 search,
                 skill,
             } => {
@@ -77,15 +107,20 @@ async fn main() -> Result<()> {
                     status.as_deref(),
                     state.as_deref(),
                     limit,
+                    offset,
                     search.as_deref(),
                     skill.as_deref(), // CLI uses --skill, internally still called mode
                 )?;
             }
+            JobCommands::Tree { root } => {
+                cli::job::job_tree_command(&work_dir, config_path.as_ref(), root)?;
+            }
             JobCommands::Get { job_id, json } => {
                 cli::job::job_get_command(&work_dir, config_path.as_ref(), job_id, json)?;
             }
             JobCommands::Start {
                 file,
+                dir,
                 input,
                 batch,
                 line_start,
@@ -96,6 +131,7 @@ async fn main() -> Result<()> {
                 finding,
                 agent,
                 agents,
+                agents_file,
                 pending,
                 force_worktree,
                 json,
@@ -103,12 +139,19 @@ async fn main() -> Result<()> {
                 fork,
                 plan,
                 permission_mode,
+                env,
+                ignore_budget,
+                auto_select,
+                context_lines,
+                tags,
+                base,
             } => {
                 cli::job::job_start_command(
                     &work_dir,
                     config_path.as_ref(),
                     cli::job::JobStartArgs {
                         file_path: file,
+                        dir_path: dir,
                         input,
                         batch,
                         line_start,
@@ -120,6 +163,7 @@ async fn main() -> Result<()> {
                         bugbounty_finding_ids: finding,
                         agent,
                         agents,
+                        agents_file,
                         queue: !pending,
                         force_worktree,
                         json,
@@ -127,6 +171,12 @@ async fn main() -> Result<()> {
                         fork_session: fork,
                         plan_mode: plan,
                         permission_mode,
+                        env,
+                        ignore_budget,
+                        auto_select,
+                        context_lines,
+                        tags,
+                        base,
                     },
                 )?;
             }
@@ -136,6 +186,9 @@ async fn main() -> Result<()> {
             JobCommands::Abort { job_id } => {
                 cli::job::job_abort_command(&work_dir, config_path.as_ref(), job_id)?;
             }
+            JobCommands::AbortAll => {
+                cli::job::job_abort_all_command(&work_dir, config_path.as_ref())?;
+            }
             JobCommands::Kill { job_id } => {
                 cli::job::job_kill_command(&work_dir, config_path.as_ref(), job_id)?;
             }
@@ -150,6 +203,32 @@ async fn main() -> Result<()> {
                     cleanup_worktree,
                 )?;
             }
+            JobCommands::Archive { job_id } => {
+                cli::job::job_archive_command(&work_dir, config_path.as_ref(), job_id)?;
+            }
+            JobCommands::Explain {
+                file,
+                skill,
+                line_start,
+                line_end,
+                prompt,
+                agent,
+                json,
+            } => {
+                cli::job::job_explain_command(
+                    &work_dir,
+                    config_path.as_ref(),
+                    cli::job::JobExplainArgs {
+                        file,
+                        skill,
+                        line_start,
+                        line_end,
+                        prompt,
+                        agent,
+                        json,
+                    },
+                )?;
+            }
             JobCommands::Continue {
                 job_id,
                 prompt,
@@ -169,8 +248,29 @@ async fn main() -> Result<()> {
                     plan,
                 )?;
             }
-            JobCommands::Wait {
+            JobCommands::ContinueBatch {
                 job_id,
+                prompts,
+                stop_on_failure,
+                json,
+                fork,
+                plan,
+            } => {
+                cli::job::job_continue_batch_command(
+                    &work_dir,
+                    config_path.as_ref(),
+                    job_id,
+                    prompts,
+                    stop_on_failure,
+                    json,
+                    fork,
+                    plan,
+                )?;
+            }
+            JobCommands::Wait {
+                job_ids,
+                all: _,
+                any,
                 timeout_secs,
                 poll_ms,
                 json,
@@ -178,12 +278,28 @@ async fn main() -> Result<()> {
                 cli::job::job_wait_command(
                     &work_dir,
                     config_path.as_ref(),
-                    job_id,
+                    &job_ids,
+                    any,
                     timeout_secs.map(Duration::from_secs),
                     Duration::from_millis(poll_ms),
                     json,
                 )?;
             }
+            JobCommands::Logs {
+                job_id,
+                follow,
+                poll_ms,
+                json,
+            } => {
+                cli::job::job_logs_command(
+                    &work_dir,
+                    config_path.as_ref(),
+                    job_id,
+                    follow,
+                    Duration::from_millis(poll_ms),
+                    json,
+                )?;
+            }
             JobCommands::Output {
                 job_id,
                 json,
@@ -193,6 +309,7 @@ async fn main() -> Result<()> {
                 artifacts,
                 summary,
                 state,
+                usage,
             } => {
                 cli::job::job_output_command(
                     &work_dir,
@@ -205,10 +322,21 @@ async fn main() -> Result<()> {
                     artifacts,
                     summary,
                     state,
+                    usage,
                 )?;
             }
-            JobCommands::Merge { job_id, message } => {
-                cli::job::job_merge_command(&work_dir, config_path.as_ref(), job_id, message)?;
+            JobCommands::Merge {
+                job_id,
+                message,
+                force,
+            } => {
+                cli::job::job_merge_command(
+                    &work_dir,
+                    config_path.as_ref(),
+                    job_id,
+                    message,
+                    force,
+                )?;
             }
             JobCommands::Reject { job_id } => {
                 cli::job::job_reject_command(&work_dir, config_path.as_ref(), job_id)?;
@@ -216,10 +344,32 @@ async fn main() -> Result<()> {
             JobCommands::Restart { job_id } => {
                 cli::job::job_restart_command(&work_dir, config_path.as_ref(), job_id)?;
             }
+            JobCommands::Retry {
+                job_id,
+                same_worktree,
+            } => {
+                cli::job::job_retry_command(
+                    &work_dir,
+                    config_path.as_ref(),
+                    job_id,
+                    same_worktree,
+                )?;
+            }
+            JobCommands::Resume { job_id } => {
+                cli::job::job_resume_command(&work_dir, config_path.as_ref(), job_id)?;
+            }
             JobCommands::Diff { job_id, json } => {
                 cli::job::job_diff_command(&work_dir, config_path.as_ref(), job_id, json)?;
             }
         },
+        Some(Commands::Queue { command }) => match command {
+            QueueCommands::Pause => {
+                cli::job::queue_pause_command(&work_dir, config_path.as_ref(), true)?;
+            }
+            QueueCommands::Resume => {
+                cli::job::queue_pause_command(&work_dir, config_path.as_ref(), false)?;
+            }
+        },
         Some(Commands::Mode { command }) => match command {
             ModeCommands::List { json } => {
                 cli::mode::mode_list_command(&work_dir, config_path.as_ref(), json)?;
@@ -230,11 +380,15 @@ async fn main() -> Result<()> {
             ModeCommands::Set {
                 name,
                 prompt,
+                prompt_file,
                 system_prompt,
+                system_prompt_file,
                 agent,
                 aliases,
                 session_mode,
                 max_turns,
+                max_output_tokens,
+                timeout_secs,
                 model,
                 disallowed_tools,
                 output_states,
@@ -248,11 +402,15 @@ async fn main() -> Result<()> {
                     cli::mode::ModeSetArgs {
                         name,
                         prompt,
+                        prompt_file,
                         system_prompt,
+                        system_prompt_file,
                         agent,
                         aliases,
                         session_mode,
                         max_turns,
+                        max_output_tokens,
+                        timeout_secs,
                         model,
                         disallowed_tools,
                         output_states,
@@ -265,6 +423,38 @@ async fn main() -> Result<()> {
             ModeCommands::Delete { name } => {
                 cli::mode::mode_delete_command(&work_dir, config_path.as_ref(), &name)?;
             }
+            ModeCommands::Rename {
+                old_name,
+                new_name,
+                force,
+            } => {
+                cli::mode::mode_rename_command(
+                    &work_dir,
+                    config_path.as_ref(),
+                    &old_name,
+                    &new_name,
+                    force,
+                )?;
+            }
+            ModeCommands::Copy {
+                src,
+                dst,
+                force,
+                agent,
+                model,
+                json,
+            } => {
+                cli::mode::mode_copy_command(
+                    &work_dir,
+                    config_path.as_ref(),
+                    &src,
+                    &dst,
+                    force,
+                    agent,
+                    model,
+                    json,
+                )?;
+            }
         },
         Some(Commands::Skill { command }) => match command {
             SkillCommands::List { json, agent } => {
@@ -323,6 +513,20 @@ async fn main() -> Result<()> {
             AgentCommands::Get { name, json } => {
                 cli::agent::agent_get_command(&work_dir, config_path.as_ref(), &name, json)?;
             }
+            AgentCommands::Test {
+                name,
+                timeout_secs,
+                json,
+            } => {
+                cli::agent::agent_test_command(
+                    &work_dir,
+                    config_path.as_ref(),
+                    &name,
+                    std::time::Duration::from_secs(timeout_secs),
+                    json,
+                )
+                .await?;
+            }
         },
         Some(Commands::Chain { command }) => match command {
             ChainCommands::List { json } => {
@@ -380,6 +584,29 @@ async fn main() -> Result<()> {
             ChainCommands::Delete { name } => {
                 cli::chain::chain_delete_command(&work_dir, config_path.as_ref(), &name)?;
             }
+            ChainCommands::Diagram { name, format } => {
+                cli::chain::chain_diagram_command(&work_dir, config_path.as_ref(), &name, &format)?;
+            }
+            ChainCommands::Run {
+                name,
+                file,
+                line_start,
+                line_end,
+                prompt,
+                logs,
+            } => {
+                cli::chain::chain_run_command(
+                    &work_dir,
+                    config_path.as_ref(),
+                    &name,
+                    std::path::Path::new(&file),
+                    line_start,
+                    line_end,
+                    prompt,
+                    logs,
+                )
+                .await?;
+            }
         },
         Some(Commands::Finding { command }) => match command {
             FindingCommands::List {
@@ -391,6 +618,13 @@ async fn main() -> Result<()> {
             } => {
                 cli::finding::list(project, status, severity, search, json)?;
             }
+            FindingCommands::Search {
+                query,
+                project,
+                json,
+            } => {
+                cli::finding::search(&query, project.as_deref(), json)?;
+            }
             FindingCommands::Show { id, json } => {
                 cli::finding::show(&id, json)?;
             }
@@ -403,6 +637,7 @@ async fn main() -> Result<()> {
                 impact,
                 confidence,
                 cwe,
+                cvss,
                 assets,
                 write_notes,
                 json,
@@ -417,13 +652,44 @@ async fn main() -> Result<()> {
                     impact,
                     confidence,
                     cwe,
+                    cvss,
                     assets,
                     write_notes,
                     json,
                 )?;
             }
-            FindingCommands::SetStatus { id, status } => {
-                cli::finding::set_status(&id, &status)?;
+            FindingCommands::Clone { id, title, json } => {
+                cli::finding::clone(&id, title.as_deref(), json)?;
+            }
+            FindingCommands::SetStatus {
+                id,
+                status,
+                of,
+                to,
+                ids,
+                from_status,
+                project,
+            } => match (to, id, status) {
+                (Some(to), _, _) => cli::finding::set_status_bulk(
+                    &to,
+                    &ids,
+                    project.as_deref(),
+                    from_status.as_deref(),
+                )?,
+                (None, Some(id), Some(status)) => {
+                    cli::finding::set_status(&id, &status, of.as_deref())?
+                }
+                // clap requires id and status unless --to is given
+                _ => anyhow::bail!("set-status needs <ID> <STATUS>, or --to <STATUS>"),
+            },
+            FindingCommands::Merge { id, into } => {
+                cli::finding::merge(&id, &into)?;
+            }
+            FindingCommands::Priority { id, priority } => {
+                cli::finding::set_priority(&id, priority)?;
+            }
+            FindingCommands::Reprioritize { ids, project, json } => {
+                cli::finding::reprioritize(&ids, project.as_deref(), json)?;
             }
             FindingCommands::Fp { id, reason } => {
                 cli::finding::mark_fp(&id, &reason)?;
@@ -452,6 +718,11 @@ async fn main() -> Result<()> {
             } => {
                 cli::finding::import_notes(&work_dir, &project, dry_run, json)?;
             }
+            FindingCommands::ValidateOutput { file, json } => {
+                if !cli::finding::validate_output(&file, json)? {
+                    std::process::exit(1);
+                }
+            }
             FindingCommands::ExtractFromJob { job_id, project, json } => {
                 cli::finding::extract_from_job(job_id, project, json)?;
             }
@@ -588,6 +859,31 @@ async fn main() -> Result<()> {
                     json,
                 )?;
             }
+            ImportCommands::Markdown {
+                file,
+                project,
+                create_jobs,
+                queue,
+                skill,
+                agent,
+                agents,
+                json,
+            } => {
+                cli::import::import_tool(
+                    &work_dir,
+                    config_path.as_ref(),
+                    "markdown",
+                    &file,
+                    project,
+                    "markdown",
+                    create_jobs,
+                    queue,
+                    &skill,
+                    agent,
+                    agents,
+                    json,
+                )?;
+            }
             ImportCommands::Auto {
                 file,
                 project,
@@ -621,8 +917,12 @@ async fn main() -> Result<()> {
             ProjectCommands::Show { id, json } => {
                 cli::project::show(&id, json)?;
             }
-            ProjectCommands::Discover { path, dry_run } => {
-                cli::project::discover(path, dry_run)?;
+            ProjectCommands::Discover {
+                path,
+                layouts,
+                dry_run,
+            } => {
+                cli::project::discover(path, &layouts, dry_run)?;
             }
             ProjectCommands::Select { id } => {
                 cli::project::select(&id)?;
@@ -641,6 +941,23 @@ async fn main() -> Result<()> {
             } => {
                 cli::project::overview(project, output, update_global, json)?;
             }
+            ProjectCommands::Context {
+                id,
+                finding,
+                file,
+                json,
+            } => {
+                cli::project::context(&id, &finding, file.as_deref(), json)?;
+            }
+            ProjectCommands::Report { id, format, out } => {
+                cli::project::report(&id, &format, out)?;
+            }
+            ProjectCommands::Export { id, out } => {
+                cli::project::export(&id, out)?;
+            }
+            ProjectCommands::Import { file, project_id } => {
+                cli::project::import(&file, project_id)?;
+            }
         },
         Some(Commands::Scope { command }) => match command {
             ScopeCommands::Show { project, json } => {
@@ -652,6 +969,14 @@ async fn main() -> Result<()> {
             ScopeCommands::Policy { project, json } => {
                 cli::scope::policy(project, json)?;
             }
+            ScopeCommands::Diff {
+                old,
+                new,
+                project,
+                json,
+            } => {
+                cli::scope::diff(&old, &new, project, json)?;
+            }
         },
         Some(Commands::Memory { command }) => match command {
             MemoryCommands::List {
@@ -662,6 +987,15 @@ async fn main() -> Result<()> {
             } => {
                 cli::memory::list(project, r#type, source, json)?;
             }
+            MemoryCommands::Add {
+                project,
+                r#type,
+                text,
+                file,
+                line,
+            } => {
+                cli::memory::add(&project, &r#type, &text, file, line)?;
+            }
             MemoryCommands::Import {
                 tool,
                 file,
@@ -687,6 +1021,42 @@ async fn main() -> Result<()> {
                 cli::session::show(&session_id, json)?;
             }
         },
+        Some(Commands::Config { command }) => match command {
+            ConfigCommands::Validate { json } => {
+                cli::config::config_validate_command(&work_dir, config_path.as_ref(), json)?;
+            }
+            ConfigCommands::Reload { json } => {
+                cli::config::config_reload_command(&work_dir, config_path.as_ref(), json)?;
+            }
+        },
+        Some(Commands::Artifact { command }) => match command {
+            ArtifactCommands::Verify { project, json } => {
+                cli::artifact::verify(&work_dir, project, json)?;
+            }
+        },
+        Some(Commands::Worktree { command }) => match command {
+            WorktreeCommands::Prune { dry_run } => {
+                cli::worktree::prune(&work_dir, config_path.as_ref(), dry_run)?;
+            }
+        },
+        Some(Commands::Stats { command }) => match command {
+            StatsCommands::Export {
+                range,
+                agent,
+                mode,
+                workspace,
+                format,
+            } => {
+                cli::stats::export(&range, agent, mode, workspace, &format)?;
+            }
+        },
+        Some(Commands::Doctor { test_agents, json }) => {
+            cli::doctor::doctor_command(&work_dir, config_path.as_ref(), test_agents, json)
+                .await?;
+        }
+        Some(Commands::Version { check, json }) => {
+            cli::version::version_command(check, json)?;
+        }
         None => {
             kyco::gui::run_gui(work_dir.clone(), config_path.clone())?;
         }
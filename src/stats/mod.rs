@@ -7,7 +7,7 @@
 //!
 //! ```text
 //! ┌─────────────────┐     ┌─────────────────┐
-//! │   Rust GUI      │     │     Bridge      │
+//! │   Executor      │     │     Bridge      │
 //! │   (Job Stats)   │     │  (Tool/File)    │
 //! └────────┬────────┘     └────────┬────────┘
 //!          │                       │
@@ -171,4 +171,43 @@ mod tests {
         assert!(!summary.top_tools.is_empty());
         assert!(!summary.top_files.is_empty());
     }
+
+    #[test]
+    fn test_today_totals_only_count_today() {
+        let dir = tempdir().expect("should create temp directory");
+        let db_path = dir.path().join("test_stats.db");
+        let manager = StatsManager::with_path(&db_path).expect("should open test database");
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let two_days_ago = now - 2 * 24 * 60 * 60 * 1000;
+        for (job_id, created_at) in [(1, now), (2, two_days_ago)] {
+            let job = JobStatsRecord {
+                job_id,
+                session_id: None,
+                mode: "refactor".to_string(),
+                agent_id: "claude".to_string(),
+                agent_type: "claude".to_string(),
+                status: "done".to_string(),
+                input_tokens: 1000,
+                output_tokens: 500,
+                cache_read_tokens: 0,
+                cache_write_tokens: 0,
+                cost_usd: 0.05,
+                duration_ms: 5000,
+                files_changed: 0,
+                lines_added: 0,
+                lines_removed: 0,
+                created_at,
+                started_at: Some(created_at),
+                finished_at: Some(created_at),
+                workspace_path: None,
+            };
+            manager.recorder().record_job(&job).expect("should record job");
+        }
+
+        // Only the job recorded today counts against today's budget
+        let (tokens_today, cost_today) = manager.query().get_today_totals().expect("should query today");
+        assert_eq!(tokens_today, 1500);
+        assert!((cost_today - 0.05).abs() < 0.001);
+    }
 }
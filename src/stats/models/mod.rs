@@ -168,6 +168,30 @@ pub enum TimeRange {
 }
 
 impl TimeRange {
+    /// Every range with its short CLI name, in dashboard order
+    pub const ALL: [(Self, &'static str); 11] = [
+        (Self::Last15Minutes, "15m"),
+        (Self::Last30Minutes, "30m"),
+        (Self::Last1Hour, "1h"),
+        (Self::Last3Hours, "3h"),
+        (Self::Last8Hours, "8h"),
+        (Self::Last1Day, "1d"),
+        (Self::Last3Days, "3d"),
+        (Self::Last7Days, "7d"),
+        (Self::Last30Days, "30d"),
+        (Self::Last90Days, "90d"),
+        (Self::AllTime, "all"),
+    ];
+
+    /// Short name accepted by [`TimeRange::from_str`] (e.g. `7d`)
+    pub fn short_name(&self) -> &'static str {
+        Self::ALL
+            .iter()
+            .find(|(range, _)| range == self)
+            .map(|(_, name)| *name)
+            .unwrap_or("all")
+    }
+
     /// Get the number of days to look back (None for all time).
     ///
     /// For sub-day ranges, this returns 1 to include the current day bucket.
@@ -225,6 +249,32 @@ impl TimeRange {
     }
 }
 
+impl std::str::FromStr for TimeRange {
+    type Err = String;
+
+    /// Parse a short name (`15m`, `1h`, `7d`, `all`) or the spelled-out label
+    /// in any case and spacing (`last7days`, `Last 7 days`, `all time`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn normalize(s: &str) -> String {
+            s.chars()
+                .filter(|c| c.is_ascii_alphanumeric())
+                .collect::<String>()
+                .to_ascii_lowercase()
+                .replace("minutes", "min")
+        }
+        let key = normalize(s);
+
+        Self::ALL
+            .iter()
+            .find(|(range, short)| key == *short || key == normalize(range.label()))
+            .map(|(range, _)| *range)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(|(_, name)| *name).collect();
+                format!("Unknown time range '{}'. Use one of: {}", s, names.join(", "))
+            })
+    }
+}
+
 /// Which graph to display in the stats view (legacy, kept for compatibility)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum StatsGraph {
@@ -235,3 +285,20 @@ pub enum StatsGraph {
     ModeUsage,
     AgentComparison,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_range_parses_short_and_spelled_names() {
+        for (range, short) in TimeRange::ALL {
+            assert_eq!(short.parse::<TimeRange>(), Ok(range));
+            assert_eq!(range.label().parse::<TimeRange>(), Ok(range));
+        }
+        assert_eq!("last7days".parse(), Ok(TimeRange::Last7Days));
+        assert_eq!("LAST_15_MINUTES".parse(), Ok(TimeRange::Last15Minutes));
+        assert_eq!("all time".parse(), Ok(TimeRange::AllTime));
+        assert!("5d".parse::<TimeRange>().is_err());
+    }
+}
@@ -40,6 +40,20 @@ impl StatsQuery {
         })
     }
 
+    /// Total tokens (input + output) and cost recorded for the current UTC day.
+    ///
+    /// Used by the executor to enforce `settings.budget`.
+    pub fn get_today_totals(&self) -> Result<(u64, f64)> {
+        let conn = self.db.conn();
+        let today = day_bucket(Utc::now().timestamp_millis());
+        let totals = conn.query_row(
+            "SELECT COALESCE(SUM(input_tokens + output_tokens), 0), COALESCE(SUM(cost_usd), 0) FROM job_stats WHERE day_bucket = ?",
+            [today],
+            |row| Ok((row.get::<_, u64>(0)?, row.get::<_, f64>(1)?)),
+        )?;
+        Ok(totals)
+    }
+
     fn cutoff_day(&self, range: TimeRange) -> Option<String> {
         range.days().map(|days| {
             let cutoff_ms = Utc::now().timestamp_millis() - (days as i64 * 24 * 60 * 60 * 1000);
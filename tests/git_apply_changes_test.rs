@@ -19,7 +19,7 @@ fn test_new_file_in_worktree() {
 
     // Create worktree
     let worktree = manager
-        .create_worktree(job_id)
+        .create_worktree(job_id, None)
         .expect("Failed to create worktree");
 
     // Create new file in worktree
@@ -49,7 +49,7 @@ fn test_new_file_staged_in_worktree() {
 
     // Create worktree
     let worktree = manager
-        .create_worktree(job_id)
+        .create_worktree(job_id, None)
         .expect("Failed to create worktree");
 
     // Create new file in worktree
@@ -91,7 +91,7 @@ fn test_apply_new_file_from_worktree() {
 
     // Create worktree
     let worktree = manager
-        .create_worktree(job_id)
+        .create_worktree(job_id, None)
         .expect("Failed to create worktree");
 
     // Create a new file in the worktree
@@ -140,7 +140,7 @@ fn test_apply_new_file_in_subdirectory() {
 
     // Create worktree
     let worktree = manager
-        .create_worktree(job_id)
+        .create_worktree(job_id, None)
         .expect("Failed to create worktree");
 
     // Create a new file in a subdirectory in the worktree
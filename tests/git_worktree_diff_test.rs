@@ -29,7 +29,7 @@ fn test_worktree_creation_and_removal() {
 
     // Create worktree
     let worktree = manager
-        .create_worktree(job_id)
+        .create_worktree(job_id, None)
         .expect("Failed to create worktree");
 
     // Verify worktree exists
@@ -64,7 +64,7 @@ fn test_diff_generation() {
 
     // Create worktree
     let worktree = manager
-        .create_worktree(job_id)
+        .create_worktree(job_id, None)
         .expect("Failed to create worktree");
 
     // Modify file in worktree
@@ -118,7 +118,7 @@ fn test_apply_changes() {
 
     // Create worktree
     let worktree = manager
-        .create_worktree(job_id)
+        .create_worktree(job_id, None)
         .expect("Failed to create worktree");
 
     // Modify file in worktree
@@ -150,7 +150,7 @@ fn test_apply_changes_uses_commit_message_for_auto_commit() {
 
     let job_id: u64 = 30;
     let worktree = manager
-        .create_worktree(job_id)
+        .create_worktree(job_id, None)
         .expect("Failed to create worktree");
 
     // Modify file in worktree but do NOT commit.
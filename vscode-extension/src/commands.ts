@@ -1,5 +1,5 @@
 import * as vscode from 'vscode';
-import type { BatchFile, SelectionPayload, BatchPayload } from './types';
+import type { BatchFile, SelectionPayload, SelectionRegion, BatchPayload } from './types';
 import { getGitRoot, getProjectRoot } from './git';
 import { normalizeSubtreeGlob } from './utils';
 import { findDependencies, getDiagnosticsForDocument } from './lsp';
@@ -46,6 +46,17 @@ export async function handleSendSelection(): Promise<void> {
         diagnostics: diagnostics
     };
 
+    // Multiple selections (multi-cursor) become one job referencing every region
+    const nonEmpty = editor.selections.filter(sel => !sel.isEmpty);
+    if (nonEmpty.length > 1) {
+        payload.regions = nonEmpty.map((sel): SelectionRegion => ({
+            file_path: filePath,
+            selected_text: document.getText(sel),
+            line_start: sel.start.line + 1,
+            line_end: sel.end.line + 1
+        }));
+    }
+
     const kycoHttp = await getKycoHttpConfig(workspace);
     sendSelectionRequest(payload, kycoHttp);
 }
@@ -15,6 +15,13 @@ export interface Diagnostic {
     code?: string;
 }
 
+export interface SelectionRegion {
+    file_path: string;
+    selected_text: string;
+    line_start: number;
+    line_end: number;
+}
+
 export interface SelectionPayload {
     file_path: string;
     selected_text: string;
@@ -31,6 +38,8 @@ export interface SelectionPayload {
     related_tests: string[];
     /** Errors and warnings from the language server for this file */
     diagnostics: Diagnostic[];
+    /** All selections when several regions are sent as one job (multi-cursor) */
+    regions?: SelectionRegion[];
 }
 
 export interface BatchFile {